@@ -609,16 +609,34 @@ contract DataHavenServiceManager is OwnableUpgradeable, IAVSRegistrar, IDataHave
         for (uint256 i = 0; i < slashings.length; i++) {
             address ethOperator = validatorSolochainAddressToEthAddress[slashings[i].operator];
             if (ethOperator == address(0)) continue;
-            IAllocationManagerTypes.SlashingParams memory slashingParams =
-                IAllocationManagerTypes.SlashingParams({
-                    operator: ethOperator,
-                    operatorSetId: VALIDATORS_SET_ID,
-                    strategies: slashings[i].strategies,
-                    wadsToSlash: slashings[i].wadsToSlash,
-                    description: slashings[i].description
-                });
-
-            _ALLOCATION_MANAGER.slashOperator(address(this), slashingParams);
+            _slashOperator(
+                ethOperator,
+                slashings[i].strategies,
+                slashings[i].wadsToSlash,
+                slashings[i].description
+            );
+        }
+
+        emit SlashingComplete();
+    }
+
+    /**
+     * @notice Slash the operators of the validators set, each request carrying the digest of the evidence of its offence
+     * @param slashings array of request to slash operator containing the operator to slash, array of proportions to slash, the reason of the slashing and the evidence digest.
+     */
+    function slashValidatorsOperatorWithEvidence(
+        SlashingRequestWithEvidence[] calldata slashings
+    ) external onlySnowbridgeInitiator {
+        for (uint256 i = 0; i < slashings.length; i++) {
+            address ethOperator = validatorSolochainAddressToEthAddress[slashings[i].operator];
+            if (ethOperator == address(0)) continue;
+            _slashOperator(
+                ethOperator,
+                slashings[i].strategies,
+                slashings[i].wadsToSlash,
+                slashings[i].description
+            );
+            emit OperatorSlashedWithEvidence(ethOperator, slashings[i].evidence);
         }
 
         emit SlashingComplete();
@@ -642,6 +660,31 @@ contract DataHavenServiceManager is OwnableUpgradeable, IAVSRegistrar, IDataHave
 
     // ============ Internal Functions ============
 
+    /**
+     * @notice Slashes an operator of the validators set through the AllocationManager
+     * @param operator The Ethereum address of the operator to slash
+     * @param strategies The strategies to slash
+     * @param wadsToSlash The proportion to slash of each strategy
+     * @param description The reason of the slashing
+     */
+    function _slashOperator(
+        address operator,
+        IStrategy[] calldata strategies,
+        uint256[] calldata wadsToSlash,
+        string calldata description
+    ) private {
+        IAllocationManagerTypes.SlashingParams memory slashingParams =
+            IAllocationManagerTypes.SlashingParams({
+                operator: operator,
+                operatorSetId: VALIDATORS_SET_ID,
+                strategies: strategies,
+                wadsToSlash: wadsToSlash,
+                description: description
+            });
+
+        _ALLOCATION_MANAGER.slashOperator(address(this), slashingParams);
+    }
+
     /**
      * @notice Sorts operator rewards array by operator address in ascending order using insertion sort
      * @dev Insertion sort is optimal for small arrays (validator set capped at 32)
@@ -104,6 +104,11 @@ interface IDataHavenServiceManagerEvents {
     /// @notice Emitted when a batch of slashing request is being successfully slashed
     event SlashingComplete();
 
+    /// @notice Emitted when an operator is slashed by a request carrying the evidence of the offence
+    /// @param operator The Ethereum address of the slashed operator
+    /// @param evidence The digest of the evidence of the offence on DataHaven
+    event OperatorSlashedWithEvidence(address indexed operator, bytes32 evidence);
+
     /// @notice Emitted when strategy multipliers are set or updated
     /// @param strategyMultipliers Array of strategy-multiplier pairs that were set
     event StrategiesAndMultipliersSet(IRewardsCoordinatorTypes
@@ -144,6 +149,15 @@ interface IDataHavenServiceManager is
         string description;
     }
 
+    /// @notice Slashing request that also carries the digest of the evidence of the offence (outbound schema V2).
+    struct SlashingRequestWithEvidence {
+        address operator;
+        IStrategy[] strategies;
+        uint256[] wadsToSlash;
+        string description;
+        bytes32 evidence;
+    }
+
     /// @notice Checks if a validator address is in the allowlist
     /// @param validator Address to check
     /// @return True if the validator is in the allowlist, false otherwise
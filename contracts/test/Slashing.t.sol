@@ -165,4 +165,101 @@ contract SlashingTest is AVSDeployer {
         emit IDataHavenServiceManagerEvents.SlashingComplete();
         serviceManager.slashValidatorsOperator(slashings);
     }
+
+    function test_fulfilSlashingRequestWithEvidence() public {
+        address solochainOperator = address(0xBEEF);
+        bytes32 evidence = keccak256("equivocation evidence");
+        (OperatorSet memory operatorSet, IStrategy[] memory strategies) =
+            _registerOperator(solochainOperator);
+
+        DataHavenServiceManager.SlashingRequestWithEvidence[] memory slashings =
+            new DataHavenServiceManager.SlashingRequestWithEvidence[](1);
+        uint256[] memory wadsToSlash = new uint256[](strategies.length);
+        for (uint256 i = 0; i < wadsToSlash.length; i++) {
+            wadsToSlash[i] = 1e16;
+        }
+        slashings[0] = IDataHavenServiceManager.SlashingRequestWithEvidence(
+            solochainOperator, strategies, wadsToSlash, "Testing slashing", evidence
+        );
+
+        vm.roll(block.number + uint32(7 days) + 1);
+
+        // Because the current magnitude for the allocation is 0
+        uint256[] memory wadsToSlashed = new uint256[](strategies.length);
+
+        vm.prank(snowbridgeAgent);
+        vm.expectEmit();
+        emit IAllocationManagerEvents.OperatorSlashed(
+            operator, operatorSet, strategies, wadsToSlashed, "Testing slashing"
+        );
+        vm.expectEmit();
+        emit IDataHavenServiceManagerEvents.OperatorSlashedWithEvidence(operator, evidence);
+        vm.expectEmit();
+        emit IDataHavenServiceManagerEvents.SlashingComplete();
+        serviceManager.slashValidatorsOperatorWithEvidence(slashings);
+    }
+
+    function test_fulfilSlashingRequestWithEvidence_skipsUnknownSolochainAddress() public {
+        vm.prank(avsOwner);
+        serviceManager.setSnowbridgeInitiator(snowbridgeAgent);
+
+        DataHavenServiceManager.SlashingRequestWithEvidence[] memory slashings =
+            new DataHavenServiceManager.SlashingRequestWithEvidence[](1);
+        slashings[0] = IDataHavenServiceManager.SlashingRequestWithEvidence(
+            address(0xDEAD),
+            new IStrategy[](0),
+            new uint256[](0),
+            "Testing unknown solochain operator",
+            keccak256("evidence")
+        );
+
+        // Only SlashingComplete is emitted, the unknown operator is not slashed
+        vm.recordLogs();
+        vm.prank(snowbridgeAgent);
+        serviceManager.slashValidatorsOperatorWithEvidence(slashings);
+        Vm.Log[] memory logs = vm.getRecordedLogs();
+        assertEq(logs.length, 1);
+        assertEq(logs[0].topics[0], IDataHavenServiceManagerEvents.SlashingComplete.selector);
+    }
+
+    function test_slashValidatorsOperatorWithEvidence_revertsForOtherCallers() public {
+        vm.prank(avsOwner);
+        serviceManager.setSnowbridgeInitiator(snowbridgeAgent);
+
+        DataHavenServiceManager.SlashingRequestWithEvidence[] memory slashings =
+            new DataHavenServiceManager.SlashingRequestWithEvidence[](0);
+
+        vm.prank(operator);
+        vm.expectRevert(abi.encodeWithSignature("OnlyRewardsInitiator()"));
+        serviceManager.slashValidatorsOperatorWithEvidence(slashings);
+    }
+
+    /// @dev Registers `operator` in the validators set, mapped to `solochainOperator`.
+    function _registerOperator(
+        address solochainOperator
+    ) internal returns (OperatorSet memory operatorSet, IStrategy[] memory strategies) {
+        vm.prank(avsOwner);
+        serviceManager.addValidatorToAllowlist(operator);
+        vm.prank(avsOwner);
+        serviceManager.setSnowbridgeInitiator(snowbridgeAgent);
+
+        vm.prank(operator);
+        delegationManager.registerAsOperator(address(0), 0, "");
+
+        uint32[] memory operatorSetIds = new uint32[](1);
+        operatorSetIds[0] = serviceManager.VALIDATORS_SET_ID();
+        vm.prank(operator);
+        allocationManager.registerForOperatorSets(
+            operator,
+            IAllocationManagerTypes.RegisterParams({
+                avs: address(serviceManager),
+                operatorSetIds: operatorSetIds,
+                data: abi.encodePacked(solochainOperator)
+            })
+        );
+
+        operatorSet =
+            OperatorSet({avs: address(serviceManager), id: serviceManager.VALIDATORS_SET_ID()});
+        strategies = allocationManager.getStrategiesInOperatorSet(operatorSet);
+    }
 }
@@ -0,0 +1,276 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Watchdog restarting the BEEFY gadget when its voter stalls.
+//!
+//! A stalled BEEFY voter does not terminate the gadget task, it just stops producing
+//! finality proofs, and with them the commitments relayed to Ethereum. The watchdog
+//! compares the best BEEFY block with the GRANDPA finalized head and, when BEEFY stops
+//! following finality for too long, drops the gadget and starts a new one with fresh
+//! links to the block import.
+//!
+//! The BEEFY gossip protocol is registered once on the network, so the watchdog keeps a
+//! spare clone of the notification service to hand over to the restarted gadget. The
+//! spare is drained while it waits, and the substreams of the peers it saw are closed on
+//! restart so that they are reopened towards the new gadget.
+
+use datahaven_runtime_common::{Block, BlockNumber};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use futures_timer::Delay;
+use log::{debug, error, info, warn};
+use sc_consensus_beefy::communication::notification::BeefyBestBlockStream;
+use sc_network::service::traits::{NotificationEvent, NotificationService, ValidationResult};
+use sc_network::PeerId;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use substrate_prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+const LOG_TARGET: &str = "beefy-watchdog";
+
+/// How often the BEEFY progress is checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Number of finalized blocks the best BEEFY block may lag behind before the voter is
+/// considered stalled. BEEFY votes on a subset of the finalized blocks, so some lag is
+/// expected.
+const MAX_BEEFY_LAG: BlockNumber = 64;
+
+/// Time without BEEFY progress, while lagging, before the first restart.
+const INITIAL_STALL_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Upper bound of the stall timeout, which doubles after every restart that does not
+/// bring BEEFY back. A stall caused by missing votes from other validators cannot be
+/// fixed locally, so restarts must not happen in a tight loop.
+const MAX_STALL_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Size of the buffer of the best BEEFY block subscription.
+const BEST_BLOCK_QUEUE_SIZE: usize = 100_000;
+
+struct Metrics {
+    restarts: Counter<U64>,
+    lag: Gauge<U64>,
+}
+
+impl Metrics {
+    fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            restarts: register(
+                Counter::new(
+                    "datahaven_beefy_watchdog_restarts_total",
+                    "Number of times the BEEFY gadget was restarted after stalling",
+                )?,
+                registry,
+            )?,
+            lag: register(
+                Gauge::new(
+                    "datahaven_beefy_watchdog_lag",
+                    "Number of finalized blocks the best BEEFY block lags behind",
+                )?,
+                registry,
+            )?,
+        })
+    }
+}
+
+/// Parameters of [`run_supervised_beefy_gadget`].
+pub(crate) struct BeefyWatchdogParams<C, S, F> {
+    /// Client used to resolve block numbers and the finalized head.
+    pub client: Arc<C>,
+    /// Sync oracle; the voter is expected to lag behind while the node is major syncing.
+    pub sync: S,
+    /// Best BEEFY block notifications, from the RPC links of the block import.
+    pub best_beefy_stream: BeefyBestBlockStream<Block>,
+    /// Notification service of the BEEFY gossip protocol.
+    pub notification_service: Box<dyn NotificationService>,
+    /// Builds a BEEFY gadget future from a notification service. The flag is `true` for
+    /// restarts.
+    pub start_gadget: F,
+    /// Registry for the watchdog metrics.
+    pub prometheus_registry: Option<Registry>,
+}
+
+/// Events the supervisor loop reacts to.
+enum Action {
+    GadgetTerminated,
+    BestBeefy(Option<<Block as sp_runtime::traits::Block>::Hash>),
+    SpareEvent(Option<NotificationEvent>),
+    Check,
+}
+
+/// Run the BEEFY gadget, restarting it whenever its voter stalls.
+///
+/// Returns when the gadget terminates on its own, like an unsupervised gadget would.
+pub(crate) async fn run_supervised_beefy_gadget<C, S, F, Fut>(params: BeefyWatchdogParams<C, S, F>)
+where
+    C: HeaderBackend<Block>,
+    S: SyncOracle,
+    F: FnMut(Box<dyn NotificationService>, bool) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let BeefyWatchdogParams {
+        client,
+        sync,
+        best_beefy_stream,
+        mut notification_service,
+        mut start_gadget,
+        prometheus_registry,
+    } = params;
+
+    let metrics =
+        prometheus_registry
+            .as_ref()
+            .and_then(|registry| match Metrics::register(registry) {
+                Ok(metrics) => Some(metrics),
+                Err(err) => {
+                    warn!(target: LOG_TARGET, "Failed to register metrics: {err:?}");
+                    None
+                }
+            });
+
+    let mut spare = match notification_service.clone() {
+        Ok(spare) => Some(spare),
+        Err(()) => {
+            warn!(
+                target: LOG_TARGET,
+                "Cannot clone the BEEFY notification service, stalls will only be reported"
+            );
+            None
+        }
+    };
+    let mut spare_peers = HashSet::<PeerId>::new();
+
+    let mut gadget: BoxFuture<'static, ()> = start_gadget(notification_service, false).boxed();
+    let mut best_beefy = best_beefy_stream.subscribe(BEST_BLOCK_QUEUE_SIZE).fuse();
+    let mut best_beefy_number = client.info().finalized_number;
+    let mut last_progress = Instant::now();
+    let mut stall_timeout = INITIAL_STALL_TIMEOUT;
+    let mut check = Delay::new(CHECK_INTERVAL).fuse();
+
+    loop {
+        let action = {
+            let spare_event = async {
+                match spare.as_mut() {
+                    Some(spare) => spare.next_event().await,
+                    None => futures::future::pending().await,
+                }
+            };
+
+            futures::select! {
+                _ = (&mut gadget).fuse() => Action::GadgetTerminated,
+                hash = best_beefy.next() => Action::BestBeefy(hash),
+                event = spare_event.fuse() => Action::SpareEvent(event),
+                _ = check => Action::Check,
+            }
+        };
+
+        match action {
+            Action::GadgetTerminated => {
+                error!(target: LOG_TARGET, "BEEFY gadget terminated");
+                return;
+            }
+            Action::BestBeefy(Some(hash)) => match client.number(hash) {
+                Ok(Some(number)) if number > best_beefy_number => {
+                    best_beefy_number = number;
+                    last_progress = Instant::now();
+                    stall_timeout = INITIAL_STALL_TIMEOUT;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    debug!(target: LOG_TARGET, "Failed to read best BEEFY block {hash:?}: {err}");
+                }
+            },
+            // The voter links are gone; the fused stream is not polled anymore.
+            Action::BestBeefy(None) => {}
+            Action::SpareEvent(Some(event)) => match event {
+                NotificationEvent::ValidateInboundSubstream { result_tx, .. } => {
+                    // The spare must not veto substreams accepted by the running gadget.
+                    let _ = result_tx.send(ValidationResult::Accept);
+                }
+                NotificationEvent::NotificationStreamOpened { peer, .. } => {
+                    spare_peers.insert(peer);
+                }
+                NotificationEvent::NotificationStreamClosed { peer } => {
+                    spare_peers.remove(&peer);
+                }
+                NotificationEvent::NotificationReceived { .. } => {}
+            },
+            Action::SpareEvent(None) => {
+                // The network is shutting down.
+                spare = None;
+            }
+            Action::Check => {
+                check = Delay::new(CHECK_INTERVAL).fuse();
+
+                let finalized = client.info().finalized_number;
+                let lag = finalized.saturating_sub(best_beefy_number);
+                if let Some(metrics) = &metrics {
+                    metrics.lag.set(lag.into());
+                }
+
+                if sync.is_major_syncing() {
+                    last_progress = Instant::now();
+                    continue;
+                }
+
+                let stalled_for = last_progress.elapsed();
+                if lag < MAX_BEEFY_LAG || stalled_for < stall_timeout {
+                    continue;
+                }
+
+                let Some(mut handed_over) = spare.take() else {
+                    warn!(
+                        target: LOG_TARGET,
+                        "BEEFY stalled at #{best_beefy_number} for {}s (finalized #{finalized}), \
+                         cannot restart the gadget",
+                        stalled_for.as_secs()
+                    );
+                    last_progress = Instant::now();
+                    continue;
+                };
+
+                warn!(
+                    target: LOG_TARGET,
+                    "BEEFY stalled at #{best_beefy_number} for {}s (finalized #{finalized}), \
+                     restarting the gadget",
+                    stalled_for.as_secs()
+                );
+
+                // Dropping the gadget future stops the stalled voter.
+                drop(gadget);
+                spare = handed_over.clone().ok();
+                for peer in spare_peers.drain() {
+                    let _ = handed_over.close_substream(peer).await;
+                }
+                gadget = start_gadget(handed_over, true).boxed();
+
+                if let Some(metrics) = &metrics {
+                    metrics.restarts.inc();
+                }
+                last_progress = Instant::now();
+                stall_timeout = (stall_timeout * 2).min(MAX_STALL_TIMEOUT);
+                info!(
+                    target: LOG_TARGET,
+                    "BEEFY gadget restarted, next restart in {}s without progress",
+                    stall_timeout.as_secs()
+                );
+            }
+        }
+    }
+}
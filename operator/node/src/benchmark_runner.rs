@@ -0,0 +1,139 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `benchmarks` subcommand, which generates the weights of a runtime.
+//!
+//! It runs the benchmarks of every pallet in the `define_benchmarks!` list of the selected
+//! runtime, through the same code path as `benchmark pallet`, and writes one `WeightInfo` file
+//! per pallet into `runtime/<runtime>/src/weights`. Each runtime gets weights measured with its
+//! own configuration and genesis, rather than copies of the weights of another runtime.
+
+use crate::cli::Cli;
+use clap::{Parser, ValueEnum};
+use datahaven_runtime_common::Block;
+use frame_benchmarking_cli::PalletCmd;
+use sc_cli::SubstrateCli;
+use sp_runtime::traits::HashingFor;
+use std::path::PathBuf;
+
+/// Runtime whose weights are generated.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum BenchmarkRuntime {
+    /// `datahaven-stagenet-runtime`
+    Stagenet,
+    /// `datahaven-testnet-runtime`
+    Testnet,
+    /// `datahaven-mainnet-runtime`
+    Mainnet,
+}
+
+impl BenchmarkRuntime {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Stagenet => "stagenet",
+            Self::Testnet => "testnet",
+            Self::Mainnet => "mainnet",
+        }
+    }
+
+    /// Development chain of the runtime, whose genesis the benchmarks start from.
+    fn chain(&self) -> &'static str {
+        match self {
+            Self::Stagenet => "stagenet-dev",
+            Self::Testnet => "testnet-dev",
+            Self::Mainnet => "mainnet-dev",
+        }
+    }
+}
+
+/// Generates the weight files of a runtime.
+#[derive(Debug, Parser)]
+pub struct BenchmarksCmd {
+    /// Runtime whose weights are generated.
+    #[arg(long, value_enum, ignore_case = true, default_value_t = BenchmarkRuntime::Testnet)]
+    pub runtime: BenchmarkRuntime,
+
+    /// Pallets to benchmark, e.g. `pallet_balances,pallet_evm`. All of them by default.
+    #[arg(long, value_delimiter = ',')]
+    pub pallets: Vec<String>,
+
+    /// Number of steps of the benchmarks components.
+    #[arg(long, default_value_t = 50)]
+    pub steps: u32,
+
+    /// Number of repetitions of each step.
+    #[arg(long, default_value_t = 20)]
+    pub repeat: u32,
+
+    /// Directory of the runtime crates, whose `<runtime>/src/weights` modules receive the
+    /// weight files.
+    #[arg(long, default_value = "runtime")]
+    pub runtimes_dir: PathBuf,
+
+    /// Template of the weight files.
+    #[arg(long, default_value = "benchmarking/frame-weight-template.hbs")]
+    pub template: PathBuf,
+
+    /// License header of the weight files.
+    #[arg(long, default_value = "../file_header.txt")]
+    pub header: PathBuf,
+}
+
+impl BenchmarksCmd {
+    pub fn run(&self, cli: &Cli) -> sc_cli::Result<()> {
+        sc_cli::LoggerBuilder::new("").init()?;
+
+        let output = self
+            .runtimes_dir
+            .join(self.runtime.name())
+            .join("src")
+            .join("weights");
+        if !output.is_dir() {
+            return Err(format!(
+                "{} is not a directory; run the command from the operator directory or set \
+                 `--runtimes-dir`",
+                output.display()
+            )
+            .into());
+        }
+        let pallets = if self.pallets.is_empty() {
+            "*".to_string()
+        } else {
+            self.pallets.join(",")
+        };
+
+        let args = [
+            "pallet".into(),
+            format!("--chain={}", self.runtime.chain()),
+            format!("--pallet={pallets}"),
+            "--extrinsic=*".into(),
+            format!("--steps={}", self.steps),
+            format!("--repeat={}", self.repeat),
+            "--wasm-execution=compiled".into(),
+            format!("--template={}", self.template.display()),
+            format!("--header={}", self.header.display()),
+            format!("--output={}", output.display()),
+        ];
+        let cmd = PalletCmd::try_parse_from(args).map_err(|err| err.to_string())?;
+
+        log::info!(
+            "Benchmarking {pallets} of the {} runtime into {}",
+            self.runtime.name(),
+            output.display()
+        );
+        cmd.run_with_spec::<HashingFor<Block>, ()>(Some(cli.load_spec(self.runtime.chain())?))
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `--pruning bridge-archive`: a pruned node that keeps the history of the bridge.
+//!
+//! The rewards merkle proofs relayed to Ethereum are rebuilt from the reward points of their
+//! era, and the slashes are audited against the slashes and session roots recorded on chain.
+//! The runtime prunes these entries after some eras, so proof-serving RPC nodes used to run
+//! as full archives. In this mode the node only keeps the state of the last
+//! [`STATE_PRUNING_BLOCKS`] blocks, and copies the entries of [`ARCHIVED_STORAGE`] to its
+//! auxiliary database as blocks get finalized. Archived entries are never removed, and are
+//! served by the `bridgeArchive_*` RPC methods.
+
+use codec::{Decode, Encode};
+use datahaven_runtime_common::{AccountId, Block, BlockNumber};
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use log::{debug, info, warn};
+use pallet_external_validators_rewards::EraRewardPoints;
+use sc_client_api::{AuxStore, Backend, BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use snowbridge_merkle_tree::merkle_proof;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    hashing::{blake2_128, twox_128, twox_64},
+    storage::StorageKey,
+    Bytes, H160, H256,
+};
+use sp_runtime::traits::{Header as HeaderT, Keccak256};
+use std::{ffi::OsString, sync::Arc};
+
+const LOG_TARGET: &str = "bridge-archive";
+
+/// Value of `--pruning` selecting the mode.
+const BRIDGE_ARCHIVE: &str = "bridge-archive";
+
+/// Number of recent blocks whose whole state is kept.
+pub(crate) const STATE_PRUNING_BLOCKS: u32 = 256;
+
+/// Storage maps copied to the archive, as `(pallet, storage)` names.
+const ARCHIVED_STORAGE: [(&[u8], &[u8]); 5] = [
+    (b"ExternalValidatorsRewards", b"RewardPointsForEra"),
+    (b"ExternalValidatorsRewards", b"EraRewardAddresses"),
+    (b"ExternalValidatorsSlashes", b"Slashes"),
+    (b"ExternalValidatorsSlashes", b"ValidatorSlashInEra"),
+    (b"Historical", b"HistoricalSessions"),
+];
+
+/// Prefix of the archived entries in the auxiliary database, followed by their storage key.
+const AUX_PREFIX: &[u8] = b"bridge-archive:";
+
+/// Auxiliary database key of the number of the last archived block.
+const LAST_ARCHIVED_BLOCK_KEY: &[u8] = b"bridge-archive-last-block";
+
+/// Replace `--pruning bridge-archive` in the command line `args` with the state pruning of
+/// the mode, which `sc-cli` can parse, and return whether the mode was selected.
+///
+/// `--state-pruning`, of which `--pruning` is an alias, is handled the same way.
+pub(crate) fn extract_pruning_mode(
+    args: impl IntoIterator<Item = OsString>,
+) -> (Vec<OsString>, bool) {
+    let state_pruning = [
+        OsString::from("--state-pruning"),
+        STATE_PRUNING_BLOCKS.to_string().into(),
+    ];
+    let mut selected = false;
+    let mut parsed = Vec::new();
+
+    let mut args = args.into_iter().peekable();
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("--pruning" | "--state-pruning")
+                if args.peek().is_some_and(|value| value == BRIDGE_ARCHIVE) =>
+            {
+                args.next();
+            }
+            Some(flag)
+                if flag.split_once('=').is_some_and(|(flag, value)| {
+                    matches!(flag, "--pruning" | "--state-pruning") && value == BRIDGE_ARCHIVE
+                }) => {}
+            _ => {
+                parsed.push(arg);
+                continue;
+            }
+        }
+        selected = true;
+        parsed.extend(state_pruning.iter().cloned());
+    }
+
+    (parsed, selected)
+}
+
+fn archive_key(storage_key: &[u8]) -> Vec<u8> {
+    [AUX_PREFIX, storage_key].concat()
+}
+
+/// Copy the entries of [`ARCHIVED_STORAGE`] in the state of `hash` which are not archived
+/// yet, or changed, and return their number.
+fn archive_block<C, BE>(client: &C, hash: H256, number: BlockNumber) -> sp_blockchain::Result<usize>
+where
+    C: StorageProvider<Block, BE> + AuxStore,
+    BE: Backend<Block>,
+{
+    let mut entries = Vec::new();
+    for (pallet, storage) in ARCHIVED_STORAGE {
+        let prefix = StorageKey([twox_128(pallet), twox_128(storage)].concat());
+        for (key, value) in client.storage_pairs(hash, Some(&prefix), None)? {
+            let key = archive_key(&key.0);
+            if client.get_aux(&key)?.as_ref() != Some(&value.0) {
+                entries.push((key, value.0));
+            }
+        }
+    }
+    let archived = entries.len();
+
+    entries.push((LAST_ARCHIVED_BLOCK_KEY.to_vec(), number.encode()));
+    let entries = entries
+        .iter()
+        .map(|(key, value)| (key.as_slice(), value.as_slice()))
+        .collect::<Vec<_>>();
+    client.insert_aux(&entries, &[])?;
+
+    Ok(archived)
+}
+
+/// Archive the bridge storage of every finalized block, from the current one on.
+pub(crate) async fn run_bridge_archive<C, BE>(client: Arc<C>)
+where
+    C: BlockchainEvents<Block> + HeaderBackend<Block> + StorageProvider<Block, BE> + AuxStore,
+    BE: Backend<Block>,
+{
+    let mut finality_notifications = client.finality_notification_stream();
+
+    let info = client.info();
+    info!(target: LOG_TARGET, "Archiving the bridge storage from #{}", info.finalized_number);
+    let mut archive = |hash, number| match archive_block(&*client, hash, number) {
+        Ok(0) => {}
+        Ok(archived) => {
+            debug!(target: LOG_TARGET, "Archived {archived} entries at #{number}")
+        }
+        Err(err) => warn!(target: LOG_TARGET, "Failed to archive #{number} {hash:?}: {err}"),
+    };
+
+    archive(info.finalized_hash, info.finalized_number);
+    while let Some(notification) = finality_notifications.next().await {
+        archive(notification.hash, *notification.header.number());
+    }
+}
+
+/// Merkle proof of the reward points of a validator in an era.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardsMerkleProof {
+    /// Root of the rewards tree of the era.
+    pub root: H256,
+    /// Hashes of the siblings on the path from the leaf to the root.
+    pub proof: Vec<H256>,
+    /// Number of validators rewarded in the era.
+    pub number_of_leaves: u64,
+    /// Index of the validator among the rewarded ones.
+    pub leaf_index: u64,
+    /// Hash of the SCALE-encoded validator and points.
+    pub leaf: H256,
+}
+
+/// Serves the archived entries, shared by the RPC methods.
+pub(crate) struct BridgeArchive<C> {
+    client: Arc<C>,
+}
+
+impl<C> BridgeArchive<C>
+where
+    C: AuxStore + Send + Sync + 'static,
+{
+    pub(crate) fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    /// RPC module with the `bridgeArchive_*` methods.
+    pub(crate) fn into_rpc(self) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        module
+            .register_method(
+                "bridgeArchive_storage",
+                |params, archive, _| -> RpcResult<Option<Bytes>> {
+                    let key: Bytes = params.one()?;
+                    Ok(archive.storage(&key).map(Bytes))
+                },
+            )
+            .expect("the method is registered once; qed");
+        module
+            .register_method(
+                "bridgeArchive_rewardsMerkleProof",
+                |params, archive, _| -> RpcResult<Option<RewardsMerkleProof>> {
+                    let (account, era) = params.parse::<(AccountId, u32)>()?;
+                    Ok(archive.rewards_merkle_proof(account, era))
+                },
+            )
+            .expect("the method is registered once; qed");
+        module
+            .register_method(
+                "bridgeArchive_lastArchivedBlock",
+                |_, archive, _| -> RpcResult<Option<BlockNumber>> {
+                    Ok(archive.last_archived_block())
+                },
+            )
+            .expect("the method is registered once; qed");
+        module
+    }
+
+    /// Latest archived value of the storage entry `key`.
+    fn storage(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.client
+            .get_aux(&archive_key(key))
+            .inspect_err(|err| warn!(target: LOG_TARGET, "Failed to read the archive: {err}"))
+            .ok()
+            .flatten()
+    }
+
+    fn last_archived_block(&self) -> Option<BlockNumber> {
+        let data = self
+            .client
+            .get_aux(LAST_ARCHIVED_BLOCK_KEY)
+            .ok()
+            .flatten()?;
+        BlockNumber::decode(&mut &data[..]).ok()
+    }
+
+    /// Same proof as the `generate_rewards_merkle_proof` runtime API, built from the archived
+    /// reward points and reward addresses of `era`.
+    fn rewards_merkle_proof(&self, account: AccountId, era: u32) -> Option<RewardsMerkleProof> {
+        let era_key = era.encode();
+        let key = [
+            &twox_128(b"ExternalValidatorsRewards")[..],
+            &twox_128(b"RewardPointsForEra"),
+            &twox_64(&era_key),
+            &era_key,
+        ]
+        .concat();
+        let points = EraRewardPoints::<AccountId>::decode(&mut &self.storage(&key)?[..]).ok()?;
+
+        let leaf_index = points
+            .individual
+            .keys()
+            .position(|validator| validator == &account)?;
+        let leaves = points.generate_rewards_leaves::<Keccak256>(|validator| {
+            let account_key = validator.encode();
+            let key = [
+                &twox_128(b"ExternalValidatorsRewards")[..],
+                &twox_128(b"EraRewardAddresses"),
+                &twox_64(&era_key),
+                &era_key,
+                &blake2_128(&account_key),
+                &account_key,
+            ]
+            .concat();
+            H160::decode(&mut &self.storage(&key)?[..]).ok()
+        });
+        let proof = merkle_proof::<Keccak256, _>(leaves.into_iter(), leaf_index as u64);
+
+        Some(RewardsMerkleProof {
+            root: proof.root,
+            proof: proof.proof,
+            number_of_leaves: proof.number_of_leaves,
+            leaf_index: proof.leaf_index,
+            leaf: proof.leaf,
+        })
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `build-spec` subcommand, extended to build the genesis from a runtime preset.
+//!
+//! `--preset <name>` replaces the genesis of the `--chain` spec with the genesis config preset
+//! `<name>` of its runtime, and `--patch <file>` merges a JSON file into it, so that operators
+//! can change the validators, the sudo key or the EVM chain id of a chain without editing the
+//! chain specs of the node. The name, id, type, properties and boot nodes of the `--chain`
+//! spec are kept.
+
+use crate::chain_spec::{self, NetworkType};
+use clap::Parser;
+use sc_cli::{CliConfiguration, NodeKeyParams, SharedParams};
+use sc_service::config::NetworkConfiguration;
+use serde_json::Value;
+use sp_genesis_builder::PresetId;
+use std::path::PathBuf;
+
+/// Build a chain specification, optionally from a genesis config preset.
+#[derive(Debug, Clone, Parser)]
+pub struct BuildSpecCmd {
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub base: sc_cli::BuildSpecCmd,
+
+    /// Genesis config preset of the runtime of the chain, such as `development` or
+    /// `local_testnet`.
+    #[arg(long, value_name = "NAME")]
+    pub preset: Option<String>,
+
+    /// JSON file merged into the genesis config of the preset.
+    #[arg(long, value_name = "FILE", requires = "preset")]
+    pub patch: Option<PathBuf>,
+}
+
+impl CliConfiguration for BuildSpecCmd {
+    fn shared_params(&self) -> &SharedParams {
+        self.base.shared_params()
+    }
+
+    fn node_key_params(&self) -> Option<&NodeKeyParams> {
+        self.base.node_key_params()
+    }
+}
+
+impl BuildSpecCmd {
+    pub fn run(
+        &self,
+        spec: Box<dyn sc_service::ChainSpec>,
+        network_config: NetworkConfiguration,
+    ) -> sc_cli::Result<()> {
+        let spec = match &self.preset {
+            Some(preset) => self.preset_spec(spec, preset)?,
+            None => spec,
+        };
+        self.base.run(spec, network_config)
+    }
+
+    fn preset_spec(
+        &self,
+        spec: Box<dyn sc_service::ChainSpec>,
+        preset: &str,
+    ) -> sc_cli::Result<Box<dyn sc_service::ChainSpec>> {
+        let (wasm, get_preset): (_, fn(&PresetId) -> Option<Vec<u8>>) = if spec.is_mainnet() {
+            (
+                datahaven_mainnet_runtime::WASM_BINARY,
+                datahaven_mainnet_runtime::genesis_config_presets::get_preset,
+            )
+        } else if spec.is_testnet() {
+            (
+                datahaven_testnet_runtime::WASM_BINARY,
+                datahaven_testnet_runtime::genesis_config_presets::get_preset,
+            )
+        } else {
+            (
+                datahaven_stagenet_runtime::WASM_BINARY,
+                datahaven_stagenet_runtime::genesis_config_presets::get_preset,
+            )
+        };
+
+        let genesis = get_preset(&PresetId::from(preset))
+            .ok_or_else(|| format!("Unknown genesis config preset `{preset}`"))?;
+        let mut genesis: Value = serde_json::from_slice(&genesis)
+            .map_err(|e| format!("Invalid genesis config preset `{preset}`: {e}"))?;
+        if let Some(path) = &self.patch {
+            let patch = std::fs::read(path)
+                .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+            let patch: Value = serde_json::from_slice(&patch)
+                .map_err(|e| format!("Invalid genesis config patch {}: {e}", path.display()))?;
+            sc_chain_spec::json_merge(&mut genesis, patch);
+        }
+
+        let mut builder = chain_spec::ChainSpec::builder(
+            wasm.ok_or_else(|| "Development wasm not available".to_string())?,
+            None,
+        )
+        .with_name(spec.name())
+        .with_id(spec.id())
+        .with_chain_type(spec.chain_type())
+        .with_properties(spec.properties())
+        .with_boot_nodes(spec.boot_nodes().to_vec())
+        .with_genesis_config_patch(genesis);
+        if let Some(protocol_id) = spec.protocol_id() {
+            builder = builder.with_protocol_id(protocol_id);
+        }
+
+        Ok(Box::new(builder.build()))
+    }
+}
@@ -44,6 +44,30 @@ pub fn development_chain_spec() -> Result<ChainSpec, String> {
     .build())
 }
 
+/// Single-node development chain of the `dev-nobridge` runtime, which needs no relayer nor
+/// beacon client to exercise the rewards, slashes and native token transfer flows.
+#[cfg(feature = "dev-nobridge")]
+pub fn nobridge_development_chain_spec() -> Result<ChainSpec, String> {
+    let mut properties = sc_service::Properties::new();
+    properties.insert("tokenSymbol".into(), TOKEN_SYMBOL.into());
+    properties.insert("tokenDecimals".into(), TOKEN_DECIMALS.into());
+    properties.insert("ss58Format".into(), SS58_FORMAT.into());
+    properties.insert("isEthereum".into(), true.into());
+
+    Ok(ChainSpec::builder(
+        WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+        None,
+    )
+    .with_name("DataHaven Stagenet Dev Without Bridge")
+    .with_id("datahaven_stagenet_dev_nobridge")
+    .with_chain_type(ChainType::Development)
+    .with_genesis_config_preset_name(
+        datahaven_stagenet_runtime::genesis_config_presets::DEV_NOBRIDGE_RUNTIME_PRESET,
+    )
+    .with_properties(properties)
+    .build())
+}
+
 pub fn local_chain_spec() -> Result<ChainSpec, String> {
     let mut properties = sc_service::Properties::new();
     properties.insert("tokenSymbol".into(), TOKEN_SYMBOL.into());
@@ -85,6 +85,11 @@ pub struct Cli {
     /// Fisherman configurations
     #[command(flatten)]
     pub fisherman_config: FishermanConfigurations,
+
+    /// Whether the node runs with `--pruning bridge-archive`, which `sc-cli` cannot parse and
+    /// is extracted from the command line beforehand.
+    #[arg(skip)]
+    pub bridge_archive: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -95,7 +100,7 @@ pub enum Subcommand {
     Key(sc_cli::KeySubcommand),
 
     /// Build a chain specification.
-    BuildSpec(sc_cli::BuildSpecCmd),
+    BuildSpec(crate::build_spec::BuildSpecCmd),
 
     /// Validate blocks.
     CheckBlock(sc_cli::CheckBlockCmd),
@@ -120,8 +125,21 @@ pub enum Subcommand {
     #[command(subcommand)]
     Benchmark(frame_benchmarking_cli::BenchmarkCmd),
 
+    /// Run the benchmarks of all the pallets of a runtime and write its weight files.
+    #[cfg(feature = "runtime-benchmarks")]
+    Benchmarks(crate::benchmark_runner::BenchmarksCmd),
+
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Manage the Frontier database of a stopped node.
+    #[command(subcommand)]
+    FrontierDb(crate::frontier_db::FrontierDbCmd),
+
+    /// Try-runtime has migrated to a standalone CLI
+    /// (<https://github.com/paritytech/try-runtime-cli>), run through
+    /// `scripts/try-runtime.sh`.
+    TryRuntime,
 }
 
 #[derive(ValueEnum, Clone, Debug, Eq, PartialEq)]
@@ -19,8 +19,10 @@ use std::sync::Arc;
 #[cfg(feature = "runtime-benchmarks")]
 use crate::benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder};
 use crate::config;
+use crate::frontier_db::FrontierDbCmd;
 use crate::service::frontier_database_dir;
 use crate::{
+    bridge_archive,
     chain_spec::{self, NetworkType},
     cli::{Cli, ProviderType, StorageLayer, Subcommand},
     service,
@@ -140,6 +142,14 @@ impl SubstrateCli for Cli {
     fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
         Ok(match id {
             "dev" | "stagenet-dev" => Box::new(chain_spec::stagenet::development_chain_spec()?),
+            #[cfg(feature = "dev-nobridge")]
+            "dev-nobridge" => Box::new(chain_spec::stagenet::nobridge_development_chain_spec()?),
+            #[cfg(not(feature = "dev-nobridge"))]
+            "dev-nobridge" => {
+                return Err("The dev-nobridge chain requires a node built with the \
+                    `dev-nobridge` feature"
+                    .into())
+            }
             "" | "local" | "stagenet-local" => Box::new(chain_spec::stagenet::local_chain_spec()?),
             "testnet-dev" => Box::new(chain_spec::testnet::development_chain_spec()?),
             "testnet-local" => Box::new(chain_spec::testnet::local_chain_spec()?),
@@ -222,9 +232,15 @@ macro_rules! construct_benchmark_partials {
     };
 }
 
+const TRY_RUNTIME_NOTICE: &str = "The `try-runtime` subcommand has been migrated to a \
+    standalone CLI (https://github.com/paritytech/try-runtime-cli). Build the runtime with the \
+    `try-runtime` feature and run it against a live chain with `scripts/try-runtime.sh`.";
+
 /// Parse and run command line arguments
 pub fn run() -> sc_cli::Result<()> {
-    let cli = Cli::from_args();
+    let (args, bridge_archive) = bridge_archive::extract_pruning_mode(std::env::args_os());
+    let mut cli = Cli::from_iter(args);
+    cli.bridge_archive = bridge_archive;
 
     match &cli.subcommand {
         Some(Subcommand::Key(cmd)) => cmd.run(&cli),
@@ -346,10 +362,25 @@ pub fn run() -> sc_cli::Result<()> {
                 }
             })
         }
+        #[cfg(feature = "runtime-benchmarks")]
+        Some(Subcommand::Benchmarks(cmd)) => cmd.run(&cli),
         Some(Subcommand::ChainInfo(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run::<Block>(&config))
         }
+        Some(Subcommand::FrontierDb(FrontierDbCmd::Migrate(cmd))) => {
+            cmd.check()?;
+            construct_async_run!(|components, cli, cmd, config| {
+                let (_, _, _, _, _, _, frontier_backend, _, _) = components.other;
+                cmd.run::<_, sc_service::TFullBackend<Block>>(
+                    components.client,
+                    frontier_backend,
+                    &config,
+                    &cli.eth,
+                )
+            })
+        }
+        Some(Subcommand::TryRuntime) => Err(TRY_RUNTIME_NOTICE.into()),
         None => {
             let mut role_options = None;
             let mut indexer_options = None;
@@ -434,7 +465,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_mainnet_runtime::RuntimeApi,
                                 sc_network::NetworkWorker<_, _>,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -444,7 +480,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_testnet_runtime::RuntimeApi,
                                 sc_network::NetworkWorker<_, _>,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -454,7 +495,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_stagenet_runtime::RuntimeApi,
                                 sc_network::NetworkWorker<_, _>,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -467,7 +513,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_mainnet_runtime::RuntimeApi,
                                 sc_network::Litep2pNetworkBackend,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -477,7 +528,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_testnet_runtime::RuntimeApi,
                                 sc_network::Litep2pNetworkBackend,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -487,7 +543,12 @@ pub fn run() -> sc_cli::Result<()> {
                                 datahaven_stagenet_runtime::RuntimeApi,
                                 sc_network::Litep2pNetworkBackend,
                             >(
-                                config, cli.eth, role_options, indexer_options, sealing_mode
+                                config,
+                                cli.eth,
+                                role_options,
+                                indexer_options,
+                                sealing_mode,
+                                cli.bridge_archive,
                             )
                             .await
                         }
@@ -16,6 +16,7 @@
 
 // Substrate
 use crate::client::{FullBackend, FullClient};
+use crate::frontier_backfill::{run_frontier_backfill, BackfillStatus, FrontierBackfillParams};
 use datahaven_runtime_common::Block;
 pub use fc_db::Backend as FrontierBackend;
 use fc_rpc::EthConfig;
@@ -36,6 +37,7 @@ use std::{
     sync::{Arc, Mutex},
     time::Duration,
 };
+use substrate_prometheus_endpoint::Registry;
 
 /// Frontier DB backend type.
 pub struct DefaultEthConfig<C, BE>(std::marker::PhantomData<(C, BE)>);
@@ -55,11 +57,21 @@ where
 pub enum BackendType {
     /// Either RocksDb or ParityDb as per inherited from the global backend settings.
     #[default]
+    #[value(alias = "kv")]
     KeyValue,
     /// Sql database with custom log indexing.
     Sql,
 }
 
+/// Optional Ethereum RPC namespaces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum EthApi {
+    /// `debug_traceTransaction`, `debug_traceBlockByNumber` and `debug_traceBlockByHash`.
+    Debug,
+    /// `trace_filter`.
+    Trace,
+}
+
 /// The ethereum-compatibility configuration used to run a node.
 #[derive(Clone, Debug, clap::Parser)]
 pub struct EthConfiguration {
@@ -111,6 +123,21 @@ pub struct EthConfiguration {
     /// Default value is 200MB.
     #[arg(long, default_value = "209715200")]
     pub frontier_sql_backend_cache_size: u64,
+
+    /// Optional RPC namespaces to enable, comma separated.
+    ///
+    /// The tracing methods re-execute blocks with the runtimes built with the `evm-tracing`
+    /// feature, which must be provided with `--wasm-runtime-overrides`.
+    #[arg(long, value_enum, ignore_case = true, value_delimiter = ',')]
+    pub ethapi: Vec<EthApi>,
+
+    /// Maximum number of blocks traced at the same time.
+    #[arg(long, default_value = "10")]
+    pub ethapi_max_permits: u32,
+
+    /// Maximum number of traces returned by a `trace_filter` request.
+    #[arg(long, default_value = "500")]
+    pub ethapi_trace_max_count: u32,
 }
 
 pub struct FrontierPartialComponents {
@@ -163,6 +190,9 @@ where
             fc_mapping_sync::EthereumBlockNotification<B>,
         >,
     >,
+    /// Progress of the mappings backfill, run with the key-value backend only.
+    pub frontier_backfill: Option<BackfillStatus>,
+    pub prometheus_registry: Option<Registry>,
 }
 
 pub async fn spawn_frontier_tasks<B, RA, HF>(
@@ -183,6 +213,8 @@ pub async fn spawn_frontier_tasks<B, RA, HF>(
         storage_override,
         sync,
         pubsub_notification_sinks,
+        frontier_backfill,
+        prometheus_registry,
     } = params;
 
     let FrontierPartialComponents {
@@ -194,6 +226,22 @@ pub async fn spawn_frontier_tasks<B, RA, HF>(
     // Spawn main mapping sync worker background task.
     match &*frontier_backend {
         fc_db::Backend::KeyValue(b) => {
+            // Index the blocks below a warp sync target, which the worker is not notified of.
+            if let Some(status) = frontier_backfill {
+                task_manager.spawn_handle().spawn_blocking(
+                    "frontier-backfill",
+                    Some("frontier"),
+                    run_frontier_backfill(FrontierBackfillParams {
+                        client: client.clone(),
+                        frontier_backend: b.clone(),
+                        storage_override: storage_override.clone(),
+                        sync: sync.clone(),
+                        status,
+                        prometheus_registry,
+                    }),
+                );
+            }
+
             task_manager.spawn_essential_handle().spawn(
                 "frontier-mapping-sync-worker",
                 Some("frontier"),
@@ -0,0 +1,1003 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM tracing RPC methods: `debug_traceTransaction`, `debug_traceBlockByNumber`,
+//! `debug_traceBlockByHash` and `trace_filter`.
+//!
+//! Traces are recorded by the `EvmTracingApi` runtime API, which re-executes the traced
+//! block on top of the state of its parent. Only runtimes built with the `evm-tracing`
+//! feature record them, so the node serving these methods must run the tracing runtimes
+//! through `--wasm-runtime-overrides`, and keep the state of the blocks it traces.
+//!
+//! `debug_*` follows geth: the default struct logger and the `callTracer` are supported.
+//! `trace_filter` returns the call traces in the format of OpenEthereum.
+
+use datahaven_runtime_common::{Block, BlockNumber, Hash};
+use dhp_evm_tracing::{
+    CallFrame, CallType, EvmTracingApi, StructLog, Trace, TraceError, TraceType, MAX_STRUCT_LOGS,
+};
+use fc_rpc::internal_err;
+use fc_rpc_core::types::BlockNumberOrHash;
+use fc_storage::StorageOverride;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use sc_client_api::BlockBackend;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{ExtrinsicOf, Header as HeaderT, HeaderOf};
+use std::{borrow::Cow, fmt::Write, sync::Arc};
+use tokio::sync::Semaphore;
+
+/// Maximum number of blocks scanned by one `trace_filter` request.
+const MAX_TRACE_FILTER_BLOCKS: u32 = 1_000;
+
+/// Selector of `Error(string)`, the revert reason of `require` and `revert`.
+const REVERT_REASON_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Tracing methods served by the node, from the `--ethapi*` options.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EvmTracingConfig {
+    /// Serve the `debug_*` methods.
+    pub debug: bool,
+    /// Serve `trace_filter`.
+    pub trace: bool,
+    /// Maximum number of traces computed at the same time.
+    pub max_permits: u32,
+    /// Maximum number of traces returned by `trace_filter`.
+    pub trace_max_count: u32,
+}
+
+/// Options of the `debug_trace*` methods.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct TraceOptions {
+    tracer: Option<String>,
+    disable_stack: bool,
+    enable_memory: bool,
+}
+
+impl TraceOptions {
+    fn trace_type(&self) -> RpcResult<TraceType> {
+        match self.tracer.as_deref() {
+            None => Ok(TraceType::StructLogs {
+                disable_stack: self.disable_stack,
+                enable_memory: self.enable_memory,
+            }),
+            Some("callTracer") => Ok(TraceType::CallList),
+            Some(tracer) => Err(internal_err(format!(
+                "Tracer {tracer} is not supported, use the callTracer or the default struct logger"
+            ))),
+        }
+    }
+}
+
+/// Filter of `trace_filter`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFilter {
+    from_block: Option<BlockNumberOrHash>,
+    to_block: Option<BlockNumberOrHash>,
+    from_address: Option<Vec<H160>>,
+    to_address: Option<Vec<H160>>,
+    /// Number of matching traces to skip.
+    after: Option<u32>,
+    count: Option<u32>,
+}
+
+impl TraceFilter {
+    fn matches(&self, frame: &CallFrame) -> bool {
+        let matches = |addresses: &Option<Vec<H160>>, address| {
+            addresses
+                .as_ref()
+                .is_none_or(|addresses| addresses.is_empty() || addresses.contains(address))
+        };
+        matches(&self.from_address, &frame.from) && matches(&self.to_address, &frame.to)
+    }
+}
+
+/// Serves the tracing methods, shared by the RPC methods.
+pub(crate) struct EvmTracing<C> {
+    client: Arc<C>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    storage_override: Arc<dyn StorageOverride<Block>>,
+    /// Bounds the number of blocks re-executed at the same time.
+    permits: Arc<Semaphore>,
+    trace_max_count: u32,
+}
+
+impl<C> EvmTracing<C>
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: EvmTracingApi<Block>,
+{
+    pub(crate) fn new(
+        client: Arc<C>,
+        frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+        storage_override: Arc<dyn StorageOverride<Block>>,
+        config: &EvmTracingConfig,
+    ) -> Self {
+        Self {
+            client,
+            frontier_backend,
+            storage_override,
+            permits: Arc::new(Semaphore::new(config.max_permits as usize)),
+            trace_max_count: config.trace_max_count,
+        }
+    }
+
+    /// RPC module with the methods enabled by `config`.
+    pub(crate) fn into_rpc(self, config: &EvmTracingConfig) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        if config.debug {
+            module
+                .register_async_method("debug_traceTransaction", |params, tracing, _| async move {
+                    let mut params = params.sequence();
+                    let transaction_hash: H256 = params.next()?;
+                    let options: Option<TraceOptions> = params.optional_next()?;
+                    let trace_type = options.unwrap_or_default().trace_type()?;
+
+                    let block = tracing.transaction_block(transaction_hash).await?;
+                    tracing
+                        .run(move |tracing| {
+                            tracing.trace_transaction(block, transaction_hash, trace_type)
+                        })
+                        .await
+                })
+                .expect("the method is registered once; qed");
+            module
+                .register_async_method(
+                    "debug_traceBlockByNumber",
+                    |params, tracing, _| async move {
+                        let mut params = params.sequence();
+                        let block: BlockNumberOrHash = params.next()?;
+                        let options: Option<TraceOptions> = params.optional_next()?;
+                        let trace_type = options.unwrap_or_default().trace_type()?;
+
+                        let block = tracing.resolve_block(block).await?;
+                        tracing
+                            .run(move |tracing| tracing.trace_block(block, trace_type))
+                            .await
+                    },
+                )
+                .expect("the method is registered once; qed");
+            module
+                .register_async_method("debug_traceBlockByHash", |params, tracing, _| async move {
+                    let mut params = params.sequence();
+                    let hash: H256 = params.next()?;
+                    let options: Option<TraceOptions> = params.optional_next()?;
+                    let trace_type = options.unwrap_or_default().trace_type()?;
+
+                    let block = tracing
+                        .resolve_block(BlockNumberOrHash::Hash {
+                            hash,
+                            require_canonical: false,
+                        })
+                        .await?;
+                    tracing
+                        .run(move |tracing| tracing.trace_block(block, trace_type))
+                        .await
+                })
+                .expect("the method is registered once; qed");
+        }
+        if config.trace {
+            module
+                .register_async_method("trace_filter", |params, tracing, _| async move {
+                    let filter: TraceFilter = params.one()?;
+                    let from = tracing
+                        .resolve_block(filter.from_block.unwrap_or(BlockNumberOrHash::Latest))
+                        .await?;
+                    let to = tracing
+                        .resolve_block(filter.to_block.unwrap_or(BlockNumberOrHash::Latest))
+                        .await?;
+                    tracing
+                        .run(move |tracing| tracing.trace_filter(from, to, filter))
+                        .await
+                })
+                .expect("the method is registered once; qed");
+        }
+        module
+    }
+
+    /// Run `f`, which re-executes blocks, on a blocking thread once a permit is available.
+    async fn run<T: Send + 'static>(
+        self: Arc<Self>,
+        f: impl FnOnce(&Self) -> RpcResult<T> + Send + 'static,
+    ) -> RpcResult<T> {
+        let _permit = self.permits.acquire().await.map_err(internal_err)?;
+        tokio::task::spawn_blocking(move || f(&self))
+            .await
+            .map_err(internal_err)?
+    }
+
+    /// Canonical block of the Ethereum transaction `transaction_hash`.
+    async fn transaction_block(&self, transaction_hash: H256) -> RpcResult<Hash> {
+        self.frontier_backend
+            .transaction_metadata(&transaction_hash)
+            .await
+            .map_err(internal_err)?
+            .into_iter()
+            .map(|metadata| metadata.substrate_block_hash)
+            .find(|hash| self.is_canonical(*hash))
+            .ok_or_else(|| internal_err(format!("Transaction {transaction_hash:?} not found")))
+    }
+
+    /// Substrate hash of a block given by number, tag or Ethereum hash.
+    async fn resolve_block(&self, block: BlockNumberOrHash) -> RpcResult<Hash> {
+        let info = self.client.info();
+        let hash = match block {
+            BlockNumberOrHash::Num(number) => BlockNumber::try_from(number)
+                .ok()
+                .and_then(|number| self.client.hash(number).ok().flatten()),
+            BlockNumberOrHash::Latest | BlockNumberOrHash::Pending => Some(info.best_hash),
+            BlockNumberOrHash::Safe | BlockNumberOrHash::Finalized => Some(info.finalized_hash),
+            BlockNumberOrHash::Earliest => Some(info.genesis_hash),
+            BlockNumberOrHash::Hash { hash, .. } => self
+                .frontier_backend
+                .block_hash(&hash)
+                .await
+                .map_err(internal_err)?
+                .and_then(|hashes| hashes.into_iter().find(|hash| self.is_canonical(*hash))),
+        };
+        hash.ok_or_else(|| internal_err(format!("Block {block:?} not found")))
+    }
+
+    fn is_canonical(&self, hash: Hash) -> bool {
+        matches!(
+            self.client.number(hash),
+            Ok(Some(number)) if self.client.hash(number).ok().flatten() == Some(hash)
+        )
+    }
+
+    /// Header and extrinsics of `hash`, to re-execute it.
+    fn block(&self, hash: Hash) -> RpcResult<(HeaderOf<Block>, Vec<ExtrinsicOf<Block>>)> {
+        let header = self
+            .client
+            .header(hash)
+            .map_err(internal_err)?
+            .ok_or_else(|| internal_err(format!("Header of block {hash:?} not found")))?;
+        let extrinsics = self
+            .client
+            .block_body(hash)
+            .map_err(internal_err)?
+            .ok_or_else(|| internal_err(format!("Body of block {hash:?} not found")))?;
+        Ok((header, extrinsics))
+    }
+
+    fn trace_transaction(
+        &self,
+        hash: Hash,
+        transaction_hash: H256,
+        trace_type: TraceType,
+    ) -> RpcResult<Value> {
+        let (header, extrinsics) = self.block(hash)?;
+        let trace = self
+            .client
+            .runtime_api()
+            .trace_transaction(
+                *header.parent_hash(),
+                &header,
+                extrinsics,
+                transaction_hash,
+                trace_type,
+            )
+            .map_err(internal_err)?
+            .map_err(trace_error)?;
+        Ok(geth_trace(trace))
+    }
+
+    fn trace_block(&self, hash: Hash, trace_type: TraceType) -> RpcResult<Value> {
+        let traces = self.block_traces(hash, trace_type)?;
+        Ok(Value::Array(
+            traces
+                .into_iter()
+                .map(|(transaction_hash, trace)| {
+                    json!({ "txHash": transaction_hash, "result": geth_trace(trace) })
+                })
+                .collect(),
+        ))
+    }
+
+    fn block_traces(&self, hash: Hash, trace_type: TraceType) -> RpcResult<Vec<(H256, Trace)>> {
+        let (header, extrinsics) = self.block(hash)?;
+        // The genesis block has neither a parent to execute it on nor transactions.
+        if header.number() == &0 {
+            return Ok(Vec::new());
+        }
+        self.client
+            .runtime_api()
+            .trace_block(*header.parent_hash(), &header, extrinsics, trace_type)
+            .map_err(internal_err)?
+            .map_err(trace_error)
+    }
+
+    fn trace_filter(&self, from: Hash, to: Hash, filter: TraceFilter) -> RpcResult<Value> {
+        let number = |hash| {
+            self.client
+                .number(hash)
+                .map_err(internal_err)?
+                .ok_or_else(|| internal_err(format!("Block {hash:?} not found")))
+        };
+        let (from, to) = (number(from)?, number(to)?);
+        if from > to {
+            return Err(internal_err("fromBlock is after toBlock"));
+        }
+        if to - from >= MAX_TRACE_FILTER_BLOCKS {
+            return Err(internal_err(format!(
+                "The range of blocks exceeds the maximum of {MAX_TRACE_FILTER_BLOCKS}"
+            )));
+        }
+        let count = filter.count.unwrap_or(self.trace_max_count);
+        if count > self.trace_max_count {
+            return Err(internal_err(format!(
+                "count exceeds the maximum of {}",
+                self.trace_max_count
+            )));
+        }
+
+        let mut skipped = 0;
+        let mut traces = Vec::new();
+        for number in from..=to {
+            let Some(hash) = self.client.hash(number).map_err(internal_err)? else {
+                break;
+            };
+            let block_hash = self
+                .storage_override
+                .current_block(hash)
+                .map(|block| block.header.hash());
+            for (position, (transaction_hash, trace)) in self
+                .block_traces(hash, TraceType::CallList)?
+                .into_iter()
+                .enumerate()
+            {
+                let Trace::CallList(frames) = trace else {
+                    continue;
+                };
+                for frame in frames.iter().filter(|frame| filter.matches(frame)) {
+                    if skipped < filter.after.unwrap_or_default() {
+                        skipped += 1;
+                        continue;
+                    }
+                    if traces.len() >= count as usize {
+                        return Ok(Value::Array(traces));
+                    }
+                    traces.push(parity_trace(
+                        frame,
+                        block_hash,
+                        number,
+                        transaction_hash,
+                        position,
+                    ));
+                }
+            }
+        }
+        Ok(Value::Array(traces))
+    }
+}
+
+fn trace_error(error: TraceError) -> jsonrpsee::types::ErrorObjectOwned {
+    internal_err(match error {
+        TraceError::TracingDisabled => {
+            "The runtime of this block does not record EVM traces, the node must run the \
+             tracing runtimes with --wasm-runtime-overrides"
+                .to_string()
+        }
+        TraceError::TransactionNotFound => "Transaction not found in its block".to_string(),
+        TraceError::TooManySteps => format!(
+            "The transaction executes more than {MAX_STRUCT_LOGS} opcodes, use the callTracer"
+        ),
+    })
+}
+
+/// Trace in the format of geth.
+fn geth_trace(trace: Trace) -> Value {
+    match trace {
+        Trace::CallList(frames) => {
+            call_tracer_frame(&mut frames.into_iter()).unwrap_or(Value::Null)
+        }
+        Trace::StructLogs {
+            gas,
+            failed,
+            return_value,
+            logs,
+        } => json!({
+            "gas": gas,
+            "failed": failed,
+            "returnValue": hex(&return_value, ""),
+            "structLogs": logs.into_iter().map(struct_log).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+/// The next frame of `frames`, with its subcalls, as returned by the `callTracer`.
+fn call_tracer_frame(frames: &mut impl Iterator<Item = CallFrame>) -> Option<Value> {
+    let frame = frames.next()?;
+    let calls = (0..frame.subtraces)
+        .map_while(|_| call_tracer_frame(frames))
+        .collect::<Vec<_>>();
+
+    let mut object = Map::new();
+    object.insert("type".into(), call_type_name(frame.call_type).into());
+    object.insert("from".into(), json!(frame.from));
+    object.insert("to".into(), json!(frame.to));
+    if let Some(value) = frame.value {
+        object.insert("value".into(), quantity(value).into());
+    }
+    object.insert("gas".into(), quantity(frame.gas).into());
+    object.insert("gasUsed".into(), quantity(frame.gas_used).into());
+    object.insert("input".into(), hex(&frame.input, "0x").into());
+    if !frame.output.is_empty() {
+        object.insert("output".into(), hex(&frame.output, "0x").into());
+    }
+    if let Some(error) = frame.error {
+        if let Some(reason) = revert_reason(&frame.output) {
+            object.insert("revertReason".into(), reason.into());
+        }
+        object.insert("error".into(), error.into());
+    }
+    if !calls.is_empty() {
+        object.insert("calls".into(), calls.into());
+    }
+    Some(Value::Object(object))
+}
+
+fn struct_log(log: StructLog) -> Value {
+    let mut object = Map::new();
+    object.insert("pc".into(), log.pc.into());
+    object.insert("op".into(), opcode_name(log.op).into());
+    object.insert("gas".into(), log.gas.into());
+    object.insert("gasCost".into(), log.gas_cost.into());
+    object.insert("depth".into(), log.depth.into());
+    if let Some(stack) = log.stack {
+        let stack = stack
+            .iter()
+            .map(|word| quantity(U256::from_big_endian(word.as_bytes())))
+            .collect::<Vec<_>>();
+        object.insert("stack".into(), stack.into());
+    }
+    if let Some(memory) = log.memory {
+        let memory = memory
+            .chunks(32)
+            .map(|word| hex(word, ""))
+            .collect::<Vec<_>>();
+        object.insert("memory".into(), memory.into());
+    }
+    Value::Object(object)
+}
+
+/// Call trace in the format of OpenEthereum.
+fn parity_trace(
+    frame: &CallFrame,
+    block_hash: Option<H256>,
+    block_number: BlockNumber,
+    transaction_hash: H256,
+    transaction_position: usize,
+) -> Value {
+    let value = quantity(frame.value.unwrap_or_default());
+    let failed = frame.error.is_some();
+    let (trace_type, action, result) = match frame.call_type {
+        CallType::Create | CallType::Create2 => (
+            "create",
+            json!({
+                "from": frame.from,
+                "gas": quantity(frame.gas),
+                "init": hex(&frame.input, "0x"),
+                "value": value,
+            }),
+            json!({
+                "address": frame.to,
+                "code": hex(&frame.output, "0x"),
+                "gasUsed": quantity(frame.gas_used),
+            }),
+        ),
+        CallType::SelfDestruct => (
+            "suicide",
+            json!({
+                "address": frame.from,
+                "balance": value,
+                "refundAddress": frame.to,
+            }),
+            Value::Null,
+        ),
+        call_type => (
+            "call",
+            json!({
+                "callType": call_type_name(call_type).to_lowercase(),
+                "from": frame.from,
+                "gas": quantity(frame.gas),
+                "input": hex(&frame.input, "0x"),
+                "to": frame.to,
+                "value": value,
+            }),
+            json!({
+                "gasUsed": quantity(frame.gas_used),
+                "output": hex(&frame.output, "0x"),
+            }),
+        ),
+    };
+
+    let mut object = Map::new();
+    object.insert("action".into(), action);
+    object.insert("blockHash".into(), json!(block_hash));
+    object.insert("blockNumber".into(), block_number.into());
+    if let Some(error) = &frame.error {
+        object.insert("error".into(), error.clone().into());
+    }
+    object.insert("result".into(), if failed { Value::Null } else { result });
+    object.insert("subtraces".into(), frame.subtraces.into());
+    object.insert("traceAddress".into(), json!(frame.trace_address));
+    object.insert("transactionHash".into(), json!(transaction_hash));
+    object.insert("transactionPosition".into(), transaction_position.into());
+    object.insert("type".into(), trace_type.into());
+    Value::Object(object)
+}
+
+fn call_type_name(call_type: CallType) -> &'static str {
+    match call_type {
+        CallType::Call => "CALL",
+        CallType::CallCode => "CALLCODE",
+        CallType::DelegateCall => "DELEGATECALL",
+        CallType::StaticCall => "STATICCALL",
+        CallType::Create => "CREATE",
+        CallType::Create2 => "CREATE2",
+        CallType::SelfDestruct => "SELFDESTRUCT",
+    }
+}
+
+/// Message of an `Error(string)` revert.
+fn revert_reason(output: &[u8]) -> Option<String> {
+    let data = output.strip_prefix(&REVERT_REASON_SELECTOR[..])?;
+    let length = U256::from_big_endian(data.get(32..64)?);
+    let length = usize::try_from(length).ok()?;
+    let message = data.get(64..64usize.checked_add(length)?)?;
+    String::from_utf8(message.to_vec()).ok()
+}
+
+fn quantity(value: impl Into<U256>) -> String {
+    format!("{:#x}", value.into())
+}
+
+fn hex(bytes: &[u8], prefix: &str) -> String {
+    let mut hex = String::with_capacity(prefix.len() + bytes.len() * 2);
+    hex.push_str(prefix);
+    for byte in bytes {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+/// Name of an opcode, as in geth.
+fn opcode_name(opcode: u8) -> Cow<'static, str> {
+    let name = match opcode {
+        0x00 => "STOP",
+        0x01 => "ADD",
+        0x02 => "MUL",
+        0x03 => "SUB",
+        0x04 => "DIV",
+        0x05 => "SDIV",
+        0x06 => "MOD",
+        0x07 => "SMOD",
+        0x08 => "ADDMOD",
+        0x09 => "MULMOD",
+        0x0a => "EXP",
+        0x0b => "SIGNEXTEND",
+        0x10 => "LT",
+        0x11 => "GT",
+        0x12 => "SLT",
+        0x13 => "SGT",
+        0x14 => "EQ",
+        0x15 => "ISZERO",
+        0x16 => "AND",
+        0x17 => "OR",
+        0x18 => "XOR",
+        0x19 => "NOT",
+        0x1a => "BYTE",
+        0x1b => "SHL",
+        0x1c => "SHR",
+        0x1d => "SAR",
+        0x20 => "KECCAK256",
+        0x30 => "ADDRESS",
+        0x31 => "BALANCE",
+        0x32 => "ORIGIN",
+        0x33 => "CALLER",
+        0x34 => "CALLVALUE",
+        0x35 => "CALLDATALOAD",
+        0x36 => "CALLDATASIZE",
+        0x37 => "CALLDATACOPY",
+        0x38 => "CODESIZE",
+        0x39 => "CODECOPY",
+        0x3a => "GASPRICE",
+        0x3b => "EXTCODESIZE",
+        0x3c => "EXTCODECOPY",
+        0x3d => "RETURNDATASIZE",
+        0x3e => "RETURNDATACOPY",
+        0x3f => "EXTCODEHASH",
+        0x40 => "BLOCKHASH",
+        0x41 => "COINBASE",
+        0x42 => "TIMESTAMP",
+        0x43 => "NUMBER",
+        0x44 => "DIFFICULTY",
+        0x45 => "GASLIMIT",
+        0x46 => "CHAINID",
+        0x47 => "SELFBALANCE",
+        0x48 => "BASEFEE",
+        0x49 => "BLOBHASH",
+        0x4a => "BLOBBASEFEE",
+        0x50 => "POP",
+        0x51 => "MLOAD",
+        0x52 => "MSTORE",
+        0x53 => "MSTORE8",
+        0x54 => "SLOAD",
+        0x55 => "SSTORE",
+        0x56 => "JUMP",
+        0x57 => "JUMPI",
+        0x58 => "PC",
+        0x59 => "MSIZE",
+        0x5a => "GAS",
+        0x5b => "JUMPDEST",
+        0x5c => "TLOAD",
+        0x5d => "TSTORE",
+        0x5e => "MCOPY",
+        0x5f => "PUSH0",
+        0x60..=0x7f => return format!("PUSH{}", opcode - 0x5f).into(),
+        0x80..=0x8f => return format!("DUP{}", opcode - 0x7f).into(),
+        0x90..=0x9f => return format!("SWAP{}", opcode - 0x8f).into(),
+        0xa0..=0xa4 => return format!("LOG{}", opcode - 0xa0).into(),
+        0xf0 => "CREATE",
+        0xf1 => "CALL",
+        0xf2 => "CALLCODE",
+        0xf3 => "RETURN",
+        0xf4 => "DELEGATECALL",
+        0xf5 => "CREATE2",
+        0xfa => "STATICCALL",
+        0xfd => "REVERT",
+        0xfe => "INVALID",
+        0xff => "SELFDESTRUCT",
+        _ => return format!("opcode {opcode:#x} not defined").into(),
+    };
+    name.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALLER: H160 = H160::repeat_byte(0x11);
+    const CONTRACT: H160 = H160::repeat_byte(0x22);
+    const OTHER: H160 = H160::repeat_byte(0x33);
+
+    fn frame(call_type: CallType, from: H160, to: H160, trace_address: Vec<u32>) -> CallFrame {
+        CallFrame {
+            call_type,
+            from,
+            to,
+            value: Some(U256::zero()),
+            gas: 100_000,
+            gas_used: 21_000,
+            input: vec![0xab, 0xcd],
+            output: Vec::new(),
+            error: None,
+            trace_address,
+            subtraces: 0,
+        }
+    }
+
+    /// Revert data of `revert(message)`.
+    fn revert_output(message: &str) -> Vec<u8> {
+        let mut output = REVERT_REASON_SELECTOR.to_vec();
+        output.extend_from_slice(H256::from_low_u64_be(32).as_bytes());
+        output.extend_from_slice(H256::from_low_u64_be(message.len() as u64).as_bytes());
+        let mut word = [0u8; 32];
+        word[..message.len()].copy_from_slice(message.as_bytes());
+        output.extend_from_slice(&word);
+        output
+    }
+
+    #[test]
+    fn call_tracer_nests_subcalls_under_their_caller() {
+        let root = CallFrame {
+            subtraces: 2,
+            output: vec![0x01],
+            ..frame(CallType::Call, CALLER, CONTRACT, vec![])
+        };
+        let first = CallFrame {
+            subtraces: 1,
+            ..frame(CallType::DelegateCall, CONTRACT, OTHER, vec![0])
+        };
+        let nested = CallFrame {
+            value: None,
+            ..frame(CallType::StaticCall, OTHER, CALLER, vec![0, 0])
+        };
+        let second = frame(CallType::Create2, CONTRACT, OTHER, vec![1]);
+
+        let trace = geth_trace(Trace::CallList(vec![root, first, nested, second]));
+
+        assert_eq!(
+            trace,
+            json!({
+                "type": "CALL",
+                "from": CALLER,
+                "to": CONTRACT,
+                "value": "0x0",
+                "gas": "0x186a0",
+                "gasUsed": "0x5208",
+                "input": "0xabcd",
+                "output": "0x01",
+                "calls": [
+                    {
+                        "type": "DELEGATECALL",
+                        "from": CONTRACT,
+                        "to": OTHER,
+                        "value": "0x0",
+                        "gas": "0x186a0",
+                        "gasUsed": "0x5208",
+                        "input": "0xabcd",
+                        "calls": [{
+                            "type": "STATICCALL",
+                            "from": OTHER,
+                            "to": CALLER,
+                            "gas": "0x186a0",
+                            "gasUsed": "0x5208",
+                            "input": "0xabcd",
+                        }],
+                    },
+                    {
+                        "type": "CREATE2",
+                        "from": CONTRACT,
+                        "to": OTHER,
+                        "value": "0x0",
+                        "gas": "0x186a0",
+                        "gasUsed": "0x5208",
+                        "input": "0xabcd",
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn call_tracer_reports_errors_and_revert_reasons() {
+        let root = CallFrame {
+            output: revert_output("not allowed"),
+            error: Some("execution reverted".into()),
+            ..frame(CallType::Call, CALLER, CONTRACT, vec![])
+        };
+
+        let trace = geth_trace(Trace::CallList(vec![root]));
+
+        assert_eq!(trace["error"], "execution reverted");
+        assert_eq!(trace["revertReason"], "not allowed");
+        assert!(trace.get("calls").is_none());
+    }
+
+    #[test]
+    fn call_tracer_of_a_transaction_without_frames_is_null() {
+        assert_eq!(geth_trace(Trace::CallList(Vec::new())), Value::Null);
+    }
+
+    #[test]
+    fn struct_logger_formats_steps_like_geth() {
+        let mut word = H256::zero();
+        word.0[31] = 0x80;
+        let trace = geth_trace(Trace::StructLogs {
+            gas: 21_064,
+            failed: false,
+            return_value: vec![0x12, 0x34],
+            logs: vec![
+                StructLog {
+                    pc: 0,
+                    op: 0x60,
+                    gas: 79_000,
+                    gas_cost: 3,
+                    depth: 1,
+                    stack: Some(vec![]),
+                    memory: None,
+                },
+                StructLog {
+                    pc: 2,
+                    op: 0x52,
+                    gas: 78_997,
+                    gas_cost: 12,
+                    depth: 1,
+                    stack: Some(vec![word, H256::zero()]),
+                    memory: Some(vec![0xff; 64]),
+                },
+            ],
+        });
+
+        assert_eq!(
+            trace,
+            json!({
+                "gas": 21_064,
+                "failed": false,
+                "returnValue": "1234",
+                "structLogs": [
+                    {
+                        "pc": 0,
+                        "op": "PUSH1",
+                        "gas": 79_000,
+                        "gasCost": 3,
+                        "depth": 1,
+                        "stack": [],
+                    },
+                    {
+                        "pc": 2,
+                        "op": "MSTORE",
+                        "gas": 78_997,
+                        "gasCost": 12,
+                        "depth": 1,
+                        "stack": ["0x80", "0x0"],
+                        "memory": ["ff".repeat(32), "ff".repeat(32)],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn parity_trace_of_a_call() {
+        let call = CallFrame {
+            value: Some(U256::from(10)),
+            output: vec![0x01],
+            subtraces: 1,
+            ..frame(CallType::StaticCall, CALLER, CONTRACT, vec![2])
+        };
+        let block_hash = H256::repeat_byte(0xaa);
+        let transaction_hash = H256::repeat_byte(0xbb);
+
+        assert_eq!(
+            parity_trace(&call, Some(block_hash), 7, transaction_hash, 3),
+            json!({
+                "action": {
+                    "callType": "staticcall",
+                    "from": CALLER,
+                    "gas": "0x186a0",
+                    "input": "0xabcd",
+                    "to": CONTRACT,
+                    "value": "0xa",
+                },
+                "blockHash": block_hash,
+                "blockNumber": 7,
+                "result": {
+                    "gasUsed": "0x5208",
+                    "output": "0x01",
+                },
+                "subtraces": 1,
+                "traceAddress": [2],
+                "transactionHash": transaction_hash,
+                "transactionPosition": 3,
+                "type": "call",
+            })
+        );
+    }
+
+    #[test]
+    fn parity_trace_of_a_failed_creation_has_no_result() {
+        let create = CallFrame {
+            error: Some("out of gas".into()),
+            ..frame(CallType::Create, CALLER, CONTRACT, vec![])
+        };
+
+        let trace = parity_trace(&create, None, 7, H256::zero(), 0);
+
+        assert_eq!(trace["type"], "create");
+        assert_eq!(
+            trace["action"],
+            json!({
+                "from": CALLER,
+                "gas": "0x186a0",
+                "init": "0xabcd",
+                "value": "0x0",
+            })
+        );
+        assert_eq!(trace["result"], Value::Null);
+        assert_eq!(trace["error"], "out of gas");
+        assert_eq!(trace["blockHash"], Value::Null);
+    }
+
+    #[test]
+    fn parity_trace_of_a_self_destruct() {
+        let destruct = CallFrame {
+            value: Some(U256::from(5)),
+            ..frame(CallType::SelfDestruct, CONTRACT, CALLER, vec![0])
+        };
+
+        let trace = parity_trace(&destruct, None, 7, H256::zero(), 0);
+
+        assert_eq!(trace["type"], "suicide");
+        assert_eq!(
+            trace["action"],
+            json!({
+                "address": CONTRACT,
+                "balance": "0x5",
+                "refundAddress": CALLER,
+            })
+        );
+        assert_eq!(trace["result"], Value::Null);
+    }
+
+    #[test]
+    fn trace_filter_matches_the_listed_addresses() {
+        let filter = |from_address: Option<Vec<H160>>, to_address: Option<Vec<H160>>| TraceFilter {
+            from_block: None,
+            to_block: None,
+            from_address,
+            to_address,
+            after: None,
+            count: None,
+        };
+        let call = frame(CallType::Call, CALLER, CONTRACT, vec![]);
+
+        assert!(filter(None, None).matches(&call));
+        assert!(filter(Some(vec![]), Some(vec![CONTRACT])).matches(&call));
+        assert!(filter(Some(vec![OTHER, CALLER]), None).matches(&call));
+        assert!(!filter(Some(vec![CONTRACT]), None).matches(&call));
+        assert!(!filter(Some(vec![CALLER]), Some(vec![OTHER])).matches(&call));
+    }
+
+    #[test]
+    fn trace_options_select_the_tracer() {
+        let options = |value| serde_json::from_value::<TraceOptions>(value).unwrap();
+
+        assert_eq!(
+            options(json!({ "disableStack": true }))
+                .trace_type()
+                .unwrap(),
+            TraceType::StructLogs {
+                disable_stack: true,
+                enable_memory: false,
+            }
+        );
+        assert_eq!(
+            options(json!({ "tracer": "callTracer" }))
+                .trace_type()
+                .unwrap(),
+            TraceType::CallList
+        );
+        assert!(options(json!({ "tracer": "prestateTracer" }))
+            .trace_type()
+            .is_err());
+    }
+
+    #[test]
+    fn revert_reason_is_only_decoded_from_error_strings() {
+        assert_eq!(
+            revert_reason(&revert_output("nope")).as_deref(),
+            Some("nope")
+        );
+        assert_eq!(revert_reason(&[]), None);
+        // A custom error, or a truncated `Error(string)`.
+        assert_eq!(revert_reason(&[0xde, 0xad, 0xbe, 0xef]), None);
+        assert_eq!(revert_reason(&revert_output("nope")[..68]), None);
+    }
+
+    #[test]
+    fn opcode_names_follow_geth() {
+        assert_eq!(opcode_name(0x00), "STOP");
+        assert_eq!(opcode_name(0x5f), "PUSH0");
+        assert_eq!(opcode_name(0x7f), "PUSH32");
+        assert_eq!(opcode_name(0x80), "DUP1");
+        assert_eq!(opcode_name(0x9f), "SWAP16");
+        assert_eq!(opcode_name(0xa4), "LOG4");
+        assert_eq!(opcode_name(0x0c), "opcode 0xc not defined");
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Background backfill of the Frontier mappings of the blocks below a warp sync target.
+//!
+//! The mapping sync worker indexes the imported blocks and the ones it reaches walking back
+//! from them. After a warp sync, the blocks below the warp target are downloaded by the gap
+//! sync, without import notifications, so the eth RPC cannot resolve their Ethereum block
+//! and transaction hashes until the Frontier database is rebuilt from scratch.
+//!
+//! `pallet-ethereum` records the Ethereum block hash and transaction hashes of every block
+//! in its header digest, so the mappings only need the headers. The backfill walks down
+//! from the finalized block as the gap sync brings the headers in, indexes the blocks that
+//! are missing, and reports its progress through metrics and the `frontier_backfillStatus`
+//! RPC method. Receipts are still read from the state of their block, which a warp-synced
+//! node only has from the warp target on.
+//!
+//! The progress is saved to the auxiliary database, so a restarted node resumes the walk
+//! where it stopped. A block that still cannot be indexed after [`MAX_ATTEMPTS`] is skipped
+//! and reported, instead of stalling the walk.
+
+use codec::{Decode, Encode};
+use datahaven_runtime_common::BlockNumber;
+use fc_storage::StorageOverride;
+use futures_timer::Delay;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use log::{debug, info, warn};
+use sc_client_api::AuxStore;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto, Zero};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use substrate_prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+const LOG_TARGET: &str = "frontier-backfill";
+
+/// Time to wait before walking down again when the gap sync has not downloaded the next
+/// header yet.
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Number of indexed blocks between two progress logs.
+const LOG_INTERVAL: u64 = 10_000;
+
+/// Number of blocks walked between two saves of the cursor.
+const CURSOR_INTERVAL: BlockNumber = 1_000;
+
+/// Number of attempts to index a block before it is skipped.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Maximum number of skipped blocks listed in [`BackfillProgress::skipped`].
+const MAX_REPORTED_SKIPPED: usize = 100;
+
+/// Auxiliary database key of the [`Cursor`].
+const CURSOR_KEY: &[u8] = b"frontier-backfill-cursor";
+
+/// Progress of the backfill, as returned by `frontier_backfillStatus`.
+#[derive(Clone, Debug, Default, Serialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct BackfillProgress {
+    /// Whether the mappings of all the blocks from genesis to `start` are indexed, except
+    /// the skipped ones.
+    pub ready: bool,
+    /// Finalized block the backfill started from.
+    pub start: Option<BlockNumber>,
+    /// Lowest block whose mappings are known to be indexed.
+    pub lowest_indexed: Option<BlockNumber>,
+    /// Number of blocks indexed by the backfill.
+    pub indexed_blocks: u64,
+    /// Number of blocks skipped because they could not be indexed.
+    pub skipped_blocks: u64,
+    /// The first [`MAX_REPORTED_SKIPPED`] skipped blocks.
+    pub skipped: Vec<BlockNumber>,
+}
+
+/// Progress of the backfill saved to the auxiliary database.
+#[derive(Encode, Decode)]
+struct Cursor<Hash> {
+    progress: BackfillProgress,
+    /// Next block to check, `None` once genesis is reached.
+    next: Option<Hash>,
+}
+
+fn load_cursor<C: AuxStore, Hash: Decode>(client: &C) -> Option<Cursor<Hash>> {
+    let data = client
+        .get_aux(CURSOR_KEY)
+        .inspect_err(|err| warn!(target: LOG_TARGET, "Failed to read the cursor: {err}"))
+        .ok()
+        .flatten()?;
+    Cursor::decode(&mut &data[..])
+        .inspect_err(|err| warn!(target: LOG_TARGET, "Invalid cursor, starting over: {err}"))
+        .ok()
+}
+
+fn save_cursor<C: AuxStore, Hash: Encode>(
+    client: &C,
+    progress: BackfillProgress,
+    next: Option<Hash>,
+) {
+    let cursor = Cursor { progress, next }.encode();
+    if let Err(err) = client.insert_aux(&[(CURSOR_KEY, cursor.as_slice())], &[]) {
+        warn!(target: LOG_TARGET, "Failed to save the cursor: {err}");
+    }
+}
+
+/// Progress of the backfill, shared with the RPC.
+#[derive(Clone, Default)]
+pub(crate) struct BackfillStatus(Arc<RwLock<BackfillProgress>>);
+
+impl BackfillStatus {
+    pub(crate) fn progress(&self) -> BackfillProgress {
+        self.0.read().expect("lock is not poisoned; qed").clone()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BackfillProgress)) {
+        f(&mut self.0.write().expect("lock is not poisoned; qed"))
+    }
+
+    /// RPC module with the `frontier_backfillStatus` method.
+    pub(crate) fn into_rpc(self) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        module
+            .register_method(
+                "frontier_backfillStatus",
+                |_, status, _| -> RpcResult<BackfillProgress> { Ok(status.progress()) },
+            )
+            .expect("the method is registered once; qed");
+        module
+    }
+}
+
+struct Metrics {
+    indexed_blocks: Counter<U64>,
+    skipped_blocks: Counter<U64>,
+    lowest_indexed: Gauge<U64>,
+}
+
+impl Metrics {
+    fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            indexed_blocks: register(
+                Counter::new(
+                    "datahaven_frontier_backfill_indexed_blocks_total",
+                    "Number of blocks whose Frontier mappings were indexed by the backfill",
+                )?,
+                registry,
+            )?,
+            skipped_blocks: register(
+                Counter::new(
+                    "datahaven_frontier_backfill_skipped_blocks_total",
+                    "Number of blocks skipped by the backfill because they could not be indexed",
+                )?,
+                registry,
+            )?,
+            lowest_indexed: register(
+                Gauge::new(
+                    "datahaven_frontier_backfill_lowest_indexed_block",
+                    "Lowest block whose Frontier mappings are known to be indexed",
+                )?,
+                registry,
+            )?,
+        })
+    }
+}
+
+/// Parameters of [`run_frontier_backfill`].
+pub(crate) struct FrontierBackfillParams<B: BlockT, C, S> {
+    /// Client providing the headers.
+    pub client: Arc<C>,
+    /// Key-value Frontier database the mappings are written to.
+    pub frontier_backend: Arc<fc_db::kv::Backend<B, C>>,
+    /// Ethereum data access, used for the blocks without a Frontier digest.
+    pub storage_override: Arc<dyn StorageOverride<B>>,
+    /// Sync oracle; the gap sync only starts once the node is synced.
+    pub sync: S,
+    /// Progress shared with the RPC.
+    pub status: BackfillStatus,
+    /// Registry for the backfill metrics.
+    pub prometheus_registry: Option<Registry>,
+}
+
+/// Index the Frontier mappings of the blocks from the finalized block down to genesis,
+/// resuming from the saved cursor if any.
+///
+/// Returns once genesis is reached, every block above being indexed by the mapping sync
+/// worker.
+pub(crate) async fn run_frontier_backfill<B, C, S>(params: FrontierBackfillParams<B, C, S>)
+where
+    B: BlockT,
+    C: HeaderBackend<B> + ProvideRuntimeApi<B> + AuxStore,
+    C::Api: fp_rpc::EthereumRuntimeRPCApi<B>,
+    S: SyncOracle,
+{
+    let FrontierBackfillParams {
+        client,
+        frontier_backend,
+        storage_override,
+        sync,
+        status,
+        prometheus_registry,
+    } = params;
+
+    let metrics =
+        prometheus_registry
+            .as_ref()
+            .and_then(|registry| match Metrics::register(registry) {
+                Ok(metrics) => Some(metrics),
+                Err(err) => {
+                    warn!(target: LOG_TARGET, "Failed to register metrics: {err:?}");
+                    None
+                }
+            });
+
+    // Next block to check, below the lowest one known to be indexed.
+    let mut next: Option<B::Hash> = None;
+    if let Some(cursor) = load_cursor::<_, B::Hash>(&*client) {
+        let progress = cursor.progress;
+        if let Some(metrics) = &metrics {
+            metrics.indexed_blocks.inc_by(progress.indexed_blocks);
+            metrics.skipped_blocks.inc_by(progress.skipped_blocks);
+            if let Some(lowest) = progress.lowest_indexed {
+                metrics.lowest_indexed.set(lowest.into());
+            }
+        }
+        let ready = progress.ready;
+        let resumed_from = progress
+            .lowest_indexed
+            .or(progress.start)
+            .unwrap_or_default();
+        status.update(|current| *current = progress);
+        if ready {
+            debug!(target: LOG_TARGET, "Frontier mappings already backfilled");
+            return;
+        }
+        info!(
+            target: LOG_TARGET,
+            "Resuming the backfill of the Frontier mappings from #{resumed_from}"
+        );
+        next = cursor.next;
+    }
+    // Failed attempts to index the next block.
+    let mut attempts = 0;
+
+    loop {
+        if sync.is_major_syncing() {
+            Delay::new(RETRY_INTERVAL).await;
+            continue;
+        }
+
+        let hash = *next.get_or_insert_with(|| {
+            let info = client.info();
+            let start: BlockNumber = info.finalized_number.unique_saturated_into();
+            status.update(|progress| progress.start = Some(start));
+            info!(target: LOG_TARGET, "Backfilling the Frontier mappings from #{start}");
+            info.finalized_hash
+        });
+
+        let header = match client.header(hash) {
+            Ok(Some(header)) => header,
+            Ok(None) => {
+                // Not downloaded by the gap sync yet.
+                Delay::new(RETRY_INTERVAL).await;
+                continue;
+            }
+            Err(err) => {
+                warn!(target: LOG_TARGET, "Failed to read header {hash:?}: {err}");
+                Delay::new(RETRY_INTERVAL).await;
+                continue;
+            }
+        };
+        let is_genesis = header.number().is_zero();
+        let number: BlockNumber = (*header.number()).unique_saturated_into();
+
+        let indexed = match frontier_backend.mapping().is_synced(&hash) {
+            Ok(true) => Ok(false),
+            Ok(false) if is_genesis => {
+                fc_mapping_sync::kv::sync_genesis_block(&*client, &frontier_backend, &header)
+                    .map(|()| true)
+            }
+            Ok(false) => fc_mapping_sync::kv::sync_block(
+                storage_override.clone(),
+                &frontier_backend,
+                &header,
+            )
+            .map(|()| true),
+            Err(err) => Err(err),
+        };
+        match indexed {
+            Ok(indexed) => {
+                status.update(|progress| {
+                    progress.lowest_indexed = Some(number);
+                    if indexed {
+                        progress.indexed_blocks += 1;
+                        if progress.indexed_blocks % LOG_INTERVAL == 0 {
+                            info!(
+                                target: LOG_TARGET,
+                                "Indexed {} blocks, down to #{number}",
+                                progress.indexed_blocks
+                            );
+                        }
+                    }
+                });
+                if let Some(metrics) = &metrics {
+                    metrics.lowest_indexed.set(number.into());
+                    if indexed {
+                        metrics.indexed_blocks.inc();
+                    }
+                }
+            }
+            Err(err) if attempts + 1 < MAX_ATTEMPTS => {
+                attempts += 1;
+                debug!(target: LOG_TARGET, "Failed to index block #{number} {hash:?}: {err}");
+                Delay::new(RETRY_INTERVAL).await;
+                continue;
+            }
+            Err(err) => {
+                warn!(
+                    target: LOG_TARGET,
+                    "Skipping block #{number} {hash:?}, failed to index it {MAX_ATTEMPTS} times: {err}"
+                );
+                status.update(|progress| {
+                    progress.skipped_blocks += 1;
+                    if progress.skipped.len() < MAX_REPORTED_SKIPPED {
+                        progress.skipped.push(number);
+                    }
+                });
+                if let Some(metrics) = &metrics {
+                    metrics.skipped_blocks.inc();
+                }
+            }
+        }
+        attempts = 0;
+
+        if is_genesis {
+            status.update(|progress| progress.ready = true);
+            save_cursor::<_, B::Hash>(&*client, status.progress(), None);
+            info!(target: LOG_TARGET, "Frontier mappings backfilled down to genesis");
+            return;
+        }
+        next = Some(*header.parent_hash());
+        if number % CURSOR_INTERVAL == 0 {
+            save_cursor(&*client, status.progress(), next);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `frontier-db` subcommand, which manages the Frontier database of a stopped node.
+//!
+//! `migrate --from kv --to sql` builds the SQL database of a node that ran with the key-value
+//! backend, so that it can restart with `--frontier-backend-type sql` without a resync. It
+//! walks the canonical chain from genesis to the best block and, for every block whose
+//! mappings the key-value database holds, inserts the block and its transactions and indexes
+//! its logs in the SQL database. Blocks already indexed in the SQL database are skipped, so an
+//! interrupted migration resumes where it stopped.
+//!
+//! Logs are read from the state of their block: the migration is meant for archive nodes, on
+//! a pruned node only the blocks whose state is kept get their logs indexed.
+
+use crate::eth::{BackendType, EthConfiguration, FrontierBackend};
+use crate::service::open_frontier_backend;
+use clap::Parser;
+use datahaven_runtime_common::Block;
+use fc_db::kv::Backend as KeyValueBackend;
+use fc_db::sql::Backend as SqlBackend;
+use log::{info, warn};
+use sc_cli::{CliConfiguration, DatabaseParams, SharedParams};
+use sc_client_api::{AuxStore, Backend, StateBackend, StorageProvider};
+use sc_service::Configuration;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_runtime::traits::BlakeTwo256;
+use std::future::Future;
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "frontier-db";
+
+/// Number of migrated blocks between two progress logs.
+const LOG_INTERVAL: u32 = 10_000;
+
+/// Frontier database subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum FrontierDbCmd {
+    /// Migrate the Frontier database of the node to another backend.
+    Migrate(FrontierDbMigrateCmd),
+}
+
+/// Migrates the Frontier database from a backend to another.
+#[derive(Debug, Clone, Parser)]
+pub struct FrontierDbMigrateCmd {
+    /// Backend the node ran with.
+    #[arg(long, value_enum, ignore_case = true)]
+    pub from: BackendType,
+
+    /// Backend the node will run with.
+    #[arg(long, value_enum, ignore_case = true)]
+    pub to: BackendType,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl CliConfiguration for FrontierDbMigrateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+impl FrontierDbMigrateCmd {
+    /// Check that the migration is supported, before the node databases are opened.
+    pub fn check(&self) -> sc_cli::Result<()> {
+        match (self.from, self.to) {
+            (BackendType::KeyValue, BackendType::Sql) => Ok(()),
+            (from, to) => Err(format!(
+                "Migrating the Frontier database from {from:?} to {to:?} is not supported, \
+                 only `--from kv --to sql` is"
+            )
+            .into()),
+        }
+    }
+
+    /// Open the SQL database next to the key-value one `source`, and return the migration.
+    pub fn run<C, BE>(
+        &self,
+        client: Arc<C>,
+        source: Arc<FrontierBackend<Block, C>>,
+        config: &Configuration,
+        eth_config: &EthConfiguration,
+    ) -> sc_cli::Result<impl Future<Output = sc_cli::Result<()>>>
+    where
+        C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE> + AuxStore,
+        C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+        C: Send + Sync + 'static,
+        C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+        BE: Backend<Block> + 'static,
+        BE::State: StateBackend<BlakeTwo256>,
+    {
+        let FrontierBackend::KeyValue(source) = &*source else {
+            return Err(
+                "The node must be started with `--frontier-backend-type key-value` \
+                 to migrate from its key-value database"
+                    .into(),
+            );
+        };
+        let mut eth_config = eth_config.clone();
+        eth_config.frontier_backend_type = BackendType::Sql;
+        let FrontierBackend::Sql(target) =
+            open_frontier_backend::<C, BE>(client.clone(), config, &mut eth_config)?
+        else {
+            unreachable!("the SQL backend is requested; qed");
+        };
+
+        Ok(migrate_kv_to_sql::<C, BE>(client, source.clone(), target))
+    }
+}
+
+/// Index the blocks of the key-value database `source` in the SQL database `target`.
+async fn migrate_kv_to_sql<C, BE>(
+    client: Arc<C>,
+    source: Arc<KeyValueBackend<Block, C>>,
+    target: Arc<SqlBackend<Block>>,
+) -> sc_cli::Result<()>
+where
+    C: ProvideRuntimeApi<Block> + StorageProvider<Block, BE> + HeaderBackend<Block>,
+    C: Send + Sync + 'static,
+    C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+    BE: Backend<Block> + 'static,
+    BE::State: StateBackend<BlakeTwo256>,
+{
+    let best = client.info().best_number;
+    info!(
+        target: LOG_TARGET,
+        "Migrating the Frontier mappings of blocks #0 to #{best} to the SQL database"
+    );
+
+    target
+        .insert_genesis_block_metadata(client.clone())
+        .await
+        .map_err(|err| format!("Failed to insert the genesis block: {err}"))?;
+
+    let (mut migrated, mut not_mapped) = (0u32, 0u32);
+    for number in 1..=best {
+        let hash = client
+            .hash(number)?
+            .ok_or_else(|| format!("Canonical block #{number} not found"))?;
+        let mapped = source
+            .mapping()
+            .is_synced(&hash)
+            .map_err(|err| format!("Failed to read the mappings of block #{number}: {err}"))?;
+        if !mapped {
+            not_mapped += 1;
+            continue;
+        }
+        if target.is_block_indexed(hash).await {
+            continue;
+        }
+
+        target
+            .insert_block_metadata(client.clone(), hash)
+            .await
+            .map_err(|err| format!("Failed to insert block #{number}: {err}"))?;
+        target.index_block_logs(hash).await;
+
+        migrated += 1;
+        if migrated % LOG_INTERVAL == 0 {
+            info!(target: LOG_TARGET, "Migrated {migrated} blocks, up to #{number}");
+        }
+    }
+
+    info!(target: LOG_TARGET, "Migrated {migrated} blocks to the SQL database");
+    if not_mapped > 0 {
+        warn!(
+            target: LOG_TARGET,
+            "{not_mapped} blocks are missing from the key-value database, the SQL mapping sync \
+             worker indexes them once the node runs with `--frontier-backend-type sql`"
+        );
+    }
+    Ok(())
+}
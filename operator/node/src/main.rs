@@ -17,8 +17,13 @@
 //! Substrate Node Template CLI library.
 #![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmark_runner;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod beefy_watchdog;
+mod bridge_archive;
+mod build_spec;
 mod chain_spec;
 mod cli;
 mod client;
@@ -26,8 +31,14 @@ mod command;
 mod config;
 mod consensus;
 mod eth;
+mod evm_tracing;
+mod frontier_backfill;
+mod frontier_db;
+mod mmr_proof;
+mod readiness;
 mod rpc;
 mod service;
+mod validator_performance;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -0,0 +1,287 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! BEEFY MMR leaf proofs in the format of the Snowbridge BEEFY client on Ethereum.
+//!
+//! `datahaven_generateMmrProof` proves the MMR leaves of a range of blocks against the MMR
+//! root of a block, BEEFY finalized for the proofs to be accepted on Ethereum. Each leaf is
+//! returned decoded, with the keccak hash the contracts recompute from its fields, and its
+//! proof is converted to the simplified form `verifyMMRLeafProof` takes: the hashes folded
+//! from the leaf up to the root, and a bitfield whose `i`-th bit is set when the `i`-th hash
+//! is the left operand. The leaf extra data, the latest outbound queue commitment, is the
+//! `parachainHeadsRoot` field of the contracts.
+
+use codec::Decode;
+use datahaven_runtime_common::{Block, BlockNumber};
+use fc_rpc::internal_err;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_consensus_beefy::mmr::MmrLeaf;
+use sp_core::{
+    hashing::keccak_256,
+    offchain::{storage::OffchainDb, OffchainDbExt, OffchainStorage},
+    H256,
+};
+use sp_mmr_primitives::MmrApi;
+use std::sync::Arc;
+
+/// Maximum number of blocks whose leaves are proven by a single call.
+const MAX_PROVEN_BLOCKS: BlockNumber = 256;
+
+/// Leaf of the BEEFY MMR, with the field names of the Snowbridge `MMRLeaf` struct.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnowbridgeMmrLeaf {
+    /// Version of the leaf format.
+    pub version: u8,
+    /// Number of the parent of the block the leaf was added in.
+    pub parent_number: BlockNumber,
+    /// Hash of the parent of the block the leaf was added in.
+    pub parent_hash: H256,
+    /// Id of the next BEEFY authority set.
+    pub next_authority_set_id: u64,
+    /// Number of authorities of the next BEEFY authority set.
+    pub next_authority_set_len: u32,
+    /// Merkle root of the Ethereum addresses of the next BEEFY authorities.
+    pub next_authority_set_root: H256,
+    /// Leaf extra data: the latest outbound queue commitment.
+    pub parachain_heads_root: H256,
+}
+
+/// Proof of the MMR leaf of a block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MmrLeafProof {
+    /// Block the leaf was added in.
+    pub block_number: BlockNumber,
+    /// Index of the leaf in the MMR.
+    pub leaf_index: u64,
+    /// The leaf.
+    pub leaf: SnowbridgeMmrLeaf,
+    /// Keccak hash of the SCALE-encoded leaf.
+    pub leaf_hash: H256,
+    /// Hashes folded with the leaf hash, in order, to compute the MMR root.
+    pub proof: Vec<H256>,
+    /// Bit `i` is set when `proof[i]` is the left operand of its hash.
+    pub proof_order: u64,
+}
+
+/// Proofs of a range of MMR leaves, as returned by `datahaven_generateMmrProof`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MmrLeafProofs {
+    /// Block whose MMR root the leaves are proven against.
+    pub block_hash: H256,
+    /// MMR root the proofs fold to.
+    pub mmr_root: H256,
+    /// Number of leaves of the MMR.
+    pub leaf_count: u64,
+    /// Proofs of the leaves, in the order of their blocks.
+    pub proofs: Vec<MmrLeafProof>,
+}
+
+/// Serves proofs of the BEEFY MMR leaves.
+pub(crate) struct MmrProof<C, S> {
+    client: Arc<C>,
+    offchain_db: OffchainDb<S>,
+}
+
+impl<C, S> MmrProof<C, S>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MmrApi<Block, H256, BlockNumber>,
+    S: OffchainStorage + 'static,
+{
+    pub(crate) fn new(client: Arc<C>, offchain_storage: S) -> Self {
+        Self {
+            client,
+            offchain_db: OffchainDb::new(offchain_storage),
+        }
+    }
+
+    /// RPC module with the `datahaven_generateMmrProof` method.
+    pub(crate) fn into_rpc(self) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        module
+            .register_method(
+                "datahaven_generateMmrProof",
+                |params, mmr, _| -> RpcResult<MmrLeafProofs> {
+                    let (from, to, at) =
+                        params.parse::<(BlockNumber, BlockNumber, Option<H256>)>()?;
+                    mmr.generate_proofs(from, to, at)
+                },
+            )
+            .expect("the method is registered once; qed");
+        module
+    }
+
+    /// Proofs of the leaves of the blocks `from..=to` against the MMR root of `at`, the last
+    /// finalized block by default.
+    fn generate_proofs(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        at: Option<H256>,
+    ) -> RpcResult<MmrLeafProofs> {
+        if from > to || to - from >= MAX_PROVEN_BLOCKS {
+            return Err(internal_err(format!(
+                "Invalid block range {from}..={to}, at most {MAX_PROVEN_BLOCKS} blocks are proven at once"
+            )));
+        }
+        let block_hash = at.unwrap_or_else(|| self.client.info().finalized_hash);
+
+        let mut api = self.client.runtime_api();
+        api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+        let mmr_root = api
+            .mmr_root(block_hash)
+            .map_err(|err| internal_err(format!("Runtime API call failed: {err}")))?
+            .map_err(|err| internal_err(format!("Failed to read the MMR root: {err:?}")))?;
+
+        let mut leaf_count = 0;
+        let mut proofs = Vec::new();
+        for block_number in from..=to {
+            let (leaves, proof) = api
+                .generate_proof(block_hash, vec![block_number], None)
+                .map_err(|err| internal_err(format!("Runtime API call failed: {err}")))?
+                .map_err(|err| {
+                    internal_err(format!(
+                        "Failed to prove the leaf of #{block_number}: {err:?}"
+                    ))
+                })?;
+            let (Some(leaf), [leaf_index]) = (leaves.into_iter().next(), &proof.leaf_indices[..])
+            else {
+                return Err(internal_err(format!(
+                    "Expected a single leaf for #{block_number}"
+                )));
+            };
+
+            let leaf = leaf.into_opaque_leaf().0;
+            let leaf_hash = H256(keccak_256(&leaf));
+            let (proof_items, proof_order) =
+                simplified_proof(*leaf_index, proof.leaf_count, &proof.items).ok_or_else(|| {
+                    internal_err(format!("Malformed MMR proof for #{block_number}"))
+                })?;
+            if fold_proof(leaf_hash, &proof_items, proof_order) != mmr_root {
+                return Err(internal_err(format!(
+                    "The proof of #{block_number} does not match the MMR root"
+                )));
+            }
+
+            leaf_count = proof.leaf_count;
+            proofs.push(MmrLeafProof {
+                block_number,
+                leaf_index: *leaf_index,
+                leaf: decode_leaf(&leaf).ok_or_else(|| {
+                    internal_err(format!("Failed to decode the leaf of #{block_number}"))
+                })?,
+                leaf_hash,
+                proof: proof_items,
+                proof_order,
+            });
+        }
+
+        Ok(MmrLeafProofs {
+            block_hash,
+            mmr_root,
+            leaf_count,
+            proofs,
+        })
+    }
+}
+
+fn decode_leaf(mut leaf: &[u8]) -> Option<SnowbridgeMmrLeaf> {
+    let leaf = MmrLeaf::<BlockNumber, H256, H256, H256>::decode(&mut leaf).ok()?;
+    let (parent_number, parent_hash) = leaf.parent_number_and_hash;
+    Some(SnowbridgeMmrLeaf {
+        version: codec::Encode::encode(&leaf.version)[0],
+        parent_number,
+        parent_hash,
+        next_authority_set_id: leaf.beefy_next_authority_set.id,
+        next_authority_set_len: leaf.beefy_next_authority_set.len,
+        next_authority_set_root: leaf.beefy_next_authority_set.keyset_commitment,
+        parachain_heads_root: leaf.leaf_extra,
+    })
+}
+
+/// Heights of the perfect trees of the peaks of an MMR of `leaf_count` leaves, left first.
+fn peak_heights(leaf_count: u64) -> impl Iterator<Item = u32> {
+    (0..u64::BITS)
+        .rev()
+        .filter(move |height| leaf_count & (1 << height) != 0)
+}
+
+/// Convert the proof of the leaf `leaf_index` generated by `pallet_mmr` into the hashes and
+/// the order bitfield folded by the Snowbridge contracts.
+///
+/// `pallet_mmr` proves a leaf with the peaks on its left, then the siblings of the nodes on
+/// its path up to its peak, then the peaks on its right bagged into one hash. Peaks are bagged
+/// from the right, the accumulated hash being the left operand, so the root of peaks `p0..pn`
+/// is `H(..H(H(pn, pn-1), pn-2).., p0)`.
+fn simplified_proof(leaf_index: u64, leaf_count: u64, items: &[H256]) -> Option<(Vec<H256>, u64)> {
+    let mut first_leaf = 0;
+    let mut left_peaks = 0;
+    let mut peak = None;
+    let mut right_peaks = 0;
+    for height in peak_heights(leaf_count) {
+        let leaves = 1u64 << height;
+        if peak.is_some() {
+            right_peaks += 1;
+        } else if leaf_index < first_leaf + leaves {
+            peak = Some((height, leaf_index - first_leaf));
+        } else {
+            left_peaks += 1;
+        }
+        first_leaf += leaves;
+    }
+    let (height, index_in_peak) = peak?;
+    let height = height as usize;
+
+    let rhs = usize::from(right_peaks > 0);
+    if items.len() != left_peaks + height + rhs {
+        return None;
+    }
+    let (peaks_on_left, path) = items.split_at(left_peaks);
+
+    // Siblings are on the left of the nodes whose index in their level is odd.
+    let mut order = index_in_peak;
+    // The bagged right peaks are the left operand of the leaf peak.
+    order |= (rhs as u64) << height;
+    // The left peaks are then folded from the closest one, each being the right operand.
+    let proof = path
+        .iter()
+        .chain(peaks_on_left.iter().rev())
+        .copied()
+        .collect();
+
+    Some((proof, order))
+}
+
+/// Root computed by the Snowbridge contracts from a leaf hash and a simplified proof.
+fn fold_proof(leaf_hash: H256, proof: &[H256], order: u64) -> H256 {
+    proof
+        .iter()
+        .enumerate()
+        .fold(leaf_hash, |acc, (index, item)| {
+            let (left, right) = if order >> index & 1 == 0 {
+                (acc, *item)
+            } else {
+                (*item, acc)
+            };
+            H256(keccak_256(&[left.0, right.0].concat()))
+        })
+}
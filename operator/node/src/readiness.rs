@@ -0,0 +1,345 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Readiness of the node to serve complete Ethereum data.
+//!
+//! A node whose Substrate sync is done can still serve incomplete Ethereum data: the
+//! Frontier mapping sync worker may lag behind the best block, and the mappings of the
+//! blocks below a warp sync target are only indexed by the backfill. This module replaces
+//! Frontier's `eth_syncing` with one that keeps reporting progress until the mappings are
+//! complete, and adds `datahaven_ready`, which also checks that the beacon client of the
+//! bridge keeps receiving finalized checkpoints, for load balancers to probe.
+//!
+//! The age of a checkpoint is measured from the time of its beacon slot. The genesis time of
+//! the beacon chain is only known for the live networks; on development and local chains,
+//! the age is measured from when the node first saw the checkpoint.
+
+use crate::frontier_backfill::{BackfillProgress, BackfillStatus};
+use codec::{Compact, Decode};
+use datahaven_runtime_common::{Block, BlockNumber};
+use fc_rpc_core::types::{SyncInfo, SyncStatus};
+use fc_storage::StorageOverride;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use sc_client_api::{Backend, StorageProvider};
+use sc_network_sync::SyncingService;
+use sc_service::{ChainSpec, ChainType};
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+use sp_core::{hashing::twox_128, storage::StorageKey, H256, U256};
+use sp_runtime::traits::Header as HeaderT;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Number of blocks the Frontier mappings may lag behind the best block and still be
+/// considered synced.
+const MAX_MAPPING_LAG: BlockNumber = 8;
+
+/// Age of the latest finalized beacon checkpoint after which the bridge is considered stale.
+/// A checkpoint is about two epochs old when it is finalized, and checkpoints are relayed
+/// every epoch, an epoch being about 6.4 minutes.
+const MAX_CHECKPOINT_AGE: Duration = Duration::from_secs(40 * 60);
+
+/// Duration of a beacon slot.
+const SECONDS_PER_SLOT: u64 = 12;
+
+/// Genesis time of the Ethereum mainnet beacon chain, followed by DataHaven mainnet.
+const MAINNET_BEACON_GENESIS_TIME: u64 = 1_606_824_023;
+
+/// Genesis time of the Hoodi beacon chain, followed by DataHaven testnet and stagenet.
+/// Source: https://github.com/eth-clients/hoodi/blob/main/metadata/config.yaml
+const HOODI_BEACON_GENESIS_TIME: u64 = 1_742_213_400;
+
+/// Name of the beacon client pallet in the DataHaven runtimes.
+const BEACON_CLIENT_PALLET: &[u8] = b"EthereumBeaconClient";
+
+/// Latest finalized checkpoint imported by the beacon client.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeaconCheckpoint {
+    /// Beacon slot of the checkpoint.
+    pub slot: u64,
+    /// Unix time of the beacon slot, when the genesis time of the beacon chain is known.
+    pub slot_time: Option<u64>,
+    /// Seconds since the beacon slot, or since this node first saw the checkpoint when the
+    /// slot time is not known.
+    pub seconds_since_update: u64,
+    /// Whether the checkpoint is recent enough for the bridge to be up to date.
+    pub fresh: bool,
+}
+
+/// Response of `datahaven_ready`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeReadiness {
+    /// Whether the node can serve complete and up-to-date data.
+    pub ready: bool,
+    /// Whether the Substrate sync is done.
+    pub substrate_synced: bool,
+    /// Best block of the node.
+    pub best_block: BlockNumber,
+    /// Best block seen on the network, if known.
+    pub highest_block: Option<BlockNumber>,
+    /// Highest block, close to the best one, whose Frontier mappings are indexed.
+    pub frontier_indexed_block: Option<BlockNumber>,
+    /// Progress of the Frontier mappings backfill, when it runs.
+    pub frontier_backfill: Option<BackfillProgress>,
+    /// Latest beacon checkpoint, `None` when the beacon client is not initialized.
+    pub beacon_checkpoint: Option<BeaconCheckpoint>,
+}
+
+/// Checks the readiness of the node, shared by the RPC methods.
+pub(crate) struct Readiness<C, BE> {
+    client: Arc<C>,
+    sync: Arc<SyncingService<Block>>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    storage_override: Arc<dyn StorageOverride<Block>>,
+    frontier_backfill: Option<BackfillStatus>,
+    /// Genesis time of the beacon chain followed by the bridge, if known.
+    beacon_genesis_time: Option<u64>,
+    /// Latest checkpoint slot seen, and when it was first seen.
+    last_checkpoint: Mutex<Option<(u64, Instant)>>,
+    _phantom: PhantomData<BE>,
+}
+
+impl<C, BE> Readiness<C, BE>
+where
+    C: HeaderBackend<Block> + StorageProvider<Block, BE> + Send + Sync + 'static,
+    BE: Backend<Block> + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        client: Arc<C>,
+        sync: Arc<SyncingService<Block>>,
+        frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+        storage_override: Arc<dyn StorageOverride<Block>>,
+        frontier_backfill: Option<BackfillStatus>,
+        beacon_genesis_time: Option<u64>,
+    ) -> Self {
+        Self {
+            client,
+            sync,
+            frontier_backend,
+            storage_override,
+            frontier_backfill,
+            beacon_genesis_time,
+            last_checkpoint: Mutex::new(None),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// RPC module with `eth_syncing` and `datahaven_ready`.
+    ///
+    /// Frontier's `eth_syncing` must be removed from the module this one is merged into.
+    pub(crate) fn into_rpc(self) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        module
+            .register_async_method("eth_syncing", |_, readiness, _| async move {
+                RpcResult::Ok(readiness.eth_syncing().await)
+            })
+            .expect("the method is registered once; qed");
+        module
+            .register_async_method("datahaven_ready", |_, readiness, _| async move {
+                RpcResult::Ok(readiness.readiness().await)
+            })
+            .expect("the method is registered once; qed");
+        module
+    }
+
+    /// Frontier's sync status, extended with the mapping sync and the backfill.
+    ///
+    /// The backfill walks down from the block it started at, its progress is reported as
+    /// the number of blocks indexed out of that block number.
+    async fn eth_syncing(&self) -> SyncStatus {
+        let best_block = self.client.info().best_number;
+        let highest_block = self.highest_block().await;
+
+        if self.sync.is_major_syncing() {
+            return sync_info(0, best_block, highest_block.unwrap_or(best_block));
+        }
+        if let Some(indexed) = self.frontier_indexed_block().await {
+            if best_block.saturating_sub(indexed) > MAX_MAPPING_LAG {
+                return sync_info(0, indexed, best_block);
+            }
+        } else {
+            return sync_info(0, 0, best_block);
+        }
+        match self
+            .frontier_backfill
+            .as_ref()
+            .map(BackfillStatus::progress)
+        {
+            Some(BackfillProgress {
+                ready: false,
+                start,
+                lowest_indexed,
+                ..
+            }) => {
+                let start = start.unwrap_or(best_block);
+                let lowest = lowest_indexed.unwrap_or(start);
+                sync_info(0, start.saturating_sub(lowest), start)
+            }
+            _ => SyncStatus::None,
+        }
+    }
+
+    async fn readiness(&self) -> NodeReadiness {
+        let best_block = self.client.info().best_number;
+        let substrate_synced = !self.sync.is_major_syncing();
+        let frontier_indexed_block = self.frontier_indexed_block().await;
+        let frontier_backfill = self
+            .frontier_backfill
+            .as_ref()
+            .map(BackfillStatus::progress);
+        let beacon_checkpoint = self.beacon_checkpoint();
+
+        let ready = substrate_synced
+            && frontier_indexed_block
+                .is_some_and(|indexed| best_block.saturating_sub(indexed) <= MAX_MAPPING_LAG)
+            && frontier_backfill
+                .as_ref()
+                .is_none_or(|progress| progress.ready)
+            && beacon_checkpoint
+                .as_ref()
+                .is_none_or(|checkpoint| checkpoint.fresh);
+
+        NodeReadiness {
+            ready,
+            substrate_synced,
+            best_block,
+            highest_block: self.highest_block().await,
+            frontier_indexed_block,
+            frontier_backfill,
+            beacon_checkpoint,
+        }
+    }
+
+    async fn highest_block(&self) -> Option<BlockNumber> {
+        self.sync.best_seen_block().await.ok().flatten()
+    }
+
+    /// Highest block, among the best one and its `MAX_MAPPING_LAG` ancestors, whose
+    /// Ethereum block hash is mapped to it in the Frontier database.
+    async fn frontier_indexed_block(&self) -> Option<BlockNumber> {
+        let mut hash = self.client.info().best_hash;
+        for _ in 0..=MAX_MAPPING_LAG {
+            let header = self.client.header(hash).ok().flatten()?;
+            if let Some(ethereum_block) = self.storage_override.current_block(hash) {
+                let mapped = self
+                    .frontier_backend
+                    .block_hash(&ethereum_block.header.hash())
+                    .await
+                    .ok()
+                    .flatten()
+                    .is_some_and(|hashes| hashes.contains(&hash));
+                if mapped {
+                    return Some(*header.number());
+                }
+            }
+            if header.number() == &0 {
+                return None;
+            }
+            hash = *header.parent_hash();
+        }
+        None
+    }
+
+    /// Latest finalized checkpoint of the beacon client, read from the best block state.
+    fn beacon_checkpoint(&self) -> Option<BeaconCheckpoint> {
+        let best_hash = self.client.info().best_hash;
+        let read = |key: Vec<u8>| {
+            self.client
+                .storage(best_hash, &StorageKey(key))
+                .ok()
+                .flatten()
+                .map(|data| data.0)
+        };
+
+        let root = read(storage_value_key(b"LatestFinalizedBlockRoot"))
+            .and_then(|data| H256::decode(&mut &data[..]).ok())
+            .filter(|root| !root.is_zero())?;
+        // `CompactBeaconState`, stored under an `Identity` hashed key.
+        let mut state_key = storage_value_key(b"FinalizedBeaconState");
+        state_key.extend_from_slice(root.as_bytes());
+        let (Compact(slot), _block_roots_root) =
+            read(state_key).and_then(|data| <(Compact<u64>, H256)>::decode(&mut &data[..]).ok())?;
+
+        let slot_time = self
+            .beacon_genesis_time
+            .map(|genesis_time| genesis_time.saturating_add(slot.saturating_mul(SECONDS_PER_SLOT)));
+        let age = match slot_time {
+            Some(slot_time) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                Duration::from_secs(now.saturating_sub(slot_time))
+            }
+            None => {
+                let mut last_checkpoint = self
+                    .last_checkpoint
+                    .lock()
+                    .expect("lock is not poisoned; qed");
+                let seen_at = match *last_checkpoint {
+                    Some((last_slot, seen_at)) if last_slot == slot => seen_at,
+                    _ => {
+                        let now = Instant::now();
+                        *last_checkpoint = Some((slot, now));
+                        now
+                    }
+                };
+                seen_at.elapsed()
+            }
+        };
+
+        Some(BeaconCheckpoint {
+            slot,
+            slot_time,
+            seconds_since_update: age.as_secs(),
+            fresh: age <= MAX_CHECKPOINT_AGE,
+        })
+    }
+}
+
+/// Genesis time of the beacon chain followed by the bridge of `chain_spec`, `None` for the
+/// development and local chains, which run with their own Ethereum network.
+pub(crate) fn beacon_genesis_time(chain_spec: &dyn ChainSpec) -> Option<u64> {
+    if chain_spec.chain_type() != ChainType::Live {
+        return None;
+    }
+    let id = chain_spec.id();
+    if id.starts_with("datahaven_mainnet") {
+        Some(MAINNET_BEACON_GENESIS_TIME)
+    } else if id.starts_with("datahaven_testnet") || id.starts_with("datahaven_stagenet") {
+        Some(HOODI_BEACON_GENESIS_TIME)
+    } else {
+        None
+    }
+}
+
+fn storage_value_key(storage: &[u8]) -> Vec<u8> {
+    [twox_128(BEACON_CLIENT_PALLET), twox_128(storage)].concat()
+}
+
+fn sync_info(starting: BlockNumber, current: BlockNumber, highest: BlockNumber) -> SyncStatus {
+    SyncStatus::Info(SyncInfo {
+        starting_block: U256::from(starting),
+        current_block: U256::from(current),
+        highest_block: U256::from(highest),
+        warp_chunks_amount: None,
+        warp_chunks_processed: None,
+    })
+}
@@ -21,9 +21,15 @@
 
 #![warn(missing_docs)]
 
+use crate::bridge_archive::BridgeArchive;
 use crate::consensus::BabeConsensusDataProvider;
 use crate::eth::DefaultEthConfig;
-use datahaven_runtime_common::{time::SLOT_DURATION, Block, BlockNumber, Hash};
+use crate::evm_tracing::{EvmTracing, EvmTracingConfig};
+use crate::frontier_backfill::BackfillStatus;
+use crate::mmr_proof::MmrProof;
+use crate::readiness::Readiness;
+use crate::validator_performance::ValidatorPerformance;
+use datahaven_runtime_common::{time::SLOT_DURATION, AccountId, Block, BlockNumber, Hash};
 use fc_rpc::{Eth, EthBlockDataCacheTask, EthFilter, Net, Web3};
 use fc_rpc::{EthPubSub, TxPool};
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
@@ -35,9 +41,14 @@ use fc_storage::StorageOverride;
 use fp_rpc::EthereumRuntimeRPCApi;
 use jsonrpsee::RpcModule;
 use sc_client_api::{Backend, StateBackend, StorageProvider};
+use sc_consensus::LongestChain;
+use sc_consensus_babe::BabeWorkerHandle;
 use sc_consensus_beefy::communication::notification::{
     BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
 };
+use sc_consensus_grandpa::{
+    FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
+};
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 use sc_network_sync::SyncingService;
 use sc_transaction_pool::ChainApi;
@@ -54,6 +65,7 @@ use shc_rpc::StorageHubClientRpcConfig;
 use sp_consensus_babe::{BabeApi, SlotDuration};
 use sp_consensus_beefy::AuthorityIdBound;
 use sp_core::H256;
+use sp_keystore::KeystorePtr;
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, Header as HeaderT};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -68,6 +80,28 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
     pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
 
+/// Dependencies for BABE
+pub struct BabeDeps {
+    /// A handle to the BABE worker for issuing requests.
+    pub babe_worker_handle: BabeWorkerHandle<Block>,
+    /// The keystore that manages the keys of the node.
+    pub keystore: KeystorePtr,
+}
+
+/// Dependencies for GRANDPA
+pub struct GrandpaDeps<B> {
+    /// Voting round info.
+    pub shared_voter_state: SharedVoterState,
+    /// Authority set info.
+    pub shared_authority_set: SharedAuthoritySet<Hash, BlockNumber>,
+    /// Receives notifications about justification events from GRANDPA.
+    pub justification_stream: GrandpaJustificationStream<Block>,
+    /// Executor to drive the subscription manager in the GRANDPA RPC handler.
+    pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
+    /// Finality proof provider.
+    pub finality_proof_provider: Arc<FinalityProofProvider<B, Block>>,
+}
+
 /// Full client dependencies.
 pub struct FullDeps<P, B, AuthorityId: AuthorityIdBound, FL, FS, Runtime>
 where
@@ -80,6 +114,12 @@ where
     pub pool: Arc<P>,
     /// BEEFY dependencies.
     pub beefy: BeefyDeps<AuthorityId>,
+    /// BABE dependencies.
+    pub babe: BabeDeps,
+    /// The chain selection strategy, used to check the epoch of the best block.
+    pub select_chain: LongestChain<B, Block>,
+    /// GRANDPA dependencies.
+    pub grandpa: GrandpaDeps<B>,
     /// Graph pool instance.
     pub graph: Arc<P>,
     /// Backend used by the node.
@@ -108,6 +148,14 @@ where
     pub command_sink: Option<futures::channel::mpsc::Sender<EngineCommand<Hash>>>,
     /// Mandated parent hashes for a given block hash.
     pub forced_parent_hashes: Option<BTreeMap<H256, H256>>,
+    /// Progress of the Frontier mappings backfill.
+    pub frontier_backfill: Option<BackfillStatus>,
+    /// Genesis time of the beacon chain followed by the bridge, if known.
+    pub beacon_genesis_time: Option<u64>,
+    /// Whether the node runs with `--pruning bridge-archive`.
+    pub bridge_archive: bool,
+    /// EVM tracing methods to serve, `None` when none is enabled.
+    pub evm_tracing: Option<EvmTracingConfig>,
     /// Storage Hub RPC config
     pub maybe_storage_hub_client_config: Option<StorageHubClientRpcConfig<FL, FS, Runtime>>,
 }
@@ -135,7 +183,12 @@ where
             BlockNumber,
         > + EthereumRuntimeRPCApi<Block>
                         + BabeApi<Block>
-                        + fp_rpc::ConvertTransactionRuntimeApi<Block>,
+                        + fp_rpc::ConvertTransactionRuntimeApi<Block>
+                        + dhp_evm_tracing::EvmTracingApi<Block>
+                        + pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<
+            Block,
+            AccountId,
+        >,
     >,
     StorageHubClient<Runtime::RuntimeApi>: StorageProvider<Block, BE>,
     FL: FileStorageT,
@@ -143,7 +196,9 @@ where
 {
     use mmr_rpc::{Mmr, MmrApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use sc_consensus_babe_rpc::{Babe, BabeApiServer};
     use sc_consensus_beefy_rpc::{Beefy, BeefyApiServer};
+    use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcModule::new(());
@@ -151,6 +206,9 @@ where
         client,
         pool,
         beefy,
+        babe,
+        select_chain,
+        grandpa,
         graph,
         network,
         sync,
@@ -165,6 +223,10 @@ where
         is_authority,
         command_sink,
         forced_parent_hashes,
+        frontier_backfill,
+        beacon_genesis_time,
+        bridge_archive,
+        evm_tracing,
         maybe_storage_hub_client_config,
     } = deps;
 
@@ -178,6 +240,25 @@ where
         )?
         .into_rpc(),
     )?;
+    module.merge(
+        Babe::new(
+            client.clone(),
+            babe.babe_worker_handle,
+            babe.keystore,
+            select_chain,
+        )
+        .into_rpc(),
+    )?;
+    module.merge(
+        Grandpa::new(
+            grandpa.subscription_executor,
+            grandpa.shared_authority_set,
+            grandpa.shared_voter_state,
+            grandpa.justification_stream,
+            grandpa.finality_proof_provider,
+        )
+        .into_rpc(),
+    )?;
     module.merge(
         Mmr::new(
             client.clone(),
@@ -187,6 +268,15 @@ where
         )
         .into_rpc(),
     )?;
+    module.merge(
+        MmrProof::new(
+            client.clone(),
+            backend
+                .offchain_storage()
+                .ok_or("Backend doesn't provide the required offchain storage")?,
+        )
+        .into_rpc(),
+    )?;
 
     if let Some(storage_hub_client_config) = maybe_storage_hub_client_config {
         module.merge(
@@ -245,6 +335,31 @@ where
         )
         .into_rpc(),
     )?;
+    // Replaced by the readiness module, which also waits for the Frontier mappings.
+    module.remove_method("eth_syncing");
+    module.merge(
+        Readiness::new(
+            Arc::clone(&client),
+            Arc::clone(&sync),
+            Arc::clone(&frontier_backend),
+            Arc::clone(&overrides),
+            frontier_backfill.clone(),
+            beacon_genesis_time,
+        )
+        .into_rpc(),
+    )?;
+    module.merge(ValidatorPerformance::new(Arc::clone(&client)).into_rpc())?;
+    if let Some(evm_tracing) = evm_tracing {
+        module.merge(
+            EvmTracing::new(
+                Arc::clone(&client),
+                Arc::clone(&frontier_backend),
+                Arc::clone(&overrides),
+                &evm_tracing,
+            )
+            .into_rpc(&evm_tracing),
+        )?;
+    }
 
     if let Some(filter_pool) = filter_pool {
         module.merge(
@@ -295,5 +410,13 @@ where
     let tx_pool = TxPool::new(client.clone(), graph.clone());
     module.merge(tx_pool.into_rpc())?;
 
+    if let Some(frontier_backfill) = frontier_backfill {
+        module.merge(frontier_backfill.into_rpc())?;
+    }
+
+    if bridge_archive {
+        module.merge(BridgeArchive::new(Arc::clone(&client)).into_rpc())?;
+    }
+
     Ok(module)
 }
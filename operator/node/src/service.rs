@@ -16,14 +16,16 @@
 
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use crate::beefy_watchdog::{run_supervised_beefy_gadget, BeefyWatchdogParams};
 use crate::cli::{ProviderType, Sealing, StorageLayer};
 use crate::command::{ProviderOptions, RoleOptions};
 use crate::eth::{
     new_frontier_partial, spawn_frontier_tasks, BackendType, FrontierBackend,
     FrontierPartialComponents, FrontierTasksParams,
 };
-use crate::eth::{EthConfiguration, StorageOverrideHandler};
-use crate::rpc::BeefyDeps;
+use crate::eth::{EthApi, EthConfiguration, StorageOverrideHandler};
+use crate::evm_tracing::EvmTracingConfig;
+use crate::rpc::{BabeDeps, BeefyDeps, GrandpaDeps};
 use async_channel::Receiver;
 use datahaven_runtime_common::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
 use fc_consensus::FrontierBlockImport;
@@ -34,13 +36,14 @@ use futures::FutureExt;
 use log::info;
 use sc_client_api::{AuxStore, Backend, BlockBackend, StateBackend, StorageProvider};
 use sc_consensus_babe::ImportQueueParams;
+use sc_consensus_beefy::communication::request_response::BeefyJustifsRequestHandler;
 use sc_consensus_grandpa::SharedVoterState;
 use sc_consensus_manual_seal::consensus::babe::BabeConsensusDataProvider;
 use sc_consensus_manual_seal::rpc::EngineCommand;
 use sc_consensus_manual_seal::{self, InstantSealParams, ManualSealParams};
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
 use sc_network::request_responses::IncomingRequest;
-use sc_network::service::traits::NetworkService;
+use sc_network::service::traits::{NetworkService, NotificationService};
 use sc_network::ProtocolName;
 use sc_service::RpcHandlers;
 use sc_service::{
@@ -125,6 +128,8 @@ pub(crate) trait FullRuntimeApi:
     + sp_consensus_grandpa::GrandpaApi<Block>
     + fp_rpc::ConvertTransactionRuntimeApi<Block>
     + fp_rpc::EthereumRuntimeRPCApi<Block>
+    + dhp_evm_tracing::EvmTracingApi<Block>
+    + pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<Block, AccountId>
 {
 }
 
@@ -145,6 +150,11 @@ impl<T> FullRuntimeApi for T where
         + sp_consensus_grandpa::GrandpaApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
+        + dhp_evm_tracing::EvmTracingApi<Block>
+        + pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<
+            Block,
+            AccountId,
+        >
 {
 }
 
@@ -173,6 +183,7 @@ pub type Service<RuntimeApi> = sc_service::PartialComponents<
         >,
         sc_consensus_grandpa::LinkHalf<Block, FullClient<RuntimeApi>, FullSelectChain>,
         sc_consensus_babe::BabeLink<Block>,
+        sc_consensus_babe::BabeWorkerHandle<Block>,
         sc_consensus_beefy::BeefyVoterLinks<Block, BeefyId>,
         sc_consensus_beefy::BeefyRPCLinks<Block, BeefyId>,
         Arc<fc_db::Backend<Block, FullClient<RuntimeApi>>>,
@@ -406,9 +417,6 @@ where
         offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool.clone()),
     })?;
 
-    // TODO Wire up to RPC
-    std::mem::forget(babe_worker_handle);
-
     Ok(sc_service::PartialComponents {
         client,
         backend,
@@ -421,6 +429,7 @@ where
             block_import,
             grandpa_link,
             babe_link,
+            babe_worker_handle,
             beefy_voter_links,
             beefy_rpc_links,
             frontier_backend,
@@ -444,6 +453,7 @@ pub async fn new_full_impl<
     role_options: Option<RoleOptions>,
     indexer_options: Option<IndexerOptions>,
     sealing: Option<Sealing>,
+    bridge_archive: bool,
 ) -> Result<TaskManager, ServiceError>
 where
     Runtime: shc_common::traits::StorageEnableRuntime<RuntimeApi = RuntimeApi>,
@@ -487,6 +497,7 @@ where
                 block_import,
                 grandpa_link,
                 babe_link,
+                babe_worker_handle,
                 beefy_voter_links,
                 beefy_rpc_links,
                 frontier_backend,
@@ -542,8 +553,9 @@ where
 
     let beefy_gossip_proto_name =
         sc_consensus_beefy::gossip_protocol_name(genesis_hash, config.chain_spec.fork_id());
+    let beefy_fork_id = config.chain_spec.fork_id().map(ToString::to_string);
     let (beefy_on_demand_justifications_handler, beefy_req_resp_cfg) =
-        sc_consensus_beefy::communication::request_response::BeefyJustifsRequestHandler::new::<_, N>(
+        BeefyJustifsRequestHandler::new::<_, N>(
             &genesis_hash,
             config.chain_spec.fork_id(),
             client.clone(),
@@ -660,6 +672,10 @@ where
     > = Default::default();
     let pubsub_notification_sinks = Arc::new(pubsub_notification_sinks);
 
+    // Only the key-value backend is backfilled, the SQL worker re-indexes missing blocks itself.
+    let frontier_backfill = matches!(&*frontier_backend, fc_db::Backend::KeyValue(_))
+        .then(crate::frontier_backfill::BackfillStatus::default);
+
     spawn_frontier_tasks(
         &task_manager,
         FrontierTasksParams {
@@ -674,12 +690,32 @@ where
             storage_override,
             sync: sync_service.clone(),
             pubsub_notification_sinks: pubsub_notification_sinks.clone(),
+            frontier_backfill: frontier_backfill.clone(),
+            prometheus_registry: prometheus_registry.clone(),
         },
     )
     .await;
 
+    if bridge_archive {
+        task_manager.spawn_handle().spawn(
+            "bridge-archive",
+            None,
+            crate::bridge_archive::run_bridge_archive::<_, FullBackend>(client.clone()),
+        );
+    }
+
     let base_path = config.base_path.path().to_path_buf().clone();
 
+    let beefy_best_block_stream = beefy_rpc_links.from_voter_best_beefy_stream.clone();
+
+    let shared_voter_state = SharedVoterState::empty();
+    let shared_authority_set = grandpa_link.shared_authority_set().clone();
+    let justification_stream = grandpa_link.justification_stream();
+    let finality_proof_provider = sc_consensus_grandpa::FinalityProofProvider::new_for_service(
+        backend.clone(),
+        Some(shared_authority_set.clone()),
+    );
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
@@ -691,7 +727,17 @@ where
         let fee_history_cache = fee_history_cache.clone();
         let block_data_cache = block_data_cache.clone();
         let fee_history_limit = eth_config.fee_history_limit;
+        let evm_tracing = (!eth_config.ethapi.is_empty()).then(|| EvmTracingConfig {
+            debug: eth_config.ethapi.contains(&EthApi::Debug),
+            trace: eth_config.ethapi.contains(&EthApi::Trace),
+            max_permits: eth_config.ethapi_max_permits,
+            trace_max_count: eth_config.ethapi_trace_max_count,
+        });
+        let beacon_genesis_time = crate::readiness::beacon_genesis_time(&*config.chain_spec);
         let sync = sync_service.clone();
+        let keystore = keystore_container.keystore();
+        let select_chain = select_chain.clone();
+        let shared_voter_state = shared_voter_state.clone();
 
         Box::new(
             move |subscription_executor: sc_rpc::SubscriptionTaskExecutor| {
@@ -708,6 +754,18 @@ where
                             .clone(),
                         subscription_executor: subscription_executor.clone(),
                     },
+                    babe: BabeDeps {
+                        babe_worker_handle: babe_worker_handle.clone(),
+                        keystore: keystore.clone(),
+                    },
+                    select_chain: select_chain.clone(),
+                    grandpa: GrandpaDeps {
+                        shared_voter_state: shared_voter_state.clone(),
+                        shared_authority_set: shared_authority_set.clone(),
+                        justification_stream: justification_stream.clone(),
+                        subscription_executor: subscription_executor.clone(),
+                        finality_proof_provider: finality_proof_provider.clone(),
+                    },
                     max_past_logs,
                     fee_history_limit,
                     fee_history_cache: fee_history_cache.clone(),
@@ -724,6 +782,10 @@ where
                         fc_db::Backend::Sql(b) => b.clone(),
                     },
                     forced_parent_hashes: None,
+                    frontier_backfill: frontier_backfill.clone(),
+                    beacon_genesis_time,
+                    bridge_archive,
+                    evm_tracing,
                     maybe_storage_hub_client_config: maybe_storage_hub_client_rpc_config.clone(),
                 };
                 crate::rpc::create_full(
@@ -907,7 +969,7 @@ where
             notification_service: grandpa_notification_service,
             voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
             prometheus_registry: prometheus_registry.clone(),
-            shared_voter_state: SharedVoterState::empty(),
+            shared_voter_state,
             telemetry: telemetry.as_ref().map(|x| x.handle()),
             offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
         };
@@ -933,38 +995,95 @@ where
         if let Some(notification_service) = beefy_notification_service {
             let justifications_protocol_name =
                 beefy_on_demand_justifications_handler.protocol_name();
-            let network_params = sc_consensus_beefy::BeefyNetworkParams {
-                network: Arc::new(network.clone()),
-                sync: sync_service.clone(),
-                gossip_protocol_name: beefy_gossip_proto_name,
-                justifications_protocol_name,
-                notification_service,
-                _phantom: core::marker::PhantomData::<Block>,
-            };
-            let payload_provider = sp_consensus_beefy::mmr::MmrRootProvider::new(client.clone());
-            let beefy_params = sc_consensus_beefy::BeefyParams {
-                client: client.clone(),
-                backend: backend.clone(),
-                payload_provider,
-                runtime: client.clone(),
-                key_store: keystore_opt.clone(),
-                network_params,
-                min_block_delta: 8,
-                prometheus_registry: prometheus_registry.clone(),
-                links: beefy_voter_links,
-                on_demand_justifications_handler: beefy_on_demand_justifications_handler,
-                is_authority: role.is_authority(),
-            };
 
-            let gadget = sc_consensus_beefy::start_beefy_gadget::<_, _, _, _, _, _, _, BeefyId>(
-                beefy_params,
+            // The justifications handler registered on the network serves requests from the
+            // database, independently of the voter, so it runs on its own and outlives gadget
+            // restarts.
+            let mut on_demand_justifications_handler = beefy_on_demand_justifications_handler;
+            task_manager.spawn_essential_handle().spawn(
+                "beefy-justifications-handler",
+                None,
+                async move { on_demand_justifications_handler.run().await },
             );
 
+            let start_gadget = {
+                let client = client.clone();
+                let backend = backend.clone();
+                let network = network.clone();
+                let sync_service = sync_service.clone();
+                let keystore_opt = keystore_opt.clone();
+                let prometheus_registry = prometheus_registry.clone();
+                let is_authority = role.is_authority();
+
+                move |notification_service: Box<dyn NotificationService>, restarted: bool| {
+                    let network_params = sc_consensus_beefy::BeefyNetworkParams {
+                        network: Arc::new(network.clone()),
+                        sync: sync_service.clone(),
+                        gossip_protocol_name: beefy_gossip_proto_name.clone(),
+                        justifications_protocol_name: justifications_protocol_name.clone(),
+                        notification_service,
+                        _phantom: core::marker::PhantomData::<Block>,
+                    };
+
+                    // Each gadget needs a handler of its own. This one is not registered on
+                    // the network and never receives requests; its protocol config must be kept
+                    // alive for as long as the gadget runs.
+                    let (idle_justifications_handler, idle_req_resp_cfg) =
+                        BeefyJustifsRequestHandler::new::<_, N>(
+                            &genesis_hash,
+                            beefy_fork_id.as_deref(),
+                            client.clone(),
+                            None,
+                        );
+
+                    let payload_provider =
+                        sp_consensus_beefy::mmr::MmrRootProvider::new(client.clone());
+                    let beefy_params = sc_consensus_beefy::BeefyParams {
+                        client: client.clone(),
+                        backend: backend.clone(),
+                        payload_provider,
+                        runtime: client.clone(),
+                        key_store: keystore_opt.clone(),
+                        network_params,
+                        min_block_delta: 8,
+                        // Metrics can only be registered once per registry.
+                        prometheus_registry: if restarted {
+                            None
+                        } else {
+                            prometheus_registry.clone()
+                        },
+                        links: beefy_voter_links.clone(),
+                        on_demand_justifications_handler: idle_justifications_handler,
+                        is_authority,
+                    };
+
+                    let gadget =
+                        sc_consensus_beefy::start_beefy_gadget::<_, _, _, _, _, _, _, BeefyId>(
+                            beefy_params,
+                        );
+
+                    async move {
+                        let _idle_req_resp_cfg = idle_req_resp_cfg;
+                        gadget.await
+                    }
+                }
+            };
+
             // BEEFY is part of consensus, if it fails we'll bring the node down with it to make
-            // sure it is noticed.
-            task_manager
-                .spawn_essential_handle()
-                .spawn_blocking("beefy-gadget", None, gadget);
+            // sure it is noticed. A stalled voter does not fail though, so the gadget runs under
+            // a watchdog restarting it when it stops following finality.
+            task_manager.spawn_essential_handle().spawn_blocking(
+                "beefy-gadget",
+                None,
+                run_supervised_beefy_gadget(BeefyWatchdogParams {
+                    client: client.clone(),
+                    sync: sync_service.clone(),
+                    best_beefy_stream: beefy_best_block_stream,
+                    notification_service,
+                    start_gadget,
+                    prometheus_registry: prometheus_registry.clone(),
+                }),
+            );
         }
 
         // Spawn MMR gadget for offchain MMR leaf indexing.
@@ -1009,6 +1128,7 @@ pub async fn new_full<
     role_options: Option<RoleOptions>,
     indexer_options: Option<IndexerOptions>,
     sealing: Option<Sealing>,
+    bridge_archive: bool,
 ) -> Result<TaskManager, ServiceError>
 where
     Runtime: shc_common::traits::StorageEnableRuntime<RuntimeApi = RuntimeApi>,
@@ -1028,6 +1148,7 @@ where
                     Some(role_options),
                     indexer_options,
                     sealing,
+                    bridge_archive,
                 )
                 .await;
             }
@@ -1042,6 +1163,7 @@ where
                     Some(role_options),
                     indexer_options,
                     sealing,
+                    bridge_archive,
                 )
                 .await;
             }
@@ -1056,6 +1178,7 @@ where
                     Some(role_options),
                     indexer_options,
                     sealing,
+                    bridge_archive,
                 )
                 .await;
             }
@@ -1070,6 +1193,7 @@ where
                     Some(role_options),
                     indexer_options,
                     sealing,
+                    bridge_archive,
                 )
                 .await;
             }
@@ -1080,6 +1204,7 @@ where
                     Some(role_options),
                     indexer_options,
                     sealing,
+                    bridge_archive,
                 )
                 .await;
             }
@@ -1091,6 +1216,7 @@ where
             None,
             indexer_options,
             sealing,
+            bridge_archive,
         )
         .await;
     };
@@ -0,0 +1,106 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Per-era validator performance report.
+//!
+//! `datahaven_validatorPerformance` returns, for each validator of an era, the online sessions
+//! and the authored blocks the session performance points were computed from, read from the
+//! best block state. Eras older than the rewards history depth are pruned and return an
+//! empty list.
+
+use datahaven_runtime_common::{AccountId, Block};
+use fc_rpc::internal_err;
+use jsonrpsee::{core::RpcResult, RpcModule};
+use pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use std::sync::Arc;
+
+/// Performance of a validator over an era, as returned by `datahaven_validatorPerformance`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorPerformanceReport {
+    /// Account of the validator.
+    pub validator: AccountId,
+    /// Number of sessions the validator was accounted in.
+    pub sessions: u32,
+    /// Number of those sessions it was considered online in.
+    pub sessions_online: u32,
+    /// Number of blocks it authored.
+    pub blocks_authored: u32,
+    /// Number of authored blocks credited, capped by the fair share of each session.
+    pub credited_blocks: u32,
+    /// Sum of the fair shares of blocks of its sessions.
+    pub expected_blocks: u32,
+    /// Average over the sessions of the weighted performance score, between 0 and 1.
+    pub weighted_score: f64,
+    /// Session performance points earned in the era.
+    pub points: u32,
+}
+
+/// Serves the performance report of the validators.
+pub(crate) struct ValidatorPerformance<C> {
+    client: Arc<C>,
+}
+
+impl<C> ValidatorPerformance<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ExternalValidatorsRewardsApi<Block, AccountId>,
+{
+    pub(crate) fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    /// RPC module with the `datahaven_validatorPerformance` method.
+    pub(crate) fn into_rpc(self) -> RpcModule<Self> {
+        let mut module = RpcModule::new(self);
+        module
+            .register_method(
+                "datahaven_validatorPerformance",
+                |params, performance, _| -> RpcResult<Vec<ValidatorPerformanceReport>> {
+                    let era: u32 = params.one()?;
+                    performance.report(era)
+                },
+            )
+            .expect("the method is registered once; qed");
+        module
+    }
+
+    fn report(&self, era: u32) -> RpcResult<Vec<ValidatorPerformanceReport>> {
+        let best_hash = self.client.info().best_hash;
+        let performances = self
+            .client
+            .runtime_api()
+            .validator_performance(best_hash, era)
+            .map_err(|err| internal_err(format!("Runtime API call failed: {err}")))?;
+
+        Ok(performances
+            .into_iter()
+            .map(|(validator, performance)| ValidatorPerformanceReport {
+                validator,
+                sessions: performance.sessions,
+                sessions_online: performance.sessions_online,
+                blocks_authored: performance.blocks_authored,
+                credited_blocks: performance.credited_blocks,
+                expected_blocks: performance.expected_blocks,
+                weighted_score: performance.weighted_score.to_float(),
+                points: performance.points,
+            })
+            .collect())
+    }
+}
@@ -18,9 +18,12 @@
 
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_support::traits::{fungible::Mutate, EnsureOrigin};
+use frame_support::traits::{
+    fungible::{InspectHold, Mutate, MutateHold},
+    EnsureOrigin,
+};
 use frame_system::RawOrigin;
-use sp_core::H160;
+use sp_core::{H160, H256};
 
 // Helper function to create a funded account
 fn create_funded_account<T: Config>(seed: u32, amount: BalanceOf<T>) -> T::AccountId {
@@ -34,10 +37,40 @@ fn ethereum_address(seed: u8) -> H160 {
     H160::from_low_u64_be(seed as u64)
 }
 
+// Helper function to queue a large transfer of 10k units, returning its id
+fn setup_large_transfer<T: Config>() -> u64
+where
+    BalanceOf<T>: From<u128>,
+{
+    let amount: BalanceOf<T> = (10_000 * 1_000_000_000u128).into(); // 10k units
+    let fee: BalanceOf<T> = (100 * 1_000_000_000u128).into(); // 100 units
+    let existential_deposit: BalanceOf<T> = T::Currency::minimum_balance();
+    let sender = create_funded_account::<T>(1, amount + fee + existential_deposit);
+    let id = 0;
+
+    T::Currency::hold(&HoldReason::LargeTransfer.into(), &sender, amount + fee)
+        .expect("the sender is funded; qed");
+    let execute_at = T::LargeTransferDelay::get();
+    PendingLargeTransfers::<T>::insert(
+        id,
+        PendingLargeTransfer {
+            sender,
+            recipient: ethereum_address(42),
+            amount,
+            fee,
+            execute_at,
+        },
+    );
+    LargeTransferQueue::<T>::insert(execute_at, id, ());
+
+    id
+}
+
 #[benchmarks(
     where
         T: Config,
         <T as Config>::PauseOrigin: EnsureOrigin<T::RuntimeOrigin>,
+        <T as Config>::SecurityOrigin: EnsureOrigin<T::RuntimeOrigin>,
         BalanceOf<T>: From<u128>,
 )]
 mod benchmarks {
@@ -56,12 +89,15 @@ mod benchmarks {
         let sender = create_funded_account::<T>(1, total_needed);
         let recipient = ethereum_address(42);
 
-        // Check the initial balance of the fee recipient
-        let initial_fee_recipient_balance = T::Currency::balance(&T::FeeRecipient::get());
-
         // Ensure pallet is not paused
         Paused::<T>::put(false);
 
+        // Worst case: both limits are checked and reached
+        OutboundLimits::<T>::put(TransferLimits {
+            per_block: Some(amount),
+            per_era: Some(amount),
+        });
+
         #[extrinsic_call]
         transfer_to_ethereum(RawOrigin::Signed(sender.clone()), recipient, amount, fee);
 
@@ -71,8 +107,8 @@ mod benchmarks {
             amount
         );
         assert_eq!(
-            T::Currency::balance(&T::FeeRecipient::get()),
-            initial_fee_recipient_balance + fee
+            T::Currency::balance_on_hold(&HoldReason::TransferFee.into(), &sender),
+            fee
         );
 
         Ok(())
@@ -114,6 +150,111 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn refund_transfer_fee() -> Result<(), BenchmarkError> {
+        // Setup
+        let fee: BalanceOf<T> = (100 * 1_000_000_000u128).into(); // 100 units
+        let existential_deposit: BalanceOf<T> = T::Currency::minimum_balance();
+        let sender = create_funded_account::<T>(1, fee + existential_deposit);
+        let message_id = H256::repeat_byte(1);
+
+        T::Currency::hold(&HoldReason::TransferFee.into(), &sender, fee)?;
+        PendingTransferFees::<T>::insert(
+            message_id,
+            PendingTransferFee {
+                payer: sender.clone(),
+                fee,
+                refund_at: T::FeeRefundTimeout::get(),
+            },
+        );
+        TransferReceipts::<T>::insert(
+            message_id,
+            TransferReceipt {
+                sender: sender.clone(),
+                recipient: ethereum_address(1),
+                amount: fee,
+                fee,
+                sent_at: Zero::zero(),
+                status: TransferStatus::Pending,
+            },
+        );
+
+        #[block]
+        {
+            Pallet::<T>::settle_transfer_fee(message_id, Some(FeeRefundReason::Timeout));
+        }
+
+        // Verify
+        assert!(!PendingTransferFees::<T>::contains_key(message_id));
+        assert_eq!(
+            TransferReceipts::<T>::get(message_id).map(|receipt| receipt.status),
+            Some(TransferStatus::TimedOut)
+        );
+        assert_eq!(
+            T::Currency::balance_on_hold(&HoldReason::TransferFee.into(), &sender),
+            0u128.into()
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_transfer_limits() -> Result<(), BenchmarkError> {
+        // Setup
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let limit: BalanceOf<T> = (10_000 * 1_000_000_000u128).into(); // 10k units
+        let limits = TransferLimits {
+            per_block: Some(limit),
+            per_era: Some(limit),
+        };
+
+        #[extrinsic_call]
+        set_transfer_limits(origin as T::RuntimeOrigin, limits.clone());
+
+        // Verify
+        assert_eq!(OutboundLimits::<T>::get(), limits);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_large_transfer() -> Result<(), BenchmarkError> {
+        // Setup
+        let origin =
+            T::SecurityOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let id = setup_large_transfer::<T>();
+
+        #[extrinsic_call]
+        cancel_large_transfer(origin as T::RuntimeOrigin, id);
+
+        // Verify
+        assert!(!PendingLargeTransfers::<T>::contains_key(id));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_large_transfer() -> Result<(), BenchmarkError> {
+        // Setup
+        let id = setup_large_transfer::<T>();
+        Paused::<T>::put(false);
+
+        #[block]
+        {
+            Pallet::<T>::execute_large_transfer(id);
+        }
+
+        // Verify
+        assert!(!PendingLargeTransfers::<T>::contains_key(id));
+        assert_eq!(
+            T::Currency::balance(&T::EthereumSovereignAccount::get()),
+            (10_000 * 1_000_000_000u128).into()
+        );
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         DataHavenNativeTransfer,
         crate::mock::new_test_ext(),
@@ -24,9 +24,27 @@
 //! - Transfer DataHaven native tokens to Ethereum via Snowbridge
 //! - Lock tokens during outbound transfers
 //! - Unlock tokens when they return from Ethereum
+//! - Mint tokens for deposits escrowed on Ethereum, once the deposit is relayed and verified
 //! - Integration with Snowbridge outbound queue for message passing
+//! - Refund of the relayer fee of transfers whose message fails on Ethereum
 //!
 //! It uses a dedicated Ethereum sovereign account to hold locked tokens during transfers.
+//!
+//! The relayer fee of a transfer is held from the sender until the delivery of its message is
+//! settled: it goes to `FeeRecipient` once a delivery receipt proves that the message was
+//! executed, and is released back to the sender if the receipt reports a failure, or if no
+//! receipt arrives within `FeeRefundTimeout` blocks.
+//!
+//! Each transfer sent leaves a receipt, by message id, recording its sender, recipient, amount,
+//! fee and delivery status. Receipts are removed `TransferReceiptRetention` blocks after the
+//! transfer.
+//!
+//! Governance can cap the amount transferred to Ethereum per block and per era, to bound the
+//! losses of a compromised bridge. Transfers that would go over a cap are rejected.
+//!
+//! Transfers of at least `LargeTransferThreshold` are not sent right away: their amount and
+//! fee are held for `LargeTransferDelay` blocks, during which `SecurityOrigin` can cancel
+//! them. Those which are not cancelled are executed automatically once the delay is over.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
@@ -34,15 +52,27 @@ extern crate alloc;
 use alloc::vec;
 use frame_support::{
     pallet_prelude::*,
+    storage::with_storage_layer,
     traits::{
-        fungible::{Inspect, Mutate},
-        tokens::Preservation,
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
     },
 };
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_external_validators::traits::{
+    EraIndex, EraIndexProvider, OnOutboundMessageSent, OutboundMessageKind,
+};
+use parity_scale_codec::DecodeWithMemTracking;
 use snowbridge_core::TokenId;
-use snowbridge_outbound_queue_primitives::v2::{Command, Message as OutboundMessage, SendMessage};
+use snowbridge_outbound_queue_primitives::{
+    v2::{Command, Message as OutboundMessage, SendMessage},
+    SendMessageFeeProvider,
+};
 use sp_core::{H160, H256};
-use sp_runtime::{traits::Saturating, BoundedVec};
+use sp_runtime::{
+    traits::{BlakeTwo256, CheckedAdd, Hash, Saturating, Zero},
+    BoundedVec,
+};
 
 pub use pallet::*;
 
@@ -60,6 +90,147 @@ pub use weights::WeightInfo;
 type BalanceOf<T> =
     <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Relayer fee of a transfer to Ethereum, held until the delivery of its message is settled.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransferFee<AccountId, Balance, BlockNumber> {
+    /// Account the fee is held from.
+    pub payer: AccountId,
+    /// Held fee.
+    pub fee: Balance,
+    /// Block at which the fee is refunded if the delivery is still not acknowledged.
+    pub refund_at: BlockNumber,
+}
+
+/// Transfer to Ethereum waiting for its delay to be over, see `LargeTransferThreshold`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingLargeTransfer<AccountId, Balance, BlockNumber> {
+    /// Account the amount and fee are held from.
+    pub sender: AccountId,
+    /// Ethereum address receiving the tokens.
+    pub recipient: H160,
+    /// Amount transferred.
+    pub amount: Balance,
+    /// Relayer fee of the transfer.
+    pub fee: Balance,
+    /// Block at which the transfer is executed.
+    pub execute_at: BlockNumber,
+}
+
+/// Record of a transfer to Ethereum, kept for `TransferReceiptRetention` blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TransferReceipt<AccountId, Balance, BlockNumber> {
+    /// Account the tokens were transferred from.
+    pub sender: AccountId,
+    /// Ethereum address receiving the tokens.
+    pub recipient: H160,
+    /// Amount transferred.
+    pub amount: Balance,
+    /// Relayer fee of the transfer.
+    pub fee: Balance,
+    /// Block in which the message of the transfer was sent.
+    pub sent_at: BlockNumber,
+    /// Delivery status of the message.
+    pub status: TransferStatus,
+}
+
+/// Delivery status of a transfer to Ethereum.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TransferStatus {
+    /// The delivery of the message is not acknowledged yet.
+    Pending,
+    /// The delivery receipt reported that the message succeeded on Ethereum.
+    Delivered,
+    /// The delivery receipt reported that the message failed on Ethereum.
+    Failed,
+    /// No delivery receipt arrived within `FeeRefundTimeout` blocks.
+    TimedOut,
+}
+
+/// Why the fee of a transfer was refunded.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FeeRefundReason {
+    /// The delivery receipt reported that the message failed on Ethereum.
+    DeliveryFailed,
+    /// No delivery receipt arrived within `FeeRefundTimeout` blocks.
+    Timeout,
+}
+
+/// Caps on the amount transferred to Ethereum, `None` meaning no cap.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct TransferLimits<Balance> {
+    /// Maximum amount transferred in a block.
+    pub per_block: Option<Balance>,
+    /// Maximum amount transferred in an era.
+    pub per_era: Option<Balance>,
+}
+
+/// Period a transfer limit applies to.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TransferLimitPeriod {
+    /// The current block.
+    Block,
+    /// The active era.
+    Era,
+}
+
+/// Notified of the tokens locked for transfers to Ethereum and returned from it.
+pub trait NativeTransferLedger<Balance> {
+    /// `amount` was locked in the Ethereum sovereign account.
+    fn on_tokens_locked(amount: Balance);
+
+    /// `amount` was unlocked from the Ethereum sovereign account.
+    fn on_tokens_unlocked(amount: Balance);
+
+    /// `amount` was minted for a deposit escrowed on Ethereum.
+    fn on_tokens_minted(amount: Balance);
+
+    /// `amount` was burned by a transfer to Ethereum.
+    fn on_tokens_burned(amount: Balance);
+}
+
+impl<Balance> NativeTransferLedger<Balance> for () {
+    fn on_tokens_locked(_: Balance) {}
+
+    fn on_tokens_unlocked(_: Balance) {}
+
+    fn on_tokens_minted(_: Balance) {}
+
+    fn on_tokens_burned(_: Balance) {}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -75,7 +246,11 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// The currency used for reserves
-        type Currency: Mutate<Self::AccountId>;
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
 
         /// The sovereign account for Ethereum bridge reserves
         /// This should be derived from the Ethereum location using
@@ -83,20 +258,50 @@ pub mod pallet {
         #[pallet::constant]
         type EthereumSovereignAccount: Get<Self::AccountId>;
 
-        /// The Snowbridge outbound queue for sending messages to Ethereum
-        type OutboundQueue: SendMessage;
+        /// The Snowbridge outbound queue for sending messages to Ethereum, which also
+        /// provides the minimum fee of a transfer
+        type OutboundQueue: SendMessage + SendMessageFeeProvider<Balance = BalanceOf<Self>>;
 
         /// Account to receive bridge fees
         type FeeRecipient: Get<Self::AccountId>;
 
+        /// Number of blocks after which the fee of a transfer whose delivery was not
+        /// acknowledged is refunded
+        #[pallet::constant]
+        type FeeRefundTimeout: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks the receipt of a transfer is kept after its message was sent,
+        /// it should be longer than `FeeRefundTimeout` for the receipt to record the outcome
+        #[pallet::constant]
+        type TransferReceiptRetention: Get<BlockNumberFor<Self>>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
 
-        /// Origin that can pause/unpause the pallet
+        /// Origin that can pause/unpause the pallet and set the transfer limits
         type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// Provides the native token ID if registered, None if not registered
         type NativeTokenId: Get<Option<TokenId>>;
+
+        /// Notified of the tokens locked and unlocked
+        type Ledger: NativeTransferLedger<BalanceOf<Self>>;
+
+        /// Notified of the transfer messages accepted by the outbound queue
+        type OnOutboundMessageSent: OnOutboundMessageSent;
+
+        /// Provides the active era, the period of the per-era transfer limit
+        type EraIndexProvider: EraIndexProvider;
+
+        /// Transfers of at least this amount are delayed by `LargeTransferDelay` blocks,
+        /// zero disables the delay
+        type LargeTransferThreshold: Get<BalanceOf<Self>>;
+
+        /// Number of blocks a large transfer waits before it is executed
+        type LargeTransferDelay: Get<BlockNumberFor<Self>>;
+
+        /// Origin that can cancel a pending large transfer
+        type SecurityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::storage]
@@ -104,6 +309,102 @@ pub mod pallet {
     /// Whether the pallet is paused
     pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
 
+    /// Ethereum deposits already credited, by deposit id, so a deposit is never minted twice.
+    #[pallet::storage]
+    pub type ProcessedDeposits<T> = StorageMap<_, Blake2_128Concat, H256, (), OptionQuery>;
+
+    /// Fees held for the transfers whose delivery is not settled yet, by message id.
+    #[pallet::storage]
+    pub type PendingTransferFees<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        PendingTransferFee<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Message ids of the pending transfer fees, by the block at which they are refunded.
+    #[pallet::storage]
+    pub type FeeRefundQueue<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, H256, (), OptionQuery>;
+
+    /// Receipts of the transfers to Ethereum, by message id.
+    #[pallet::storage]
+    pub type TransferReceipts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        TransferReceipt<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Message ids of the transfer receipts, by the block at which they are removed.
+    #[pallet::storage]
+    pub type TransferReceiptExpiry<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, H256, (), OptionQuery>;
+
+    /// Caps on the amount transferred to Ethereum.
+    #[pallet::storage]
+    pub type OutboundLimits<T: Config> = StorageValue<_, TransferLimits<BalanceOf<T>>, ValueQuery>;
+
+    /// Amount transferred to Ethereum in a block, with the block number.
+    ///
+    /// Only the latest block is kept, an older entry counts as nothing transferred.
+    #[pallet::storage]
+    pub type BlockOutbound<T: Config> =
+        StorageValue<_, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+    /// Amount transferred to Ethereum in an era, with the era index.
+    ///
+    /// Only the latest era is kept, an older entry counts as nothing transferred.
+    #[pallet::storage]
+    pub type EraOutbound<T: Config> = StorageValue<_, (EraIndex, BalanceOf<T>), OptionQuery>;
+
+    /// Caps on the amount minted for deposits escrowed on Ethereum.
+    #[pallet::storage]
+    pub type DepositLimits<T: Config> = StorageValue<_, TransferLimits<BalanceOf<T>>, ValueQuery>;
+
+    /// Amount minted for deposits in a block, with the block number.
+    ///
+    /// Only the latest block is kept, an older entry counts as nothing minted.
+    #[pallet::storage]
+    pub type BlockDeposits<T: Config> =
+        StorageValue<_, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+    /// Amount minted for deposits in an era, with the era index.
+    ///
+    /// Only the latest era is kept, an older entry counts as nothing minted.
+    #[pallet::storage]
+    pub type EraDeposits<T: Config> = StorageValue<_, (EraIndex, BalanceOf<T>), OptionQuery>;
+
+    /// Id of the next large transfer.
+    #[pallet::storage]
+    pub type NextLargeTransferId<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Large transfers waiting for their delay to be over, by id.
+    #[pallet::storage]
+    pub type PendingLargeTransfers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        PendingLargeTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Ids of the pending large transfers, by the block at which they are executed.
+    #[pallet::storage]
+    pub type LargeTransferQueue<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, u64, (), OptionQuery>;
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Relayer fee of a transfer to Ethereum, until the delivery of its message is settled.
+        TransferFee,
+        /// Amount and fee of a large transfer to Ethereum, until it is executed or cancelled.
+        LargeTransfer,
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -126,6 +427,72 @@ pub mod pallet {
             amount: BalanceOf<T>,
         },
 
+        /// Tokens minted for a deposit escrowed on Ethereum
+        DepositMinted {
+            deposit_id: H256,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// The delivery of a transfer was acknowledged and its fee paid to the fee recipient
+        TransferFeePaid {
+            message_id: H256,
+            account: T::AccountId,
+            fee: BalanceOf<T>,
+        },
+
+        /// The fee of a transfer was released back to its sender
+        TransferFeeRefunded {
+            message_id: H256,
+            account: T::AccountId,
+            fee: BalanceOf<T>,
+            reason: FeeRefundReason,
+        },
+
+        /// The transfer limits were set
+        TransferLimitsSet {
+            limits: TransferLimits<BalanceOf<T>>,
+        },
+
+        /// The amount transferred in the period reached its limit, further transfers are
+        /// rejected until the next one
+        TransferLimitReached {
+            period: TransferLimitPeriod,
+            limit: BalanceOf<T>,
+        },
+
+        /// The deposit limits were set
+        DepositLimitsSet {
+            limits: TransferLimits<BalanceOf<T>>,
+        },
+
+        /// The amount minted for deposits in the period reached its limit, further deposits
+        /// are rejected until the next one
+        DepositLimitReached {
+            period: TransferLimitPeriod,
+            limit: BalanceOf<T>,
+        },
+
+        /// A large transfer was queued, it is executed at `execute_at` unless cancelled
+        LargeTransferQueued {
+            id: u64,
+            from: T::AccountId,
+            to: H160,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+            execute_at: BlockNumberFor<T>,
+        },
+
+        /// A large transfer was executed after its delay
+        LargeTransferExecuted { id: u64 },
+
+        /// A large transfer was cancelled and its amount and fee released to its sender
+        LargeTransferCancelled { id: u64 },
+
+        /// A large transfer could not be executed, its amount and fee were released to its
+        /// sender
+        LargeTransferFailed { id: u64, error: DispatchError },
+
         /// Pallet paused
         Paused,
 
@@ -149,10 +516,48 @@ pub mod pallet {
         TransfersDisabled,
         /// Fee cannot be zero
         ZeroFee,
+        /// The fee does not cover the processing of the message, see `quote_transfer_fee`
+        FeeTooLow,
         /// Native token has not been registered on Ethereum yet
         TokenNotRegistered,
         /// Insufficient balance in Ethereum sovereign account
         InsufficientSovereignBalance,
+        /// The deposit has already been credited
+        DepositAlreadyProcessed,
+        /// The transfer would exceed the amount allowed in the block or the era
+        TransferLimitExceeded,
+        /// The deposit would exceed the amount allowed to be minted in the block or the era
+        DepositLimitExceeded,
+        /// No pending large transfer has this id
+        UnknownLargeTransfer,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut refunds = 0u64;
+            for (message_id, ()) in FeeRefundQueue::<T>::drain_prefix(now) {
+                Self::settle_transfer_fee(message_id, Some(FeeRefundReason::Timeout));
+                refunds += 1;
+            }
+            let mut large_transfers = 0u64;
+            for (id, ()) in LargeTransferQueue::<T>::drain_prefix(now) {
+                Self::execute_large_transfer(id);
+                large_transfers += 1;
+            }
+            let mut expired_receipts = 0u64;
+            for (message_id, ()) in TransferReceiptExpiry::<T>::drain_prefix(now) {
+                TransferReceipts::<T>::remove(message_id);
+                expired_receipts += 1;
+            }
+            T::DbWeight::get()
+                .reads(3)
+                .saturating_add(T::DbWeight::get().writes(expired_receipts.saturating_mul(2)))
+                .saturating_add(T::WeightInfo::refund_transfer_fee().saturating_mul(refunds))
+                .saturating_add(
+                    T::WeightInfo::execute_large_transfer().saturating_mul(large_transfers),
+                )
+        }
     }
 
     #[pallet::call]
@@ -160,7 +565,11 @@ pub mod pallet {
         /// Transfer DataHaven native tokens to Ethereum
         ///
         /// Locks the tokens in the vault and sends a message through Snowbridge
-        /// to mint the equivalent tokens on Ethereum.
+        /// to mint the equivalent tokens on Ethereum. The fee is held until the delivery
+        /// of the message is settled.
+        ///
+        /// Transfers of at least `LargeTransferThreshold` are queued instead, and executed
+        /// after `LargeTransferDelay` blocks unless cancelled by `SecurityOrigin`.
         ///
         /// Parameters:
         /// - `origin`: The account initiating the transfer
@@ -184,30 +593,17 @@ pub mod pallet {
 
             ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
             ensure!(fee > Zero::zero(), Error::<T>::ZeroFee);
+            ensure!(fee >= Self::quote_transfer_fee(), Error::<T>::FeeTooLow);
             ensure!(
                 recipient != H160::zero(),
                 Error::<T>::InvalidEthereumAddress
             );
 
-            // Transfer fee to recipient
-            T::Currency::transfer(&who, &T::FeeRecipient::get(), fee, Preservation::Preserve)?;
-
-            // Lock tokens in the sovereign account
-            Self::lock_tokens(&who, amount)?;
-
-            // Build and send the message
-            let message = Self::build_mint_message(token_id, recipient, amount, fee)?;
-            T::OutboundQueue::validate(&message)
-                .and_then(|ticket| T::OutboundQueue::deliver(ticket))
-                .map_err(|_| Error::<T>::SendMessageFailed)?;
-
-            Self::deposit_event(Event::TokensTransferredToEthereum {
-                from: who,
-                to: recipient,
-                amount,
-            });
+            if Self::is_large_transfer(amount) {
+                return Self::queue_large_transfer(who, recipient, amount, fee);
+            }
 
-            Ok(())
+            Self::do_transfer_to_ethereum(who, token_id, recipient, amount, fee)
         }
 
         /// Pause the pallet, preventing all transfers
@@ -235,6 +631,63 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set the caps on the amount transferred to Ethereum per block and per era
+        ///
+        /// The amounts already transferred in the current block and era count towards the
+        /// new limits.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_transfer_limits())]
+        pub fn set_transfer_limits(
+            origin: OriginFor<T>,
+            limits: TransferLimits<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            OutboundLimits::<T>::put(&limits);
+
+            Self::deposit_event(Event::TransferLimitsSet { limits });
+
+            Ok(())
+        }
+
+        /// Cancel a pending large transfer, releasing its amount and fee to its sender
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::cancel_large_transfer())]
+        pub fn cancel_large_transfer(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            T::SecurityOrigin::ensure_origin(origin)?;
+
+            let transfer =
+                PendingLargeTransfers::<T>::take(id).ok_or(Error::<T>::UnknownLargeTransfer)?;
+            LargeTransferQueue::<T>::remove(transfer.execute_at, id);
+            Self::release_large_transfer(&transfer);
+
+            Self::deposit_event(Event::LargeTransferCancelled { id });
+
+            Ok(())
+        }
+
+        /// Set the caps on the amount minted for deposits escrowed on Ethereum per block and
+        /// per era
+        ///
+        /// The amounts already minted in the current block and era count towards the new
+        /// limits. A deposit over the limits is rejected, and can be relayed again once the
+        /// next period allows it.
+        #[pallet::call_index(5)]
+        // Same storage accesses as `set_transfer_limits`
+        #[pallet::weight(T::WeightInfo::set_transfer_limits())]
+        pub fn set_deposit_limits(
+            origin: OriginFor<T>,
+            limits: TransferLimits<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            DepositLimits::<T>::put(&limits);
+
+            Self::deposit_event(Event::DepositLimitsSet { limits });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -269,6 +722,260 @@ pub mod pallet {
             })
         }
 
+        /// Lock `amount` and send the message minting it on Ethereum, holding `fee` until
+        /// the delivery of the message is settled
+        fn do_transfer_to_ethereum(
+            who: T::AccountId,
+            token_id: TokenId,
+            recipient: H160,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::record_outbound_amount(amount)?;
+
+            // Hold the fee until the delivery is settled
+            T::Currency::hold(&HoldReason::TransferFee.into(), &who, fee)?;
+
+            // Lock tokens in the sovereign account
+            Self::lock_tokens(&who, amount)?;
+
+            // Build and send the message
+            let message = Self::build_mint_message(token_id, recipient, amount, fee)?;
+            let message_id = T::OutboundQueue::validate(&message)
+                .and_then(|ticket| T::OutboundQueue::deliver(ticket))
+                .map_err(|_| Error::<T>::SendMessageFailed)?;
+            T::OnOutboundMessageSent::on_outbound_message_sent(
+                OutboundMessageKind::NativeTransfer,
+                None,
+                message_id,
+                BlakeTwo256::hash_of(&message),
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let refund_at = now.saturating_add(T::FeeRefundTimeout::get());
+            PendingTransferFees::<T>::insert(
+                message_id,
+                PendingTransferFee {
+                    payer: who.clone(),
+                    fee,
+                    refund_at,
+                },
+            );
+            FeeRefundQueue::<T>::insert(refund_at, message_id, ());
+
+            TransferReceipts::<T>::insert(
+                message_id,
+                TransferReceipt {
+                    sender: who.clone(),
+                    recipient,
+                    amount,
+                    fee,
+                    sent_at: now,
+                    status: TransferStatus::Pending,
+                },
+            );
+            TransferReceiptExpiry::<T>::insert(
+                now.saturating_add(T::TransferReceiptRetention::get()),
+                message_id,
+                (),
+            );
+
+            Self::deposit_event(Event::TokensTransferredToEthereum {
+                from: who,
+                to: recipient,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Whether a transfer of `amount` is delayed by `LargeTransferDelay` blocks
+        pub fn is_large_transfer(amount: BalanceOf<T>) -> bool {
+            let threshold = T::LargeTransferThreshold::get();
+            !threshold.is_zero() && amount >= threshold
+        }
+
+        /// Hold the amount and fee of a large transfer and queue it
+        fn queue_large_transfer(
+            who: T::AccountId,
+            recipient: H160,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let held = amount.checked_add(&fee).ok_or(Error::<T>::Overflow)?;
+            T::Currency::hold(&HoldReason::LargeTransfer.into(), &who, held)?;
+
+            let id = NextLargeTransferId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.wrapping_add(1);
+                id
+            });
+            let execute_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::LargeTransferDelay::get());
+            PendingLargeTransfers::<T>::insert(
+                id,
+                PendingLargeTransfer {
+                    sender: who.clone(),
+                    recipient,
+                    amount,
+                    fee,
+                    execute_at,
+                },
+            );
+            LargeTransferQueue::<T>::insert(execute_at, id, ());
+
+            Self::deposit_event(Event::LargeTransferQueued {
+                id,
+                from: who,
+                to: recipient,
+                amount,
+                fee,
+                execute_at,
+            });
+
+            Ok(())
+        }
+
+        /// Execute the pending large transfer `id`, whose delay is over
+        ///
+        /// The transfer is checked again as if it was submitted now. If it fails, its
+        /// amount and fee are released to its sender.
+        pub(crate) fn execute_large_transfer(id: u64) {
+            let Some(transfer) = PendingLargeTransfers::<T>::take(id) else {
+                return;
+            };
+
+            let result = with_storage_layer(|| -> DispatchResult {
+                Self::release_large_transfer(&transfer);
+                ensure!(!Paused::<T>::get(), Error::<T>::TransfersDisabled);
+                let token_id = T::NativeTokenId::get().ok_or(Error::<T>::TokenNotRegistered)?;
+                Self::do_transfer_to_ethereum(
+                    transfer.sender.clone(),
+                    token_id,
+                    transfer.recipient,
+                    transfer.amount,
+                    transfer.fee,
+                )
+            });
+
+            match result {
+                Ok(()) => Self::deposit_event(Event::LargeTransferExecuted { id }),
+                Err(error) => {
+                    Self::release_large_transfer(&transfer);
+                    Self::deposit_event(Event::LargeTransferFailed { id, error });
+                }
+            }
+        }
+
+        /// Release the amount and fee held for a large transfer
+        fn release_large_transfer(
+            transfer: &PendingLargeTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        ) {
+            let _ = T::Currency::release(
+                &HoldReason::LargeTransfer.into(),
+                &transfer.sender,
+                transfer.amount.saturating_add(transfer.fee),
+                Precision::BestEffort,
+            );
+        }
+
+        /// Count `amount` towards the transfer limits of the current block and era
+        ///
+        /// Fails if it would exceed either limit.
+        fn record_outbound_amount(amount: BalanceOf<T>) -> DispatchResult {
+            let limits = OutboundLimits::<T>::get();
+            let block = frame_system::Pallet::<T>::block_number();
+            let era = T::EraIndexProvider::active_era().index;
+            let limit_reached = |period, limit| Event::TransferLimitReached { period, limit };
+
+            let block_total = Self::period_total(
+                BlockOutbound::<T>::get(),
+                block,
+                amount,
+                limits.per_block,
+                TransferLimitPeriod::Block,
+                Error::<T>::TransferLimitExceeded,
+                limit_reached,
+            )?;
+            let era_total = Self::period_total(
+                EraOutbound::<T>::get(),
+                era,
+                amount,
+                limits.per_era,
+                TransferLimitPeriod::Era,
+                Error::<T>::TransferLimitExceeded,
+                limit_reached,
+            )?;
+
+            BlockOutbound::<T>::put((block, block_total));
+            EraOutbound::<T>::put((era, era_total));
+
+            Ok(())
+        }
+
+        /// Count `amount` towards the deposit limits of the current block and era
+        ///
+        /// Fails if it would exceed either limit.
+        fn record_deposit_amount(amount: BalanceOf<T>) -> DispatchResult {
+            let limits = DepositLimits::<T>::get();
+            let block = frame_system::Pallet::<T>::block_number();
+            let era = T::EraIndexProvider::active_era().index;
+            let limit_reached = |period, limit| Event::DepositLimitReached { period, limit };
+
+            let block_total = Self::period_total(
+                BlockDeposits::<T>::get(),
+                block,
+                amount,
+                limits.per_block,
+                TransferLimitPeriod::Block,
+                Error::<T>::DepositLimitExceeded,
+                limit_reached,
+            )?;
+            let era_total = Self::period_total(
+                EraDeposits::<T>::get(),
+                era,
+                amount,
+                limits.per_era,
+                TransferLimitPeriod::Era,
+                Error::<T>::DepositLimitExceeded,
+                limit_reached,
+            )?;
+
+            BlockDeposits::<T>::put((block, block_total));
+            EraDeposits::<T>::put((era, era_total));
+
+            Ok(())
+        }
+
+        /// Amount transferred in the `current` period once `amount` is added, checked
+        /// against `limit`
+        fn period_total<Period: PartialEq>(
+            recorded: Option<(Period, BalanceOf<T>)>,
+            current: Period,
+            amount: BalanceOf<T>,
+            limit: Option<BalanceOf<T>>,
+            period: TransferLimitPeriod,
+            exceeded: Error<T>,
+            limit_reached: impl Fn(TransferLimitPeriod, BalanceOf<T>) -> Event<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let transferred = recorded
+                .filter(|(recorded_period, _)| *recorded_period == current)
+                .map(|(_, transferred)| transferred)
+                .unwrap_or_default();
+            let total = transferred
+                .checked_add(&amount)
+                .ok_or(Error::<T>::Overflow)?;
+
+            if let Some(limit) = limit {
+                ensure!(total <= limit, exceeded);
+                if total == limit {
+                    Self::deposit_event(limit_reached(period, limit));
+                }
+            }
+
+            Ok(total)
+        }
+
         /// Lock tokens for transfer to Ethereum
         ///
         /// Transfers tokens from a user to the Ethereum sovereign account and updates tracking
@@ -280,6 +987,7 @@ pub mod pallet {
                 amount,
                 Preservation::Preserve,
             )?;
+            T::Ledger::on_tokens_locked(amount);
 
             Self::deposit_event(Event::TokensLocked {
                 account: who.clone(),
@@ -306,6 +1014,7 @@ pub mod pallet {
 
             // Transfer from the Ethereum sovereign account
             T::Currency::transfer(&sovereign, who, amount, Preservation::Preserve)?;
+            T::Ledger::on_tokens_unlocked(amount);
 
             Self::deposit_event(Event::TokensUnlocked {
                 account: who.clone(),
@@ -315,6 +1024,118 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Mint tokens for a deposit escrowed on Ethereum
+        ///
+        /// The deposit must have been verified by the caller, e.g. relayed through the
+        /// Snowbridge inbound queue. Each `deposit_id` is only credited once, within the
+        /// `DepositLimits` of the block and era.
+        pub fn mint_deposit(
+            deposit_id: H256,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::TransfersDisabled);
+            ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
+            ensure!(
+                !ProcessedDeposits::<T>::contains_key(deposit_id),
+                Error::<T>::DepositAlreadyProcessed
+            );
+            Self::record_deposit_amount(amount)?;
+
+            T::Currency::mint_into(who, amount)?;
+            T::Ledger::on_tokens_minted(amount);
+            ProcessedDeposits::<T>::insert(deposit_id, ());
+
+            Self::deposit_event(Event::DepositMinted {
+                deposit_id,
+                account: who.clone(),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Settle the fee of a transfer whose delivery to Ethereum was acknowledged
+        ///
+        /// Called with the result reported by the delivery receipt of the message
+        /// `message_id`. Messages which are not native transfers are ignored.
+        pub fn on_message_delivered(message_id: H256, success: bool) {
+            let Some(pending) = PendingTransferFees::<T>::get(message_id) else {
+                return;
+            };
+            FeeRefundQueue::<T>::remove(pending.refund_at, message_id);
+            Self::settle_transfer_fee(
+                message_id,
+                (!success).then_some(FeeRefundReason::DeliveryFailed),
+            );
+        }
+
+        /// Pay the pending fee of `message_id` to the fee recipient, or refund it to its
+        /// sender if `refund` is set, and record the outcome in the transfer receipt.
+        pub(crate) fn settle_transfer_fee(message_id: H256, refund: Option<FeeRefundReason>) {
+            let Some(PendingTransferFee { payer, fee, .. }) =
+                PendingTransferFees::<T>::take(message_id)
+            else {
+                return;
+            };
+            TransferReceipts::<T>::mutate(message_id, |receipt| {
+                if let Some(receipt) = receipt {
+                    receipt.status = match refund {
+                        None => TransferStatus::Delivered,
+                        Some(FeeRefundReason::DeliveryFailed) => TransferStatus::Failed,
+                        Some(FeeRefundReason::Timeout) => TransferStatus::TimedOut,
+                    };
+                }
+            });
+            let reason = HoldReason::TransferFee.into();
+
+            match refund {
+                None => {
+                    let paid = T::Currency::transfer_on_hold(
+                        &reason,
+                        &payer,
+                        &T::FeeRecipient::get(),
+                        fee,
+                        Precision::BestEffort,
+                        Restriction::Free,
+                        Fortitude::Polite,
+                    );
+                    match paid {
+                        Ok(fee) => {
+                            Self::deposit_event(Event::TransferFeePaid {
+                                message_id,
+                                account: payer,
+                                fee,
+                            });
+                        }
+                        Err(_) => {
+                            frame_support::defensive!("The fee recipient cannot receive a fee");
+                            let _ =
+                                T::Currency::release(&reason, &payer, fee, Precision::BestEffort);
+                        }
+                    }
+                }
+                Some(refund_reason) => {
+                    let fee = T::Currency::release(&reason, &payer, fee, Precision::BestEffort)
+                        .unwrap_or_default();
+                    Self::deposit_event(Event::TransferFeeRefunded {
+                        message_id,
+                        account: payer,
+                        fee,
+                        reason: refund_reason,
+                    });
+                }
+            }
+        }
+
+        /// Minimum fee of a transfer to Ethereum
+        ///
+        /// This is the cost of processing the message of the transfer on DataHaven, as charged
+        /// by the outbound queue. It does not depend on the amount transferred.
+        pub fn quote_transfer_fee() -> BalanceOf<T> {
+            T::OutboundQueue::local_fee()
+        }
+
         /// Get the balance of locked tokens in the Ethereum sovereign account
         /// This represents the total amount of tokens locked for transfers to Ethereum
         pub fn total_locked_balance() -> BalanceOf<T> {
@@ -21,7 +21,11 @@ use {
         traits::{ConstU32, Everything, Get},
     },
     frame_system::EnsureRoot,
-    snowbridge_outbound_queue_primitives::v2::{Message as OutboundMessage, SendMessage},
+    pallet_external_validators::traits::{ActiveEraInfo, EraIndex, EraIndexProvider},
+    snowbridge_outbound_queue_primitives::{
+        v2::{Message as OutboundMessage, SendMessage},
+        SendMessageFeeProvider,
+    },
     sp_core::H256,
     sp_runtime::{
         traits::{BlakeTwo256, IdentityLookup},
@@ -84,14 +88,14 @@ impl pallet_balances::Config for Test {
     type MaxLocks = ();
     type MaxReserves = MaxReserves;
     type ReserveIdentifier = [u8; 8];
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type FreezeIdentifier = ();
     type MaxFreezes = ConstU32<0>;
     type RuntimeFreezeReason = ();
     type DoneSlashHandler = ();
 }
 
-// Simple mock that always succeeds
+// Simple mock that succeeds unless `OutboundQueueFails` is set
 pub struct MockOkOutboundQueue;
 
 impl SendMessage for MockOkOutboundQueue {
@@ -100,13 +104,24 @@ impl SendMessage for MockOkOutboundQueue {
     fn validate(
         message: &OutboundMessage,
     ) -> Result<Self::Ticket, snowbridge_outbound_queue_primitives::SendError> {
+        if OutboundQueueFails::get() {
+            return Err(snowbridge_outbound_queue_primitives::SendError::MessageTooLarge);
+        }
         Ok(message.clone())
     }
 
     fn deliver(
-        _ticket: Self::Ticket,
+        ticket: Self::Ticket,
     ) -> Result<H256, snowbridge_outbound_queue_primitives::SendError> {
-        Ok(H256::zero())
+        Ok(ticket.id)
+    }
+}
+
+impl SendMessageFeeProvider for MockOkOutboundQueue {
+    type Balance = u128;
+
+    fn local_fee() -> Self::Balance {
+        LOCAL_FEE
     }
 }
 
@@ -124,7 +139,34 @@ parameter_types! {
     pub const EthereumSovereignAccount: u64 = 999;
     pub const DataHavenTokenId: H256 = H256::repeat_byte(0x01);
     pub const FeeRecipientAccount: u64 = 1000;
+    pub const FeeRefundTimeout: u64 = 10;
+    pub const TransferReceiptRetention: u64 = 20;
+    pub storage OutboundQueueFails: bool = false;
     pub storage IsTokenRegistered: bool = true; // Default to registered for most tests
+    pub storage ActiveEraIndex: EraIndex = 0;
+    pub storage LargeTransferThreshold: u128 = 0; // Large transfers are enabled by the tests
+    pub const LargeTransferDelay: u64 = 5;
+}
+
+pub struct MockEraIndexProvider;
+
+impl MockEraIndexProvider {
+    pub fn with_era(era_index: EraIndex) {
+        ActiveEraIndex::set(&era_index);
+    }
+}
+
+impl EraIndexProvider for MockEraIndexProvider {
+    fn active_era() -> ActiveEraInfo {
+        ActiveEraInfo {
+            index: ActiveEraIndex::get(),
+            start: None,
+        }
+    }
+
+    fn era_to_session_start(_era_index: EraIndex) -> Option<u32> {
+        None
+    }
 }
 
 pub struct MockNativeTokenId;
@@ -141,12 +183,21 @@ impl Get<Option<H256>> for MockNativeTokenId {
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type EthereumSovereignAccount = EthereumSovereignAccount;
     type OutboundQueue = MockOkOutboundQueue;
     type NativeTokenId = MockNativeTokenId;
     type FeeRecipient = FeeRecipientAccount;
+    type FeeRefundTimeout = FeeRefundTimeout;
+    type TransferReceiptRetention = TransferReceiptRetention;
+    type Ledger = ();
+    type OnOutboundMessageSent = ();
     type WeightInfo = ();
     type PauseOrigin = EnsureRoot<u64>;
+    type EraIndexProvider = MockEraIndexProvider;
+    type LargeTransferThreshold = LargeTransferThreshold;
+    type LargeTransferDelay = LargeTransferDelay;
+    type SecurityOrigin = EnsureRoot<u64>;
 }
 
 pub const ALICE: u64 = 1;
@@ -155,6 +206,7 @@ pub const CHARLIE: u64 = 3;
 pub const ETHEREUM_SOVEREIGN: u64 = 999;
 pub const FEE_RECIPIENT: u64 = 1000;
 pub const INITIAL_BALANCE: u128 = 10_000;
+pub const LOCAL_FEE: u128 = 5;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::<Test>::default()
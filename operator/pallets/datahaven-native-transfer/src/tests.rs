@@ -15,12 +15,20 @@
 // along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
-    crate::{mock::*, Error, Pallet as DataHavenNativeTransfer, Paused},
+    crate::{
+        mock::*, Error, FeeRefundQueue, FeeRefundReason, HoldReason, LargeTransferQueue,
+        Pallet as DataHavenNativeTransfer, Paused, PendingLargeTransfer, PendingLargeTransfers,
+        PendingTransferFee, PendingTransferFees, ProcessedDeposits, TransferLimitPeriod,
+        TransferLimits, TransferReceipt, TransferReceiptExpiry, TransferReceipts, TransferStatus,
+    },
     frame_support::{
         assert_noop, assert_ok,
-        traits::fungible::{Inspect, Mutate},
+        traits::{
+            fungible::{Inspect, InspectHold, Mutate},
+            Hooks,
+        },
     },
-    sp_core::H160,
+    sp_core::{H160, H256},
     sp_runtime::DispatchError,
 };
 
@@ -28,6 +36,23 @@ fn ethereum_address() -> H160 {
     H160::from_low_u64_be(42)
 }
 
+fn held_fee(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::TransferFee.into(), &who)
+}
+
+/// Transfer from Alice, returning the id of the message sent.
+fn transfer_from_alice(amount: u128, fee: u128) -> H256 {
+    assert_ok!(DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+        RuntimeOrigin::signed(ALICE),
+        ethereum_address(),
+        amount,
+        fee
+    ));
+    PendingTransferFees::<Test>::iter_keys()
+        .last()
+        .expect("the fee is pending")
+}
+
 // ===========================
 // Transfer Tests
 // ===========================
@@ -46,10 +71,11 @@ fn transfer_to_ethereum_works() {
             fee
         ));
 
-        // Check tokens were locked and fee was withdrawn
+        // Check tokens were locked and fee was held
         assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - amount - fee);
         assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), amount);
-        assert_eq!(Balances::balance(&FEE_RECIPIENT), fee);
+        assert_eq!(held_fee(ALICE), fee);
+        assert_eq!(Balances::balance(&FEE_RECIPIENT), 0);
 
         // Check event was emitted
         assert_eq!(
@@ -158,7 +184,8 @@ fn multiple_transfers_work() {
             INITIAL_BALANCE - amount1 - amount2 - 100
         );
         assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), amount1 + amount2);
-        assert_eq!(Balances::balance(&FEE_RECIPIENT), 100);
+        assert_eq!(held_fee(ALICE), 100);
+        assert_eq!(PendingTransferFees::<Test>::iter().count(), 2);
     });
 }
 
@@ -177,6 +204,32 @@ fn transfer_with_zero_fee_fails() {
     });
 }
 
+#[test]
+fn transfer_with_fee_below_quote_fails() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            DataHavenNativeTransfer::<Test>::quote_transfer_fee(),
+            LOCAL_FEE
+        );
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                ethereum_address(),
+                1000,
+                LOCAL_FEE - 1
+            ),
+            Error::<Test>::FeeTooLow
+        );
+        assert_ok!(DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+            RuntimeOrigin::signed(ALICE),
+            ethereum_address(),
+            1000,
+            LOCAL_FEE
+        ));
+    });
+}
+
 #[test]
 fn transfer_fails_when_token_not_registered() {
     new_test_ext().execute_with(|| {
@@ -309,6 +362,139 @@ fn lock_unlock_different_amounts() {
     });
 }
 
+// ===========================
+// Deposit Tests
+// ===========================
+
+#[test]
+fn mint_deposit_works() {
+    new_test_ext().execute_with(|| {
+        let deposit_id = H256::repeat_byte(0xd1);
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::mint_deposit(
+            deposit_id, &BOB, 500
+        ));
+
+        assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE + 500);
+        assert_eq!(Balances::total_issuance(), issuance + 500);
+        assert!(ProcessedDeposits::<Test>::contains_key(deposit_id));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::DepositMinted {
+                deposit_id,
+                account: BOB,
+                amount: 500,
+            })
+        );
+    });
+}
+
+#[test]
+fn mint_deposit_is_credited_once() {
+    new_test_ext().execute_with(|| {
+        let deposit_id = H256::repeat_byte(0xd1);
+        assert_ok!(DataHavenNativeTransfer::<Test>::mint_deposit(
+            deposit_id, &BOB, 500
+        ));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::mint_deposit(deposit_id, &ALICE, 500),
+            Error::<Test>::DepositAlreadyProcessed
+        );
+    });
+}
+
+#[test]
+fn mint_deposit_fails_with_zero_amount() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::mint_deposit(H256::repeat_byte(0xd1), &BOB, 0),
+            Error::<Test>::InvalidAmount
+        );
+    });
+}
+
+#[test]
+fn mint_deposit_fails_when_paused() {
+    new_test_ext().execute_with(|| {
+        Paused::<Test>::put(true);
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::mint_deposit(H256::repeat_byte(0xd1), &BOB, 500),
+            Error::<Test>::TransfersDisabled
+        );
+    });
+}
+
+fn deposit(id: u8, amount: u128) -> frame_support::dispatch::DispatchResult {
+    DataHavenNativeTransfer::<Test>::mint_deposit(H256::repeat_byte(id), &BOB, amount)
+}
+
+#[test]
+fn set_deposit_limits_requires_pause_origin() {
+    new_test_ext().execute_with(|| {
+        let limits = TransferLimits {
+            per_block: Some(100),
+            per_era: Some(1000),
+        };
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::set_deposit_limits(
+                RuntimeOrigin::signed(ALICE),
+                limits.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::set_deposit_limits(
+            RuntimeOrigin::root(),
+            limits.clone()
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::DepositLimitsSet { limits })
+        );
+    });
+}
+
+#[test]
+fn deposits_are_capped_per_block_and_era() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::set_deposit_limits(
+            RuntimeOrigin::root(),
+            TransferLimits {
+                per_block: Some(500),
+                per_era: Some(800),
+            }
+        ));
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(deposit(1, 300));
+        assert_noop!(deposit(2, 201), Error::<Test>::DepositLimitExceeded);
+        assert_ok!(deposit(2, 200));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DataHavenNativeTransfer(crate::Event::DepositLimitReached {
+                period: TransferLimitPeriod::Block,
+                limit: 500,
+            })));
+
+        // The rejected deposit was not processed, it can be relayed again later
+        System::set_block_number(2);
+        assert_noop!(deposit(3, 301), Error::<Test>::DepositLimitExceeded);
+        assert!(!ProcessedDeposits::<Test>::contains_key(H256::repeat_byte(
+            3
+        )));
+        assert_ok!(deposit(3, 300));
+
+        MockEraIndexProvider::with_era(1);
+        assert_ok!(deposit(4, 500));
+        assert_eq!(Balances::total_issuance(), issuance + 1300);
+
+        // Transfers to Ethereum have their own limits
+        assert_ok!(transfer(500));
+    });
+}
+
 // ===========================
 // Pause/Unpause Tests
 // ===========================
@@ -435,7 +621,7 @@ fn transfer_preserves_existential_deposit() {
         // Alice should still have existential deposit
         assert_eq!(Balances::balance(&ALICE), 1);
         assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), transfer_amount);
-        assert_eq!(Balances::balance(&FEE_RECIPIENT), fee);
+        assert_eq!(held_fee(ALICE), fee);
     });
 }
 
@@ -463,7 +649,8 @@ fn transfer_with_preservation_mode() {
         let balance = 3u128;
         <Balances as Mutate<_>>::set_balance(&ALICE, balance);
 
-        // Try to transfer all - should fail due to Preservation::Preserve
+        // Try to transfer all - should fail due to Preservation::Preserve, the held fee
+        // keeping the account alive
         assert_noop!(
             DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
                 RuntimeOrigin::signed(ALICE),
@@ -471,7 +658,538 @@ fn transfer_with_preservation_mode() {
                 2,
                 1
             ),
-            DispatchError::Token(sp_runtime::TokenError::NotExpendable)
+            DispatchError::Token(sp_runtime::TokenError::OnlyProvider)
+        );
+    });
+}
+
+// ===========================
+// Transfer Fee Tests
+// ===========================
+
+#[test]
+fn transfer_fee_is_pending_until_delivery() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        assert_eq!(
+            PendingTransferFees::<Test>::get(message_id),
+            Some(PendingTransferFee {
+                payer: ALICE,
+                fee: 100,
+                refund_at: 1 + FeeRefundTimeout::get(),
+            })
+        );
+        assert!(FeeRefundQueue::<Test>::contains_key(
+            1 + FeeRefundTimeout::get(),
+            message_id
+        ));
+    });
+}
+
+#[test]
+fn transfer_fee_is_paid_on_successful_delivery() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, true);
+
+        assert_eq!(held_fee(ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 1000 - 100);
+        assert_eq!(Balances::balance(&FEE_RECIPIENT), 100);
+        assert!(!PendingTransferFees::<Test>::contains_key(message_id));
+        assert_eq!(FeeRefundQueue::<Test>::iter().count(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferFeePaid {
+                message_id,
+                account: ALICE,
+                fee: 100,
+            })
+        );
+    });
+}
+
+#[test]
+fn transfer_fee_is_refunded_on_failed_delivery() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, false);
+
+        assert_eq!(held_fee(ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 1000);
+        assert_eq!(Balances::balance(&FEE_RECIPIENT), 0);
+        assert!(!PendingTransferFees::<Test>::contains_key(message_id));
+        assert_eq!(FeeRefundQueue::<Test>::iter().count(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferFeeRefunded {
+                message_id,
+                account: ALICE,
+                fee: 100,
+                reason: FeeRefundReason::DeliveryFailed,
+            })
+        );
+    });
+}
+
+#[test]
+fn transfer_fee_is_refunded_after_timeout() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+        let refund_at = 1 + FeeRefundTimeout::get();
+
+        DataHavenNativeTransfer::<Test>::on_initialize(refund_at - 1);
+        assert_eq!(held_fee(ALICE), 100);
+
+        DataHavenNativeTransfer::<Test>::on_initialize(refund_at);
+        assert_eq!(held_fee(ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 1000);
+        assert!(!PendingTransferFees::<Test>::contains_key(message_id));
+        assert_eq!(FeeRefundQueue::<Test>::iter().count(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferFeeRefunded {
+                message_id,
+                account: ALICE,
+                fee: 100,
+                reason: FeeRefundReason::Timeout,
+            })
+        );
+
+        // A late delivery receipt no longer moves the fee
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, true);
+        assert_eq!(Balances::balance(&FEE_RECIPIENT), 0);
+    });
+}
+
+#[test]
+fn delivery_of_other_messages_is_ignored() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(H256::repeat_byte(0xff), false);
+
+        assert_eq!(held_fee(ALICE), 100);
+        assert!(PendingTransferFees::<Test>::contains_key(message_id));
+    });
+}
+
+// ===========================
+// Transfer Receipt Tests
+// ===========================
+
+fn receipt_status(message_id: H256) -> Option<TransferStatus> {
+    TransferReceipts::<Test>::get(message_id).map(|receipt| receipt.status)
+}
+
+#[test]
+fn transfer_records_a_pending_receipt() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        assert_eq!(
+            TransferReceipts::<Test>::get(message_id),
+            Some(TransferReceipt {
+                sender: ALICE,
+                recipient: ethereum_address(),
+                amount: 1000,
+                fee: 100,
+                sent_at: 1,
+                status: TransferStatus::Pending,
+            })
+        );
+        assert!(TransferReceiptExpiry::<Test>::contains_key(
+            1 + TransferReceiptRetention::get(),
+            message_id
+        ));
+    });
+}
+
+#[test]
+fn receipt_is_delivered_on_successful_delivery() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, true);
+
+        assert_eq!(receipt_status(message_id), Some(TransferStatus::Delivered));
+    });
+}
+
+#[test]
+fn receipt_is_failed_on_failed_delivery() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, false);
+
+        assert_eq!(receipt_status(message_id), Some(TransferStatus::Failed));
+    });
+}
+
+#[test]
+fn receipt_is_timed_out_after_the_refund_timeout() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+
+        DataHavenNativeTransfer::<Test>::on_initialize(1 + FeeRefundTimeout::get());
+        assert_eq!(receipt_status(message_id), Some(TransferStatus::TimedOut));
+
+        // A late delivery receipt does not change the outcome
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, true);
+        assert_eq!(receipt_status(message_id), Some(TransferStatus::TimedOut));
+    });
+}
+
+#[test]
+fn receipt_is_removed_after_its_retention() {
+    new_test_ext().execute_with(|| {
+        let message_id = transfer_from_alice(1000, 100);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(message_id, true);
+        let expires_at = 1 + TransferReceiptRetention::get();
+
+        DataHavenNativeTransfer::<Test>::on_initialize(expires_at - 1);
+        assert!(TransferReceipts::<Test>::contains_key(message_id));
+
+        DataHavenNativeTransfer::<Test>::on_initialize(expires_at);
+        assert!(!TransferReceipts::<Test>::contains_key(message_id));
+        assert_eq!(TransferReceiptExpiry::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn each_transfer_has_its_own_receipt() {
+    new_test_ext().execute_with(|| {
+        let first = transfer_from_alice(1000, 100);
+        System::set_block_number(2);
+        assert_ok!(transfer(500));
+        let second = TransferReceipts::<Test>::iter_keys()
+            .find(|message_id| *message_id != first)
+            .expect("the second receipt is recorded");
+
+        assert_eq!(TransferReceipts::<Test>::iter().count(), 2);
+        assert_eq!(
+            TransferReceipts::<Test>::get(second).map(|receipt| (receipt.amount, receipt.sent_at)),
+            Some((500, 2))
+        );
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(first, false);
+        assert_eq!(receipt_status(first), Some(TransferStatus::Failed));
+        assert_eq!(receipt_status(second), Some(TransferStatus::Pending));
+    });
+}
+
+#[test]
+fn transfer_fails_when_the_message_cannot_be_sent() {
+    new_test_ext().execute_with(|| {
+        OutboundQueueFails::set(&true);
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                ethereum_address(),
+                1000,
+                100
+            ),
+            Error::<Test>::SendMessageFailed
+        );
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+        assert_eq!(held_fee(ALICE), 0);
+        assert_eq!(TransferReceipts::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn rejected_transfer_records_no_receipt() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                ethereum_address(),
+                INITIAL_BALANCE + 1,
+                100
+            ),
+            DispatchError::Token(sp_runtime::TokenError::FundsUnavailable)
+        );
+        assert_eq!(TransferReceipts::<Test>::iter().count(), 0);
+        assert_eq!(TransferReceiptExpiry::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn large_transfer_records_a_receipt_once_executed() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+        assert_ok!(transfer(1000));
+        assert_eq!(TransferReceipts::<Test>::iter().count(), 0);
+
+        System::set_block_number(execute_at);
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at);
+
+        let (_, receipt) = TransferReceipts::<Test>::iter()
+            .next()
+            .expect("the receipt is recorded");
+        assert_eq!(receipt.amount, 1000);
+        assert_eq!(receipt.sent_at, execute_at);
+        assert_eq!(receipt.status, TransferStatus::Pending);
+    });
+}
+
+#[test]
+fn large_transfer_whose_message_cannot_be_sent_records_no_receipt() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+        assert_ok!(transfer(1000));
+
+        OutboundQueueFails::set(&true);
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at);
+
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 0);
+        assert_eq!(TransferReceipts::<Test>::iter().count(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::LargeTransferFailed {
+                id: 0,
+                error: Error::<Test>::SendMessageFailed.into(),
+            })
+        );
+    });
+}
+
+// ===========================
+// Transfer Limit Tests
+// ===========================
+
+fn set_limits(per_block: Option<u128>, per_era: Option<u128>) {
+    assert_ok!(DataHavenNativeTransfer::<Test>::set_transfer_limits(
+        RuntimeOrigin::root(),
+        TransferLimits { per_block, per_era }
+    ));
+}
+
+fn transfer(amount: u128) -> frame_support::dispatch::DispatchResult {
+    DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+        RuntimeOrigin::signed(ALICE),
+        ethereum_address(),
+        amount,
+        10,
+    )
+}
+
+#[test]
+fn set_transfer_limits_requires_pause_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::set_transfer_limits(
+                RuntimeOrigin::signed(ALICE),
+                TransferLimits::default()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        set_limits(Some(100), Some(1000));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferLimitsSet {
+                limits: TransferLimits {
+                    per_block: Some(100),
+                    per_era: Some(1000),
+                },
+            })
+        );
+    });
+}
+
+#[test]
+fn transfers_are_capped_per_block() {
+    new_test_ext().execute_with(|| {
+        set_limits(Some(500), None);
+
+        assert_ok!(transfer(300));
+        assert_noop!(transfer(201), Error::<Test>::TransferLimitExceeded);
+        assert_ok!(transfer(200));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferLimitReached {
+                period: TransferLimitPeriod::Block,
+                limit: 500,
+            })));
+        assert_noop!(transfer(1), Error::<Test>::TransferLimitExceeded);
+
+        // The cap applies to each block
+        System::set_block_number(2);
+        assert_ok!(transfer(500));
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+    });
+}
+
+#[test]
+fn transfers_are_capped_per_era() {
+    new_test_ext().execute_with(|| {
+        set_limits(None, Some(500));
+
+        assert_ok!(transfer(300));
+        System::set_block_number(2);
+        assert_noop!(transfer(201), Error::<Test>::TransferLimitExceeded);
+        assert_ok!(transfer(200));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferLimitReached {
+                period: TransferLimitPeriod::Era,
+                limit: 500,
+            })));
+
+        // The cap applies to each era
+        MockEraIndexProvider::with_era(1);
+        assert_ok!(transfer(500));
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+    });
+}
+
+#[test]
+fn transfers_before_the_limits_are_set_count_towards_them() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(400));
+
+        set_limits(Some(500), Some(500));
+        assert_noop!(transfer(101), Error::<Test>::TransferLimitExceeded);
+
+        // Lifting the limits allows transfers again
+        set_limits(None, None);
+        assert_ok!(transfer(101));
+    });
+}
+
+// ===========================
+// Large Transfer Tests
+// ===========================
+
+fn held_for_large_transfer(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::LargeTransfer.into(), &who)
+}
+
+#[test]
+fn large_transfer_is_queued() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+
+        assert_ok!(transfer(1000));
+
+        assert_eq!(held_for_large_transfer(ALICE), 1010);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 0);
+        assert_eq!(
+            PendingLargeTransfers::<Test>::get(0),
+            Some(PendingLargeTransfer {
+                sender: ALICE,
+                recipient: ethereum_address(),
+                amount: 1000,
+                fee: 10,
+                execute_at,
+            })
+        );
+        assert!(LargeTransferQueue::<Test>::contains_key(execute_at, 0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::LargeTransferQueued {
+                id: 0,
+                from: ALICE,
+                to: ethereum_address(),
+                amount: 1000,
+                fee: 10,
+                execute_at,
+            })
+        );
+
+        // Smaller transfers are not delayed
+        assert_ok!(transfer(999));
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 999);
+    });
+}
+
+#[test]
+fn large_transfer_is_executed_after_its_delay() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+        assert_ok!(transfer(1000));
+
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at - 1);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 0);
+
+        System::set_block_number(execute_at);
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at);
+
+        assert_eq!(held_for_large_transfer(ALICE), 0);
+        assert_eq!(held_fee(ALICE), 10);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 1010);
+        assert!(!PendingLargeTransfers::<Test>::contains_key(0));
+        assert_eq!(PendingTransferFees::<Test>::iter().count(), 1);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::LargeTransferExecuted { id: 0 })
+        );
+    });
+}
+
+#[test]
+fn large_transfer_can_be_cancelled_by_security_origin() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+        assert_ok!(transfer(1000));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::cancel_large_transfer(RuntimeOrigin::signed(ALICE), 0),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::cancel_large_transfer(RuntimeOrigin::root(), 1),
+            Error::<Test>::UnknownLargeTransfer
+        );
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::cancel_large_transfer(
+            RuntimeOrigin::root(),
+            0
+        ));
+        assert_eq!(held_for_large_transfer(ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+        assert!(!LargeTransferQueue::<Test>::contains_key(execute_at, 0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::LargeTransferCancelled { id: 0 })
+        );
+
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 0);
+    });
+}
+
+#[test]
+fn failed_large_transfer_is_released() {
+    new_test_ext().execute_with(|| {
+        LargeTransferThreshold::set(&1000);
+        let execute_at = 1 + LargeTransferDelay::get();
+        assert_ok!(transfer(1000));
+
+        Paused::<Test>::put(true);
+        DataHavenNativeTransfer::<Test>::on_initialize(execute_at);
+
+        assert_eq!(held_for_large_transfer(ALICE), 0);
+        assert_eq!(held_fee(ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 0);
+        assert!(!PendingLargeTransfers::<Test>::contains_key(0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::LargeTransferFailed {
+                id: 0,
+                error: Error::<Test>::TransfersDisabled.into(),
+            })
         );
     });
 }
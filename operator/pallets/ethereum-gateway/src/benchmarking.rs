@@ -0,0 +1,53 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-ethereum-gateway
+
+use super::*;
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn rotate_gateway() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GatewayOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let new_gateway = H160::repeat_byte(0x42);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_gateway);
+
+        assert_eq!(Pallet::<T>::active_gateway(), new_gateway);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retire_previous_gateway() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GatewayOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        PreviousGateway::<T>::put((H160::repeat_byte(0x42), BlockNumberFor::<T>::from(1u32)));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(PreviousGateway::<T>::get().is_none());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,194 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Ethereum Gateway Pallet
+//!
+//! Keeps the address of the Snowbridge Gateway contract on Ethereum, so the contract can be
+//! migrated without a runtime upgrade.
+//!
+//! ## Overview
+//!
+//! - The active gateway is the destination of outbound messages and the only source of their
+//!   delivery receipts. It is kept in `ActiveGateway`, the single source of the address, which
+//!   rotations update.
+//! - Governance rotates the gateway with `rotate_gateway`. Messages emitted by the previous
+//!   gateway are still accepted for `RotationGracePeriod` blocks, so the ones already in
+//!   flight can be relayed.
+//! - `retire_previous_gateway` stops accepting the previous gateway before the end of the
+//!   grace period.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use sp_core::H160;
+use sp_runtime::traits::Saturating;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Where the active gateway address is kept.
+pub trait GatewayStore {
+    /// The active gateway.
+    fn get() -> H160;
+
+    /// Replace the active gateway.
+    fn set(gateway: H160) -> DispatchResult;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin that can rotate the gateway
+        type GatewayOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Storage of the active gateway, also read by the Snowbridge queues
+        type ActiveGateway: GatewayStore;
+
+        /// Number of blocks the previous gateway is still accepted for after a rotation
+        #[pallet::constant]
+        type RotationGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Gateway replaced by the last rotation and the last block its messages are accepted at.
+    #[pallet::storage]
+    pub type PreviousGateway<T: Config> = StorageValue<_, (H160, BlockNumberFor<T>), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The gateway was rotated, the old one is accepted until `old_gateway_accepted_until`
+        GatewayRotated {
+            old_gateway: H160,
+            new_gateway: H160,
+            old_gateway_accepted_until: BlockNumberFor<T>,
+        },
+
+        /// The previous gateway is no longer accepted
+        PreviousGatewayRetired { gateway: H160 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The gateway cannot be the zero address
+        ZeroGateway,
+        /// The new gateway is already the active one
+        GatewayAlreadyActive,
+        /// There is no previous gateway to retire
+        NoPreviousGateway,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Make `new_gateway` the active gateway
+        ///
+        /// The replaced gateway is still accepted for `RotationGracePeriod` blocks, replacing
+        /// the gateway of any earlier rotation.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::rotate_gateway())]
+        pub fn rotate_gateway(origin: OriginFor<T>, new_gateway: H160) -> DispatchResult {
+            T::GatewayOrigin::ensure_origin(origin)?;
+
+            ensure!(!new_gateway.is_zero(), Error::<T>::ZeroGateway);
+            let old_gateway = Self::active_gateway();
+            ensure!(new_gateway != old_gateway, Error::<T>::GatewayAlreadyActive);
+
+            let old_gateway_accepted_until = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::RotationGracePeriod::get());
+            T::ActiveGateway::set(new_gateway)?;
+            PreviousGateway::<T>::put((old_gateway, old_gateway_accepted_until));
+
+            Self::deposit_event(Event::GatewayRotated {
+                old_gateway,
+                new_gateway,
+                old_gateway_accepted_until,
+            });
+
+            Ok(())
+        }
+
+        /// Stop accepting the messages of the previous gateway
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::retire_previous_gateway())]
+        pub fn retire_previous_gateway(origin: OriginFor<T>) -> DispatchResult {
+            T::GatewayOrigin::ensure_origin(origin)?;
+
+            let (gateway, _) = PreviousGateway::<T>::take().ok_or(Error::<T>::NoPreviousGateway)?;
+
+            Self::deposit_event(Event::PreviousGatewayRetired { gateway });
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The gateway outbound messages are sent to.
+    pub fn active_gateway() -> H160 {
+        T::ActiveGateway::get()
+    }
+
+    /// Whether messages emitted by `gateway` are accepted: the active gateway, or the previous
+    /// one within its grace period.
+    pub fn is_accepted_gateway(gateway: &H160) -> bool {
+        if *gateway == Self::active_gateway() {
+            return true;
+        }
+
+        PreviousGateway::<T>::get().is_some_and(|(previous, accepted_until)| {
+            previous == *gateway && frame_system::Pallet::<T>::block_number() <= accepted_until
+        })
+    }
+}
+
+/// The active gateway, for the `GatewayAddress` of the Snowbridge queues.
+pub struct ActiveGatewayAddress<T>(PhantomData<T>);
+
+impl<T: Config> Get<H160> for ActiveGatewayAddress<T> {
+    fn get() -> H160 {
+        Pallet::<T>::active_gateway()
+    }
+}
+
+/// The previous gateway, while it is still accepted.
+pub struct PreviousGatewayAddress<T>(PhantomData<T>);
+
+impl<T: Config> Contains<H160> for PreviousGatewayAddress<T> {
+    fn contains(gateway: &H160) -> bool {
+        *gateway != Pallet::<T>::active_gateway() && Pallet::<T>::is_accepted_gateway(gateway)
+    }
+}
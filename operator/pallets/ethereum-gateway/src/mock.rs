@@ -0,0 +1,76 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate as pallet_ethereum_gateway,
+    crate::GatewayStore,
+    frame_support::{derive_impl, parameter_types, traits::Everything},
+    frame_system::EnsureRoot,
+    sp_core::H160,
+    sp_runtime::{BuildStorage, DispatchResult},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        EthereumGateway: pallet_ethereum_gateway,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const DefaultGateway: H160 = H160::repeat_byte(0x01);
+    pub storage Gateway: H160 = DefaultGateway::get();
+    pub const RotationGracePeriod: u64 = 10;
+}
+
+/// Keeps the active gateway in the `Gateway` storage parameter.
+pub struct MockGatewayStore;
+impl GatewayStore for MockGatewayStore {
+    fn get() -> H160 {
+        Gateway::get()
+    }
+
+    fn set(gateway: H160) -> DispatchResult {
+        Gateway::set(&gateway);
+        Ok(())
+    }
+}
+
+impl pallet_ethereum_gateway::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type GatewayOrigin = EnsureRoot<u64>;
+    type ActiveGateway = MockGatewayStore;
+    type RotationGracePeriod = RotationGracePeriod;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,162 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, ActiveGatewayAddress, Error, Event, PreviousGateway, PreviousGatewayAddress},
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{Contains, Get},
+    },
+    sp_core::H160,
+    sp_runtime::DispatchError,
+};
+
+const NEW_GATEWAY: H160 = H160::repeat_byte(0x02);
+
+#[test]
+fn default_gateway_is_active_until_rotated() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(EthereumGateway::active_gateway(), DefaultGateway::get());
+        assert_eq!(ActiveGatewayAddress::<Test>::get(), DefaultGateway::get());
+        assert!(EthereumGateway::is_accepted_gateway(&DefaultGateway::get()));
+        assert!(!EthereumGateway::is_accepted_gateway(&NEW_GATEWAY));
+    });
+}
+
+#[test]
+fn rotate_gateway_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EthereumGateway::rotate_gateway(
+            RuntimeOrigin::root(),
+            NEW_GATEWAY
+        ));
+
+        assert_eq!(EthereumGateway::active_gateway(), NEW_GATEWAY);
+        assert_eq!(Gateway::get(), NEW_GATEWAY);
+        assert_eq!(
+            PreviousGateway::<Test>::get(),
+            Some((DefaultGateway::get(), 11))
+        );
+        System::assert_last_event(
+            Event::GatewayRotated {
+                old_gateway: DefaultGateway::get(),
+                new_gateway: NEW_GATEWAY,
+                old_gateway_accepted_until: 11,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn previous_gateway_is_accepted_during_grace_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EthereumGateway::rotate_gateway(
+            RuntimeOrigin::root(),
+            NEW_GATEWAY
+        ));
+
+        System::set_block_number(11);
+        assert!(EthereumGateway::is_accepted_gateway(&NEW_GATEWAY));
+        assert!(EthereumGateway::is_accepted_gateway(&DefaultGateway::get()));
+        assert!(PreviousGatewayAddress::<Test>::contains(
+            &DefaultGateway::get()
+        ));
+        assert!(!PreviousGatewayAddress::<Test>::contains(&NEW_GATEWAY));
+
+        System::set_block_number(12);
+        assert!(EthereumGateway::is_accepted_gateway(&NEW_GATEWAY));
+        assert!(!EthereumGateway::is_accepted_gateway(&DefaultGateway::get()));
+        assert!(!PreviousGatewayAddress::<Test>::contains(
+            &DefaultGateway::get()
+        ));
+    });
+}
+
+#[test]
+fn rotating_back_to_the_previous_gateway_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EthereumGateway::rotate_gateway(
+            RuntimeOrigin::root(),
+            NEW_GATEWAY
+        ));
+        assert_ok!(EthereumGateway::rotate_gateway(
+            RuntimeOrigin::root(),
+            DefaultGateway::get()
+        ));
+
+        assert_eq!(EthereumGateway::active_gateway(), DefaultGateway::get());
+        assert_eq!(PreviousGateway::<Test>::get(), Some((NEW_GATEWAY, 11)));
+        assert!(!PreviousGatewayAddress::<Test>::contains(
+            &DefaultGateway::get()
+        ));
+    });
+}
+
+#[test]
+fn rotate_gateway_fails_for_invalid_gateway() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EthereumGateway::rotate_gateway(RuntimeOrigin::root(), H160::zero()),
+            Error::<Test>::ZeroGateway
+        );
+        assert_noop!(
+            EthereumGateway::rotate_gateway(RuntimeOrigin::root(), DefaultGateway::get()),
+            Error::<Test>::GatewayAlreadyActive
+        );
+    });
+}
+
+#[test]
+fn rotate_gateway_requires_gateway_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EthereumGateway::rotate_gateway(RuntimeOrigin::signed(1), NEW_GATEWAY),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            EthereumGateway::retire_previous_gateway(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn retire_previous_gateway_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EthereumGateway::retire_previous_gateway(RuntimeOrigin::root()),
+            Error::<Test>::NoPreviousGateway
+        );
+
+        assert_ok!(EthereumGateway::rotate_gateway(
+            RuntimeOrigin::root(),
+            NEW_GATEWAY
+        ));
+        assert_ok!(EthereumGateway::retire_previous_gateway(
+            RuntimeOrigin::root()
+        ));
+
+        assert!(PreviousGateway::<Test>::get().is_none());
+        assert!(!EthereumGateway::is_accepted_gateway(&DefaultGateway::get()));
+        System::assert_last_event(
+            Event::PreviousGatewayRetired {
+                gateway: DefaultGateway::get(),
+            }
+            .into(),
+        );
+    });
+}
@@ -0,0 +1,74 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_ethereum_gateway`
+//!
+//! NOT BENCHMARKED: hand-written estimates, from the storage accesses of each call. The
+//! active gateway is read and written through `Config::ActiveGateway`, counted here as one
+//! storage item.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ethereum_gateway`.
+pub trait WeightInfo {
+    fn rotate_gateway() -> Weight;
+    fn retire_previous_gateway() -> Weight;
+}
+
+/// Weights for `pallet_ethereum_gateway` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `Config::ActiveGateway` (r:1 w:1)
+    /// Storage: `EthereumGateway::PreviousGateway` (r:0 w:1)
+    /// Proof: `EthereumGateway::PreviousGateway` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    fn rotate_gateway() -> Weight {
+        Weight::from_parts(9_348_000, 1505)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `EthereumGateway::PreviousGateway` (r:1 w:1)
+    /// Proof: `EthereumGateway::PreviousGateway` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    fn retire_previous_gateway() -> Weight {
+        Weight::from_parts(7_702_000, 1509)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `Config::ActiveGateway` (r:1 w:1)
+    /// Storage: `EthereumGateway::PreviousGateway` (r:0 w:1)
+    /// Proof: `EthereumGateway::PreviousGateway` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    fn rotate_gateway() -> Weight {
+        Weight::from_parts(9_348_000, 1505)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `EthereumGateway::PreviousGateway` (r:1 w:1)
+    /// Proof: `EthereumGateway::PreviousGateway` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+    fn retire_previous_gateway() -> Weight {
+        Weight::from_parts(7_702_000, 1509)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM congestion pallet.
+//!
+//! Reports, at the end of every block, the EVM gas used against the gas target of the block
+//! and the change of the fee multiplier it caused, so infrastructure providers can alert on
+//! sustained congestion without deriving it from the receipts.
+//!
+//! - `BlockGasUsage` is emitted for every block that used gas or changed the multiplier, idle
+//!   blocks at a stable multiplier emit nothing.
+//! - The gas used by the last `AverageWindow` blocks is kept in a ring buffer and summarised
+//!   by the `EvmCongestionApi` runtime API.
+//!
+//! The pallet reads the gas used and the next multiplier in `on_finalize`, so it must be
+//! declared after `pallet-ethereum` and `pallet-transaction-payment` in the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_runtime::{traits::UniqueSaturatedInto, FixedU128, Perquintill};
+
+pub use pallet::*;
+
+/// EVM gas used over the recent blocks, as returned by the runtime API.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+)]
+pub struct CongestionSummary {
+    /// Number of blocks recorded, at most `AverageWindow`.
+    pub blocks: u32,
+    /// Average gas used by the recorded blocks.
+    pub average_gas_used: u64,
+    /// Gas used by a block at the target fullness.
+    pub gas_target: u64,
+    /// Number of recorded blocks that used more gas than the target.
+    pub blocks_above_target: u32,
+    /// Fee multiplier applied to the next block.
+    pub fee_multiplier: FixedU128,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// EVM gas used by the current block, once the Ethereum block is stored
+        type GasUsed: Get<u64>;
+
+        /// Maximum EVM gas of a block
+        type BlockGasLimit: Get<U256>;
+
+        /// Fullness of the block the fee multiplier is adjusted towards
+        type TargetBlockFullness: Get<Perquintill>;
+
+        /// Fee multiplier applied to the next block
+        type FeeMultiplier: Get<FixedU128>;
+
+        /// Number of blocks the average gas used is computed over
+        #[pallet::constant]
+        type AverageWindow: Get<u32>;
+    }
+
+    /// Gas used by the last `AverageWindow` blocks, oldest first.
+    #[pallet::storage]
+    pub type RecentGasUsed<T: Config> =
+        StorageValue<_, BoundedVec<u64, T::AverageWindow>, ValueQuery>;
+
+    /// Fee multiplier at the end of the previous block, that is the one the current block pays.
+    #[pallet::storage]
+    pub type LastFeeMultiplier<T> = StorageValue<_, FixedU128, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// EVM gas used by the block against the target, and the resulting multiplier change
+        BlockGasUsage {
+            gas_used: u64,
+            gas_target: u64,
+            previous_multiplier: FixedU128,
+            next_multiplier: FixedU128,
+        },
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+            // Weight of `on_finalize`: the Ethereum block, the next multiplier and the two
+            // values of the pallet.
+            T::DbWeight::get().reads_writes(4, 2)
+        }
+
+        fn on_finalize(_: BlockNumberFor<T>) {
+            let gas_used = T::GasUsed::get();
+            let next_multiplier = T::FeeMultiplier::get();
+            let previous_multiplier = LastFeeMultiplier::<T>::get().unwrap_or(next_multiplier);
+
+            RecentGasUsed::<T>::mutate(|recent| {
+                if recent.is_full() && !recent.is_empty() {
+                    recent.remove(0);
+                }
+                // Only fails with an empty window.
+                let _ = recent.try_push(gas_used);
+            });
+            LastFeeMultiplier::<T>::put(next_multiplier);
+
+            if gas_used > 0 || next_multiplier != previous_multiplier {
+                Self::deposit_event(Event::BlockGasUsage {
+                    gas_used,
+                    gas_target: Self::gas_target(),
+                    previous_multiplier,
+                    next_multiplier,
+                });
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Gas used by a block at `TargetBlockFullness`.
+    pub fn gas_target() -> u64 {
+        let block_gas_limit: u64 = T::BlockGasLimit::get().unique_saturated_into();
+        T::TargetBlockFullness::get() * block_gas_limit
+    }
+
+    pub fn congestion_summary() -> CongestionSummary {
+        let recent = RecentGasUsed::<T>::get();
+        let gas_target = Self::gas_target();
+        let blocks = recent.len() as u32;
+        let total_gas_used = recent.iter().fold(0u128, |total, gas_used| {
+            total.saturating_add(*gas_used as u128)
+        });
+
+        CongestionSummary {
+            blocks,
+            average_gas_used: total_gas_used
+                .checked_div(blocks as u128)
+                .unwrap_or_default() as u64,
+            gas_target,
+            blocks_above_target: recent
+                .iter()
+                .filter(|gas_used| **gas_used > gas_target)
+                .count() as u32,
+            fee_multiplier: T::FeeMultiplier::get(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate as pallet_evm_congestion,
+    frame_support::{
+        derive_impl, parameter_types,
+        traits::{Everything, Hooks},
+    },
+    sp_core::U256,
+    sp_runtime::{BuildStorage, FixedU128, Perquintill},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        EvmCongestion: pallet_evm_congestion,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+}
+
+parameter_types! {
+    pub storage GasUsed: u64 = 0;
+    pub storage FeeMultiplier: FixedU128 = FixedU128::from(1u128);
+    pub BlockGasLimit: U256 = U256::from(1_000_000u64);
+    pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(35);
+    pub const AverageWindow: u32 = 3;
+}
+
+impl pallet_evm_congestion::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type GasUsed = GasUsed;
+    type BlockGasLimit = BlockGasLimit;
+    type TargetBlockFullness = TargetBlockFullness;
+    type FeeMultiplier = FeeMultiplier;
+    type AverageWindow = AverageWindow;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Finalize the current block after it used `gas_used` and moved the multiplier to
+/// `next_multiplier`.
+pub fn finalize_block(gas_used: u64, next_multiplier: FixedU128) {
+    GasUsed::set(&gas_used);
+    FeeMultiplier::set(&next_multiplier);
+    EvmCongestion::on_finalize(System::block_number());
+    System::set_block_number(System::block_number() + 1);
+}
@@ -0,0 +1,98 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, CongestionSummary, Event},
+    sp_runtime::FixedU128,
+};
+
+#[test]
+fn summary_is_empty_before_the_first_block() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            EvmCongestion::congestion_summary(),
+            CongestionSummary {
+                gas_target: 350_000,
+                fee_multiplier: FixedU128::from(1u128),
+                ..Default::default()
+            }
+        );
+    });
+}
+
+#[test]
+fn block_gas_usage_reports_the_multiplier_change() {
+    new_test_ext().execute_with(|| {
+        finalize_block(500_000, FixedU128::from_rational(11, 10));
+
+        System::assert_last_event(
+            Event::BlockGasUsage {
+                gas_used: 500_000,
+                gas_target: 350_000,
+                previous_multiplier: FixedU128::from_rational(11, 10),
+                next_multiplier: FixedU128::from_rational(11, 10),
+            }
+            .into(),
+        );
+
+        finalize_block(600_000, FixedU128::from_rational(12, 10));
+
+        System::assert_last_event(
+            Event::BlockGasUsage {
+                gas_used: 600_000,
+                gas_target: 350_000,
+                previous_multiplier: FixedU128::from_rational(11, 10),
+                next_multiplier: FixedU128::from_rational(12, 10),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn idle_blocks_at_a_stable_multiplier_emit_nothing() {
+    new_test_ext().execute_with(|| {
+        finalize_block(0, FixedU128::from(1u128));
+        finalize_block(0, FixedU128::from(1u128));
+
+        assert!(System::events().is_empty());
+
+        finalize_block(0, FixedU128::from_rational(9, 10));
+
+        assert_eq!(System::events().len(), 1);
+    });
+}
+
+#[test]
+fn summary_averages_the_last_blocks() {
+    new_test_ext().execute_with(|| {
+        for gas_used in [1_000_000, 100_000, 400_000, 700_000] {
+            finalize_block(gas_used, FixedU128::from(2u128));
+        }
+
+        // The first block is out of the window of 3 blocks.
+        assert_eq!(
+            EvmCongestion::congestion_summary(),
+            CongestionSummary {
+                blocks: 3,
+                average_gas_used: 400_000,
+                gas_target: 350_000,
+                blocks_above_target: 2,
+                fee_multiplier: FixedU128::from(2u128),
+            }
+        );
+    });
+}
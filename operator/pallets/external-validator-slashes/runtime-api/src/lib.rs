@@ -0,0 +1,77 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the External Validator Slashes pallet
+//!
+//! * `slashes_for_era`: Read the slashes applied in an era
+//! * `unreported_queue`: Read the slash batches not sent to EigenLayer yet
+//! * `bonded_eras`: Read the eras whose offences can still be slashed
+//! * `slashing_mode`: Read whether offences are slashed, only logged or ignored
+//! * `offence_stats`: Count the slashes and slashed fractions of each offence kind
+//! * `offence_history`: Read the slashes sent to EigenLayer for an era, after their pruning
+//! * `simulate_slash`: Compute the slash an offence would result in, without applying it
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pallet_external_validator_slashes::{
+    OffenceKind, OffenceKindStats, OffenceRecord, Slash, SlashData, SlashingModeOption,
+};
+use parity_scale_codec::Codec;
+use sp_runtime::Perbill;
+use sp_staking::{EraIndex, SessionIndex};
+
+sp_api::decl_runtime_apis! {
+    pub trait ExternalValidatorSlashesApi<AccountId, SlashId>
+    where
+        AccountId: Codec,
+        SlashId: Codec,
+    {
+        /// Slashes applied at the start of `era`, still deferred if not confirmed.
+        fn slashes_for_era(era: EraIndex) -> Vec<Slash<AccountId, SlashId>>;
+
+        /// Slash batches waiting to be sent to EigenLayer, from the next one to be sent,
+        /// each with the era its slashes were applied in.
+        fn unreported_queue() -> Vec<(EraIndex, Vec<Slash<AccountId, SlashId>>)>;
+
+        /// Bonded eras, oldest first, with the session they started at and their external
+        /// index. Offences committed before the first of them are no longer slashed.
+        fn bonded_eras() -> Vec<(EraIndex, SessionIndex, u64)>;
+
+        /// Current slashing mode.
+        fn slashing_mode() -> SlashingModeOption;
+
+        /// Number of slashes and sum of the slashed fractions of each offence kind, for the
+        /// slashes applied from `from_era` to `to_era` included. Only the eras still bonded
+        /// keep their slashes.
+        fn offence_stats(from_era: EraIndex, to_era: EraIndex) -> Vec<(OffenceKind, OffenceKindStats)>;
+
+        /// Slashes applied in `era` and sent to EigenLayer, with the id of their message.
+        /// Kept for `OffenceHistoryDepth` eras, after the slashes themselves are pruned.
+        fn offence_history(era: EraIndex) -> Vec<OffenceRecord<AccountId>>;
+
+        /// Slash `validator` would get for an `offence_kind` offence of `fraction` committed in
+        /// `era`, with the WAD value that would be sent to EigenLayer for it. `None` if the
+        /// offence would not be slashed. Nothing is written to storage.
+        fn simulate_slash(
+            validator: AccountId,
+            offence_kind: OffenceKind,
+            fraction: Perbill,
+            era: EraIndex,
+        ) -> Option<SlashData<AccountId>>;
+    }
+}
@@ -39,11 +39,12 @@ mod benchmarks {
         let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
         Slash {
             validator: dummy(),
-            reporters: vec![],
+            reporters: Default::default(),
             slash_id,
             percentage: Perbill::from_percent(1),
             confirmed: false,
             offence_kind: OffenceKind::LivenessOffence,
+            evidence: H256::repeat_byte(1),
         }
     }
 
@@ -80,17 +81,49 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn reduce_deferred_slash() -> Result<(), BenchmarkError> {
+        let era = T::EraIndexProvider::active_era()
+            .index
+            .saturating_add(T::SlashDeferDuration::get())
+            .saturating_add(One::one());
+        let mut existing_slashes = Vec::new();
+        for _ in 0..MAX_SLASHES {
+            let mut slash = dummy_slash::<T>(One::one());
+            slash.percentage = Perbill::from_percent(50);
+            existing_slashes.push(slash);
+        }
+        Slashes::<T>::insert(era, &existing_slashes);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            era,
+            MAX_SLASHES - 1,
+            Perbill::from_percent(10),
+        );
+
+        assert_eq!(
+            Slashes::<T>::get(era)[(MAX_SLASHES - 1) as usize].percentage,
+            Perbill::from_percent(10)
+        );
+        Ok(())
+    }
+
     #[benchmark]
     fn force_inject_slash() -> Result<(), BenchmarkError> {
         let era = T::EraIndexProvider::active_era().index;
         let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let origin = T::ForceInjectSlashOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
         #[extrinsic_call]
         _(
-            RawOrigin::Root,
+            origin as T::RuntimeOrigin,
             era,
             dummy(),
             Perbill::from_percent(50),
             OffenceKind::LivenessOffence,
+            H256::repeat_byte(1),
         );
 
         assert_eq!(
@@ -106,10 +139,12 @@ mod benchmarks {
 
     #[benchmark]
     fn process_slashes_queue(s: Linear<1, 200>) -> Result<(), BenchmarkError> {
-        let first_batch = (0..s)
-            .map(|_| dummy_slash::<T>(One::one()))
-            .collect::<Vec<_>>();
-        let second_batch = vec![dummy_slash::<T>(One::one())];
+        let first_batch = BoundedVec::truncate_from(
+            (0..s)
+                .map(|_| dummy_slash::<T>(One::one()))
+                .collect::<Vec<_>>(),
+        );
+        let second_batch = BoundedVec::truncate_from(vec![dummy_slash::<T>(One::one())]);
 
         assert!(ExternalValidatorSlashes::<T>::unsent_queue_push((
             1,
@@ -141,7 +176,7 @@ mod benchmarks {
 
     #[benchmark]
     fn retry_unsent_slash_era() -> Result<(), BenchmarkError> {
-        let batch = vec![dummy_slash::<T>(One::one())];
+        let batch = BoundedVec::truncate_from(vec![dummy_slash::<T>(One::one())]);
         assert!(ExternalValidatorSlashes::<T>::unsent_queue_push((1, batch)));
 
         let origin =
@@ -155,6 +190,129 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn process_failed_slash_messages(s: Linear<1, 200>) -> Result<(), BenchmarkError> {
+        // Fill the failed messages so the due one has to be searched for
+        for id in 0..FAILED_SLASH_MESSAGES_CAPACITY {
+            let slashes = if id == 0 {
+                (0..s)
+                    .map(|_| dummy_slash::<T>(One::one()))
+                    .collect::<Vec<_>>()
+            } else {
+                vec![dummy_slash::<T>(One::one())]
+            };
+            FailedSlashMessages::<T>::insert(
+                id,
+                FailedSlashMessage {
+                    era: 1,
+                    slashes,
+                    attempts: 1,
+                    next_retry: Zero::zero(),
+                },
+            );
+        }
+
+        let processed;
+
+        #[block]
+        {
+            processed = Pallet::<T>::process_failed_slash_messages(Zero::zero());
+        }
+
+        assert_eq!(processed, Some(s));
+        assert_eq!(
+            FailedSlashMessages::<T>::count(),
+            FAILED_SLASH_MESSAGES_CAPACITY - 1
+        );
+
+        Ok(())
+    }
+
+    fn dead_letter_batch<T: Config>(s: u32) -> SlashBatch<T> {
+        BoundedVec::truncate_from((0..s).map(|_| dummy_slash::<T>(One::one())).collect())
+    }
+
+    #[benchmark]
+    fn redrive_dead_letter_slash_message(
+        s: Linear<1, { T::MaxQueuedSlashes::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        DeadLetterSlashMessages::<T>::insert(0, (1, dead_letter_batch::<T>(s)));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0u32);
+
+        assert!(!DeadLetterSlashMessages::<T>::contains_key(0));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn discard_dead_letter_slash_message(
+        s: Linear<1, { T::MaxQueuedSlashes::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        DeadLetterSlashMessages::<T>::insert(0, (1, dead_letter_batch::<T>(s)));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0u32);
+
+        assert!(!DeadLetterSlashMessages::<T>::contains_key(0));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_slash_fraction_bounds() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let offence_kind = OffenceKind::Custom(BoundedVec::truncate_from(vec![0u8; 256]));
+        let bounds = SlashFractionBounds {
+            min: Perbill::from_percent(1),
+            max: Perbill::from_percent(10),
+        };
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            offence_kind.clone(),
+            Some(bounds),
+        );
+
+        assert_eq!(SlashFractionBoundsOf::<T>::get(offence_kind), Some(bounds));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn acknowledge_slash() -> Result<(), BenchmarkError> {
+        let validator: T::AccountId = whitelisted_caller();
+        let mut slash_id = T::SlashId::default();
+        let mut inbox = Vec::new();
+        for _ in 0..T::MaxInboxEntries::get() {
+            inbox.push(InboxEntry {
+                slash_id,
+                era: 1,
+                percentage: Perbill::from_percent(50),
+                offence_kind: OffenceKind::Custom(BoundedVec::truncate_from(vec![0u8; 256])),
+            });
+            slash_id = slash_id.saturating_add(One::one());
+        }
+        // The last slash of the inbox is the one searched the longest
+        let last_slash_id = inbox
+            .last()
+            .map(|entry| entry.slash_id)
+            .ok_or(BenchmarkError::Weightless)?;
+        ValidatorInbox::<T>::insert(&validator, BoundedVec::truncate_from(inbox));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(validator.clone()), last_slash_id);
+
+        assert!(ValidatorInbox::<T>::get(&validator)
+            .iter()
+            .all(|entry| entry.slash_id != last_slash_id));
+
+        Ok(())
+    }
+
     #[benchmark]
     fn set_slashing_mode() -> Result<(), BenchmarkError> {
         #[extrinsic_call]
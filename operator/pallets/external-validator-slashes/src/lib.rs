@@ -18,8 +18,17 @@
 //!
 //! A pallet to store slashes based on offences committed by validators
 //! Slashes can be cancelled during the DeferPeriod through cancel_deferred_slash
-//! Slashes can also be forcedly injected via the force_inject_slash extrinsic
+//! Slashes can also be forcedly injected via the force_inject_slash extrinsic, together with
+//! a hash referencing the evidence for the slash
 //! Slashes for a particular era are removed after the bondingPeriod has elapsed
+//! Every slash carries a digest of its evidence, relayed to EigenLayer with the slash: the
+//! offence report for slashes coming from `on_offence`, the provided hash for injected ones
+//! Fractions reported through `on_offence` are first adjusted by the runtime's SlashPolicy, which
+//! can escalate the slashes of validators that offended earlier in the bonding period, then
+//! clamped to the bounds governance set for the offence kind, if any, through
+//! set_slash_fraction_bounds
+//! Deferred slashes are also added to the inbox of their validator, which the validator or its
+//! proxy clears through acknowledge_slash
 //!
 //! ## OnOffence trait
 //!
@@ -37,13 +46,16 @@ use {
     log::log,
     pallet_external_validators::{
         derive_storage_traits,
-        traits::{EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider, OnEraStart},
+        traits::{
+            EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider, OnEraStart,
+            OnOutboundMessageSent, OperatorAddressProvider, OutboundMessageKind,
+        },
     },
     parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, FullCodec},
-    sp_core::H256,
+    sp_core::{H160, H256},
     sp_runtime::{
-        traits::{Convert, Debug, One, Saturating, Zero},
-        DispatchResult, Perbill,
+        traits::{BlakeTwo256, Convert, Debug, Hash as HashT, One, Saturating, Zero},
+        DispatchResult, PerThing, Perbill,
     },
     sp_staking::{
         offence::{Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
@@ -56,11 +68,15 @@ pub use pallet::*;
 #[cfg(test)]
 mod mock;
 
+#[cfg(test)]
+mod proptests;
+
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 /// Identifies the type of consensus offence for EigenLayer slash reporting.
@@ -97,16 +113,166 @@ impl OffenceKind {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+/// Floor and ceiling applied to the fractions reported for an offence kind.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+)]
+pub struct SlashFractionBounds {
+    pub min: Perbill,
+    pub max: Perbill,
+}
+
+impl SlashFractionBounds {
+    /// Clamp `fraction` into the bounds. Zero fractions are raised to the floor as well.
+    pub fn clamp(&self, fraction: Perbill) -> Perbill {
+        fraction.max(self.min).min(self.max)
+    }
+}
+
+/// Maps an offence to the fraction its offender is slashed by.
+pub trait SlashPolicy {
+    /// Fraction an offence of `offence_kind` is slashed by. `reported` is the fraction
+    /// computed by the reporting pallet and `previous_offences` the number of offences of the
+    /// offender reported in the bonded eras before this one.
+    fn slash_fraction(
+        offence_kind: &OffenceKind,
+        reported: Perbill,
+        previous_offences: u32,
+    ) -> Perbill;
+}
+
+/// Slashes by the reported fraction.
+impl SlashPolicy for () {
+    fn slash_fraction(_: &OffenceKind, reported: Perbill, _: u32) -> Perbill {
+        reported
+    }
+}
+
+/// Slashes by the reported fraction, increased by `Increase` of it for each previous offence
+/// of the validator in the bonded eras, up to 100%. A zero `Increase` disables the escalation.
+pub struct EscalatingSlashPolicy<Increase>(PhantomData<Increase>);
+
+impl<Increase: Get<Perbill>> SlashPolicy for EscalatingSlashPolicy<Increase> {
+    fn slash_fraction(_: &OffenceKind, reported: Perbill, previous_offences: u32) -> Perbill {
+        let increase = Increase::get()
+            .mul_floor(reported.deconstruct())
+            .saturating_mul(previous_offences);
+        // Saturates at 100%.
+        Perbill::from_parts(reported.deconstruct().saturating_add(increase))
+    }
+}
+
+/// Convert a slashed proportion into the WAD amount relayed to EigenLayer, `max_wad` being
+/// the amount of a 100% slash.
+///
+/// Rounds down, without overflowing for any `max_wad`.
+pub fn perbill_to_wad(percentage: Perbill, max_wad: u128) -> u128 {
+    percentage.mul_floor(max_wad)
+}
+
+#[derive(Encode, Decode, TypeInfo, Debug, PartialEq, Eq, Clone)]
 pub struct SlashData<AccountId> {
     pub validator: AccountId,
+    /// Address of the EigenLayer operator slashed for `validator`.
+    pub operator: H160,
     pub wad_to_slash: u128,
     pub description: String,
+    /// Digest of the evidence of the slash, see [`Slash::evidence`].
+    pub evidence: H256,
+}
+
+/// How the slashes of a validator sent in the same message are merged into one.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    TypeInfo,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    Default,
+)]
+pub enum SlashAggregation {
+    /// Slash the validator by the largest of its slashes.
+    #[default]
+    Max,
+    /// Slash the validator by the sum of its slashes, up to the WAD of a 100% slash.
+    Sum,
+}
+
+/// Merge the slashes of each validator into a single entry, so a validator slashed several
+/// times is slashed once on EigenLayer.
+///
+/// Validators keep the order of their first slash. Descriptions are joined with `"; "`, and
+/// the evidence of a merged entry is the hash of the evidences it merges.
+pub fn aggregate_slash_data<AccountId: PartialEq>(
+    slashes: Vec<SlashData<AccountId>>,
+    aggregation: SlashAggregation,
+    max_wad: u128,
+) -> Vec<SlashData<AccountId>> {
+    let mut aggregated: Vec<(SlashData<AccountId>, Vec<H256>)> = Vec::new();
+    for slash in slashes {
+        match aggregated
+            .iter_mut()
+            .find(|(merged, _)| merged.validator == slash.validator)
+        {
+            Some((merged, evidences)) => {
+                merged.wad_to_slash = match aggregation {
+                    SlashAggregation::Max => merged.wad_to_slash.max(slash.wad_to_slash),
+                    SlashAggregation::Sum => merged
+                        .wad_to_slash
+                        .saturating_add(slash.wad_to_slash)
+                        .min(max_wad),
+                };
+                merged.description.push_str("; ");
+                merged.description.push_str(&slash.description);
+                evidences.push(slash.evidence);
+            }
+            None => {
+                let evidences = vec![slash.evidence];
+                aggregated.push((slash, evidences));
+            }
+        }
+    }
+
+    aggregated
+        .into_iter()
+        .map(|(mut merged, evidences)| {
+            if evidences.len() > 1 {
+                merged.evidence = <BlakeTwo256 as HashT>::hash_of(&evidences);
+            }
+            merged
+        })
+        .collect()
+}
+
+/// Digest of the evidence of an offence reported on-chain, for one of its offenders.
+///
+/// It is computed like the report id of `pallet_offences`, so on runtimes hashing with
+/// Blake2-256 it is the key of the report in `pallet_offences::Reports`.
+pub fn offence_evidence_digest<O, Id>(offence: &O, offender: &Id) -> H256
+where
+    O: Offence<Id>,
+    Id: Encode,
+{
+    <BlakeTwo256 as HashT>::hash_of(&(O::ID, offence.time_slot().encode(), offender))
 }
 
 // FIXME (nice to have): Merge with SendMessage trait from pallet external-validator-reward (similar trait)
 pub trait SendMessage<AccountId> {
-    type Message;
+    type Message: Encode;
     type Ticket;
 
     fn build(utils: &Vec<SlashData<AccountId>>, era: u32) -> Option<Self::Message>;
@@ -131,22 +297,78 @@ pub mod pallet {
             slash_era: EraIndex,
         },
         /// The slashes message was sent correctly.
-        SlashesMessageSent { message_id: H256 },
-        /// The slashes message failed to send and the batch was moved to the back
-        /// of the queue for retry.
+        SlashesMessageSent {
+            message_id: H256,
+            schema_version: u8,
+        },
+        /// The slashes message failed to send and the batch was scheduled for retry.
         SlashesMessageSendFailed { era: EraIndex, count: u32 },
-        /// A queued slashes batch was retried manually and sent successfully.
+        /// A failed slashes message was retried and failed again.
+        SlashesMessageRetryFailed {
+            id: u32,
+            era: EraIndex,
+            attempts: u32,
+        },
+        /// A slashes message exhausted its retries and was moved to the dead-letter queue.
+        SlashesMessageDeadLettered { id: u32, era: EraIndex, count: u32 },
+        /// A dead-lettered slashes message was dropped without being sent.
+        DeadLetterSlashMessageDiscarded { id: u32, era: EraIndex, count: u32 },
+        /// A failed slashes batch was retried and sent successfully.
         SlashesMessageRetried {
             message_id: H256,
             era: EraIndex,
             count: u32,
+            schema_version: u8,
         },
         /// We injected a slash
-        SlashInjected { slash_id: T::SlashId, era: u32 },
+        SlashInjected {
+            slash_id: T::SlashId,
+            era: u32,
+            evidence_hash: H256,
+        },
         /// Number of slashes processed
         SlashAddedToQueue { number: u32, era: u32 },
-        /// The unsent queue is full; this slash era could not be enqueued.
+        /// The unsent queue is full; the slashes of this era, and of the following ones, are
+        /// queued in the next blocks.
         UnsentQueueFull { era: EraIndex },
+        /// The slashes of `from_era` were full, the slash is applied in `to_era` instead.
+        SlashSpilledOver {
+            slash_id: T::SlashId,
+            from_era: EraIndex,
+            to_era: EraIndex,
+        },
+        /// The slashes of the era the slash was deferred to, and of the following one, are
+        /// full. The slash is not applied.
+        SlashDropped {
+            validator: T::AccountId,
+            slash_id: T::SlashId,
+            era: EraIndex,
+        },
+        /// The bounds of the fractions reported for an offence kind were set or removed.
+        SlashFractionBoundsSet {
+            offence_kind: OffenceKind,
+            bounds: Option<SlashFractionBounds>,
+        },
+        /// A deferred slash was lowered during its defer period.
+        DeferredSlashReduced {
+            era: EraIndex,
+            slash_id: T::SlashId,
+            validator: T::AccountId,
+            old_percentage: Perbill,
+            new_percentage: Perbill,
+        },
+        /// Deferred slashes were cancelled by a message from Ethereum.
+        DeferredSlashesCancelledFromEthereum {
+            era: EraIndex,
+            slash_ids: Vec<T::SlashId>,
+        },
+        /// The slashing mode was set by a message from Ethereum.
+        SlashingModeSetFromEthereum { mode: SlashingModeOption },
+        /// A validator acknowledged a slash of its inbox.
+        SlashAcknowledged {
+            validator: T::AccountId,
+            slash_id: T::SlashId,
+        },
     }
 
     #[pallet::config]
@@ -175,6 +397,11 @@ pub mod pallet {
         #[pallet::constant]
         type BondingDuration: Get<EraIndex>;
 
+        /// Number of eras the slashes sent to EigenLayer are kept in `OffenceHistory`, after
+        /// the era they were applied in. Zero disables the history.
+        #[pallet::constant]
+        type OffenceHistoryDepth: Get<EraIndex>;
+
         // SlashId type, used as a counter on the number of slashes
         type SlashId: Default
             + FullCodec
@@ -186,10 +413,22 @@ pub mod pallet {
             + Saturating
             + One
             + Ord
-            + MaxEncodedLen;
+            + MaxEncodedLen
+            + DecodeWithMemTracking;
 
         type SendMessage: SendMessage<Self::AccountId>;
 
+        /// Payload schema version the message builder currently encodes with.
+        /// Only used to tag the sent events, the builder reads the same source.
+        type OutboundSchemaVersion: Get<u8>;
+
+        /// Notified of the slashes messages accepted by the outbound queue.
+        type OnOutboundMessageSent: OnOutboundMessageSent;
+
+        /// Whether slashes messages are held back, for instance while the bridge is in
+        /// maintenance. Held back slashes stay queued until this turns false.
+        type HoldOutboundMessages: Get<bool>;
+
         /// Era index provider, used to fetch the active era among other things
         type EraIndexProvider: EraIndexProvider;
 
@@ -208,11 +447,50 @@ pub mod pallet {
         #[pallet::constant]
         type QueuedSlashesProcessedPerBlock: Get<u32>;
 
+        /// Maximum number of slashes applied in an era. Slashes reported once an era is full
+        /// are applied in the following era.
+        #[pallet::constant]
+        type MaxSlashesPerEra: Get<u32>;
+
+        /// Maximum number of slashes in a batch of the unsent queue, and so in a slashes
+        /// message. Must be at least `QueuedSlashesProcessedPerBlock`, the size the batches
+        /// are built with.
+        #[pallet::constant]
+        type MaxQueuedSlashes: Get<u32>;
+
+        /// How the slashes of a validator sent in the same message are merged.
+        type SlashAggregationMode: Get<SlashAggregation>;
+
+        /// Fraction the offences reported through `on_offence` are slashed by, before the
+        /// bounds of their kind are applied. `()` slashes by the reported fraction.
+        type SlashPolicy: SlashPolicy;
+
+        /// EigenLayer operators registered for the validators. Validators without one are
+        /// slashed at the address of their own account.
+        type OperatorAddresses: OperatorAddressProvider<Self::AccountId>;
+
+        /// Number of times a failed slashes message is retried before being moved to the
+        /// dead-letter queue.
+        #[pallet::constant]
+        type MaxSlashMessageRetries: Get<u32>;
+
+        /// Number of blocks between two attempts to send a failed slashes message.
+        #[pallet::constant]
+        type SlashMessageRetryInterval: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of unacknowledged slashes kept in the inbox of a validator. Once
+        /// full, the oldest slash is dropped from the inbox.
+        #[pallet::constant]
+        type MaxInboxEntries: Get<u32>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
 
         /// Origin for governance calls such as retrying an unsent slash batch.
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to manually inject slashes, e.g. root or a council multisig.
+        type ForceInjectSlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::error]
@@ -244,6 +522,18 @@ pub mod pallet {
         EraNotInUnsentQueue,
         /// The message delivery still failed on retry.
         MessageSendFailed,
+        /// Manually injected slashes must reference their evidence.
+        MissingEvidenceHash,
+        /// No dead-lettered slashes message with the given id.
+        DeadLetterNotFound,
+        /// The floor of the slash fraction bounds is above their ceiling.
+        InvalidSlashFractionBounds,
+        /// A reduced slash must stay above zero and below its current fraction.
+        InvalidReducedPercentage,
+        /// The era the slash is applied in, and the following one, are full.
+        TooManySlashes,
+        /// The slash is not in the inbox of the caller.
+        SlashNotInInbox,
     }
 
     #[apply(derive_storage_traits)]
@@ -258,6 +548,7 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(migrations::STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     /// All slashing events on validators, mapped by era to the highest slash proportion
@@ -280,12 +571,22 @@ pub mod pallet {
     #[pallet::getter(fn next_slash_id)]
     pub type NextSlashId<T: Config> = StorageValue<_, T::SlashId, ValueQuery>;
 
+    /// Slashes applied in an era, at most `MaxSlashesPerEra`.
+    pub type EraSlashes<T> = BoundedVec<
+        Slash<<T as frame_system::Config>::AccountId, <T as Config>::SlashId>,
+        <T as Config>::MaxSlashesPerEra,
+    >;
+
+    /// Slashes sent in the same message, at most `MaxQueuedSlashes`.
+    pub type SlashBatch<T> = BoundedVec<
+        Slash<<T as frame_system::Config>::AccountId, <T as Config>::SlashId>,
+        <T as Config>::MaxQueuedSlashes,
+    >;
+
     /// All unapplied slashes that are queued for later.
     #[pallet::storage]
-    #[pallet::unbounded]
     #[pallet::getter(fn slashes)]
-    pub type Slashes<T: Config> =
-        StorageMap<_, Twox64Concat, EraIndex, Vec<Slash<T::AccountId, T::SlashId>>, ValueQuery>;
+    pub type Slashes<T: Config> = StorageMap<_, Twox64Concat, EraIndex, EraSlashes<T>, ValueQuery>;
 
     /// Maximum number of unsent slash batches in the retry ring buffer.
     pub const UNSENT_QUEUE_CAPACITY: u32 = 64;
@@ -295,9 +596,8 @@ pub mod pallet {
     /// of slash records. Retries keep the original era so the outbound message id
     /// remains stable across later blocks and eras.
     #[pallet::storage]
-    #[pallet::unbounded]
     pub type UnsentSlashBatch<T: Config> =
-        StorageMap<_, Twox64Concat, u32, (EraIndex, Vec<Slash<T::AccountId, T::SlashId>>)>;
+        StorageMap<_, Twox64Concat, u32, (EraIndex, SlashBatch<T>)>;
 
     /// Ring buffer head: next slot to be processed by `on_initialize`.
     #[pallet::storage]
@@ -308,6 +608,56 @@ pub mod pallet {
     #[pallet::storage]
     pub type UnsentSlashTail<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Era and index of the first slash not added to the unsent queue yet, which was full.
+    /// The slashes from there on, and the ones of the following eras up to the active one,
+    /// are added in the next blocks.
+    #[pallet::storage]
+    pub type UnqueuedSlashesCursor<T: Config> = StorageValue<_, (EraIndex, u32), OptionQuery>;
+
+    /// Maximum number of slash batches waiting in `FailedSlashMessages`. Once full, failed
+    /// batches are moved to the back of the unsent queue instead.
+    pub const FAILED_SLASH_MESSAGES_CAPACITY: u32 = 16;
+
+    /// Slash batches whose outbound message failed, retried every
+    /// `SlashMessageRetryInterval` blocks until `MaxSlashMessageRetries` is reached.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type FailedSlashMessages<T: Config> = CountedStorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        FailedSlashMessage<T::AccountId, T::SlashId, BlockNumberFor<T>>,
+    >;
+
+    /// Id of the next entry of `FailedSlashMessages`.
+    #[pallet::storage]
+    pub type NextFailedSlashMessageId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Maximum number of slash batches in `DeadLetterSlashMessages`. Once full, batches that
+    /// exhausted their retries stay in `FailedSlashMessages` and keep being retried.
+    pub const DEAD_LETTER_SLASH_MESSAGES_CAPACITY: u32 = 64;
+
+    /// Slash batches that exhausted their retries. They are only sent again through
+    /// `redrive_dead_letter_slash_message`, or dropped with
+    /// `discard_dead_letter_slash_message`.
+    #[pallet::storage]
+    pub type DeadLetterSlashMessages<T: Config> =
+        CountedStorageMap<_, Twox64Concat, u32, (EraIndex, SlashBatch<T>)>;
+
+    /// Evidence reference of every manually injected slash, keyed by the era in which
+    /// the slash is applied and its slash id. Pruned together with `Slashes`.
+    #[pallet::storage]
+    pub type InjectedSlashEvidence<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::SlashId, H256>;
+
+    /// Slashes sent to EigenLayer, with the id of the message that carried them, by the era
+    /// they were applied in. Kept for `OffenceHistoryDepth` eras, past the pruning of
+    /// `Slashes` at the end of the bonding period.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type OffenceHistory<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, Vec<OffenceRecord<T::AccountId>>, ValueQuery>;
+
     // Turns slashing on or off
     #[pallet::storage]
     pub type SlashingMode<T: Config> = StorageValue<_, SlashingModeOption, ValueQuery>;
@@ -333,6 +683,48 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Bounds of the fractions reported through `on_offence`, per offence kind. Kinds
+    /// without bounds are slashed by the reported fraction.
+    #[pallet::storage]
+    pub type SlashFractionBoundsOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, OffenceKind, SlashFractionBounds, OptionQuery>;
+
+    /// Number of offences of a validator reported through `on_offence` for an era, counted
+    /// by `SlashPolicy` while the era is bonded. Pruned with the era.
+    #[pallet::storage]
+    pub type OffencesInEra<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Evidence digest per (session, offender), set and consumed together with
+    /// `PendingOffenceKind`.
+    #[pallet::storage]
+    pub type PendingOffenceEvidence<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SessionIndex,
+        Twox64Concat,
+        T::ValidatorId,
+        H256,
+        OptionQuery,
+    >;
+
+    /// Slashes deferred against each validator and not acknowledged by it yet, oldest first.
+    /// Monitoring agents can watch the entry of a validator instead of scanning the events.
+    #[pallet::storage]
+    pub type ValidatorInbox<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<InboxEntry<T::SlashId>, T::MaxInboxEntries>,
+        ValueQuery,
+    >;
+
+    /// Last session in which each validator was reported unresponsive by ImOnline.
+    /// Read by `ImOnlineLiveness` while that session ends.
+    #[pallet::storage]
+    pub type UnresponsiveInSession<T: Config> =
+        StorageMap<_, Twox64Concat, T::ValidatorId, SessionIndex, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -394,6 +786,60 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Lower the fraction of a slash deferred for a later era to `new_percentage`.
+        ///
+        /// Unlike `cancel_deferred_slash`, the validator is still slashed, so `new_percentage`
+        /// must be above zero and below the current fraction of the slash.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::reduce_deferred_slash())]
+        pub fn reduce_deferred_slash(
+            origin: OriginFor<T>,
+            era: EraIndex,
+            slash_index: u32,
+            new_percentage: Perbill,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let active_era = T::EraIndexProvider::active_era().index;
+
+            // We need to be in the defer period
+            ensure!(
+                era <= active_era
+                    .saturating_add(T::SlashDeferDuration::get().saturating_add(One::one()))
+                    && era > active_era,
+                Error::<T>::DeferPeriodIsOver
+            );
+
+            let mut era_slashes = Slashes::<T>::get(era);
+            let slash = era_slashes
+                .get_mut(slash_index as usize)
+                .ok_or(Error::<T>::InvalidSlashIndex)?;
+            ensure!(
+                !new_percentage.is_zero() && new_percentage < slash.percentage,
+                Error::<T>::InvalidReducedPercentage
+            );
+
+            let old_percentage = slash.percentage;
+            slash.percentage = new_percentage;
+            let slash_id = slash.slash_id;
+            let validator = slash.validator.clone();
+            Slashes::<T>::insert(era, &era_slashes);
+
+            Self::deposit_event(Event::<T>::DeferredSlashReduced {
+                era,
+                slash_id,
+                validator,
+                old_percentage,
+                new_percentage,
+            });
+
+            Ok(())
+        }
+
+        /// Manually inject a slash for `validator` in `era`.
+        ///
+        /// `evidence_hash` must reference the evidence backing the slash; it is emitted in
+        /// `SlashInjected` and kept in `InjectedSlashEvidence` while the slash is bonded.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::force_inject_slash())]
         pub fn force_inject_slash(
@@ -402,8 +848,10 @@ pub mod pallet {
             validator: T::AccountId,
             percentage: Perbill,
             offence_kind: OffenceKind,
+            evidence_hash: H256,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::ForceInjectSlashOrigin::ensure_origin(origin)?;
+            ensure!(!evidence_hash.is_zero(), Error::<T>::MissingEvidenceHash);
             let active_era = T::EraIndexProvider::active_era().index;
 
             ensure!(era <= active_era, Error::<T>::ProvidedFutureEra);
@@ -422,6 +870,7 @@ pub mod pallet {
                 validator,
                 slash_defer_duration,
                 offence_kind,
+                evidence_hash,
             )
             .ok_or(Error::<T>::ErrorComputingSlash)?;
 
@@ -433,15 +882,16 @@ pub mod pallet {
                     .saturating_add(One::one())
             };
 
-            Slashes::<T>::mutate(era_to_consider, |era_slashes| {
-                era_slashes.push(slash);
-            });
+            let era_to_consider =
+                Self::defer_slash(era_to_consider, slash).ok_or(Error::<T>::TooManySlashes)?;
+            InjectedSlashEvidence::<T>::insert(era_to_consider, next_slash_id, evidence_hash);
 
             NextSlashId::<T>::put(next_slash_id.saturating_add(One::one()));
 
             Self::deposit_event(Event::<T>::SlashInjected {
                 slash_id: next_slash_id,
                 era: era_to_consider,
+                evidence_hash,
             });
 
             Ok(())
@@ -468,18 +918,15 @@ pub mod pallet {
 
             let (slot, (era, slashes)) = found.ok_or(Error::<T>::EraNotInUnsentQueue)?;
             let count = slashes.len() as u32;
-            let slashes_to_send = slashes
-                .iter()
-                .map(Self::slash_to_send_data)
-                .collect::<Vec<_>>();
-            let message_id = Self::send_slashes_message(&slashes_to_send, era)
-                .ok_or(Error::<T>::MessageSendFailed)?;
+            let message_id =
+                Self::send_slashes(&slashes, era).ok_or(Error::<T>::MessageSendFailed)?;
 
             Self::unsent_queue_remove_slot(slot);
             Self::deposit_event(Event::<T>::SlashesMessageRetried {
                 message_id,
                 era,
                 count,
+                schema_version: T::OutboundSchemaVersion::get(),
             });
 
             Ok(())
@@ -494,18 +941,148 @@ pub mod pallet {
 
             Ok(())
         }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::redrive_dead_letter_slash_message(
+            T::MaxQueuedSlashes::get()
+        ))]
+        pub fn redrive_dead_letter_slash_message(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let (era, slashes) =
+                DeadLetterSlashMessages::<T>::get(id).ok_or(Error::<T>::DeadLetterNotFound)?;
+            let count = slashes.len() as u32;
+            let message_id =
+                Self::send_slashes(&slashes, era).ok_or(Error::<T>::MessageSendFailed)?;
+
+            DeadLetterSlashMessages::<T>::remove(id);
+            Self::deposit_event(Event::<T>::SlashesMessageRetried {
+                message_id,
+                era,
+                count,
+                schema_version: T::OutboundSchemaVersion::get(),
+            });
+
+            Ok(())
+        }
+
+        /// Set the floor and ceiling of the fractions reported for `offence_kind`, or remove
+        /// them with `None`. Only affects offences reported after the call.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_slash_fraction_bounds())]
+        pub fn set_slash_fraction_bounds(
+            origin: OriginFor<T>,
+            offence_kind: OffenceKind,
+            bounds: Option<SlashFractionBounds>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            match bounds {
+                Some(bounds) => {
+                    ensure!(
+                        bounds.min <= bounds.max,
+                        Error::<T>::InvalidSlashFractionBounds
+                    );
+                    SlashFractionBoundsOf::<T>::insert(&offence_kind, bounds);
+                }
+                None => SlashFractionBoundsOf::<T>::remove(&offence_kind),
+            }
+
+            Self::deposit_event(Event::<T>::SlashFractionBoundsSet {
+                offence_kind,
+                bounds,
+            });
+
+            Ok(())
+        }
+
+        /// Acknowledge the slash `slash_id` deferred against the caller, removing it from its
+        /// inbox. Proxies of the validator can acknowledge its slashes on its behalf.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::acknowledge_slash())]
+        pub fn acknowledge_slash(origin: OriginFor<T>, slash_id: T::SlashId) -> DispatchResult {
+            let validator = ensure_signed(origin)?;
+
+            ValidatorInbox::<T>::try_mutate_exists(&validator, |maybe_inbox| -> DispatchResult {
+                let inbox = maybe_inbox.as_mut().ok_or(Error::<T>::SlashNotInInbox)?;
+                let index = inbox
+                    .iter()
+                    .position(|entry| entry.slash_id == slash_id)
+                    .ok_or(Error::<T>::SlashNotInInbox)?;
+                inbox.remove(index);
+                if inbox.is_empty() {
+                    *maybe_inbox = None;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::SlashAcknowledged {
+                validator,
+                slash_id,
+            });
+
+            Ok(())
+        }
+
+        /// Drop the dead-lettered slashes message `id` without sending it, to make room in the
+        /// dead-letter queue once the slashes are no longer worth sending.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::discard_dead_letter_slash_message(
+            T::MaxQueuedSlashes::get()
+        ))]
+        pub fn discard_dead_letter_slash_message(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let (era, slashes) =
+                DeadLetterSlashMessages::<T>::take(id).ok_or(Error::<T>::DeadLetterNotFound)?;
+            Self::deposit_event(Event::<T>::DeadLetterSlashMessageDiscarded {
+                id,
+                era,
+                count: slashes.len() as u32,
+            });
+
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            match Self::process_slashes_queue() {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            if T::HoldOutboundMessages::get() {
+                // Slashes are still queued, and sent once messages are no longer held back
+                return T::WeightInfo::process_slashes_queue(0)
+                    .saturating_add(Self::add_unqueued_slashes_to_queue());
+            }
+
+            let queue_weight = match Self::process_slashes_queue() {
                 ProcessSlashesQueueOutcome::Empty => T::WeightInfo::process_slashes_queue(0),
                 ProcessSlashesQueueOutcome::Sent(count)
                 | ProcessSlashesQueueOutcome::Requeued(count) => {
                     T::WeightInfo::process_slashes_queue(count)
                 }
-            }
+            };
+            let retry_count = Self::process_failed_slash_messages(n).unwrap_or(0);
+            let unqueued_weight = Self::add_unqueued_slashes_to_queue();
+
+            queue_weight
+                .saturating_add(T::WeightInfo::process_failed_slash_messages(retry_count))
+                .saturating_add(unqueued_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxQueuedSlashes::get() > 0,
+                "MaxQueuedSlashes must allow at least one slash per batch"
+            );
+            assert!(
+                T::QueuedSlashesProcessedPerBlock::get() <= T::MaxQueuedSlashes::get(),
+                "Slash batches are built larger than MaxQueuedSlashes"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 }
@@ -587,6 +1164,9 @@ where
         let mut next_slash_id = NextSlashId::<T>::get();
         add_db_reads_writes(1, 0);
 
+        let bonded_eras = BondedEras::<T>::get();
+        add_db_reads_writes(1, 0);
+
         for (details, slash_fraction) in offenders.iter().zip(slash_fraction) {
             let (stash, _) = &details.offender;
 
@@ -609,14 +1189,45 @@ where
             };
             add_db_reads_writes(1, 1);
 
+            // Set alongside the offence kind, missing only if the offence was not reported
+            // through the wrapper.
+            let evidence =
+                pallet::PendingOffenceEvidence::<T>::take(slash_session, stash).unwrap_or_default();
+            add_db_reads_writes(1, 1);
+
             // Skip if the validator is invulnerable.
             if invulnerables.contains(stash) {
                 continue;
             }
 
+            // Escalate the slashes of validators that already offended in the bonded eras.
+            let previous_offences = Self::offences_in_bonded_eras(stash, &bonded_eras);
+            add_db_reads_writes(bonded_eras.len() as u64, 0);
+            let slash_fraction =
+                T::SlashPolicy::slash_fraction(&offence_kind, *slash_fraction, previous_offences);
+
+            let slash_fraction = match SlashFractionBoundsOf::<T>::get(&offence_kind) {
+                Some(bounds) => {
+                    let clamped = bounds.clamp(slash_fraction);
+                    if clamped != slash_fraction {
+                        log!(
+                            log::Level::Debug,
+                            "clamped {:?} slash fraction of {:?} from {:?} to {:?}",
+                            offence_kind,
+                            stash,
+                            slash_fraction,
+                            clamped,
+                        );
+                    }
+                    clamped
+                }
+                None => slash_fraction,
+            };
+            add_db_reads_writes(1, 0);
+
             Self::deposit_event(Event::<T>::SlashReported {
                 validator: stash.clone(),
-                fraction: *slash_fraction,
+                fraction: slash_fraction,
                 slash_era,
             });
 
@@ -628,16 +1239,21 @@ where
             add_db_reads_writes(1, 1);
 
             let slash = compute_slash::<T>(
-                *slash_fraction,
+                slash_fraction,
                 next_slash_id,
                 slash_era,
                 stash.clone(),
                 slash_defer_duration,
                 offence_kind.clone(),
+                evidence,
             );
 
             if let Some(mut slash) = slash {
-                slash.reporters = details.reporters.clone();
+                slash.reporters = BoundedVec::truncate_from(details.reporters.clone());
+
+                // Only offences actually slashed escalate the next ones.
+                OffencesInEra::<T>::mutate(slash_era, stash, |count| count.saturating_inc());
+                add_db_reads_writes(1, 1);
 
                 // Defer to end of some `slash_defer_duration` from now.
                 log!(
@@ -651,21 +1267,18 @@ where
 
                 // Cover slash defer duration equal to 0
                 // Slashes are applied at the end of the current era
-                if slash_defer_duration == 0 {
-                    Slashes::<T>::mutate(active_era.saturating_add(One::one()), move |for_now| {
-                        for_now.push(slash)
-                    });
-                    add_db_reads_writes(1, 1);
+                let apply_era = if slash_defer_duration == 0 {
+                    active_era.saturating_add(One::one())
                 } else {
                     // Else, slashes are applied after slash_defer_period since the slashed era
-                    Slashes::<T>::mutate(
-                        slash_era
-                            .saturating_add(slash_defer_duration)
-                            .saturating_add(One::one()),
-                        move |for_later| for_later.push(slash),
-                    );
-                    add_db_reads_writes(1, 1);
-                }
+                    slash_era
+                        .saturating_add(slash_defer_duration)
+                        .saturating_add(One::one())
+                };
+                // The following era is only written when the first one is full.
+                let _ = Self::defer_slash(apply_era, slash);
+                // Both eras of the slashes, and the inbox of the validator.
+                add_db_reads_writes(3, 3);
 
                 // Fix unwrap
                 next_slash_id = next_slash_id.saturating_add(One::one());
@@ -711,6 +1324,9 @@ where
                         );
                     }
                     Slashes::<T>::remove(pruned_era);
+                    let _ =
+                        InjectedSlashEvidence::<T>::clear_prefix(pruned_era, REMOVE_LIMIT, None);
+                    let _ = OffencesInEra::<T>::clear_prefix(pruned_era, REMOVE_LIMIT, None);
                 }
 
                 if let Some(&(_, first_session, _)) = bonded.first() {
@@ -719,58 +1335,364 @@ where
             }
         });
 
+        if let Some(pruned_era) = era_index.checked_sub(T::OffenceHistoryDepth::get()) {
+            OffenceHistory::<T>::remove(pruned_era);
+        }
+
         Self::add_era_slashes_to_queue(era_index);
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Cancel the slashes of `era` with the given ids, on behalf of EigenLayer governance.
+    ///
+    /// Same defer period rule as `cancel_deferred_slash`, but slashes are selected by id,
+    /// which does not change when other slashes of the era are cancelled. Ids that are not
+    /// found, for instance already cancelled by root, are ignored as long as one matches.
+    pub fn cancel_deferred_slashes_by_id(
+        era: EraIndex,
+        slash_ids: Vec<T::SlashId>,
+    ) -> DispatchResult {
+        let active_era = T::EraIndexProvider::active_era().index;
+
+        // We need to be in the defer period
+        ensure!(
+            era <= active_era
+                .saturating_add(T::SlashDeferDuration::get().saturating_add(One::one()))
+                && era > active_era,
+            Error::<T>::DeferPeriodIsOver
+        );
+        ensure!(!slash_ids.is_empty(), Error::<T>::EmptyTargets);
+
+        let mut era_slashes = Slashes::<T>::get(era);
+        let mut cancelled = Vec::new();
+        era_slashes.retain(|slash| {
+            let cancel = slash_ids.contains(&slash.slash_id);
+            if cancel {
+                cancelled.push(slash.slash_id);
+            }
+            !cancel
+        });
+        ensure!(!cancelled.is_empty(), Error::<T>::InvalidSlashIndex);
+
+        Slashes::<T>::insert(era, &era_slashes);
+        Self::deposit_event(Event::<T>::DeferredSlashesCancelledFromEthereum {
+            era,
+            slash_ids: cancelled,
+        });
+        Ok(())
+    }
+
+    /// Slashes applied from `from_era` to `to_era` included, grouped by offence kind in the
+    /// order the kinds are first met.
+    ///
+    /// Computed from `Slashes`, so only the bonded eras and the eras of the deferred slashes
+    /// are counted; cancelled slashes are not.
+    pub fn offence_stats(
+        from_era: EraIndex,
+        to_era: EraIndex,
+    ) -> Vec<(OffenceKind, OffenceKindStats)> {
+        let mut eras = Slashes::<T>::iter()
+            .filter(|(era, _)| (from_era..=to_era).contains(era))
+            .collect::<Vec<_>>();
+        eras.sort_by_key(|(era, _)| *era);
+
+        let mut stats: Vec<(OffenceKind, OffenceKindStats)> = Vec::new();
+        for slash in eras.into_iter().flat_map(|(_, slashes)| slashes) {
+            let index = match stats
+                .iter()
+                .position(|(kind, _)| kind == &slash.offence_kind)
+            {
+                Some(index) => index,
+                None => {
+                    stats.push((slash.offence_kind.clone(), OffenceKindStats::default()));
+                    stats.len() - 1
+                }
+            };
+            let kind_stats = &mut stats[index].1;
+            kind_stats.count.saturating_inc();
+            if slash.confirmed {
+                kind_stats.confirmed.saturating_inc();
+            }
+            kind_stats
+                .total_slashed_parts
+                .saturating_accrue(slash.percentage.deconstruct().into());
+            kind_stats.max_slashed = kind_stats.max_slashed.max(slash.percentage);
+        }
+        stats
+    }
+
+    /// Slash `validator` would get for an `offence_kind` offence of `fraction` committed in
+    /// `era`, with the WAD value that would be sent to EigenLayer for it, without writing to
+    /// storage.
+    ///
+    /// Follows what `on_offence` does: the fraction is adjusted by `SlashPolicy` and clamped
+    /// into the bounds of the offence kind, and `None` is returned if the offence would not
+    /// be slashed, because slashing is not enabled, `era` is not bonded, the validator is
+    /// invulnerable or it was already slashed as much in `era`. The WAD value is the one of
+    /// this slash alone, before it is merged with the other slashes of the validator sent in
+    /// the same message. Simulated slashes carry no evidence.
+    pub fn simulate_slash(
+        validator: T::AccountId,
+        offence_kind: OffenceKind,
+        fraction: Perbill,
+        era: EraIndex,
+    ) -> Option<SlashData<T::AccountId>> {
+        if SlashingMode::<T>::get() != SlashingModeOption::Enabled {
+            return None;
+        }
+        let bonded_eras = BondedEras::<T>::get();
+        if !bonded_eras
+            .iter()
+            .any(|(bonded_era, _, _)| *bonded_era == era)
+        {
+            return None;
+        }
+        if T::ValidatorIdOf::convert(validator.clone())
+            .is_some_and(|id| T::InvulnerablesProvider::invulnerables().contains(&id))
+        {
+            return None;
+        }
+
+        let fraction = T::SlashPolicy::slash_fraction(
+            &offence_kind,
+            fraction,
+            Self::offences_in_bonded_eras(&validator, &bonded_eras),
+        );
+        let fraction = SlashFractionBoundsOf::<T>::get(&offence_kind)
+            .map_or(fraction, |bounds| bounds.clamp(fraction));
+        let slash = preview_slash::<T>(
+            fraction,
+            NextSlashId::<T>::get(),
+            era,
+            validator,
+            T::SlashDeferDuration::get(),
+            offence_kind,
+            H256::zero(),
+        )?;
+
+        Some(Self::slash_to_send_data(&slash))
+    }
+
+    /// Offences of `validator` reported through `on_offence` for the bonded eras.
+    fn offences_in_bonded_eras(
+        validator: &T::AccountId,
+        bonded_eras: &[(EraIndex, SessionIndex, u64)],
+    ) -> u32 {
+        bonded_eras.iter().fold(0, |count, (era, _, _)| {
+            count.saturating_add(OffencesInEra::<T>::get(era, validator))
+        })
+    }
+
+    /// Set the slashing mode on behalf of EigenLayer governance, as `set_slashing_mode` does
+    /// for root.
+    pub fn set_slashing_mode_from_ethereum(mode: SlashingModeOption) {
+        SlashingMode::<T>::put(mode.clone());
+        Self::deposit_event(Event::<T>::SlashingModeSetFromEthereum { mode });
+    }
+
+    /// Defer `slash` to `era`, or to the following era if the slashes of `era` are full, and
+    /// add it to the inbox of its validator. Returns the era the slash is applied in, `None`
+    /// if it was dropped.
+    fn defer_slash(era: EraIndex, slash: Slash<T::AccountId, T::SlashId>) -> Option<EraIndex> {
+        if Slashes::<T>::try_append(era, &slash).is_ok() {
+            Self::add_to_inbox(&slash, era);
+            return Some(era);
+        }
+
+        let next_era = era.saturating_add(One::one());
+        if Slashes::<T>::try_append(next_era, &slash).is_ok() {
+            Self::deposit_event(Event::<T>::SlashSpilledOver {
+                slash_id: slash.slash_id,
+                from_era: era,
+                to_era: next_era,
+            });
+            Self::add_to_inbox(&slash, next_era);
+            return Some(next_era);
+        }
+
+        log::error!(
+            target: "ext_validators_slashes",
+            "Slashes of eras {era} and {next_era} are full, dropping slash {:?} of {:?}",
+            slash.slash_id,
+            slash.validator,
+        );
+        Self::deposit_event(Event::<T>::SlashDropped {
+            validator: slash.validator,
+            slash_id: slash.slash_id,
+            era,
+        });
+        None
+    }
+
+    /// Add `slash`, applied in `era`, to the inbox of its validator, dropping the oldest entry
+    /// if the inbox is full.
+    fn add_to_inbox(slash: &Slash<T::AccountId, T::SlashId>, era: EraIndex) {
+        let entry = InboxEntry {
+            slash_id: slash.slash_id,
+            era,
+            percentage: slash.percentage,
+            offence_kind: slash.offence_kind.clone(),
+        };
+        ValidatorInbox::<T>::mutate(&slash.validator, |inbox| {
+            if inbox.is_full() && !inbox.is_empty() {
+                inbox.remove(0);
+            }
+            let _ = inbox.try_push(entry);
+        });
+    }
+
     fn add_era_slashes_to_queue(active_era: EraIndex) {
-        let slashes = Slashes::<T>::get(active_era);
-        if slashes.is_empty() {
+        // The slashes of earlier eras are still waiting for room in the queue, the ones of
+        // this era are added after them.
+        if UnqueuedSlashesCursor::<T>::exists() {
             return;
         }
+        Self::queue_slashes(active_era, 0, active_era);
+    }
 
-        let batch_size = T::QueuedSlashesProcessedPerBlock::get().max(1) as usize;
-        let mut enqueued = 0u32;
+    /// Keep adding the slashes left over when the unsent queue was full, up to the active era.
+    fn add_unqueued_slashes_to_queue() -> Weight {
+        let Some((era, index)) = UnqueuedSlashesCursor::<T>::get() else {
+            return T::DbWeight::get().reads(1);
+        };
+        let active_era = T::EraIndexProvider::active_era().index;
+        let batches = Self::queue_slashes(era, index, active_era);
+
+        let eras = u64::from(active_era.saturating_sub(era)).saturating_add(1);
+        T::DbWeight::get().reads_writes(
+            eras.saturating_add(3),
+            u64::from(batches).saturating_mul(2).saturating_add(1),
+        )
+    }
 
-        for batch in slashes.chunks(batch_size) {
-            if Self::unsent_queue_push((active_era, batch.to_vec())) {
+    /// Add the slashes of the eras `era..=last_era` to the unsent queue, starting with the
+    /// `index`-th slash of `era`, in batches of `QueuedSlashesProcessedPerBlock`.
+    ///
+    /// If the queue gets full, where it stopped is kept in `UnqueuedSlashesCursor`. Returns
+    /// the number of batches added.
+    fn queue_slashes(mut era: EraIndex, mut index: u32, last_era: EraIndex) -> u32 {
+        let batch_size = T::QueuedSlashesProcessedPerBlock::get()
+            .min(T::MaxQueuedSlashes::get())
+            .max(1) as usize;
+        let mut batches = 0u32;
+
+        while era <= last_era {
+            let slashes = Slashes::<T>::get(era);
+            let mut enqueued = 0u32;
+            let mut full = false;
+
+            for batch in slashes
+                .get(index as usize..)
+                .unwrap_or_default()
+                .chunks(batch_size)
+            {
+                if !Self::unsent_queue_push((era, BoundedVec::truncate_from(batch.to_vec()))) {
+                    full = true;
+                    break;
+                }
                 enqueued = enqueued.saturating_add(batch.len() as u32);
-            } else {
-                log::warn!(
-                    target: "ext_validators_slashes",
-                    "Unsent slash queue full, cannot enqueue era {active_era}",
-                );
-                Self::deposit_event(Event::<T>::UnsentQueueFull { era: active_era });
-                break;
+                batches.saturating_inc();
             }
-        }
 
-        if enqueued > 0 {
-            Self::deposit_event(Event::<T>::SlashAddedToQueue {
-                number: enqueued,
-                era: active_era,
-            });
+            if enqueued > 0 {
+                Self::deposit_event(Event::<T>::SlashAddedToQueue {
+                    number: enqueued,
+                    era,
+                });
+            }
+
+            if full {
+                let cursor = (era, index.saturating_add(enqueued));
+                if UnqueuedSlashesCursor::<T>::get() != Some(cursor) {
+                    log::warn!(
+                        target: "ext_validators_slashes",
+                        "Unsent slash queue full, deferring the slashes of era {era}",
+                    );
+                    Self::deposit_event(Event::<T>::UnsentQueueFull { era });
+                }
+                UnqueuedSlashesCursor::<T>::put(cursor);
+                return batches;
+            }
+
+            era.saturating_inc();
+            index = 0;
         }
+
+        UnqueuedSlashesCursor::<T>::kill();
+        batches
     }
 
     fn slash_to_send_data(slash: &Slash<T::AccountId, T::SlashId>) -> SlashData<T::AccountId> {
         // Keep the original slash batch intact until delivery succeeds so failed
         // batches can be moved to the back of the queue instead of being dropped.
-        let max_wad = T::MaxSlashWad::get();
-        let wad_to_slash = (slash.percentage.deconstruct() as u128)
-            .saturating_mul(max_wad)
-            .checked_div(1_000_000_000u128)
-            .unwrap_or(0)
-            .min(max_wad);
-
         SlashData {
             validator: slash.validator.clone(),
-            wad_to_slash,
+            operator: Self::operator_address(&slash.validator),
+            wad_to_slash: perbill_to_wad(slash.percentage, T::MaxSlashWad::get()),
             description: slash.offence_kind.to_description(),
+            evidence: slash.evidence,
         }
     }
 
+    /// Address of the EigenLayer operator slashed for `validator`: the one registered for it,
+    /// or the address of its account.
+    pub fn operator_address(validator: &T::AccountId) -> H160 {
+        T::OperatorAddresses::operator_address(validator).unwrap_or_else(|| {
+            let account = validator.encode();
+            let mut address = H160::zero();
+            let len = account.len().min(address.0.len());
+            address.0[..len].copy_from_slice(&account[..len]);
+            address
+        })
+    }
+
+    /// Data sent to EigenLayer for a batch of slashes, with the slashes of a validator merged
+    /// according to `SlashAggregationMode`.
+    fn slashes_to_send_data(
+        slashes: &[Slash<T::AccountId, T::SlashId>],
+    ) -> Vec<SlashData<T::AccountId>> {
+        aggregate_slash_data(
+            slashes.iter().map(Self::slash_to_send_data).collect(),
+            T::SlashAggregationMode::get(),
+            T::MaxSlashWad::get(),
+        )
+    }
+
+    /// Send a batch of slashes applied in `era_index` to EigenLayer, and record them in
+    /// `OffenceHistory` once sent.
+    fn send_slashes(
+        slashes: &[Slash<T::AccountId, T::SlashId>],
+        era_index: EraIndex,
+    ) -> Option<H256> {
+        let message_id =
+            Self::send_slashes_message(&Self::slashes_to_send_data(slashes), era_index)?;
+        Self::record_offence_history(slashes, era_index, message_id);
+        Some(message_id)
+    }
+
+    /// Record the slashes sent in `message_id`, unless `era_index` is already out of the
+    /// `OffenceHistoryDepth` window.
+    fn record_offence_history(
+        slashes: &[Slash<T::AccountId, T::SlashId>],
+        era_index: EraIndex,
+        message_id: H256,
+    ) {
+        let depth = T::OffenceHistoryDepth::get();
+        let active_era = T::EraIndexProvider::active_era().index;
+        if depth == 0 || era_index.saturating_add(depth) <= active_era {
+            return;
+        }
+        OffenceHistory::<T>::mutate(era_index, |history| {
+            history.extend(slashes.iter().map(|slash| OffenceRecord {
+                validator: slash.validator.clone(),
+                offence_kind: slash.offence_kind.clone(),
+                fraction: slash.percentage,
+                message_id,
+            }))
+        });
+    }
+
     fn send_slashes_message(
         slashes_to_send: &[SlashData<T::AccountId>],
         era_index: EraIndex,
@@ -780,6 +1702,7 @@ impl<T: Config> Pallet<T> {
                 log::warn!(target: "ext_validators_slashes", "Failed to build outbound message");
                 None
             })?;
+        let payload_hash = <BlakeTwo256 as HashT>::hash_of(&outbound);
 
         let ticket = T::SendMessage::validate(outbound)
             .map_err(|e| {
@@ -791,7 +1714,7 @@ impl<T: Config> Pallet<T> {
             })
             .ok()?;
 
-        T::SendMessage::deliver(ticket)
+        let message_id = T::SendMessage::deliver(ticket)
             .map_err(|e| {
                 log::warn!(
                     target: "ext_validators_slashes",
@@ -799,7 +1722,15 @@ impl<T: Config> Pallet<T> {
                     e
                 );
             })
-            .ok()
+            .ok()?;
+
+        T::OnOutboundMessageSent::on_outbound_message_sent(
+            OutboundMessageKind::Slashes,
+            Some(era_index),
+            message_id,
+            payload_hash,
+        );
+        Some(message_id)
     }
 
     #[allow(dead_code)]
@@ -807,16 +1738,29 @@ impl<T: Config> Pallet<T> {
         UnsentSlashHead::<T>::get() == UnsentSlashTail::<T>::get()
     }
 
-    #[allow(dead_code)]
-    pub(crate) fn unsent_queue_len() -> u32 {
+    /// Slash batches waiting in the unsent queue, from the next one to be sent, each with
+    /// the era its slashes were applied in.
+    pub fn unsent_queue() -> Vec<(EraIndex, Vec<Slash<T::AccountId, T::SlashId>>)> {
+        let tail = UnsentSlashTail::<T>::get();
+        let mut slot = UnsentSlashHead::<T>::get();
+        let mut batches = Vec::new();
+        while slot != tail {
+            if let Some((era, batch)) = UnsentSlashBatch::<T>::get(slot) {
+                batches.push((era, batch.into_inner()));
+            }
+            slot = (slot + 1) % UNSENT_QUEUE_CAPACITY;
+        }
+        batches
+    }
+
+    /// Number of slash batches waiting in the unsent queue.
+    pub fn unsent_queue_len() -> u32 {
         let head = UnsentSlashHead::<T>::get();
         let tail = UnsentSlashTail::<T>::get();
         tail.wrapping_sub(head) % UNSENT_QUEUE_CAPACITY
     }
 
-    pub(crate) fn unsent_queue_push(
-        entry: (EraIndex, Vec<Slash<T::AccountId, T::SlashId>>),
-    ) -> bool {
+    pub(crate) fn unsent_queue_push(entry: (EraIndex, SlashBatch<T>)) -> bool {
         let head = UnsentSlashHead::<T>::get();
         let tail = UnsentSlashTail::<T>::get();
         let next_tail = (tail + 1) % UNSENT_QUEUE_CAPACITY;
@@ -861,7 +1805,8 @@ impl<T: Config> Pallet<T> {
     /// Retry contract shared with rewards:
     /// - process the current head batch,
     /// - if send succeeds, remove it from the queue,
-    /// - if send fails, move the same batch to the back so later slash batches can progress.
+    /// - if send fails, move the same batch to `FailedSlashMessages`, or to the back of the
+    ///   queue when that is full, so later slash batches can progress.
     pub(crate) fn process_slashes_queue() -> ProcessSlashesQueueOutcome {
         let head = UnsentSlashHead::<T>::get();
         let tail = UnsentSlashTail::<T>::get();
@@ -876,27 +1821,48 @@ impl<T: Config> Pallet<T> {
         };
 
         let slashes_count = slashes.len() as u32;
-        let slashes_to_send = slashes
-            .iter()
-            .map(Self::slash_to_send_data)
-            .collect::<Vec<_>>();
 
-        match Self::send_slashes_message(&slashes_to_send, era_index) {
+        match Self::send_slashes(&slashes, era_index) {
             Some(message_id) => {
                 UnsentSlashBatch::<T>::remove(head);
                 UnsentSlashHead::<T>::put((head + 1) % UNSENT_QUEUE_CAPACITY);
-                Self::deposit_event(Event::<T>::SlashesMessageSent { message_id });
+                Self::deposit_event(Event::<T>::SlashesMessageSent {
+                    message_id,
+                    schema_version: T::OutboundSchemaVersion::get(),
+                });
                 ProcessSlashesQueueOutcome::Sent(slashes_count)
             }
             None => {
                 UnsentSlashBatch::<T>::remove(head);
                 UnsentSlashHead::<T>::put((head + 1) % UNSENT_QUEUE_CAPACITY);
-                UnsentSlashBatch::<T>::insert(tail, (era_index, slashes));
-                UnsentSlashTail::<T>::put((tail + 1) % UNSENT_QUEUE_CAPACITY);
-                log::warn!(
-                    target: "ext_validators_slashes",
-                    "Failed to send {slashes_count} slash entries for era {era_index}, moved batch to back of queue",
-                );
+                if FailedSlashMessages::<T>::count() < FAILED_SLASH_MESSAGES_CAPACITY {
+                    let id = NextFailedSlashMessageId::<T>::mutate(|next| {
+                        let id = *next;
+                        *next = next.wrapping_add(1);
+                        id
+                    });
+                    FailedSlashMessages::<T>::insert(
+                        id,
+                        FailedSlashMessage {
+                            era: era_index,
+                            slashes: slashes.into_inner(),
+                            attempts: 1,
+                            next_retry: frame_system::Pallet::<T>::block_number()
+                                .saturating_add(T::SlashMessageRetryInterval::get()),
+                        },
+                    );
+                    log::warn!(
+                        target: "ext_validators_slashes",
+                        "Failed to send {slashes_count} slash entries for era {era_index}, scheduled retry {id}",
+                    );
+                } else {
+                    UnsentSlashBatch::<T>::insert(tail, (era_index, slashes));
+                    UnsentSlashTail::<T>::put((tail + 1) % UNSENT_QUEUE_CAPACITY);
+                    log::warn!(
+                        target: "ext_validators_slashes",
+                        "Failed to send {slashes_count} slash entries for era {era_index}, moved batch to back of queue",
+                    );
+                }
                 Self::deposit_event(Event::<T>::SlashesMessageSendFailed {
                     era: era_index,
                     count: slashes_count,
@@ -905,6 +1871,120 @@ impl<T: Config> Pallet<T> {
             }
         }
     }
+
+    /// Retry the oldest failed slashes message that is due at block `now`.
+    ///
+    /// A message failing again is rescheduled, or moved to `DeadLetterSlashMessages` once
+    /// it used all of its retries and the dead-letter queue has room. Returns the number of
+    /// slashes of the retried message.
+    pub(crate) fn process_failed_slash_messages(now: BlockNumberFor<T>) -> Option<u32> {
+        let (id, mut failed) = FailedSlashMessages::<T>::iter()
+            .filter(|(_, failed)| failed.next_retry <= now)
+            .min_by_key(|(id, _)| *id)?;
+
+        let slashes_count = failed.slashes.len() as u32;
+
+        if let Some(message_id) = Self::send_slashes(&failed.slashes, failed.era) {
+            FailedSlashMessages::<T>::remove(id);
+            Self::deposit_event(Event::<T>::SlashesMessageRetried {
+                message_id,
+                era: failed.era,
+                count: slashes_count,
+                schema_version: T::OutboundSchemaVersion::get(),
+            });
+            return Some(slashes_count);
+        }
+
+        // The first attempt is the one made from the unsent queue.
+        failed.attempts.saturating_inc();
+        if failed.attempts > T::MaxSlashMessageRetries::get()
+            && DeadLetterSlashMessages::<T>::count() < DEAD_LETTER_SLASH_MESSAGES_CAPACITY
+        {
+            FailedSlashMessages::<T>::remove(id);
+            log::error!(
+                target: "ext_validators_slashes",
+                "Slashes message {id} for era {} failed {} times, moved to dead-letter queue",
+                failed.era,
+                failed.attempts,
+            );
+            Self::deposit_event(Event::<T>::SlashesMessageDeadLettered {
+                id,
+                era: failed.era,
+                count: slashes_count,
+            });
+            // Failed messages are built from a `SlashBatch`, nothing is truncated.
+            DeadLetterSlashMessages::<T>::insert(
+                id,
+                (failed.era, BoundedVec::truncate_from(failed.slashes)),
+            );
+        } else {
+            if failed.attempts > T::MaxSlashMessageRetries::get() {
+                log::error!(
+                    target: "ext_validators_slashes",
+                    "Slashes message {id} for era {} failed {} times, dead-letter queue is full",
+                    failed.era,
+                    failed.attempts,
+                );
+            }
+            Self::deposit_event(Event::<T>::SlashesMessageRetryFailed {
+                id,
+                era: failed.era,
+                attempts: failed.attempts,
+            });
+            failed.next_retry = now.saturating_add(T::SlashMessageRetryInterval::get());
+            FailedSlashMessages::<T>::insert(id, failed);
+        }
+
+        Some(slashes_count)
+    }
+
+    /// Checks the invariants of the slashes storage:
+    /// * `BondedEras` is sorted and covers at most `BondingDuration + 1` eras,
+    /// * the unsent queue only has batches between its head and tail,
+    /// * `FailedSlashMessages` and `DeadLetterSlashMessages` stay within their capacity,
+    /// * the offence kinds and evidences noted for `on_offence` were consumed in their block.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        use frame_support::ensure;
+
+        let bonded_eras = BondedEras::<T>::get();
+        ensure!(
+            bonded_eras.windows(2).all(|eras| eras[0].0 < eras[1].0),
+            "The bonded eras are not sorted"
+        );
+        ensure!(
+            bonded_eras.len() <= T::BondingDuration::get().saturating_add(1) as usize,
+            "More eras are bonded than the bonding duration"
+        );
+
+        let head = UnsentSlashHead::<T>::get();
+        let tail = UnsentSlashTail::<T>::get();
+        ensure!(
+            head < UNSENT_QUEUE_CAPACITY && tail < UNSENT_QUEUE_CAPACITY,
+            "The unsent slashes ring buffer pointers are out of bounds"
+        );
+        let len = Self::unsent_queue_len();
+        ensure!(
+            UnsentSlashBatch::<T>::iter_keys()
+                .all(|slot| slot.wrapping_sub(head) % UNSENT_QUEUE_CAPACITY < len),
+            "An unsent slash batch is stored outside of the ring buffer"
+        );
+
+        ensure!(
+            FailedSlashMessages::<T>::count() <= FAILED_SLASH_MESSAGES_CAPACITY,
+            "Too many failed slash messages are waiting for a retry"
+        );
+        ensure!(
+            DeadLetterSlashMessages::<T>::count() <= DEAD_LETTER_SLASH_MESSAGES_CAPACITY,
+            "Too many slash messages are dead-lettered"
+        );
+        ensure!(
+            PendingOffenceKind::<T>::iter_keys().next().is_none()
+                && PendingOffenceEvidence::<T>::iter_keys().next().is_none(),
+            "An offence kind or evidence was left over after its offence was reported"
+        );
+        Ok(())
+    }
 }
 
 pub(crate) enum ProcessSlashesQueueOutcome {
@@ -913,14 +1993,18 @@ pub(crate) enum ProcessSlashesQueueOutcome {
     Requeued(u32),
 }
 
+/// Maximum number of reporters kept with a slash.
+pub const MAX_SLASH_REPORTERS: u32 = 16;
+
 /// A pending slash record. The value of the slash has been computed but not applied yet,
 /// rather deferred for several eras.
-#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq)]
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq, MaxEncodedLen)]
 pub struct Slash<AccountId, SlashId> {
     /// The stash ID of the offending validator.
     pub validator: AccountId,
-    /// Reporters of the offence; bounty payout recipients.
-    pub reporters: Vec<AccountId>,
+    /// Reporters of the offence; bounty payout recipients. Only the first
+    /// [`MAX_SLASH_REPORTERS`] are kept.
+    pub reporters: BoundedVec<AccountId, ConstU32<MAX_SLASH_REPORTERS>>,
     /// The amount of payout.
     pub slash_id: SlashId,
     pub percentage: Perbill,
@@ -928,6 +2012,59 @@ pub struct Slash<AccountId, SlashId> {
     pub confirmed: bool,
     /// The type of consensus offence (relayed to EigenLayer as a description string).
     pub offence_kind: OffenceKind,
+    /// Digest of the evidence of the offence, captured when it was reported: the offence
+    /// report digest (see [`offence_evidence_digest`]) or the hash given to
+    /// `force_inject_slash`. Zero for offences reported without evidence.
+    pub evidence: H256,
+}
+
+/// Slashes of an offence kind, as returned by [`Pallet::offence_stats`].
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq, Eq, Default)]
+pub struct OffenceKindStats {
+    /// Number of slashes.
+    pub count: u32,
+    /// Number of slashes past their defer period.
+    pub confirmed: u32,
+    /// Sum of the slashed fractions, in parts per billion. Several slashes add up to more
+    /// than one validator stake.
+    pub total_slashed_parts: u64,
+    /// Highest slashed fraction.
+    pub max_slashed: Perbill,
+}
+
+/// A slash deferred against a validator, kept in [`ValidatorInbox`] until the validator
+/// acknowledges it.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq, Eq, MaxEncodedLen)]
+pub struct InboxEntry<SlashId> {
+    pub slash_id: SlashId,
+    /// Era the slash is applied in.
+    pub era: EraIndex,
+    pub percentage: Perbill,
+    pub offence_kind: OffenceKind,
+}
+
+/// A slash sent to EigenLayer, as recorded in [`OffenceHistory`].
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq, Eq)]
+pub struct OffenceRecord<AccountId> {
+    /// The slashed validator.
+    pub validator: AccountId,
+    pub offence_kind: OffenceKind,
+    /// Slashed fraction of the validator stake, before the slashes of the batch are merged.
+    pub fraction: Perbill,
+    /// Id of the outbound message that carried the slash.
+    pub message_id: H256,
+}
+
+/// A slash batch whose outbound message failed and is waiting to be retried.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, Clone, PartialEq)]
+pub struct FailedSlashMessage<AccountId, SlashId, BlockNumber> {
+    /// Era the slashes were applied in, which the outbound message is built for.
+    pub era: EraIndex,
+    pub slashes: Vec<Slash<AccountId, SlashId>>,
+    /// Number of failed attempts to send the message.
+    pub attempts: u32,
+    /// Block from which the message is retried.
+    pub next_retry: BlockNumber,
 }
 
 /// Computes a slash of a validator and nominators. It returns an unapplied
@@ -943,14 +2080,37 @@ pub(crate) fn compute_slash<T: Config>(
     stash: T::AccountId,
     slash_defer_duration: EraIndex,
     offence_kind: OffenceKind,
+    evidence: H256,
+) -> Option<Slash<T::AccountId, T::SlashId>> {
+    let slash = preview_slash::<T>(
+        slash_fraction,
+        slash_id,
+        slash_era,
+        stash,
+        slash_defer_duration,
+        offence_kind,
+        evidence,
+    )?;
+    ValidatorSlashInEra::<T>::insert(slash_era, &slash.validator, slash_fraction);
+
+    Some(slash)
+}
+
+/// The slash [`compute_slash`] would return, without updating the slashing metadata.
+pub(crate) fn preview_slash<T: Config>(
+    slash_fraction: Perbill,
+    slash_id: T::SlashId,
+    slash_era: EraIndex,
+    stash: T::AccountId,
+    slash_defer_duration: EraIndex,
+    offence_kind: OffenceKind,
+    evidence: H256,
 ) -> Option<Slash<T::AccountId, T::SlashId>> {
     let prior_slash_p = ValidatorSlashInEra::<T>::get(slash_era, &stash).unwrap_or(Zero::zero());
 
     // compare slash proportions rather than slash values to avoid issues due to rounding
     // error.
-    if slash_fraction.deconstruct() > prior_slash_p.deconstruct() {
-        ValidatorSlashInEra::<T>::insert(slash_era, &stash, slash_fraction);
-    } else {
+    if slash_fraction.deconstruct() <= prior_slash_p.deconstruct() {
         // we slash based on the max in era - this new event is not the max,
         // so neither the validator or any nominators will need an update.
         //
@@ -963,12 +2123,13 @@ pub(crate) fn compute_slash<T: Config>(
 
     let confirmed = slash_defer_duration.is_zero();
     Some(Slash {
-        validator: stash.clone(),
+        validator: stash,
         percentage: slash_fraction,
         slash_id,
-        reporters: Vec::new(),
+        reporters: BoundedVec::new(),
         confirmed,
         offence_kind,
+        evidence,
     })
 }
 
@@ -1000,9 +2161,14 @@ impl<V, F> HasValidatorId<V> for (V, F) {
 /// 2. **Tag offence kind**: store the `OffenceKind` per offender in `PendingOffenceKind`
 ///    before delegating to the inner reporter, so that `on_offence` can read it via
 ///    `PendingOffenceKind::take()`.
+/// 3. **Capture evidence**: store the digest of the offence report per offender in
+///    `PendingOffenceEvidence`, read by `on_offence` the same way.
+/// 4. **Track liveness**: record the session of liveness offences per offender in
+///    `UnresponsiveInSession`, even when the offence itself is discarded.
 ///
 /// If the inner `report_offence` fails (e.g. duplicate report), stale `PendingOffenceKind`
-/// entries are cleaned up to prevent leaking into unrelated future offences.
+/// and `PendingOffenceEvidence` entries are cleaned up to prevent leaking into unrelated
+/// future offences.
 pub struct EquivocationReportWrapper<T, Inner, Kind>(PhantomData<(T, Inner, Kind)>);
 
 impl<T, Inner, Kind, R, O, Id> ReportOffence<R, Id, O> for EquivocationReportWrapper<T, Inner, Kind>
@@ -1011,11 +2177,21 @@ where
     Inner: ReportOffence<R, Id, O>,
     O: Offence<Id>,
     Kind: OffenceKindProvider,
-    Id: HasValidatorId<T::ValidatorId>,
+    Id: HasValidatorId<T::ValidatorId> + Encode,
 {
     fn report_offence(reporters: Vec<R>, offence: O) -> Result<(), OffenceError> {
-        // Discard offences from before the bonding period.
         let offence_session = offence.session_index();
+        let offenders = offence.offenders();
+        if Kind::kind() == OffenceKind::LivenessOffence {
+            for offender in &offenders {
+                pallet::UnresponsiveInSession::<T>::insert(
+                    offender.validator_id(),
+                    offence_session,
+                );
+            }
+        }
+
+        // Discard offences from before the bonding period.
         let bonded_eras = pallet::BondedEras::<T>::get();
         if bonded_eras
             .first()
@@ -1031,18 +2207,26 @@ where
             return Ok(());
         }
 
-        let offenders = offence.offenders();
         for offender in &offenders {
             pallet::PendingOffenceKind::<T>::insert(
                 offence_session,
                 offender.validator_id(),
                 Kind::kind(),
             );
+            pallet::PendingOffenceEvidence::<T>::insert(
+                offence_session,
+                offender.validator_id(),
+                offence_evidence_digest(&offence, offender),
+            );
         }
         let result = Inner::report_offence(reporters, offence);
         if result.is_err() {
             for offender in &offenders {
                 pallet::PendingOffenceKind::<T>::remove(offence_session, offender.validator_id());
+                pallet::PendingOffenceEvidence::<T>::remove(
+                    offence_session,
+                    offender.validator_id(),
+                );
             }
         }
         result
@@ -1080,3 +2264,21 @@ impl OffenceKindProvider for ImOnlineUnresponsive {
         OffenceKind::LivenessOffence
     }
 }
+
+/// Validators not reported unresponsive by ImOnline in the current session.
+///
+/// ImOnline clears its received heartbeats before the session ends, so they cannot be read
+/// by session end handlers. Instead, this relies on the unresponsiveness offences routed
+/// through `EquivocationReportWrapper<_, _, ImOnlineUnresponsive>`, which are reported right
+/// before the session ends. Until then, every validator is considered online.
+pub struct ImOnlineLiveness<T>(PhantomData<T>);
+
+impl<T> frame_support::traits::Contains<<T as Config>::ValidatorId> for ImOnlineLiveness<T>
+where
+    T: Config + pallet_session::Config,
+{
+    fn contains(validator: &<T as Config>::ValidatorId) -> bool {
+        pallet::UnresponsiveInSession::<T>::get(validator)
+            != Some(pallet_session::Pallet::<T>::current_index())
+    }
+}
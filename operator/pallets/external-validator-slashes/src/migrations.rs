@@ -0,0 +1,298 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the external validator slashes pallet.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "ext_validators_slashes::migration";
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+pub mod v0 {
+    use super::*;
+
+    /// A slash record before the evidence digest was added.
+    #[derive(Encode, Decode, Clone)]
+    pub struct Slash<AccountId, SlashId> {
+        pub validator: AccountId,
+        pub reporters: Vec<AccountId>,
+        pub slash_id: SlashId,
+        pub percentage: Perbill,
+        pub confirmed: bool,
+        pub offence_kind: OffenceKind,
+    }
+
+    #[derive(Encode, Decode)]
+    pub struct FailedSlashMessage<AccountId, SlashId, BlockNumber> {
+        pub era: EraIndex,
+        pub slashes: Vec<Slash<AccountId, SlashId>>,
+        pub attempts: u32,
+        pub next_retry: BlockNumber,
+    }
+}
+
+pub mod v1 {
+    use super::*;
+
+    type OldSlashes<T> =
+        Vec<v0::Slash<<T as frame_system::Config>::AccountId, <T as Config>::SlashId>>;
+
+    /// A slash record before its reporters were bounded.
+    #[derive(Encode, Decode, Clone)]
+    pub struct Slash<AccountId, SlashId> {
+        pub validator: AccountId,
+        pub reporters: Vec<AccountId>,
+        pub slash_id: SlashId,
+        pub percentage: Perbill,
+        pub confirmed: bool,
+        pub offence_kind: OffenceKind,
+        pub evidence: H256,
+    }
+
+    impl<AccountId, SlashId> From<Slash<AccountId, SlashId>> for super::Slash<AccountId, SlashId> {
+        fn from(slash: Slash<AccountId, SlashId>) -> Self {
+            Self {
+                validator: slash.validator,
+                reporters: BoundedVec::truncate_from(slash.reporters),
+                slash_id: slash.slash_id,
+                percentage: slash.percentage,
+                confirmed: slash.confirmed,
+                offence_kind: slash.offence_kind,
+                evidence: slash.evidence,
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub struct FailedSlashMessage<AccountId, SlashId, BlockNumber> {
+        pub era: EraIndex,
+        pub slashes: Vec<Slash<AccountId, SlashId>>,
+        pub attempts: u32,
+        pub next_retry: BlockNumber,
+    }
+
+    /// Adds the evidence digest to the stored slashes.
+    ///
+    /// Injected slashes take the evidence hash kept in `InjectedSlashEvidence`, the ones
+    /// reported through `on_offence` before the upgrade have no evidence and get zero.
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedMigrateV0ToV1<T> {
+        fn migrate_slashes(
+            era: EraIndex,
+            slashes: OldSlashes<T>,
+        ) -> Vec<super::Slash<T::AccountId, T::SlashId>> {
+            slashes
+                .into_iter()
+                .map(|slash| super::Slash {
+                    evidence: InjectedSlashEvidence::<T>::get(era, slash.slash_id)
+                        .unwrap_or_default(),
+                    validator: slash.validator,
+                    reporters: BoundedVec::truncate_from(slash.reporters),
+                    slash_id: slash.slash_id,
+                    percentage: slash.percentage,
+                    confirmed: slash.confirmed,
+                    offence_kind: slash.offence_kind,
+                })
+                .collect()
+        }
+    }
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads_writes = 0u64;
+
+            // Values over the bounds introduced in v2 are truncated here, see
+            // `v2::UncheckedMigrateV1ToV2`.
+            Slashes::<T>::translate::<OldSlashes<T>, _>(|era, slashes| {
+                reads_writes.saturating_accrue(1 + slashes.len() as u64);
+                Some(BoundedVec::truncate_from(Self::migrate_slashes(
+                    era, slashes,
+                )))
+            });
+            UnsentSlashBatch::<T>::translate::<(EraIndex, OldSlashes<T>), _>(
+                |_, (era, slashes)| {
+                    reads_writes.saturating_accrue(1 + slashes.len() as u64);
+                    Some((
+                        era,
+                        BoundedVec::truncate_from(Self::migrate_slashes(era, slashes)),
+                    ))
+                },
+            );
+            FailedSlashMessages::<T>::translate::<
+                v0::FailedSlashMessage<T::AccountId, T::SlashId, BlockNumberFor<T>>,
+                _,
+            >(|_, failed| {
+                reads_writes.saturating_accrue(1 + failed.slashes.len() as u64);
+                Some(super::FailedSlashMessage {
+                    era: failed.era,
+                    slashes: Self::migrate_slashes(failed.era, failed.slashes),
+                    attempts: failed.attempts,
+                    next_retry: failed.next_retry,
+                })
+            });
+            DeadLetterSlashMessages::<T>::translate::<(EraIndex, OldSlashes<T>), _>(
+                |_, (era, slashes)| {
+                    reads_writes.saturating_accrue(1 + slashes.len() as u64);
+                    Some((
+                        era,
+                        BoundedVec::truncate_from(Self::migrate_slashes(era, slashes)),
+                    ))
+                },
+            );
+
+            log::info!(
+                target: LOG_TARGET,
+                "Added the evidence digest to stored slashes",
+            );
+            T::DbWeight::get().reads_writes(reads_writes, reads_writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let slashes_count = Slashes::<T>::iter_keys().count() as u32;
+            Ok(slashes_count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let slashes_count = u32::decode(&mut state.as_slice())
+                .map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            frame_support::ensure!(
+                Slashes::<T>::iter_keys().count() as u32 == slashes_count,
+                "Slashes were lost during the migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    type OldSlashes<T> =
+        Vec<v1::Slash<<T as frame_system::Config>::AccountId, <T as Config>::SlashId>>;
+
+    /// Bounds the slashes of an era, the slash batches of the unsent queue and the reporters
+    /// of every stored slash.
+    ///
+    /// The encoding is unchanged, values within the bounds are kept as they are. Slashes over
+    /// the bounds, which the pallet could not have stored since, are dropped and logged.
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedMigrateV1ToV2<T> {
+        fn bound<S: Get<u32>>(
+            what: &str,
+            era: EraIndex,
+            slashes: OldSlashes<T>,
+        ) -> BoundedVec<Slash<T::AccountId, T::SlashId>, S> {
+            if slashes.len() > S::get() as usize {
+                log::error!(
+                    target: LOG_TARGET,
+                    "Dropping {} {what} of era {era} over the bound of {}",
+                    slashes.len() - S::get() as usize,
+                    S::get(),
+                );
+            }
+            BoundedVec::truncate_from(slashes.into_iter().map(Into::into).collect())
+        }
+    }
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads_writes = 0u64;
+
+            Slashes::<T>::translate::<OldSlashes<T>, _>(|era, slashes| {
+                reads_writes.saturating_accrue(1);
+                Some(Self::bound::<T::MaxSlashesPerEra>("slashes", era, slashes))
+            });
+            UnsentSlashBatch::<T>::translate::<(EraIndex, OldSlashes<T>), _>(
+                |_, (era, slashes)| {
+                    reads_writes.saturating_accrue(1);
+                    Some((
+                        era,
+                        Self::bound::<T::MaxQueuedSlashes>("unsent slashes", era, slashes),
+                    ))
+                },
+            );
+            FailedSlashMessages::<T>::translate::<
+                v1::FailedSlashMessage<T::AccountId, T::SlashId, BlockNumberFor<T>>,
+                _,
+            >(|_, failed| {
+                reads_writes.saturating_accrue(1);
+                Some(FailedSlashMessage {
+                    era: failed.era,
+                    slashes: failed.slashes.into_iter().map(Into::into).collect(),
+                    attempts: failed.attempts,
+                    next_retry: failed.next_retry,
+                })
+            });
+            DeadLetterSlashMessages::<T>::translate::<(EraIndex, OldSlashes<T>), _>(
+                |_, (era, slashes)| {
+                    reads_writes.saturating_accrue(1);
+                    Some((
+                        era,
+                        Self::bound::<T::MaxQueuedSlashes>("dead-lettered slashes", era, slashes),
+                    ))
+                },
+            );
+
+            log::info!(target: LOG_TARGET, "Bounded the stored slashes");
+            T::DbWeight::get().reads_writes(reads_writes, reads_writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let slashes_count = Slashes::<T>::iter_keys().count() as u32;
+            Ok(slashes_count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let slashes_count = u32::decode(&mut state.as_slice())
+                .map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            frame_support::ensure!(
+                Slashes::<T>::iter_keys().count() as u32 == slashes_count,
+                "Slashes were lost during the migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migrates the pallet to storage version 1, once.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Migrates the pallet to [`STORAGE_VERSION`] 2, once.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    v2::UncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
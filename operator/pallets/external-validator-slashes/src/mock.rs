@@ -20,17 +20,18 @@ use pallet_external_validators::traits::EraIndex;
 use pallet_external_validators::traits::EraIndexProvider;
 use pallet_external_validators::traits::ExternalIndexProvider;
 use pallet_external_validators::traits::InvulnerablesProvider;
+use pallet_external_validators::traits::OperatorAddressProvider;
 use {
     crate as external_validator_slashes,
     core::cell::RefCell,
     frame_support::{
-        parameter_types,
-        traits::{ConstU128, ConstU16, ConstU32, ConstU64, Get},
+        ord_parameter_types, parameter_types,
+        traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Get},
         weights::constants::RocksDbWeight,
     },
     frame_system as system,
     snowbridge_outbound_queue_primitives::{SendError, SendMessageFeeProvider},
-    sp_core::H256,
+    sp_core::{H160, H256},
     sp_runtime::{
         testing::UintAuthorityId,
         traits::{BlakeTwo256, ConvertInto, IdentityLookup},
@@ -137,6 +138,7 @@ thread_local! {
     pub static MOCK_SEND_MESSAGE_SHOULD_FAIL: RefCell<bool> = const { RefCell::new(false) };
     pub static LAST_SENT_SLASHES: RefCell<Vec<crate::SlashData<AccountId>>> = RefCell::new(Vec::new());
     pub static LAST_BUILT_ERA: RefCell<Option<EraIndex>> = const { RefCell::new(None) };
+    pub static SLASH_AGGREGATION: RefCell<crate::SlashAggregation> = const { RefCell::new(crate::SlashAggregation::Max) };
 }
 
 impl MockEraIndexProvider {
@@ -219,6 +221,19 @@ impl DeferPeriodGetter {
     }
 }
 
+pub struct SlashAggregationGetter;
+impl Get<crate::SlashAggregation> for SlashAggregationGetter {
+    fn get() -> crate::SlashAggregation {
+        SLASH_AGGREGATION.with(|q| *q.borrow())
+    }
+}
+
+impl SlashAggregationGetter {
+    pub fn with_aggregation(aggregation: crate::SlashAggregation) {
+        SLASH_AGGREGATION.with(|r| *r.borrow_mut() = aggregation);
+    }
+}
+
 pub struct MockOkOutboundQueue;
 impl MockOkOutboundQueue {
     pub fn last_sent_slashes() -> Vec<crate::SlashData<AccountId>> {
@@ -270,6 +285,23 @@ impl ExternalIndexProvider for TimestampProvider {
 
 parameter_types! {
     pub const BondingDuration: u32 = 5u32;
+    pub static MaxSlashesPerEra: u32 = 1000;
+    pub static RepeatOffenceSlashIncrease: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+    pub static RegisteredOperators: Vec<(u64, H160)> = vec![];
+    pub static HoldOutboundMessages: bool = false;
+}
+
+pub struct MockOperatorAddresses;
+impl OperatorAddressProvider<u64> for MockOperatorAddresses {
+    fn operator_address(validator: &u64) -> Option<H160> {
+        RegisteredOperators::get()
+            .into_iter()
+            .find_map(|(registered, operator)| (registered == *validator).then_some(operator))
+    }
+}
+
+ord_parameter_types! {
+    pub const SlashCouncil: u64 = 99;
 }
 
 impl external_validator_slashes::Config for Test {
@@ -278,15 +310,31 @@ impl external_validator_slashes::Config for Test {
     type ValidatorIdOf = IdentityValidator;
     type SlashDeferDuration = DeferPeriodGetter;
     type BondingDuration = BondingDuration;
+    type OffenceHistoryDepth = ConstU32<10>;
     type SlashId = u32;
     type EraIndexProvider = MockEraIndexProvider;
     type InvulnerablesProvider = MockInvulnerableProvider;
     type ExternalIndexProvider = TimestampProvider;
     type MaxSlashWad = ConstU128<50_000_000_000_000_000>;
     type QueuedSlashesProcessedPerBlock = ConstU32<20>;
+    type MaxSlashesPerEra = MaxSlashesPerEra;
+    type MaxQueuedSlashes = ConstU32<200>;
+    type SlashAggregationMode = SlashAggregationGetter;
+    type SlashPolicy = crate::EscalatingSlashPolicy<RepeatOffenceSlashIncrease>;
+    type OperatorAddresses = MockOperatorAddresses;
+    type MaxSlashMessageRetries = ConstU32<3>;
+    type SlashMessageRetryInterval = ConstU64<2>;
+    type MaxInboxEntries = ConstU32<3>;
     type WeightInfo = ();
     type SendMessage = MockOkOutboundQueue;
+    type OutboundSchemaVersion = ConstU8<1>;
+    type OnOutboundMessageSent = ();
+    type HoldOutboundMessages = HoldOutboundMessages;
     type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+    type ForceInjectSlashOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<u64>,
+        frame_system::EnsureSignedBy<SlashCouncil, u64>,
+    >;
 }
 
 pub struct FullIdentificationOf;
@@ -387,6 +435,10 @@ pub type MockBabeWrapper =
 pub type MockGrandpaWrapper =
     crate::EquivocationReportWrapper<Test, MockInnerReporter, crate::GrandpaEquivocation>;
 
+/// Type alias for the wrapper using the mock reporter with ImOnlineUnresponsive kind.
+pub type MockImOnlineWrapper =
+    crate::EquivocationReportWrapper<Test, MockInnerReporter, crate::ImOnlineUnresponsive>;
+
 pub fn run_block() {
     run_to_block(System::block_number() + 1);
 }
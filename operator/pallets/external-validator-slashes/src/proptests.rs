@@ -0,0 +1,164 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Property tests of the WAD conversion and of the cancellation of deferred slashes.
+
+use {
+    super::*,
+    crate::mock::{
+        new_test_ext, ExternalValidatorSlashes, MockEraIndexProvider, RuntimeOrigin, Test,
+    },
+    proptest::prelude::*,
+    sp_core::U256,
+};
+
+const PERBILL_ONE: u32 = 1_000_000_000;
+
+/// Perbill parts, biased towards the boundaries.
+fn perbill_parts() -> impl Strategy<Value = u32> {
+    prop_oneof![
+        Just(0),
+        Just(1),
+        Just(PERBILL_ONE - 1),
+        Just(PERBILL_ONE),
+        0..=PERBILL_ONE,
+    ]
+}
+
+/// WAD amounts of a 100% slash, biased towards the values where `parts * max_wad` overflows.
+fn max_wads() -> impl Strategy<Value = u128> {
+    let overflow_threshold = u128::MAX / PERBILL_ONE as u128;
+    prop_oneof![
+        Just(0),
+        Just(1),
+        Just(1_000_000_000_000_000_000),
+        Just(overflow_threshold),
+        Just(overflow_threshold + 1),
+        Just(u128::MAX - 1),
+        Just(u128::MAX),
+        overflow_threshold - 1_000..=overflow_threshold + 1_000,
+        any::<u128>(),
+    ]
+}
+
+/// `parts * max_wad / 1e9` rounded down, computed without overflow.
+fn exact_wad(parts: u32, max_wad: u128) -> u128 {
+    (U256::from(parts) * U256::from(max_wad) / U256::from(PERBILL_ONE)).as_u128()
+}
+
+proptest! {
+    #[test]
+    fn wad_is_the_exact_floored_proportion(parts in perbill_parts(), max_wad in max_wads()) {
+        let wad = perbill_to_wad(Perbill::from_parts(parts), max_wad);
+
+        prop_assert_eq!(wad, exact_wad(parts, max_wad));
+        prop_assert!(wad <= max_wad);
+    }
+
+    #[test]
+    fn wad_bounds_are_exact(max_wad in max_wads()) {
+        prop_assert_eq!(perbill_to_wad(Perbill::zero(), max_wad), 0);
+        prop_assert_eq!(perbill_to_wad(Perbill::one(), max_wad), max_wad);
+    }
+
+    #[test]
+    fn wad_is_monotonic_in_the_proportion(
+        a in perbill_parts(),
+        b in perbill_parts(),
+        max_wad in max_wads(),
+    ) {
+        let (lower, higher) = (a.min(b), a.max(b));
+
+        prop_assert!(
+            perbill_to_wad(Perbill::from_parts(lower), max_wad)
+                <= perbill_to_wad(Perbill::from_parts(higher), max_wad)
+        );
+    }
+
+    #[test]
+    fn wad_is_monotonic_in_the_max_wad(
+        parts in perbill_parts(),
+        a in max_wads(),
+        b in max_wads(),
+    ) {
+        let (lower, higher) = (a.min(b), a.max(b));
+
+        prop_assert!(
+            perbill_to_wad(Perbill::from_parts(parts), lower)
+                <= perbill_to_wad(Perbill::from_parts(parts), higher)
+        );
+    }
+
+    #[test]
+    fn cancel_deferred_slash_removes_exactly_the_given_indices(
+        slashes_count in 0u32..8,
+        slash_indices in prop::collection::vec(0u32..10, 0..6),
+    ) {
+        new_test_ext().execute_with(|| -> Result<(), TestCaseError> {
+            // Slashes of era 0 are deferred to era 3 with the default defer period of 2.
+            Pallet::<Test>::on_era_start(1, 0, 1);
+            MockEraIndexProvider::with_era(1);
+            for validator in 0..slashes_count {
+                ExternalValidatorSlashes::force_inject_slash(
+                    RuntimeOrigin::root(),
+                    0,
+                    10 + validator as u64,
+                    Perbill::from_percent(10),
+                    OffenceKind::LivenessOffence,
+                    H256::zero(),
+                )
+                .map_err(|err| TestCaseError::fail(format!("{err:?}")))?;
+            }
+            let slashes = Slashes::<Test>::get(3);
+            prop_assert_eq!(slashes.len() as u32, slashes_count);
+
+            let result = ExternalValidatorSlashes::cancel_deferred_slash(
+                RuntimeOrigin::root(),
+                3,
+                slash_indices.clone(),
+            );
+
+            let sorted_and_unique = slash_indices.windows(2).all(|pair| pair[0] < pair[1]);
+            let expected_error = if slash_indices.is_empty() {
+                Some(Error::<Test>::EmptyTargets)
+            } else if !sorted_and_unique {
+                Some(Error::<Test>::NotSortedAndUnique)
+            } else if slash_indices.iter().any(|index| *index >= slashes_count) {
+                Some(Error::<Test>::InvalidSlashIndex)
+            } else {
+                None
+            };
+
+            match expected_error {
+                Some(error) => {
+                    prop_assert_eq!(result, Err(error.into()));
+                    prop_assert_eq!(Slashes::<Test>::get(3), slashes);
+                }
+                None => {
+                    prop_assert_eq!(result, Ok(()));
+                    let remaining = slashes
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(index, _)| !slash_indices.contains(&(*index as u32)))
+                        .map(|(_, slash)| slash)
+                        .collect::<Vec<_>>();
+                    prop_assert_eq!(Slashes::<Test>::get(3), remaining);
+                }
+            }
+            Ok(())
+        })?;
+    }
+}
@@ -18,44 +18,33 @@ use {
     super::*,
     crate::{
         mock::{
-            new_test_ext, run_block, DeferPeriodGetter, ExternalValidatorSlashes, MockBabeWrapper,
-            MockEraIndexProvider, MockGrandpaWrapper, MockInnerReporter, MockOffence,
-            MockOkOutboundQueue, RuntimeEvent, RuntimeOrigin, System, Test,
+            new_test_ext, run_block, run_to_block, DeferPeriodGetter, ExternalValidatorSlashes,
+            HoldOutboundMessages, MockBabeWrapper, MockEraIndexProvider, MockGrandpaWrapper,
+            MockImOnlineWrapper, MockInnerReporter, MockOffence, MockOkOutboundQueue, RuntimeEvent,
+            RuntimeOrigin, System, Test,
         },
         OffenceKind, Slash,
     },
-    frame_support::{assert_noop, assert_ok, BoundedVec},
+    frame_support::{assert_noop, assert_ok, traits::Contains, BoundedVec},
     sp_staking::offence::ReportOffence,
 };
 
-fn queued_slash_ids() -> Vec<u32> {
-    let mut queued = Vec::new();
-    let mut slot = UnsentSlashHead::<Test>::get();
-    let tail = UnsentSlashTail::<Test>::get();
-
-    while slot != tail {
-        if let Some((_, batch)) = UnsentSlashBatch::<Test>::get(slot) {
-            queued.extend(batch.into_iter().map(|slash| slash.slash_id));
-        }
-        slot = (slot + 1) % UNSENT_QUEUE_CAPACITY;
-    }
+fn evidence_hash() -> H256 {
+    H256::repeat_byte(0xee)
+}
 
-    queued
+fn queued_slash_ids() -> Vec<u32> {
+    ExternalValidatorSlashes::unsent_queue()
+        .into_iter()
+        .flat_map(|(_, batch)| batch.into_iter().map(|slash| slash.slash_id))
+        .collect()
 }
 
 fn queued_batch_eras() -> Vec<u32> {
-    let mut queued = Vec::new();
-    let mut slot = UnsentSlashHead::<Test>::get();
-    let tail = UnsentSlashTail::<Test>::get();
-
-    while slot != tail {
-        if let Some((era, _)) = UnsentSlashBatch::<Test>::get(slot) {
-            queued.push(era);
-        }
-        slot = (slot + 1) % UNSENT_QUEUE_CAPACITY;
-    }
-
-    queued
+    ExternalValidatorSlashes::unsent_queue()
+        .into_iter()
+        .map(|(era, _)| era)
+        .collect()
 }
 
 fn unsent_queue_len() -> u32 {
@@ -72,6 +61,7 @@ fn root_can_inject_manual_offence() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_eq!(
             Slashes::<Test>::get(get_slashing_era(0)),
@@ -79,11 +69,12 @@ fn root_can_inject_manual_offence() {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
                 confirmed: false,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::Custom(BoundedVec::truncate_from(
                     b"Test slash".to_vec()
                 )),
+                evidence: evidence_hash(),
             }]
         );
         assert_eq!(NextSlashId::<Test>::get(), 1);
@@ -101,6 +92,7 @@ fn cannot_inject_future_era_offence() {
                 1u64,
                 Perbill::from_percent(75),
                 OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+                evidence_hash(),
             ),
             Error::<Test>::ProvidedFutureEra
         );
@@ -119,12 +111,79 @@ fn cannot_inject_era_offence_too_far_in_the_past() {
                 4u64,
                 Perbill::from_percent(75),
                 OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+                evidence_hash(),
             ),
             Error::<Test>::ProvidedNonSlashableEra
         );
     });
 }
 
+#[test]
+fn council_can_inject_manual_offence_with_evidence() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+            RuntimeOrigin::signed(99),
+            0,
+            1u64,
+            Perbill::from_percent(75),
+            OffenceKind::LivenessOffence,
+            evidence_hash(),
+        ));
+
+        let slash_era = get_slashing_era(0);
+        assert_eq!(Slashes::<Test>::get(slash_era).len(), 1);
+        assert_eq!(
+            InjectedSlashEvidence::<Test>::get(slash_era, 0),
+            Some(evidence_hash())
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashInjected {
+                slash_id: 0,
+                era: slash_era,
+                evidence_hash: evidence_hash(),
+            },
+        ));
+    });
+}
+
+#[test]
+fn non_authorized_origin_cannot_inject_offence() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_noop!(
+            ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::signed(1),
+                0,
+                1u64,
+                Perbill::from_percent(75),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn cannot_inject_offence_without_evidence() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_noop!(
+            ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                1u64,
+                Perbill::from_percent(75),
+                OffenceKind::LivenessOffence,
+                H256::zero(),
+            ),
+            Error::<Test>::MissingEvidenceHash
+        );
+    });
+}
+
 #[test]
 fn root_can_cancel_deferred_slash() {
     new_test_ext().execute_with(|| {
@@ -135,6 +194,7 @@ fn root_can_cancel_deferred_slash() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_ok!(ExternalValidatorSlashes::cancel_deferred_slash(
             RuntimeOrigin::root(),
@@ -156,6 +216,7 @@ fn root_cannot_cancel_deferred_slash_if_outside_deferring_period() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
 
         start_era(4, 0, 4);
@@ -177,6 +238,7 @@ fn root_cannot_cancel_out_of_bounds() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_noop!(
             ExternalValidatorSlashes::cancel_deferred_slash(
@@ -199,6 +261,7 @@ fn root_cannot_cancel_duplicates() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_noop!(
             ExternalValidatorSlashes::cancel_deferred_slash(RuntimeOrigin::root(), 3, vec![0, 0]),
@@ -217,6 +280,7 @@ fn root_cannot_cancel_if_not_sorted() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_ok!(ExternalValidatorSlashes::force_inject_slash(
             RuntimeOrigin::root(),
@@ -224,6 +288,7 @@ fn root_cannot_cancel_if_not_sorted() {
             2u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_noop!(
             ExternalValidatorSlashes::cancel_deferred_slash(RuntimeOrigin::root(), 3, vec![1, 0]),
@@ -232,6 +297,187 @@ fn root_cannot_cancel_if_not_sorted() {
     });
 }
 
+#[test]
+fn ethereum_can_cancel_deferred_slashes_by_id() {
+    new_test_ext().execute_with(|| {
+        start_era(1, 0, 1);
+        for validator in [1u64, 2u64] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                validator,
+                Perbill::from_percent(75),
+                OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+                evidence_hash(),
+            ));
+        }
+
+        // Unknown ids are ignored
+        assert_ok!(ExternalValidatorSlashes::cancel_deferred_slashes_by_id(
+            3,
+            vec![1, 7]
+        ));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::DeferredSlashesCancelledFromEthereum {
+                era: 3,
+                slash_ids: vec![1],
+            },
+        ));
+
+        let remaining = Slashes::<Test>::get(get_slashing_era(0));
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].slash_id, 0);
+        assert_eq!(remaining[0].validator, 1);
+    });
+}
+
+#[test]
+fn ethereum_cannot_cancel_unknown_or_elapsed_slashes() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+
+        assert_noop!(
+            ExternalValidatorSlashes::cancel_deferred_slashes_by_id(3, vec![]),
+            Error::<Test>::EmptyTargets
+        );
+        assert_noop!(
+            ExternalValidatorSlashes::cancel_deferred_slashes_by_id(3, vec![5]),
+            Error::<Test>::InvalidSlashIndex
+        );
+
+        start_era(4, 0, 4);
+
+        assert_noop!(
+            ExternalValidatorSlashes::cancel_deferred_slashes_by_id(3, vec![0]),
+            Error::<Test>::DeferPeriodIsOver
+        );
+    });
+}
+
+#[test]
+fn ethereum_can_set_slashing_mode() {
+    new_test_ext().execute_with(|| {
+        ExternalValidatorSlashes::set_slashing_mode_from_ethereum(SlashingModeOption::LogOnly);
+
+        assert_eq!(SlashingMode::<Test>::get(), SlashingModeOption::LogOnly);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashingModeSetFromEthereum {
+                mode: SlashingModeOption::LogOnly,
+            },
+        ));
+    });
+}
+
+fn inject_deferred_slash() {
+    start_era(1, 0, 1);
+    assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+        RuntimeOrigin::root(),
+        0,
+        1u64,
+        Perbill::from_percent(75),
+        OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+        evidence_hash(),
+    ));
+}
+
+#[test]
+fn root_can_reduce_deferred_slash() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+        assert_ok!(ExternalValidatorSlashes::reduce_deferred_slash(
+            RuntimeOrigin::root(),
+            3,
+            0,
+            Perbill::from_percent(10),
+        ));
+
+        let slashes = Slashes::<Test>::get(get_slashing_era(0));
+        assert_eq!(slashes.len(), 1);
+        assert_eq!(slashes[0].percentage, Perbill::from_percent(10));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::DeferredSlashReduced {
+                era: 3,
+                slash_id: 0,
+                validator: 1,
+                old_percentage: Perbill::from_percent(75),
+                new_percentage: Perbill::from_percent(10),
+            },
+        ));
+    });
+}
+
+#[test]
+fn reduced_slash_must_be_lower_and_non_zero() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+        for new_percentage in [
+            Perbill::zero(),
+            Perbill::from_percent(75),
+            Perbill::from_percent(80),
+        ] {
+            assert_noop!(
+                ExternalValidatorSlashes::reduce_deferred_slash(
+                    RuntimeOrigin::root(),
+                    3,
+                    0,
+                    new_percentage,
+                ),
+                Error::<Test>::InvalidReducedPercentage
+            );
+        }
+    });
+}
+
+#[test]
+fn root_cannot_reduce_out_of_bounds() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+        assert_noop!(
+            ExternalValidatorSlashes::reduce_deferred_slash(
+                RuntimeOrigin::root(),
+                3,
+                1,
+                Perbill::from_percent(10),
+            ),
+            Error::<Test>::InvalidSlashIndex
+        );
+    });
+}
+
+#[test]
+fn root_cannot_reduce_deferred_slash_if_outside_deferring_period() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+        start_era(4, 0, 4);
+
+        assert_noop!(
+            ExternalValidatorSlashes::reduce_deferred_slash(
+                RuntimeOrigin::root(),
+                3,
+                0,
+                Perbill::from_percent(10),
+            ),
+            Error::<Test>::DeferPeriodIsOver
+        );
+    });
+}
+
+#[test]
+fn non_root_cannot_reduce_deferred_slash() {
+    new_test_ext().execute_with(|| {
+        inject_deferred_slash();
+        assert_noop!(
+            ExternalValidatorSlashes::reduce_deferred_slash(
+                RuntimeOrigin::signed(1),
+                3,
+                0,
+                Perbill::from_percent(10),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
 #[test]
 fn test_after_bonding_period_we_can_remove_slashes() {
     new_test_ext().execute_with(|| {
@@ -246,6 +492,7 @@ fn test_after_bonding_period_we_can_remove_slashes() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
 
         assert_eq!(
@@ -254,11 +501,12 @@ fn test_after_bonding_period_we_can_remove_slashes() {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
                 confirmed: false,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::Custom(BoundedVec::truncate_from(
                     b"Test slash".to_vec()
                 )),
+                evidence: evidence_hash(),
             }]
         );
 
@@ -294,9 +542,10 @@ fn test_on_offence_injects_offences() {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
                 confirmed: false,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::LivenessOffence,
+                evidence: H256::zero(),
             }]
         );
     });
@@ -361,6 +610,7 @@ fn defer_period_of_zero_confirms_immediately_slashes() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_eq!(
             Slashes::<Test>::get(get_slashing_era(0)),
@@ -368,11 +618,12 @@ fn defer_period_of_zero_confirms_immediately_slashes() {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
                 confirmed: true,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::Custom(BoundedVec::truncate_from(
                     b"Test slash".to_vec()
                 )),
+                evidence: evidence_hash(),
             }]
         );
     });
@@ -389,6 +640,7 @@ fn we_cannot_cancel_anything_with_defer_period_zero() {
             1u64,
             Perbill::from_percent(75),
             OffenceKind::Custom(BoundedVec::truncate_from(b"Test slash".to_vec())),
+            evidence_hash(),
         ));
         assert_noop!(
             ExternalValidatorSlashes::cancel_deferred_slash(RuntimeOrigin::root(), 0, vec![0]),
@@ -420,9 +672,10 @@ fn test_on_offence_defer_period_0() {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
                 confirmed: true,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::LivenessOffence,
+                evidence: H256::zero(),
             }]
         );
         start_era(2, 2, 2);
@@ -454,9 +707,10 @@ fn test_slashes_command_matches_event() {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
                 confirmed: true,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::LivenessOffence,
+                evidence: H256::zero(),
             }]
         );
         start_era(2, 2, 2);
@@ -465,6 +719,7 @@ fn test_slashes_command_matches_event() {
         System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
             crate::Event::SlashesMessageSent {
                 message_id: Default::default(),
+                schema_version: 1,
             },
         ));
     });
@@ -530,6 +785,44 @@ fn wad_conversion_50_percent_slash_maps_to_half_max_slash_wad() {
     });
 }
 
+#[test]
+fn slashes_are_sent_to_the_registered_operator() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        crate::mock::RegisteredOperators::set(vec![(3, H160::repeat_byte(0x33))]);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        PendingOffenceKind::<Test>::insert(0, 3u64, OffenceKind::LivenessOffence);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(50)],
+            0,
+        );
+
+        start_era(2, 2, 2);
+        run_block();
+
+        let sent = MockOkOutboundQueue::last_sent_slashes();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].validator, 3);
+        assert_eq!(sent[0].operator, H160::repeat_byte(0x33));
+    });
+}
+
+#[test]
+fn validators_without_operator_are_slashed_at_their_account_address() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            ExternalValidatorSlashes::operator_address(&3),
+            H160::from_low_u64_le(3)
+        );
+    });
+}
+
 #[test]
 fn wad_conversion_zero_percent_slash_maps_to_zero() {
     new_test_ext().execute_with(|| {
@@ -622,15 +915,12 @@ fn test_on_offence_defer_period_0_messages_get_queued() {
 }
 
 #[test]
-fn failed_slashes_batch_is_moved_to_back_of_queue() {
+fn held_outbound_messages_keep_slashes_queued() {
     new_test_ext().execute_with(|| {
         crate::mock::DeferPeriodGetter::with_defer_period(0);
-        MockOkOutboundQueue::set_should_fail(true);
-
         start_era(0, 0, 0);
         start_era(1, 1, 1);
-
-        for i in 0..25 {
+        for i in 0..5 {
             PendingOffenceKind::<Test>::insert(0, 3 + i, OffenceKind::LivenessOffence);
             Pallet::<Test>::on_offence(
                 &[OffenceDetails {
@@ -642,25 +932,22 @@ fn failed_slashes_batch_is_moved_to_back_of_queue() {
             );
         }
 
+        HoldOutboundMessages::set(true);
         start_era(2, 2, 2);
-        assert_eq!(queued_slash_ids(), (0..25).collect::<Vec<_>>());
-        assert_eq!(queued_batch_eras(), vec![2, 2]);
+        assert_eq!(queued_slash_ids(), (0..5).collect::<Vec<_>>());
 
         run_block();
+        run_block();
+        assert_eq!(queued_slash_ids(), (0..5).collect::<Vec<_>>());
 
-        assert_eq!(unsent_queue_len(), 2);
-        assert_eq!(
-            queued_slash_ids(),
-            (20..25).chain(0..20).collect::<Vec<_>>()
-        );
-        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
-            crate::Event::SlashesMessageSendFailed { era: 2, count: 20 },
-        ));
+        HoldOutboundMessages::set(false);
+        run_block();
+        assert!(ExternalValidatorSlashes::unsent_queue_is_empty());
     });
 }
 
 #[test]
-fn failed_slashes_batch_retries_after_send_is_reenabled() {
+fn failed_slashes_batch_is_moved_to_failed_messages() {
     new_test_ext().execute_with(|| {
         crate::mock::DeferPeriodGetter::with_defer_period(0);
         MockOkOutboundQueue::set_should_fail(true);
@@ -681,35 +968,47 @@ fn failed_slashes_batch_retries_after_send_is_reenabled() {
         }
 
         start_era(2, 2, 2);
-        run_block();
-        assert_eq!(
-            queued_slash_ids(),
-            (20..25).chain(0..20).collect::<Vec<_>>()
-        );
-
-        start_era(3, 3, 3);
-        MockOkOutboundQueue::set_should_fail(false);
+        assert_eq!(queued_slash_ids(), (0..25).collect::<Vec<_>>());
+        assert_eq!(queued_batch_eras(), vec![2, 2]);
 
         run_block();
+
         assert_eq!(unsent_queue_len(), 1);
-        assert_eq!(queued_slash_ids(), (0..20).collect::<Vec<_>>());
-        assert_eq!(MockOkOutboundQueue::last_sent_slashes().len(), 5);
-        assert_eq!(MockOkOutboundQueue::last_built_era(), Some(2));
+        assert_eq!(queued_slash_ids(), (20..25).collect::<Vec<_>>());
+        let failed = FailedSlashMessages::<Test>::get(0).unwrap();
+        assert_eq!(failed.era, 2);
+        assert_eq!(failed.attempts, 1);
+        assert_eq!(failed.next_retry, System::block_number() + 2);
+        assert_eq!(
+            failed
+                .slashes
+                .iter()
+                .map(|slash| slash.slash_id)
+                .collect::<Vec<_>>(),
+            (0..20).collect::<Vec<_>>()
+        );
         System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
-            crate::Event::SlashesMessageSent {
-                message_id: Default::default(),
-            },
+            crate::Event::SlashesMessageSendFailed { era: 2, count: 20 },
         ));
-
-        run_block();
-        assert!(ExternalValidatorSlashes::unsent_queue_is_empty());
     });
 }
 
 #[test]
-fn retry_extrinsic_succeeds_for_matching_era() {
+fn failed_slashes_batch_is_moved_to_back_of_queue_when_failed_messages_are_full() {
     new_test_ext().execute_with(|| {
+        for id in 0..FAILED_SLASH_MESSAGES_CAPACITY {
+            FailedSlashMessages::<Test>::insert(
+                id,
+                FailedSlashMessage {
+                    era: 1,
+                    slashes: vec![],
+                    attempts: 1,
+                    next_retry: 1_000,
+                },
+            );
+        }
         crate::mock::DeferPeriodGetter::with_defer_period(0);
+        MockOkOutboundQueue::set_should_fail(true);
 
         start_era(0, 0, 0);
         start_era(1, 1, 1);
@@ -727,17 +1026,306 @@ fn retry_extrinsic_succeeds_for_matching_era() {
         }
 
         start_era(2, 2, 2);
-        start_era(5, 5, 5);
-
-        assert_ok!(ExternalValidatorSlashes::retry_unsent_slash_era(
-            RuntimeOrigin::root(),
-            2,
-        ));
+        run_block();
 
-        assert_eq!(unsent_queue_len(), 1);
-        assert_eq!(queued_slash_ids(), (20..25).collect::<Vec<_>>());
-        assert_eq!(MockOkOutboundQueue::last_built_era(), Some(2));
-    });
+        assert_eq!(unsent_queue_len(), 2);
+        assert_eq!(
+            queued_slash_ids(),
+            (20..25).chain(0..20).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            FailedSlashMessages::<Test>::count(),
+            FAILED_SLASH_MESSAGES_CAPACITY
+        );
+    });
+}
+
+#[test]
+fn failed_slashes_batch_retries_after_send_is_reenabled() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        MockOkOutboundQueue::set_should_fail(true);
+
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        for i in 0..25 {
+            PendingOffenceKind::<Test>::insert(0, 3 + i, OffenceKind::LivenessOffence);
+            Pallet::<Test>::on_offence(
+                &[OffenceDetails {
+                    offender: (3 + i, ()),
+                    reporters: vec![],
+                }],
+                &[Perbill::from_percent(75)],
+                0,
+            );
+        }
+
+        start_era(2, 2, 2);
+        run_block();
+        assert_eq!(queued_slash_ids(), (20..25).collect::<Vec<_>>());
+
+        start_era(3, 3, 3);
+        MockOkOutboundQueue::set_should_fail(false);
+
+        // The rest of the queue is sent while the failed batch waits for its retry
+        run_block();
+        assert!(ExternalValidatorSlashes::unsent_queue_is_empty());
+        assert_eq!(MockOkOutboundQueue::last_sent_slashes().len(), 5);
+        assert_eq!(FailedSlashMessages::<Test>::count(), 1);
+        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashesMessageSent {
+                message_id: Default::default(),
+                schema_version: 1,
+            },
+        ));
+
+        run_block();
+        assert_eq!(FailedSlashMessages::<Test>::count(), 0);
+        assert_eq!(MockOkOutboundQueue::last_sent_slashes().len(), 20);
+        assert_eq!(MockOkOutboundQueue::last_built_era(), Some(2));
+        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashesMessageRetried {
+                message_id: Default::default(),
+                era: 2,
+                count: 20,
+                schema_version: 1,
+            },
+        ));
+    });
+}
+
+#[test]
+fn failed_slashes_message_is_dead_lettered_after_max_retries() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        MockOkOutboundQueue::set_should_fail(true);
+
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        for i in 0..25 {
+            PendingOffenceKind::<Test>::insert(0, 3 + i, OffenceKind::LivenessOffence);
+            Pallet::<Test>::on_offence(
+                &[OffenceDetails {
+                    offender: (3 + i, ()),
+                    reporters: vec![],
+                }],
+                &[Perbill::from_percent(75)],
+                0,
+            );
+        }
+
+        start_era(2, 2, 2);
+        run_block();
+        let first_failure = System::block_number();
+
+        // Retried every 2 blocks, 3 times
+        for retry in 1..=3u32 {
+            run_to_block(first_failure + 2 * retry as u64);
+            if retry < 3 {
+                System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+                    crate::Event::SlashesMessageRetryFailed {
+                        id: 0,
+                        era: 2,
+                        attempts: retry + 1,
+                    },
+                ));
+            }
+        }
+
+        assert!(!FailedSlashMessages::<Test>::contains_key(0));
+        let (era, slashes) = DeadLetterSlashMessages::<Test>::get(0).unwrap();
+        assert_eq!(era, 2);
+        assert_eq!(slashes.len(), 20);
+        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashesMessageDeadLettered {
+                id: 0,
+                era: 2,
+                count: 20,
+            },
+        ));
+    });
+}
+
+#[test]
+fn root_can_redrive_dead_letter_slash_message() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        DeadLetterSlashMessages::<Test>::insert(
+            7,
+            (
+                2,
+                BoundedVec::truncate_from(vec![Slash {
+                    validator: 5000u64,
+                    reporters: Default::default(),
+                    slash_id: 0,
+                    percentage: Perbill::from_percent(10),
+                    confirmed: true,
+                    offence_kind: OffenceKind::LivenessOffence,
+                    evidence: H256::zero(),
+                }]),
+            ),
+        );
+
+        assert_noop!(
+            ExternalValidatorSlashes::redrive_dead_letter_slash_message(
+                RuntimeOrigin::signed(1),
+                7
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        MockOkOutboundQueue::set_should_fail(true);
+        assert_noop!(
+            ExternalValidatorSlashes::redrive_dead_letter_slash_message(RuntimeOrigin::root(), 7),
+            Error::<Test>::MessageSendFailed
+        );
+
+        MockOkOutboundQueue::set_should_fail(false);
+        assert_ok!(ExternalValidatorSlashes::redrive_dead_letter_slash_message(
+            RuntimeOrigin::root(),
+            7
+        ));
+        assert!(!DeadLetterSlashMessages::<Test>::contains_key(7));
+        assert_eq!(MockOkOutboundQueue::last_built_era(), Some(2));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashesMessageRetried {
+                message_id: Default::default(),
+                era: 2,
+                count: 1,
+                schema_version: 1,
+            },
+        ));
+
+        assert_noop!(
+            ExternalValidatorSlashes::redrive_dead_letter_slash_message(RuntimeOrigin::root(), 7),
+            Error::<Test>::DeadLetterNotFound
+        );
+    });
+}
+
+#[test]
+fn exhausted_slashes_message_keeps_retrying_when_dead_letters_are_full() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for id in 0..DEAD_LETTER_SLASH_MESSAGES_CAPACITY {
+            DeadLetterSlashMessages::<Test>::insert(100 + id, (1, BoundedVec::new()));
+        }
+        FailedSlashMessages::<Test>::insert(
+            0,
+            FailedSlashMessage {
+                era: 2,
+                slashes: vec![],
+                attempts: 3,
+                next_retry: 1,
+            },
+        );
+        MockOkOutboundQueue::set_should_fail(true);
+
+        assert_eq!(Pallet::<Test>::process_failed_slash_messages(1), Some(0));
+
+        let failed = FailedSlashMessages::<Test>::get(0).unwrap();
+        assert_eq!(failed.attempts, 4);
+        assert_eq!(failed.next_retry, 3);
+        assert!(!DeadLetterSlashMessages::<Test>::contains_key(0));
+        assert_eq!(
+            DeadLetterSlashMessages::<Test>::count(),
+            DEAD_LETTER_SLASH_MESSAGES_CAPACITY
+        );
+
+        // Once a dead letter is discarded, the message takes its place.
+        assert_ok!(ExternalValidatorSlashes::discard_dead_letter_slash_message(
+            RuntimeOrigin::root(),
+            100
+        ));
+        assert_eq!(Pallet::<Test>::process_failed_slash_messages(3), Some(0));
+        assert!(!FailedSlashMessages::<Test>::contains_key(0));
+        assert!(DeadLetterSlashMessages::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn root_can_discard_dead_letter_slash_message() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        DeadLetterSlashMessages::<Test>::insert(
+            7,
+            (
+                2,
+                BoundedVec::truncate_from(vec![Slash {
+                    validator: 5000u64,
+                    reporters: Default::default(),
+                    slash_id: 0,
+                    percentage: Perbill::from_percent(10),
+                    confirmed: true,
+                    offence_kind: OffenceKind::LivenessOffence,
+                    evidence: H256::zero(),
+                }]),
+            ),
+        );
+
+        assert_noop!(
+            ExternalValidatorSlashes::discard_dead_letter_slash_message(
+                RuntimeOrigin::signed(1),
+                7
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(ExternalValidatorSlashes::discard_dead_letter_slash_message(
+            RuntimeOrigin::root(),
+            7
+        ));
+        assert_eq!(DeadLetterSlashMessages::<Test>::count(), 0);
+        assert!(MockOkOutboundQueue::last_sent_slashes().is_empty());
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::DeadLetterSlashMessageDiscarded {
+                id: 7,
+                era: 2,
+                count: 1,
+            },
+        ));
+
+        assert_noop!(
+            ExternalValidatorSlashes::discard_dead_letter_slash_message(RuntimeOrigin::root(), 7),
+            Error::<Test>::DeadLetterNotFound
+        );
+    });
+}
+
+#[test]
+fn retry_extrinsic_succeeds_for_matching_era() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        for i in 0..25 {
+            PendingOffenceKind::<Test>::insert(0, 3 + i, OffenceKind::LivenessOffence);
+            Pallet::<Test>::on_offence(
+                &[OffenceDetails {
+                    offender: (3 + i, ()),
+                    reporters: vec![],
+                }],
+                &[Perbill::from_percent(75)],
+                0,
+            );
+        }
+
+        start_era(2, 2, 2);
+        start_era(5, 5, 5);
+
+        assert_ok!(ExternalValidatorSlashes::retry_unsent_slash_era(
+            RuntimeOrigin::root(),
+            2,
+        ));
+
+        assert_eq!(unsent_queue_len(), 1);
+        assert_eq!(queued_slash_ids(), (20..25).collect::<Vec<_>>());
+        assert_eq!(MockOkOutboundQueue::last_built_era(), Some(2));
+    });
 }
 
 #[test]
@@ -802,15 +1390,16 @@ fn unsent_queue_full_emits_event() {
         for i in 0..63u32 {
             let slash = Slash {
                 validator: 1000 + i as u64,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: i,
                 percentage: Perbill::from_percent(1),
                 confirmed: true,
                 offence_kind: OffenceKind::LivenessOffence,
+                evidence: H256::zero(),
             };
             assert!(ExternalValidatorSlashes::unsent_queue_push((
                 1,
-                vec![slash]
+                BoundedVec::truncate_from(vec![slash])
             )));
         }
 
@@ -818,11 +1407,12 @@ fn unsent_queue_full_emits_event() {
             2,
             vec![Slash {
                 validator: 5000u64,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 999,
                 percentage: Perbill::from_percent(10),
                 confirmed: true,
                 offence_kind: OffenceKind::LivenessOffence,
+                evidence: H256::zero(),
             }],
         );
 
@@ -830,6 +1420,107 @@ fn unsent_queue_full_emits_event() {
 
         assert_eq!(unsent_queue_len(), 63);
         assert_eq!(Slashes::<Test>::get(2).len(), 1);
+        assert_eq!(UnqueuedSlashesCursor::<Test>::get(), Some((2, 0)));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::UnsentQueueFull { era: 2 },
+        ));
+
+        // Sending the first batch makes room for the slash of era 2 in the next block.
+        run_block();
+
+        assert_eq!(unsent_queue_len(), 63);
+        assert_eq!(unsent_queue_eras().last(), Some(&2));
+        assert_eq!(unsent_queue_slash_ids().last(), Some(&999));
+        assert_eq!(UnqueuedSlashesCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn slash_spills_over_to_next_era_when_era_is_full() {
+    new_test_ext().execute_with(|| {
+        crate::mock::MaxSlashesPerEra::set(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        for validator in [3u64, 4] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                1,
+                validator,
+                Perbill::from_percent(10),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ));
+        }
+
+        let era = get_slashing_era(1);
+        assert_eq!(Slashes::<Test>::get(era).len(), 1);
+        assert_eq!(Slashes::<Test>::get(era)[0].validator, 3);
+        assert_eq!(Slashes::<Test>::get(era + 1)[0].validator, 4);
+        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashSpilledOver {
+                slash_id: 1,
+                from_era: era,
+                to_era: era + 1,
+            },
+        ));
+
+        // Both eras are full now.
+        assert_noop!(
+            ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                1,
+                5u64,
+                Perbill::from_percent(10),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ),
+            Error::<Test>::TooManySlashes
+        );
+    });
+}
+
+#[test]
+fn offence_is_dropped_when_era_and_next_era_are_full() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        crate::mock::MaxSlashesPerEra::set(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        for validator in [4u64, 5] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                validator,
+                Perbill::from_percent(10),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ));
+        }
+
+        PendingOffenceKind::<Test>::insert(0, 3u64, OffenceKind::LivenessOffence);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            0,
+        );
+
+        let era = get_slashing_era(0);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashDropped {
+                validator: 3,
+                slash_id: 2,
+                era,
+            },
+        ));
+        assert!(Slashes::<Test>::get(era)
+            .iter()
+            .chain(Slashes::<Test>::get(era + 1).iter())
+            .all(|slash| slash.validator != 3));
     });
 }
 
@@ -941,9 +1632,10 @@ fn on_offence_reads_pending_offence_kind_from_double_map() {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
                 confirmed: false,
-                reporters: vec![],
+                reporters: Default::default(),
                 slash_id: 0,
                 offence_kind: OffenceKind::BabeEquivocation,
+                evidence: H256::zero(),
             }]
         );
 
@@ -1060,24 +1752,58 @@ fn wrapper_sets_pending_offence_kind_per_session_and_offender() {
 }
 
 #[test]
-fn wrapper_cleans_up_pending_offence_kind_on_error() {
+fn wrapper_records_unresponsive_validators() {
     new_test_ext().execute_with(|| {
         start_era(0, 0, 0);
-        start_era(1, 1, 1);
         MockInnerReporter::reset();
-        MockInnerReporter::set_should_fail(true);
+        let session = pallet_session::Pallet::<Test>::current_index();
 
-        let result = MockBabeWrapper::report_offence(
+        let _ = MockBabeWrapper::report_offence(
             Vec::<u64>::new(),
             MockOffence {
-                session_index: 0,
-                offenders: vec![(3, ()), (4, ())],
+                session_index: session,
+                offenders: vec![(4, ())],
             },
         );
-
-        assert!(result.is_err());
-        // Entries should have been cleaned up.
-        assert_eq!(PendingOffenceKind::<Test>::get(0, 3u64), None);
+        let _ = MockImOnlineWrapper::report_offence(
+            Vec::<u64>::new(),
+            MockOffence {
+                session_index: session,
+                offenders: vec![(3, ())],
+            },
+        );
+
+        // Only liveness offences are recorded.
+        assert_eq!(UnresponsiveInSession::<Test>::get(3u64), Some(session));
+        assert_eq!(UnresponsiveInSession::<Test>::get(4u64), None);
+        assert!(!ImOnlineLiveness::<Test>::contains(&3));
+        assert!(ImOnlineLiveness::<Test>::contains(&4));
+
+        // An unresponsiveness from a previous session does not count in the next one.
+        pallet_session::CurrentIndex::<Test>::put(session + 1);
+        assert!(ImOnlineLiveness::<Test>::contains(&3));
+    });
+}
+
+#[test]
+fn wrapper_cleans_up_pending_offence_kind_on_error() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        MockInnerReporter::reset();
+        MockInnerReporter::set_should_fail(true);
+
+        let result = MockBabeWrapper::report_offence(
+            Vec::<u64>::new(),
+            MockOffence {
+                session_index: 0,
+                offenders: vec![(3, ()), (4, ())],
+            },
+        );
+
+        assert!(result.is_err());
+        // Entries should have been cleaned up.
+        assert_eq!(PendingOffenceKind::<Test>::get(0, 3u64), None);
         assert_eq!(PendingOffenceKind::<Test>::get(0, 4u64), None);
     });
 }
@@ -1122,6 +1848,534 @@ fn wrapper_error_cleanup_does_not_affect_other_sessions() {
     });
 }
 
+// ── Evidence digest tests ──
+
+#[test]
+fn wrapper_sets_pending_offence_evidence_per_offender() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        MockInnerReporter::reset();
+
+        let offence = || MockOffence {
+            session_index: 0,
+            offenders: vec![(3, ()), (4, ())],
+        };
+        assert_ok!(MockBabeWrapper::report_offence(
+            Vec::<u64>::new(),
+            offence()
+        ));
+
+        let evidence_3 = offence_evidence_digest(&offence(), &(3u64, ()));
+        let evidence_4 = offence_evidence_digest(&offence(), &(4u64, ()));
+        assert_ne!(evidence_3, evidence_4);
+        assert_eq!(
+            PendingOffenceEvidence::<Test>::get(0, 3u64),
+            Some(evidence_3)
+        );
+        assert_eq!(
+            PendingOffenceEvidence::<Test>::get(0, 4u64),
+            Some(evidence_4)
+        );
+    });
+}
+
+#[test]
+fn wrapper_cleans_up_pending_offence_evidence_on_error() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        MockInnerReporter::reset();
+        MockInnerReporter::set_should_fail(true);
+
+        let result = MockBabeWrapper::report_offence(
+            Vec::<u64>::new(),
+            MockOffence {
+                session_index: 0,
+                offenders: vec![(3, ())],
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(PendingOffenceEvidence::<Test>::get(0, 3u64), None);
+    });
+}
+
+#[test]
+fn offence_evidence_is_stored_with_slash_and_sent() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        let evidence = H256::repeat_byte(0xab);
+        PendingOffenceKind::<Test>::insert(0, 3u64, OffenceKind::GrandpaEquivocation);
+        PendingOffenceEvidence::<Test>::insert(0, 3u64, evidence);
+
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            0,
+        );
+
+        assert_eq!(PendingOffenceEvidence::<Test>::get(0, 3u64), None);
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(0))
+                .iter()
+                .map(|slash| slash.evidence)
+                .collect::<Vec<_>>(),
+            vec![evidence]
+        );
+
+        start_era(2, 2, 2);
+        run_block();
+
+        let sent = MockOkOutboundQueue::last_sent_slashes();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].evidence, evidence);
+    });
+}
+
+#[test]
+fn injected_slash_is_sent_with_its_evidence_hash() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+            RuntimeOrigin::root(),
+            0,
+            3u64,
+            Perbill::from_percent(75),
+            OffenceKind::LivenessOffence,
+            evidence_hash(),
+        ));
+
+        start_era(1, 1, 1);
+        run_block();
+
+        let sent = MockOkOutboundQueue::last_sent_slashes();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].evidence, evidence_hash());
+    });
+}
+
+#[test]
+fn migration_adds_evidence_to_stored_slashes() {
+    use crate::migrations::{v0, MigrateV0ToV1};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        let old_slash = |slash_id| v0::Slash::<u64, u32> {
+            validator: 3,
+            reporters: vec![],
+            slash_id,
+            percentage: Perbill::from_percent(10),
+            confirmed: false,
+            offence_kind: OffenceKind::LivenessOffence,
+        };
+        // Slash 0 was injected with evidence, slash 1 was reported before the upgrade.
+        InjectedSlashEvidence::<Test>::insert(3, 0, evidence_hash());
+        frame_support::storage::unhashed::put(
+            &Slashes::<Test>::hashed_key_for(3),
+            &vec![old_slash(0), old_slash(1)],
+        );
+        frame_support::storage::unhashed::put(
+            &UnsentSlashBatch::<Test>::hashed_key_for(0),
+            &(3u32, vec![old_slash(0)]),
+        );
+        frame_support::storage::unhashed::put(
+            &DeadLetterSlashMessages::<Test>::hashed_key_for(0),
+            &(3u32, vec![old_slash(1)]),
+        );
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(1)
+        );
+        assert_eq!(
+            Slashes::<Test>::get(3)
+                .iter()
+                .map(|slash| slash.evidence)
+                .collect::<Vec<_>>(),
+            vec![evidence_hash(), H256::zero()]
+        );
+        assert_eq!(
+            UnsentSlashBatch::<Test>::get(0).map(|(_, slashes)| slashes[0].evidence),
+            Some(evidence_hash())
+        );
+        assert_eq!(
+            DeadLetterSlashMessages::<Test>::get(0).map(|(_, slashes)| slashes[0].evidence),
+            Some(H256::zero())
+        );
+    });
+}
+
+#[test]
+fn migration_bounds_stored_slashes() {
+    use crate::migrations::{v1, MigrateV1ToV2};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        crate::mock::MaxSlashesPerEra::set(2);
+
+        let old_slash = |slash_id| v1::Slash::<u64, u32> {
+            validator: 3,
+            reporters: (0..20).collect(),
+            slash_id,
+            percentage: Perbill::from_percent(10),
+            confirmed: false,
+            offence_kind: OffenceKind::LivenessOffence,
+            evidence: evidence_hash(),
+        };
+        frame_support::storage::unhashed::put(
+            &Slashes::<Test>::hashed_key_for(3),
+            &vec![old_slash(0), old_slash(1), old_slash(2)],
+        );
+        frame_support::storage::unhashed::put(
+            &UnsentSlashBatch::<Test>::hashed_key_for(0),
+            &(3u32, vec![old_slash(0)]),
+        );
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            crate::migrations::STORAGE_VERSION
+        );
+        // Slashes over the bound are dropped, reporters over the bound are truncated.
+        let slashes = Slashes::<Test>::get(3);
+        assert_eq!(
+            slashes
+                .iter()
+                .map(|slash| slash.slash_id)
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            slashes[0].reporters.len() as u32,
+            crate::MAX_SLASH_REPORTERS
+        );
+        assert_eq!(
+            UnsentSlashBatch::<Test>::get(0).map(|(era, slashes)| (era, slashes[0].evidence)),
+            Some((3, evidence_hash()))
+        );
+    });
+}
+
+// ── Slash fraction bounds tests ──
+
+fn bounds(min_percent: u32, max_percent: u32) -> SlashFractionBounds {
+    SlashFractionBounds {
+        min: Perbill::from_percent(min_percent),
+        max: Perbill::from_percent(max_percent),
+    }
+}
+
+fn report_offence(validator: u64, kind: OffenceKind, fraction: Perbill) {
+    PendingOffenceKind::<Test>::insert(0, validator, kind);
+    Pallet::<Test>::on_offence(
+        &[OffenceDetails {
+            offender: (validator, ()),
+            reporters: vec![],
+        }],
+        &[fraction],
+        0,
+    );
+}
+
+#[test]
+fn root_can_set_and_remove_slash_fraction_bounds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ExternalValidatorSlashes::set_slash_fraction_bounds(
+            RuntimeOrigin::root(),
+            OffenceKind::BabeEquivocation,
+            Some(bounds(1, 10)),
+        ));
+        assert_eq!(
+            SlashFractionBoundsOf::<Test>::get(OffenceKind::BabeEquivocation),
+            Some(bounds(1, 10))
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashFractionBoundsSet {
+                offence_kind: OffenceKind::BabeEquivocation,
+                bounds: Some(bounds(1, 10)),
+            },
+        ));
+
+        assert_ok!(ExternalValidatorSlashes::set_slash_fraction_bounds(
+            RuntimeOrigin::root(),
+            OffenceKind::BabeEquivocation,
+            None,
+        ));
+        assert_eq!(
+            SlashFractionBoundsOf::<Test>::get(OffenceKind::BabeEquivocation),
+            None
+        );
+    });
+}
+
+#[test]
+fn slash_fraction_bounds_require_governance_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorSlashes::set_slash_fraction_bounds(
+                RuntimeOrigin::signed(1),
+                OffenceKind::BabeEquivocation,
+                Some(bounds(1, 10)),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn slash_fraction_bounds_floor_cannot_exceed_ceiling() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorSlashes::set_slash_fraction_bounds(
+                RuntimeOrigin::root(),
+                OffenceKind::BabeEquivocation,
+                Some(bounds(10, 1)),
+            ),
+            Error::<Test>::InvalidSlashFractionBounds
+        );
+    });
+}
+
+#[test]
+fn on_offence_clamps_fraction_to_offence_kind_bounds() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        // Liveness capped at 0.1%, equivocations slashed at least 1%.
+        SlashFractionBoundsOf::<Test>::insert(
+            OffenceKind::LivenessOffence,
+            SlashFractionBounds {
+                min: Perbill::zero(),
+                max: Perbill::from_rational(1u32, 1000u32),
+            },
+        );
+        SlashFractionBoundsOf::<Test>::insert(OffenceKind::BabeEquivocation, bounds(1, 100));
+
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(50));
+        report_offence(4, OffenceKind::BabeEquivocation, Perbill::from_parts(1_000));
+        // No bounds for GRANDPA equivocations, the reported fraction is kept.
+        report_offence(
+            5,
+            OffenceKind::GrandpaEquivocation,
+            Perbill::from_percent(30),
+        );
+
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(0))
+                .iter()
+                .map(|slash| (slash.validator, slash.percentage))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, Perbill::from_rational(1u32, 1000u32)),
+                (4, Perbill::from_percent(1)),
+                (5, Perbill::from_percent(30)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn slash_fraction_floor_applies_to_zero_fractions() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        SlashFractionBoundsOf::<Test>::insert(OffenceKind::LivenessOffence, bounds(1, 10));
+
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::zero());
+
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(0))
+                .iter()
+                .map(|slash| slash.percentage)
+                .collect::<Vec<_>>(),
+            vec![Perbill::from_percent(1)]
+        );
+    });
+}
+
+// ── Slash policy tests ──
+
+fn reported_fractions() -> Vec<(u64, Perbill)> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::ExternalValidatorSlashes(crate::Event::SlashReported {
+                validator,
+                fraction,
+                ..
+            }) => Some((validator, fraction)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn escalating_slash_policy_raises_the_fraction_of_repeat_offences() {
+    type Policy = crate::EscalatingSlashPolicy<crate::mock::RepeatOffenceSlashIncrease>;
+    let kind = OffenceKind::LivenessOffence;
+    crate::mock::RepeatOffenceSlashIncrease::set(Perbill::from_percent(50));
+
+    assert_eq!(
+        Policy::slash_fraction(&kind, Perbill::from_percent(20), 0),
+        Perbill::from_percent(20)
+    );
+    assert_eq!(
+        Policy::slash_fraction(&kind, Perbill::from_percent(20), 3),
+        Perbill::from_percent(50)
+    );
+    // Saturates at 100%.
+    assert_eq!(
+        Policy::slash_fraction(&kind, Perbill::from_percent(60), 2),
+        Perbill::one()
+    );
+    // The default policy keeps the reported fraction.
+    assert_eq!(
+        <() as crate::SlashPolicy>::slash_fraction(&kind, Perbill::from_percent(20), 3),
+        Perbill::from_percent(20)
+    );
+}
+
+#[test]
+fn on_offence_escalates_the_slashes_of_repeat_offenders() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        crate::mock::RepeatOffenceSlashIncrease::set(Perbill::from_percent(50));
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(20));
+        report_offence(3, OffenceKind::BabeEquivocation, Perbill::from_percent(20));
+        // Other validators are not affected.
+        report_offence(4, OffenceKind::LivenessOffence, Perbill::from_percent(20));
+        start_era(2, 2, 2);
+        PendingOffenceKind::<Test>::insert(2, 3u64, OffenceKind::LivenessOffence);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(20)],
+            2,
+        );
+
+        assert_eq!(OffencesInEra::<Test>::get(0, 3), 2);
+        assert_eq!(OffencesInEra::<Test>::get(2, 3), 1);
+        assert_eq!(OffencesInEra::<Test>::get(0, 4), 1);
+        assert_eq!(
+            reported_fractions(),
+            vec![
+                (3, Perbill::from_percent(20)),
+                (3, Perbill::from_percent(30)),
+                (4, Perbill::from_percent(20)),
+                (3, Perbill::from_percent(40)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn offences_not_slashed_do_not_escalate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        crate::mock::RepeatOffenceSlashIncrease::set(Perbill::from_percent(50));
+        start_era(0, 0, 0);
+
+        // Only logged
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(20));
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::Enabled,
+        ));
+        // Nothing to slash
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::zero());
+        assert_eq!(OffencesInEra::<Test>::get(0, 3), 0);
+
+        System::reset_events();
+        start_era(1, 1, 1);
+        PendingOffenceKind::<Test>::insert(1, 3u64, OffenceKind::LivenessOffence);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(20)],
+            1,
+        );
+
+        assert_eq!(reported_fractions(), vec![(3, Perbill::from_percent(20))]);
+        assert_eq!(OffencesInEra::<Test>::get(1, 3), 1);
+    });
+}
+
+#[test]
+fn offences_are_forgotten_at_the_end_of_the_bonding_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        crate::mock::RepeatOffenceSlashIncrease::set(Perbill::from_percent(50));
+        start_era(0, 0, 0);
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(20));
+
+        // Era 0 is no longer bonded once era 6 starts.
+        for era in 1..=6 {
+            start_era(era, era, era as u64);
+        }
+        assert_eq!(OffencesInEra::<Test>::get(0, 3), 0);
+
+        System::reset_events();
+        PendingOffenceKind::<Test>::insert(6, 3u64, OffenceKind::LivenessOffence);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(20)],
+            6,
+        );
+        assert_eq!(reported_fractions(), vec![(3, Perbill::from_percent(20))]);
+    });
+}
+
+#[test]
+fn simulate_slash_escalates_like_on_offence() {
+    new_test_ext().execute_with(|| {
+        crate::mock::RepeatOffenceSlashIncrease::set(Perbill::from_percent(50));
+        start_era(0, 0, 0);
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(20));
+
+        let simulated = ExternalValidatorSlashes::simulate_slash(
+            3,
+            OffenceKind::LivenessOffence,
+            Perbill::from_percent(25),
+            0,
+        )
+        .expect("offence is slashed");
+
+        // 37.5% of MaxSlashWad
+        assert_eq!(simulated.wad_to_slash, 18_750_000_000_000_000);
+    });
+}
+
 fn start_era(era_index: EraIndex, session_index: SessionIndex, external_idx: u64) {
     Pallet::<Test>::on_era_start(era_index, session_index, external_idx);
     crate::mock::MockEraIndexProvider::with_era(era_index);
@@ -1136,3 +2390,409 @@ fn get_slashing_era(slash_era: EraIndex) -> EraIndex {
         MockEraIndexProvider::active_era().index.saturating_add(1)
     }
 }
+
+fn inject_repeated_slashes() {
+    crate::mock::DeferPeriodGetter::with_defer_period(0);
+    start_era(0, 0, 0);
+    start_era(1, 1, 1);
+
+    for (validator, percentage, evidence) in [(3u64, 10, 0x31), (4, 10, 0x41), (3, 20, 0x32)] {
+        assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+            RuntimeOrigin::root(),
+            1,
+            validator,
+            Perbill::from_percent(percentage),
+            OffenceKind::LivenessOffence,
+            H256::repeat_byte(evidence),
+        ));
+    }
+
+    start_era(2, 2, 2);
+    run_block();
+}
+
+#[test]
+fn slashes_of_a_validator_are_merged_by_max() {
+    new_test_ext().execute_with(|| {
+        inject_repeated_slashes();
+
+        let sent = MockOkOutboundQueue::last_sent_slashes();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].validator, 3);
+        assert_eq!(sent[0].wad_to_slash, 10_000_000_000_000_000u128);
+        assert_eq!(
+            sent[0].description,
+            "Liveness offence; Liveness offence".to_string()
+        );
+        assert_eq!(
+            sent[0].evidence,
+            <BlakeTwo256 as HashT>::hash_of(&vec![
+                H256::repeat_byte(0x31),
+                H256::repeat_byte(0x32)
+            ])
+        );
+        assert_eq!(sent[1].validator, 4);
+        assert_eq!(sent[1].wad_to_slash, 5_000_000_000_000_000u128);
+        assert_eq!(sent[1].evidence, H256::repeat_byte(0x41));
+    });
+}
+
+#[test]
+fn slashes_of_a_validator_are_merged_by_sum() {
+    new_test_ext().execute_with(|| {
+        crate::mock::SlashAggregationGetter::with_aggregation(SlashAggregation::Sum);
+        inject_repeated_slashes();
+
+        let sent = MockOkOutboundQueue::last_sent_slashes();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].validator, 3);
+        assert_eq!(sent[0].wad_to_slash, 15_000_000_000_000_000u128);
+        assert_eq!(sent[1].wad_to_slash, 5_000_000_000_000_000u128);
+    });
+}
+
+#[test]
+fn sent_slashes_are_recorded_in_offence_history() {
+    new_test_ext().execute_with(|| {
+        inject_repeated_slashes();
+
+        let record = |validator, percent| OffenceRecord {
+            validator,
+            offence_kind: OffenceKind::LivenessOffence,
+            fraction: Perbill::from_percent(percent),
+            message_id: H256::zero(),
+        };
+        // Recorded before the slashes of a validator are merged
+        assert_eq!(
+            OffenceHistory::<Test>::get(2),
+            vec![record(3u64, 10), record(4, 10), record(3, 20)]
+        );
+    });
+}
+
+#[test]
+fn offence_history_is_kept_after_the_slashes_are_pruned() {
+    new_test_ext().execute_with(|| {
+        inject_repeated_slashes();
+
+        // Slashes of era 2 are pruned once it leaves the bonding period
+        for era in 3..=8 {
+            start_era(era, era, era as u64);
+        }
+        assert!(Slashes::<Test>::get(2).is_empty());
+        assert_eq!(OffenceHistory::<Test>::get(2).len(), 3);
+
+        // and the history once it leaves the history depth
+        for era in 9..=12 {
+            start_era(era, era, era as u64);
+        }
+        assert!(OffenceHistory::<Test>::get(2).is_empty());
+    });
+}
+
+#[test]
+fn slashes_are_recorded_in_offence_history_once_sent() {
+    new_test_ext().execute_with(|| {
+        MockOkOutboundQueue::set_should_fail(true);
+        inject_repeated_slashes();
+        assert!(OffenceHistory::<Test>::get(2).is_empty());
+
+        MockOkOutboundQueue::set_should_fail(false);
+        run_to_block(System::block_number() + 2);
+        assert_eq!(OffenceHistory::<Test>::get(2).len(), 3);
+    });
+}
+
+#[test]
+fn summed_slashes_are_capped_at_max_wad() {
+    let slash = |wad_to_slash| SlashData {
+        validator: 3u64,
+        operator: H160::repeat_byte(3),
+        wad_to_slash,
+        description: "Liveness offence".into(),
+        evidence: H256::repeat_byte(1),
+    };
+
+    let aggregated = aggregate_slash_data(vec![slash(70), slash(60)], SlashAggregation::Sum, 100);
+    assert_eq!(aggregated.len(), 1);
+    assert_eq!(aggregated[0].wad_to_slash, 100);
+}
+
+#[test]
+fn try_state_holds_while_slashes_are_processed() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+            RuntimeOrigin::root(),
+            0,
+            1u64,
+            Perbill::from_percent(75),
+            OffenceKind::BabeEquivocation,
+            evidence_hash(),
+        ));
+        for era in 1..=4 {
+            start_era(era, era, era as u64);
+            run_block();
+            assert_ok!(ExternalValidatorSlashes::do_try_state());
+        }
+
+        // An offence kind noted for an offence which never reached `on_offence`
+        PendingOffenceKind::<Test>::insert(4, 1u64, OffenceKind::LivenessOffence);
+        assert!(ExternalValidatorSlashes::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn offence_stats_are_grouped_by_offence_kind() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        for (validator, percent, offence_kind) in [
+            (1u64, 10, OffenceKind::BabeEquivocation),
+            (2u64, 5, OffenceKind::LivenessOffence),
+            (3u64, 30, OffenceKind::BabeEquivocation),
+        ] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                validator,
+                Perbill::from_percent(percent),
+                offence_kind,
+                evidence_hash(),
+            ));
+        }
+        let slashing_era = get_slashing_era(0);
+
+        assert_eq!(
+            ExternalValidatorSlashes::offence_stats(0, slashing_era),
+            vec![
+                (
+                    OffenceKind::BabeEquivocation,
+                    OffenceKindStats {
+                        count: 2,
+                        confirmed: 0,
+                        total_slashed_parts: Perbill::from_percent(40).deconstruct().into(),
+                        max_slashed: Perbill::from_percent(30),
+                    }
+                ),
+                (
+                    OffenceKind::LivenessOffence,
+                    OffenceKindStats {
+                        count: 1,
+                        confirmed: 0,
+                        total_slashed_parts: Perbill::from_percent(5).deconstruct().into(),
+                        max_slashed: Perbill::from_percent(5),
+                    }
+                ),
+            ]
+        );
+        // Slashes are counted in the era they are applied in
+        assert!(ExternalValidatorSlashes::offence_stats(0, slashing_era - 1).is_empty());
+    });
+}
+
+#[test]
+fn simulate_slash_returns_the_data_sent_to_eigenlayer() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        assert_eq!(
+            ExternalValidatorSlashes::simulate_slash(
+                3,
+                OffenceKind::BabeEquivocation,
+                Perbill::from_percent(50),
+                0,
+            ),
+            Some(SlashData {
+                validator: 3,
+                operator: ExternalValidatorSlashes::operator_address(&3),
+                wad_to_slash: 25_000_000_000_000_000,
+                description: OffenceKind::BabeEquivocation.to_description(),
+                evidence: H256::zero(),
+            })
+        );
+
+        // Nothing was written
+        assert_eq!(ValidatorSlashInEra::<Test>::get(0, 3), None);
+        assert_eq!(NextSlashId::<Test>::get(), 0);
+        assert_eq!(Slashes::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn simulate_slash_clamps_fraction_to_offence_kind_bounds() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        SlashFractionBoundsOf::<Test>::insert(OffenceKind::LivenessOffence, bounds(1, 10));
+
+        let simulated = ExternalValidatorSlashes::simulate_slash(
+            3,
+            OffenceKind::LivenessOffence,
+            Perbill::from_percent(50),
+            0,
+        )
+        .expect("offence is slashed");
+
+        // 10% of MaxSlashWad
+        assert_eq!(simulated.wad_to_slash, 5_000_000_000_000_000);
+    });
+}
+
+#[test]
+fn simulate_slash_matches_the_applied_slash() {
+    new_test_ext().execute_with(|| {
+        DeferPeriodGetter::with_defer_period(0);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+
+        let simulated = ExternalValidatorSlashes::simulate_slash(
+            3,
+            OffenceKind::LivenessOffence,
+            Perbill::from_percent(30),
+            0,
+        );
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(30));
+        start_era(2, 2, 2);
+        run_block();
+
+        assert_eq!(
+            Some(MockOkOutboundQueue::last_sent_slashes()[0].clone()),
+            simulated
+        );
+    });
+}
+
+#[test]
+fn simulate_slash_is_none_if_the_offence_is_not_slashed() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        let simulate = |validator, fraction, era| {
+            ExternalValidatorSlashes::simulate_slash(
+                validator,
+                OffenceKind::LivenessOffence,
+                Perbill::from_percent(fraction),
+                era,
+            )
+        };
+
+        // Invulnerable validator
+        assert_eq!(simulate(1, 50, 0), None);
+        // Era not bonded
+        assert_eq!(simulate(3, 50, 1), None);
+
+        // Not more than the prior slash of the validator in the era
+        report_offence(3, OffenceKind::LivenessOffence, Perbill::from_percent(50));
+        assert_eq!(simulate(3, 50, 0), None);
+        assert!(simulate(3, 60, 0).is_some());
+
+        // Only logged
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        assert_eq!(simulate(4, 50, 0), None);
+    });
+}
+
+fn inbox_slash_ids(validator: u64) -> Vec<u32> {
+    ValidatorInbox::<Test>::get(validator)
+        .into_iter()
+        .map(|entry| entry.slash_id)
+        .collect()
+}
+
+#[test]
+fn deferred_slashes_are_added_to_the_validator_inbox() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+            RuntimeOrigin::root(),
+            0,
+            1u64,
+            Perbill::from_percent(75),
+            OffenceKind::BabeEquivocation,
+            evidence_hash(),
+        ));
+
+        assert_eq!(
+            ValidatorInbox::<Test>::get(1).into_inner(),
+            vec![InboxEntry {
+                slash_id: 0,
+                era: get_slashing_era(0),
+                percentage: Perbill::from_percent(75),
+                offence_kind: OffenceKind::BabeEquivocation,
+            }]
+        );
+        assert!(ValidatorInbox::<Test>::get(2).is_empty());
+    });
+}
+
+#[test]
+fn validator_acknowledges_the_slashes_of_its_inbox() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        for percentage in [10, 20] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                1u64,
+                Perbill::from_percent(percentage),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ));
+        }
+
+        // Only the slashed validator can acknowledge its slashes
+        assert_noop!(
+            ExternalValidatorSlashes::acknowledge_slash(RuntimeOrigin::signed(2), 0),
+            Error::<Test>::SlashNotInInbox
+        );
+
+        assert_ok!(ExternalValidatorSlashes::acknowledge_slash(
+            RuntimeOrigin::signed(1),
+            0
+        ));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashAcknowledged {
+                validator: 1,
+                slash_id: 0,
+            },
+        ));
+        assert_eq!(inbox_slash_ids(1), vec![1]);
+        assert_noop!(
+            ExternalValidatorSlashes::acknowledge_slash(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::SlashNotInInbox
+        );
+
+        assert_ok!(ExternalValidatorSlashes::acknowledge_slash(
+            RuntimeOrigin::signed(1),
+            1
+        ));
+        assert!(!ValidatorInbox::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn full_inbox_drops_the_oldest_slash() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        // The inbox of the mock keeps 3 slashes
+        for percentage in [10, 20, 30, 40] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                1u64,
+                Perbill::from_percent(percentage),
+                OffenceKind::LivenessOffence,
+                evidence_hash(),
+            ));
+        }
+
+        assert_eq!(inbox_slash_ids(1), vec![1, 2, 3]);
+        // The dropped slash is still applied
+        assert_eq!(
+            ExternalValidatorSlashes::slashes(get_slashing_era(0)).len(),
+            4
+        );
+    });
+}
@@ -59,6 +59,12 @@ pub trait WeightInfo {
 	fn process_slashes_queue(s: u32, ) -> Weight;
 	fn retry_unsent_slash_era() -> Weight;
 	fn set_slashing_mode() -> Weight;
+	fn process_failed_slash_messages(s: u32, ) -> Weight;
+	fn redrive_dead_letter_slash_message(s: u32, ) -> Weight;
+	fn discard_dead_letter_slash_message(s: u32, ) -> Weight;
+	fn set_slash_fraction_bounds() -> Weight;
+	fn reduce_deferred_slash() -> Weight;
+	fn acknowledge_slash() -> Weight;
 }
 
 /// Weights for pallet_external_validator_slashes using the Substrate node and recommended hardware.
@@ -138,6 +144,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 
 	fn retry_unsent_slash_era() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
 		// Same as the success path for one queued batch.
 		Self::process_slashes_queue(10)
 	}
@@ -148,6 +156,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 
+	fn process_failed_slash_messages(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the due message, after reading every failed message to find it, and moving
+		// it to the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	fn redrive_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the message, after taking it out of the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	fn discard_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Taking the message out of the dead-letter queue, with the proof size per slash of
+		// `process_slashes_queue`.
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(s.into()))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::SlashFractionBoundsOf` (r:0 w:1)
+	/// Proof: `ExternalValidatorSlashes::SlashFractionBoundsOf` (`max_values`: None, `max_size`: Some(284), added: 2759, mode: `MaxEncodedLen`)
+	fn set_slash_fraction_bounds() -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorSlashes::Slashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorSlashes::Slashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reduce_deferred_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42194`
+		//  Estimated: `45659`
+		Weight::from_parts(430_467_141, 45659)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidatorSlashes::ValidatorInbox` (r:1 w:1)
+	/// Proof: `ExternalValidatorSlashes::ValidatorInbox` (`max_values`: None, `max_size`: Some(4381), added: 6856, mode: `MaxEncodedLen`)
+	fn acknowledge_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4420`
+		//  Estimated: `7846`
+		Weight::from_parts(16_204_000, 7846)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
 }
 
 // For backwards compatibility and tests
@@ -228,6 +295,9 @@ impl WeightInfo for () {
 	}
 
 	fn retry_unsent_slash_era() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Same as the success path for one queued batch.
 		Self::process_slashes_queue(10)
 	}
 
@@ -236,4 +306,63 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	fn process_failed_slash_messages(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the due message, after reading every failed message to find it, and moving
+		// it to the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	fn redrive_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the message, after taking it out of the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	fn discard_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Taking the message out of the dead-letter queue, with the proof size per slash of
+		// `process_slashes_queue`.
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(s.into()))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::SlashFractionBoundsOf` (r:0 w:1)
+	/// Proof: `ExternalValidatorSlashes::SlashFractionBoundsOf` (`max_values`: None, `max_size`: Some(284), added: 2759, mode: `MaxEncodedLen`)
+	fn set_slash_fraction_bounds() -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorSlashes::Slashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorSlashes::Slashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reduce_deferred_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42194`
+		//  Estimated: `45659`
+		Weight::from_parts(430_467_141, 45659)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidatorSlashes::ValidatorInbox` (r:1 w:1)
+	/// Proof: `ExternalValidatorSlashes::ValidatorInbox` (`max_values`: None, `max_size`: Some(4381), added: 6856, mode: `MaxEncodedLen`)
+	fn acknowledge_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4420`
+		//  Estimated: `7846`
+		Weight::from_parts(16_204_000, 7846)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
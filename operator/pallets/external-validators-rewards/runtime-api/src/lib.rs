@@ -0,0 +1,65 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the External Validators Rewards pallet
+//!
+//! * `era_points_paged`: Read the reward points of an era in bounded pages
+//! * `era_reward_points`: Read all the reward points of an era
+//! * `generate_rewards_merkle_proof`: Prove the reward points of a validator in an era
+//! * `verify_rewards_merkle_proof`: Check a proof against its rewards root
+//! * `validator_performance`: Read the performance behind the session points of an era
+//! * `reward_address`: Read the Ethereum address a validator receives its rewards at
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pallet_external_validators_rewards::types::ValidatorPerformance;
+use parity_scale_codec::Codec;
+use snowbridge_merkle_tree::MerkleProof;
+use sp_core::H160;
+
+sp_api::decl_runtime_apis! {
+    pub trait ExternalValidatorsRewardsApi<AccountId> where AccountId: Codec
+    {
+        /// Reward points earned in `era_index`, ordered by account and starting right after
+        /// `start_key`. Returns at most `limit` entries; the runtime may cap it further.
+        fn era_points_paged(
+            era_index: u32,
+            start_key: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, u32)>;
+
+        /// Total reward points of `era_index` and the points earned by each validator,
+        /// ordered by account.
+        fn era_reward_points(era_index: u32) -> (u32, Vec<(AccountId, u32)>);
+
+        /// Merkle proof of the points earned by `account` in `era_index`, or `None` if it
+        /// earned no points in that era.
+        fn generate_rewards_merkle_proof(account: AccountId, era_index: u32) -> Option<MerkleProof>;
+
+        /// Whether `proof` is valid for the rewards root it carries.
+        fn verify_rewards_merkle_proof(proof: MerkleProof) -> bool;
+
+        /// Performance of each validator accounted in `era_index`, from which its session
+        /// performance points were computed, ordered by account hash.
+        fn validator_performance(era_index: u32) -> Vec<(AccountId, ValidatorPerformance)>;
+
+        /// Ethereum address the rewards of `account` are paid to by the distributor contract.
+        /// Defaults to the address derived from the account itself.
+        fn reward_address(account: AccountId) -> H160;
+    }
+}
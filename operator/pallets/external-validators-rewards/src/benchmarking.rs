@@ -21,9 +21,15 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as ExternalValidatorsRewards;
 use {
-    crate::types::BenchmarkHelper,
+    crate::types::{BenchmarkHelper, RewardsPayout},
+    alloc::collections::BTreeMap,
     frame_benchmarking::{account, v2::*, BenchmarkError},
-    frame_support::traits::{Currency, EnsureOrigin},
+    frame_support::{
+        traits::{fungible, Currency, EnsureOrigin},
+        BoundedVec,
+    },
+    frame_system::RawOrigin,
+    pallet_external_validators::traits::EraIndexProvider,
 };
 
 const SEED: u32 = 0;
@@ -52,41 +58,44 @@ fn push_unsent_entry<T: Config>(era_index: u32, timestamp: u32, inflation: u128)
 mod benchmarks {
     use super::*;
 
-    // worst case for the end of an era.
+    // worst case for the end of an era with `n` rewarded validators, processed at once.
     #[benchmark]
-    fn on_era_end() -> Result<(), BenchmarkError> {
+    fn on_era_end(n: Linear<1, 1_000>) -> Result<(), BenchmarkError> {
         frame_system::Pallet::<T>::set_block_number(0u32.into());
 
-        let mut era_reward_points = EraRewardPoints::default();
-        era_reward_points.total = 20 * 1000;
-
-        for i in 0..1000 {
-            let account_id = create_funded_user::<T>("candidate", i, 100);
-            era_reward_points.individual.insert(account_id, 20);
-        }
-
         T::BenchmarkHelper::setup();
-        <RewardPointsForEra<T>>::insert(1u32, era_reward_points);
+        setup_era_reward_points::<T>(1, n);
 
         #[block]
         {
             <ExternalValidatorsRewards<T> as OnEraEnd>::on_era_end(1u32);
+            ExternalValidatorsRewards::<T>::progress_era_end(u32::MAX);
         }
 
         Ok(())
     }
 
-    /// Helper to populate reward points for an era with 1000 validators.
-    fn setup_era_reward_points<T: Config + pallet_balances::Config>(era_index: u32) {
+    /// Helper to populate reward points, and the rewards tree leaves they give, for an era
+    /// with `validators` validators of 20 points each.
+    fn setup_era_reward_points<T: Config + pallet_balances::Config>(
+        era_index: u32,
+        validators: u32,
+    ) {
         let mut era_reward_points = EraRewardPoints::default();
-        era_reward_points.total = 20 * 1000;
+        era_reward_points.total = 20 * validators;
+        let mut leaves = BTreeMap::new();
 
-        for i in 0..1000 {
+        for i in 0..validators {
             let account_id = create_funded_user::<T>("candidate", i, 100);
+            leaves.insert(
+                account_id.clone(),
+                ExternalValidatorsRewards::<T>::pending_rewards_leaf(&account_id, 20),
+            );
             era_reward_points.individual.insert(account_id, 20);
         }
 
         <RewardPointsForEra<T>>::insert(era_index, era_reward_points);
+        <RewardLeavesForEra<T>>::insert(era_index, leaves);
     }
 
     // on_initialize: unsent queue is empty (2 reads for head+tail)
@@ -125,7 +134,7 @@ mod benchmarks {
     fn process_unsent_reward_eras_success() -> Result<(), BenchmarkError> {
         frame_system::Pallet::<T>::set_block_number(0u32.into());
         T::BenchmarkHelper::setup();
-        setup_era_reward_points::<T>(1);
+        setup_era_reward_points::<T>(1, 1000);
 
         push_unsent_entry::<T>(1, 0, 42);
 
@@ -144,7 +153,7 @@ mod benchmarks {
     fn process_unsent_reward_eras_failed() -> Result<(), BenchmarkError> {
         frame_system::Pallet::<T>::set_block_number(0u32.into());
         T::BenchmarkHelper::setup();
-        setup_era_reward_points::<T>(1);
+        setup_era_reward_points::<T>(1, 1000);
 
         push_unsent_entry::<T>(1, 0, 42);
 
@@ -161,7 +170,7 @@ mod benchmarks {
     fn retry_unsent_reward_era() -> Result<(), BenchmarkError> {
         frame_system::Pallet::<T>::set_block_number(0u32.into());
         T::BenchmarkHelper::setup();
-        setup_era_reward_points::<T>(1);
+        setup_era_reward_points::<T>(1, 1000);
 
         push_unsent_entry::<T>(1, 0, 42);
 
@@ -176,6 +185,181 @@ mod benchmarks {
         Ok(())
     }
 
+    // Root or governance extrinsic: resend the message of an era from its rewards pot
+    #[benchmark]
+    fn force_resend_rewards_message() -> Result<(), BenchmarkError> {
+        frame_system::Pallet::<T>::set_block_number(0u32.into());
+        T::BenchmarkHelper::setup();
+        setup_era_reward_points::<T>(1, 1000);
+
+        let root = ExternalValidatorsRewards::<T>::era_rewards_merkle_root(1)
+            .ok_or(BenchmarkError::Weightless)?;
+        EraRewardsPots::<T>::insert(
+            1u32,
+            EraRewardsPot {
+                root,
+                total_points: 20 * 1000,
+                rewards_amount: 42,
+                era_start_timestamp: 0,
+                payout: RewardsPayout::Ethereum,
+            },
+        );
+        push_unsent_entry::<T>(1, 0, 42);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1u32);
+
+        assert!(ExternalValidatorsRewards::<T>::unsent_queue_is_empty());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_next_era_inflation() -> Result<(), BenchmarkError> {
+        let origin = T::InflationOverrideOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1_000u128);
+
+        assert_eq!(NextEraInflationOverride::<T>::get(), Some(1_000));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_performance_weights() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            Percent::from_percent(50),
+            Percent::from_percent(30),
+            Percent::from_percent(20),
+        );
+
+        assert!(PerformanceWeightsOverride::<T>::get().is_some());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_inflation_params() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1_000u32, 30u32, 90u32);
+
+        assert!(InflationParamsOverride::<T>::get().is_some());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_rewards_split() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Perbill::from_percent(30));
+
+        assert_eq!(RewardsSplit::<T>::get(), Perbill::from_percent(30));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_rewards_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        <T::Currency as fungible::Mutate<_>>::mint_into(
+            &T::RewardsEthereumSovereignAccount::get(),
+            1_000_000_000_000_000_000u128.into(),
+        )?;
+        let new_account: T::AccountId = account("rewards", 0, SEED);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_account.clone());
+
+        assert_eq!(RewardsAccountOverride::<T>::get(), Some(new_account));
+
+        Ok(())
+    }
+
+    // worst case: the validator has points in the active era, so its leaf is hashed again.
+    #[benchmark]
+    fn set_reward_address() -> Result<(), BenchmarkError> {
+        let active_era = T::EraIndexProvider::active_era().index;
+        setup_era_reward_points::<T>(active_era, 1000);
+        let validator: T::AccountId = account("candidate", 0, SEED);
+        let reward_address = H160::repeat_byte(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(validator.clone()), reward_address);
+
+        assert_eq!(
+            RewardDestination::<T>::get(&validator),
+            Some(reward_address)
+        );
+        assert_eq!(
+            RewardLeavesForEra::<T>::get(active_era).get(&validator),
+            Some(&ExternalValidatorsRewards::<T>::pending_rewards_leaf(
+                &validator, 20
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_rewards() -> Result<(), BenchmarkError> {
+        setup_era_reward_points::<T>(1, 1000);
+
+        let rewards_amount = 1_000_000_000_000_000_000u128;
+        let root = ExternalValidatorsRewards::<T>::era_rewards_merkle_root(1)
+            .ok_or(BenchmarkError::Weightless)?;
+        EraRewardsPots::<T>::insert(
+            1u32,
+            EraRewardsPot {
+                root,
+                total_points: 20 * 1000,
+                rewards_amount,
+                era_start_timestamp: 0,
+                payout: RewardsPayout::Claim,
+            },
+        );
+        <T::Currency as fungible::Mutate<_>>::mint_into(
+            &T::RewardsEthereumSovereignAccount::get(),
+            rewards_amount.into(),
+        )?;
+
+        let claimer: T::AccountId = account("candidate", 0, SEED);
+        EraRewardAddresses::<T>::insert(
+            1u32,
+            &claimer,
+            ExternalValidatorsRewards::<T>::reward_address(&claimer),
+        );
+        let proof =
+            ExternalValidatorsRewards::<T>::generate_rewards_merkle_proof(claimer.clone(), 1)
+                .ok_or(BenchmarkError::Weightless)?;
+        let merkle_proof = RewardsClaimProof {
+            points: 20,
+            proof: BoundedVec::truncate_from(proof.proof),
+            leaf_index: proof.leaf_index,
+            number_of_leaves: proof.number_of_leaves,
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(claimer.clone()), 1u32, merkle_proof);
+
+        assert!(ClaimedRewards::<T>::contains_key(1u32, &claimer));
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         ExternalValidatorsRewards,
         crate::mock::new_test_ext(),
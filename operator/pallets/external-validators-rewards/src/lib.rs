@@ -29,25 +29,52 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
 pub use pallet::*;
 
 use alloc::vec::Vec;
+use core::ops::Bound;
 use {
-    crate::types::{EraRewardsUtils, HandleInflation, SendMessage},
+    crate::types::{
+        EraEnd, EraEndStage, EraRewardsPot, EraRewardsUtils, HandleInflation, InflationParams,
+        PerformanceWeights, RewardsClaimProof, RewardsDelivery, RewardsLedger, RewardsPayout,
+        SendMessage, ValidatorPerformance,
+    },
     frame_support::traits::{Get, ValidatorSet},
-    pallet_external_validators::traits::{ExternalIndexProvider, OnEraEnd, OnEraStart},
+    pallet_external_validators::traits::{
+        ExternalIndexProvider, OnEraEnd, OnEraStart, OnOutboundMessageSent,
+        OperatorAddressProvider, OutboundMessageKind,
+    },
     parity_scale_codec::{Decode, Encode},
+    snowbridge_merkle_tree::{merkle_proof, merkle_root, verify_proof, MerkleProof},
     sp_core::{H160, H256},
     sp_runtime::{
-        traits::{Hash, Zero},
-        Perbill,
+        helpers_128bit::multiply_by_rational_with_rounding,
+        traits::{BlakeTwo256, Hash, Zero},
+        FixedPointNumber, FixedU128, Perbill, Percent, Rounding,
     },
     sp_staking::SessionIndex,
 };
 
+/// Maximum number of entries returned by a single [`Pallet::era_points_paged`] call.
+pub const MAX_ERA_POINTS_PAGE_SIZE: u32 = 1_000;
+
+/// Content of the leaf of a validator in an era rewards tree, see
+/// [`EraRewardPoints::generate_rewards_leaves`].
+fn rewards_leaf_data<AccountId: Encode>(
+    account_id: &AccountId,
+    reward_address: Option<H160>,
+    reward_points: u32,
+) -> Vec<u8> {
+    match reward_address {
+        Some(reward_address) => (account_id, reward_address, reward_points).encode(),
+        None => (account_id, reward_points).encode(),
+    }
+}
+
 /// Trait for checking if a validator has been slashed in a given era
 pub trait SlashingCheck<AccountId> {
     fn is_slashed(era_index: u32, validator: &AccountId) -> bool;
@@ -62,19 +89,24 @@ impl<AccountId> SlashingCheck<AccountId> for () {
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::traits::fungible;
+    use frame_support::traits::{
+        fungible,
+        tokens::{Fortitude, Preservation},
+        Contains,
+    };
     use sp_runtime::PerThing;
 
     pub use crate::weights::WeightInfo;
     use alloc::collections::BTreeMap;
     use {
-        super::*, frame_support::pallet_prelude::*, frame_system::pallet_prelude::OriginFor,
-        pallet_external_validators::traits::EraIndexProvider, sp_runtime::Saturating,
+        super::*,
+        frame_support::{pallet_prelude::*, traits::EitherOfDiverse},
+        frame_system::{ensure_signed, pallet_prelude::OriginFor, EnsureRoot},
+        pallet_external_validators::traits::EraIndexProvider,
+        sp_io::MultiRemovalResults,
+        sp_runtime::Saturating,
     };
 
-    /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
-
     pub type RewardPoints = u32;
     pub type EraIndex = u32;
 
@@ -125,11 +157,16 @@ pub mod pallet {
         /// The remainder (100% - block - liveness) is the unconditional base reward.
         type BlockAuthoringWeight: Get<Perbill>;
 
-        /// Weight of liveness (block authorship) in the rewards formula.
+        /// Weight of liveness in the rewards formula.
         /// Combined with BlockAuthoringWeight, the sum should not exceed 100%.
         /// The remainder (100% - block - liveness) is the unconditional base reward.
         type LivenessWeight: Get<Perbill>;
 
+        /// Validators proven online during the current session by other means than block
+        /// authorship, such as ImOnline heartbeats. A validator who authored a block is
+        /// always considered online.
+        type LivenessCheck: Contains<Self::AccountId>;
+
         /// Soft cap on block authoring rewards as a percentage above fair share.
         /// E.g., 50% means validators can earn credit for up to 150% of their fair share.
         /// With 60% BlockAuthoringWeight, this gives over-performers up to 30% bonus reward.
@@ -150,39 +187,108 @@ pub mod pallet {
         #[pallet::constant]
         type MaxInflationPercent: Get<u32>;
 
+        /// Maximum number of validators rewarded in a single era.
+        ///
+        /// Bounds the size of the rewards message and of the rewards tree. When more validators
+        /// earned points, only the ones with the most points are rewarded, and the points of
+        /// the others are carried over to the next era.
+        #[pallet::constant]
+        type MaxRewardedValidatorsPerEra: Get<u32>;
+
+        /// Steps of the end of an era processed per block, the first ones when the era ends
+        /// and the others in the following blocks. Generating the leaf of a rewarded validator
+        /// is one step, and so are computing the rewards root, minting the inflation and
+        /// sending the rewards message.
+        #[pallet::constant]
+        type EraEndStepsPerBlock: Get<u32>;
+
+        /// EigenLayer operators registered for the validators. The rewards of validators
+        /// without one are submitted for the address of their own account.
+        type OperatorAddresses: OperatorAddressProvider<Self::AccountId>;
+
         /// Hashing tool used to generate/verify merkle roots and proofs.
         type Hashing: Hash<Output = H256>;
 
         /// Currency the rewards are minted in
-        type Currency: fungible::Inspect<Self::AccountId, Balance: From<u128>>
+        type Currency: fungible::Inspect<Self::AccountId, Balance: From<u128> + Into<u128>>
             + fungible::Mutate<Self::AccountId>;
 
-        /// Ethereum Sovereign Account where rewards will be minted
+        /// Ethereum Sovereign Account where rewards will be minted, until governance sets
+        /// `RewardsAccountOverride`.
         type RewardsEthereumSovereignAccount: Get<Self::AccountId>;
 
+        /// Where the rewards of the eras ending are paid out: sent to EigenLayer, or kept in
+        /// the rewards account for validators to claim with `claim_rewards`.
+        type RewardsPayout: Get<RewardsPayout>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
 
         /// How to send messages via Snowbridge Outbound Queue V2.
         type SendMessage: SendMessage;
 
+        /// Payload schema version the message builder currently encodes with.
+        /// Only used to tag the sent events, the builder reads the same source.
+        type OutboundSchemaVersion: Get<u8>;
+
+        /// Notified of the rewards messages accepted by the outbound queue.
+        type OnOutboundMessageSent: OnOutboundMessageSent;
+
+        /// Whether rewards messages are held back, for instance while the bridge is in
+        /// maintenance. Held back messages wait in the unsent queue until this turns false.
+        type HoldOutboundMessages: Get<bool>;
+
         /// Hook for minting inflation tokens.
         type HandleInflation: HandleInflation<Self::AccountId>;
 
+        /// Proportion of the inflation minted to the treasury until governance sets
+        /// `RewardsSplit`.
+        #[pallet::constant]
+        type DefaultRewardsSplit: Get<Perbill>;
+
+        /// Notified of the inflation minted and of the rewards sent.
+        type RewardsLedger: RewardsLedger;
+
         /// Origin for governance calls (e.g., retrying unsent reward messages).
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Origin allowed to override the inflation of the next era.
+        /// Meant for dev and test networks only, production runtimes should use `EnsureNever`.
+        type InflationOverrideOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         #[cfg(feature = "runtime-benchmarks")]
         type BenchmarkHelper: types::BenchmarkHelper;
     }
     #[pallet::pallet]
-    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::storage_version(migrations::STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
     impl<T: Config> Hooks<frame_system::pallet_prelude::BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
             Self::process_unsent_reward_eras()
+                .saturating_add(Self::progress_era_end(T::EraEndStepsPerBlock::get()))
+        }
+
+        fn on_idle(
+            _n: frame_system::pallet_prelude::BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            Self::prune_era_entries(remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::EraEndStepsPerBlock::get() > 0,
+                "EraEndStepsPerBlock must allow the end of an era to progress"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(
+            _n: frame_system::pallet_prelude::BlockNumberFor<T>,
+        ) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
@@ -198,37 +304,292 @@ pub mod pallet {
         ) -> DispatchResult {
             T::GovernanceOrigin::ensure_origin(origin)?;
 
-            // Scan the ring buffer for the requested era
-            let head = UnsentRewardHead::<T>::get();
-            let tail = UnsentRewardTail::<T>::get();
-            let mut found = None;
-            let mut slot = head;
-            while slot != tail {
-                if let Some(entry @ (idx, _, _)) = UnsentRewardEra::<T>::get(slot) {
-                    if idx == era_index {
-                        found = Some((slot, entry));
-                        break;
-                    }
-                }
-                slot = (slot + 1) % UNSENT_QUEUE_CAPACITY;
-            }
-            let (slot, (_, timestamp, inflation)) = found.ok_or(Error::<T>::EraNotInUnsentQueue)?;
+            let (slot, (_, timestamp, inflation)) =
+                Self::unsent_queue_find(era_index).ok_or(Error::<T>::EraNotInUnsentQueue)?;
 
             let reward_points = RewardPointsForEra::<T>::get(era_index);
             let info = reward_points
-                .generate_era_rewards_info(era_index, inflation, timestamp)
+                .generate_era_rewards_info(era_index, inflation, timestamp, Self::operator_address)
                 .ok_or(Error::<T>::RewardPointsPruned)?;
 
             let message_id =
                 Self::send_rewards_message(&info).ok_or(Error::<T>::MessageSendFailed)?;
 
             Self::unsent_queue_remove_slot(slot);
+            T::RewardsLedger::on_rewards_sent(era_index, inflation);
+            T::SendMessage::on_rewards_sent(&info);
 
             Self::deposit_event(Event::RewardsMessageRetried {
                 message_id,
                 era_index,
                 total_points: info.total_points,
                 inflation_amount: inflation,
+                schema_version: T::OutboundSchemaVersion::get(),
+            });
+
+            Ok(())
+        }
+
+        /// Send the rewards message of an era which was never sent, or whose message failed
+        /// on Ethereum.
+        ///
+        /// The message is rebuilt from the era rewards pot, so eras still in the unsent queue,
+        /// dropped because the queue was full, or whose message failed on Ethereum can be
+        /// recovered. The era is removed from the unsent queue once sent.
+        ///
+        /// The message carries the amount paid to each operator, so any other era is refused:
+        /// its message may have been delivered already and EigenLayer would pay out its
+        /// rewards twice.
+        ///
+        /// The era must still be within `HistoryDepth`, and its points must still give the
+        /// stored root, so the message matches what validators claim against on DataHaven.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::force_resend_rewards_message())]
+        pub fn force_resend_rewards_message(
+            origin: OriginFor<T>,
+            era_index: EraIndex,
+        ) -> DispatchResult {
+            EitherOfDiverse::<EnsureRoot<T::AccountId>, T::GovernanceOrigin>::ensure_origin(
+                origin,
+            )?;
+            Self::resend_era_rewards_pot(era_index)
+        }
+
+        /// Override the inflation minted at the end of the next era with `amount`.
+        ///
+        /// The override replaces both `EraInflationProvider` and the performance scaling, so
+        /// end-to-end tests can follow the rewards path with a known amount. It is consumed
+        /// by the next era that ends with reward points, eras without points mint nothing.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_next_era_inflation())]
+        pub fn set_next_era_inflation(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+            T::InflationOverrideOrigin::ensure_origin(origin)?;
+
+            NextEraInflationOverride::<T>::put(amount);
+            Self::deposit_event(Event::NextEraInflationOverridden { amount });
+
+            Ok(())
+        }
+
+        /// Set the weights of the session performance formula, as percentages of the points.
+        ///
+        /// `block_pct`, `liveness_pct` and `base_pct` must sum to 100. They replace
+        /// `BlockAuthoringWeight` and `LivenessWeight` from the next session awarded.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_performance_weights())]
+        pub fn set_performance_weights(
+            origin: OriginFor<T>,
+            block_pct: Percent,
+            liveness_pct: Percent,
+            base_pct: Percent,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let sum = u16::from(block_pct.deconstruct())
+                + u16::from(liveness_pct.deconstruct())
+                + u16::from(base_pct.deconstruct());
+            ensure!(sum == 100, Error::<T>::InvalidPerformanceWeights);
+
+            let to_perbill = |pct: Percent| Perbill::from_percent(pct.deconstruct().into());
+            let weights = PerformanceWeights {
+                block_authoring: to_perbill(block_pct),
+                liveness: to_perbill(liveness_pct),
+                base: to_perbill(base_pct),
+            };
+            PerformanceWeightsOverride::<T>::put(weights);
+            Self::deposit_event(Event::PerformanceWeightsSet { weights });
+
+            Ok(())
+        }
+
+        /// Set the parameters of the performance-scaled inflation curve.
+        ///
+        /// `expected_blocks_per_era` must not be zero and `min_inflation_percent` must not
+        /// exceed `max_inflation_percent`, itself at most 100. They replace
+        /// `ExpectedBlocksPerEra`, `MinInflationPercent` and `MaxInflationPercent` from the next
+        /// era that ends.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_inflation_params())]
+        pub fn set_inflation_params(
+            origin: OriginFor<T>,
+            expected_blocks_per_era: u32,
+            min_inflation_percent: u32,
+            max_inflation_percent: u32,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                expected_blocks_per_era > 0
+                    && min_inflation_percent <= max_inflation_percent
+                    && max_inflation_percent <= 100,
+                Error::<T>::InvalidInflationParams
+            );
+
+            let params = InflationParams {
+                expected_blocks_per_era,
+                min_inflation_percent,
+                max_inflation_percent,
+            };
+            InflationParamsOverride::<T>::put(params);
+            Self::deposit_event(Event::InflationParamsSet { params });
+
+            Ok(())
+        }
+
+        /// Set the proportion of the era inflation minted to the treasury, the rest being
+        /// minted to the rewards account.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_rewards_split())]
+        pub fn set_rewards_split(
+            origin: OriginFor<T>,
+            treasury_proportion: Perbill,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            RewardsSplit::<T>::put(treasury_proportion);
+            Self::deposit_event(Event::RewardsSplitSet {
+                treasury_proportion,
+            });
+
+            Ok(())
+        }
+
+        /// Set the account the era rewards are minted to and claimed from.
+        ///
+        /// The whole balance of the current rewards account, which backs the rewards not
+        /// claimed yet, is moved to `account`, and the amount moved is reported in the event.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_rewards_account())]
+        pub fn set_rewards_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let old_account = Self::rewards_account();
+            ensure!(old_account != account, Error::<T>::SameRewardsAccount);
+
+            let moved_amount = <T::Currency as fungible::Inspect<_>>::reducible_balance(
+                &old_account,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            if !moved_amount.is_zero() {
+                <T::Currency as fungible::Mutate<_>>::transfer(
+                    &old_account,
+                    &account,
+                    moved_amount,
+                    Preservation::Expendable,
+                )?;
+            }
+            RewardsAccountOverride::<T>::put(&account);
+
+            Self::deposit_event(Event::RewardsAccountSet {
+                old_account,
+                new_account: account,
+                moved_amount: moved_amount.into(),
+            });
+
+            Ok(())
+        }
+
+        /// Set the Ethereum address the caller's rewards are paid out to.
+        ///
+        /// The address is recorded in the rewards tree of the eras that end from now on, for
+        /// the Ethereum rewards distributor to pay the validator there. Until it is set, the
+        /// validator is paid to the address of its own account.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_reward_address())]
+        pub fn set_reward_address(origin: OriginFor<T>, reward_address: H160) -> DispatchResult {
+            let validator = ensure_signed(origin)?;
+
+            RewardDestination::<T>::insert(&validator, reward_address);
+
+            // The leaf of the validator in the active era commits to its reward address until
+            // the era ends and the address is recorded.
+            let active_era = T::EraIndexProvider::active_era().index;
+            if !EraRewardAddresses::<T>::contains_key(active_era, &validator) {
+                if let Some(points) = RewardPointsForEra::<T>::get(active_era)
+                    .individual
+                    .get(&validator)
+                {
+                    RewardLeavesForEra::<T>::mutate(active_era, |leaves| {
+                        leaves.insert(
+                            validator.clone(),
+                            Self::pending_rewards_leaf(&validator, *points),
+                        );
+                    });
+                }
+            }
+
+            Self::deposit_event(Event::RewardAddressSet {
+                validator,
+                reward_address,
+            });
+
+            Ok(())
+        }
+
+        /// Claim the caller's share of the rewards of `era_index` on DataHaven.
+        ///
+        /// `merkle_proof` must show that the caller's `(account, reward_address, points)` leaf,
+        /// as recorded when the era ended, is part of the rewards tree stored for the era. The
+        /// caller receives `points / total_points` of the era rewards, paid from the rewards
+        /// account. Each validator can claim once per era, and only while the era is within
+        /// `HistoryDepth`. Only the eras that ended with the `Claim` payout can be claimed, the
+        /// rewards of the others are paid out on Ethereum.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(
+            origin: OriginFor<T>,
+            era_index: EraIndex,
+            merkle_proof: RewardsClaimProof,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let pot = EraRewardsPots::<T>::get(era_index).ok_or(Error::<T>::NoClaimableRewards)?;
+            ensure!(
+                pot.payout == RewardsPayout::Claim,
+                Error::<T>::RewardsPaidOnEthereum
+            );
+            ensure!(
+                !ClaimedRewards::<T>::contains_key(era_index, &who),
+                Error::<T>::RewardsAlreadyClaimed
+            );
+
+            let leaf = T::Hashing::hash(&Self::rewards_leaf_data(
+                era_index,
+                &who,
+                merkle_proof.points,
+            ));
+            ensure!(
+                Self::verify_rewards_merkle_proof(MerkleProof {
+                    root: pot.root,
+                    proof: merkle_proof.proof.into_inner(),
+                    number_of_leaves: merkle_proof.number_of_leaves,
+                    leaf_index: merkle_proof.leaf_index,
+                    leaf,
+                }),
+                Error::<T>::InvalidRewardsProof
+            );
+
+            let amount = multiply_by_rational_with_rounding(
+                pot.rewards_amount,
+                merkle_proof.points.into(),
+                pot.total_points,
+                Rounding::Down,
+            )
+            .unwrap_or_default();
+
+            <T::Currency as fungible::Mutate<_>>::transfer(
+                &Self::rewards_account(),
+                &who,
+                amount.into(),
+                Preservation::Preserve,
+            )?;
+            ClaimedRewards::<T>::insert(era_index, &who, ());
+
+            Self::deposit_event(Event::RewardsClaimed {
+                era_index,
+                validator: who,
+                amount,
             });
 
             Ok(())
@@ -244,6 +605,7 @@ pub mod pallet {
             era_index: EraIndex,
             total_points: u128,
             inflation_amount: u128,
+            schema_version: u8,
         },
         /// The rewards message failed to send; era queued for retry.
         RewardsMessageSendFailed { era_index: EraIndex },
@@ -253,11 +615,57 @@ pub mod pallet {
             era_index: EraIndex,
             total_points: u128,
             inflation_amount: u128,
+            schema_version: u8,
         },
         /// An unsent era was dropped because its reward points have been pruned.
         UnsentEraExpired { era_index: EraIndex },
         /// The unsent queue is full; this era could not be enqueued for retry.
         UnsentQueueFull { era_index: EraIndex },
+        /// The inflation of the next era to end has been overridden.
+        NextEraInflationOverridden { amount: u128 },
+        /// The weights of the session performance formula were set by governance.
+        PerformanceWeightsSet { weights: PerformanceWeights },
+        /// The parameters of the performance-scaled inflation were set by governance.
+        InflationParamsSet { params: InflationParams },
+        /// The proportion of the inflation minted to the treasury was set by governance.
+        RewardsSplitSet { treasury_proportion: Perbill },
+        /// The rewards account was changed by governance, and its balance moved to the new one.
+        RewardsAccountSet {
+            old_account: T::AccountId,
+            new_account: T::AccountId,
+            moved_amount: u128,
+        },
+        /// The rewards of the era can be claimed on DataHaven, no message is sent for them.
+        RewardsClaimable {
+            era_index: EraIndex,
+            total_points: u128,
+            rewards_amount: u128,
+        },
+        /// A validator claimed its share of the era rewards.
+        RewardsClaimed {
+            era_index: EraIndex,
+            validator: T::AccountId,
+            amount: u128,
+        },
+        /// A validator set the Ethereum address its rewards are paid out to.
+        RewardAddressSet {
+            validator: T::AccountId,
+            reward_address: H160,
+        },
+        /// More validators than `MaxRewardedValidatorsPerEra` earned points in the era. The
+        /// points of the ones left out of the rewards were carried over to the next era.
+        RewardedValidatorsCapped {
+            era_index: EraIndex,
+            carried_over_validators: u32,
+            carried_over_points: RewardPoints,
+        },
+        /// Outbound messages are held back; era queued until they are sent again.
+        RewardsMessageHeld { era_index: EraIndex },
+        /// A delivery receipt proved that the rewards message of the era was executed on
+        /// Ethereum.
+        RewardsMessageDelivered { era_index: EraIndex },
+        /// A delivery receipt reported that the rewards message of the era failed on Ethereum.
+        RewardsMessageDeliveryFailed { era_index: EraIndex },
     }
 
     #[pallet::error]
@@ -268,6 +676,29 @@ pub mod pallet {
         RewardPointsPruned,
         /// The message delivery still failed on retry.
         MessageSendFailed,
+        /// The era has no rewards pot to claim from, or it has been pruned.
+        NoClaimableRewards,
+        /// The caller already claimed its rewards for this era.
+        RewardsAlreadyClaimed,
+        /// The merkle proof does not match the era rewards root.
+        InvalidRewardsProof,
+        /// The performance weights do not sum to 100%.
+        InvalidPerformanceWeights,
+        /// The expected blocks per era are zero, or the inflation percentages are not ordered
+        /// or exceed 100%.
+        InvalidInflationParams,
+        /// The account is already the rewards account.
+        SameRewardsAccount,
+        /// The reward points of the era no longer give the rewards root stored in its pot.
+        RewardsRootMismatch,
+        /// The rewards of the era are paid out on Ethereum, they cannot be claimed.
+        RewardsPaidOnEthereum,
+        /// The rewards of the era are claimed on DataHaven, they are not sent to Ethereum.
+        RewardsClaimedOnDataHaven,
+        /// The rewards message of the era was delivered to Ethereum, its rewards are paid out.
+        RewardsAlreadyDelivered,
+        /// The delivery of the rewards message of the era is not acknowledged yet.
+        RewardsDeliveryPending,
     }
 
     /// Keep tracks of distributed points per validator and total.
@@ -280,7 +711,8 @@ pub mod pallet {
     impl<AccountId: Ord + sp_runtime::traits::Debug + Parameter> EraRewardPoints<AccountId> {
         /// Generate utils needed for EigenLayer rewards submission:
         ///  - total_points: number of total points of the era_index specified.
-        ///  - individual_points: (address, points) tuples for each validator.
+        ///  - individual_points: (operator address, points) tuples for each validator, the
+        ///    address being given by `operator_address`.
         ///  - inflation_amount: total inflation tokens to distribute.
         ///  - era_start_timestamp: timestamp when the era started (seconds since Unix epoch).
         pub fn generate_era_rewards_info(
@@ -288,15 +720,13 @@ pub mod pallet {
             era_index: EraIndex,
             inflation_amount: u128,
             era_start_timestamp: u32,
+            operator_address: impl Fn(&AccountId) -> H160,
         ) -> Option<EraRewardsUtils> {
-            let mut individual_points = Vec::with_capacity(self.individual.len());
-
-            for (account_id, reward_points) in self.individual.iter() {
-                // Convert AccountId to H160 for EigenLayer rewards submission.
-                // In DataHaven, AccountId is H160, so encode() produces exactly 20 bytes.
-                individual_points
-                    .push((H160::from_slice(&account_id.encode()[..20]), *reward_points));
-            }
+            let individual_points: Vec<_> = self
+                .individual
+                .iter()
+                .map(|(account_id, reward_points)| (operator_address(account_id), *reward_points))
+                .collect();
 
             let total_points: u128 = individual_points.iter().map(|(_, pts)| *pts as u128).sum();
 
@@ -312,6 +742,28 @@ pub mod pallet {
                 inflation_amount,
             })
         }
+
+        /// Merkle leaves of the era rewards tree, one per rewarded validator.
+        ///
+        /// Each leaf is the hash of the SCALE-encoded `(account_id, reward_address,
+        /// reward_points)` tuple, in the same order as `individual`. Validators without a
+        /// `reward_address`, which is the case of every validator of the eras ended before
+        /// the reward addresses were recorded, get a `(account_id, reward_points)` leaf.
+        pub fn generate_rewards_leaves<Hasher: Hash<Output = H256>>(
+            &self,
+            reward_address: impl Fn(&AccountId) -> Option<H160>,
+        ) -> Vec<H256> {
+            self.individual
+                .iter()
+                .map(|(account_id, reward_points)| {
+                    Hasher::hash(&rewards_leaf_data(
+                        account_id,
+                        reward_address(account_id),
+                        *reward_points,
+                    ))
+                })
+                .collect()
+        }
     }
 
     impl<AccountId> Default for EraRewardPoints<AccountId> {
@@ -330,6 +782,14 @@ pub mod pallet {
     pub type RewardPointsForEra<T: Config> =
         StorageMap<_, Twox64Concat, EraIndex, EraRewardPoints<T::AccountId>, ValueQuery>;
 
+    /// Leaf hash of each validator with points in an era rewards tree, in the order of
+    /// `RewardPointsForEra`. Updated as points are awarded, so that the root of the tree is
+    /// computed without hashing every leaf again.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type RewardLeavesForEra<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, BTreeMap<T::AccountId, H256>, ValueQuery>;
+
     /// Track the number of blocks authored by each validator in the current session.
     /// Cleared at the end of each session.
     #[pallet::storage]
@@ -337,6 +797,19 @@ pub mod pallet {
     pub type BlocksAuthoredInSession<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
 
+    /// Performance of each validator in an era, accumulated as its sessions are awarded.
+    /// Whitelisted validators are not accounted.
+    #[pallet::storage]
+    pub type ValidatorPerformanceInEra<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        ValidatorPerformance,
+        OptionQuery,
+    >;
+
     /// Track the total number of blocks produced in each era.
     /// Used to scale inflation based on network performance.
     #[pallet::storage]
@@ -370,18 +843,234 @@ pub mod pallet {
     #[pallet::storage]
     pub type UnsentRewardTail<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Inflation to mint at the end of the next era instead of the scaled
+    /// `EraInflationProvider` amount. Taken when an era with reward points ends.
+    #[pallet::storage]
+    pub type NextEraInflationOverride<T: Config> = StorageValue<_, u128, OptionQuery>;
+
+    /// Weights of the session performance formula set by governance. When not set,
+    /// `BlockAuthoringWeight` and `LivenessWeight` are used.
+    #[pallet::storage]
+    pub type PerformanceWeightsOverride<T: Config> =
+        StorageValue<_, PerformanceWeights, OptionQuery>;
+
+    /// Parameters of the performance-scaled inflation set by governance. When not set,
+    /// `ExpectedBlocksPerEra`, `MinInflationPercent` and `MaxInflationPercent` are used.
+    #[pallet::storage]
+    pub type InflationParamsOverride<T: Config> = StorageValue<_, InflationParams, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultRewardsSplit<T: Config>() -> Perbill {
+        T::DefaultRewardsSplit::get()
+    }
+
+    /// Proportion of the era inflation minted to the treasury, the rest going to the rewards
+    /// account.
+    #[pallet::storage]
+    pub type RewardsSplit<T: Config> = StorageValue<_, Perbill, ValueQuery, DefaultRewardsSplit<T>>;
+
+    /// Account the era rewards are minted to and claimed from, set by governance. When not
+    /// set, `RewardsEthereumSovereignAccount` is used.
+    #[pallet::storage]
+    pub type RewardsAccountOverride<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// Ethereum address each validator is paid out to, set by the validator. When not set,
+    /// the validator is paid to the address of its own account.
+    #[pallet::storage]
+    pub type RewardDestination<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, H160, OptionQuery>;
+
+    /// Ethereum address of each rewarded validator when an era ended, as committed to in the
+    /// era rewards tree.
+    #[pallet::storage]
+    pub type EraRewardAddresses<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        H160,
+        OptionQuery,
+    >;
+
+    /// End of an era still being processed, resumed in `on_initialize`.
+    #[pallet::storage]
+    pub type EraEndInProgress<T: Config> = StorageValue<_, EraEnd<T::AccountId>, OptionQuery>;
+
+    /// Rewards root and amount of each ended era, used to verify `claim_rewards`.
+    #[pallet::storage]
+    pub type EraRewardsPots<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, EraRewardsPot, OptionQuery>;
+
+    /// Delivery of the rewards message of each era sent to Ethereum, within `HistoryDepth`.
+    #[pallet::storage]
+    pub type EraRewardsDelivery<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, RewardsDelivery, OptionQuery>;
+
+    /// Era of each rewards message whose delivery is not acknowledged yet, by message id.
+    #[pallet::storage]
+    pub type PendingRewardsMessages<T: Config> =
+        StorageMap<_, Identity, H256, EraIndex, OptionQuery>;
+
+    /// First and last of the eras out of `HistoryDepth` whose entries per validator, in
+    /// `ClaimedRewards`, `EraRewardAddresses` and `ValidatorPerformanceInEra`, are still being
+    /// removed in `on_idle`.
+    #[pallet::storage]
+    pub type ErasToPrune<T: Config> = StorageValue<_, (EraIndex, EraIndex), OptionQuery>;
+
+    /// Validators that already claimed their rewards for an era.
+    #[pallet::storage]
+    pub type ClaimedRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
     impl<T: Config> Pallet<T> {
         /// Reward validators. Does not check if the validators are valid, caller needs to make sure of that.
         pub fn reward_by_ids(points: impl IntoIterator<Item = (T::AccountId, RewardPoints)>) {
             let active_era = T::EraIndexProvider::active_era();
 
+            let mut leaves = RewardLeavesForEra::<T>::get(active_era.index);
             RewardPointsForEra::<T>::mutate(active_era.index, |era_rewards| {
                 for (validator, points) in points.into_iter() {
-                    (*era_rewards.individual.entry(validator.clone()).or_default())
-                        .saturating_accrue(points);
+                    let validator_points =
+                        era_rewards.individual.entry(validator.clone()).or_default();
+                    validator_points.saturating_accrue(points);
                     era_rewards.total.saturating_accrue(points);
+                    leaves.insert(
+                        validator.clone(),
+                        Self::pending_rewards_leaf(&validator, *validator_points),
+                    );
                 }
-            })
+            });
+            RewardLeavesForEra::<T>::insert(active_era.index, leaves);
+        }
+
+        /// Rebuild the rewards message of `era_index` from its rewards pot and send it.
+        fn resend_era_rewards_pot(era_index: EraIndex) -> DispatchResult {
+            let pot = EraRewardsPots::<T>::get(era_index).ok_or(Error::<T>::NoClaimableRewards)?;
+            ensure!(
+                pot.payout == RewardsPayout::Ethereum,
+                Error::<T>::RewardsClaimedOnDataHaven
+            );
+            let delivery = EraRewardsDelivery::<T>::get(era_index);
+            match delivery {
+                Some(RewardsDelivery::Delivered) => {
+                    return Err(Error::<T>::RewardsAlreadyDelivered.into())
+                }
+                Some(RewardsDelivery::Pending(_)) => {
+                    return Err(Error::<T>::RewardsDeliveryPending.into())
+                }
+                Some(RewardsDelivery::Failed) | Some(RewardsDelivery::Dropped) => {}
+                None => ensure!(
+                    Self::unsent_queue_find(era_index).is_some(),
+                    Error::<T>::EraNotInUnsentQueue
+                ),
+            }
+            ensure!(
+                Self::era_rewards_merkle_root(era_index) == Some(pot.root),
+                Error::<T>::RewardsRootMismatch
+            );
+            let info = RewardPointsForEra::<T>::get(era_index)
+                .generate_era_rewards_info(
+                    era_index,
+                    pot.rewards_amount,
+                    pot.era_start_timestamp,
+                    Self::operator_address,
+                )
+                .ok_or(Error::<T>::RewardPointsPruned)?;
+
+            let message_id =
+                Self::send_rewards_message(&info).ok_or(Error::<T>::MessageSendFailed)?;
+
+            // Eras still queued or dropped were never sent, the others are already accounted for.
+            let queued = Self::unsent_queue_find(era_index);
+            if let Some((slot, _)) = queued {
+                Self::unsent_queue_remove_slot(slot);
+            }
+            if queued.is_some() || delivery == Some(RewardsDelivery::Dropped) {
+                T::RewardsLedger::on_rewards_sent(era_index, pot.rewards_amount);
+                T::SendMessage::on_rewards_sent(&info);
+            }
+
+            Self::deposit_event(Event::RewardsMessageRetried {
+                message_id,
+                era_index,
+                total_points: info.total_points,
+                inflation_amount: pot.rewards_amount,
+                schema_version: T::OutboundSchemaVersion::get(),
+            });
+
+            Ok(())
+        }
+
+        /// Merkle root of the rewards tree for `era_index`.
+        /// Returns `None` if no validator earned points in that era.
+        pub fn era_rewards_merkle_root(era_index: EraIndex) -> Option<H256> {
+            let leaves = RewardLeavesForEra::<T>::get(era_index);
+            if leaves.is_empty() {
+                return None;
+            }
+            Some(merkle_root::<T::Hashing, _>(leaves.into_values()))
+        }
+
+        /// Generate a merkle proof showing that `account_id` earned its recorded points
+        /// in `era_index`. Returns `None` if the validator has no points in that era.
+        pub fn generate_rewards_merkle_proof(
+            account_id: T::AccountId,
+            era_index: EraIndex,
+        ) -> Option<MerkleProof> {
+            let leaves = RewardLeavesForEra::<T>::get(era_index);
+            let leaf_index = leaves
+                .keys()
+                .position(|validator| validator == &account_id)?;
+
+            Some(merkle_proof::<T::Hashing, _>(
+                leaves.into_values(),
+                leaf_index as u64,
+            ))
+        }
+
+        /// Verify a merkle proof generated by [`Pallet::generate_rewards_merkle_proof`].
+        pub fn verify_rewards_merkle_proof(merkle_proof: MerkleProof) -> bool {
+            verify_proof::<T::Hashing, _, _>(
+                &merkle_proof.root,
+                merkle_proof.proof,
+                merkle_proof.number_of_leaves,
+                merkle_proof.leaf_index,
+                merkle_proof.leaf,
+            )
+        }
+
+        /// Reward points earned in `era_index`, ordered by account and starting right after
+        /// `start_key` (or from the first account if `None`).
+        ///
+        /// At most `limit` entries are returned, capped at [`MAX_ERA_POINTS_PAGE_SIZE`]. Callers
+        /// iterate by passing the last returned account as the next `start_key` until a page
+        /// shorter than the requested limit comes back.
+        pub fn era_points_paged(
+            era_index: EraIndex,
+            start_key: Option<T::AccountId>,
+            limit: u32,
+        ) -> Vec<(T::AccountId, RewardPoints)> {
+            let limit = limit.min(MAX_ERA_POINTS_PAGE_SIZE) as usize;
+            let individual = RewardPointsForEra::<T>::get(era_index).individual;
+
+            let lower_bound = match start_key {
+                Some(key) => Bound::Excluded(key),
+                None => Bound::Unbounded,
+            };
+
+            individual
+                .range((lower_bound, Bound::Unbounded))
+                .take(limit)
+                .map(|(account, points)| (account.clone(), *points))
+                .collect()
         }
 
         /// Helper to build, validate and deliver an outbound message.
@@ -391,6 +1080,7 @@ pub mod pallet {
                 log::error!(target: "ext_validators_rewards", "Failed to build outbound message");
                 None
             })?;
+            let payload_hash = BlakeTwo256::hash_of(&outbound);
 
             let ticket = T::SendMessage::validate(outbound)
                 .map_err(|e| {
@@ -402,7 +1092,7 @@ pub mod pallet {
                 })
                 .ok()?;
 
-            T::SendMessage::deliver(ticket)
+            let message_id = T::SendMessage::deliver(ticket)
                 .map_err(|e| {
                     log::error!(
                         target: "ext_validators_rewards",
@@ -410,7 +1100,102 @@ pub mod pallet {
                         e
                     );
                 })
-                .ok()
+                .ok()?;
+
+            T::OnOutboundMessageSent::on_outbound_message_sent(
+                OutboundMessageKind::Rewards,
+                Some(info.era_index),
+                message_id,
+                payload_hash,
+            );
+            EraRewardsDelivery::<T>::insert(info.era_index, RewardsDelivery::Pending(message_id));
+            PendingRewardsMessages::<T>::insert(message_id, info.era_index);
+            Some(message_id)
+        }
+
+        /// Record the delivery of a rewards message to Ethereum
+        ///
+        /// Called with the result reported by the delivery receipt of the message
+        /// `message_id`. Messages which are not rewards messages are ignored.
+        pub fn on_message_delivered(message_id: H256, success: bool) {
+            let Some(era_index) = PendingRewardsMessages::<T>::take(message_id) else {
+                return;
+            };
+            if success {
+                EraRewardsDelivery::<T>::insert(era_index, RewardsDelivery::Delivered);
+                Self::deposit_event(Event::RewardsMessageDelivered { era_index });
+            } else {
+                EraRewardsDelivery::<T>::insert(era_index, RewardsDelivery::Failed);
+                Self::deposit_event(Event::RewardsMessageDeliveryFailed { era_index });
+            }
+        }
+
+        /// Keep at most `MaxRewardedValidatorsPerEra` validators in the rewards of `era_index`,
+        /// carrying the points of the others over to the next era.
+        ///
+        /// Validators are ranked by points, ties going to the lowest account so the cut does
+        /// not depend on the order points were awarded in.
+        pub(crate) fn cap_rewarded_validators(era_index: EraIndex) {
+            let max_rewarded = T::MaxRewardedValidatorsPerEra::get().max(1) as usize;
+            let era_rewards = RewardPointsForEra::<T>::get(era_index);
+            if era_rewards.individual.len() <= max_rewarded {
+                return;
+            }
+
+            let mut ranked: Vec<_> = era_rewards.individual.into_iter().collect();
+            ranked.sort_by(|(account_a, points_a), (account_b, points_b)| {
+                points_b
+                    .cmp(points_a)
+                    .then_with(|| account_a.cmp(account_b))
+            });
+            let carried_over = ranked.split_off(max_rewarded);
+
+            let mut capped_rewards = EraRewardPoints::default();
+            for (validator, points) in ranked {
+                capped_rewards.total.saturating_accrue(points);
+                capped_rewards.individual.insert(validator, points);
+            }
+            RewardLeavesForEra::<T>::mutate(era_index, |leaves| {
+                leaves.retain(|validator, _| capped_rewards.individual.contains_key(validator))
+            });
+            RewardPointsForEra::<T>::insert(era_index, capped_rewards);
+
+            let next_era_index = era_index.saturating_add(1);
+            let carried_over_validators = carried_over.len() as u32;
+            let mut carried_over_points: RewardPoints = 0;
+            let mut next_era_leaves = RewardLeavesForEra::<T>::get(next_era_index);
+            RewardPointsForEra::<T>::mutate(next_era_index, |next_era_rewards| {
+                for (validator, points) in carried_over {
+                    let validator_points = next_era_rewards
+                        .individual
+                        .entry(validator.clone())
+                        .or_default();
+                    validator_points.saturating_accrue(points);
+                    next_era_rewards.total.saturating_accrue(points);
+                    carried_over_points.saturating_accrue(points);
+                    next_era_leaves.insert(
+                        validator.clone(),
+                        Self::pending_rewards_leaf(&validator, *validator_points),
+                    );
+                }
+            });
+            RewardLeavesForEra::<T>::insert(next_era_index, next_era_leaves);
+
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::DbWeight::get()
+                    .reads_writes(carried_over_validators.saturating_add(4).into(), 4),
+                DispatchClass::Mandatory,
+            );
+
+            log::warn!(
+                target: "ext_validators_rewards",
+                "Era {era_index} rewards capped, {carried_over_validators} validators carried over to the next era",
+            );
+            Self::deposit_event(Event::RewardedValidatorsCapped {
+                era_index,
+                carried_over_validators,
+                carried_over_points,
+            });
         }
 
         // ── Ring-buffer helpers ──────────────────────────────────────────
@@ -444,6 +1229,22 @@ pub mod pallet {
             true
         }
 
+        /// Find the slot and entry of `era_index` in the ring buffer.
+        fn unsent_queue_find(era_index: EraIndex) -> Option<(u32, (EraIndex, u32, u128))> {
+            let head = UnsentRewardHead::<T>::get();
+            let tail = UnsentRewardTail::<T>::get();
+            let mut slot = head;
+            while slot != tail {
+                if let Some(entry @ (idx, _, _)) = UnsentRewardEra::<T>::get(slot) {
+                    if idx == era_index {
+                        return Some((slot, entry));
+                    }
+                }
+                slot = (slot + 1) % UNSENT_QUEUE_CAPACITY;
+            }
+            None
+        }
+
         /// Remove the entry at a given slot and compact the buffer by shifting
         /// subsequent entries back. Used by the extrinsic and `on_era_start`.
         fn unsent_queue_remove_slot(slot: u32) {
@@ -488,6 +1289,57 @@ pub mod pallet {
 
         // ── Core retry logic ──────────────────────────────────────────────
 
+        /// Remove the entries per validator of the eras in `ErasToPrune`, as many as fit in
+        /// `remaining_weight`. What is left is removed in the next blocks.
+        pub(crate) fn prune_era_entries(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let Some((mut era, last_era)) = ErasToPrune::<T>::get() else {
+                return db_weight.reads(1);
+            };
+            let base_weight = db_weight.reads_writes(1, 1);
+            let mut limit = remaining_weight
+                .saturating_sub(base_weight)
+                .ref_time()
+                .checked_div(db_weight.writes(1).ref_time())
+                .unwrap_or(u64::MAX)
+                .min(u32::MAX.into()) as u32;
+            if limit == 0 {
+                return db_weight.reads(1);
+            }
+
+            let mut removed = 0u32;
+            while era <= last_era {
+                let mut complete = true;
+                let mut clear = |clear_prefix: fn(EraIndex, u32) -> MultiRemovalResults| {
+                    if limit == 0 {
+                        complete = false;
+                        return;
+                    }
+                    // Each prefix is cleared once per block, so no cursor is needed.
+                    let result = clear_prefix(era, limit);
+                    limit.saturating_reduce(result.loops);
+                    removed.saturating_accrue(result.loops);
+                    complete &= result.maybe_cursor.is_none();
+                };
+                clear(|era, limit| ClaimedRewards::<T>::clear_prefix(era, limit, None));
+                clear(|era, limit| EraRewardAddresses::<T>::clear_prefix(era, limit, None));
+                clear(|era, limit| ValidatorPerformanceInEra::<T>::clear_prefix(era, limit, None));
+
+                if !complete {
+                    break;
+                }
+                era.saturating_inc();
+            }
+
+            if era > last_era {
+                ErasToPrune::<T>::kill();
+            } else {
+                ErasToPrune::<T>::put((era, last_era));
+            }
+
+            base_weight.saturating_add(db_weight.writes(removed.into()))
+        }
+
         /// Process at most one unsent reward era per block.
         /// On failure the head pointer advances to the next entry so a single
         /// stuck era does not block retries for subsequent eras.
@@ -495,7 +1347,7 @@ pub mod pallet {
             let head = UnsentRewardHead::<T>::get();
             let tail = UnsentRewardTail::<T>::get();
 
-            if head == tail {
+            if head == tail || T::HoldOutboundMessages::get() {
                 return T::WeightInfo::process_unsent_reward_eras_empty();
             }
 
@@ -507,32 +1359,39 @@ pub mod pallet {
 
             // Check if reward points are still available
             let reward_points = RewardPointsForEra::<T>::get(era_index);
-            let info =
-                match reward_points.generate_era_rewards_info(era_index, inflation, timestamp) {
-                    Some(info) => info,
-                    None => {
-                        // Reward points have been pruned — discard this entry
-                        log::warn!(
-                            target: "ext_validators_rewards",
-                            "Unsent era {era_index} expired: reward points pruned",
-                        );
-                        UnsentRewardEra::<T>::remove(head);
-                        UnsentRewardHead::<T>::put((head + 1) % UNSENT_QUEUE_CAPACITY);
-                        Self::deposit_event(Event::UnsentEraExpired { era_index });
-                        return T::WeightInfo::process_unsent_reward_eras_expired();
-                    }
-                };
+            let info = match reward_points.generate_era_rewards_info(
+                era_index,
+                inflation,
+                timestamp,
+                Self::operator_address,
+            ) {
+                Some(info) => info,
+                None => {
+                    // Reward points have been pruned — discard this entry
+                    log::warn!(
+                        target: "ext_validators_rewards",
+                        "Unsent era {era_index} expired: reward points pruned",
+                    );
+                    UnsentRewardEra::<T>::remove(head);
+                    UnsentRewardHead::<T>::put((head + 1) % UNSENT_QUEUE_CAPACITY);
+                    Self::deposit_event(Event::UnsentEraExpired { era_index });
+                    return T::WeightInfo::process_unsent_reward_eras_expired();
+                }
+            };
 
             // Attempt to resend
             match Self::send_rewards_message(&info) {
                 Some(message_id) => {
                     UnsentRewardEra::<T>::remove(head);
                     UnsentRewardHead::<T>::put((head + 1) % UNSENT_QUEUE_CAPACITY);
+                    T::RewardsLedger::on_rewards_sent(era_index, inflation);
+                    T::SendMessage::on_rewards_sent(&info);
                     Self::deposit_event(Event::RewardsMessageRetried {
                         message_id,
                         era_index,
                         total_points: info.total_points,
                         inflation_amount: inflation,
+                        schema_version: T::OutboundSchemaVersion::get(),
                     });
                     T::WeightInfo::process_unsent_reward_eras_success()
                 }
@@ -554,6 +1413,63 @@ pub mod pallet {
             }
         }
 
+        /// Checks that the unsent eras ring buffer only has entries between its head and
+        /// tail, that the reward points of every era add up to their total and have a
+        /// rewards tree leaf each, and that the pending rewards messages are the last sent for
+        /// their era.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            use frame_support::ensure;
+
+            let head = UnsentRewardHead::<T>::get();
+            let tail = UnsentRewardTail::<T>::get();
+            ensure!(
+                head < UNSENT_QUEUE_CAPACITY && tail < UNSENT_QUEUE_CAPACITY,
+                "The unsent eras ring buffer pointers are out of bounds"
+            );
+            let len = Self::unsent_queue_len();
+            ensure!(
+                UnsentRewardEra::<T>::iter_keys()
+                    .all(|slot| slot.wrapping_sub(head) % UNSENT_QUEUE_CAPACITY < len),
+                "An unsent era is stored outside of the ring buffer"
+            );
+
+            for (era_index, points) in RewardPointsForEra::<T>::iter() {
+                ensure!(
+                    RewardLeavesForEra::<T>::get(era_index)
+                        .keys()
+                        .eq(points.individual.keys()),
+                    "The rewards tree leaves of an era do not match its reward points"
+                );
+                let individual_total = points
+                    .individual
+                    .values()
+                    .fold(0 as RewardPoints, |total, points| {
+                        total.saturating_add(*points)
+                    });
+                ensure!(
+                    individual_total == points.total,
+                    "The reward points of an era do not add up to its total"
+                );
+            }
+
+            if let Some(era_end) = EraEndInProgress::<T>::get() {
+                ensure!(
+                    !EraRewardsPots::<T>::contains_key(era_end.era_index),
+                    "The end of an era is in progress after its rewards were sent"
+                );
+            }
+
+            for (message_id, era_index) in PendingRewardsMessages::<T>::iter() {
+                ensure!(
+                    EraRewardsDelivery::<T>::get(era_index)
+                        == Some(RewardsDelivery::Pending(message_id)),
+                    "A pending rewards message is not the one of its era"
+                );
+            }
+            Ok(())
+        }
+
         /// Track a block authored by a validator
         pub fn note_block_author(author: T::AccountId) {
             // Track per-session authorship for performance points
@@ -568,6 +1484,70 @@ pub mod pallet {
             });
         }
 
+        /// Account the era rewards are minted to: the one set by governance, or the configured
+        /// `RewardsEthereumSovereignAccount`.
+        pub fn rewards_account() -> T::AccountId {
+            RewardsAccountOverride::<T>::get()
+                .unwrap_or_else(T::RewardsEthereumSovereignAccount::get)
+        }
+
+        /// Ethereum address the rewards of `validator` are paid out to: the one it set, or the
+        /// address of its account.
+        pub fn reward_address(validator: &T::AccountId) -> H160 {
+            RewardDestination::<T>::get(validator).unwrap_or_else(|| {
+                let account = validator.encode();
+                let mut address = H160::zero();
+                let len = account.len().min(address.0.len());
+                address.0[..len].copy_from_slice(&account[..len]);
+                address
+            })
+        }
+
+        /// Address of the EigenLayer operator rewarded for `validator`: the one registered for
+        /// it, or the address of its account.
+        pub fn operator_address(validator: &T::AccountId) -> H160 {
+            T::OperatorAddresses::operator_address(validator).unwrap_or_else(|| {
+                let account = validator.encode();
+                let mut address = H160::zero();
+                let len = account.len().min(address.0.len());
+                address.0[..len].copy_from_slice(&account[..len]);
+                address
+            })
+        }
+
+        /// Content of the leaf of `validator` in the rewards tree of `era_index`.
+        fn rewards_leaf_data(
+            era_index: EraIndex,
+            validator: &T::AccountId,
+            points: RewardPoints,
+        ) -> Vec<u8> {
+            rewards_leaf_data(
+                validator,
+                EraRewardAddresses::<T>::get(era_index, validator),
+                points,
+            )
+        }
+
+        /// Leaf of `validator` in the rewards tree of an era that has not ended yet, which
+        /// commits to its current reward address.
+        pub(crate) fn pending_rewards_leaf(validator: &T::AccountId, points: RewardPoints) -> H256 {
+            T::Hashing::hash(&rewards_leaf_data(
+                validator,
+                Some(Self::reward_address(validator)),
+                points,
+            ))
+        }
+
+        /// Parameters of the inflation curve: the ones set by governance, or the configured
+        /// constants.
+        pub fn inflation_params() -> InflationParams {
+            InflationParamsOverride::<T>::get().unwrap_or_else(|| InflationParams {
+                expected_blocks_per_era: T::ExpectedBlocksPerEra::get(),
+                min_inflation_percent: T::MinInflationPercent::get(),
+                max_inflation_percent: T::MaxInflationPercent::get(),
+            })
+        }
+
         /// Calculate performance-scaled inflation based on blocks produced in the era.
         ///
         /// # Formula
@@ -590,9 +1570,11 @@ pub mod pallet {
             use sp_runtime::Perbill;
 
             let blocks_produced = BlocksProducedInEra::<T>::get(era_index);
-            let expected_blocks = T::ExpectedBlocksPerEra::get();
-            let min_percent = T::MinInflationPercent::get();
-            let max_percent = T::MaxInflationPercent::get();
+            let InflationParams {
+                expected_blocks_per_era: expected_blocks,
+                min_inflation_percent: min_percent,
+                max_inflation_percent: max_percent,
+            } = Self::inflation_params();
 
             // Calculate performance ratio (capped at 100%)
             let performance_ratio = if expected_blocks > 0 {
@@ -646,12 +1628,13 @@ pub mod pallet {
         ///
         /// # Liveness Scoring
         ///
-        /// Uses block authorship as proof of liveness. A validator is considered online if
-        /// they authored at least one block in the current session. This is simpler and more
-        /// reliable than ImOnline heartbeats, which have timing issues with session rotation.
+        /// A validator is considered online if they authored at least one block in the current
+        /// session, or if `LivenessCheck` contains them (e.g. they were not reported
+        /// unresponsive by ImOnline).
         ///
         /// # Weight Validation
         ///
+        /// Weights set through `set_performance_weights` take precedence over the configured ones.
         /// If BlockAuthoringWeight + LivenessWeight > 100%, values are proportionally scaled down
         /// to ensure the sum does not exceed 100%. This prevents configuration errors from
         /// breaking the reward system.
@@ -660,6 +1643,9 @@ pub mod pallet {
         ///
         /// Whitelisted validators are excluded from rewards AND from fair share calculation.
         /// This ensures regular validators' fair share isn't diluted by whitelisted validators.
+        ///
+        /// The inputs and result of the formula are accumulated per era in
+        /// [`ValidatorPerformanceInEra`], so validators can check their points.
         pub fn award_session_performance_points(
             session_index: SessionIndex,
             validators: Vec<T::AccountId>,
@@ -709,8 +1695,13 @@ pub mod pallet {
             let max_credited_blocks =
                 fair_share.saturating_add(fair_share_cap.mul_floor(fair_share));
 
-            // Get and validate reward weights with defensive scaling
-            let (block_weight, liveness_weight, base_weight) = {
+            // Weights set by governance always sum to 100%, the configured ones are scaled
+            // down defensively
+            let (block_weight, liveness_weight, base_weight) = if let Some(weights) =
+                PerformanceWeightsOverride::<T>::get()
+            {
+                (weights.block_authoring, weights.liveness, weights.base)
+            } else {
                 let raw_block = T::BlockAuthoringWeight::get();
                 let raw_liveness = T::LivenessWeight::get();
                 let sum = raw_block.saturating_add(raw_liveness);
@@ -750,6 +1741,7 @@ pub mod pallet {
                 base_weight.deconstruct() * 100 / Perbill::ACCURACY
             );
 
+            let era_index = T::EraIndexProvider::active_era().index;
             let mut rewards = Vec::new();
 
             // Calculate points for each validator
@@ -782,11 +1774,9 @@ pub mod pallet {
                 // credited_blocks = min(blocks_authored, max_credited_blocks)
                 let credited_blocks = blocks_authored.min(max_credited_blocks);
 
-                // Liveness score: Use block authorship as proof of liveness.
-                // A validator who authored at least one block is definitively online.
-                // This is simpler and more reliable than trying to cache ImOnline state
-                // which has timing issues with session rotation.
-                let is_online = blocks_authored > 0;
+                // Liveness score: a validator who authored at least one block is definitively
+                // online. Otherwise we rely on the configured liveness provider.
+                let is_online = blocks_authored > 0 || T::LivenessCheck::contains(validator);
                 let liveness_score = if is_online {
                     Perbill::one()
                 } else {
@@ -835,6 +1825,35 @@ pub mod pallet {
                 // Total points = block contribution + liveness/base contribution
                 let points = block_contribution.saturating_add(liveness_base_contribution);
 
+                // Weighted score of the session, not capped at 100% for over-performers
+                let score = FixedU128::from_perbill(block_weight)
+                    .saturating_mul(FixedU128::saturating_from_rational(
+                        credited_blocks,
+                        fair_share,
+                    ))
+                    .saturating_add(FixedU128::from_perbill(other_weight));
+                ValidatorPerformanceInEra::<T>::mutate(era_index, validator, |performance| {
+                    let performance = performance.get_or_insert_with(Default::default);
+                    let previous_sessions = FixedU128::from_u32(performance.sessions);
+                    performance.sessions.saturating_inc();
+                    performance.weighted_score = performance
+                        .weighted_score
+                        .saturating_mul(previous_sessions)
+                        .saturating_add(score)
+                        / FixedU128::from_u32(performance.sessions);
+                    performance
+                        .sessions_online
+                        .saturating_accrue(u32::from(is_online));
+                    performance
+                        .blocks_authored
+                        .saturating_accrue(blocks_authored);
+                    performance
+                        .credited_blocks
+                        .saturating_accrue(credited_blocks);
+                    performance.expected_blocks.saturating_accrue(fair_share);
+                    performance.points.saturating_accrue(points);
+                });
+
                 if points > 0 {
                     log::debug!(
                         target: "ext_validators_rewards",
@@ -869,7 +1888,19 @@ pub mod pallet {
             };
 
             RewardPointsForEra::<T>::remove(era_index_to_delete);
+            RewardLeavesForEra::<T>::remove(era_index_to_delete);
             BlocksProducedInEra::<T>::remove(era_index_to_delete);
+            EraRewardsPots::<T>::remove(era_index_to_delete);
+            if let Some(RewardsDelivery::Pending(message_id)) =
+                EraRewardsDelivery::<T>::take(era_index_to_delete)
+            {
+                PendingRewardsMessages::<T>::remove(message_id);
+            }
+            // The entries per validator are removed in `on_idle`, as the weight allows.
+            ErasToPrune::<T>::mutate(|eras| {
+                let first_era = eras.map_or(era_index_to_delete, |(first_era, _)| first_era);
+                *eras = Some((first_era, era_index_to_delete));
+            });
 
             // Proactively clean up any unsent entries whose reward points
             // have been pruned (this era and any older ones still lingering).
@@ -893,9 +1924,19 @@ pub mod pallet {
 
     impl<T: Config> OnEraEnd for Pallet<T> {
         fn on_era_end(era_index: EraIndex) {
-            // Calculate performance-scaled inflation based on blocks produced.
-            let base_inflation = T::EraInflationProvider::get();
-            let scaled_inflation = Self::calculate_scaled_inflation(era_index, base_inflation);
+            // The points of the next eras must not be mixed up with the ones of an era whose
+            // end is still being processed.
+            if let Some(era_end) = EraEndInProgress::<T>::get() {
+                log::warn!(
+                    target: "ext_validators_rewards",
+                    "End of era {} still in progress when era {era_index} ended, completing it",
+                    era_end.era_index,
+                );
+                frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                    Self::progress_era_end(u32::MAX),
+                    DispatchClass::Mandatory,
+                );
+            }
 
             // Check that there are reward points before minting.
             // This prevents minting inflation when no validators have earned rewards.
@@ -915,21 +1956,16 @@ pub mod pallet {
                 return;
             }
 
-            let ethereum_sovereign_account = T::RewardsEthereumSovereignAccount::get();
+            // Calculate performance-scaled inflation based on blocks produced, unless it has
+            // been overridden. Taken only once the era mints, so an override set for an era
+            // without points applies to the next one.
+            let scaled_inflation = NextEraInflationOverride::<T>::take().unwrap_or_else(|| {
+                let base_inflation = T::EraInflationProvider::get();
+                Self::calculate_scaled_inflation(era_index, base_inflation)
+            });
 
-            // Mint scaled inflation tokens using the configurable handler.
-            // Returns an InflationMintResult with the rewards/treasury split.
-            let mint_result = match T::HandleInflation::mint_inflation(
-                &ethereum_sovereign_account,
-                scaled_inflation,
-            ) {
-                Ok(result) => result,
-                Err(err) => {
-                    log::error!(target: "ext_validators_rewards", "Failed to handle inflation: {err:?}");
-                    log::error!(target: "ext_validators_rewards", "Not sending message since there are no rewards to distribute");
-                    return;
-                }
-            };
+            // Keep the rewards message and tree within bounds before building them.
+            Self::cap_rewarded_validators(era_index);
 
             // Get era start timestamp from the active era (still the ending era at this point).
             // Convert from milliseconds to seconds for EigenLayer compatibility.
@@ -938,12 +1974,153 @@ pub mod pallet {
                 .map(|ms| (ms / 1000) as u32)
                 .unwrap_or(0);
 
+            // The rest is processed in stages, over the next blocks if needed.
+            EraEndInProgress::<T>::put(EraEnd {
+                era_index,
+                inflation: scaled_inflation,
+                era_start_timestamp,
+                stage: EraEndStage::GenerateLeaves { last: None },
+            });
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                Self::progress_era_end(T::EraEndStepsPerBlock::get()),
+                DispatchClass::Mandatory,
+            );
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Process up to `steps` steps of the era end in progress, see [`EraEndStage`].
+        /// Generating the leaf of a validator is one step, and so is each of the other stages.
+        pub(crate) fn progress_era_end(mut steps: u32) -> Weight {
+            let Some(mut era_end) = EraEndInProgress::<T>::get() else {
+                return T::DbWeight::get().reads(1);
+            };
+            let era_index = era_end.era_index;
+            // The stage of the era end is read and written back, the steps themselves are
+            // accounted for by the `on_era_end` benchmark.
+            let weight = T::DbWeight::get().reads_writes(1, 1);
+            let mut steps_done = 0u32;
+
+            while steps > 0 {
+                era_end.stage = match era_end.stage {
+                    EraEndStage::GenerateLeaves { last } => {
+                        let (generated, last) = Self::generate_era_leaves(era_index, last, steps);
+                        let generated = generated.max(1);
+                        steps.saturating_reduce(generated);
+                        steps_done.saturating_accrue(generated);
+                        match last {
+                            Some(last) => EraEndStage::GenerateLeaves { last: Some(last) },
+                            None => EraEndStage::ComputeRoot,
+                        }
+                    }
+                    EraEndStage::ComputeRoot => {
+                        steps.saturating_dec();
+                        steps_done.saturating_inc();
+                        EraEndStage::Mint {
+                            root: Self::era_rewards_merkle_root(era_index),
+                        }
+                    }
+                    EraEndStage::Mint { root } => {
+                        steps.saturating_dec();
+                        steps_done.saturating_inc();
+                        // Mint scaled inflation tokens using the configurable handler.
+                        // Returns an InflationMintResult with the rewards/treasury split.
+                        match T::HandleInflation::mint_inflation(
+                            &Self::rewards_account(),
+                            era_end.inflation,
+                        ) {
+                            Ok(mint_result) => {
+                                T::RewardsLedger::on_inflation_minted(era_index, &mint_result);
+                                EraEndStage::Send {
+                                    root,
+                                    rewards_amount: mint_result.rewards_amount,
+                                }
+                            }
+                            Err(err) => {
+                                log::error!(target: "ext_validators_rewards", "Failed to handle inflation: {err:?}");
+                                log::error!(target: "ext_validators_rewards", "Not sending message since there are no rewards to distribute");
+                                EraEndInProgress::<T>::kill();
+                                return weight
+                                    .saturating_add(T::WeightInfo::on_era_end(steps_done));
+                            }
+                        }
+                    }
+                    EraEndStage::Send {
+                        root,
+                        rewards_amount,
+                    } => {
+                        steps_done.saturating_inc();
+                        Self::send_era_rewards(&era_end, root, rewards_amount);
+                        EraEndInProgress::<T>::kill();
+                        return weight.saturating_add(T::WeightInfo::on_era_end(steps_done));
+                    }
+                };
+            }
+
+            EraEndInProgress::<T>::put(era_end);
+            weight.saturating_add(T::WeightInfo::on_era_end(steps_done))
+        }
+
+        /// Record the reward address of up to `limit` rewarded validators of `era_index`, the
+        /// ones after `last`, and generate their leaf with it.
+        ///
+        /// Returns the number of leaves generated and the last validator whose leaf was, or
+        /// `None` once every leaf of the era is generated.
+        fn generate_era_leaves(
+            era_index: EraIndex,
+            last: Option<T::AccountId>,
+            limit: u32,
+        ) -> (u32, Option<T::AccountId>) {
+            let era_rewards = RewardPointsForEra::<T>::get(era_index);
+            let lower_bound = match last {
+                Some(last) => Bound::Excluded(last),
+                None => Bound::Unbounded,
+            };
+            let mut validators = era_rewards
+                .individual
+                .range((lower_bound, Bound::Unbounded));
+
+            let mut leaves = RewardLeavesForEra::<T>::get(era_index);
+            let mut generated = 0u32;
+            let mut last = None;
+            for (validator, points) in validators.by_ref().take(limit as usize) {
+                // Commit to the current reward address of the validator, so that the rewards
+                // tree of the era no longer changes with it.
+                let reward_address = Self::reward_address(validator);
+                EraRewardAddresses::<T>::insert(era_index, validator, reward_address);
+                leaves.insert(
+                    validator.clone(),
+                    T::Hashing::hash(&rewards_leaf_data(validator, Some(reward_address), *points)),
+                );
+                generated.saturating_inc();
+                last = Some(validator.clone());
+            }
+            RewardLeavesForEra::<T>::insert(era_index, leaves);
+
+            if validators.next().is_none() {
+                (generated, None)
+            } else {
+                (generated, last)
+            }
+        }
+
+        /// Keep the rewards pot of an era whose inflation was minted and send its rewards
+        /// message, queueing it for retry if it could not be sent.
+        fn send_era_rewards(
+            era_end: &EraEnd<T::AccountId>,
+            root: Option<H256>,
+            rewards_amount: u128,
+        ) {
+            let era_index = era_end.era_index;
+            let era_start_timestamp = era_end.era_start_timestamp;
+
             // Generate era rewards utils with the actual rewards amount (post-treasury split).
             // This ensures the message to EigenLayer matches the actual minted rewards.
             let info = match RewardPointsForEra::<T>::get(&era_index).generate_era_rewards_info(
                 era_index,
-                mint_result.rewards_amount,
+                rewards_amount,
                 era_start_timestamp,
+                Self::operator_address,
             ) {
                 Some(info) => info,
                 None => {
@@ -956,33 +2133,63 @@ pub mod pallet {
                 }
             };
 
-            frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::on_era_end(),
-                DispatchClass::Mandatory,
-            );
+            // Keep the rewards root, to claim the rewards or resend the message of the era.
+            let payout = T::RewardsPayout::get();
+            if let Some(root) = root {
+                EraRewardsPots::<T>::insert(
+                    era_index,
+                    EraRewardsPot {
+                        root,
+                        total_points: info.total_points,
+                        rewards_amount,
+                        era_start_timestamp,
+                        payout,
+                    },
+                );
+            }
 
-            match Self::send_rewards_message(&info) {
+            // Rewards claimed on DataHaven are not sent to Ethereum, so they are paid out once.
+            if payout == RewardsPayout::Claim {
+                Self::deposit_event(Event::RewardsClaimable {
+                    era_index,
+                    total_points: info.total_points,
+                    rewards_amount,
+                });
+                return;
+            }
+
+            let held = T::HoldOutboundMessages::get();
+            let sent = if held {
+                None
+            } else {
+                Self::send_rewards_message(&info)
+            };
+            match sent {
                 Some(message_id) => {
+                    T::RewardsLedger::on_rewards_sent(era_index, rewards_amount);
+                    T::SendMessage::on_rewards_sent(&info);
                     Self::deposit_event(Event::RewardsMessageSent {
                         message_id,
                         era_index,
                         total_points: info.total_points,
-                        inflation_amount: mint_result.rewards_amount,
+                        inflation_amount: rewards_amount,
+                        schema_version: T::OutboundSchemaVersion::get(),
                     });
                 }
                 None => {
-                    // Message failed — queue for automatic retry via on_initialize
-                    if Self::unsent_queue_push((
-                        era_index,
-                        era_start_timestamp,
-                        mint_result.rewards_amount,
-                    )) {
-                        Self::deposit_event(Event::RewardsMessageSendFailed { era_index });
+                    // Message held back or failed — queue for automatic retry via on_initialize
+                    if Self::unsent_queue_push((era_index, era_start_timestamp, rewards_amount)) {
+                        if held {
+                            Self::deposit_event(Event::RewardsMessageHeld { era_index });
+                        } else {
+                            Self::deposit_event(Event::RewardsMessageSendFailed { era_index });
+                        }
                     } else {
                         log::error!(
                             target: "ext_validators_rewards",
                             "Unsent reward queue full, cannot enqueue era {era_index}",
                         );
+                        EraRewardsDelivery::<T>::insert(era_index, RewardsDelivery::Dropped);
                         Self::deposit_event(Event::UnsentQueueFull { era_index });
                     }
                 }
@@ -995,7 +2202,7 @@ pub mod pallet {
 ///
 /// This implements the 60/30/10 performance formula for solochain validators:
 /// - 60% weight: Block production (credited blocks vs fair share)
-/// - 30% weight: Liveness (1.0 if authored at least one block, 0.0 otherwise)
+/// - 30% weight: Liveness (1.0 if authored at least one block or in `LivenessCheck`, 0.0 otherwise)
 /// - 10% weight: Base guarantee (always awarded)
 ///
 /// Wraps an inner SessionManager (typically `NoteHistoricalRoot<ExternalValidators>`) and calls
@@ -1073,3 +2280,12 @@ impl<T: Config>
         Self::note_block_author(author);
     }
 }
+
+/// Proportion of the era inflation minted to the treasury, as set in [`RewardsSplit`].
+pub struct TreasuryProportion<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> Get<Perbill> for TreasuryProportion<T> {
+    fn get() -> Perbill {
+        RewardsSplit::<T>::get()
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the external validators rewards pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::StorageVersion,
+    traits::UncheckedOnRuntimeUpgrade, weights::Weight,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "ext_validators_rewards::migration";
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+pub mod v2 {
+    use super::*;
+
+    /// Stores the treasury proportion the runtime used so far, `Current`, in `RewardsSplit`.
+    pub struct UncheckedMigrateV1ToV2<T, Current>(PhantomData<(T, Current)>);
+
+    impl<T: Config, Current: Get<Perbill>> UncheckedOnRuntimeUpgrade
+        for UncheckedMigrateV1ToV2<T, Current>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let treasury_proportion = Current::get();
+            RewardsSplit::<T>::put(treasury_proportion);
+
+            log::info!(
+                target: LOG_TARGET,
+                "Set the rewards split to {:?} of the inflation to the treasury",
+                treasury_proportion,
+            );
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(Current::get().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let treasury_proportion = Perbill::decode(&mut state.as_slice())
+                .map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            frame_support::ensure!(
+                RewardsSplit::<T>::exists(),
+                "The rewards split was not stored"
+            );
+            frame_support::ensure!(
+                RewardsSplit::<T>::get() == treasury_proportion,
+                "The treasury proportion was not carried over to the rewards split"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use pallet_external_validators::traits::EraIndexProvider;
+    use sp_runtime::Saturating;
+
+    /// Fills `RewardLeavesForEra` for the eras that already have reward points.
+    ///
+    /// Validators keep the leaf their rewards tree was built from when the era ended, and
+    /// the eras that have not ended yet commit to the current reward addresses.
+    pub struct UncheckedMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let active_era = T::EraIndexProvider::active_era().index;
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            for (era_index, points) in RewardPointsForEra::<T>::iter() {
+                let leaves: BTreeMap<_, _> = points
+                    .individual
+                    .iter()
+                    .map(|(validator, reward_points)| {
+                        reads.saturating_accrue(2);
+                        let reward_address = EraRewardAddresses::<T>::get(era_index, validator)
+                            .or_else(|| {
+                                (era_index >= active_era)
+                                    .then(|| Pallet::<T>::reward_address(validator))
+                            });
+                        let leaf = T::Hashing::hash(&rewards_leaf_data(
+                            validator,
+                            reward_address,
+                            *reward_points,
+                        ));
+                        (validator.clone(), leaf)
+                    })
+                    .collect();
+                RewardLeavesForEra::<T>::insert(era_index, leaves);
+                reads.saturating_inc();
+                writes.saturating_inc();
+            }
+
+            log::info!(target: LOG_TARGET, "Cached the rewards tree leaves of {writes} eras");
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let roots: Vec<(EraIndex, H256)> = EraRewardsPots::<T>::iter()
+                .map(|(era_index, pot)| (era_index, pot.root))
+                .collect();
+            Ok(roots.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let roots = Vec::<(EraIndex, H256)>::decode(&mut state.as_slice())
+                .map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            for (era_index, root) in roots {
+                frame_support::ensure!(
+                    Pallet::<T>::era_rewards_merkle_root(era_index) == Some(root),
+                    "The rewards root of an ended era changed"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Migrates the pallet to storage version 2, once.
+pub type MigrateV1ToV2<T, Current> = VersionedMigration<
+    1,
+    2,
+    v2::UncheckedMigrateV1ToV2<T, Current>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Migrates the pallet to [`STORAGE_VERSION`] 3, once.
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2,
+    3,
+    v3::UncheckedMigrateV2ToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -19,10 +19,12 @@ use {
     crate::types::HandleInflation,
     frame_support::{
         parameter_types,
-        traits::{fungible::Mutate, ConstU32, ConstU64},
+        traits::{fungible::Mutate, ConstU32, ConstU64, ConstU8},
     },
     pallet_balances::AccountData,
-    pallet_external_validators::traits::ExternalIndexProvider,
+    pallet_external_validators::traits::{
+        ExternalIndexProvider, OnOutboundMessageSent, OutboundMessageKind,
+    },
     snowbridge_outbound_queue_primitives::{SendError, SendMessageFeeProvider},
     sp_core::{H160, H256},
     sp_runtime::{
@@ -61,7 +63,7 @@ impl frame_system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
     type BlockLength = ();
-    type DbWeight = ();
+    type DbWeight = frame_support::weights::constants::RocksDbWeight;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     type Hash = H256;
@@ -140,6 +142,23 @@ impl crate::types::SendMessage for MockOkOutboundQueue {
     fn deliver(_: Self::Ticket) -> Result<H256, SendError> {
         Ok(H256::zero())
     }
+
+    fn on_rewards_sent(utils: &crate::types::EraRewardsUtils) {
+        Mock::mutate(|mock| mock.first_sends.push(utils.era_index));
+    }
+}
+
+pub struct MockOutboundMessageObserver;
+impl OnOutboundMessageSent for MockOutboundMessageObserver {
+    fn on_outbound_message_sent(
+        kind: OutboundMessageKind,
+        era_index: Option<u32>,
+        _message_id: H256,
+        payload_hash: H256,
+    ) {
+        assert_eq!(kind, OutboundMessageKind::Rewards);
+        Mock::mutate(|mock| mock.sent_messages.push((era_index, payload_hash)));
+    }
 }
 
 impl SendMessageFeeProvider for MockOkOutboundQueue {
@@ -177,6 +196,10 @@ parameter_types! {
     // With 32 validators: author gets 196 pts, each non-author gets 4 pts per block
     // Per session (600 blocks): ~6,000 pts/validator, Per era: ~36,000 pts/validator
     pub const BasePointsPerBlock: u32 = 320;
+    pub RewardsPayout: crate::types::RewardsPayout = Mock::mock().rewards_payout;
+    pub MaxRewardedValidatorsPerEra: u32 = Mock::mock().max_rewarded_validators.unwrap_or(100);
+    pub EraEndStepsPerBlock: u32 = Mock::mock().era_end_steps_per_block.unwrap_or(1_000);
+    pub HoldOutboundMessages: bool = Mock::mock().hold_outbound_messages;
 }
 
 pub struct MockValidatorSet;
@@ -189,8 +212,8 @@ impl frame_support::traits::ValidatorSet<H160> for MockValidatorSet {
     }
 
     fn validators() -> Vec<Self::ValidatorId> {
-        // Return empty vec for now - tests will populate via reward_by_ids
-        vec![]
+        // Empty unless a test sets it - most tests populate points via reward_by_ids
+        Mock::mock().validators
     }
 }
 
@@ -205,6 +228,24 @@ impl crate::SlashingCheck<H160> for MockSlashingCheck {
     }
 }
 
+pub struct MockLivenessCheck;
+impl frame_support::traits::Contains<H160> for MockLivenessCheck {
+    fn contains(validator: &H160) -> bool {
+        Mock::mock().heartbeat_validators.contains(validator)
+    }
+}
+
+/// Operators registered in the mock data.
+pub struct MockOperatorAddresses;
+impl pallet_external_validators::traits::OperatorAddressProvider<H160> for MockOperatorAddresses {
+    fn operator_address(validator: &H160) -> Option<H160> {
+        Mock::mock()
+            .operators
+            .into_iter()
+            .find_map(|(registered, operator)| (registered == *validator).then_some(operator))
+    }
+}
+
 impl pallet_external_validators_rewards::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type EraIndexProvider = mock_data::Pallet<Test>;
@@ -217,16 +258,27 @@ impl pallet_external_validators_rewards::Config for Test {
     type BasePointsPerBlock = BasePointsPerBlock;
     type BlockAuthoringWeight = BlockAuthoringWeight;
     type LivenessWeight = LivenessWeight;
+    type LivenessCheck = MockLivenessCheck;
     type FairShareCap = FairShareCap;
     type ExpectedBlocksPerEra = ExpectedBlocksPerEra;
     type MinInflationPercent = MinInflationPercent;
     type MaxInflationPercent = MaxInflationPercent;
+    type MaxRewardedValidatorsPerEra = MaxRewardedValidatorsPerEra;
+    type EraEndStepsPerBlock = EraEndStepsPerBlock;
+    type OperatorAddresses = MockOperatorAddresses;
     type Hashing = Keccak256;
     type SendMessage = MockOkOutboundQueue;
+    type OutboundSchemaVersion = ConstU8<1>;
+    type OnOutboundMessageSent = MockOutboundMessageObserver;
+    type HoldOutboundMessages = HoldOutboundMessages;
     type HandleInflation = InflationMinter;
+    type DefaultRewardsSplit = InflationTreasuryProportion;
+    type RewardsLedger = ();
     type Currency = Balances;
     type RewardsEthereumSovereignAccount = RewardsEthereumSovereignAccount;
+    type RewardsPayout = RewardsPayout;
     type GovernanceOrigin = frame_system::EnsureRoot<H160>;
+    type InflationOverrideOrigin = frame_system::EnsureRoot<H160>;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
@@ -246,7 +298,7 @@ impl HandleInflation<H160> for InflationMinter {
         }
 
         // Get treasury allocation proportion
-        let treasury_proportion = InflationTreasuryProportion::get();
+        let treasury_proportion = pallet_external_validators_rewards::RewardsSplit::<Test>::get();
 
         // Calculate amounts
         let treasury_amount = treasury_proportion.mul_floor(total_amount);
@@ -295,10 +347,28 @@ pub mod mock_data {
         pub era_inflation: Option<u128>,
         /// Set of validators that are considered offline (for liveness testing)
         pub offline_validators: Vec<sp_core::H160>,
+        /// Set of validators that sent a heartbeat, returned by MockLivenessCheck
+        pub heartbeat_validators: Vec<sp_core::H160>,
         /// Set of (era_index, validator_id) pairs that are slashed
         pub slashed_validators: Vec<(u32, sp_core::H160)>,
         /// When true, MockOkOutboundQueue::validate will return Err(SendError::MessageTooLarge)
         pub send_message_fails: bool,
+        /// Validators returned by MockValidatorSet
+        pub validators: Vec<sp_core::H160>,
+        /// Where the rewards of the eras ending are paid out
+        pub rewards_payout: crate::types::RewardsPayout,
+        /// Overrides `MaxRewardedValidatorsPerEra`, 100 if not set
+        pub max_rewarded_validators: Option<u32>,
+        /// Overrides `EraEndStepsPerBlock`, 1000 if not set
+        pub era_end_steps_per_block: Option<u32>,
+        /// (validator, operator) pairs returned by MockOperatorAddresses
+        pub operators: Vec<(sp_core::H160, sp_core::H160)>,
+        /// Era and payload hash of the messages reported to `OnOutboundMessageSent`
+        pub sent_messages: Vec<(Option<EraIndex>, sp_core::H256)>,
+        /// Returned by `HoldOutboundMessages`
+        pub hold_outbound_messages: bool,
+        /// Eras reported to `SendMessage::on_rewards_sent`
+        pub first_sends: Vec<EraIndex>,
     }
 
     #[pallet::config]
@@ -15,11 +15,20 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
 use {
-    crate::{self as pallet_external_validators_rewards, mock::*},
+    crate::{
+        self as pallet_external_validators_rewards, mock::*, types::ValidatorPerformance,
+        ValidatorPerformanceInEra,
+    },
     alloc::collections::btree_map::BTreeMap,
-    frame_support::{assert_noop, assert_ok, traits::fungible::Mutate},
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Hooks},
+        weights::Weight,
+    },
     pallet_external_validators::traits::{ActiveEraInfo, OnEraEnd, OnEraStart},
+    parity_scale_codec::Encode,
     sp_core::H160,
+    sp_runtime::{traits::Keccak256, FixedU128, Perbill, Percent},
 };
 
 #[test]
@@ -165,7 +174,12 @@ fn test_on_era_end() {
         let treasury_amount = InflationTreasuryProportion::get().mul_floor(inflation);
         let rewards_amount = inflation - treasury_amount;
         // Use 0 for era_start_timestamp in tests
-        let rewards_info = era_rewards.generate_era_rewards_info(1, inflation, 0);
+        let rewards_info = era_rewards.generate_era_rewards_info(
+            1,
+            inflation,
+            0,
+            ExternalValidatorsRewards::operator_address,
+        );
         assert!(rewards_info.is_some());
         System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
             crate::Event::RewardsMessageSent {
@@ -173,6 +187,7 @@ fn test_on_era_end() {
                 era_index: 1,
                 total_points: total_points as u128,
                 inflation_amount: rewards_amount,
+                schema_version: 1,
             },
         ));
     })
@@ -207,7 +222,12 @@ fn test_on_era_end_with_zero_inflation() {
         let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
         let inflation =
             <Test as pallet_external_validators_rewards::Config>::EraInflationProvider::get();
-        let rewards_info = era_rewards.generate_era_rewards_info(1, inflation, 0);
+        let rewards_info = era_rewards.generate_era_rewards_info(
+            1,
+            inflation,
+            0,
+            ExternalValidatorsRewards::operator_address,
+        );
         assert!(rewards_info.is_some());
         // With zero inflation, no RewardsMessageSent event should be emitted
         let events = System::events();
@@ -251,7 +271,12 @@ fn test_on_era_end_with_zero_points() {
         let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
         let inflation =
             <Test as pallet_external_validators_rewards::Config>::EraInflationProvider::get();
-        let rewards_info = era_rewards.generate_era_rewards_info(1, inflation, 0);
+        let rewards_info = era_rewards.generate_era_rewards_info(
+            1,
+            inflation,
+            0,
+            ExternalValidatorsRewards::operator_address,
+        );
         assert!(
             rewards_info.is_none(),
             "generate_era_rewards_info should return None when total_points is zero"
@@ -1948,6 +1973,134 @@ fn test_session_performance_multiple_sessions_cumulative() {
     })
 }
 
+#[test]
+fn test_session_performance_is_recorded_per_era() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+        });
+
+        let validators: Vec<H160> = (1..=4).map(H160::from_low_u64_be).collect();
+
+        // Same session as `test_session_performance_60_30_10_formula`
+        for _ in 0..4 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+        }
+        for _ in 0..2 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(3));
+        }
+        end_session(1, validators, vec![]);
+
+        // fair_share = 2, max_credited = 3
+        // score = 60% × credited / fair_share + 30% × online + 10%
+        assert_eq!(
+            ValidatorPerformanceInEra::<Test>::get(1, H160::from_low_u64_be(1)),
+            Some(ValidatorPerformance {
+                sessions: 1,
+                sessions_online: 1,
+                blocks_authored: 4,
+                credited_blocks: 3,
+                expected_blocks: 2,
+                weighted_score: FixedU128::from_rational(13, 10),
+                points: 896,
+            })
+        );
+        assert_eq!(
+            ValidatorPerformanceInEra::<Test>::get(1, H160::from_low_u64_be(3)),
+            Some(ValidatorPerformance {
+                sessions: 1,
+                sessions_online: 1,
+                blocks_authored: 2,
+                credited_blocks: 2,
+                expected_blocks: 2,
+                weighted_score: FixedU128::from_u32(1),
+                points: 704,
+            })
+        );
+        assert_eq!(
+            ValidatorPerformanceInEra::<Test>::get(1, H160::from_low_u64_be(4)),
+            Some(ValidatorPerformance {
+                sessions: 1,
+                sessions_online: 0,
+                blocks_authored: 0,
+                credited_blocks: 0,
+                expected_blocks: 2,
+                weighted_score: FixedU128::from_rational(1, 10),
+                points: 80,
+            })
+        );
+
+        // The recorded points add up to the era points
+        let recorded: u32 = ValidatorPerformanceInEra::<Test>::iter_prefix_values(1)
+            .map(|performance| performance.points)
+            .sum();
+        assert_eq!(
+            recorded,
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1).total
+        );
+    })
+}
+
+#[test]
+fn test_session_performance_is_averaged_over_sessions() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+        });
+
+        let validators: Vec<H160> = (1..=3).map(H160::from_low_u64_be).collect();
+        let whitelisted = vec![H160::from_low_u64_be(3)];
+
+        // Session 1: both validators author their fair share
+        for _ in 0..4 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(3));
+        }
+        end_session(1, validators.clone(), whitelisted.clone());
+
+        // Session 2: validator 2 is offline
+        for _ in 0..3 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+        }
+        end_session(2, validators, whitelisted);
+
+        let performance =
+            ValidatorPerformanceInEra::<Test>::get(1, H160::from_low_u64_be(2)).unwrap();
+        assert_eq!(performance.sessions, 2);
+        assert_eq!(performance.sessions_online, 1);
+        assert_eq!(performance.blocks_authored, 4);
+        assert_eq!(performance.expected_blocks, 4 + 1);
+        // (100% + 10%) / 2
+        assert_eq!(
+            performance.weighted_score,
+            FixedU128::from_rational(55, 100)
+        );
+
+        // Whitelisted validators are not accounted
+        assert_eq!(
+            ValidatorPerformanceInEra::<Test>::get(1, H160::from_low_u64_be(3)),
+            None
+        );
+
+        // Pruned with the other data of the era
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+        ExternalValidatorsRewards::on_idle(1, Weight::MAX);
+        assert_eq!(ValidatorPerformanceInEra::<Test>::iter_prefix(1).count(), 0);
+    })
+}
+
 #[test]
 fn test_session_performance_base_reward_points_config() {
     new_test_ext().execute_with(|| {
@@ -2749,6 +2902,46 @@ fn test_session_performance_offline_validator_zero_blocks() {
     })
 }
 
+#[test]
+fn test_session_performance_heartbeat_proves_liveness() {
+    // A validator that authored no blocks but sent a heartbeat keeps its liveness points
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.heartbeat_validators = vec![H160::from_low_u64_be(2)];
+        });
+
+        let validators = vec![
+            H160::from_low_u64_be(1),
+            H160::from_low_u64_be(2),
+            H160::from_low_u64_be(3),
+        ];
+
+        // Only validators 1 and 3 author blocks
+        for _ in 0..5 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(3));
+        }
+
+        end_session(1, validators, vec![]);
+
+        // Validator 2 (heartbeat, 0 blocks):
+        // block_contribution = 60% × 0 × 320 = 0
+        // liveness_base_contribution = 40% × 10 × 320 / 3 = 426
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(2)),
+            Some(&426),
+            "Validator with a heartbeat but 0 blocks should get liveness and base = 426 points"
+        );
+    })
+}
+
 // =============================================================================
 // WEIGHT OVERFLOW HANDLING TESTS
 // =============================================================================
@@ -3777,6 +3970,42 @@ fn send_failure_queues_era() {
     })
 }
 
+#[test]
+fn held_outbound_messages_queue_era() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: Some(30_000),
+            });
+            mock.hold_outbound_messages = true;
+        });
+
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 100)]);
+        for _ in 0..600 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+        }
+
+        ExternalValidatorsRewards::on_era_end(1);
+
+        assert_eq!(unsent_len(), 1);
+        assert!(Mock::mock().sent_messages.is_empty());
+        System::assert_has_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsMessageHeld { era_index: 1 },
+        ));
+
+        // Held messages are not retried
+        ExternalValidatorsRewards::process_unsent_reward_eras();
+        assert_eq!(unsent_len(), 1);
+
+        Mock::mutate(|mock| mock.hold_outbound_messages = false);
+        ExternalValidatorsRewards::process_unsent_reward_eras();
+        assert!(unsent_is_empty());
+    })
+}
+
 #[test]
 fn on_initialize_retries_and_succeeds() {
     new_test_ext().execute_with(|| {
@@ -3809,6 +4038,7 @@ fn on_initialize_retries_and_succeeds() {
                 era_index: 1,
                 total_points: 100,
                 inflation_amount: 42,
+                schema_version: 1,
             },
         ));
     })
@@ -3863,6 +4093,7 @@ fn on_initialize_moves_failed_entry_to_back() {
                 era_index: 1,
                 total_points: 200,
                 inflation_amount: 42,
+                schema_version: 1,
             },
         ));
 
@@ -3978,6 +4209,7 @@ fn retry_extrinsic_success() {
                 era_index: 1,
                 total_points: 100,
                 inflation_amount: 42,
+                schema_version: 1,
             },
         ));
     })
@@ -4061,6 +4293,10 @@ fn unsent_queue_full() {
 
         // Queue should still be at 63
         assert_eq!(unsent_len(), 63);
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(65),
+            Some(crate::types::RewardsDelivery::Dropped)
+        );
     })
 }
 
@@ -4156,6 +4392,7 @@ fn head_of_line_blocking_avoided() {
                 era_index: 3,
                 total_points: 100,
                 inflation_amount: 30,
+                schema_version: 1,
             },
         ));
 
@@ -4167,6 +4404,7 @@ fn head_of_line_blocking_avoided() {
                 era_index: 1,
                 total_points: 100,
                 inflation_amount: 10,
+                schema_version: 1,
             },
         ));
 
@@ -4175,3 +4413,1814 @@ fn head_of_line_blocking_avoided() {
         assert!(unsent_is_empty());
     })
 }
+
+#[test]
+fn rewards_merkle_proof_roundtrip() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(1), 10),
+            (H160::from_low_u64_be(2), 20),
+            (H160::from_low_u64_be(3), 30),
+        ]);
+
+        let root =
+            ExternalValidatorsRewards::era_rewards_merkle_root(1).expect("era 1 has reward points");
+
+        for (validator, points) in [(1u64, 10u32), (2, 20), (3, 30)] {
+            let account = H160::from_low_u64_be(validator);
+            let proof = ExternalValidatorsRewards::generate_rewards_merkle_proof(account, 1)
+                .expect("validator has reward points");
+
+            assert_eq!(proof.root, root);
+            assert_eq!(proof.number_of_leaves, 3);
+            // The era has not ended, the leaves commit to the current reward addresses.
+            assert_eq!(
+                proof.leaf,
+                <Keccak256 as sp_runtime::traits::Hash>::hash(&(account, account, points).encode())
+            );
+            assert!(ExternalValidatorsRewards::verify_rewards_merkle_proof(
+                proof
+            ));
+        }
+    })
+}
+
+#[test]
+fn rewards_merkle_proof_rejects_tampered_leaf() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(1), 10),
+            (H160::from_low_u64_be(2), 20),
+        ]);
+
+        let mut proof =
+            ExternalValidatorsRewards::generate_rewards_merkle_proof(H160::from_low_u64_be(1), 1)
+                .expect("validator has reward points");
+        proof.leaf = <Keccak256 as sp_runtime::traits::Hash>::hash(
+            &(H160::from_low_u64_be(1), 11u32).encode(),
+        );
+
+        assert!(!ExternalValidatorsRewards::verify_rewards_merkle_proof(
+            proof
+        ));
+    })
+}
+
+#[test]
+fn rewards_merkle_proof_none_without_points() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+
+        assert!(ExternalValidatorsRewards::era_rewards_merkle_root(2).is_none());
+        assert!(ExternalValidatorsRewards::generate_rewards_merkle_proof(
+            H160::from_low_u64_be(2),
+            1
+        )
+        .is_none());
+    })
+}
+
+/// Leaf of `validator` in the rewards tree of an era that has not ended, paying to
+/// `reward_address`.
+fn pending_leaf(validator: u64, reward_address: H160, points: u32) -> sp_core::H256 {
+    <Keccak256 as sp_runtime::traits::Hash>::hash(
+        &(H160::from_low_u64_be(validator), reward_address, points).encode(),
+    )
+}
+
+#[test]
+fn reward_leaves_follow_the_awarded_points() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(1), 10),
+            (H160::from_low_u64_be(2), 20),
+        ]);
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 5)]);
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(1),
+            BTreeMap::from([
+                (
+                    H160::from_low_u64_be(1),
+                    pending_leaf(1, H160::from_low_u64_be(1), 15)
+                ),
+                (
+                    H160::from_low_u64_be(2),
+                    pending_leaf(2, H160::from_low_u64_be(2), 20)
+                ),
+            ])
+        );
+        assert_ok!(ExternalValidatorsRewards::do_try_state());
+    })
+}
+
+#[test]
+fn set_reward_address_updates_the_leaf_of_the_active_era() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(3), 30)]);
+        let root = ExternalValidatorsRewards::era_rewards_merkle_root(1);
+
+        let reward_address = H160::repeat_byte(0xaa);
+        assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(H160::from_low_u64_be(3)),
+            reward_address,
+        ));
+        // Validators without points in the era get no leaf.
+        assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(H160::from_low_u64_be(4)),
+            reward_address,
+        ));
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(1),
+            BTreeMap::from([(
+                H160::from_low_u64_be(3),
+                pending_leaf(3, reward_address, 30)
+            )])
+        );
+        assert_ne!(ExternalValidatorsRewards::era_rewards_merkle_root(1), root);
+    })
+}
+
+/// End era 1 with 10, 30 and 50 points for validators 1, 3 and 5 and 800_000 of rewards
+/// (1_000_000 of inflation minus the 20% sent to the treasury).
+fn end_era_with_claimable_rewards() {
+    run_to_block(1);
+    Mock::mutate(|mock| {
+        mock.active_era = Some(ActiveEraInfo {
+            index: 1,
+            start: None,
+        });
+        mock.rewards_payout = crate::types::RewardsPayout::Claim;
+    });
+    ExternalValidatorsRewards::reward_by_ids([
+        (H160::from_low_u64_be(1), 10),
+        (H160::from_low_u64_be(3), 30),
+        (H160::from_low_u64_be(5), 50),
+    ]);
+    pallet_external_validators_rewards::NextEraInflationOverride::<Test>::put(1_000_000);
+    ExternalValidatorsRewards::on_era_end(1);
+}
+
+fn claim_proof(account: H160, points: u32) -> crate::types::RewardsClaimProof {
+    let proof = ExternalValidatorsRewards::generate_rewards_merkle_proof(account, 1)
+        .expect("validator has reward points");
+    crate::types::RewardsClaimProof {
+        points,
+        proof: proof.proof.try_into().expect("proof fits the bound"),
+        leaf_index: proof.leaf_index,
+        number_of_leaves: proof.number_of_leaves,
+    }
+}
+
+#[test]
+fn claim_rewards_pays_proportional_share() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        let validator = H160::from_low_u64_be(3);
+        let balance_before = Balances::free_balance(validator);
+
+        assert_ok!(ExternalValidatorsRewards::claim_rewards(
+            RuntimeOrigin::signed(validator),
+            1,
+            claim_proof(validator, 30),
+        ));
+
+        // 800_000 * 30 / 90, rounded down
+        assert_eq!(Balances::free_balance(validator), balance_before + 266_666);
+        assert!(
+            pallet_external_validators_rewards::ClaimedRewards::<Test>::contains_key(1, validator)
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsClaimed {
+                era_index: 1,
+                validator,
+                amount: 266_666,
+            },
+        ));
+    })
+}
+
+#[test]
+fn claim_rewards_rejects_double_claim() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        let validator = H160::from_low_u64_be(5);
+
+        assert_ok!(ExternalValidatorsRewards::claim_rewards(
+            RuntimeOrigin::signed(validator),
+            1,
+            claim_proof(validator, 50),
+        ));
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                1,
+                claim_proof(validator, 50),
+            ),
+            crate::Error::<Test>::RewardsAlreadyClaimed
+        );
+    })
+}
+
+#[test]
+fn claim_rewards_rejects_invalid_proof() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        let validator = H160::from_low_u64_be(1);
+
+        // Inflated points
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                1,
+                claim_proof(validator, 11),
+            ),
+            crate::Error::<Test>::InvalidRewardsProof
+        );
+        // Someone else's proof
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(H160::from_low_u64_be(2)),
+                1,
+                claim_proof(validator, 10),
+            ),
+            crate::Error::<Test>::InvalidRewardsProof
+        );
+    })
+}
+
+#[test]
+fn claim_rewards_fails_once_era_is_pruned() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        let validator = H160::from_low_u64_be(3);
+        let merkle_proof = claim_proof(validator, 30);
+
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                2,
+                merkle_proof.clone(),
+            ),
+            crate::Error::<Test>::NoClaimableRewards
+        );
+
+        // HistoryDepth is 10 in the mock
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                1,
+                merkle_proof,
+            ),
+            crate::Error::<Test>::NoClaimableRewards
+        );
+    })
+}
+
+#[test]
+fn claimable_rewards_are_not_sent_to_ethereum() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardsClaimable {
+                era_index: 1,
+                ..
+            })
+        )));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardsMessageSent { .. })
+        )));
+        assert!(ExternalValidatorsRewards::unsent_queue_is_empty());
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::RewardsClaimedOnDataHaven
+        );
+    })
+}
+
+#[test]
+fn rewards_sent_to_ethereum_cannot_be_claimed() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            });
+            mock.rewards_payout = crate::types::RewardsPayout::Ethereum;
+        });
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(3), 30)]);
+        ExternalValidatorsRewards::on_era_end(2);
+
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardsMessageSent {
+                era_index: 2,
+                ..
+            })
+        )));
+        let validator = H160::from_low_u64_be(3);
+        let proof = ExternalValidatorsRewards::generate_rewards_merkle_proof(validator, 2)
+            .expect("validator has reward points");
+        assert_noop!(
+            ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                2,
+                crate::types::RewardsClaimProof {
+                    points: 30,
+                    proof: proof.proof.try_into().expect("proof fits the bound"),
+                    leaf_index: proof.leaf_index,
+                    number_of_leaves: proof.number_of_leaves,
+                },
+            ),
+            crate::Error::<Test>::RewardsPaidOnEthereum
+        );
+    })
+}
+
+#[test]
+fn set_reward_address_stores_destination() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let validator = H160::from_low_u64_be(3);
+        let reward_address = H160::repeat_byte(0xaa);
+
+        // Defaults to the validator account itself
+        assert_eq!(
+            ExternalValidatorsRewards::reward_address(&validator),
+            validator
+        );
+
+        assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(validator),
+            reward_address,
+        ));
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardDestination::<Test>::get(validator),
+            Some(reward_address)
+        );
+        assert_eq!(
+            ExternalValidatorsRewards::reward_address(&validator),
+            reward_address
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardAddressSet {
+                validator,
+                reward_address,
+            },
+        ));
+    })
+}
+
+#[test]
+fn reward_addresses_are_recorded_at_era_end() {
+    new_test_ext().execute_with(|| {
+        let reward_address = H160::repeat_byte(0xaa);
+        assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(H160::from_low_u64_be(3)),
+            reward_address,
+        ));
+
+        end_era_with_claimable_rewards();
+
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardAddresses::<Test>::get(
+                1,
+                H160::from_low_u64_be(3)
+            ),
+            Some(reward_address)
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardAddresses::<Test>::get(
+                1,
+                H160::from_low_u64_be(1)
+            ),
+            Some(H160::from_low_u64_be(1))
+        );
+
+        let proof =
+            ExternalValidatorsRewards::generate_rewards_merkle_proof(H160::from_low_u64_be(3), 1)
+                .expect("validator has reward points");
+        assert_eq!(
+            proof.leaf,
+            <Keccak256 as sp_runtime::traits::Hash>::hash(
+                &(H160::from_low_u64_be(3), reward_address, 30u32).encode()
+            )
+        );
+        assert!(ExternalValidatorsRewards::verify_rewards_merkle_proof(
+            proof
+        ));
+    })
+}
+
+#[test]
+fn changing_reward_address_does_not_affect_ended_eras() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        let validator = H160::from_low_u64_be(3);
+        let root = ExternalValidatorsRewards::era_rewards_merkle_root(1);
+
+        assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(validator),
+            H160::repeat_byte(0xbb),
+        ));
+
+        assert_eq!(ExternalValidatorsRewards::era_rewards_merkle_root(1), root);
+        assert_ok!(ExternalValidatorsRewards::claim_rewards(
+            RuntimeOrigin::signed(validator),
+            1,
+            claim_proof(validator, 30),
+        ));
+    })
+}
+
+#[test]
+fn reward_addresses_are_pruned_with_the_era() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+        assert!(
+            pallet_external_validators_rewards::EraRewardAddresses::<Test>::iter_prefix(1)
+                .next()
+                .is_some()
+        );
+
+        // HistoryDepth is 10 in the mock
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+        ExternalValidatorsRewards::on_idle(1, Weight::MAX);
+
+        assert!(
+            pallet_external_validators_rewards::EraRewardAddresses::<Test>::iter_prefix(1)
+                .next()
+                .is_none()
+        );
+        assert!(!pallet_external_validators_rewards::RewardLeavesForEra::<
+            Test,
+        >::contains_key(1));
+    })
+}
+
+#[test]
+fn era_entries_are_pruned_within_idle_weight() {
+    let entries = || {
+        pallet_external_validators_rewards::ClaimedRewards::<Test>::iter_prefix(1).count()
+            + pallet_external_validators_rewards::EraRewardAddresses::<Test>::iter_prefix(1).count()
+    };
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        end_era_with_claimable_rewards();
+        for (validator, points) in [(1, 10), (3, 30), (5, 50)] {
+            let validator = H160::from_low_u64_be(validator);
+            assert_ok!(ExternalValidatorsRewards::claim_rewards(
+                RuntimeOrigin::signed(validator),
+                1,
+                claim_proof(validator, points),
+            ));
+        }
+        assert_eq!(entries(), 6);
+
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+        assert_eq!(entries(), 6);
+        assert_eq!(
+            pallet_external_validators_rewards::ErasToPrune::<Test>::get(),
+            Some((1, 1))
+        );
+    });
+
+    // Room for the pruned eras and two removals per block. Each block is committed, as the
+    // removals only go on from the committed state.
+    let db_weight = <Test as frame_system::Config>::DbWeight::get();
+    let idle_weight = db_weight.reads_writes(1, 1) + db_weight.writes(2);
+    let mut blocks = 0;
+    while ext.execute_with(pallet_external_validators_rewards::ErasToPrune::<Test>::exists) {
+        ext.commit_all().unwrap();
+        ext.execute_with(|| {
+            let used = ExternalValidatorsRewards::on_idle(1, idle_weight);
+            assert!(used.all_lte(idle_weight));
+        });
+        blocks += 1;
+        assert!(blocks <= 10, "pruning does not progress");
+    }
+
+    ext.execute_with(|| {
+        assert_eq!(entries(), 0);
+        assert!(blocks >= 3);
+
+        // No room to remove anything
+        ExternalValidatorsRewards::on_era_start(12, 0, 12);
+        ExternalValidatorsRewards::on_idle(1, db_weight.reads_writes(1, 1));
+        assert_eq!(
+            pallet_external_validators_rewards::ErasToPrune::<Test>::get(),
+            Some((2, 2))
+        );
+    });
+}
+
+#[test]
+fn era_points_paged_iterates_all_validators_in_order() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids((1..=5).map(|i| (H160::from_low_u64_be(i), 10)));
+
+        let first_page = ExternalValidatorsRewards::era_points_paged(1, None, 2);
+        assert_eq!(
+            first_page,
+            vec![
+                (H160::from_low_u64_be(1), 10),
+                (H160::from_low_u64_be(2), 10)
+            ]
+        );
+
+        let second_page =
+            ExternalValidatorsRewards::era_points_paged(1, Some(H160::from_low_u64_be(2)), 2);
+        assert_eq!(
+            second_page,
+            vec![
+                (H160::from_low_u64_be(3), 10),
+                (H160::from_low_u64_be(4), 10)
+            ]
+        );
+
+        let last_page =
+            ExternalValidatorsRewards::era_points_paged(1, Some(H160::from_low_u64_be(4)), 2);
+        assert_eq!(last_page, vec![(H160::from_low_u64_be(5), 10)]);
+
+        assert!(
+            ExternalValidatorsRewards::era_points_paged(1, Some(H160::from_low_u64_be(5)), 2)
+                .is_empty()
+        );
+    })
+}
+
+#[test]
+fn era_points_paged_caps_limit() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        let validators = u64::from(crate::MAX_ERA_POINTS_PAGE_SIZE) + 10;
+        ExternalValidatorsRewards::reward_by_ids(
+            (1..=validators).map(|i| (H160::from_low_u64_be(i), 1)),
+        );
+
+        let page = ExternalValidatorsRewards::era_points_paged(1, None, u32::MAX);
+        assert_eq!(page.len(), crate::MAX_ERA_POINTS_PAGE_SIZE as usize);
+        assert!(ExternalValidatorsRewards::era_points_paged(2, None, 10).is_empty());
+    })
+}
+
+#[test]
+fn next_era_inflation_override_is_minted_and_consumed() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.era_inflation = Some(10_000_000);
+        });
+        let rewards_account = RewardsEthereumSovereignAccount::get();
+
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::NextEraInflationOverridden { amount: 1_000_000 },
+        ));
+
+        // No blocks authored: without the override only the minimum inflation would be minted
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        let balance_before = Balances::free_balance(&rewards_account);
+        ExternalValidatorsRewards::on_era_end(1);
+
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            balance_before + 1_000_000 * 80 / 100
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::NextEraInflationOverride::<Test>::get(),
+            None
+        );
+
+        // The following era falls back to the scaled provider amount
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        let balance_before = Balances::free_balance(&rewards_account);
+        let expected = ExternalValidatorsRewards::calculate_scaled_inflation(2, 10_000_000);
+        ExternalValidatorsRewards::on_era_end(2);
+
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            balance_before + expected * 80 / 100
+        );
+    })
+}
+
+#[test]
+fn next_era_inflation_override_is_kept_by_era_without_points() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.era_inflation = Some(10_000_000);
+        });
+        let rewards_account = RewardsEthereumSovereignAccount::get();
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+
+        // Nothing is minted for an era without points
+        ExternalValidatorsRewards::on_era_end(1);
+        assert_eq!(
+            pallet_external_validators_rewards::NextEraInflationOverride::<Test>::get(),
+            Some(1_000_000)
+        );
+
+        // The override applies to the next era that mints
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        let balance_before = Balances::free_balance(&rewards_account);
+        ExternalValidatorsRewards::on_era_end(2);
+
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            balance_before + 1_000_000 * 80 / 100
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::NextEraInflationOverride::<Test>::get(),
+            None
+        );
+    })
+}
+
+#[test]
+fn set_next_era_inflation_requires_override_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_next_era_inflation(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                1_000_000
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+/// End era 1 like `end_era_with_claimable_rewards`, sending its rewards to Ethereum.
+fn end_era_sent_to_ethereum() {
+    Mock::mutate(|mock| mock.rewards_payout = crate::types::RewardsPayout::Ethereum);
+    run_to_block(1);
+    Mock::mutate(|mock| {
+        mock.active_era = Some(ActiveEraInfo {
+            index: 1,
+            start: None,
+        });
+    });
+    ExternalValidatorsRewards::reward_by_ids([
+        (H160::from_low_u64_be(1), 10),
+        (H160::from_low_u64_be(3), 30),
+        (H160::from_low_u64_be(5), 50),
+    ]);
+    pallet_external_validators_rewards::NextEraInflationOverride::<Test>::put(1_000_000);
+    ExternalValidatorsRewards::on_era_end(1);
+}
+
+#[test]
+fn force_resend_rewards_message_refuses_delivered_era() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), true);
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::RewardsAlreadyDelivered
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_refuses_era_pending_delivery() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::RewardsDeliveryPending
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_refuses_claimable_era() {
+    new_test_ext().execute_with(|| {
+        end_era_with_claimable_rewards();
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::RewardsClaimedOnDataHaven
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_sends_era_whose_delivery_failed() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), false);
+        System::reset_events();
+
+        assert_ok!(ExternalValidatorsRewards::force_resend_rewards_message(
+            RuntimeOrigin::root(),
+            1
+        ));
+
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsMessageRetried {
+                message_id: Default::default(),
+                era_index: 1,
+                total_points: 90,
+                inflation_amount: 800_000,
+                schema_version: 1,
+            },
+        ));
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1),
+            Some(crate::types::RewardsDelivery::Pending(Default::default()))
+        );
+        // The remainder of the era was handled when it was first sent.
+        assert_eq!(Mock::mock().first_sends, vec![1]);
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_removes_era_from_unsent_queue() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.send_message_fails = true);
+        end_era_sent_to_ethereum();
+        assert_eq!(unsent_len(), 1);
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::MessageSendFailed
+        );
+        assert_eq!(unsent_len(), 1);
+
+        assert!(Mock::mock().first_sends.is_empty());
+
+        Mock::mutate(|mock| mock.send_message_fails = false);
+        assert_ok!(ExternalValidatorsRewards::force_resend_rewards_message(
+            RuntimeOrigin::root(),
+            1
+        ));
+        assert!(unsent_is_empty());
+        assert_eq!(Mock::mock().first_sends, vec![1]);
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_requires_unsent_or_failed_era() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        // The era was sent before deliveries were tracked, so it may have been delivered.
+        pallet_external_validators_rewards::EraRewardsDelivery::<Test>::remove(1);
+        pallet_external_validators_rewards::PendingRewardsMessages::<Test>::remove(
+            sp_core::H256::zero(),
+        );
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::EraNotInUnsentQueue
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_sends_era_dropped_from_full_queue() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.send_message_fails = true);
+        for i in 0..63u32 {
+            push_unsent(i + 100, 0, 42);
+        }
+        end_era_sent_to_ethereum();
+        assert_eq!(unsent_len(), 63);
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1),
+            Some(crate::types::RewardsDelivery::Dropped)
+        );
+
+        Mock::mutate(|mock| mock.send_message_fails = false);
+        assert_ok!(ExternalValidatorsRewards::force_resend_rewards_message(
+            RuntimeOrigin::root(),
+            1
+        ));
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1),
+            Some(crate::types::RewardsDelivery::Pending(Default::default()))
+        );
+        assert_eq!(Mock::mock().first_sends, vec![1]);
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_requires_rewards_pot() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 100)]);
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::NoClaimableRewards
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_requires_root() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), false);
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                1
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_requires_matching_root() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), false);
+        pallet_external_validators_rewards::EraRewardsPots::<Test>::mutate(1, |pot| {
+            if let Some(pot) = pot {
+                pot.root = sp_core::H256::repeat_byte(1);
+            }
+        });
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::RewardsRootMismatch
+        );
+    })
+}
+
+#[test]
+fn force_resend_rewards_message_requires_era_within_history_depth() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), false);
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+
+        assert_noop!(
+            ExternalValidatorsRewards::force_resend_rewards_message(RuntimeOrigin::root(), 1),
+            crate::Error::<Test>::NoClaimableRewards
+        );
+    })
+}
+
+#[test]
+fn delivery_of_rewards_message_is_recorded() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        assert_eq!(
+            pallet_external_validators_rewards::PendingRewardsMessages::<Test>::get(
+                sp_core::H256::zero()
+            ),
+            Some(1)
+        );
+
+        ExternalValidatorsRewards::on_message_delivered(Default::default(), true);
+
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsMessageDelivered { era_index: 1 },
+        ));
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1),
+            Some(crate::types::RewardsDelivery::Delivered)
+        );
+        assert!(
+            !pallet_external_validators_rewards::PendingRewardsMessages::<Test>::contains_key(
+                sp_core::H256::zero()
+            )
+        );
+    })
+}
+
+#[test]
+fn delivery_of_other_messages_is_ignored() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        System::reset_events();
+
+        ExternalValidatorsRewards::on_message_delivered(sp_core::H256::repeat_byte(1), true);
+
+        assert!(System::events().is_empty());
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1),
+            Some(crate::types::RewardsDelivery::Pending(Default::default()))
+        );
+    })
+}
+
+#[test]
+fn pending_delivery_is_pruned_with_its_era() {
+    new_test_ext().execute_with(|| {
+        end_era_sent_to_ethereum();
+        ExternalValidatorsRewards::on_era_start(11, 0, 11);
+
+        assert!(pallet_external_validators_rewards::EraRewardsDelivery::<Test>::get(1).is_none());
+        assert!(
+            !pallet_external_validators_rewards::PendingRewardsMessages::<Test>::contains_key(
+                sp_core::H256::zero()
+            )
+        );
+    })
+}
+
+fn end_era_with_points(era_index: u32, points: &[(u64, u32)]) {
+    run_to_block(1);
+    Mock::mutate(|mock| {
+        mock.active_era = Some(ActiveEraInfo {
+            index: era_index,
+            start: None,
+        })
+    });
+    ExternalValidatorsRewards::reward_by_ids(
+        points
+            .iter()
+            .map(|(validator, points)| (H160::from_low_u64_be(*validator), *points)),
+    );
+    ExternalValidatorsRewards::on_era_end(era_index);
+}
+
+#[test]
+fn rewarded_validators_at_the_bound_are_not_capped() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.max_rewarded_validators = Some(3));
+        end_era_with_points(1, &[(1, 10), (2, 20), (3, 30)]);
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.individual.len(), 3);
+        assert_eq!(era_rewards.total, 60);
+        assert!(
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(2)
+                .individual
+                .is_empty()
+        );
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardedValidatorsCapped { .. })
+        )));
+    })
+}
+
+#[test]
+fn rewarded_validators_above_the_bound_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.max_rewarded_validators = Some(3));
+        end_era_with_points(1, &[(1, 10), (2, 40), (3, 30), (4, 20)]);
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                H160::from_low_u64_be(2),
+                H160::from_low_u64_be(3),
+                H160::from_low_u64_be(4)
+            ]
+        );
+        assert_eq!(era_rewards.total, 90);
+
+        let next_era_rewards =
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(2);
+        assert_eq!(
+            next_era_rewards.individual,
+            BTreeMap::from([(H160::from_low_u64_be(1), 10)])
+        );
+        assert_eq!(next_era_rewards.total, 10);
+
+        // The leaves move with the points.
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(1)
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec![
+                H160::from_low_u64_be(2),
+                H160::from_low_u64_be(3),
+                H160::from_low_u64_be(4)
+            ]
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(2),
+            BTreeMap::from([(
+                H160::from_low_u64_be(1),
+                pending_leaf(1, H160::from_low_u64_be(1), 10)
+            )])
+        );
+
+        System::assert_has_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardedValidatorsCapped {
+                era_index: 1,
+                carried_over_validators: 1,
+                carried_over_points: 10,
+            },
+        ));
+        // The message and the claimable root only cover the rewarded validators.
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardsMessageSent {
+                era_index: 1,
+                total_points: 90,
+                ..
+            })
+        )));
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1).map(|pot| pot.root),
+            ExternalValidatorsRewards::era_rewards_merkle_root(1)
+        );
+    })
+}
+
+#[test]
+fn rewarded_validators_cap_breaks_ties_by_account() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.max_rewarded_validators = Some(2));
+        end_era_with_points(1, &[(3, 50), (2, 50), (1, 50), (4, 60)]);
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual,
+            BTreeMap::from([
+                (H160::from_low_u64_be(1), 50),
+                (H160::from_low_u64_be(4), 60)
+            ])
+        );
+
+        let next_era_rewards =
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(2);
+        assert_eq!(
+            next_era_rewards.individual,
+            BTreeMap::from([
+                (H160::from_low_u64_be(2), 50),
+                (H160::from_low_u64_be(3), 50)
+            ])
+        );
+    })
+}
+
+#[test]
+fn carried_over_points_add_to_the_next_era() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.max_rewarded_validators = Some(1));
+        end_era_with_points(1, &[(1, 10), (2, 20)]);
+        end_era_with_points(2, &[(1, 5), (2, 5)]);
+
+        // Validator 1 carried 10 points over from era 1, for 15 in era 2.
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(2);
+        assert_eq!(
+            era_rewards.individual,
+            BTreeMap::from([(H160::from_low_u64_be(1), 15)])
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(3).individual,
+            BTreeMap::from([(H160::from_low_u64_be(2), 5)])
+        );
+    })
+}
+
+#[test]
+fn set_performance_weights_replaces_configured_weights() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+        });
+
+        assert_ok!(ExternalValidatorsRewards::set_performance_weights(
+            RuntimeOrigin::root(),
+            Percent::from_percent(50),
+            Percent::from_percent(30),
+            Percent::from_percent(20),
+        ));
+        let weights = crate::types::PerformanceWeights {
+            block_authoring: Perbill::from_percent(50),
+            liveness: Perbill::from_percent(30),
+            base: Perbill::from_percent(20),
+        };
+        assert_eq!(
+            pallet_external_validators_rewards::PerformanceWeightsOverride::<Test>::get(),
+            Some(weights)
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::PerformanceWeightsSet { weights },
+        ));
+
+        // Same blocks as the 60/30/10 formula test
+        for _ in 0..4 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+        }
+        for _ in 0..2 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(3));
+        }
+        end_session(1, (1..=4).map(H160::from_low_u64_be).collect(), vec![]);
+
+        // fair_share = 2, max_credited = 3
+        // - Validators 1 and 2: block = 50% × 3 × 320 = 480, other = 50% × 10 × 320 / 4 = 400
+        // - Validator 3: block = 50% × 2 × 320 = 320, other = 400
+        // - Validator 4: offline, other = 20% × 10 × 320 / 4 = 160
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.total, 880 + 880 + 720 + 160);
+    })
+}
+
+#[test]
+fn set_performance_weights_must_sum_to_100() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_performance_weights(
+                RuntimeOrigin::root(),
+                Percent::from_percent(50),
+                Percent::from_percent(30),
+                Percent::from_percent(30),
+            ),
+            crate::Error::<Test>::InvalidPerformanceWeights
+        );
+        assert_noop!(
+            ExternalValidatorsRewards::set_performance_weights(
+                RuntimeOrigin::root(),
+                Percent::from_percent(50),
+                Percent::from_percent(30),
+                Percent::from_percent(10),
+            ),
+            crate::Error::<Test>::InvalidPerformanceWeights
+        );
+    })
+}
+
+#[test]
+fn set_performance_weights_requires_governance_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_performance_weights(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                Percent::from_percent(50),
+                Percent::from_percent(30),
+                Percent::from_percent(20),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_inflation_params_replaces_configured_curve() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        pallet_external_validators_rewards::BlocksProducedInEra::<Test>::insert(1, 150);
+
+        // Configured curve: 150 of 600 blocks = 25% → 20% + 25% × 80% = 40%
+        assert_eq!(
+            ExternalValidatorsRewards::calculate_scaled_inflation(1, 1_000_000),
+            400_000
+        );
+
+        assert_ok!(ExternalValidatorsRewards::set_inflation_params(
+            RuntimeOrigin::root(),
+            300,
+            10,
+            90,
+        ));
+        let params = crate::types::InflationParams {
+            expected_blocks_per_era: 300,
+            min_inflation_percent: 10,
+            max_inflation_percent: 90,
+        };
+        assert_eq!(ExternalValidatorsRewards::inflation_params(), params);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::InflationParamsSet { params },
+        ));
+
+        // 150 of 300 blocks = 50% → 10% + 50% × 80% = 50%
+        assert_eq!(
+            ExternalValidatorsRewards::calculate_scaled_inflation(1, 1_000_000),
+            500_000
+        );
+    })
+}
+
+#[test]
+fn set_inflation_params_rejects_out_of_bounds_values() {
+    new_test_ext().execute_with(|| {
+        for (expected_blocks, min_percent, max_percent) in
+            [(0, 20, 100), (600, 50, 40), (600, 20, 101)]
+        {
+            assert_noop!(
+                ExternalValidatorsRewards::set_inflation_params(
+                    RuntimeOrigin::root(),
+                    expected_blocks,
+                    min_percent,
+                    max_percent,
+                ),
+                crate::Error::<Test>::InvalidInflationParams
+            );
+        }
+    })
+}
+
+#[test]
+fn set_inflation_params_requires_governance_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_inflation_params(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                600,
+                20,
+                100,
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_rewards_split_changes_treasury_share_of_inflation() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+        });
+        let rewards_account = RewardsEthereumSovereignAccount::get();
+        let treasury_account = TreasuryAccount::get();
+        assert_eq!(
+            pallet_external_validators_rewards::RewardsSplit::<Test>::get(),
+            InflationTreasuryProportion::get()
+        );
+
+        assert_ok!(ExternalValidatorsRewards::set_rewards_split(
+            RuntimeOrigin::root(),
+            Perbill::from_percent(35),
+        ));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsSplitSet {
+                treasury_proportion: Perbill::from_percent(35),
+            },
+        ));
+
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        let rewards_before = Balances::free_balance(&rewards_account);
+        let treasury_before = Balances::free_balance(&treasury_account);
+        ExternalValidatorsRewards::on_era_end(1);
+
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            rewards_before + 650_000
+        );
+        assert_eq!(
+            Balances::free_balance(&treasury_account),
+            treasury_before + 350_000
+        );
+    })
+}
+
+#[test]
+fn set_rewards_split_requires_governance_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_rewards_split(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                Perbill::from_percent(35),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_rewards_account_moves_balance_and_redirects_minting() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+        });
+        let old_account = RewardsEthereumSovereignAccount::get();
+        let new_account = H160::from_low_u64_be(0x7e7e);
+        assert_ok!(Balances::mint_into(&old_account, 1_000));
+        let old_balance = Balances::free_balance(&old_account);
+
+        assert_ok!(ExternalValidatorsRewards::set_rewards_account(
+            RuntimeOrigin::root(),
+            new_account,
+        ));
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardsAccountSet {
+                old_account,
+                new_account,
+                moved_amount: old_balance,
+            },
+        ));
+        assert_eq!(Balances::free_balance(&old_account), 0);
+        assert_eq!(Balances::free_balance(&new_account), old_balance);
+        assert_eq!(ExternalValidatorsRewards::rewards_account(), new_account);
+
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        ExternalValidatorsRewards::on_era_end(1);
+
+        assert_eq!(Balances::free_balance(&old_account), 0);
+        assert_eq!(Balances::free_balance(&new_account), old_balance + 800_000);
+    })
+}
+
+#[test]
+fn set_rewards_account_rejects_current_account() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_rewards_account(
+                RuntimeOrigin::root(),
+                RewardsEthereumSovereignAccount::get(),
+            ),
+            crate::Error::<Test>::SameRewardsAccount
+        );
+    })
+}
+
+#[test]
+fn set_rewards_account_requires_governance_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_rewards_account(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                H160::from_low_u64_be(0x7e7e),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn sent_rewards_messages_are_reported_to_observer() {
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.send_message_fails = true;
+        });
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        ExternalValidatorsRewards::on_era_end(1);
+
+        // Messages not accepted by the outbound queue are not reported
+        assert!(Mock::mock().sent_messages.is_empty());
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            });
+            mock.send_message_fails = false;
+        });
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        ExternalValidatorsRewards::reward_by_ids([(H160::from_low_u64_be(1), 10)]);
+        ExternalValidatorsRewards::on_era_end(2);
+
+        let info = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(2)
+            .generate_era_rewards_info(2, 800_000, 0, ExternalValidatorsRewards::operator_address)
+            .unwrap();
+        assert_eq!(
+            Mock::mock().sent_messages,
+            vec![(Some(2), BlakeTwo256::hash_of(&info))]
+        );
+    })
+}
+
+#[test]
+fn rewards_message_carries_the_registered_operator_addresses() {
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let (with_operator, without_operator) =
+            (H160::from_low_u64_be(1), H160::from_low_u64_be(2));
+        let operator = H160::repeat_byte(0x0e);
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.operators = vec![(with_operator, operator)];
+        });
+        assert_ok!(ExternalValidatorsRewards::set_next_era_inflation(
+            RuntimeOrigin::root(),
+            1_000_000
+        ));
+        ExternalValidatorsRewards::reward_by_ids([(with_operator, 10), (without_operator, 20)]);
+        ExternalValidatorsRewards::on_era_end(1);
+
+        let info = crate::types::EraRewardsUtils {
+            era_index: 1,
+            era_start_timestamp: 0,
+            total_points: 30,
+            individual_points: vec![(operator, 10), (without_operator, 20)],
+            inflation_amount: 800_000,
+        };
+        assert_eq!(
+            Mock::mock().sent_messages,
+            vec![(Some(1), BlakeTwo256::hash_of(&info))]
+        );
+    })
+}
+
+#[test]
+fn migration_to_v2_stores_current_treasury_proportion() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    frame_support::parameter_types! {
+        pub const CurrentTreasuryProportion: Perbill = Perbill::from_percent(25);
+    }
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<ExternalValidatorsRewards>();
+
+        crate::migrations::MigrateV1ToV2::<Test, CurrentTreasuryProportion>::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardsSplit::<Test>::get(),
+            Perbill::from_percent(25)
+        );
+        assert_eq!(
+            ExternalValidatorsRewards::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+
+        // Running it again does not overwrite a split set by governance
+        assert_ok!(ExternalValidatorsRewards::set_rewards_split(
+            RuntimeOrigin::root(),
+            Perbill::from_percent(35),
+        ));
+        crate::migrations::MigrateV1ToV2::<Test, CurrentTreasuryProportion>::on_runtime_upgrade();
+        assert_eq!(
+            pallet_external_validators_rewards::RewardsSplit::<Test>::get(),
+            Perbill::from_percent(35)
+        );
+    })
+}
+
+#[test]
+fn try_state_checks_points_and_unsent_queue() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(1), 10),
+            (H160::from_low_u64_be(3), 30),
+        ]);
+        assert!(ExternalValidatorsRewards::unsent_queue_push((1, 0, 100)));
+        assert_ok!(ExternalValidatorsRewards::do_try_state());
+
+        // An entry left behind the tail of the ring buffer
+        pallet_external_validators_rewards::UnsentRewardEra::<Test>::insert(5, (2, 0, 100));
+        assert!(ExternalValidatorsRewards::do_try_state().is_err());
+        pallet_external_validators_rewards::UnsentRewardEra::<Test>::remove(5);
+
+        // Points that do not add up to the era total
+        pallet_external_validators_rewards::RewardPointsForEra::<Test>::mutate(1, |points| {
+            points.total += 1
+        });
+        assert!(ExternalValidatorsRewards::do_try_state().is_err());
+    })
+}
+
+#[test]
+fn migration_to_v3_caches_the_reward_leaves() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<ExternalValidatorsRewards>();
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+        let reward_address = H160::repeat_byte(0xaa);
+        for era_index in [1, 2] {
+            pallet_external_validators_rewards::RewardPointsForEra::<Test>::insert(
+                era_index,
+                crate::EraRewardPoints {
+                    total: 40,
+                    individual: BTreeMap::from([
+                        (H160::from_low_u64_be(1), 10),
+                        (H160::from_low_u64_be(3), 30),
+                    ]),
+                },
+            );
+        }
+        // Era 1 ended after validator 3 set its reward address, era 2 is the active one.
+        pallet_external_validators_rewards::EraRewardAddresses::<Test>::insert(
+            1,
+            H160::from_low_u64_be(3),
+            reward_address,
+        );
+        pallet_external_validators_rewards::RewardDestination::<Test>::insert(
+            H160::from_low_u64_be(3),
+            reward_address,
+        );
+
+        crate::migrations::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            ExternalValidatorsRewards::on_chain_storage_version(),
+            StorageVersion::new(3)
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(1),
+            BTreeMap::from([
+                (
+                    H160::from_low_u64_be(1),
+                    <Keccak256 as sp_runtime::traits::Hash>::hash(
+                        &(H160::from_low_u64_be(1), 10u32).encode()
+                    )
+                ),
+                (
+                    H160::from_low_u64_be(3),
+                    pending_leaf(3, reward_address, 30)
+                ),
+            ])
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(2),
+            BTreeMap::from([
+                (
+                    H160::from_low_u64_be(1),
+                    pending_leaf(1, H160::from_low_u64_be(1), 10)
+                ),
+                (
+                    H160::from_low_u64_be(3),
+                    pending_leaf(3, reward_address, 30)
+                ),
+            ])
+        );
+    })
+}
+
+fn era_end_stage() -> Option<crate::types::EraEndStage<H160>> {
+    pallet_external_validators_rewards::EraEndInProgress::<Test>::get().map(|era_end| era_end.stage)
+}
+
+fn rewards_message_sent(era: u32) -> bool {
+    System::events().iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::ExternalValidatorsRewards(crate::Event::RewardsMessageSent {
+                era_index,
+                ..
+            }) if era_index == era
+        )
+    })
+}
+
+#[test]
+fn era_end_is_processed_over_several_blocks() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.era_end_steps_per_block = Some(1));
+        end_era_with_claimable_rewards();
+
+        // Only the first leaf is generated when the era ends.
+        assert_eq!(
+            era_end_stage(),
+            Some(crate::types::EraEndStage::GenerateLeaves {
+                last: Some(H160::from_low_u64_be(1))
+            })
+        );
+        assert!(
+            pallet_external_validators_rewards::EraRewardAddresses::<Test>::contains_key(
+                1,
+                H160::from_low_u64_be(1)
+            )
+        );
+        assert!(!pallet_external_validators_rewards::EraRewardAddresses::<
+            Test,
+        >::contains_key(1, H160::from_low_u64_be(3)));
+        assert!(pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1).is_none());
+        assert!(!rewards_message_sent(1));
+
+        // Two more leaves, then the root, then the inflation.
+        for _ in 0..4 {
+            ExternalValidatorsRewards::on_initialize(2);
+        }
+        let root = ExternalValidatorsRewards::era_rewards_merkle_root(1);
+        assert_eq!(
+            era_end_stage(),
+            Some(crate::types::EraEndStage::Send {
+                root,
+                rewards_amount: 800_000,
+            })
+        );
+        assert!(!rewards_message_sent(1));
+
+        ExternalValidatorsRewards::on_initialize(3);
+        assert_eq!(era_end_stage(), None);
+        assert!(rewards_message_sent(1));
+        let pot = pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1)
+            .expect("rewards were sent");
+        assert_eq!(Some(pot.root), root);
+        assert_eq!(pot.rewards_amount, 800_000);
+
+        // Nothing left to process.
+        System::reset_events();
+        ExternalValidatorsRewards::on_initialize(4);
+        assert!(System::events().is_empty());
+    })
+}
+
+#[test]
+fn era_end_in_progress_is_completed_when_the_next_era_ends() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.era_end_steps_per_block = Some(1));
+        end_era_with_claimable_rewards();
+        assert!(era_end_stage().is_some());
+
+        end_era_with_points(2, &[(2, 20)]);
+
+        assert!(rewards_message_sent(1));
+        assert!(pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1).is_some());
+        assert_eq!(
+            pallet_external_validators_rewards::EraEndInProgress::<Test>::get()
+                .map(|era_end| (era_end.era_index, era_end.stage)),
+            Some((2, crate::types::EraEndStage::ComputeRoot))
+        );
+        assert!(!rewards_message_sent(2));
+    })
+}
+
+#[test]
+fn reward_address_set_during_era_end_is_in_the_rewards_root() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| mock.era_end_steps_per_block = Some(1));
+        end_era_with_claimable_rewards();
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+
+        // Validator 1 already has its leaf, validator 5 does not.
+        let reward_address = H160::repeat_byte(0xaa);
+        for validator in [1, 5] {
+            assert_ok!(ExternalValidatorsRewards::set_reward_address(
+                RuntimeOrigin::signed(H160::from_low_u64_be(validator)),
+                reward_address,
+            ));
+        }
+        for _ in 0..5 {
+            ExternalValidatorsRewards::on_initialize(2);
+        }
+        assert_eq!(era_end_stage(), None);
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardLeavesForEra::<Test>::get(1),
+            BTreeMap::from([
+                (
+                    H160::from_low_u64_be(1),
+                    pending_leaf(1, H160::from_low_u64_be(1), 10)
+                ),
+                (
+                    H160::from_low_u64_be(3),
+                    pending_leaf(3, H160::from_low_u64_be(3), 30)
+                ),
+                (
+                    H160::from_low_u64_be(5),
+                    pending_leaf(5, reward_address, 50)
+                ),
+            ])
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1).map(|pot| pot.root),
+            ExternalValidatorsRewards::era_rewards_merkle_root(1)
+        );
+    })
+}
+
+#[test]
+fn era_end_in_progress_is_dropped_when_minting_fails() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.era_end_steps_per_block = Some(4);
+            mock.era_inflation = Some(0);
+        });
+        end_era_with_points(1, &[(1, 10), (3, 30), (5, 50)]);
+        assert!(matches!(
+            era_end_stage(),
+            Some(crate::types::EraEndStage::Mint { root: Some(_) })
+        ));
+
+        ExternalValidatorsRewards::on_initialize(2);
+
+        assert_eq!(era_end_stage(), None);
+        assert!(pallet_external_validators_rewards::EraRewardsPots::<Test>::get(1).is_none());
+        assert!(!rewards_message_sent(1));
+    })
+}
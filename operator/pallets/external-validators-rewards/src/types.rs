@@ -15,11 +15,14 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
 use alloc::vec::Vec;
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use snowbridge_outbound_queue_primitives::SendError;
-use sp_core::{H160, H256};
+use sp_core::{ConstU32, H160, H256};
+use sp_runtime::{BoundedVec, FixedU128, Perbill};
 
 /// Data needed for EigenLayer rewards submission via Snowbridge.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Encode)]
 pub struct EraRewardsUtils {
     pub era_index: u32,
     pub era_start_timestamp: u32,
@@ -29,7 +32,7 @@ pub struct EraRewardsUtils {
 }
 
 pub trait SendMessage {
-    type Message;
+    type Message: Encode;
     type Ticket;
 
     fn build(utils: &EraRewardsUtils) -> Option<Self::Message>;
@@ -37,6 +40,177 @@ pub trait SendMessage {
     fn validate(message: Self::Message) -> Result<Self::Ticket, SendError>;
 
     fn deliver(ticket: Self::Ticket) -> Result<H256, SendError>;
+
+    /// Called once the rewards message of an era is sent for the first time.
+    ///
+    /// Not called when the message of the era is resent.
+    fn on_rewards_sent(_utils: &EraRewardsUtils) {}
+}
+
+/// Maximum number of nodes accepted in a rewards claim merkle proof.
+/// A proof of this depth covers trees of up to 2^32 leaves.
+pub const MAX_CLAIM_PROOF_LENGTH: u32 = 32;
+
+/// Proof submitted with `claim_rewards` showing that the caller earned `points` in an era.
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+pub struct RewardsClaimProof {
+    /// Reward points earned by the caller, hashed with its account to rebuild the leaf.
+    pub points: u32,
+    /// Sibling hashes from the leaf up to (excluding) the root.
+    pub proof: BoundedVec<H256, ConstU32<MAX_CLAIM_PROOF_LENGTH>>,
+    /// Position of the leaf in the tree (0-based).
+    pub leaf_index: u64,
+    /// Total number of leaves in the tree.
+    pub number_of_leaves: u64,
+}
+
+/// Where the rewards of an era are paid out. Each era is paid out one way only, the one
+/// configured when it ends.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RewardsPayout {
+    /// Send the rewards message to EigenLayer, which pays out the rewards on Ethereum.
+    #[default]
+    Ethereum,
+    /// Keep the rewards on DataHaven, where validators claim them with `claim_rewards`.
+    Claim,
+}
+
+/// Rewards of an ended era, paid out on Ethereum or claimed on DataHaven.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct EraRewardsPot {
+    /// Merkle root of the era rewards tree.
+    pub root: H256,
+    /// Sum of the reward points of every validator in the era.
+    pub total_points: u128,
+    /// Amount minted to the rewards account for the era, shared among validators.
+    pub rewards_amount: u128,
+    /// Start of the era in seconds, the start of the EigenLayer rewards period.
+    pub era_start_timestamp: u32,
+    /// Where the rewards of the era are paid out.
+    pub payout: RewardsPayout,
+}
+
+/// Delivery of the rewards message of an era sent to Ethereum.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum RewardsDelivery {
+    /// The message with this id was sent, its delivery is not acknowledged yet.
+    Pending(H256),
+    /// A delivery receipt proved that the message was executed on Ethereum.
+    Delivered,
+    /// A delivery receipt reported that the message failed on Ethereum.
+    Failed,
+    /// The message was never sent and the unsent queue was full, so it is not retried.
+    Dropped,
+}
+
+/// Stage reached by the end of an era, processed over the blocks following it.
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum EraEndStage<AccountId> {
+    /// Recording the reward address of the rewarded validators and generating their leaf with
+    /// it, in account order from the one after `last`.
+    GenerateLeaves { last: Option<AccountId> },
+    /// Computing the root of the rewards tree.
+    ComputeRoot,
+    /// Minting the inflation of the era, whose rewards tree has `root`.
+    Mint { root: Option<H256> },
+    /// Sending the rewards message of the era, `rewards_amount` having been minted.
+    Send {
+        root: Option<H256>,
+        rewards_amount: u128,
+    },
+}
+
+/// End of an era still being processed.
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct EraEnd<AccountId> {
+    /// The era that ended.
+    pub era_index: u32,
+    /// Inflation of the era, scaled to its block production or overridden.
+    pub inflation: u128,
+    /// Start of the era in seconds, read when it ended.
+    pub era_start_timestamp: u32,
+    /// Next stage to process.
+    pub stage: EraEndStage<AccountId>,
+}
+
+/// Weights of the session performance formula, set by governance. They sum to 100%.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct PerformanceWeights {
+    /// Weight of the block production score.
+    pub block_authoring: Perbill,
+    /// Weight of the liveness score.
+    pub liveness: Perbill,
+    /// Unconditional part of the points.
+    pub base: Perbill,
+}
+
+/// Performance of a validator over the sessions of an era awarded so far, as accounted by the
+/// session performance points.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ValidatorPerformance {
+    /// Number of sessions the validator was accounted in.
+    pub sessions: u32,
+    /// Number of sessions in which the validator authored at least one block, and was
+    /// therefore considered online.
+    pub sessions_online: u32,
+    /// Blocks authored by the validator.
+    pub blocks_authored: u32,
+    /// Blocks credited to the validator, capped in each session at its fair share plus
+    /// `FairShareCap`.
+    pub credited_blocks: u32,
+    /// Sum of the fair shares of the sessions, the number of blocks expected from the
+    /// validator.
+    pub expected_blocks: u32,
+    /// Average of the weighted scores of the sessions. A validator online that authored its
+    /// fair share scores 1, and up to `1 + block weight × FairShareCap` when over-performing.
+    pub weighted_score: FixedU128,
+    /// Performance points awarded to the validator.
+    pub points: u32,
+}
+
+/// Parameters of the performance-scaled inflation curve, set by governance.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct InflationParams {
+    /// Number of blocks of an era at which the full inflation is minted.
+    pub expected_blocks_per_era: u32,
+    /// Percentage of the inflation minted when no block is produced.
+    pub min_inflation_percent: u32,
+    /// Percentage of the inflation minted when all the expected blocks are produced.
+    pub max_inflation_percent: u32,
 }
 
 /// Result of minting inflation tokens, detailing the split between rewards and treasury.
@@ -70,6 +244,21 @@ impl<AccountId> HandleInflation<AccountId> for () {
     }
 }
 
+/// Notified of the tokens minted as inflation and of the rewards sent to EigenLayer.
+pub trait RewardsLedger {
+    /// Inflation of `era_index` was minted.
+    fn on_inflation_minted(era_index: u32, mint_result: &InflationMintResult);
+
+    /// The rewards message of `era_index`, distributing `rewards_amount`, was sent.
+    fn on_rewards_sent(era_index: u32, rewards_amount: u128);
+}
+
+impl RewardsLedger for () {
+    fn on_inflation_minted(_: u32, _: &InflationMintResult) {}
+
+    fn on_rewards_sent(_: u32, _: u128) {}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub trait BenchmarkHelper {
     fn setup();
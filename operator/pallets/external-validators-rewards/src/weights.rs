@@ -53,12 +53,20 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_external_validators_rewards.
 pub trait WeightInfo {
-	fn on_era_end() -> Weight;
+	fn on_era_end(n: u32, ) -> Weight;
 	fn process_unsent_reward_eras_empty() -> Weight;
 	fn process_unsent_reward_eras_expired() -> Weight;
 	fn process_unsent_reward_eras_success() -> Weight;
 	fn process_unsent_reward_eras_failed() -> Weight;
 	fn retry_unsent_reward_era() -> Weight;
+	fn set_next_era_inflation() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn force_resend_rewards_message() -> Weight;
+	fn set_performance_weights() -> Weight;
+	fn set_inflation_params() -> Weight;
+	fn set_rewards_split() -> Weight;
+	fn set_rewards_account() -> Weight;
+	fn set_reward_address() -> Weight;
 }
 
 /// Weights for pallet_external_validators_rewards using the Substrate node and recommended hardware.
@@ -80,14 +88,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(32845), added: 35320, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xf5207f03cfdce586301014700e2c2593fad157e461d71fd4c1f936839a5f1f3e` (r:0 w:1)
 	/// Proof: UNKNOWN KEY `0xf5207f03cfdce586301014700e2c2593fad157e461d71fd4c1f936839a5f1f3e` (r:0 w:1)
-	fn on_era_end() -> Weight {
+	/// Storage: `ExternalValidatorsRewards::RewardLeavesForEra` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardLeavesForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn on_era_end(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `36522`
-		//  Estimated: `39987`
-		// Minimum execution time: 1_042_933_000 picoseconds.
-		Weight::from_parts(1_136_401_000, 39987)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		//  Measured:  `12522 + n * (76 ±0)`
+		//  Estimated: `15987 + n * (76 ±0)`
+		// Minimum execution time: 109_318_000 picoseconds.
+		Weight::from_parts(108_655_000, 15987)
+			// Standard Error: 1_528
+			.saturating_add(Weight::from_parts(248_917, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 76).saturating_mul(n.into()))
 	}
 
 	fn process_unsent_reward_eras_empty() -> Weight {
@@ -107,7 +121,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Same as on_era_end + queue read/write
 		Weight::from_parts(1_136_401_000, 39987)
 			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 
 	fn process_unsent_reward_eras_failed() -> Weight {
@@ -119,6 +133,114 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Same as success path
 		Self::process_unsent_reward_eras_success()
 	}
+
+	fn force_resend_rewards_message() -> Weight {
+		// Success path plus the reads of the era rewards pot and of its delivery
+		Self::process_unsent_reward_eras_success()
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::NextEraInflationOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::NextEraInflationOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_next_era_inflation() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::PerformanceWeightsOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::PerformanceWeightsOverride` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_performance_weights() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::InflationParamsOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::InflationParamsOverride` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_inflation_params() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::RewardsSplit` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardsSplit` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_rewards_split() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::RewardsAccountOverride` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardsAccountOverride` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn set_rewards_account() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `transfer_all` of the balances pallet, which also moves a whole
+		// balance, plus that of `set_transfer_limits` to put the new account. The proof size adds
+		// up the reads listed above.
+		Weight::from_parts(72_523_000, 5697)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::EraRewardAddresses` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::RewardPointsForEra` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardPointsForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsRewards::RewardLeavesForEra` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardLeavesForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsRewards::RewardDestination` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardDestination` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_reward_address() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// The points and leaves of the active era are measured as in `on_era_end`, 76 bytes per
+		// validator, for the 1_000 of `MaxRewardedValidatorsPerEra`. The execution time adds the
+		// time per validator of `on_era_end` for each of them, an upper bound of decoding and
+		// encoding their points and leaves. Hashing the leaf is included in the base cost.
+		Weight::from_parts(442_284_000, 79059)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::EraRewardsPots` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardsPots` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::ClaimedRewards` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::ClaimedRewards` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::EraRewardAddresses` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::RewardsAccountOverride` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardsAccountOverride` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn claim_rewards() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `transfer_keep_alive` of the balances pallet, which also moves funds
+		// from an account kept alive, plus 20 µs to hash a full depth proof. The proof size adds
+		// up the reads listed above.
+		Weight::from_parts(78_930_000, 13319)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -139,14 +261,20 @@ impl WeightInfo for () {
 	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(32845), added: 35320, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xf5207f03cfdce586301014700e2c2593fad157e461d71fd4c1f936839a5f1f3e` (r:0 w:1)
 	/// Proof: UNKNOWN KEY `0xf5207f03cfdce586301014700e2c2593fad157e461d71fd4c1f936839a5f1f3e` (r:0 w:1)
-	fn on_era_end() -> Weight {
+	/// Storage: `ExternalValidatorsRewards::RewardLeavesForEra` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardLeavesForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn on_era_end(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `36522`
-		//  Estimated: `39987`
-		// Minimum execution time: 1_042_933_000 picoseconds.
-		Weight::from_parts(1_136_401_000, 39987)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		//  Measured:  `12522 + n * (76 ±0)`
+		//  Estimated: `15987 + n * (76 ±0)`
+		// Minimum execution time: 109_318_000 picoseconds.
+		Weight::from_parts(108_655_000, 15987)
+			// Standard Error: 1_528
+			.saturating_add(Weight::from_parts(248_917, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 76).saturating_mul(n.into()))
 	}
 
 	fn process_unsent_reward_eras_empty() -> Weight {
@@ -163,7 +291,7 @@ impl WeightInfo for () {
 	fn process_unsent_reward_eras_success() -> Weight {
 		Weight::from_parts(1_136_401_000, 39987)
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 
 	fn process_unsent_reward_eras_failed() -> Weight {
@@ -173,4 +301,48 @@ impl WeightInfo for () {
 	fn retry_unsent_reward_era() -> Weight {
 		Self::process_unsent_reward_eras_success()
 	}
+
+	fn force_resend_rewards_message() -> Weight {
+		// Success path plus the reads of the era rewards pot and of its delivery
+		Self::process_unsent_reward_eras_success()
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+
+	fn set_next_era_inflation() -> Weight {
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_performance_weights() -> Weight {
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_inflation_params() -> Weight {
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_rewards_split() -> Weight {
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_rewards_account() -> Weight {
+		Weight::from_parts(72_523_000, 5697)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn set_reward_address() -> Weight {
+		Weight::from_parts(442_284_000, 79059)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(78_930_000, 13319)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
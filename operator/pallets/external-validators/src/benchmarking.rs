@@ -26,6 +26,7 @@ use {
     frame_system::{EventRecord, RawOrigin},
     pallet_session::{self as session, SessionManager},
     rand::{RngCore, SeedableRng},
+    sp_core::{ecdsa, Pair, H160},
     sp_runtime::{codec, traits::Convert},
 };
 const SEED: u32 = 0;
@@ -100,6 +101,21 @@ fn invulnerables<
         .collect()
 }
 
+/// Address of the operator signing with the key of `seed`, and its signature over the
+/// registration of `validator`.
+fn operator_signature<T: Config>(
+    validator: &<T as Config>::ValidatorId,
+    seed: u8,
+) -> (H160, [u8; 65]) {
+    let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+    let hash = ExternalValidators::<T>::operator_registration_hash(validator);
+    let mut signature = [0u8; 65];
+    signature.copy_from_slice(pair.sign_prehashed(&hash).as_ref());
+    let operator = ExternalValidators::<T>::recover_operator(validator, &signature)
+        .expect("signature was just made");
+    (operator, signature)
+}
+
 #[allow(clippy::multiple_bound_locations)]
 #[benchmarks(where T: session::Config + pallet_balances::Config)]
 mod benchmarks {
@@ -243,6 +259,52 @@ mod benchmarks {
         Ok(())
     }
 
+    // worst case replaces the operator registered before.
+    #[benchmark]
+    fn register_operator_address() -> Result<(), BenchmarkError> {
+        let (account_id, validator_id) = invulnerables::<T>(1)
+            .pop()
+            .expect("one validator was created");
+        let (previous, _) = operator_signature::<T>(&validator_id, 1);
+        OperatorAddresses::<T>::insert(&validator_id, previous);
+        OperatorValidators::<T>::insert(previous, &validator_id);
+        let (operator, signature) = operator_signature::<T>(&validator_id, 2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(account_id), operator, signature);
+
+        assert_last_event::<T>(
+            Event::OperatorAddressSet {
+                validator: validator_id,
+                operator,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_operator_address() -> Result<(), BenchmarkError> {
+        let (account_id, validator_id) = invulnerables::<T>(1)
+            .pop()
+            .expect("one validator was created");
+        let (operator, _) = operator_signature::<T>(&validator_id, 1);
+        OperatorAddresses::<T>::insert(&validator_id, operator);
+        OperatorValidators::<T>::insert(operator, &validator_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(account_id));
+
+        assert_last_event::<T>(
+            Event::OperatorAddressRemoved {
+                validator: validator_id,
+                operator,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         ExternalValidators,
         crate::mock::new_test_ext(),
@@ -24,6 +24,8 @@
 //!      Are not rewarded.
 //! - ExternalValidators: Validators set using storage proofs from another blockchain. Can be disabled by setting
 //!     `SkipExternalValidators` to true.
+//! - Operator addresses: Addresses of the EigenLayer operators of the validators, registered by each validator
+//!     with a signature of the operator. Used to address the operators in the messages sent to EigenLayer.
 //!
 //! Validators only change once per era. By default the era changes after a fixed number of sessions, but new eras
 //! can be forced or disabled using a root extrinsic.
@@ -44,7 +46,7 @@ use {
     sp_staking::SessionIndex,
     traits::{
         ActiveEraInfo, EraIndex, EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider,
-        OnEraEnd, OnEraStart, ValidatorProvider,
+        OnEraEnd, OnEraStart, OperatorAddressProvider, ValidatorProvider,
     },
 };
 
@@ -98,13 +100,21 @@ pub mod pallet {
             dispatch::DispatchResultWithPostInfo,
             pallet_prelude::*,
             traits::{EnsureOrigin, UnixTime, ValidatorRegistration},
-            BoundedVec, DefaultNoBound,
+            BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+            RuntimeDebugNoBound,
         },
         frame_system::pallet_prelude::*,
         sp_core::H160,
-        sp_runtime::{traits::Convert, SaturatedConversion},
+        sp_io::hashing::keccak_256,
+        sp_runtime::{
+            traits::{Convert, Zero},
+            SaturatedConversion,
+        },
     };
 
+    /// Prefix of the message signed by the EigenLayer operators to be registered.
+    pub const OPERATOR_REGISTRATION_TAG: &[u8] = b"datahaven-operator-registration";
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -117,7 +127,7 @@ pub mod pallet {
         /// Number of eras to keep in history.
         ///
         /// Following information is kept for eras in `[current_era -
-        /// HistoryDepth, current_era]`: `ErasStartSessionIndex`
+        /// HistoryDepth, current_era]`: `ErasStartSessionIndex`, `EraValidatorSetChanges`
         ///
         /// Must be more than the number of eras delayed by session.
         /// I.e. active era must always be in history. I.e. `active_era >
@@ -180,6 +190,41 @@ pub mod pallet {
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    /// Maximum number of validators of an era: all whitelisted and external validators.
+    #[derive(TypeInfo, Encode, Decode, MaxEncodedLen)]
+    #[codec(mel_bound(T: Config))]
+    #[scale_info(skip_type_params(T))]
+    pub struct MaxEraValidators<T: Config>(PhantomData<T>);
+    impl<T: Config> Get<u32> for MaxEraValidators<T> {
+        fn get() -> u32 {
+            T::MaxWhitelistedValidators::get().saturating_add(T::MaxExternalValidators::get())
+        }
+    }
+
+    /// Changes of the validator set at the start of an era, relative to the previous era.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[codec(mel_bound(T: Config))]
+    #[scale_info(skip_type_params(T))]
+    pub struct ValidatorSetChange<T: Config> {
+        /// Validators of the era that were not validating in the previous era.
+        pub added: BoundedVec<T::ValidatorId, MaxEraValidators<T>>,
+        /// Validators of the previous era that are not validating anymore.
+        pub removed: BoundedVec<T::ValidatorId, MaxEraValidators<T>>,
+        /// External index of the validators of the previous era.
+        pub previous_external_index: u64,
+        /// External index of the validators of the era.
+        pub external_index: u64,
+    }
+
     /// Fixed validators set by root/governance. Have priority over the external validators.
     #[pallet::storage]
     pub type WhitelistedValidators<T: Config> =
@@ -197,6 +242,23 @@ pub mod pallet {
     pub type WhitelistedValidatorsActiveEraPending<T: Config> =
         StorageValue<_, BoundedVec<T::ValidatorId, T::MaxWhitelistedValidators>, ValueQuery>;
 
+    /// Validators of the active era, as returned to the session pallet when the era was planned.
+    #[pallet::storage]
+    pub type ValidatorsActiveEra<T: Config> =
+        StorageValue<_, BoundedVec<T::ValidatorId, MaxEraValidators<T>>, ValueQuery>;
+
+    /// Same as `ValidatorsActiveEra` but only exists for a brief period of time when the next era
+    /// has been planned but not enacted yet.
+    #[pallet::storage]
+    pub type ValidatorsActiveEraPending<T: Config> =
+        StorageValue<_, BoundedVec<T::ValidatorId, MaxEraValidators<T>>, OptionQuery>;
+
+    /// Changes of the validator set at the start of each of the last [`Config::HistoryDepth`]
+    /// eras.
+    #[pallet::storage]
+    pub type EraValidatorSetChanges<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, ValidatorSetChange<T>, OptionQuery>;
+
     /// Validators set using storage proofs from another blockchain. Ignored if `SkipExternalValidators` is true.
     #[pallet::storage]
     pub type ExternalValidators<T: Config> =
@@ -239,6 +301,17 @@ pub mod pallet {
     #[pallet::storage]
     pub type CurrentExternalIndex<T> = StorageValue<_, u64, ValueQuery>;
 
+    /// EigenLayer operator address of each validator that registered one.
+    #[pallet::storage]
+    pub type OperatorAddresses<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::ValidatorId, H160, OptionQuery>;
+
+    /// Validator each registered operator address belongs to, so that an operator is only
+    /// registered for one validator.
+    #[pallet::storage]
+    pub type OperatorValidators<T: Config> =
+        StorageMap<_, Blake2_128Concat, H160, T::ValidatorId, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -289,6 +362,14 @@ pub mod pallet {
         WhitelistedValidatorRemoved { account_id: T::AccountId },
         /// A new era has started.
         NewEra { era: EraIndex },
+        /// The validator set changed at the start of an era, relative to the previous era.
+        ValidatorSetChanged {
+            era: EraIndex,
+            added: Vec<T::ValidatorId>,
+            removed: Vec<T::ValidatorId>,
+            previous_external_index: u64,
+            external_index: u64,
+        },
         /// A new force era mode was set.
         ForceEra { mode: Forcing },
         /// External validators were set.
@@ -296,6 +377,16 @@ pub mod pallet {
             validators: Vec<T::ValidatorId>,
             external_index: u64,
         },
+        /// A validator registered the address of its EigenLayer operator.
+        OperatorAddressSet {
+            validator: T::ValidatorId,
+            operator: H160,
+        },
+        /// A validator removed the address of its EigenLayer operator.
+        OperatorAddressRemoved {
+            validator: T::ValidatorId,
+            operator: H160,
+        },
     }
 
     #[pallet::error]
@@ -316,6 +407,12 @@ pub mod pallet {
         TargetEraTooNew,
         /// The target era has already been seen (targetEra <= ExternalIndex). Duplicate or stale.
         DuplicateOrStaleTargetEra,
+        /// The signature was not made by the operator over the registration of the caller.
+        InvalidOperatorSignature,
+        /// The operator address is registered for another validator.
+        OperatorAlreadyRegistered,
+        /// The caller has no operator address registered.
+        NoOperatorAddress,
     }
 
     #[pallet::call]
@@ -419,6 +516,67 @@ pub mod pallet {
 
             Self::set_external_validators_inner(validators, external_index)
         }
+
+        /// Register `operator` as the address of the EigenLayer operator of the caller,
+        /// replacing the one registered before, if any.
+        ///
+        /// `signature` must be the EIP-191 (`personal_sign`) signature of the operator over
+        /// [`Pallet::operator_registration_message`] of the caller. The caller must have
+        /// session keys registered.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::register_operator_address())]
+        pub fn register_operator_address(
+            origin: OriginFor<T>,
+            operator: H160,
+            signature: [u8; 65],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let validator = T::ValidatorIdOf::convert(who)
+                .filter(T::ValidatorRegistration::is_registered)
+                .ok_or(Error::<T>::NoKeysRegistered)?;
+
+            ensure!(
+                Self::recover_operator(&validator, &signature) == Some(operator),
+                Error::<T>::InvalidOperatorSignature
+            );
+            if let Some(registered) = OperatorValidators::<T>::get(operator) {
+                ensure!(
+                    registered == validator,
+                    Error::<T>::OperatorAlreadyRegistered
+                );
+            }
+
+            if let Some(previous) = OperatorAddresses::<T>::insert(&validator, operator) {
+                OperatorValidators::<T>::remove(previous);
+            }
+            OperatorValidators::<T>::insert(operator, &validator);
+
+            Self::deposit_event(Event::OperatorAddressSet {
+                validator,
+                operator,
+            });
+            Ok(())
+        }
+
+        /// Remove the address of the EigenLayer operator of the caller. The messages sent to
+        /// EigenLayer address the caller by its own account again.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::remove_operator_address())]
+        pub fn remove_operator_address(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let validator =
+                T::ValidatorIdOf::convert(who).ok_or(Error::<T>::UnableToDeriveValidatorId)?;
+
+            let operator =
+                OperatorAddresses::<T>::take(&validator).ok_or(Error::<T>::NoOperatorAddress)?;
+            OperatorValidators::<T>::remove(operator);
+
+            Self::deposit_event(Event::OperatorAddressRemoved {
+                validator,
+                operator,
+            });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -462,6 +620,34 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Message the EigenLayer operator of `validator` signs to be registered for it. It is
+        /// tied to the chain by its genesis hash, so registrations cannot be replayed on
+        /// another chain.
+        pub fn operator_registration_message(validator: &T::ValidatorId) -> Vec<u8> {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (OPERATOR_REGISTRATION_TAG, genesis_hash, validator).encode()
+        }
+
+        /// Hash the EigenLayer operator of `validator` signs to be registered for it: the
+        /// EIP-191 (`personal_sign`) hash of [`Pallet::operator_registration_message`].
+        pub fn operator_registration_hash(validator: &T::ValidatorId) -> [u8; 32] {
+            let message = Self::operator_registration_message(validator);
+            let mut eth_message =
+                alloc::format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+            eth_message.extend_from_slice(&message);
+            keccak_256(&eth_message)
+        }
+
+        /// Address of the account that signed the registration of `validator`.
+        pub(crate) fn recover_operator(
+            validator: &T::ValidatorId,
+            signature: &[u8; 65],
+        ) -> Option<H160> {
+            let hash = Self::operator_registration_hash(validator);
+            let public = sp_io::crypto::secp256k1_ecdsa_recover(signature, &hash).ok()?;
+            Some(H160::from_slice(&keccak_256(&public)[12..]))
+        }
+
         /// Helper to set a new `ForceEra` mode.
         pub(crate) fn set_force_era(mode: Forcing) {
             log::info!("Setting force era mode {:?}.", mode);
@@ -579,7 +765,8 @@ pub mod pallet {
         /// Start a new era. It does:
         /// * Increment `active_era.index`,
         /// * reset `active_era.start`,
-        /// * emit `NewEra` event,
+        /// * record the changes of the validator set in `EraValidatorSetChanges`,
+        /// * emit `NewEra` and `ValidatorSetChanged` events,
         /// * call `OnEraStart` hook,
         pub(crate) fn start_era(start_session: SessionIndex) {
             let active_era = ActiveEra::<T>::mutate(|active_era| {
@@ -598,11 +785,57 @@ pub mod pallet {
                 WhitelistedValidatorsActiveEraPending::<T>::take(),
             );
             let external_idx = PendingExternalIndex::<T>::take();
+            let previous_external_idx = CurrentExternalIndex::<T>::get();
             CurrentExternalIndex::<T>::put(external_idx);
+            let change =
+                Self::record_validator_set_change(active_era, previous_external_idx, external_idx);
             Self::deposit_event(Event::NewEra { era: active_era });
+            Self::deposit_event(Event::ValidatorSetChanged {
+                era: active_era,
+                added: change.added.into_inner(),
+                removed: change.removed.into_inner(),
+                previous_external_index: change.previous_external_index,
+                external_index: change.external_index,
+            });
             T::OnEraStart::on_era_start(active_era, start_session, external_idx);
         }
 
+        /// Enact the validators planned for `era` and store how they differ from the validators
+        /// of the previous era.
+        pub(crate) fn record_validator_set_change(
+            era: EraIndex,
+            previous_external_index: u64,
+            external_index: u64,
+        ) -> ValidatorSetChange<T> {
+            // The era may have been planned before the pending validators were tracked.
+            let validators = ValidatorsActiveEraPending::<T>::take()
+                .unwrap_or_else(|| BoundedVec::truncate_from(Self::validators()));
+            let previous_validators = ValidatorsActiveEra::<T>::get();
+
+            let current: BTreeSet<_> = validators.iter().collect();
+            let previous: BTreeSet<_> = previous_validators.iter().collect();
+            let added = validators
+                .iter()
+                .filter(|v| !previous.contains(v))
+                .cloned()
+                .collect::<Vec<_>>();
+            let removed = previous_validators
+                .iter()
+                .filter(|v| !current.contains(v))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let change = ValidatorSetChange {
+                added: BoundedVec::truncate_from(added),
+                removed: BoundedVec::truncate_from(removed),
+                previous_external_index,
+                external_index,
+            };
+            ValidatorsActiveEra::<T>::put(validators);
+            EraValidatorSetChanges::<T>::insert(era, &change);
+            change
+        }
+
         /// End era. It does:
         /// * call `OnEraEnd` hook,
         pub(crate) fn end_era(active_era: ActiveEraInfo, _session_index: SessionIndex) {
@@ -637,8 +870,10 @@ pub mod pallet {
             // Save the external index for when the era truly changes (start_era)
             PendingExternalIndex::<T>::put(ExternalIndex::<T>::get());
 
-            // Returns new validators
-            Self::validators()
+            // Returns new validators, saving them for when the era truly changes (start_era)
+            let validators = Self::validators();
+            ValidatorsActiveEraPending::<T>::put(BoundedVec::truncate_from(validators.clone()));
+            validators
         }
 
         /// Potentially plan a new era.
@@ -653,6 +888,46 @@ pub mod pallet {
         /// Clear all era information for given era.
         pub(crate) fn clear_era_information(era_index: EraIndex) {
             ErasStartSessionIndex::<T>::remove(era_index);
+            EraValidatorSetChanges::<T>::remove(era_index);
+        }
+
+        /// Checks the era invariants:
+        /// * the planned era is the active one or the next one,
+        /// * `ErasStartSessionIndex` holds the start session of the eras in
+        ///   `[current_era - HistoryDepth, current_era]` only,
+        /// * the whitelisted validators are unique.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            use frame_support::ensure;
+
+            if let Some(active_era) = ActiveEra::<T>::get() {
+                let current_era =
+                    CurrentEra::<T>::get().ok_or("An era is active but none planned")?;
+                ensure!(
+                    current_era == active_era.index
+                        || current_era == active_era.index.saturating_add(1),
+                    "The planned era is neither the active era nor the next one"
+                );
+            }
+            if let Some(current_era) = CurrentEra::<T>::get() {
+                ensure!(
+                    ErasStartSessionIndex::<T>::contains_key(current_era),
+                    "The start session of the planned era is missing"
+                );
+                let oldest_era = current_era.saturating_sub(T::HistoryDepth::get());
+                ensure!(
+                    ErasStartSessionIndex::<T>::iter_keys()
+                        .all(|era| (oldest_era..=current_era).contains(&era)),
+                    "A start session is kept outside of the history depth"
+                );
+            }
+
+            let whitelisted = WhitelistedValidators::<T>::get();
+            ensure!(
+                remove_duplicates(whitelisted.to_vec()).len() == whitelisted.len(),
+                "A whitelisted validator is duplicated"
+            );
+            Ok(())
         }
     }
 
@@ -676,6 +951,11 @@ pub mod pallet {
             }
             // `on_finalize` weight is tracked in `on_initialize`
         }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     impl<T: Config> ExternalIndexProvider for Pallet<T> {
@@ -762,6 +1042,12 @@ impl<T: Config> InvulnerablesProvider<T::ValidatorId> for Pallet<T> {
     }
 }
 
+impl<T: Config> OperatorAddressProvider<T::ValidatorId> for Pallet<T> {
+    fn operator_address(validator: &T::ValidatorId) -> Option<sp_core::H160> {
+        OperatorAddresses::<T>::get(validator)
+    }
+}
+
 /// Mode of era-forcing.
 #[derive(
     Copy,
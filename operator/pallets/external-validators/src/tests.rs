@@ -20,10 +20,11 @@ use {
             last_event, new_test_ext, run_to_block, run_to_session, ExternalValidators, HookCall,
             Mock, RootAccount, RuntimeEvent, RuntimeOrigin, Session, System, Test,
         },
-        traits::{ExternalIndexProvider, ValidatorProvider},
-        Error,
+        traits::{ExternalIndexProvider, OperatorAddressProvider, ValidatorProvider},
+        EraValidatorSetChanges, Error, OperatorAddresses, OperatorValidators, ValidatorsActiveEra,
     },
     frame_support::{assert_noop, assert_ok},
+    sp_core::{ecdsa, Pair, H160},
     sp_runtime::traits::BadOrigin,
 };
 
@@ -345,6 +346,74 @@ fn era_hooks() {
     });
 }
 
+#[test]
+fn era_start_records_validator_set_changes() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        // All validators of the genesis era are new
+        let change = EraValidatorSetChanges::<Test>::get(0).unwrap();
+        assert_eq!(change.added.to_vec(), vec![1, 2]);
+        assert!(change.removed.is_empty());
+
+        assert_ok!(ExternalValidators::set_external_validators_inner(
+            vec![50, 51],
+            1
+        ));
+        // Session 6 starts era 1
+        run_to_block(30);
+
+        let change = EraValidatorSetChanges::<Test>::get(1).unwrap();
+        assert_eq!(change.added.to_vec(), vec![50, 51]);
+        assert!(change.removed.is_empty());
+        assert_eq!(change.previous_external_index, 0);
+        assert_eq!(change.external_index, 1);
+        System::assert_has_event(RuntimeEvent::ExternalValidators(
+            crate::Event::ValidatorSetChanged {
+                era: 1,
+                added: vec![50, 51],
+                removed: vec![],
+                previous_external_index: 0,
+                external_index: 1,
+            },
+        ));
+
+        assert_ok!(ExternalValidators::remove_whitelisted(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1
+        ));
+        assert_ok!(ExternalValidators::set_external_validators_inner(
+            vec![51],
+            2
+        ));
+        // Session 12 starts era 2
+        run_to_block(60);
+
+        System::assert_has_event(RuntimeEvent::ExternalValidators(
+            crate::Event::ValidatorSetChanged {
+                era: 2,
+                added: vec![],
+                removed: vec![1, 50],
+                previous_external_index: 1,
+                external_index: 2,
+            },
+        ));
+        assert_eq!(ValidatorsActiveEra::<Test>::get().to_vec(), vec![2, 51]);
+        assert_eq!(Session::validators(), vec![2, 51]);
+    });
+}
+
+#[test]
+fn unchanged_validator_set_is_recorded_empty() {
+    new_test_ext().execute_with(|| {
+        run_to_session(6);
+
+        let change = EraValidatorSetChanges::<Test>::get(1).unwrap();
+        assert!(change.added.is_empty());
+        assert!(change.removed.is_empty());
+        assert_eq!(change.previous_external_index, change.external_index);
+    });
+}
+
 #[test]
 fn target_era_validation_accepts_next_era() {
     new_test_ext().execute_with(|| {
@@ -553,3 +622,192 @@ fn era_boundary_race_resubmit_without_advance() {
         );
     });
 }
+
+#[test]
+fn try_state_holds_across_eras() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ExternalValidators::set_external_validators_inner(
+            vec![50, 51],
+            1
+        ));
+        for session in 1..=20 {
+            run_to_session(session);
+            assert_ok!(ExternalValidators::do_try_state());
+        }
+
+        // A start session for an era that is not planned yet
+        let current_era = ExternalValidators::current_era().unwrap();
+        crate::ErasStartSessionIndex::<Test>::insert(current_era + 1, 100);
+        assert!(ExternalValidators::do_try_state().is_err());
+    });
+}
+
+/// Address of the operator with the key of `seed`, and its signature over the registration of
+/// `validator`.
+fn operator_signature(validator: u64, seed: u8) -> (H160, [u8; 65]) {
+    let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+    let hash = ExternalValidators::operator_registration_hash(&validator);
+    let mut signature = [0u8; 65];
+    signature.copy_from_slice(pair.sign_prehashed(&hash).as_ref());
+    let operator = H160::from(pair.public().to_eth_address().expect("valid public key"));
+    (operator, signature)
+}
+
+#[test]
+fn validator_can_register_its_operator_address() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        let (operator, signature) = operator_signature(3, 1);
+
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(3),
+            operator,
+            signature,
+        ));
+
+        assert_eq!(OperatorAddresses::<Test>::get(3), Some(operator));
+        assert_eq!(OperatorValidators::<Test>::get(operator), Some(3));
+        assert_eq!(
+            <ExternalValidators as OperatorAddressProvider<u64>>::operator_address(&3),
+            Some(operator)
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidators(
+            crate::Event::OperatorAddressSet {
+                validator: 3,
+                operator,
+            },
+        ));
+    });
+}
+
+#[test]
+fn registering_another_operator_address_replaces_the_previous_one() {
+    new_test_ext().execute_with(|| {
+        let (previous, signature) = operator_signature(3, 1);
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(3),
+            previous,
+            signature,
+        ));
+        let (operator, signature) = operator_signature(3, 2);
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(3),
+            operator,
+            signature,
+        ));
+
+        assert_eq!(OperatorAddresses::<Test>::get(3), Some(operator));
+        assert_eq!(OperatorValidators::<Test>::get(previous), None);
+
+        // The previous operator can be registered for another validator.
+        let (_, signature) = operator_signature(4, 1);
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(4),
+            previous,
+            signature,
+        ));
+    });
+}
+
+#[test]
+fn operator_address_requires_the_signature_of_the_operator() {
+    new_test_ext().execute_with(|| {
+        let (operator, signature) = operator_signature(3, 1);
+        let (other_operator, _) = operator_signature(3, 2);
+
+        // Signed by another key
+        assert_noop!(
+            ExternalValidators::register_operator_address(
+                RuntimeOrigin::signed(3),
+                other_operator,
+                signature,
+            ),
+            Error::<Test>::InvalidOperatorSignature
+        );
+        // Signed for another validator
+        assert_noop!(
+            ExternalValidators::register_operator_address(
+                RuntimeOrigin::signed(4),
+                operator,
+                signature,
+            ),
+            Error::<Test>::InvalidOperatorSignature
+        );
+        assert_noop!(
+            ExternalValidators::register_operator_address(
+                RuntimeOrigin::signed(3),
+                operator,
+                [0u8; 65],
+            ),
+            Error::<Test>::InvalidOperatorSignature
+        );
+    });
+}
+
+#[test]
+fn operator_address_is_registered_for_one_validator() {
+    new_test_ext().execute_with(|| {
+        let (operator, signature) = operator_signature(3, 1);
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(3),
+            operator,
+            signature,
+        ));
+
+        let (_, signature) = operator_signature(4, 1);
+        assert_noop!(
+            ExternalValidators::register_operator_address(
+                RuntimeOrigin::signed(4),
+                operator,
+                signature,
+            ),
+            Error::<Test>::OperatorAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn operator_address_requires_session_keys() {
+    new_test_ext().execute_with(|| {
+        // 42 is not registered in the mock
+        let (operator, signature) = operator_signature(42, 1);
+        assert_noop!(
+            ExternalValidators::register_operator_address(
+                RuntimeOrigin::signed(42),
+                operator,
+                signature,
+            ),
+            Error::<Test>::NoKeysRegistered
+        );
+    });
+}
+
+#[test]
+fn validator_can_remove_its_operator_address() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_noop!(
+            ExternalValidators::remove_operator_address(RuntimeOrigin::signed(3)),
+            Error::<Test>::NoOperatorAddress
+        );
+
+        let (operator, signature) = operator_signature(3, 1);
+        assert_ok!(ExternalValidators::register_operator_address(
+            RuntimeOrigin::signed(3),
+            operator,
+            signature,
+        ));
+        assert_ok!(ExternalValidators::remove_operator_address(
+            RuntimeOrigin::signed(3)
+        ));
+
+        assert_eq!(OperatorAddresses::<Test>::get(3), None);
+        assert_eq!(OperatorValidators::<Test>::get(operator), None);
+        System::assert_last_event(RuntimeEvent::ExternalValidators(
+            crate::Event::OperatorAddressRemoved {
+                validator: 3,
+                operator,
+            },
+        ));
+    });
+}
@@ -18,7 +18,7 @@ use alloc::vec::Vec;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use snowbridge_outbound_queue_primitives::SendError;
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::RuntimeDebug;
 
 /// Information regarding the active era (era in used in session).
@@ -52,6 +52,18 @@ pub trait InvulnerablesProvider<ValidatorId> {
     fn invulnerables() -> Vec<ValidatorId>;
 }
 
+/// Address of the EigenLayer operator registered for a validator, if any. Validators without
+/// one are addressed by their own account.
+pub trait OperatorAddressProvider<ValidatorId> {
+    fn operator_address(validator: &ValidatorId) -> Option<H160>;
+}
+
+impl<ValidatorId> OperatorAddressProvider<ValidatorId> for () {
+    fn operator_address(_validator: &ValidatorId) -> Option<H160> {
+        None
+    }
+}
+
 pub trait OnEraStart {
     fn on_era_start(_era_index: EraIndex, _session_start: u32, _external_idx: u64) {}
 }
@@ -81,6 +93,43 @@ pub trait ExternalIndexProvider {
     fn get_external_index() -> u64;
 }
 
+/// Kind of message sent to Ethereum through the outbound queue.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum OutboundMessageKind {
+    /// Slashes of an era.
+    Slashes,
+    /// Rewards of an era.
+    Rewards,
+    /// Native tokens transferred to Ethereum.
+    NativeTransfer,
+}
+
+/// Notified of every message accepted by the outbound queue, so that pallets can react to
+/// the messages sent to Ethereum without changes to the sending pallets.
+pub trait OnOutboundMessageSent {
+    /// `era_index` is the era the message is about, if any, and `payload_hash` the
+    /// `BlakeTwo256` hash of the SCALE encoded message handed to the outbound queue.
+    fn on_outbound_message_sent(
+        _kind: OutboundMessageKind,
+        _era_index: Option<EraIndex>,
+        _message_id: H256,
+        _payload_hash: H256,
+    ) {
+    }
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl OnOutboundMessageSent for Tuple {
+    fn on_outbound_message_sent(
+        kind: OutboundMessageKind,
+        era_index: Option<EraIndex>,
+        message_id: H256,
+        payload_hash: H256,
+    ) {
+        for_tuples!( #( Tuple::on_outbound_message_sent(kind, era_index, message_id, payload_hash); )* );
+    }
+}
+
 pub trait DeliverMessage {
     type Ticket;
 
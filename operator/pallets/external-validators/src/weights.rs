@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn force_era() -> Weight;
 	fn set_external_validators() -> Weight;
 	fn new_session(r: u32, ) -> Weight;
+	fn register_operator_address() -> Weight;
+	fn remove_operator_address() -> Weight;
 }
 
 /// Weights for pallet_external_validators using the Substrate node and recommended hardware.
@@ -149,6 +151,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+
+	fn register_operator_address() -> Weight {
+		// Reads Session::NextKeys, OperatorAddresses and OperatorValidators, writes OperatorAddresses and
+		// OperatorValidators of both operators. Recovering the signer is included in the base cost.
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	fn remove_operator_address() -> Weight {
+		// Reads and writes OperatorAddresses, writes OperatorValidators.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -238,4 +255,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+
+	fn register_operator_address() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn remove_operator_address() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
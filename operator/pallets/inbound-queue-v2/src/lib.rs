@@ -28,6 +28,7 @@ extern crate alloc;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod message_processors;
+pub mod migration;
 pub mod weights;
 
 #[cfg(test)]
@@ -38,11 +39,10 @@ mod test;
 
 pub use crate::weights::WeightInfo;
 use alloc::boxed::Box;
+use core::marker::PhantomData;
+use frame_support::traits::Contains;
 use frame_system::ensure_signed;
-use snowbridge_core::{
-    sparse_bitmap::{SparseBitmap, SparseBitmapImpl},
-    BasicOperatingMode,
-};
+use snowbridge_core::BasicOperatingMode;
 use snowbridge_inbound_queue_primitives::{
     v2::{ConvertMessage, ConvertMessageError, Message, MessageProcessor},
     EventProof, RewardLedger, VerificationError, Verifier,
@@ -60,7 +60,29 @@ pub const LOG_TARGET: &str = "snowbridge-pallet-inbound-queue-v2";
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
-pub type Nonce<T> = SparseBitmapImpl<crate::NonceBitmap<T>>;
+/// Processed nonces of each gateway, as a sparse bitmap of 128 nonces per bucket.
+///
+/// A rotated gateway numbers its messages from scratch, so nonces are tracked per gateway.
+pub struct Nonce<T>(PhantomData<T>);
+
+impl<T: Config> Nonce<T> {
+    /// Computes the bucket index and the bit mask of a nonce.
+    fn bucket_and_mask(nonce: u64) -> (u128, u128) {
+        ((nonce >> 7).into(), 1u128 << (nonce & 127))
+    }
+
+    /// Whether `nonce` was processed for `gateway`.
+    pub fn get(gateway: H160, nonce: u64) -> bool {
+        let (bucket, mask) = Self::bucket_and_mask(nonce);
+        NonceBitmap::<T>::get(gateway, bucket) & mask != 0
+    }
+
+    /// Mark `nonce` as processed for `gateway`.
+    pub fn set(gateway: H160, nonce: u64) {
+        let (bucket, mask) = Self::bucket_and_mask(nonce);
+        NonceBitmap::<T>::mutate(gateway, bucket, |value| *value |= mask);
+    }
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -70,6 +92,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
 
     #[pallet::pallet]
+    #[pallet::storage_version(migration::STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -85,6 +108,8 @@ pub mod pallet {
         /// Address of the Gateway contract.
         #[pallet::constant]
         type GatewayAddress: Get<H160>;
+        /// Gateway contracts still accepted besides `GatewayAddress`, while migrating to a new one.
+        type PreviousGateways: Contains<H160>;
         /// Process the message that was submitted
         type MessageProcessor: MessageProcessor<Self::AccountId>;
         #[cfg(feature = "runtime-benchmarks")]
@@ -173,10 +198,11 @@ pub mod pallet {
         }
     }
 
-    /// StorageMap used for encoding a SparseBitmapImpl that tracks whether a specific nonce has
-    /// been processed or not. Message nonces are unique and never repeated.
+    /// Buckets of the [`Nonce`] bitmap of each gateway. The nonces of a gateway are unique and
+    /// never repeated.
     #[pallet::storage]
-    pub type NonceBitmap<T: Config> = StorageMap<_, Twox64Concat, u128, u128, ValueQuery>;
+    pub type NonceBitmap<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, H160, Twox64Concat, u128, u128, ValueQuery>;
 
     /// The current operating mode of the pallet.
     #[pallet::storage]
@@ -220,14 +246,16 @@ pub mod pallet {
         pub fn process_message(relayer: T::AccountId, message: Message) -> DispatchResult {
             // Verify that the message was submitted from the known Gateway contract
             ensure!(
-                T::GatewayAddress::get() == message.gateway,
+                T::GatewayAddress::get() == message.gateway
+                    || T::PreviousGateways::contains(&message.gateway),
                 Error::<T>::InvalidGateway
             );
 
-            let (nonce, relayer_fee) = (message.nonce, message.relayer_fee);
+            let (gateway, nonce, relayer_fee) =
+                (message.gateway, message.nonce, message.relayer_fee);
 
             // Verify the message has not been processed
-            ensure!(!Nonce::<T>::get(nonce.into()), Error::<T>::InvalidNonce);
+            ensure!(!Nonce::<T>::get(gateway, nonce), Error::<T>::InvalidNonce);
 
             // Process message
             let message_id = T::MessageProcessor::process_message(relayer.clone(), message)?;
@@ -242,7 +270,7 @@ pub mod pallet {
             }
 
             // Mark message as received
-            Nonce::<T>::set(nonce.into());
+            Nonce::<T>::set(gateway, nonce);
 
             // Emit event with the message_id
             Self::deposit_event(Event::MessageReceived { nonce, message_id });
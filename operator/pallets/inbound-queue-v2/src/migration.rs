@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Storage migrations of the inbound queue pallet.
+
+use super::*;
+use alloc::vec::Vec;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::{StorageVersion, Twox64Concat, ValueQuery},
+    storage_alias,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+    use super::*;
+
+    /// Nonce bitmap shared by all the gateways.
+    #[storage_alias]
+    pub type NonceBitmap<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u128, u128, ValueQuery>;
+
+    /// Moves the nonce bitmap under the active gateway, `Config::GatewayAddress`.
+    ///
+    /// Nonces processed for a previous gateway still accepted during a rotation grace period
+    /// are moved as well, so the upgrade should be applied outside of one.
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let gateway = T::GatewayAddress::get();
+            // One bucket per 128 messages, they are moved in a single block.
+            let buckets: Vec<(u128, u128)> = NonceBitmap::<T>::drain().collect();
+            for (bucket, value) in &buckets {
+                crate::NonceBitmap::<T>::insert(gateway, bucket, value);
+            }
+
+            log::info!(
+                target: LOG_TARGET,
+                "Moved {} nonce buckets under gateway {gateway:?}",
+                buckets.len()
+            );
+            let count = buckets.len() as u64;
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_mul(2))
+        }
+    }
+}
+
+/// Migrates the pallet to [`STORAGE_VERSION`] 1, once.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
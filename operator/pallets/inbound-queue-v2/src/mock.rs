@@ -5,7 +5,10 @@ use super::*;
 use crate::{self as inbound_queue_v2, message_processors::XcmMessageProcessor};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{convert::From, default::Default, marker::PhantomData};
-use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, Equals},
+};
 use hex_literal::hex;
 use scale_info::TypeInfo;
 use snowbridge_beacon_primitives::{
@@ -97,6 +100,7 @@ impl<'a, AccountId: Clone + Clone> TryConvert<&'a AccountId, Location>
 parameter_types! {
     pub const EthereumNetwork: xcm::v5::NetworkId = xcm::v5::NetworkId::Ethereum { chain_id: 11155111 };
     pub const GatewayAddress: H160 = H160(GATEWAY_ADDRESS);
+    pub const PreviousGatewayAddress: H160 = H160::repeat_byte(0x77);
     pub InboundQueueLocation: InteriorLocation = [PalletInstance(84)].into();
     pub UniversalLocation: InteriorLocation =
         [GlobalConsensus(ByGenesis(WESTEND_GENESIS_HASH)), Parachain(1002)].into();
@@ -167,6 +171,7 @@ impl inbound_queue_v2::Config for Test {
     type Verifier = MockVerifier;
     type RewardPayment = ();
     type GatewayAddress = GatewayAddress;
+    type PreviousGateways = Equals<PreviousGatewayAddress>;
     type MessageProcessor = (
         DummyPrefix,
         XcmMessageProcessor<
@@ -361,3 +361,66 @@ fn zero_reward_does_not_register_reward() {
         );
     });
 }
+
+fn message_from(gateway: H160, nonce: u64) -> Message {
+    Message {
+        nonce,
+        assets: vec![],
+        xcm: Payload::Raw(vec![]),
+        claimer: None,
+        execution_fee: 1_000_000_000,
+        relayer_fee: 0,
+        gateway,
+        origin: H160::random(),
+        value: 3_000_000_000,
+    }
+}
+
+#[test]
+fn nonces_are_tracked_per_gateway() {
+    new_tester().execute_with(|| {
+        let relayer: AccountId = Keyring::Bob.into();
+
+        // A rotated gateway numbers its messages from scratch
+        assert_ok!(InboundQueue::process_message(
+            relayer.clone(),
+            message_from(PreviousGatewayAddress::get(), 1)
+        ));
+        assert_ok!(InboundQueue::process_message(
+            relayer.clone(),
+            message_from(GatewayAddress::get(), 1)
+        ));
+
+        assert!(Nonce::<Test>::get(GatewayAddress::get(), 1));
+        assert!(Nonce::<Test>::get(PreviousGatewayAddress::get(), 1));
+        assert_noop!(
+            InboundQueue::process_message(relayer.clone(), message_from(GatewayAddress::get(), 1)),
+            Error::<Test>::InvalidNonce
+        );
+        assert_noop!(
+            InboundQueue::process_message(relayer, message_from(PreviousGatewayAddress::get(), 1)),
+            Error::<Test>::InvalidNonce
+        );
+    });
+}
+
+#[test]
+fn migration_moves_the_nonces_under_the_active_gateway() {
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    new_tester().execute_with(|| {
+        migration::v1::NonceBitmap::<Test>::insert(0, 0b10);
+        migration::v1::NonceBitmap::<Test>::insert(2, 0b1);
+
+        migration::v1::UncheckedMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert!(Nonce::<Test>::get(GatewayAddress::get(), 1));
+        assert!(Nonce::<Test>::get(GatewayAddress::get(), 256));
+        assert!(!Nonce::<Test>::get(GatewayAddress::get(), 2));
+        assert!(!Nonce::<Test>::get(PreviousGatewayAddress::get(), 1));
+        assert_eq!(
+            crate::NonceBitmap::<Test>::iter_prefix(GatewayAddress::get()).count(),
+            2
+        );
+    });
+}
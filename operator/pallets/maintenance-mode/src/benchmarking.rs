@@ -0,0 +1,53 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-maintenance-mode
+
+use super::*;
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn enter_maintenance_mode() -> Result<(), BenchmarkError> {
+        let origin = T::MaintenanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(Pallet::<T>::is_in_maintenance_mode());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn resume_normal_operation() -> Result<(), BenchmarkError> {
+        let origin = T::MaintenanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        MaintenanceMode::<T>::put(true);
+        ConsecutiveDeliveryFailures::<T>::put(1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(!Pallet::<T>::is_in_maintenance_mode());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,201 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Maintenance Mode Pallet
+//!
+//! Suspends the calls that cross the bridge to Ethereum or run the EVM, while blocks are still
+//! produced and governance keeps working.
+//!
+//! ## Overview
+//!
+//! - The pallet is the call filter of the runtime: outside maintenance calls go through
+//!   `NormalCallFilter`, during maintenance through `MaintenanceCallFilter`.
+//! - `MaintenanceOrigin` enters maintenance with `enter_maintenance_mode` and leaves it with
+//!   `resume_normal_operation`.
+//! - The runtime reports the delivery receipts of its outbound messages with
+//!   `note_message_delivery`. After `MaxConsecutiveDeliveryFailures` failed deliveries in a row
+//!   the pallet enters maintenance by itself, and only `MaintenanceOrigin` can resume.
+//! - [`InMaintenance`] lets the pallets sending messages to Ethereum hold them back until
+//!   normal operation resumes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Filter of the calls outside maintenance
+        type NormalCallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Filter of the calls during maintenance
+        type MaintenanceCallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Origin that can enter maintenance and resume normal operation
+        type MaintenanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of failed deliveries in a row after which maintenance is entered, zero never
+        /// enters it
+        #[pallet::constant]
+        type MaxConsecutiveDeliveryFailures: Get<u32>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Whether the chain is in maintenance.
+    #[pallet::storage]
+    pub type MaintenanceMode<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Number of outbound messages whose delivery failed since the last successful one.
+    #[pallet::storage]
+    pub type ConsecutiveDeliveryFailures<T> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The chain entered maintenance on demand of `MaintenanceOrigin`
+        EnteredMaintenanceMode,
+
+        /// The chain entered maintenance after `failures` failed deliveries in a row
+        EnteredMaintenanceModeOnDeliveryFailures { failures: u32 },
+
+        /// The chain left maintenance
+        NormalOperationResumed,
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The chain is already in maintenance
+        AlreadyInMaintenanceMode,
+        /// The chain is not in maintenance
+        NotInMaintenanceMode,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Enter maintenance, filtering the calls with `MaintenanceCallFilter`
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::enter_maintenance_mode())]
+        pub fn enter_maintenance_mode(origin: OriginFor<T>) -> DispatchResult {
+            T::MaintenanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !MaintenanceMode::<T>::get(),
+                Error::<T>::AlreadyInMaintenanceMode
+            );
+            MaintenanceMode::<T>::put(true);
+
+            Self::deposit_event(Event::EnteredMaintenanceMode);
+
+            Ok(())
+        }
+
+        /// Leave maintenance, filtering the calls with `NormalCallFilter` again
+        ///
+        /// The count of failed deliveries starts over.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::resume_normal_operation())]
+        pub fn resume_normal_operation(origin: OriginFor<T>) -> DispatchResult {
+            T::MaintenanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                MaintenanceMode::<T>::get(),
+                Error::<T>::NotInMaintenanceMode
+            );
+            MaintenanceMode::<T>::put(false);
+            ConsecutiveDeliveryFailures::<T>::kill();
+
+            Self::deposit_event(Event::NormalOperationResumed);
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether the chain is in maintenance.
+    pub fn is_in_maintenance_mode() -> bool {
+        MaintenanceMode::<T>::get()
+    }
+
+    /// Record the delivery receipt of an outbound message, entering maintenance once
+    /// `MaxConsecutiveDeliveryFailures` deliveries failed in a row.
+    pub fn note_message_delivery(success: bool) {
+        if success {
+            ConsecutiveDeliveryFailures::<T>::kill();
+            return;
+        }
+
+        let failures = ConsecutiveDeliveryFailures::<T>::mutate(|failures| {
+            *failures = failures.saturating_add(1);
+            *failures
+        });
+        let max_failures = T::MaxConsecutiveDeliveryFailures::get();
+        if max_failures == 0 || failures < max_failures || Self::is_in_maintenance_mode() {
+            return;
+        }
+
+        log::warn!(
+            target: "maintenance_mode",
+            "Entering maintenance after {failures} failed deliveries in a row",
+        );
+        MaintenanceMode::<T>::put(true);
+        Self::deposit_event(Event::EnteredMaintenanceModeOnDeliveryFailures { failures });
+    }
+}
+
+impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for Pallet<T> {
+    fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+        if Self::is_in_maintenance_mode() {
+            T::MaintenanceCallFilter::contains(call)
+        } else {
+            T::NormalCallFilter::contains(call)
+        }
+    }
+}
+
+/// Whether the chain is in maintenance, for the pallets holding their outbound messages back
+/// meanwhile.
+pub struct InMaintenance<T>(PhantomData<T>);
+
+impl<T: Config> Get<bool> for InMaintenance<T> {
+    fn get() -> bool {
+        Pallet::<T>::is_in_maintenance_mode()
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate as pallet_maintenance_mode,
+    frame_support::{
+        derive_impl, parameter_types,
+        traits::{Contains, Everything},
+    },
+    frame_system::EnsureRoot,
+    sp_runtime::BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        MaintenanceMode: pallet_maintenance_mode,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = MaintenanceMode;
+    type Block = Block;
+}
+
+/// Only the calls of the maintenance mode pallet are allowed during maintenance.
+pub struct MaintenanceCallFilter;
+impl Contains<RuntimeCall> for MaintenanceCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::MaintenanceMode(_))
+    }
+}
+
+parameter_types! {
+    pub const MaxConsecutiveDeliveryFailures: u32 = 3;
+}
+
+impl pallet_maintenance_mode::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type NormalCallFilter = Everything;
+    type MaintenanceCallFilter = MaintenanceCallFilter;
+    type MaintenanceOrigin = EnsureRoot<u64>;
+    type MaxConsecutiveDeliveryFailures = MaxConsecutiveDeliveryFailures;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, ConsecutiveDeliveryFailures, Error, Event, InMaintenance},
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{Contains, Get},
+    },
+    sp_runtime::{traits::Dispatchable, DispatchError},
+};
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+#[test]
+fn enter_maintenance_mode_works() {
+    new_test_ext().execute_with(|| {
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(
+            RuntimeOrigin::root()
+        ));
+
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+        assert!(InMaintenance::<Test>::get());
+        System::assert_last_event(Event::EnteredMaintenanceMode.into());
+    });
+}
+
+#[test]
+fn enter_maintenance_mode_checks_origin_and_state() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(
+            RuntimeOrigin::root()
+        ));
+        assert_noop!(
+            MaintenanceMode::enter_maintenance_mode(RuntimeOrigin::root()),
+            Error::<Test>::AlreadyInMaintenanceMode
+        );
+    });
+}
+
+#[test]
+fn resume_normal_operation_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MaintenanceMode::resume_normal_operation(RuntimeOrigin::root()),
+            Error::<Test>::NotInMaintenanceMode
+        );
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(
+            RuntimeOrigin::root()
+        ));
+        assert_noop!(
+            MaintenanceMode::resume_normal_operation(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(MaintenanceMode::resume_normal_operation(
+            RuntimeOrigin::root()
+        ));
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+        System::assert_last_event(Event::NormalOperationResumed.into());
+    });
+}
+
+#[test]
+fn maintenance_call_filter_applies_during_maintenance() {
+    new_test_ext().execute_with(|| {
+        let resume = RuntimeCall::MaintenanceMode(crate::Call::resume_normal_operation {});
+        assert!(MaintenanceMode::contains(&remark()));
+        assert!(MaintenanceMode::contains(&resume));
+
+        assert_ok!(MaintenanceMode::enter_maintenance_mode(
+            RuntimeOrigin::root()
+        ));
+
+        assert!(!MaintenanceMode::contains(&remark()));
+        assert!(MaintenanceMode::contains(&resume));
+        assert_noop!(
+            remark().dispatch(RuntimeOrigin::signed(1)),
+            frame_system::Error::<Test>::CallFiltered
+        );
+    });
+}
+
+#[test]
+fn consecutive_delivery_failures_enter_maintenance() {
+    new_test_ext().execute_with(|| {
+        MaintenanceMode::note_message_delivery(false);
+        MaintenanceMode::note_message_delivery(false);
+        assert_eq!(ConsecutiveDeliveryFailures::<Test>::get(), 2);
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+
+        MaintenanceMode::note_message_delivery(false);
+
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+        System::assert_last_event(
+            Event::EnteredMaintenanceModeOnDeliveryFailures { failures: 3 }.into(),
+        );
+    });
+}
+
+#[test]
+fn successful_delivery_resets_the_failures() {
+    new_test_ext().execute_with(|| {
+        MaintenanceMode::note_message_delivery(false);
+        MaintenanceMode::note_message_delivery(false);
+        MaintenanceMode::note_message_delivery(true);
+        assert_eq!(ConsecutiveDeliveryFailures::<Test>::get(), 0);
+
+        MaintenanceMode::note_message_delivery(false);
+        MaintenanceMode::note_message_delivery(false);
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+    });
+}
+
+#[test]
+fn resuming_after_delivery_failures_needs_the_origin() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..MaxConsecutiveDeliveryFailures::get() {
+            MaintenanceMode::note_message_delivery(false);
+        }
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+
+        // Failures and successes while in maintenance do not resume normal operation
+        System::reset_events();
+        MaintenanceMode::note_message_delivery(false);
+        MaintenanceMode::note_message_delivery(true);
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+        assert!(System::events().is_empty());
+
+        assert_ok!(MaintenanceMode::resume_normal_operation(
+            RuntimeOrigin::root()
+        ));
+        assert_eq!(ConsecutiveDeliveryFailures::<Test>::get(), 0);
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+    });
+}
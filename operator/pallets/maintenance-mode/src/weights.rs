@@ -0,0 +1,89 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Weights for `pallet_maintenance_mode`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_maintenance_mode`.
+pub trait WeightInfo {
+    fn enter_maintenance_mode() -> Weight;
+    fn resume_normal_operation() -> Weight;
+}
+
+/// Weights for `pallet_maintenance_mode` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `MaintenanceMode::MaintenanceMode` (r:1 w:1)
+    /// Proof: `MaintenanceMode::MaintenanceMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn enter_maintenance_mode() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `1486`
+        // Minimum execution time: 7_102_000 picoseconds.
+        Weight::from_parts(7_391_000, 1486)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `MaintenanceMode::MaintenanceMode` (r:1 w:1)
+    /// Proof: `MaintenanceMode::MaintenanceMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `MaintenanceMode::ConsecutiveDeliveryFailures` (r:0 w:1)
+    /// Proof: `MaintenanceMode::ConsecutiveDeliveryFailures` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn resume_normal_operation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `22`
+        //  Estimated: `1486`
+        // Minimum execution time: 8_245_000 picoseconds.
+        Weight::from_parts(8_560_000, 1486)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `MaintenanceMode::MaintenanceMode` (r:1 w:1)
+    /// Proof: `MaintenanceMode::MaintenanceMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn enter_maintenance_mode() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `1486`
+        // Minimum execution time: 7_102_000 picoseconds.
+        Weight::from_parts(7_391_000, 1486)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `MaintenanceMode::MaintenanceMode` (r:1 w:1)
+    /// Proof: `MaintenanceMode::MaintenanceMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `MaintenanceMode::ConsecutiveDeliveryFailures` (r:0 w:1)
+    /// Proof: `MaintenanceMode::ConsecutiveDeliveryFailures` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn resume_normal_operation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `22`
+        //  Estimated: `1486`
+        // Minimum execution time: 8_245_000 picoseconds.
+        Weight::from_parts(8_560_000, 1486)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the Outbound Commitment Store pallet
+//!
+//! * `commitment_history`: Read the last commitments stored, so a relayer that fell behind can
+//!   recover the ones it missed
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pallet_outbound_commitment_store::CommitmentRecord;
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    pub trait OutboundCommitmentStoreApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Last `HistorySize` commitments stored, oldest first, with the block they were
+        /// stored in and where they come from.
+        fn commitment_history() -> Vec<CommitmentRecord<BlockNumber>>;
+    }
+}
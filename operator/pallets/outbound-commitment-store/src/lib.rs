@@ -15,19 +15,46 @@
 // along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
 
+use alloc::vec::Vec;
 use frame_support::{pallet_prelude::*, traits::StorageVersion};
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::H256;
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 /// Current storage version.
 const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+/// Where a stored commitment comes from.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentSource {
+    /// Merkle root of the messages committed by the Snowbridge outbound queue V2.
+    OutboundQueueV2,
+}
+
+/// A commitment kept in the history, with the block it was stored in.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub struct CommitmentRecord<BlockNumber> {
+    pub block_number: BlockNumber,
+    pub source: CommitmentSource,
+    pub hash: H256,
+}
+
 /// A pallet for storing the latest commitment hash from the outbound queue.
 ///
 /// This pallet provides a simple way to track the most recent commitment hash,
 /// which can be included in BEEFY MMR leaves for cross-chain verification.
+///
+/// The last `HistorySize` commitments are also kept, so a relayer that fell behind can
+/// still recover the commitments it missed.
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -39,27 +66,76 @@ pub mod pallet {
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Number of commitments kept in `CommitmentHistory`.
+        #[pallet::constant]
+        type HistorySize: Get<u32>;
     }
 
     #[pallet::storage]
     #[pallet::getter(fn latest_commitment)]
     pub type LatestCommitment<T> = StorageValue<_, H256, OptionQuery>;
 
+    /// Ring buffer of the last `HistorySize` commitments, the n-th commitment stored being
+    /// kept in slot `n % HistorySize`.
+    #[pallet::storage]
+    pub type CommitmentHistory<T: Config> =
+        StorageMap<_, Twox64Concat, u32, CommitmentRecord<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Number of commitments stored so far.
+    #[pallet::storage]
+    pub type CommitmentCount<T> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         CommitmentStored { hash: H256 },
     }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                T::HistorySize::get() > 0,
+                "HistorySize must keep at least the latest commitment"
+            );
+        }
+    }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn store_commitment(commitment: H256) {
+    pub fn store_commitment(source: CommitmentSource, commitment: H256) {
         LatestCommitment::<T>::put(commitment);
 
+        let count = CommitmentCount::<T>::get();
+        CommitmentHistory::<T>::insert(
+            Self::history_slot(count),
+            CommitmentRecord {
+                block_number: frame_system::Pallet::<T>::block_number(),
+                source,
+                hash: commitment,
+            },
+        );
+        CommitmentCount::<T>::put(count.saturating_add(1));
+
         Self::deposit_event(Event::CommitmentStored { hash: commitment });
     }
 
     pub fn get_latest_commitment() -> Option<H256> {
         LatestCommitment::<T>::get()
     }
+
+    /// Commitments kept in the history, oldest first.
+    pub fn commitment_history() -> Vec<CommitmentRecord<BlockNumberFor<T>>> {
+        let count = CommitmentCount::<T>::get();
+        let first = count.saturating_sub(T::HistorySize::get().into());
+        (first..count)
+            .filter_map(|index| CommitmentHistory::<T>::get(Self::history_slot(index)))
+            .collect()
+    }
+
+    fn history_slot(index: u64) -> u32 {
+        // The slot is lower than `HistorySize`, so it fits in a `u32`.
+        (index % u64::from(T::HistorySize::get().max(1))) as u32
+    }
 }
@@ -0,0 +1,85 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, CommitmentCount, CommitmentRecord, CommitmentSource, Event},
+    sp_core::H256,
+};
+
+/// Store a commitment in each of the blocks `from..=to`, block `n` committing to `n`.
+fn commit_blocks(from: u64, to: u64) {
+    for block_number in from..=to {
+        System::set_block_number(block_number);
+        OutboundCommitmentStore::store_commitment(
+            CommitmentSource::OutboundQueueV2,
+            H256::from_low_u64_be(block_number),
+        );
+    }
+}
+
+fn record(block_number: u64) -> CommitmentRecord<u64> {
+    CommitmentRecord {
+        block_number,
+        source: CommitmentSource::OutboundQueueV2,
+        hash: H256::from_low_u64_be(block_number),
+    }
+}
+
+#[test]
+fn history_is_empty_before_the_first_commitment() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(OutboundCommitmentStore::get_latest_commitment(), None);
+        assert!(OutboundCommitmentStore::commitment_history().is_empty());
+    });
+}
+
+#[test]
+fn stored_commitment_is_the_latest_and_in_the_history() {
+    new_test_ext().execute_with(|| {
+        commit_blocks(1, 2);
+
+        assert_eq!(
+            OutboundCommitmentStore::get_latest_commitment(),
+            Some(H256::from_low_u64_be(2))
+        );
+        assert_eq!(
+            OutboundCommitmentStore::commitment_history(),
+            vec![record(1), record(2)]
+        );
+        System::assert_last_event(RuntimeEvent::OutboundCommitmentStore(
+            Event::CommitmentStored {
+                hash: H256::from_low_u64_be(2),
+            },
+        ));
+    });
+}
+
+#[test]
+fn history_keeps_the_last_history_size_commitments() {
+    new_test_ext().execute_with(|| {
+        commit_blocks(1, 7);
+
+        assert_eq!(
+            OutboundCommitmentStore::commitment_history(),
+            vec![record(5), record(6), record(7)]
+        );
+        assert_eq!(CommitmentCount::<Test>::get(), 7);
+        assert_eq!(
+            crate::CommitmentHistory::<Test>::iter().count(),
+            HistorySize::get() as usize
+        );
+    });
+}
@@ -77,7 +77,7 @@ use bridge_hub_common::{AggregateMessageOrigin, CustomDigestItem};
 use codec::Decode;
 use frame_support::{
     storage::StorageStreamIter,
-    traits::{tokens::Balance, EnqueueMessage, Get, ProcessMessageError},
+    traits::{tokens::Balance, Contains, EnqueueMessage, Get, ProcessMessageError},
     weights::{Weight, WeightToFee},
 };
 use snowbridge_core::{BasicOperatingMode, TokenId};
@@ -95,7 +95,7 @@ use sp_runtime::{
     traits::{BlockNumberProvider, Hash, MaybeEquivalence},
     DigestItem,
 };
-pub use types::{OnNewCommitment, PendingOrder, ProcessMessageOriginOf};
+pub use types::{OnMessageDelivered, OnNewCommitment, PendingOrder, ProcessMessageOriginOf};
 pub use weights::WeightInfo;
 use xcm::latest::{Location, NetworkId};
 type DeliveryReceiptOf<T> = DeliveryReceipt<<T as frame_system::Config>::AccountId>;
@@ -140,6 +140,9 @@ pub mod pallet {
         /// Hook that is called whenever there is a new commitment.
         type OnNewCommitment: OnNewCommitment;
 
+        /// Hook that is called whenever the delivery of a message is proven.
+        type OnMessageDelivered: OnMessageDelivered;
+
         /// Convert a weight value into a deductible fee based.
         type WeightToFee: WeightToFee<Balance = Self::Balance>;
 
@@ -152,6 +155,8 @@ pub mod pallet {
         /// Address of the Gateway contract
         #[pallet::constant]
         type GatewayAddress: Get<H160>;
+        /// Gateway contracts still accepted besides `GatewayAddress`, while migrating to a new one.
+        type PreviousGateways: Contains<H160>;
         /// Reward discriminator type.
         type RewardKind: Parameter + MaxEncodedLen + Send + Sync + Copy + Clone;
         /// The default RewardKind discriminator for rewards allocated to relayers from this pallet.
@@ -400,7 +405,8 @@ pub mod pallet {
         {
             // Verify that the message was submitted from the known Gateway contract
             ensure!(
-                T::GatewayAddress::get() == receipt.gateway,
+                T::GatewayAddress::get() == receipt.gateway
+                    || T::PreviousGateways::contains(&receipt.gateway),
                 Error::<T>::InvalidGateway
             );
 
@@ -414,6 +420,7 @@ pub mod pallet {
             }
 
             <PendingOrders<T>>::remove(nonce);
+            T::OnMessageDelivered::on_message_delivered(receipt.topic, receipt.success);
 
             Self::deposit_event(Event::MessageDeliveryProofReceived { nonce });
 
@@ -4,7 +4,7 @@ use super::*;
 
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{Everything, Hooks},
+    traits::{Everything, Hooks, Nothing},
     weights::IdentityFee,
     BoundedVec,
 };
@@ -132,6 +132,7 @@ impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Verifier = MockVerifier;
     type GatewayAddress = GatewayAddress;
+    type PreviousGateways = Nothing;
     type Hashing = Keccak256;
     type MessageQueue = MessageQueue;
     type MaxMessagePayloadSize = ConstU32<1024>;
@@ -146,6 +147,7 @@ impl crate::Config for Test {
     type RewardKind = BridgeReward;
     type DefaultRewardKind = DefaultMyRewardKind;
     type OnNewCommitment = ();
+    type OnMessageDelivered = ();
 }
 
 fn setup() {
@@ -30,3 +30,13 @@ pub trait OnNewCommitment {
 impl OnNewCommitment for () {
     fn on_new_commitment(_commitment: H256) {}
 }
+
+/// Hook called when a delivery receipt proves that a message was executed on Ethereum.
+pub trait OnMessageDelivered {
+    /// `id` is the topic of the message, `success` whether its commands succeeded.
+    fn on_message_delivered(id: H256, success: bool);
+}
+
+impl OnMessageDelivered for () {
+    fn on_message_delivered(_id: H256, _success: bool) {}
+}
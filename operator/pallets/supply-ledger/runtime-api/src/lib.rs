@@ -0,0 +1,31 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the Supply Ledger pallet
+//!
+//! * `supply_totals`: Read the HAVE minted, sent as rewards, locked and returned since genesis
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_supply_ledger::SupplyTotals;
+
+sp_api::decl_runtime_apis! {
+    pub trait SupplyLedgerApi {
+        /// Cumulative HAVE minted as inflation, sent as rewards, locked for transfers to
+        /// Ethereum and returned from it.
+        fn supply_totals() -> SupplyTotals;
+    }
+}
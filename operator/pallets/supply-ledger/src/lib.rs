@@ -0,0 +1,250 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Supply ledger pallet.
+//!
+//! Keeps cumulative counters of the HAVE minted as inflation, the rewards sent to EigenLayer,
+//! the tokens locked for and returned from Ethereum, and the tokens minted and burned by the
+//! bridge, so the circulating supply on each side of the bridge can be audited on-chain. The
+//! counters are fed by the `RewardsLedger` and `NativeTransferLedger` hooks of the rewards
+//! and native transfer pallets, and by the burns routed through the pallet as an
+//! `OnUnbalanced` handler, each burn depositing a `Burned` event.
+//!
+//! The total issuance is attested at genesis, and must always equal that allocation plus the
+//! inflation and bridge mints minus the burns and bridge burns. The invariant is checked by
+//! `try_state`, so a minting or burning path that bypasses the ledger is caught by the
+//! try-runtime checks of an upgrade.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Balanced, Credit, Inspect},
+        Imbalance, OnUnbalanced,
+    },
+};
+use pallet_datahaven_native_transfer::NativeTransferLedger;
+use pallet_external_validators_rewards::types::{InflationMintResult, RewardsLedger};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::UniqueSaturatedInto, TryRuntimeError};
+
+pub use pallet::*;
+
+/// Cumulative amounts of HAVE, since genesis.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+)]
+pub struct SupplyTotals {
+    /// Minted as inflation, to the rewards account and to the treasury.
+    pub inflation_minted: u128,
+    /// Distributed to validators through the rewards messages sent to EigenLayer.
+    pub rewards_sent: u128,
+    /// Locked in the Ethereum sovereign account by native transfers to Ethereum.
+    pub locked: u128,
+    /// Unlocked from the Ethereum sovereign account by transfers back to DataHaven.
+    pub returned: u128,
+    /// Burned, from the fees not sent to the treasury and the dust of reaped accounts.
+    pub burned: u128,
+    /// Minted by the bridge, for deposits escrowed on Ethereum.
+    pub bridge_minted: u128,
+    /// Burned by the bridge, for transfers to Ethereum that do not lock the tokens.
+    pub bridge_burned: u128,
+}
+
+impl SupplyTotals {
+    /// Tokens currently held on the Ethereum side of the bridge.
+    pub fn bridged_out(&self) -> u128 {
+        self.locked.saturating_sub(self.returned)
+    }
+
+    /// Total issuance expected from `baseline`, the issuance the counters started from.
+    pub fn expected_issuance(&self, baseline: u128) -> u128 {
+        baseline
+            .saturating_add(self.inflation_minted)
+            .saturating_add(self.bridge_minted)
+            .saturating_sub(self.burned)
+            .saturating_sub(self.bridge_burned)
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    #[pallet::storage_version(migrations::STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The native currency, whose total issuance is checked against the counters.
+        type Currency: Balanced<Self::AccountId>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Tokens routed through the ledger were burned, such as the share of the fees not
+        /// sent to the treasury.
+        Burned { amount: u128 },
+    }
+
+    /// Cumulative supply counters.
+    #[pallet::storage]
+    pub type Totals<T> = StorageValue<_, SupplyTotals, ValueQuery>;
+
+    /// Total issuance the counters started from: the genesis allocation, or the issuance
+    /// attested by [`migrations::MigrateV0ToV1`] on chains started before burns were counted.
+    #[pallet::storage]
+    pub type IssuanceBaseline<T> = StorageValue<_, u128, OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        #[serde(skip)]
+        pub _config: PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        /// Attests the genesis allocation. The pallet must come after the balances pallet in
+        /// the runtime, for the balances to be built first.
+        fn build(&self) {
+            IssuanceBaseline::<T>::put(Pallet::<T>::total_issuance());
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+            Self::check_issuance()
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn supply_totals() -> SupplyTotals {
+        Totals::<T>::get()
+    }
+
+    fn total_issuance() -> u128 {
+        T::Currency::total_issuance().unique_saturated_into()
+    }
+
+    /// Checks that the total issuance equals the attested baseline plus the inflation and
+    /// bridge mints minus the burns and bridge burns. Passes while no baseline is attested.
+    pub fn check_issuance() -> Result<(), TryRuntimeError> {
+        let Some(baseline) = IssuanceBaseline::<T>::get() else {
+            return Ok(());
+        };
+        let expected = Totals::<T>::get().expected_issuance(baseline);
+        let actual = Self::total_issuance();
+        if actual != expected {
+            log::error!(
+                target: "supply-ledger",
+                "Total issuance is {actual}, the ledger expects {expected}",
+            );
+            return Err("The total issuance does not match the supply ledger".into());
+        }
+        Ok(())
+    }
+}
+
+/// Burns the credit and counts it in [`SupplyTotals::burned`].
+impl<T: Config> OnUnbalanced<Credit<T::AccountId, T::Currency>> for Pallet<T> {
+    fn on_nonzero_unbalanced(amount: Credit<T::AccountId, T::Currency>) {
+        let burned: u128 = amount.peek().unique_saturated_into();
+        Totals::<T>::mutate(|totals| {
+            totals.burned = totals.burned.saturating_add(burned);
+        });
+        // Dropping the credit reduces the total issuance.
+        drop(amount);
+        Self::deposit_event(Event::Burned { amount: burned });
+    }
+}
+
+impl<T: Config> RewardsLedger for Pallet<T> {
+    fn on_inflation_minted(_era_index: u32, mint_result: &InflationMintResult) {
+        Totals::<T>::mutate(|totals| {
+            totals.inflation_minted = totals
+                .inflation_minted
+                .saturating_add(mint_result.rewards_amount)
+                .saturating_add(mint_result.treasury_amount);
+        });
+    }
+
+    fn on_rewards_sent(_era_index: u32, rewards_amount: u128) {
+        Totals::<T>::mutate(|totals| {
+            totals.rewards_sent = totals.rewards_sent.saturating_add(rewards_amount);
+        });
+    }
+}
+
+impl<T: Config, Balance: UniqueSaturatedInto<u128>> NativeTransferLedger<Balance> for Pallet<T> {
+    fn on_tokens_locked(amount: Balance) {
+        Totals::<T>::mutate(|totals| {
+            totals.locked = totals.locked.saturating_add(amount.unique_saturated_into());
+        });
+    }
+
+    fn on_tokens_unlocked(amount: Balance) {
+        Totals::<T>::mutate(|totals| {
+            totals.returned = totals
+                .returned
+                .saturating_add(amount.unique_saturated_into());
+        });
+    }
+
+    fn on_tokens_minted(amount: Balance) {
+        Totals::<T>::mutate(|totals| {
+            totals.bridge_minted = totals
+                .bridge_minted
+                .saturating_add(amount.unique_saturated_into());
+        });
+    }
+
+    fn on_tokens_burned(amount: Balance) {
+        Totals::<T>::mutate(|totals| {
+            totals.bridge_burned = totals
+                .bridge_burned
+                .saturating_add(amount.unique_saturated_into());
+        });
+    }
+}
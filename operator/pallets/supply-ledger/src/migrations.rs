@@ -0,0 +1,162 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the supply ledger pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::StorageVersion,
+    traits::UncheckedOnRuntimeUpgrade, weights::Weight,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+
+const LOG_TARGET: &str = "supply-ledger::migration";
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+pub mod v1 {
+    use super::*;
+
+    /// Supply counters before burns were counted.
+    #[derive(Encode, Decode)]
+    pub struct OldSupplyTotals {
+        pub inflation_minted: u128,
+        pub rewards_sent: u128,
+        pub locked: u128,
+        pub returned: u128,
+    }
+
+    /// Adds the burn counter to `Totals` and attests the current issuance.
+    ///
+    /// The genesis allocation is not known on a running chain, so the baseline is derived
+    /// from the current total issuance minus the inflation minted so far. The burns made
+    /// before the upgrade are folded into that baseline.
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let totals = Totals::<T>::translate::<OldSupplyTotals, _>(|old| {
+                old.map(|old| SupplyTotals {
+                    inflation_minted: old.inflation_minted,
+                    rewards_sent: old.rewards_sent,
+                    locked: old.locked,
+                    returned: old.returned,
+                    burned: 0,
+                    bridge_minted: 0,
+                    bridge_burned: 0,
+                })
+            })
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+            let baseline = Pallet::<T>::total_issuance().saturating_sub(totals.inflation_minted);
+            IssuanceBaseline::<T>::put(baseline);
+
+            log::info!(
+                target: LOG_TARGET,
+                "Attested an issuance baseline of {baseline}",
+            );
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                IssuanceBaseline::<T>::exists(),
+                "The issuance baseline was not attested"
+            );
+            Pallet::<T>::check_issuance()
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Supply counters before the bridge mints and burns were counted.
+    #[derive(Encode, Decode)]
+    pub struct OldSupplyTotals {
+        pub inflation_minted: u128,
+        pub rewards_sent: u128,
+        pub locked: u128,
+        pub returned: u128,
+        pub burned: u128,
+    }
+
+    /// Adds the bridge mint and burn counters to `Totals`.
+    ///
+    /// The deposits minted before the upgrade were not counted, so they are folded into the
+    /// issuance baseline, as the burns were by [`super::v1::UncheckedMigrateV0ToV1`].
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let totals = Totals::<T>::translate::<OldSupplyTotals, _>(|old| {
+                old.map(|old| SupplyTotals {
+                    inflation_minted: old.inflation_minted,
+                    rewards_sent: old.rewards_sent,
+                    locked: old.locked,
+                    returned: old.returned,
+                    burned: old.burned,
+                    bridge_minted: 0,
+                    bridge_burned: 0,
+                })
+            })
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+            if IssuanceBaseline::<T>::exists() {
+                let baseline = Pallet::<T>::total_issuance()
+                    .saturating_add(totals.burned)
+                    .saturating_sub(totals.inflation_minted);
+                IssuanceBaseline::<T>::put(baseline);
+                log::info!(
+                    target: LOG_TARGET,
+                    "Attested an issuance baseline of {baseline}",
+                );
+            }
+            T::DbWeight::get().reads_writes(3, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            Pallet::<T>::check_issuance()
+        }
+    }
+}
+
+/// Migrates the pallet to storage version 1, once.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Migrates the pallet to [`STORAGE_VERSION`] 2, once.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    v2::UncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
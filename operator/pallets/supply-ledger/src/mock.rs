@@ -0,0 +1,76 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate as pallet_supply_ledger,
+    frame_support::{
+        derive_impl,
+        traits::{ConstU128, Everything},
+    },
+    sp_runtime::BuildStorage,
+};
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+
+/// Balances endowed at genesis.
+pub const GENESIS_ISSUANCE: u128 = 1_500;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        SupplyLedger: pallet_supply_ledger,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type DustRemoval = SupplyLedger;
+    type ExistentialDeposit = ConstU128<10>;
+    type AccountStore = System;
+}
+
+impl pallet_supply_ledger::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = RuntimeGenesisConfig {
+        balances: pallet_balances::GenesisConfig {
+            balances: vec![(ALICE, 1_000), (BOB, 500)],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
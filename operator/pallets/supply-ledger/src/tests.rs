@@ -0,0 +1,272 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{migrations, mock::*, IssuanceBaseline, SupplyTotals, Totals},
+    frame_support::{
+        assert_ok,
+        traits::{
+            fungible::{Balanced, Inspect, Mutate},
+            tokens::{Fortitude, Precision, Preservation},
+            OnUnbalanced, UncheckedOnRuntimeUpgrade,
+        },
+    },
+    pallet_datahaven_native_transfer::NativeTransferLedger,
+    pallet_external_validators_rewards::types::{InflationMintResult, RewardsLedger},
+    parity_scale_codec::Encode,
+};
+
+fn burn_from_alice(amount: u128) {
+    let credit = <Balances as Balanced<u64>>::withdraw(
+        &ALICE,
+        amount,
+        Precision::Exact,
+        Preservation::Preserve,
+        Fortitude::Polite,
+    )
+    .unwrap();
+    SupplyLedger::on_unbalanced(credit);
+}
+
+#[test]
+fn totals_start_at_zero() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SupplyLedger::supply_totals(), SupplyTotals::default());
+    });
+}
+
+#[test]
+fn inflation_minted_counts_rewards_and_treasury() {
+    new_test_ext().execute_with(|| {
+        let mint_result = InflationMintResult {
+            rewards_amount: 800,
+            treasury_amount: 200,
+        };
+        SupplyLedger::on_inflation_minted(1, &mint_result);
+        SupplyLedger::on_inflation_minted(2, &mint_result);
+        SupplyLedger::on_rewards_sent(1, 800);
+
+        let totals = SupplyLedger::supply_totals();
+        assert_eq!(totals.inflation_minted, 2_000);
+        assert_eq!(totals.rewards_sent, 800);
+    });
+}
+
+#[test]
+fn locked_and_returned_tokens_are_tracked() {
+    new_test_ext().execute_with(|| {
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_locked(1_000);
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_locked(500);
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_unlocked(300);
+
+        let totals = SupplyLedger::supply_totals();
+        assert_eq!(totals.locked, 1_500);
+        assert_eq!(totals.returned, 300);
+        assert_eq!(totals.bridged_out(), 1_200);
+    });
+}
+
+#[test]
+fn counters_saturate() {
+    new_test_ext().execute_with(|| {
+        SupplyLedger::on_rewards_sent(1, u128::MAX);
+        SupplyLedger::on_rewards_sent(2, 1);
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_unlocked(10);
+
+        let totals = SupplyLedger::supply_totals();
+        assert_eq!(totals.rewards_sent, u128::MAX);
+        assert_eq!(totals.bridged_out(), 0);
+    });
+}
+
+#[test]
+fn genesis_allocation_is_attested() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(IssuanceBaseline::<Test>::get(), Some(GENESIS_ISSUANCE));
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn burns_are_counted() {
+    new_test_ext().execute_with(|| {
+        burn_from_alice(100);
+
+        assert_eq!(SupplyLedger::supply_totals().burned, 100);
+        assert_eq!(Balances::total_issuance(), GENESIS_ISSUANCE - 100);
+        assert_ok!(SupplyLedger::check_issuance());
+        System::assert_last_event(RuntimeEvent::SupplyLedger(crate::Event::Burned {
+            amount: 100,
+        }));
+    });
+}
+
+#[test]
+fn dust_of_reaped_accounts_is_counted_as_burned() {
+    new_test_ext().execute_with(|| {
+        // Leaves 5 to Bob, below the existential deposit of 10.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            495
+        ));
+
+        assert_eq!(SupplyLedger::supply_totals().burned, 5);
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn unrecorded_mint_breaks_the_invariant() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::mint_into(&ALICE, 1_000));
+        assert!(SupplyLedger::check_issuance().is_err());
+
+        SupplyLedger::on_inflation_minted(
+            1,
+            &InflationMintResult {
+                rewards_amount: 800,
+                treasury_amount: 200,
+            },
+        );
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn bridge_mints_and_burns_are_counted() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::mint_into(&BOB, 500));
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_minted(500);
+        assert_ok!(SupplyLedger::check_issuance());
+
+        assert_ok!(Balances::burn_from(
+            &ALICE,
+            200,
+            Preservation::Preserve,
+            Precision::Exact,
+            Fortitude::Polite,
+        ));
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_burned(200);
+        assert_ok!(SupplyLedger::check_issuance());
+
+        let totals = SupplyLedger::supply_totals();
+        assert_eq!(totals.bridge_minted, 500);
+        assert_eq!(totals.bridge_burned, 200);
+        assert_eq!(Balances::total_issuance(), GENESIS_ISSUANCE + 300);
+    });
+}
+
+#[test]
+fn unrecorded_burn_breaks_the_invariant() {
+    new_test_ext().execute_with(|| {
+        let credit = <Balances as Balanced<u64>>::withdraw(
+            &ALICE,
+            100,
+            Precision::Exact,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .unwrap();
+        drop(credit);
+
+        assert!(SupplyLedger::check_issuance().is_err());
+    });
+}
+
+#[test]
+fn migration_adds_the_burn_counter_and_attests_the_issuance() {
+    new_test_ext().execute_with(|| {
+        // State of a chain started before the burns were counted: 1_000 minted as inflation
+        // and 100 burned since genesis.
+        assert_ok!(Balances::mint_into(&ALICE, 1_000));
+        burn_from_alice(100);
+        IssuanceBaseline::<Test>::kill();
+        frame_support::storage::unhashed::put_raw(
+            &Totals::<Test>::hashed_key(),
+            &migrations::v1::OldSupplyTotals {
+                inflation_minted: 1_000,
+                rewards_sent: 800,
+                locked: 300,
+                returned: 100,
+            }
+            .encode(),
+        );
+
+        migrations::v1::UncheckedMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            SupplyLedger::supply_totals(),
+            SupplyTotals {
+                inflation_minted: 1_000,
+                rewards_sent: 800,
+                locked: 300,
+                returned: 100,
+                burned: 0,
+                bridge_minted: 0,
+                bridge_burned: 0,
+            }
+        );
+        assert_eq!(
+            IssuanceBaseline::<Test>::get(),
+            Some(GENESIS_ISSUANCE - 100)
+        );
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn migration_adds_the_bridge_counters_and_folds_unrecorded_deposits() {
+    new_test_ext().execute_with(|| {
+        // 1_000 minted as inflation, 100 burned and 400 minted for deposits before the
+        // bridge mints were counted.
+        assert_ok!(Balances::mint_into(&ALICE, 1_000));
+        burn_from_alice(100);
+        assert_ok!(Balances::mint_into(&BOB, 400));
+        frame_support::storage::unhashed::put_raw(
+            &Totals::<Test>::hashed_key(),
+            &migrations::v2::OldSupplyTotals {
+                inflation_minted: 1_000,
+                rewards_sent: 800,
+                locked: 300,
+                returned: 100,
+                burned: 100,
+            }
+            .encode(),
+        );
+        assert!(SupplyLedger::check_issuance().is_err());
+
+        migrations::v2::UncheckedMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            SupplyLedger::supply_totals(),
+            SupplyTotals {
+                inflation_minted: 1_000,
+                rewards_sent: 800,
+                locked: 300,
+                returned: 100,
+                burned: 100,
+                bridge_minted: 0,
+                bridge_burned: 0,
+            }
+        );
+        assert_eq!(
+            IssuanceBaseline::<Test>::get(),
+            Some(GENESIS_ISSUANCE + 400)
+        );
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
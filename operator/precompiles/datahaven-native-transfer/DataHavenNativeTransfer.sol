@@ -13,6 +13,22 @@ DataHavenNativeTransfer constant DATAHAVEN_NATIVE_TRANSFER_CONTRACT =
 /// @notice Interface for transferring DataHaven native tokens to/from Ethereum via Snowbridge
 /// @custom:address 0x0000000000000000000000000000000000000819
 interface DataHavenNativeTransfer {
+    /// @dev A large transfer waiting for its delay to be over.
+    struct PendingTransfer {
+        /// Id of the transfer.
+        uint64 id;
+        /// Account the amount and fee are held from.
+        address sender;
+        /// Ethereum address receiving the tokens.
+        address recipient;
+        /// Amount transferred.
+        uint256 amount;
+        /// Relayer fee of the transfer.
+        uint256 fee;
+        /// Block at which the transfer is executed.
+        uint32 executeAt;
+    }
+
     /// @notice Emitted when tokens are locked for transfer to Ethereum
     /// @param account The account that locked tokens
     /// @param amount The amount of tokens locked
@@ -24,8 +40,16 @@ interface DataHavenNativeTransfer {
     /// @param amount The amount of tokens transferred
     event TokensTransferredToEthereum(address indexed from, address indexed to, uint256 amount);
 
+    /// @notice Emitted when a large transfer is queued instead of being sent
+    /// @param id The id of the queued transfer
+    /// @param from The account initiating the transfer
+    /// @param to The Ethereum address receiving the tokens
+    /// @param amount The amount of tokens transferred
+    event LargeTransferQueued(uint64 indexed id, address indexed from, address indexed to, uint256 amount);
+
     /// @notice Transfer DataHaven native tokens to Ethereum
-    /// @dev Locks tokens in the sovereign account and sends message through Snowbridge
+    /// @dev Locks tokens in the sovereign account and sends message through Snowbridge.
+    /// Transfers of at least the large transfer threshold are queued and sent after a delay.
     /// @param recipient Ethereum address to receive the tokens
     /// @param amount Amount of tokens to transfer (in smallest unit)
     /// @param fee Fee to incentivize relayers (in smallest unit)
@@ -42,8 +66,26 @@ interface DataHavenNativeTransfer {
     /// @custom:selector 05480e10
     function totalLockedBalance() external view returns (uint256 balance);
 
+    /// @notice Get the minimum fee of a transfer to Ethereum
+    /// @dev The cost of processing the message on DataHaven, transfers with a lower fee revert
+    /// @param amount Amount of tokens to transfer (in smallest unit)
+    /// @return fee Minimum fee (in smallest unit)
+    /// @custom:selector 63ec2108
+    function quoteTransferFee(uint256 amount) external view returns (uint256 fee);
+
     /// @notice Get the Ethereum sovereign account address
     /// @return account The sovereign account address (as H160)
     /// @custom:selector 71f9ae03
     function ethereumSovereignAccount() external view returns (address account);
+
+    /// @notice Get the large transfers waiting for their delay to be over
+    /// @return transfers The pending transfers, by id
+    /// @custom:selector 0b67dd65
+    function pendingTransfers() external view returns (PendingTransfer[] memory transfers);
+
+    /// @notice Cancel a pending large transfer, releasing its amount and fee to its sender
+    /// @dev Only callable by the accounts allowed by the pallet's security origin
+    /// @param id Id of the transfer, as returned by pendingTransfers
+    /// @custom:selector 68901b36
+    function cancelPendingTransfer(uint64 id) external;
 }
@@ -21,17 +21,25 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use core::marker::PhantomData;
+use datahaven_precompile_errors::PrecompileError;
 use fp_evm::PrecompileHandle;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::fungible::Inspect;
+use frame_support::traits::tokens::{Fortitude, Preservation};
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_datahaven_native_transfer::{
-    Call as NativeTransferCall, Pallet as NativeTransferPallet,
+    Call as NativeTransferCall, NextLargeTransferId, Pallet as NativeTransferPallet,
+    PendingLargeTransfer, PendingLargeTransfers,
 };
 use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
-use sp_core::{H160, U256};
-use sp_runtime::traits::Dispatchable;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, SaturatedConversion, Saturating};
 
 /// Solidity selector for the TokensLocked event:
 /// keccak256("TokensLocked(address,uint256)")
@@ -42,6 +50,11 @@ pub const SELECTOR_LOG_TOKENS_LOCKED: [u8; 32] = keccak256!("TokensLocked(addres
 pub const SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM: [u8; 32] =
     keccak256!("TokensTransferredToEthereum(address,address,uint256)");
 
+/// Solidity selector for the LargeTransferQueued event:
+/// keccak256("LargeTransferQueued(uint64,address,address,uint256)")
+pub const SELECTOR_LOG_LARGE_TRANSFER_QUEUED: [u8; 32] =
+    keccak256!("LargeTransferQueued(uint64,address,address,uint256)");
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -52,6 +65,18 @@ type BalanceOf<Runtime> =
         <Runtime as frame_system::Config>::AccountId,
     >>::Balance;
 
+/// A large transfer waiting for its delay to be over, as returned by `pendingTransfers`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct PendingTransfer {
+    id: u64,
+    sender: Address,
+    recipient: Address,
+    amount: U256,
+    fee: U256,
+    /// Block at which the transfer is executed.
+    execute_at: u32,
+}
+
 /// Precompile for DataHaven Native Transfer pallet
 pub struct DataHavenNativeTransferPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -73,6 +98,12 @@ where
     /// Locks tokens in the sovereign account and sends a message through Snowbridge
     /// to mint the equivalent tokens on Ethereum.
     ///
+    /// Reverts with the `Paused` custom error while transfers are disabled, and with
+    /// `InsufficientBalance` if the caller cannot pay `amount + fee` and stay alive.
+    ///
+    /// Transfers of at least the large transfer threshold are queued instead, see
+    /// `pendingTransfers`, and only emit `LargeTransferQueued`.
+    ///
     /// Parameters:
     /// - `recipient`: Ethereum address to receive the tokens
     /// - `amount`: Amount of tokens to transfer (in smallest unit)
@@ -111,6 +142,52 @@ where
             return Err(revert("Fee must be greater than zero"));
         }
 
+        // Check the failures contracts are expected to handle before dispatching
+        handle.record_db_read::<Runtime>(1)?;
+        if NativeTransferPallet::<Runtime>::is_paused() {
+            return Err(PrecompileError::Paused.into());
+        }
+
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+        let spendable =
+            <Runtime as pallet_datahaven_native_transfer::Config>::Currency::reducible_balance(
+                &caller,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            );
+        if spendable < amount_balance.saturating_add(fee_balance) {
+            return Err(PrecompileError::InsufficientBalance.into());
+        }
+
+        // Large transfers are queued under the next id
+        if NativeTransferPallet::<Runtime>::is_large_transfer(amount_balance) {
+            handle.record_db_read::<Runtime>(u64::max_encoded_len())?;
+            let id = NextLargeTransferId::<Runtime>::get();
+
+            // LargeTransferQueued(uint64,address,address,uint256) -> 4 topics
+            handle.record_log_costs_manual(4, 32)?;
+
+            let call = NativeTransferCall::<Runtime>::transfer_to_ethereum {
+                recipient: recipient_h160,
+                amount: amount_balance,
+                fee: fee_balance,
+            }
+            .into();
+            RuntimeHelper::<Runtime>::try_dispatch(handle, Some(caller).into(), call, 0)?;
+
+            log4(
+                handle.context().address,
+                SELECTOR_LOG_LARGE_TRANSFER_QUEUED,
+                H256::from_low_u64_be(id),
+                handle.context().caller,
+                recipient_h160,
+                solidity::encode_event_data(amount),
+            )
+            .record(handle)?;
+
+            return Ok(());
+        }
+
         // Reserve gas for emitting the two EVM logs we produce on success:
         // - TokensLocked(address,uint256)  -> 2 topics
         // - TokensTransferredToEthereum(address,address,uint256) -> 3 topics
@@ -189,6 +266,27 @@ where
         Ok(balance_u256)
     }
 
+    /// Get the minimum fee of a transfer to Ethereum
+    ///
+    /// This is the cost of processing the message of the transfer on DataHaven, charged by
+    /// the Snowbridge outbound queue. Transfers with a lower fee revert. The fee does not
+    /// depend on `amount` today.
+    ///
+    /// Returns:
+    /// - The minimum fee in smallest unit
+    #[precompile::public("quoteTransferFee(uint256)")]
+    #[precompile::view]
+    fn quote_transfer_fee(handle: &mut impl PrecompileHandle, amount: U256) -> EvmResult<U256> {
+        let _amount: BalanceOf<Runtime> = amount
+            .try_into()
+            .map_err(|_| RevertReason::custom("Amount overflow").in_field("amount"))?;
+
+        // The fee is computed from weights, which may be read from storage
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        Ok(NativeTransferPallet::<Runtime>::quote_transfer_fee().into())
+    }
+
     /// Get the Ethereum sovereign account address
     ///
     /// Returns the address of the account that holds locked tokens during transfers.
@@ -211,4 +309,50 @@ where
         // Convert to Address for the return
         Ok(Address(account_h160))
     }
+
+    /// Get the large transfers waiting for their delay to be over
+    ///
+    /// Returns:
+    /// - The pending large transfers, by id
+    #[precompile::public("pendingTransfers()")]
+    #[precompile::view]
+    fn pending_transfers(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<PendingTransfer>> {
+        let mut transfers = Vec::new();
+        for (id, transfer) in PendingLargeTransfers::<Runtime>::iter() {
+            handle.record_db_read::<Runtime>(u64::max_encoded_len().saturating_add(
+                PendingLargeTransfer::<
+                    Runtime::AccountId,
+                    BalanceOf<Runtime>,
+                    BlockNumberFor<Runtime>,
+                >::max_encoded_len(),
+            ))?;
+            transfers.push(PendingTransfer {
+                id,
+                sender: Address(transfer.sender.into()),
+                recipient: Address(transfer.recipient),
+                amount: transfer.amount.into(),
+                fee: transfer.fee.into(),
+                execute_at: transfer.execute_at.saturated_into(),
+            });
+        }
+        transfers.sort_by_key(|transfer| transfer.id);
+
+        Ok(transfers)
+    }
+
+    /// Cancel a pending large transfer, releasing its amount and fee to its sender
+    ///
+    /// Only callable by the accounts allowed by the pallet's security origin.
+    ///
+    /// Parameters:
+    /// - `id`: Id of the transfer, as returned by `pendingTransfers`
+    #[precompile::public("cancelPendingTransfer(uint64)")]
+    fn cancel_pending_transfer(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+        let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = NativeTransferCall::<Runtime>::cancel_large_transfer { id }.into();
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(caller).into(), call, 0)?;
+
+        Ok(())
+    }
 }
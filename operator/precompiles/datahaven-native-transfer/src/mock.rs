@@ -21,12 +21,13 @@ use super::*;
 use frame_support::traits::Everything;
 use frame_support::{construct_runtime, parameter_types, weights::Weight};
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use pallet_external_validators::traits::{ActiveEraInfo, EraIndex, EraIndexProvider};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode};
 use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
 use snowbridge_core::TokenId;
 use snowbridge_outbound_queue_primitives::v1::Ticket;
 use snowbridge_outbound_queue_primitives::v2::{Message, SendMessage};
-use snowbridge_outbound_queue_primitives::SendError;
+use snowbridge_outbound_queue_primitives::{SendError, SendMessageFeeProvider};
 use sp_core::H256;
 use sp_runtime::BuildStorage;
 use sp_runtime::{
@@ -105,7 +106,7 @@ impl pallet_balances::Config for Runtime {
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
     type RuntimeFreezeReason = ();
@@ -185,35 +186,50 @@ pub struct MockOutboundQueue;
 impl SendMessage for MockOutboundQueue {
     type Ticket = MockTicket;
 
-    fn validate(_message: &Message) -> Result<Self::Ticket, SendError> {
+    fn validate(message: &Message) -> Result<Self::Ticket, SendError> {
         // For testing, always succeed validation
-        Ok(MockTicket)
+        Ok(MockTicket(message.id))
     }
 
-    fn deliver(_ticket: Self::Ticket) -> Result<H256, SendError> {
+    fn deliver(ticket: Self::Ticket) -> Result<H256, SendError> {
         // For testing, always succeed delivery
-        Ok(H256::zero())
+        Ok(ticket.0)
     }
 }
 
+impl SendMessageFeeProvider for MockOutboundQueue {
+    type Balance = Balance;
+
+    fn local_fee() -> Self::Balance {
+        LOCAL_FEE
+    }
+}
+
+/// Minimum fee of a transfer, charged by the mock outbound queue.
+pub const LOCAL_FEE: Balance = 10;
+
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking)]
-pub struct MockTicket;
+pub struct MockTicket(H256);
 
 impl Ticket for MockTicket {
     fn message_id(&self) -> H256 {
-        H256::zero()
+        self.0
     }
 }
 
 parameter_types! {
     pub EthereumSovereignAccountParam: AccountId = EthereumSovereign.into();
     pub FeeRecipientParam: AccountId = FeeRecipient.into();
+    pub const FeeRefundTimeout: u32 = 10;
+    pub const TransferReceiptRetention: u32 = 20;
     // Mock token ID - Some(TokenId) for testing
     // TokenId is H256, so we create it directly
     pub NativeTokenIdParam: Option<TokenId> = Some(H256([1u8; 32]));
+    pub storage LargeTransferThreshold: Balance = 0; // Large transfers are enabled by the tests
+    pub const LargeTransferDelay: u32 = 5;
 }
 
-// Mock origin that allows account 0 to pause/unpause (for testing)
+// Mock origin that allows account 0 to pause/unpause and cancel transfers (for testing)
 pub struct EnsureAccountZero;
 impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAccountZero {
     type Success = AccountId;
@@ -233,12 +249,35 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAccountZero {
 impl pallet_datahaven_native_transfer::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type EthereumSovereignAccount = EthereumSovereignAccountParam;
     type OutboundQueue = MockOutboundQueue;
     type FeeRecipient = FeeRecipientParam;
+    type FeeRefundTimeout = FeeRefundTimeout;
+    type TransferReceiptRetention = TransferReceiptRetention;
+    type Ledger = ();
+    type OnOutboundMessageSent = ();
     type WeightInfo = ();
     type PauseOrigin = EnsureAccountZero;
     type NativeTokenId = NativeTokenIdParam;
+    type EraIndexProvider = MockEraIndexProvider;
+    type LargeTransferThreshold = LargeTransferThreshold;
+    type LargeTransferDelay = LargeTransferDelay;
+    type SecurityOrigin = EnsureAccountZero;
+}
+
+pub struct MockEraIndexProvider;
+impl EraIndexProvider for MockEraIndexProvider {
+    fn active_era() -> ActiveEraInfo {
+        ActiveEraInfo {
+            index: 0,
+            start: None,
+        }
+    }
+
+    fn era_to_session_start(_era_index: EraIndex) -> Option<u32> {
+        None
+    }
 }
 
 pub(crate) struct ExtBuilder {
@@ -300,3 +339,11 @@ pub(crate) fn precompiles() -> Precompiles<Runtime> {
 pub(crate) fn balance(account: impl Into<AccountId>) -> Balance {
     Balances::free_balance(account.into())
 }
+
+pub(crate) fn held_fee(account: impl Into<AccountId>) -> Balance {
+    use frame_support::traits::fungible::InspectHold;
+    Balances::balance_on_hold(
+        &pallet_datahaven_native_transfer::HoldReason::TransferFee.into(),
+        &account.into(),
+    )
+}
@@ -17,12 +17,15 @@
 //! Comprehensive test suite for DataHaven Native Transfer precompile
 
 use crate::mock::{
-    balance, precompiles, Alice, Bob, EthereumSovereign, ExistentialDeposit, ExtBuilder,
-    FeeRecipient, NativeTransferPrecompile, PCall,
+    balance, held_fee, precompiles, Alice, Bob, EthereumSovereign, ExistentialDeposit, ExtBuilder,
+    FeeRecipient, LargeTransferDelay, LargeTransferThreshold, NativeTransferPrecompile, PCall,
+    Root, Runtime, LOCAL_FEE,
 };
-use precompile_utils::prelude::Address;
+use crate::{PendingTransfer, SELECTOR_LOG_LARGE_TRANSFER_QUEUED};
+use datahaven_precompile_errors::PrecompileError;
+use precompile_utils::prelude::{log4, solidity, Address};
 use precompile_utils::testing::*;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 
 // Test helper to get the precompile address
 fn precompile_address() -> H160 {
@@ -39,6 +42,9 @@ fn test_selectors() {
     assert!(!PCall::transfer_to_ethereum_selectors().is_empty());
     assert!(!PCall::total_locked_balance_selectors().is_empty());
     assert!(!PCall::ethereum_sovereign_account_selectors().is_empty());
+    assert!(PCall::pending_transfers_selectors().contains(&0x0b67dd65));
+    assert!(PCall::cancel_pending_transfer_selectors().contains(&0x68901b36));
+    assert!(PCall::quote_transfer_fee_selectors().contains(&0x63ec2108));
 }
 
 // ============================================================================
@@ -62,6 +68,15 @@ fn test_function_modifiers() {
 
             // ethereumSovereignAccount - view
             tester.test_view_modifier(PCall::ethereum_sovereign_account_selectors());
+
+            // quoteTransferFee - view
+            tester.test_view_modifier(PCall::quote_transfer_fee_selectors());
+
+            // pendingTransfers - view
+            tester.test_view_modifier(PCall::pending_transfers_selectors());
+
+            // cancelPendingTransfer - non-view, non-payable
+            tester.test_default_modifier(PCall::cancel_pending_transfer_selectors());
         });
 }
 
@@ -103,8 +118,9 @@ fn test_transfer_to_ethereum_success() {
                 initial_balance - 1000 - 100 // amount + fee
             );
 
-            // Fee should go to fee recipient
-            assert_eq!(balance(FeeRecipient), 100);
+            // Fee should be held until the delivery is acknowledged
+            assert_eq!(held_fee(Alice), 100);
+            assert_eq!(balance(FeeRecipient), 0);
 
             // Amount should be locked in sovereign account
             assert_eq!(balance(EthereumSovereign), initial_sovereign_balance + 1000);
@@ -203,11 +219,29 @@ fn test_transfer_to_ethereum_insufficient_balance() {
                         fee,
                     },
                 )
-                .execute_reverts(|output| {
-                    // Pallet will return Token(NotExpendable) or similar balance error
-                    let output_str = from_utf8_lossy(output);
-                    output_str.contains("Token") || output_str.contains("InsufficientBalance")
-                });
+                .execute_reverts(|output| output == PrecompileError::InsufficientBalance.encode());
+        });
+}
+
+#[test]
+fn test_transfer_to_ethereum_paused() {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 10000)])
+        .build()
+        .execute_with(|| {
+            pallet_datahaven_native_transfer::Paused::<Runtime>::put(true);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum {
+                        recipient: H160::from_low_u64_be(0x1234).into(),
+                        amount: U256::from(1000),
+                        fee: U256::from(100),
+                    },
+                )
+                .execute_reverts(|output| output == PrecompileError::Paused.encode());
         });
 }
 
@@ -256,8 +290,9 @@ fn test_transfer_to_ethereum_multiple_transfers() {
             // Verify sovereign account has both amounts locked
             assert_eq!(balance(EthereumSovereign), initial_sovereign + 2000);
 
-            // Verify fee recipient got both fees
-            assert_eq!(balance(FeeRecipient), 200);
+            // Verify both fees are held until the deliveries are acknowledged
+            assert_eq!(held_fee(Alice), 100);
+            assert_eq!(held_fee(Bob), 100);
         });
 }
 
@@ -548,6 +583,144 @@ fn test_fee_overflow() {
         });
 }
 
+// ============================================================================
+// Fee Quote Tests
+// ============================================================================
+
+#[test]
+fn test_quote_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::quote_transfer_fee {
+                    amount: U256::from(1000),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(LOCAL_FEE));
+    });
+}
+
+#[test]
+fn test_transfer_with_fee_below_quote_reverts() {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 10000)])
+        .build()
+        .execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum {
+                        recipient: H160::from_low_u64_be(0x1234).into(),
+                        amount: U256::from(1000),
+                        fee: U256::from(LOCAL_FEE - 1),
+                    },
+                )
+                .execute_reverts(|output| from_utf8_lossy(output).contains("FeeTooLow"));
+        });
+}
+
+// ============================================================================
+// Large Transfer Tests
+// ============================================================================
+
+#[test]
+fn test_large_transfer_is_queued() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (Alice.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            LargeTransferThreshold::set(&1000);
+            let recipient = H160::from_low_u64_be(0x1234);
+            let initial_sovereign = balance(EthereumSovereign);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum {
+                        recipient: recipient.into(),
+                        amount: U256::from(1000),
+                        fee: U256::from(100),
+                    },
+                )
+                .expect_log(log4(
+                    precompile_address(),
+                    SELECTOR_LOG_LARGE_TRANSFER_QUEUED,
+                    H256::from_low_u64_be(0),
+                    H160::from(Alice),
+                    recipient,
+                    solidity::encode_event_data(U256::from(1000)),
+                ))
+                .execute_returns(());
+
+            // Nothing is locked until the delay is over
+            assert_eq!(balance(EthereumSovereign), initial_sovereign);
+
+            precompiles()
+                .prepare_test(Alice, precompile_address(), PCall::pending_transfers {})
+                .execute_returns(vec![PendingTransfer {
+                    id: 0,
+                    sender: Address(Alice.into()),
+                    recipient: Address(recipient),
+                    amount: U256::from(1000),
+                    fee: U256::from(100),
+                    execute_at: 1 + LargeTransferDelay::get(),
+                }]);
+        });
+}
+
+#[test]
+fn test_cancel_pending_transfer() {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 10000)])
+        .build()
+        .execute_with(|| {
+            LargeTransferThreshold::set(&1000);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum {
+                        recipient: H160::from_low_u64_be(0x1234).into(),
+                        amount: U256::from(1000),
+                        fee: U256::from(100),
+                    },
+                )
+                .execute_returns(());
+            assert_eq!(balance(Alice), 10000 - 1100);
+
+            // Only the security origin can cancel
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::cancel_pending_transfer { id: 0 },
+                )
+                .execute_reverts(|output| from_utf8_lossy(output).contains("BadOrigin"));
+
+            precompiles()
+                .prepare_test(
+                    Root,
+                    precompile_address(),
+                    PCall::cancel_pending_transfer { id: 0 },
+                )
+                .execute_returns(());
+
+            assert_eq!(balance(Alice), 10000);
+            precompiles()
+                .prepare_test(Alice, precompile_address(), PCall::pending_transfers {})
+                .execute_returns(Vec::<PendingTransfer>::new());
+        });
+}
+
 // Helper function to convert bytes to UTF-8 string for debugging
 fn from_utf8_lossy(bytes: &[u8]) -> String {
     String::from_utf8_lossy(bytes).to_string()
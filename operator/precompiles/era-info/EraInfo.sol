@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The EraInfo precompile address.
+address constant ERA_INFO_ADDRESS = 0x000000000000000000000000000000000000081B;
+
+/// @dev The EraInfo precompile instance.
+EraInfo constant ERA_INFO_CONTRACT = EraInfo(ERA_INFO_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title Era Info Interface
+/// @notice Interface to read the block author, active era and session index of DataHaven
+/// @custom:address 0x000000000000000000000000000000000000081B
+interface EraInfo {
+    /// @notice Get the author of the current block
+    /// @dev Matches block.coinbase; returns the zero address if the author cannot be found
+    /// @return author Address of the block author
+    /// @custom:selector 16c48554
+    function blockAuthor() external view returns (address author);
+
+    /// @notice Get the active era
+    /// @return index Index of the active era
+    /// @return start Start of the era in milliseconds since the unix epoch, 0 if not set yet
+    /// @custom:selector c2f192cb
+    function activeEra() external view returns (uint32 index, uint64 start);
+
+    /// @notice Get the current session index
+    /// @return index Index of the current session
+    /// @custom:selector d4166763
+    function currentSession() external view returns (uint32 index);
+}
@@ -0,0 +1,93 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose block author, era and session information to the EVM layer.
+//!
+//! All functions are cheap views so contracts can gate logic on the current era or
+//! session without relying on off-chain oracles.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use pallet_external_validators::traits::ActiveEraInfo;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_staking::SessionIndex;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Upper bound of the digest data read to resolve the block author: the BABE pre-runtime
+/// log plus the session validator it points to.
+const AUTHOR_LOOKUP_READ_SIZE: usize = 128;
+
+/// Precompile exposing block author, era and session information
+pub struct EraInfoPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> EraInfoPrecompile<Runtime>
+where
+    Runtime: pallet_external_validators::Config + pallet_session::Config + pallet_evm::Config,
+{
+    /// Get the author of the current block
+    ///
+    /// Resolved through the same `FindAuthor` used by the EVM, so it always matches
+    /// `block.coinbase`.
+    ///
+    /// Returns:
+    /// - Address of the block author, or the zero address if it cannot be found
+    #[precompile::public("blockAuthor()")]
+    #[precompile::view]
+    fn block_author(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+        handle.record_db_read::<Runtime>(AUTHOR_LOOKUP_READ_SIZE)?;
+
+        Ok(Address(pallet_evm::Pallet::<Runtime>::find_author()))
+    }
+
+    /// Get the active era
+    ///
+    /// Returns:
+    /// - Index of the active era
+    /// - Start of the active era in milliseconds since the unix epoch, or 0 if not set yet
+    #[precompile::public("activeEra()")]
+    #[precompile::view]
+    fn active_era(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, u64)> {
+        handle.record_db_read::<Runtime>(ActiveEraInfo::max_encoded_len())?;
+
+        let active_era =
+            pallet_external_validators::Pallet::<Runtime>::active_era().unwrap_or(ActiveEraInfo {
+                index: 0,
+                start: None,
+            });
+
+        Ok((active_era.index, active_era.start.unwrap_or_default()))
+    }
+
+    /// Get the current session index
+    ///
+    /// Returns:
+    /// - Index of the current session
+    #[precompile::public("currentSession()")]
+    #[precompile::view]
+    fn current_session(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_db_read::<Runtime>(SessionIndex::max_encoded_len())?;
+
+        Ok(pallet_session::CurrentIndex::<Runtime>::get())
+    }
+}
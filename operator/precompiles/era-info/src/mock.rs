@@ -0,0 +1,330 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities and mock runtime for Era Info precompile tests
+
+use super::*;
+
+use frame_support::traits::{
+    ConstU32, ConstU64, Everything, FindAuthor, OnFinalize, OnInitialize, ValidatorRegistration,
+};
+use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+    testing::UintAuthorityId,
+    traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+    BuildStorage, ConsensusEngineId, Perbill, RuntimeAppPublic,
+};
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        EVM: pallet_evm,
+        Timestamp: pallet_timestamp,
+        ExternalValidators: pallet_external_validators,
+        Session: pallet_session,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+    pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeTask = RuntimeTask;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Precompiles<R> =
+    PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, EraInfoPrecompile<R>>,)>;
+
+pub type PCall = EraInfoPrecompileCall<Runtime>;
+
+mock_account!(EraInfo, |_| MockAccount::from_u64(1));
+mock_account!(Alice, |_| MockAccount::from_u64(2));
+mock_account!(Bob, |_| MockAccount::from_u64(3));
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub GasLimitPovSizeRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(MAX_POV_SIZE)
+    };
+    pub GasLimitStorageGrowthRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+    };
+    pub static MockBlockAuthor: Option<H160> = None;
+}
+
+/// Block author set by the tests, digests are ignored.
+pub struct MockFindAuthor;
+impl FindAuthor<H160> for MockFindAuthor {
+    fn find_author<'a, I>(_digests: I) -> Option<H160>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        MockBlockAuthor::get()
+    }
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type CreateOriginFilter = ();
+    type CreateInnerOriginFilter = ();
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Runtime>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = MockFindAuthor;
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+    type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+    type Timestamp = Timestamp;
+    type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+    type AccountProvider = FrameSystemAccountProvider<Runtime>;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+pub struct IsRegistered;
+impl ValidatorRegistration<AccountId> for IsRegistered {
+    fn is_registered(_id: &AccountId) -> bool {
+        true
+    }
+}
+
+parameter_types! {
+    pub const SessionsPerEra: SessionIndex = 2;
+    pub const AuthorizedOrigin: H160 = H160::repeat_byte(0x0);
+}
+
+impl pallet_external_validators::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
+    type HistoryDepth = ConstU32<84>;
+    type MaxWhitelistedValidators = ConstU32<20>;
+    type MaxExternalValidators = ConstU32<20>;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ValidatorRegistration = IsRegistered;
+    type UnixTime = Timestamp;
+    type SessionsPerEra = SessionsPerEra;
+    type OnEraStart = ();
+    type OnEraEnd = ();
+    type AuthorizedOrigin = AuthorizedOrigin;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+sp_runtime::impl_opaque_keys! {
+    pub struct MockSessionKeys {
+        pub aura: UintAuthorityId,
+    }
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [sp_runtime::KeyTypeId] = &[UintAuthorityId::ID];
+    fn on_genesis_session<Ks: OpaqueKeys>(_validators: &[(AccountId, Ks)]) {}
+    fn on_new_session<Ks: OpaqueKeys>(
+        _changed: bool,
+        _validators: &[(AccountId, Ks)],
+        _queued_validators: &[(AccountId, Ks)],
+    ) {
+    }
+    fn on_disabled(_validator_index: u32) {}
+}
+
+parameter_types! {
+    pub const Offset: u32 = 0;
+    pub const Period: u32 = 5;
+}
+
+impl pallet_session::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+    type SessionManager = ExternalValidators;
+    type SessionHandler = TestSessionHandler;
+    type Keys = MockSessionKeys;
+    type WeightInfo = ();
+    type DisablingStrategy = ();
+}
+
+pub const INIT_TIMESTAMP: u64 = 30_000;
+pub const BLOCK_TIME: u64 = 1000;
+
+pub(crate) struct ExtBuilder {
+    validators: Vec<AccountId>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> ExtBuilder {
+        ExtBuilder {
+            validators: vec![Alice.into(), Bob.into()],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        pallet_external_validators::GenesisConfig::<Runtime> {
+            skip_external_validators: false,
+            whitelisted_validators: self.validators.clone(),
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet external validators storage can be assimilated");
+
+        let keys = self
+            .validators
+            .iter()
+            .enumerate()
+            .map(|(i, account)| {
+                (
+                    *account,
+                    *account,
+                    MockSessionKeys {
+                        aura: UintAuthorityId(i as u64),
+                    },
+                )
+            })
+            .collect();
+        pallet_session::GenesisConfig::<Runtime> {
+            keys,
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet session storage can be assimilated");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub(crate) fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+pub(crate) fn run_to_session(n: SessionIndex) {
+    run_to_block(Period::get() * n + 1);
+}
+
+pub(crate) fn run_to_block(n: u32) {
+    let old_block_number = System::block_number();
+
+    for x in old_block_number..n {
+        ExternalValidators::on_finalize(System::block_number());
+        Session::on_finalize(System::block_number());
+
+        System::set_block_number(x + 1);
+        Timestamp::set_timestamp(u64::from(System::block_number()) * BLOCK_TIME + INIT_TIMESTAMP);
+
+        ExternalValidators::on_initialize(System::block_number());
+        Session::on_initialize(System::block_number());
+    }
+}
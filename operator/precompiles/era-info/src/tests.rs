@@ -0,0 +1,113 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test suite for Era Info precompile
+
+use crate::mock::{
+    precompiles, run_to_session, Alice, Bob, EraInfo, ExtBuilder, ExternalValidators,
+    MockBlockAuthor, PCall, SessionsPerEra,
+};
+use precompile_utils::prelude::Address;
+use precompile_utils::testing::*;
+use sp_core::H160;
+
+fn precompile_address() -> H160 {
+    EraInfo.into()
+}
+
+#[test]
+fn test_selectors() {
+    assert!(!PCall::block_author_selectors().is_empty());
+    assert!(!PCall::active_era_selectors().is_empty());
+    assert!(!PCall::current_session_selectors().is_empty());
+}
+
+#[test]
+fn test_function_modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, precompile_address());
+
+        tester.test_view_modifier(PCall::block_author_selectors());
+        tester.test_view_modifier(PCall::active_era_selectors());
+        tester.test_view_modifier(PCall::current_session_selectors());
+    });
+}
+
+#[test]
+fn test_block_author_defaults_to_zero() {
+    ExtBuilder::default().build().execute_with(|| {
+        MockBlockAuthor::set(None);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::block_author {})
+            .expect_no_logs()
+            .execute_returns(Address(H160::zero()));
+    });
+}
+
+#[test]
+fn test_block_author() {
+    ExtBuilder::default().build().execute_with(|| {
+        MockBlockAuthor::set(Some(Bob.into()));
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::block_author {})
+            .expect_no_logs()
+            .execute_returns(Address(Bob.into()));
+    });
+}
+
+#[test]
+fn test_active_era_at_genesis() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::active_era {})
+            .expect_no_logs()
+            .execute_returns((0u32, 0u64));
+    });
+}
+
+#[test]
+fn test_active_era_follows_pallet() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_session(SessionsPerEra::get() * 2);
+
+        let active_era = ExternalValidators::active_era().expect("era has been activated");
+        assert!(active_era.index > 0);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::active_era {})
+            .expect_no_logs()
+            .execute_returns((active_era.index, active_era.start.unwrap_or_default()));
+    });
+}
+
+#[test]
+fn test_current_session() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_session(3);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::current_session {})
+            .expect_no_logs()
+            .execute_returns(3u32);
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["EraInfo.sol"], PCall::supports_selector)
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.4;
+
+/// @author The DataHaven Team
+/// @title DataHaven Precompile Errors
+/// @notice Custom errors DataHaven precompiles revert with. The revert data is the 4-byte
+/// error selector, so callers can match it with `try/catch` on the error type.
+
+/// @dev The caller cannot cover the amount and fees of the operation.
+/// Selector: f4d678b8
+error InsufficientBalance();
+
+/// @dev The pallet behind the precompile is paused.
+/// Selector: 9e87fac8
+error Paused();
+
+/// @dev The operation exceeds the current rate limit.
+/// Selector: 3f7b7a68
+error RateLimited();
+
+/// @dev The bridge cannot accept the message right now.
+/// Selector: db6a2053
+error BridgeBusy();
+
+/// @dev The caller is not allowed to perform the operation.
+/// Selector: 1c2f59db
+error BadOrigin();
@@ -0,0 +1,115 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Structured revert errors shared by DataHaven precompiles.
+//!
+//! Failures are returned as Solidity custom errors instead of plain strings: the revert data
+//! is the 4-byte selector of the error, so contracts can handle them programmatically. The
+//! matching Solidity definitions are published in `DataHavenPrecompileErrors.sol`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use fp_evm::{ExitRevert, PrecompileFailure};
+use precompile_utils::keccak256;
+
+/// First 4 bytes of the keccak256 hash of a Solidity error signature, computed at compile time.
+macro_rules! error_selector {
+    ($signature:literal) => {{
+        const HASH: [u8; 32] = keccak256!($signature);
+        [HASH[0], HASH[1], HASH[2], HASH[3]]
+    }};
+}
+
+/// Errors a DataHaven precompile can revert with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecompileError {
+    /// The caller cannot cover the amount and fees of the operation.
+    InsufficientBalance,
+    /// The pallet behind the precompile is paused.
+    Paused,
+    /// The operation exceeds the current rate limit.
+    RateLimited,
+    /// The bridge cannot accept the message right now.
+    BridgeBusy,
+    /// The caller is not allowed to perform the operation.
+    BadOrigin,
+}
+
+impl PrecompileError {
+    /// Solidity signature of the error.
+    pub const fn signature(self) -> &'static str {
+        match self {
+            Self::InsufficientBalance => "InsufficientBalance()",
+            Self::Paused => "Paused()",
+            Self::RateLimited => "RateLimited()",
+            Self::BridgeBusy => "BridgeBusy()",
+            Self::BadOrigin => "BadOrigin()",
+        }
+    }
+
+    /// Selector of the error, which is the full revert data.
+    pub const fn selector(self) -> [u8; 4] {
+        match self {
+            Self::InsufficientBalance => error_selector!("InsufficientBalance()"),
+            Self::Paused => error_selector!("Paused()"),
+            Self::RateLimited => error_selector!("RateLimited()"),
+            Self::BridgeBusy => error_selector!("BridgeBusy()"),
+            Self::BadOrigin => error_selector!("BadOrigin()"),
+        }
+    }
+
+    /// ABI-encoded revert data of the error.
+    pub fn encode(self) -> Vec<u8> {
+        self.selector().to_vec()
+    }
+}
+
+impl From<PrecompileError> for PrecompileFailure {
+    fn from(error: PrecompileError) -> Self {
+        PrecompileFailure::Revert {
+            exit_status: ExitRevert::Reverted,
+            output: error.encode(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selectors_match_solidity_definitions() {
+        assert_eq!(
+            PrecompileError::InsufficientBalance.selector(),
+            [0xf4, 0xd6, 0x78, 0xb8]
+        );
+        assert_eq!(PrecompileError::Paused.selector(), [0x9e, 0x87, 0xfa, 0xc8]);
+        assert_eq!(
+            PrecompileError::RateLimited.selector(),
+            [0x3f, 0x7b, 0x7a, 0x68]
+        );
+        assert_eq!(
+            PrecompileError::BridgeBusy.selector(),
+            [0xdb, 0x6a, 0x20, 0x53]
+        );
+        assert_eq!(
+            PrecompileError::BadOrigin.selector(),
+            [0x1c, 0x2f, 0x59, 0xdb]
+        );
+    }
+}
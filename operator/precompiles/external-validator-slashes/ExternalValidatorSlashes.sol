@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ExternalValidatorSlashes precompile address.
+address constant EXTERNAL_VALIDATOR_SLASHES_ADDRESS = 0x000000000000000000000000000000000000081C;
+
+/// @dev The ExternalValidatorSlashes precompile instance.
+ExternalValidatorSlashes constant EXTERNAL_VALIDATOR_SLASHES_CONTRACT =
+    ExternalValidatorSlashes(EXTERNAL_VALIDATOR_SLASHES_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title External Validator Slashes Interface
+/// @notice Interface to read the pending validator slashes of DataHaven and let governance act on them
+/// @custom:address 0x000000000000000000000000000000000000081C
+interface ExternalValidatorSlashes {
+    /// @dev A slash waiting to be applied.
+    struct PendingSlash {
+        /// @dev The slashed validator
+        address validator;
+        /// @dev Unique identifier of the slash
+        uint32 slashId;
+        /// @dev Slashed proportion in parts per billion
+        uint32 percentage;
+        /// @dev Whether the slash is out of its defer period
+        bool confirmed;
+    }
+
+    /// @dev A slash of a validator.
+    struct SlashRecord {
+        /// @dev Era in which the slash is applied
+        uint32 era;
+        /// @dev Slashed proportion in parts per billion
+        uint32 percentage;
+        /// @dev 0 liveness, 1 BABE, 2 GRANDPA, 3 BEEFY equivocation, 4 custom offence
+        uint8 kind;
+        /// @dev Whether the slash is out of its defer period
+        bool confirmed;
+    }
+
+    /// @notice Get the slashes to be applied in an era
+    /// @param era The era in which the slashes are applied
+    /// @return slashes The pending slashes, in storage order
+    /// @custom:selector 5aeecce5
+    function pendingSlashes(uint32 era) external view returns (PendingSlash[] memory slashes);
+
+    /// @notice Get the slashes of a validator applied in a range of eras
+    /// @dev Only the slashes of bonded eras are kept. Reverts if the range spans more than 1000 eras
+    /// @param validator The slashed validator
+    /// @param fromEra The first era of the range
+    /// @param toEra The last era of the range, included
+    /// @return history The slashes of the validator, oldest era first
+    /// @custom:selector 0ce593a2
+    function slashHistory(address validator, uint32 fromEra, uint32 toEra)
+        external
+        view
+        returns (SlashRecord[] memory history);
+
+    /// @notice Get the current slashing mode
+    /// @return mode 0 if slashing is enabled, 1 if slashes are only logged, 2 if disabled
+    /// @custom:selector e5495a0e
+    function slashingMode() external view returns (uint8 mode);
+
+    /// @notice Get the number of slash batches waiting to be reported to Ethereum
+    /// @return length Number of batches in the unsent queue
+    /// @custom:selector 8ca5fbff
+    function unreportedQueueLength() external view returns (uint32 length);
+
+    /// @notice Cancel slashes of an era that are still in their defer period
+    /// @dev Reverts with BadOrigin() unless called by an allowed governance contract
+    /// @param era The era in which the slashes are applied
+    /// @param slashIndices Sorted, unique indices of the slashes in pendingSlashes(era)
+    /// @custom:selector 0dc65b9c
+    function cancelDeferredSlash(uint32 era, uint32[] memory slashIndices) external;
+
+    /// @notice Change the slashing mode
+    /// @dev Reverts with BadOrigin() unless called by an allowed governance contract
+    /// @param mode 0 to enable slashing, 1 to only log slashes, 2 to disable it
+    /// @custom:selector 030af378
+    function setSlashingMode(uint8 mode) external;
+}
@@ -0,0 +1,276 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the External Validator Slashes pallet to the EVM layer.
+//!
+//! Anyone can read the pending slashes, the slash history of a validator, the slashing mode
+//! and the length of the queue of slashes not yet reported to Ethereum. Cancelling deferred slashes and changing the
+//! slashing mode are root calls, dispatched only when the caller is one of the governance
+//! contracts allowed by the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use datahaven_precompile_errors::PrecompileError;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::{ConstU32, Contains};
+use pallet_external_validator_slashes::{
+    Call as SlashesCall, OffenceKind, Pallet as SlashesPallet, Slashes, SlashingMode,
+    SlashingModeOption,
+};
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::H160;
+use sp_runtime::traits::Dispatchable;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of slash indices accepted by `cancelDeferredSlash`.
+pub const MAX_CANCELLED_SLASHES: u32 = 1_000;
+
+/// Maximum number of eras read by one `slashHistory` call.
+pub const MAX_SLASH_HISTORY_ERAS: u32 = 1_000;
+
+/// Size of the unsent queue head and tail indices, read to compute its length.
+const UNSENT_QUEUE_BOUNDS_READ_SIZE: usize = 8;
+
+/// A slash waiting to be applied, as returned by `pendingSlashes`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct PendingSlash {
+    validator: Address,
+    slash_id: u32,
+    /// Slashed proportion in parts per billion.
+    percentage: u32,
+    confirmed: bool,
+}
+
+/// A slash of a validator, as returned by `slashHistory`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct SlashRecord {
+    /// Era in which the slash is applied.
+    era: u32,
+    /// Slashed proportion in parts per billion.
+    percentage: u32,
+    /// Offence kind, see `offence_kind_to_u8`.
+    kind: u8,
+    confirmed: bool,
+}
+
+fn offence_kind_to_u8(kind: &OffenceKind) -> u8 {
+    match kind {
+        OffenceKind::LivenessOffence => 0,
+        OffenceKind::BabeEquivocation => 1,
+        OffenceKind::GrandpaEquivocation => 2,
+        OffenceKind::BeefyEquivocation => 3,
+        OffenceKind::Custom(_) => 4,
+    }
+}
+
+fn slashing_mode_to_u8(mode: SlashingModeOption) -> u8 {
+    match mode {
+        SlashingModeOption::Enabled => 0,
+        SlashingModeOption::LogOnly => 1,
+        SlashingModeOption::Disabled => 2,
+    }
+}
+
+fn slashing_mode_from_u8(mode: u8) -> MayRevert<SlashingModeOption> {
+    match mode {
+        0 => Ok(SlashingModeOption::Enabled),
+        1 => Ok(SlashingModeOption::LogOnly),
+        2 => Ok(SlashingModeOption::Disabled),
+        _ => Err(RevertReason::custom("Unknown slashing mode").in_field("mode")),
+    }
+}
+
+/// Precompile for External Validator Slashes pallet
+pub struct ExternalValidatorSlashesPrecompile<Runtime, GovernanceCallers>(
+    PhantomData<(Runtime, GovernanceCallers)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, GovernanceCallers> ExternalValidatorSlashesPrecompile<Runtime, GovernanceCallers>
+where
+    Runtime: pallet_external_validator_slashes::Config + pallet_evm::Config,
+    Runtime::AccountId: Into<H160>,
+    Runtime::SlashId: Into<u32>,
+    Runtime::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<SlashesCall<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<frame_system::RawOrigin<Runtime::AccountId>>,
+    GovernanceCallers: Contains<H160>,
+{
+    /// Get the slashes to be applied in an era
+    ///
+    /// Slashes still in their defer period can be cancelled with `cancelDeferredSlash`.
+    ///
+    /// Returns:
+    /// - The pending slashes of the era, in storage order
+    #[precompile::public("pendingSlashes(uint32)")]
+    #[precompile::view]
+    fn pending_slashes(
+        handle: &mut impl PrecompileHandle,
+        era: u32,
+    ) -> EvmResult<Vec<PendingSlash>> {
+        let slashes = Slashes::<Runtime>::get(era);
+        handle.record_db_read::<Runtime>(slashes.encoded_size())?;
+
+        Ok(slashes
+            .into_iter()
+            .map(|slash| PendingSlash {
+                validator: Address(slash.validator.into()),
+                slash_id: slash.slash_id.into(),
+                percentage: slash.percentage.deconstruct(),
+                confirmed: slash.confirmed,
+            })
+            .collect())
+    }
+
+    /// Get the slashes of a validator applied from `from_era` to `to_era` included
+    ///
+    /// Slashes are kept while their era is bonded, older ones are not returned.
+    ///
+    /// Returns:
+    /// - The slashes of the validator, oldest era first
+    #[precompile::public("slashHistory(address,uint32,uint32)")]
+    #[precompile::view]
+    fn slash_history(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+        from_era: u32,
+        to_era: u32,
+    ) -> EvmResult<Vec<SlashRecord>> {
+        if from_era > to_era {
+            return Err(RevertReason::custom("Era range is empty")
+                .in_field("toEra")
+                .into());
+        }
+        if to_era - from_era >= MAX_SLASH_HISTORY_ERAS {
+            return Err(RevertReason::custom("Era range is too large")
+                .in_field("toEra")
+                .into());
+        }
+
+        let mut history = Vec::new();
+        for era in from_era..=to_era {
+            let slashes = Slashes::<Runtime>::get(era);
+            handle.record_db_read::<Runtime>(slashes.encoded_size())?;
+
+            history.extend(
+                slashes
+                    .into_iter()
+                    .filter(|slash| Address(slash.validator.clone().into()) == validator)
+                    .map(|slash| SlashRecord {
+                        era,
+                        percentage: slash.percentage.deconstruct(),
+                        kind: offence_kind_to_u8(&slash.offence_kind),
+                        confirmed: slash.confirmed,
+                    }),
+            );
+        }
+
+        Ok(history)
+    }
+
+    /// Get the current slashing mode
+    ///
+    /// Returns:
+    /// - `0` if slashing is enabled, `1` if slashes are only logged, `2` if disabled
+    #[precompile::public("slashingMode()")]
+    #[precompile::view]
+    fn slashing_mode(handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        handle.record_db_read::<Runtime>(SlashingModeOption::max_encoded_len())?;
+
+        Ok(slashing_mode_to_u8(SlashingMode::<Runtime>::get()))
+    }
+
+    /// Get the number of slash batches waiting to be reported to Ethereum
+    #[precompile::public("unreportedQueueLength()")]
+    #[precompile::view]
+    fn unreported_queue_length(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_db_read::<Runtime>(UNSENT_QUEUE_BOUNDS_READ_SIZE)?;
+
+        Ok(SlashesPallet::<Runtime>::unsent_queue_len())
+    }
+
+    /// Cancel slashes of an era that are still in their defer period
+    ///
+    /// Only callable by the governance contracts allowed by the runtime.
+    ///
+    /// Parameters:
+    /// - `era`: Era in which the slashes are applied
+    /// - `slash_indices`: Sorted, unique indices of the slashes in `pendingSlashes(era)`
+    #[precompile::public("cancelDeferredSlash(uint32,uint32[])")]
+    fn cancel_deferred_slash(
+        handle: &mut impl PrecompileHandle,
+        era: u32,
+        slash_indices: BoundedVec<u32, ConstU32<MAX_CANCELLED_SLASHES>>,
+    ) -> EvmResult {
+        Self::ensure_governance_caller(handle)?;
+
+        let call = SlashesCall::<Runtime>::cancel_deferred_slash {
+            era,
+            slash_indices: slash_indices.into(),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            frame_system::RawOrigin::Root.into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Change the slashing mode
+    ///
+    /// Only callable by the governance contracts allowed by the runtime.
+    ///
+    /// Parameters:
+    /// - `mode`: `0` to enable slashing, `1` to only log slashes, `2` to disable it
+    #[precompile::public("setSlashingMode(uint8)")]
+    fn set_slashing_mode(handle: &mut impl PrecompileHandle, mode: u8) -> EvmResult {
+        Self::ensure_governance_caller(handle)?;
+        let mode = slashing_mode_from_u8(mode)?;
+
+        let call = SlashesCall::<Runtime>::set_slashing_mode { mode };
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            frame_system::RawOrigin::Root.into(),
+            call,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    fn ensure_governance_caller(handle: &mut impl PrecompileHandle) -> EvmResult {
+        // The allowed callers may be read from storage
+        handle.record_db_read::<Runtime>(H160::len_bytes())?;
+
+        if !GovernanceCallers::contains(&handle.context().caller) {
+            return Err(PrecompileError::BadOrigin.into());
+        }
+
+        Ok(())
+    }
+}
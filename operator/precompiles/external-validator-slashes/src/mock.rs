@@ -0,0 +1,317 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities and mock runtime for External Validator Slashes precompile tests
+
+use super::*;
+
+use frame_support::traits::{ConstBool, ConstU128, ConstU32, ConstU8, Everything};
+use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use pallet_external_validator_slashes::{SendMessage, SlashData};
+use pallet_external_validators::traits::{
+    ActiveEraInfo, EraIndex, EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider,
+};
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use snowbridge_outbound_queue_primitives::SendError;
+use sp_core::{H256, U256};
+use sp_runtime::BuildStorage;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        EVM: pallet_evm,
+        Timestamp: pallet_timestamp,
+        ExternalValidatorSlashes: pallet_external_validator_slashes,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+    pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeTask = RuntimeTask;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Precompiles<R> = PrecompileSetBuilder<
+    R,
+    (PrecompileAt<AddressU64<1>, ExternalValidatorSlashesPrecompile<R, IsGovernance>>,),
+>;
+
+pub type PCall = ExternalValidatorSlashesPrecompileCall<Runtime, IsGovernance>;
+
+mock_account!(SlashesPrecompile, |_| MockAccount::from_u64(1));
+mock_account!(Alice, |_| MockAccount::from_u64(2));
+mock_account!(Bob, |_| MockAccount::from_u64(3));
+mock_account!(Governance, |_| MockAccount::from_u64(100));
+
+/// Only the `Governance` account may dispatch governance actions.
+pub struct IsGovernance;
+impl Contains<H160> for IsGovernance {
+    fn contains(caller: &H160) -> bool {
+        *caller == Governance.into()
+    }
+}
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub GasLimitPovSizeRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(MAX_POV_SIZE)
+    };
+    pub GasLimitStorageGrowthRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+    };
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type CreateOriginFilter = ();
+    type CreateInnerOriginFilter = ();
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Runtime>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+    type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+    type Timestamp = Timestamp;
+    type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+    type AccountProvider = FrameSystemAccountProvider<Runtime>;
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub static ActiveEra: EraIndex = 1;
+    pub const SlashDeferDuration: EraIndex = 2;
+    pub const BondingDuration: EraIndex = 5;
+}
+
+pub struct MockEraIndexProvider;
+impl EraIndexProvider for MockEraIndexProvider {
+    fn active_era() -> ActiveEraInfo {
+        ActiveEraInfo {
+            index: ActiveEra::get(),
+            start: None,
+        }
+    }
+
+    fn era_to_session_start(era_index: EraIndex) -> Option<u32> {
+        Some(era_index)
+    }
+}
+
+pub struct MockExternalIndexProvider;
+impl ExternalIndexProvider for MockExternalIndexProvider {
+    fn get_external_index() -> u64 {
+        0
+    }
+}
+
+pub struct MockInvulnerablesProvider;
+impl InvulnerablesProvider<AccountId> for MockInvulnerablesProvider {
+    fn invulnerables() -> Vec<AccountId> {
+        vec![]
+    }
+}
+
+// Mock outbound queue: messages are never sent from these tests
+pub struct MockSendMessage;
+impl SendMessage<AccountId> for MockSendMessage {
+    type Message = ();
+    type Ticket = ();
+
+    fn build(_slashes: &Vec<SlashData<AccountId>>, _era: u32) -> Option<Self::Message> {
+        Some(())
+    }
+
+    fn validate(_message: Self::Message) -> Result<Self::Ticket, SendError> {
+        Ok(())
+    }
+
+    fn deliver(_ticket: Self::Ticket) -> Result<H256, SendError> {
+        Ok(H256::zero())
+    }
+}
+
+pub struct IdentityValidator;
+impl sp_runtime::traits::Convert<AccountId, Option<AccountId>> for IdentityValidator {
+    fn convert(account: AccountId) -> Option<AccountId> {
+        Some(account)
+    }
+}
+
+impl pallet_external_validator_slashes::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = IdentityValidator;
+    type SlashDeferDuration = SlashDeferDuration;
+    type BondingDuration = BondingDuration;
+    type OffenceHistoryDepth = ConstU32<10>;
+    type SlashId = u32;
+    type SendMessage = MockSendMessage;
+    type OutboundSchemaVersion = ConstU8<1>;
+    type OnOutboundMessageSent = ();
+    type HoldOutboundMessages = ConstBool<false>;
+    type EraIndexProvider = MockEraIndexProvider;
+    type InvulnerablesProvider = MockInvulnerablesProvider;
+    type ExternalIndexProvider = MockExternalIndexProvider;
+    type MaxSlashWad = ConstU128<50_000_000_000_000_000>;
+    type QueuedSlashesProcessedPerBlock = ConstU32<20>;
+    type MaxSlashesPerEra = ConstU32<1000>;
+    type MaxQueuedSlashes = ConstU32<200>;
+    type SlashAggregationMode = ();
+    type SlashPolicy = ();
+    type OperatorAddresses = ();
+    type MaxSlashMessageRetries = ConstU32<3>;
+    type SlashMessageRetryInterval = ConstU32<2>;
+    type MaxInboxEntries = ConstU32<3>;
+    type WeightInfo = ();
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type ForceInjectSlashOrigin = frame_system::EnsureRoot<AccountId>;
+}
+
+pub(crate) struct ExtBuilder {
+    balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> ExtBuilder {
+        ExtBuilder { balances: vec![] }
+    }
+}
+
+impl ExtBuilder {
+    #[allow(dead_code)]
+    pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: self.balances,
+            dev_accounts: Default::default(),
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet balances storage can be assimilated");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub(crate) fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
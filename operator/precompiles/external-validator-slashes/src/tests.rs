@@ -0,0 +1,359 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test suite for External Validator Slashes precompile
+
+use crate::mock::{
+    precompiles, Alice, Bob, ExtBuilder, Governance, PCall, Runtime, SlashesPrecompile,
+};
+use crate::{PendingSlash, SlashRecord};
+use datahaven_precompile_errors::PrecompileError;
+use pallet_external_validator_slashes::{
+    OffenceKind, Slash, Slashes, SlashingMode, SlashingModeOption, UnsentSlashBatch,
+    UnsentSlashTail,
+};
+use precompile_utils::prelude::{Address, BoundedVec};
+use precompile_utils::testing::*;
+use sp_core::{H160, H256};
+use sp_runtime::Perbill;
+
+/// Era in which the slashes of the tests are applied, within the defer period of the mock.
+const SLASH_ERA: u32 = 3;
+
+fn precompile_address() -> H160 {
+    SlashesPrecompile.into()
+}
+
+fn slash(validator: H160, slash_id: u32, percent: u32) -> Slash<crate::mock::AccountId, u32> {
+    Slash {
+        validator: validator.into(),
+        reporters: Default::default(),
+        slash_id,
+        percentage: Perbill::from_percent(percent),
+        confirmed: false,
+        offence_kind: OffenceKind::BabeEquivocation,
+        evidence: H256::zero(),
+    }
+}
+
+fn setup_slashes() {
+    Slashes::<Runtime>::insert(
+        SLASH_ERA,
+        vec![
+            slash(Alice.into(), 0, 10),
+            slash(Bob.into(), 1, 20),
+            slash(Alice.into(), 2, 30),
+        ],
+    );
+}
+
+fn pending_slash(validator: H160, slash_id: u32, percent: u32) -> PendingSlash {
+    PendingSlash {
+        validator: Address(validator),
+        slash_id,
+        percentage: Perbill::from_percent(percent).deconstruct(),
+        confirmed: false,
+    }
+}
+
+#[test]
+fn test_selectors() {
+    assert!(PCall::pending_slashes_selectors().contains(&0x5aeecce5));
+    assert!(PCall::slash_history_selectors().contains(&0x0ce593a2));
+    assert!(PCall::slashing_mode_selectors().contains(&0xe5495a0e));
+    assert!(PCall::unreported_queue_length_selectors().contains(&0x8ca5fbff));
+    assert!(PCall::cancel_deferred_slash_selectors().contains(&0x0dc65b9c));
+    assert!(PCall::set_slashing_mode_selectors().contains(&0x030af378));
+}
+
+#[test]
+fn test_function_modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, precompile_address());
+
+        tester.test_view_modifier(PCall::pending_slashes_selectors());
+        tester.test_view_modifier(PCall::slash_history_selectors());
+        tester.test_view_modifier(PCall::slashing_mode_selectors());
+        tester.test_view_modifier(PCall::unreported_queue_length_selectors());
+        tester.test_default_modifier(PCall::cancel_deferred_slash_selectors());
+        tester.test_default_modifier(PCall::set_slashing_mode_selectors());
+    });
+}
+
+#[test]
+fn test_pending_slashes() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_slashes();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::pending_slashes { era: SLASH_ERA },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![
+                pending_slash(Alice.into(), 0, 10),
+                pending_slash(Bob.into(), 1, 20),
+                pending_slash(Alice.into(), 2, 30),
+            ]);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::pending_slashes { era: SLASH_ERA + 1 },
+            )
+            .execute_returns(Vec::<PendingSlash>::new());
+    });
+}
+
+#[test]
+fn test_slash_history() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_slashes();
+        Slashes::<Runtime>::insert(
+            SLASH_ERA + 2,
+            vec![Slash {
+                confirmed: true,
+                offence_kind: OffenceKind::LivenessOffence,
+                ..slash(Alice.into(), 3, 5)
+            }],
+        );
+        let record = |era, percent, kind, confirmed| SlashRecord {
+            era,
+            percentage: Perbill::from_percent(percent).deconstruct(),
+            kind,
+            confirmed,
+        };
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                precompile_address(),
+                PCall::slash_history {
+                    validator: Address(Alice.into()),
+                    from_era: 0,
+                    to_era: SLASH_ERA + 2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![
+                record(SLASH_ERA, 10, 1, false),
+                record(SLASH_ERA, 30, 1, false),
+                record(SLASH_ERA + 2, 5, 0, true),
+            ]);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                precompile_address(),
+                PCall::slash_history {
+                    validator: Address(Bob.into()),
+                    from_era: SLASH_ERA + 1,
+                    to_era: SLASH_ERA + 2,
+                },
+            )
+            .execute_returns(Vec::<SlashRecord>::new());
+    });
+}
+
+#[test]
+fn test_slash_history_era_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::slash_history {
+                    validator: Address(Alice.into()),
+                    from_era: 2,
+                    to_era: 1,
+                },
+            )
+            .execute_reverts(|output| output == b"toEra: Era range is empty");
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::slash_history {
+                    validator: Address(Alice.into()),
+                    from_era: 0,
+                    to_era: crate::MAX_SLASH_HISTORY_ERAS,
+                },
+            )
+            .execute_reverts(|output| output == b"toEra: Era range is too large");
+    });
+}
+
+#[test]
+fn test_slashing_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::slashing_mode {})
+            .expect_no_logs()
+            .execute_returns(0u8);
+
+        SlashingMode::<Runtime>::put(SlashingModeOption::LogOnly);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::slashing_mode {})
+            .execute_returns(1u8);
+    });
+}
+
+#[test]
+fn test_unreported_queue_length() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::unreported_queue_length {},
+            )
+            .execute_returns(0u32);
+
+        UnsentSlashBatch::<Runtime>::insert(0, (1, vec![slash(Alice.into(), 0, 10)]));
+        UnsentSlashBatch::<Runtime>::insert(1, (2, vec![slash(Bob.into(), 1, 20)]));
+        UnsentSlashTail::<Runtime>::put(2);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::unreported_queue_length {},
+            )
+            .execute_returns(2u32);
+    });
+}
+
+#[test]
+fn test_cancel_deferred_slash() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_slashes();
+
+        precompiles()
+            .prepare_test(
+                Governance,
+                precompile_address(),
+                PCall::cancel_deferred_slash {
+                    era: SLASH_ERA,
+                    slash_indices: BoundedVec::from(vec![0, 2]),
+                },
+            )
+            .execute_returns(());
+
+        assert_eq!(
+            Slashes::<Runtime>::get(SLASH_ERA),
+            vec![slash(Bob.into(), 1, 20)]
+        );
+    });
+}
+
+#[test]
+fn test_cancel_deferred_slash_outside_defer_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_slashes();
+
+        precompiles()
+            .prepare_test(
+                Governance,
+                precompile_address(),
+                // Past the end of the defer period of the active era
+                PCall::cancel_deferred_slash {
+                    era: SLASH_ERA + 2,
+                    slash_indices: BoundedVec::from(vec![0]),
+                },
+            )
+            .execute_reverts(|output| {
+                core::str::from_utf8(output)
+                    .unwrap()
+                    .contains("DeferPeriodIsOver")
+            });
+    });
+}
+
+#[test]
+fn test_cancel_deferred_slash_requires_governance() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_slashes();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::cancel_deferred_slash {
+                    era: SLASH_ERA,
+                    slash_indices: BoundedVec::from(vec![0]),
+                },
+            )
+            .execute_reverts(|output| output == PrecompileError::BadOrigin.encode());
+
+        assert_eq!(Slashes::<Runtime>::get(SLASH_ERA).len(), 3);
+    });
+}
+
+#[test]
+fn test_set_slashing_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Governance,
+                precompile_address(),
+                PCall::set_slashing_mode { mode: 2 },
+            )
+            .execute_returns(());
+
+        assert_eq!(SlashingMode::<Runtime>::get(), SlashingModeOption::Disabled);
+    });
+}
+
+#[test]
+fn test_set_slashing_mode_unknown_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Governance,
+                precompile_address(),
+                PCall::set_slashing_mode { mode: 3 },
+            )
+            .execute_reverts(|output| output == b"mode: Unknown slashing mode");
+    });
+}
+
+#[test]
+fn test_set_slashing_mode_requires_governance() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                precompile_address(),
+                PCall::set_slashing_mode { mode: 2 },
+            )
+            .execute_reverts(|output| output == PrecompileError::BadOrigin.encode());
+
+        assert_eq!(SlashingMode::<Runtime>::get(), SlashingModeOption::Enabled);
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["ExternalValidatorSlashes.sol"],
+        PCall::supports_selector,
+    )
+}
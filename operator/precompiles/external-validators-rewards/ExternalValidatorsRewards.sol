@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ExternalValidatorsRewards precompile address.
+address constant EXTERNAL_VALIDATORS_REWARDS_ADDRESS = 0x000000000000000000000000000000000000081A;
+
+/// @dev The ExternalValidatorsRewards precompile instance.
+ExternalValidatorsRewards constant EXTERNAL_VALIDATORS_REWARDS_CONTRACT =
+    ExternalValidatorsRewards(EXTERNAL_VALIDATORS_REWARDS_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title External Validators Rewards Interface
+/// @notice Interface to read era rewards state and verify era reward participation proofs produced by DataHaven
+/// @custom:address 0x000000000000000000000000000000000000081A
+interface ExternalValidatorsRewards {
+    /// @notice Verify that a leaf belongs to an era rewards merkle tree
+    /// @dev The leaf is the raw SCALE-encoded (validator, rewardAddress, points) tuple, or (validator, points) for
+    /// eras that ended before reward addresses were recorded; it is hashed by the precompile
+    /// @param root Merkle root of the era rewards tree
+    /// @param proof Sibling hashes from the leaf up to (excluding) the root
+    /// @param leafIndex Position of the leaf in the tree (0-based)
+    /// @param leafCount Total number of leaves in the tree
+    /// @param leaf Raw leaf content
+    /// @return valid True if the proof is valid for the given root
+    /// @custom:selector b99a56c8
+    function verifyRewardProof(
+        bytes32 root,
+        bytes32[] memory proof,
+        uint64 leafIndex,
+        uint64 leafCount,
+        bytes memory leaf
+    ) external view returns (bool valid);
+
+    /// @notice Total reward points earned by all validators in an era
+    /// @param eraIndex The era to query
+    /// @return points Total points, 0 if the era has no points or was pruned
+    /// @custom:selector 1357b9ff
+    function eraTotalPoints(uint32 eraIndex) external view returns (uint32 points);
+
+    /// @notice Reward points earned by a validator in an era
+    /// @param eraIndex The era to query
+    /// @param validator The validator address
+    /// @return points Points of the validator, 0 if it earned none
+    /// @custom:selector d2518a0b
+    function eraValidatorPoints(uint32 eraIndex, address validator) external view returns (uint32 points);
+
+    /// @notice Merkle root of the rewards tree of an era
+    /// @param eraIndex The era to query
+    /// @return root The merkle root, zero if no validator earned points in the era
+    /// @custom:selector b1cda1e9
+    function eraRewardsRoot(uint32 eraIndex) external view returns (bytes32 root);
+
+    /// @notice Ethereum address the rewards of a validator are paid to
+    /// @dev Changes made with set_reward_address only apply to eras ending afterwards
+    /// @param validator The validator address
+    /// @return rewardAddress The reward address, the validator address itself if none was set
+    /// @custom:selector 99798509
+    function rewardAddress(address validator) external view returns (address rewardAddress);
+
+    /// @notice Inflation of an era scaled by the number of blocks produced in it
+    /// @param eraIndex The era to query
+    /// @return amount Amount of tokens minted for the era, before the treasury split
+    /// @custom:selector 97c47b8d
+    function eraScaledInflation(uint32 eraIndex) external view returns (uint256 amount);
+}
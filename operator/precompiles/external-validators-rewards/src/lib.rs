@@ -0,0 +1,211 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the External Validators Rewards pallet to the EVM layer.
+//!
+//! This precompile allows EVM smart contracts deployed on DataHaven to verify era
+//! participation proofs against the rewards merkle roots produced by the pallet, and to
+//! read the rewards state of an era without an off-chain indexer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::traits::{ConstU32, Get};
+use pallet_evm::AddressMapping;
+use pallet_external_validators_rewards::{
+    EraIndex, EraRewardPoints, Pallet as ExternalValidatorsRewardsPallet, RewardLeavesForEra,
+    RewardPointsForEra,
+};
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use snowbridge_merkle_tree::MerkleProof;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Hash;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of nodes accepted in a rewards merkle proof.
+/// A proof of this depth covers trees of up to 2^32 leaves.
+pub const MAX_PROOF_LENGTH: u32 = 32;
+
+/// Maximum size in bytes of a rewards merkle leaf.
+pub const MAX_LEAF_SIZE: u32 = 256;
+
+type GetProofLengthLimit = ConstU32<MAX_PROOF_LENGTH>;
+type GetLeafSizeLimit = ConstU32<MAX_LEAF_SIZE>;
+
+/// Gas cost of hashing `len` bytes, priced like the EVM `KECCAK256` opcode.
+fn hash_gas_cost(len: usize) -> u64 {
+    30u64.saturating_add(6u64.saturating_mul((len as u64).div_ceil(32)))
+}
+
+/// Read the reward points of `era_index`, charging for the size of the stored value.
+fn era_reward_points<Runtime>(
+    handle: &mut impl PrecompileHandle,
+    era_index: EraIndex,
+) -> EvmResult<EraRewardPoints<Runtime::AccountId>>
+where
+    Runtime: pallet_external_validators_rewards::Config + pallet_evm::Config,
+{
+    let points = RewardPointsForEra::<Runtime>::get(era_index);
+    handle.record_db_read::<Runtime>(points.encoded_size())?;
+    Ok(points)
+}
+
+/// Precompile for External Validators Rewards pallet
+pub struct ExternalValidatorsRewardsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ExternalValidatorsRewardsPrecompile<Runtime>
+where
+    Runtime: pallet_external_validators_rewards::Config + pallet_evm::Config,
+{
+    /// Verify that a leaf belongs to an era rewards merkle tree
+    ///
+    /// The leaf is hashed with the same hasher the pallet uses to build the tree, so
+    /// callers pass the raw SCALE-encoded `(validator, rewardAddress, points)` tuple, or
+    /// `(validator, points)` for eras that ended before reward addresses were recorded.
+    ///
+    /// Parameters:
+    /// - `root`: Merkle root of the era rewards tree
+    /// - `proof`: Sibling hashes from the leaf up to (excluding) the root
+    /// - `leaf_index`: Position of the leaf in the tree (0-based)
+    /// - `leaf_count`: Total number of leaves in the tree
+    /// - `leaf`: Raw leaf content
+    ///
+    /// Returns:
+    /// - `true` if the proof is valid for the given root
+    #[precompile::public("verifyRewardProof(bytes32,bytes32[],uint64,uint64,bytes)")]
+    #[precompile::view]
+    fn verify_reward_proof(
+        handle: &mut impl PrecompileHandle,
+        root: H256,
+        proof: BoundedVec<H256, GetProofLengthLimit>,
+        leaf_index: u64,
+        leaf_count: u64,
+        leaf: BoundedBytes<GetLeafSizeLimit>,
+    ) -> EvmResult<bool> {
+        let proof: Vec<H256> = proof.into();
+        let leaf: Vec<u8> = leaf.into();
+
+        // One hash for the leaf plus one per proof node (two concatenated hashes each)
+        let proof_cost = hash_gas_cost(64).saturating_mul(proof.len() as u64);
+        handle.record_cost(hash_gas_cost(leaf.len()).saturating_add(proof_cost))?;
+
+        let merkle_proof = MerkleProof {
+            root,
+            proof,
+            number_of_leaves: leaf_count,
+            leaf_index,
+            leaf: <Runtime as pallet_external_validators_rewards::Config>::Hashing::hash(&leaf),
+        };
+
+        Ok(ExternalValidatorsRewardsPallet::<Runtime>::verify_rewards_merkle_proof(merkle_proof))
+    }
+
+    /// Total reward points earned by all validators in an era
+    ///
+    /// Returns `0` for eras without points or pruned from storage.
+    #[precompile::public("eraTotalPoints(uint32)")]
+    #[precompile::view]
+    fn era_total_points(handle: &mut impl PrecompileHandle, era_index: u32) -> EvmResult<u32> {
+        Ok(era_reward_points::<Runtime>(handle, era_index)?.total)
+    }
+
+    /// Reward points earned by a validator in an era
+    ///
+    /// Returns `0` if the validator earned no points in that era.
+    #[precompile::public("eraValidatorPoints(uint32,address)")]
+    #[precompile::view]
+    fn era_validator_points(
+        handle: &mut impl PrecompileHandle,
+        era_index: u32,
+        validator: Address,
+    ) -> EvmResult<u32> {
+        let validator = Runtime::AddressMapping::into_account_id(validator.into());
+        let points = era_reward_points::<Runtime>(handle, era_index)?;
+
+        Ok(points
+            .individual
+            .get(&validator)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Merkle root of the rewards tree of an era
+    ///
+    /// Returns the zero hash if no validator earned points in that era.
+    #[precompile::public("eraRewardsRoot(uint32)")]
+    #[precompile::view]
+    fn era_rewards_root(handle: &mut impl PrecompileHandle, era_index: u32) -> EvmResult<H256> {
+        // RewardLeavesForEra, then one hash per inner node
+        let leaves = RewardLeavesForEra::<Runtime>::get(era_index);
+        handle.record_db_read::<Runtime>(leaves.encoded_size())?;
+        handle.record_cost(hash_gas_cost(64).saturating_mul(leaves.len() as u64))?;
+
+        Ok(
+            ExternalValidatorsRewardsPallet::<Runtime>::era_rewards_merkle_root(era_index)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Ethereum address the rewards of a validator are paid to
+    ///
+    /// Returns the validator address itself unless it set a different one with
+    /// `set_reward_address`. Changes only apply to eras ending afterwards.
+    #[precompile::public("rewardAddress(address)")]
+    #[precompile::view]
+    fn reward_address(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<Address> {
+        // RewardDestination
+        handle.record_db_read::<Runtime>(H160::max_encoded_len())?;
+
+        let validator = Runtime::AddressMapping::into_account_id(validator.into());
+
+        Ok(ExternalValidatorsRewardsPallet::<Runtime>::reward_address(&validator).into())
+    }
+
+    /// Inflation of an era scaled by the number of blocks produced in it
+    ///
+    /// This is the amount minted at the end of the era, before the treasury split, unless
+    /// it has been overridden by governance.
+    #[precompile::public("eraScaledInflation(uint32)")]
+    #[precompile::view]
+    fn era_scaled_inflation(handle: &mut impl PrecompileHandle, era_index: u32) -> EvmResult<U256> {
+        // BlocksProducedInEra and the base inflation
+        handle.record_db_read::<Runtime>(4)?;
+        handle.record_db_read::<Runtime>(16)?;
+
+        let base_inflation =
+            <Runtime as pallet_external_validators_rewards::Config>::EraInflationProvider::get();
+
+        Ok(
+            ExternalValidatorsRewardsPallet::<Runtime>::calculate_scaled_inflation(
+                era_index,
+                base_inflation,
+            )
+            .into(),
+        )
+    }
+}
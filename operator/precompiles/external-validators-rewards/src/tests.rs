@@ -0,0 +1,326 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test suite for External Validators Rewards precompile
+
+use crate::mock::{
+    precompiles, Alice, Bob, Charlie, ExtBuilder, ExternalValidatorsRewards, PCall,
+    RewardsPrecompile, Runtime, RuntimeOrigin, ACTIVE_ERA,
+};
+use crate::{MAX_LEAF_SIZE, MAX_PROOF_LENGTH};
+use pallet_external_validators_rewards::BlocksProducedInEra;
+use parity_scale_codec::Encode;
+use precompile_utils::prelude::{Address, BoundedBytes, BoundedVec};
+use precompile_utils::testing::*;
+use snowbridge_merkle_tree::MerkleProof;
+use sp_core::{H160, H256, U256};
+
+fn precompile_address() -> H160 {
+    RewardsPrecompile.into()
+}
+
+/// Reward Alice, Bob and Charlie in the active era and return Bob's proof and raw leaf.
+fn setup_rewards() -> (MerkleProof, Vec<u8>) {
+    ExternalValidatorsRewards::reward_by_ids([
+        (Alice.into(), 10),
+        (Bob.into(), 20),
+        (Charlie.into(), 30),
+    ]);
+
+    let proof = ExternalValidatorsRewards::generate_rewards_merkle_proof(Bob.into(), ACTIVE_ERA)
+        .expect("Bob has reward points");
+    // Bob did not set a reward address, the leaf commits to the address of its account.
+    let leaf = (H160::from(Bob), H160::from(Bob), 20u32).encode();
+
+    (proof, leaf)
+}
+
+fn verify_call(proof: &MerkleProof, leaf: Vec<u8>) -> PCall {
+    PCall::verify_reward_proof {
+        root: proof.root,
+        proof: BoundedVec::from(proof.proof.clone()),
+        leaf_index: proof.leaf_index,
+        leaf_count: proof.number_of_leaves,
+        leaf: BoundedBytes::from(leaf),
+    }
+}
+
+#[test]
+fn test_selectors() {
+    assert!(!PCall::verify_reward_proof_selectors().is_empty());
+    assert!(PCall::era_total_points_selectors().contains(&0x1357b9ff));
+    assert!(PCall::era_validator_points_selectors().contains(&0xd2518a0b));
+    assert!(PCall::era_rewards_root_selectors().contains(&0xb1cda1e9));
+    assert!(PCall::era_scaled_inflation_selectors().contains(&0x97c47b8d));
+    assert!(PCall::reward_address_selectors().contains(&0x99798509));
+}
+
+#[test]
+fn test_function_modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, precompile_address());
+
+        tester.test_view_modifier(PCall::verify_reward_proof_selectors());
+        tester.test_view_modifier(PCall::era_total_points_selectors());
+        tester.test_view_modifier(PCall::era_validator_points_selectors());
+        tester.test_view_modifier(PCall::era_rewards_root_selectors());
+        tester.test_view_modifier(PCall::era_scaled_inflation_selectors());
+        tester.test_view_modifier(PCall::reward_address_selectors());
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_valid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (proof, leaf) = setup_rewards();
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), verify_call(&proof, leaf))
+            .expect_no_logs()
+            .execute_returns(true);
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_wrong_points() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (proof, _) = setup_rewards();
+        let forged_leaf = (H160::from(Bob), 21u32).encode();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                verify_call(&proof, forged_leaf),
+            )
+            .execute_returns(false);
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_wrong_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (mut proof, leaf) = setup_rewards();
+        proof.root = H256::repeat_byte(0x11);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), verify_call(&proof, leaf))
+            .execute_returns(false);
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_index_out_of_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (mut proof, leaf) = setup_rewards();
+        proof.leaf_index = proof.number_of_leaves;
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), verify_call(&proof, leaf))
+            .execute_returns(false);
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_too_many_nodes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (proof, leaf) = setup_rewards();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::verify_reward_proof {
+                    root: proof.root,
+                    proof: BoundedVec::from(vec![H256::zero(); MAX_PROOF_LENGTH as usize + 1]),
+                    leaf_index: proof.leaf_index,
+                    leaf_count: proof.number_of_leaves,
+                    leaf: BoundedBytes::from(leaf),
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"Value is too large for length"));
+    });
+}
+
+#[test]
+fn test_verify_reward_proof_leaf_too_large() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (proof, _) = setup_rewards();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                verify_call(&proof, vec![0u8; MAX_LEAF_SIZE as usize + 1]),
+            )
+            .execute_reverts(|output| output.ends_with(b"Value is too large for length"));
+    });
+}
+
+#[test]
+fn test_era_points() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_rewards();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_total_points {
+                    era_index: ACTIVE_ERA,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(60u32);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_validator_points {
+                    era_index: ACTIVE_ERA,
+                    validator: H160::from(Charlie).into(),
+                },
+            )
+            .execute_returns(30u32);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_validator_points {
+                    era_index: ACTIVE_ERA,
+                    validator: H160::repeat_byte(0x42).into(),
+                },
+            )
+            .execute_returns(0u32);
+    });
+}
+
+#[test]
+fn test_era_points_unknown_era() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_rewards();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_total_points {
+                    era_index: ACTIVE_ERA + 1,
+                },
+            )
+            .execute_returns(0u32);
+    });
+}
+
+#[test]
+fn test_era_rewards_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (proof, _) = setup_rewards();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_rewards_root {
+                    era_index: ACTIVE_ERA,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(proof.root);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_rewards_root {
+                    era_index: ACTIVE_ERA + 1,
+                },
+            )
+            .execute_returns(H256::zero());
+    });
+}
+
+#[test]
+fn test_era_scaled_inflation() {
+    ExtBuilder::default().build().execute_with(|| {
+        // No blocks produced: minimum inflation (20%)
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_scaled_inflation {
+                    era_index: ACTIVE_ERA,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(200_000u128));
+
+        // All expected blocks produced: full inflation
+        BlocksProducedInEra::<Runtime>::insert(ACTIVE_ERA, 600);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_scaled_inflation {
+                    era_index: ACTIVE_ERA,
+                },
+            )
+            .execute_returns(U256::from(1_000_000u128));
+    });
+}
+
+#[test]
+fn test_reward_address() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Defaults to the validator address
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::reward_address {
+                    validator: H160::from(Bob).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Address::from(H160::from(Bob)));
+
+        frame_support::assert_ok!(ExternalValidatorsRewards::set_reward_address(
+            RuntimeOrigin::signed(Bob.into()),
+            H160::repeat_byte(0xaa),
+        ));
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::reward_address {
+                    validator: H160::from(Bob).into(),
+                },
+            )
+            .execute_returns(Address::from(H160::repeat_byte(0xaa)));
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["ExternalValidatorsRewards.sol"],
+        PCall::supports_selector,
+    )
+}
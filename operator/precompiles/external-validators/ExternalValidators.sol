@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ExternalValidators precompile address.
+address constant EXTERNAL_VALIDATORS_ADDRESS = 0x0000000000000000000000000000000000000821;
+
+/// @dev The ExternalValidators precompile instance.
+ExternalValidators constant EXTERNAL_VALIDATORS_CONTRACT = ExternalValidators(
+    EXTERNAL_VALIDATORS_ADDRESS
+);
+
+/// @author The DataHaven Team
+/// @title External Validators Interface
+/// @notice Interface to read the eras, active validators and reward point shares of DataHaven
+/// @custom:address 0x0000000000000000000000000000000000000821
+interface ExternalValidators {
+    /// @notice Get the active era
+    /// @return index Index of the active era
+    /// @return start Start of the era in milliseconds since the unix epoch, 0 if not set yet
+    /// @custom:selector c2f192cb
+    function activeEra() external view returns (uint32 index, uint64 start);
+
+    /// @notice Get the external index the active validators were selected at
+    /// @return index External index sent by the validator set message from Ethereum
+    /// @custom:selector 17e8d0e4
+    function externalIndex() external view returns (uint64 index);
+
+    /// @notice Get the validators of the current session
+    /// @return validators Addresses of the validators, whitelisted ones included
+    /// @custom:selector d2d49c4b
+    function activeValidators()
+        external
+        view
+        returns (address[] memory validators);
+
+    /// @notice Get the share of the reward points of an era earned by a validator
+    /// @dev Returns 0 for all values if the era has no points or was pruned from storage
+    /// @param eraIndex Index of the era
+    /// @param validator Address of the validator
+    /// @return points Reward points earned by the validator
+    /// @return total Reward points earned by all validators
+    /// @return sharePerBillion Share of the validator, in parts per billion
+    /// @custom:selector 4660b455
+    function eraPointShare(
+        uint32 eraIndex,
+        address validator
+    )
+        external
+        view
+        returns (uint32 points, uint32 total, uint32 sharePerBillion);
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the external validators and their reward points to the EVM layer.
+//!
+//! Lets restakers and Ethereum-side tooling mirrored through the EVM read the active era,
+//! the active validator set, the external index it was selected at, and the share of the
+//! era reward points earned by each validator.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::traits::{Get, ValidatorSet};
+use pallet_evm::AddressMapping;
+use pallet_external_validators::traits::{ActiveEraInfo, ExternalIndexProvider};
+use pallet_external_validators_rewards::RewardPointsForEra;
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::H160;
+use sp_runtime::Perbill;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Precompile exposing the external validators, eras and their reward points
+pub struct ExternalValidatorsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ExternalValidatorsPrecompile<Runtime>
+where
+    Runtime: pallet_external_validators::Config
+        + pallet_external_validators_rewards::Config
+        + pallet_evm::Config,
+    Runtime::AccountId: Into<H160>,
+{
+    /// Get the active era
+    ///
+    /// Returns:
+    /// - Index of the active era
+    /// - Start of the active era in milliseconds since the unix epoch, or 0 if not set yet
+    #[precompile::public("activeEra()")]
+    #[precompile::view]
+    fn active_era(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, u64)> {
+        handle.record_db_read::<Runtime>(ActiveEraInfo::max_encoded_len())?;
+
+        let active_era =
+            pallet_external_validators::Pallet::<Runtime>::active_era().unwrap_or(ActiveEraInfo {
+                index: 0,
+                start: None,
+            });
+
+        Ok((active_era.index, active_era.start.unwrap_or_default()))
+    }
+
+    /// Get the external index the active validators were selected at
+    ///
+    /// Returns:
+    /// - The external index, as sent by the validator set message from Ethereum
+    #[precompile::public("externalIndex()")]
+    #[precompile::view]
+    fn external_index(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        handle.record_db_read::<Runtime>(u64::max_encoded_len())?;
+
+        Ok(pallet_external_validators::Pallet::<Runtime>::get_external_index())
+    }
+
+    /// Get the validators of the current session
+    ///
+    /// Returns:
+    /// - Addresses of the validators, whitelisted ones included
+    #[precompile::public("activeValidators()")]
+    #[precompile::view]
+    fn active_validators(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        let max_validators =
+            <Runtime as pallet_external_validators::Config>::MaxWhitelistedValidators::get()
+                .saturating_add(
+                    <Runtime as pallet_external_validators::Config>::MaxExternalValidators::get(),
+                );
+        handle.record_db_read::<Runtime>(
+            (max_validators as usize).saturating_mul(Runtime::AccountId::max_encoded_len()),
+        )?;
+
+        Ok(
+            <Runtime as pallet_external_validators_rewards::Config>::ValidatorSet::validators()
+                .into_iter()
+                .map(|validator| Address(validator.into()))
+                .collect(),
+        )
+    }
+
+    /// Share of the reward points of an era earned by a validator
+    ///
+    /// Returns `0` for all values if the era has no points or was pruned from storage.
+    ///
+    /// Returns:
+    /// - Reward points earned by the validator
+    /// - Reward points earned by all validators
+    /// - Share of the validator, in parts per billion
+    #[precompile::public("eraPointShare(uint32,address)")]
+    #[precompile::view]
+    fn era_point_share(
+        handle: &mut impl PrecompileHandle,
+        era_index: u32,
+        validator: Address,
+    ) -> EvmResult<(u32, u32, u32)> {
+        let validator = Runtime::AddressMapping::into_account_id(validator.into());
+        let points = RewardPointsForEra::<Runtime>::get(era_index);
+        handle.record_db_read::<Runtime>(points.encoded_size())?;
+
+        let validator_points = points
+            .individual
+            .get(&validator)
+            .copied()
+            .unwrap_or_default();
+        let share = Perbill::from_rational(validator_points, points.total.max(1));
+
+        Ok((validator_points, points.total, share.deconstruct()))
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities and mock runtime for External Validators precompile tests
+
+use super::*;
+
+use frame_support::traits::{
+    ConstBool, ConstU32, ConstU8, Everything, Nothing, ValidatorRegistration,
+};
+use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use pallet_external_validators_rewards::types::{EraRewardsUtils, SendMessage};
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use snowbridge_outbound_queue_primitives::SendError;
+use sp_core::{H256, U256};
+use sp_runtime::{
+    traits::{BlakeTwo256, ConvertInto, IdentityLookup, Keccak256},
+    BuildStorage,
+};
+use sp_staking::SessionIndex;
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        EVM: pallet_evm,
+        Timestamp: pallet_timestamp,
+        ExternalValidators: pallet_external_validators,
+        ExternalValidatorsRewards: pallet_external_validators_rewards,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeTask = RuntimeTask;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Precompiles<R> =
+    PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, ExternalValidatorsPrecompile<R>>,)>;
+
+pub type PCall = ExternalValidatorsPrecompileCall<Runtime>;
+
+mock_account!(ValidatorsPrecompile, |_| MockAccount::from_u64(1));
+mock_account!(Alice, |_| MockAccount::from_u64(2));
+mock_account!(Bob, |_| MockAccount::from_u64(3));
+mock_account!(Charlie, |_| MockAccount::from_u64(4));
+mock_account!(RewardsSovereign, |_| MockAccount::from_u64(100));
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub GasLimitPovSizeRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(MAX_POV_SIZE)
+    };
+    pub GasLimitStorageGrowthRatio: u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+    };
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type CreateOriginFilter = ();
+    type CreateInnerOriginFilter = ();
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Runtime>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+    type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+    type Timestamp = Timestamp;
+    type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+    type AccountProvider = FrameSystemAccountProvider<Runtime>;
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+pub struct IsRegistered;
+impl ValidatorRegistration<AccountId> for IsRegistered {
+    fn is_registered(_id: &AccountId) -> bool {
+        true
+    }
+}
+
+parameter_types! {
+    pub const SessionsPerEra: SessionIndex = 2;
+    pub const AuthorizedOrigin: H160 = H160::repeat_byte(0x0);
+}
+
+impl pallet_external_validators::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
+    type HistoryDepth = ConstU32<84>;
+    type MaxWhitelistedValidators = ConstU32<20>;
+    type MaxExternalValidators = ConstU32<20>;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ValidatorRegistration = IsRegistered;
+    type UnixTime = Timestamp;
+    type SessionsPerEra = SessionsPerEra;
+    type OnEraStart = ();
+    type OnEraEnd = ();
+    type AuthorizedOrigin = AuthorizedOrigin;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+parameter_types! {
+    /// Validators of the current session, set by the tests.
+    pub static SessionValidators: Vec<AccountId> = vec![];
+}
+
+pub struct MockValidatorSet;
+impl ValidatorSet<AccountId> for MockValidatorSet {
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+
+    fn session_index() -> SessionIndex {
+        0
+    }
+
+    fn validators() -> Vec<Self::ValidatorId> {
+        SessionValidators::get()
+    }
+}
+
+// Mock outbound queue: messages are never sent from these tests
+pub struct MockSendMessage;
+impl SendMessage for MockSendMessage {
+    type Message = EraRewardsUtils;
+    type Ticket = EraRewardsUtils;
+
+    fn build(utils: &EraRewardsUtils) -> Option<Self::Message> {
+        Some(utils.clone())
+    }
+
+    fn validate(message: Self::Message) -> Result<Self::Ticket, SendError> {
+        Ok(message)
+    }
+
+    fn deliver(_ticket: Self::Ticket) -> Result<H256, SendError> {
+        Ok(H256::zero())
+    }
+}
+
+parameter_types! {
+    pub RewardsSovereignAccount: AccountId = RewardsSovereign.into();
+    pub const EraInflation: u128 = 1_000_000;
+}
+
+impl pallet_external_validators_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type EraIndexProvider = ExternalValidators;
+    type HistoryDepth = ConstU32<10>;
+    type EraInflationProvider = EraInflation;
+    type ExternalIndexProvider = ExternalValidators;
+    type GetWhitelistedValidators = ();
+    type ValidatorSet = MockValidatorSet;
+    type SlashingCheck = ();
+    type BasePointsPerBlock = ConstU32<320>;
+    type BlockAuthoringWeight = ();
+    type LivenessWeight = ();
+    type LivenessCheck = Nothing;
+    type FairShareCap = ();
+    type ExpectedBlocksPerEra = ConstU32<600>;
+    type MinInflationPercent = ConstU32<20>;
+    type MaxInflationPercent = ConstU32<100>;
+    type RewardsPayout = ();
+    type MaxRewardedValidatorsPerEra = ConstU32<100>;
+    type EraEndStepsPerBlock = ConstU32<1000>;
+    type OperatorAddresses = ();
+    type Hashing = Keccak256;
+    type Currency = Balances;
+    type RewardsEthereumSovereignAccount = RewardsSovereignAccount;
+    type WeightInfo = ();
+    type SendMessage = MockSendMessage;
+    type OutboundSchemaVersion = ConstU8<1>;
+    type OnOutboundMessageSent = ();
+    type HoldOutboundMessages = ConstBool<false>;
+    type HandleInflation = ();
+    type RewardsLedger = ();
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type InflationOverrideOrigin = frame_system::EnsureNever<()>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub(crate) fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test suite for External Validators precompile
+
+use crate::mock::{
+    precompiles, Alice, Bob, Charlie, ExtBuilder, PCall, Runtime, SessionValidators,
+    ValidatorsPrecompile,
+};
+use pallet_external_validators::traits::ActiveEraInfo;
+use pallet_external_validators_rewards::{EraRewardPoints, RewardPointsForEra};
+use precompile_utils::prelude::Address;
+use precompile_utils::testing::*;
+use sp_core::H160;
+
+fn precompile_address() -> H160 {
+    ValidatorsPrecompile.into()
+}
+
+#[test]
+fn test_selectors() {
+    assert!(!PCall::active_era_selectors().is_empty());
+    assert!(!PCall::external_index_selectors().is_empty());
+    assert!(!PCall::active_validators_selectors().is_empty());
+    assert!(!PCall::era_point_share_selectors().is_empty());
+}
+
+#[test]
+fn test_function_modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, precompile_address());
+
+        tester.test_view_modifier(PCall::active_era_selectors());
+        tester.test_view_modifier(PCall::external_index_selectors());
+        tester.test_view_modifier(PCall::active_validators_selectors());
+        tester.test_view_modifier(PCall::era_point_share_selectors());
+    });
+}
+
+#[test]
+fn test_active_era_at_genesis() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::active_era {})
+            .expect_no_logs()
+            .execute_returns((0u32, 0u64));
+    });
+}
+
+#[test]
+fn test_active_era_follows_pallet() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_external_validators::ActiveEra::<Runtime>::put(ActiveEraInfo {
+            index: 7,
+            start: Some(1_700_000_000_000),
+        });
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::active_era {})
+            .expect_no_logs()
+            .execute_returns((7u32, 1_700_000_000_000u64));
+    });
+}
+
+#[test]
+fn test_external_index() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_external_validators::CurrentExternalIndex::<Runtime>::put(42);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::external_index {})
+            .expect_no_logs()
+            .execute_returns(42u64);
+    });
+}
+
+#[test]
+fn test_active_validators() {
+    ExtBuilder::default().build().execute_with(|| {
+        SessionValidators::set(vec![Alice.into(), Bob.into()]);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::active_validators {})
+            .expect_no_logs()
+            .execute_returns(vec![Address(Alice.into()), Address(Bob.into())]);
+    });
+}
+
+#[test]
+fn test_era_point_share() {
+    ExtBuilder::default().build().execute_with(|| {
+        RewardPointsForEra::<Runtime>::insert(
+            1,
+            EraRewardPoints {
+                total: 60,
+                individual: [(Alice.into(), 10), (Bob.into(), 20), (Charlie.into(), 30)]
+                    .into_iter()
+                    .collect(),
+            },
+        );
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_point_share {
+                    era_index: 1,
+                    validator: Address(Bob.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns((20u32, 60u32, 333_333_333u32));
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_point_share {
+                    era_index: 1,
+                    validator: Address(Charlie.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns((30u32, 60u32, 500_000_000u32));
+    });
+}
+
+#[test]
+fn test_era_point_share_unknown_era() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::era_point_share {
+                    era_index: 3,
+                    validator: Address(Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns((0u32, 0u32, 0u32));
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["ExternalValidators.sol"],
+        PCall::supports_selector,
+    )
+}
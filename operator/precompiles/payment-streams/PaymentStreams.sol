@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The PaymentStreams precompile address.
+address constant PAYMENT_STREAMS_ADDRESS = 0x000000000000000000000000000000000000081F;
+
+/// @dev The PaymentStreams precompile instance.
+PaymentStreams constant PAYMENT_STREAMS_CONTRACT = PaymentStreams(
+    PAYMENT_STREAMS_ADDRESS
+);
+
+/// @author The DataHaven Team
+/// @title Payment Streams Interface
+/// @notice Interface to list and settle the StorageHub payment streams of an account.
+/// Streams are charged from the free balance of the account, top them up by transferring HAVE.
+/// @custom:address 0x000000000000000000000000000000000000081F
+interface PaymentStreams {
+    /// @dev A payment stream from a user to a storage provider
+    struct PaymentStream {
+        /// Provider paid by the stream
+        bytes32 provider;
+        /// Whether the stream charges a fixed rate per tick, or a price per unit provided
+        bool fixedRate;
+        /// Amount charged per tick, zero for dynamic rate streams
+        uint256 rate;
+        /// Units of storage provided, zero for fixed rate streams
+        uint256 amountProvided;
+        /// Deposit held from the user for the stream
+        uint256 deposit;
+        /// Whether the user ran out of funds to pay the stream
+        bool outOfFunds;
+    }
+
+    /// @notice Get the payment streams of a user
+    /// @dev Costs a storage read for every payment stream of the chain
+    /// @param user The account to query
+    /// @return streams The fixed rate streams of the user, followed by its dynamic rate streams
+    /// @custom:selector e01cfa8f
+    function paymentStreamsOf(
+        address user
+    ) external view returns (PaymentStream[] memory streams);
+
+    /// @notice Pay the outstanding debt of the caller to some providers and close their streams
+    /// @dev The deposits of the closed streams are released to the caller
+    /// @param providers The providers whose streams are settled
+    /// @custom:selector c309c131
+    function payOutstandingDebt(bytes32[] memory providers) external;
+}
@@ -0,0 +1,152 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the StorageHub payment streams to the EVM layer.
+//!
+//! Lets EVM wallets list the payment streams of an account and settle them. Streams are
+//! charged from the free balance of the user, so they are topped up with a plain transfer
+//! of HAVE to the account.
+//!
+//! The streams are keyed by provider first, listing the streams of a user scans every
+//! stream and charges a read for each of them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use pallet_payment_streams::{
+    types::{BalanceOf, ProviderIdFor},
+    Call as PaymentStreamsCall, DynamicRatePaymentStreams, FixedRatePaymentStreams,
+};
+use precompile_utils::prelude::*;
+use sp_core::{ConstU32, H256, U256};
+use sp_runtime::traits::Dispatchable;
+
+/// Upper bound of a payment stream entry: both hashed keys and the stream itself.
+const STREAM_READ_SIZE: usize = 128;
+
+/// Maximum number of providers settled by a single `payOutstandingDebt` call.
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
+/// Payment stream, as returned by `paymentStreamsOf(address)`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct PaymentStream {
+    /// Provider paid by the stream.
+    pub provider: H256,
+    /// Whether the stream charges a fixed rate per tick, or a price per unit provided.
+    pub fixed_rate: bool,
+    /// Amount charged per tick, zero for dynamic rate streams.
+    pub rate: U256,
+    /// Units of storage provided, zero for fixed rate streams.
+    pub amount_provided: U256,
+    /// Deposit held from the user for the stream.
+    pub deposit: U256,
+    /// Whether the user ran out of funds to pay the stream.
+    pub out_of_funds: bool,
+}
+
+/// Precompile exposing the payment streams of pallet-payment-streams
+pub struct PaymentStreamsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> PaymentStreamsPrecompile<Runtime>
+where
+    Runtime: pallet_payment_streams::Config + pallet_evm::Config,
+    BalanceOf<Runtime>: Into<U256>,
+    <Runtime as pallet_payment_streams::Config>::Units: Into<U256>,
+    ProviderIdFor<Runtime>: From<H256> + Into<H256>,
+    <Runtime as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<PaymentStreamsCall<Runtime>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Get the payment streams of a user
+    ///
+    /// Costs a storage read for every payment stream of the chain.
+    ///
+    /// Parameters:
+    /// - user: The account to query
+    ///
+    /// Returns:
+    /// - The fixed rate streams of the user, followed by its dynamic rate streams
+    #[precompile::public("paymentStreamsOf(address)")]
+    #[precompile::view]
+    fn payment_streams_of(
+        handle: &mut impl PrecompileHandle,
+        user: Address,
+    ) -> EvmResult<Vec<PaymentStream>> {
+        let user = Runtime::AddressMapping::into_account_id(user.into());
+        let mut streams = Vec::new();
+
+        for (provider, account, stream) in FixedRatePaymentStreams::<Runtime>::iter() {
+            handle.record_db_read::<Runtime>(STREAM_READ_SIZE)?;
+            if account == user {
+                streams.push(PaymentStream {
+                    provider: provider.into(),
+                    fixed_rate: true,
+                    rate: stream.rate.into(),
+                    deposit: stream.user_deposit.into(),
+                    out_of_funds: stream.out_of_funds_tick.is_some(),
+                    ..Default::default()
+                });
+            }
+        }
+        for (provider, account, stream) in DynamicRatePaymentStreams::<Runtime>::iter() {
+            handle.record_db_read::<Runtime>(STREAM_READ_SIZE)?;
+            if account == user {
+                streams.push(PaymentStream {
+                    provider: provider.into(),
+                    fixed_rate: false,
+                    amount_provided: stream.amount_provided.into(),
+                    deposit: stream.user_deposit.into(),
+                    out_of_funds: stream.out_of_funds_tick.is_some(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        Ok(streams)
+    }
+
+    /// Pay the outstanding debt of the caller to some providers and close their streams
+    ///
+    /// The deposits of the closed streams are released to the caller.
+    ///
+    /// Parameters:
+    /// - providers: The providers whose streams are settled
+    #[precompile::public("payOutstandingDebt(bytes32[])")]
+    fn pay_outstanding_debt(
+        handle: &mut impl PrecompileHandle,
+        providers: BoundedVec<H256, GetArrayLimit>,
+    ) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let providers: Vec<H256> = providers.into();
+        let call = PaymentStreamsCall::<Runtime>::pay_outstanding_debt {
+            providers: providers.into_iter().map(Into::into).collect(),
+        };
+
+        <RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The SessionKeys precompile address.
+address constant SESSION_KEYS_ADDRESS = 0x0000000000000000000000000000000000000820;
+
+/// @dev The SessionKeys precompile instance.
+SessionKeys constant SESSION_KEYS_CONTRACT = SessionKeys(SESSION_KEYS_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title SessionKeys Interface
+/// @notice Interface for validators to rotate their session keys
+/// @custom:address 0x0000000000000000000000000000000000000820
+interface SessionKeys {
+    /// @notice Set the session keys of the caller, used from the next session
+    /// @param keys The encoded session keys, as returned by author_rotateKeys or encodeKeys
+    /// @param proof The proof of ownership of the keys
+    /// @custom:selector 250e0e9f
+    function setKeys(bytes memory keys, bytes memory proof) external;
+
+    /// @notice Remove the session keys of the caller
+    /// @custom:selector c8587297
+    function purgeKeys() external;
+
+    /// @notice Get the session keys of a validator for the next session
+    /// @param who The validator to query
+    /// @return keys The encoded session keys, empty if the validator has none
+    /// @custom:selector c20454fc
+    function nextKeys(address who) external view returns (bytes memory keys);
+
+    /// @notice Encode the public keys of each key type into a bundle of session keys
+    /// @dev The keys are ordered as babe (32 bytes), grandpa (32 bytes), imOnline (32 bytes)
+    /// and beefy (33 bytes compressed ECDSA public key)
+    /// @param keys The public keys, in the order of the key types of the runtime
+    /// @return bundle The encoded session keys, to be passed to setKeys
+    /// @custom:selector 0ed60e95
+    function encodeKeys(
+        bytes[] memory keys
+    ) external view returns (bytes memory bundle);
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to let validators manage their session keys from the EVM layer.
+//!
+//! External validators operate with Ethereum keys, `setKeys` and `purgeKeys` let them rotate
+//! their session keys from their Ethereum tooling. `encodeKeys` builds the bundle expected by
+//! `setKeys` from the public keys of each key type.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use pallet_session::Call as SessionCall;
+use parity_scale_codec::{DecodeAll, Encode};
+use precompile_utils::prelude::*;
+use sp_core::ConstU32;
+use sp_runtime::traits::{Convert, Dispatchable, OpaqueKeys};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum size of an encoded bundle of session keys.
+pub const KEYS_LIMIT: u32 = 1024;
+/// Maximum size of an ownership proof.
+pub const PROOF_LIMIT: u32 = 1024;
+/// Maximum size of a public key.
+pub const KEY_LIMIT: u32 = 128;
+/// Maximum number of public keys in a bundle.
+pub const KEY_COUNT_LIMIT: u32 = 16;
+
+type GetKeysLimit = ConstU32<KEYS_LIMIT>;
+type GetProofLimit = ConstU32<PROOF_LIMIT>;
+type GetKeyLimit = ConstU32<KEY_LIMIT>;
+type GetKeyCountLimit = ConstU32<KEY_COUNT_LIMIT>;
+
+/// Precompile exposing the session keys management of pallet-session
+pub struct SessionKeysPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SessionKeysPrecompile<Runtime>
+where
+    Runtime: pallet_session::Config + pallet_evm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<SessionCall<Runtime>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Set the session keys of the caller, used from the next session
+    ///
+    /// Parameters:
+    /// - keys: The encoded session keys, as returned by `author_rotateKeys` or `encodeKeys`
+    /// - proof: The proof of ownership of the keys
+    #[precompile::public("setKeys(bytes,bytes)")]
+    fn set_keys(
+        handle: &mut impl PrecompileHandle,
+        keys: BoundedBytes<GetKeysLimit>,
+        proof: BoundedBytes<GetProofLimit>,
+    ) -> EvmResult {
+        let keys: Vec<u8> = keys.into();
+        let keys = Runtime::Keys::decode_all(&mut &keys[..])
+            .map_err(|_| RevertReason::custom("Invalid session keys").in_field("keys"))?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let call = SessionCall::<Runtime>::set_keys {
+            keys,
+            proof: proof.into(),
+        };
+
+        <RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+
+    /// Remove the session keys of the caller
+    #[precompile::public("purgeKeys()")]
+    fn purge_keys(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let call = SessionCall::<Runtime>::purge_keys {};
+
+        <RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+
+    /// Get the session keys of a validator for the next session
+    ///
+    /// Parameters:
+    /// - who: The validator to query
+    ///
+    /// Returns:
+    /// - The encoded session keys, empty if the validator has none
+    #[precompile::public("nextKeys(address)")]
+    #[precompile::view]
+    fn next_keys(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<UnboundedBytes> {
+        handle.record_db_read::<Runtime>(KEYS_LIMIT as usize)?;
+
+        let who = Runtime::AddressMapping::into_account_id(who.into());
+        let keys = Runtime::ValidatorIdOf::convert(who)
+            .and_then(pallet_session::NextKeys::<Runtime>::get)
+            .map(|keys| keys.encode())
+            .unwrap_or_default();
+
+        Ok(keys.into())
+    }
+
+    /// Encode the public keys of each key type into a bundle of session keys
+    ///
+    /// Parameters:
+    /// - keys: The public keys, in the order of the key types of the runtime
+    ///
+    /// Returns:
+    /// - The encoded session keys, to be passed to `setKeys`
+    #[precompile::public("encodeKeys(bytes[])")]
+    #[precompile::view]
+    fn encode_keys(
+        _handle: &mut impl PrecompileHandle,
+        keys: BoundedVec<BoundedBytes<GetKeyLimit>, GetKeyCountLimit>,
+    ) -> EvmResult<UnboundedBytes> {
+        let keys: Vec<Vec<u8>> = Vec::from(keys).into_iter().map(Into::into).collect();
+        let key_ids = Runtime::Keys::key_ids();
+        if keys.len() != key_ids.len() {
+            return Err(RevertReason::custom("Wrong number of keys")
+                .in_field("keys")
+                .into());
+        }
+
+        // Every key must decode at its own position, not only the concatenation as a whole.
+        let encoded = keys.concat();
+        let bundle = Runtime::Keys::decode_all(&mut &encoded[..])
+            .map_err(|_| RevertReason::custom("Invalid session keys").in_field("keys"))?;
+        for (index, (key, id)) in keys.iter().zip(key_ids).enumerate() {
+            if bundle.get_raw(*id) != key.as_slice() {
+                return Err(RevertReason::custom("Invalid key")
+                    .in_array(index)
+                    .in_field("keys")
+                    .into());
+            }
+        }
+
+        Ok(encoded.into())
+    }
+}
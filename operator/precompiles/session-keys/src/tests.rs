@@ -0,0 +1,198 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Alice validates with the dummy key 1, Bob has no session keys.
+fn ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+        .with_keys(vec![(Alice.into(), 1)])
+        .build()
+}
+
+fn next_keys(who: impl Into<AccountId>) -> Option<MockSessionKeys> {
+    pallet_session::NextKeys::<Runtime>::get(who.into())
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["SessionKeys.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn set_keys_sets_the_keys_of_the_caller() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::set_keys {
+                    keys: session_keys(2).encode().into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        assert_eq!(next_keys(Bob), Some(session_keys(2)));
+    })
+}
+
+#[test]
+fn set_keys_rotates_the_keys_of_the_caller() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::set_keys {
+                    keys: session_keys(3).encode().into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .execute_returns(());
+
+        assert_eq!(next_keys(Alice), Some(session_keys(3)));
+    })
+}
+
+#[test]
+fn set_keys_reverts_on_invalid_keys() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::set_keys {
+                    keys: vec![1u8; 7].into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .execute_reverts(|output| output == b"keys: Invalid session keys");
+
+        assert_eq!(next_keys(Bob), None);
+    })
+}
+
+#[test]
+fn set_keys_reverts_on_keys_of_another_validator() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::set_keys {
+                    keys: session_keys(1).encode().into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"DuplicatedKey\") })"));
+    })
+}
+
+#[test]
+fn purge_keys_removes_the_keys_of_the_caller() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::purge_keys {})
+            .expect_no_logs()
+            .execute_returns(());
+
+        assert_eq!(next_keys(Alice), None);
+    })
+}
+
+#[test]
+fn next_keys_returns_the_encoded_keys() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::next_keys {
+                    who: Address(Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from(session_keys(1).encode()));
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::next_keys {
+                    who: Address(Bob.into()),
+                },
+            )
+            .execute_returns(UnboundedBytes::from(Vec::<u8>::new()));
+    })
+}
+
+#[test]
+fn encode_keys_returns_the_bundle_expected_by_set_keys() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::encode_keys {
+                    keys: vec![BoundedBytes::from(2u64.to_le_bytes().to_vec())].into(),
+                },
+            )
+            .execute_returns(UnboundedBytes::from(session_keys(2).encode()));
+    })
+}
+
+#[test]
+fn encode_keys_reverts_on_wrong_number_of_keys() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::encode_keys {
+                    keys: vec![
+                        BoundedBytes::from(2u64.to_le_bytes().to_vec()),
+                        BoundedBytes::from(3u64.to_le_bytes().to_vec()),
+                    ]
+                    .into(),
+                },
+            )
+            .execute_reverts(|output| output == b"keys: Wrong number of keys");
+    })
+}
+
+#[test]
+fn encode_keys_reverts_on_invalid_keys() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::encode_keys {
+                    keys: vec![BoundedBytes::from(vec![2u8; 9])].into(),
+                },
+            )
+            .execute_reverts(|output| output == b"keys: Invalid session keys");
+    })
+}
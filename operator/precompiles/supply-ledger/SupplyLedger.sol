@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The SupplyLedger precompile address.
+address constant SUPPLY_LEDGER_ADDRESS = 0x000000000000000000000000000000000000081D;
+
+/// @dev The SupplyLedger precompile instance.
+SupplyLedger constant SUPPLY_LEDGER_CONTRACT = SupplyLedger(SUPPLY_LEDGER_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title Supply Ledger Interface
+/// @notice Interface to read the HAVE minted for rewards and bridged to Ethereum since genesis
+/// @custom:address 0x000000000000000000000000000000000000081D
+interface SupplyLedger {
+    /// @notice Get the cumulative supply counters
+    /// @return inflationMinted HAVE minted as inflation, to the rewards account and the treasury
+    /// @return rewardsSent HAVE distributed through the rewards messages sent to EigenLayer
+    /// @return locked HAVE locked by native transfers to Ethereum
+    /// @return returned HAVE unlocked by transfers back from Ethereum
+    /// @custom:selector bd98bddb
+    function supplyTotals()
+        external
+        view
+        returns (
+            uint256 inflationMinted,
+            uint256 rewardsSent,
+            uint256 locked,
+            uint256 returned
+        );
+
+    /// @notice Get the HAVE currently held on the Ethereum side of the bridge
+    /// @return amount Locked minus returned HAVE
+    /// @custom:selector 4e3b4243
+    function bridgedOut() external view returns (uint256 amount);
+}
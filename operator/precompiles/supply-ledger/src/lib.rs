@@ -0,0 +1,76 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the HAVE supply ledger to the EVM layer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use pallet_supply_ledger::SupplyTotals;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Precompile exposing the cumulative HAVE supply counters
+pub struct SupplyLedgerPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SupplyLedgerPrecompile<Runtime>
+where
+    Runtime: pallet_supply_ledger::Config + pallet_evm::Config,
+{
+    /// Get the cumulative supply counters
+    ///
+    /// Returns:
+    /// - HAVE minted as inflation
+    /// - HAVE distributed through the rewards messages
+    /// - HAVE locked by native transfers to Ethereum
+    /// - HAVE returned from Ethereum
+    #[precompile::public("supplyTotals()")]
+    #[precompile::view]
+    fn supply_totals(handle: &mut impl PrecompileHandle) -> EvmResult<(U256, U256, U256, U256)> {
+        handle.record_db_read::<Runtime>(SupplyTotals::max_encoded_len())?;
+
+        let totals = pallet_supply_ledger::Pallet::<Runtime>::supply_totals();
+
+        Ok((
+            totals.inflation_minted.into(),
+            totals.rewards_sent.into(),
+            totals.locked.into(),
+            totals.returned.into(),
+        ))
+    }
+
+    /// Get the HAVE currently held on the Ethereum side of the bridge
+    ///
+    /// Returns:
+    /// - Locked minus returned HAVE
+    #[precompile::public("bridgedOut()")]
+    #[precompile::view]
+    fn bridged_out(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(SupplyTotals::max_encoded_len())?;
+
+        Ok(pallet_supply_ledger::Pallet::<Runtime>::supply_totals()
+            .bridged_out()
+            .into())
+    }
+}
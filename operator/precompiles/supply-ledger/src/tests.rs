@@ -0,0 +1,89 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test suite for Supply Ledger precompile
+
+use crate::mock::{precompiles, Alice, ExtBuilder, PCall, SupplyLedger, SupplyLedgerAddress};
+use pallet_datahaven_native_transfer::NativeTransferLedger;
+use pallet_external_validators_rewards::types::{InflationMintResult, RewardsLedger};
+use precompile_utils::testing::*;
+use sp_core::{H160, U256};
+
+fn precompile_address() -> H160 {
+    SupplyLedgerAddress.into()
+}
+
+#[test]
+fn test_selectors() {
+    assert!(!PCall::supply_totals_selectors().is_empty());
+    assert!(!PCall::bridged_out_selectors().is_empty());
+}
+
+#[test]
+fn test_function_modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, precompile_address());
+
+        tester.test_view_modifier(PCall::supply_totals_selectors());
+        tester.test_view_modifier(PCall::bridged_out_selectors());
+    });
+}
+
+#[test]
+fn test_supply_totals_at_genesis() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::supply_totals {})
+            .expect_no_logs()
+            .execute_returns((U256::zero(), U256::zero(), U256::zero(), U256::zero()));
+    });
+}
+
+#[test]
+fn test_supply_totals_follow_pallet() {
+    ExtBuilder::default().build().execute_with(|| {
+        SupplyLedger::on_inflation_minted(
+            1,
+            &InflationMintResult {
+                rewards_amount: 900,
+                treasury_amount: 100,
+            },
+        );
+        SupplyLedger::on_rewards_sent(1, 900);
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_locked(400);
+        <SupplyLedger as NativeTransferLedger<u128>>::on_tokens_unlocked(150);
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::supply_totals {})
+            .expect_no_logs()
+            .execute_returns((
+                U256::from(1_000u32),
+                U256::from(900u32),
+                U256::from(400u32),
+                U256::from(150u32),
+            ));
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), PCall::bridged_out {})
+            .expect_no_logs()
+            .execute_returns(U256::from(250u32));
+    });
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["SupplyLedger.sol"], PCall::supports_selector)
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Vesting precompile address.
+address constant VESTING_ADDRESS = 0x000000000000000000000000000000000000081E;
+
+/// @dev The Vesting precompile instance.
+Vesting constant VESTING_CONTRACT = Vesting(VESTING_ADDRESS);
+
+/// @author The DataHaven Team
+/// @title Vesting Interface
+/// @notice Interface to read the HAVE vesting schedules of an account and unlock its vested funds
+/// @custom:address 0x000000000000000000000000000000000000081E
+interface Vesting {
+    /// @dev A vesting schedule
+    struct VestingSchedule {
+        /// Amount locked at the start of the schedule
+        uint256 locked;
+        /// Amount unlocked at each block once the schedule started
+        uint256 perBlock;
+        /// Block at which the schedule starts unlocking
+        uint32 startingBlock;
+    }
+
+    /// @notice Get the amount of the vesting schedules of an account that has vested
+    /// @dev Includes the funds already unlocked by vest(); finished schedules are removed by vest()
+    /// @param who The account to query
+    /// @return amount The vested amount
+    /// @custom:selector 9114557e
+    function vestedBalance(address who) external view returns (uint256 amount);
+
+    /// @notice Get the vesting schedules of an account
+    /// @param who The account to query
+    /// @return schedules The vesting schedules of the account
+    /// @custom:selector fdb20ccb
+    function vestingSchedules(
+        address who
+    ) external view returns (VestingSchedule[] memory schedules);
+
+    /// @notice Unlock the vested funds of the caller
+    /// @dev Reverts if the caller has no vesting schedule
+    /// @custom:selector 458efde3
+    function vest() external;
+}
@@ -0,0 +1,151 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to expose the HAVE vesting schedules to the EVM layer.
+//!
+//! Lets standard EVM wallets show the vesting status of an account and unlock the vested
+//! funds with `vest()`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Currency,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use pallet_vesting::{Call as VestingCall, VestingInfo};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{BlockNumberProvider, Dispatchable};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_vesting::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Vesting schedule, as returned by `vestingSchedules(address)`.
+#[derive(Default, solidity::Codec)]
+pub struct VestingSchedule {
+    locked: U256,
+    per_block: U256,
+    starting_block: u32,
+}
+
+/// Precompile exposing the vesting schedules of pallet-vesting
+pub struct VestingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> VestingPrecompile<Runtime>
+where
+    Runtime: pallet_vesting::Config + pallet_evm::Config,
+    BalanceOf<Runtime>: Into<U256>,
+    BlockNumberFor<Runtime>: Into<u32>,
+    <Runtime as frame_system::Config>::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<VestingCall<Runtime>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Get the amount of the vesting schedules of an account that has vested
+    ///
+    /// Includes the funds already unlocked by `vest()`. Finished schedules are removed by
+    /// `vest()`, their amount is no longer counted afterwards.
+    ///
+    /// Parameters:
+    /// - who: The account to query
+    ///
+    /// Returns:
+    /// - The vested amount
+    #[precompile::public("vestedBalance(address)")]
+    #[precompile::view]
+    fn vested_balance(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
+        let now = <Runtime as pallet_vesting::Config>::BlockNumberProvider::current_block_number();
+
+        let vested = Self::schedules(handle, who)?
+            .iter()
+            .fold(U256::zero(), |vested, schedule| {
+                let locked: U256 = schedule.locked().into();
+                let unvested: U256 = schedule
+                    .locked_at::<Runtime::BlockNumberToBalance>(now)
+                    .into();
+                vested.saturating_add(locked.saturating_sub(unvested))
+            });
+
+        Ok(vested)
+    }
+
+    /// Get the vesting schedules of an account
+    ///
+    /// Parameters:
+    /// - who: The account to query
+    ///
+    /// Returns:
+    /// - The vesting schedules: locked amount, amount unlocked per block and starting block
+    #[precompile::public("vestingSchedules(address)")]
+    #[precompile::view]
+    fn vesting_schedules(
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<Vec<VestingSchedule>> {
+        Ok(Self::schedules(handle, who)?
+            .into_iter()
+            .map(|schedule| VestingSchedule {
+                locked: schedule.locked().into(),
+                per_block: schedule.per_block().into(),
+                starting_block: schedule.starting_block().into(),
+            })
+            .collect())
+    }
+
+    /// Unlock the vested funds of the caller
+    ///
+    /// Reverts if the caller has no vesting schedule.
+    #[precompile::public("vest()")]
+    fn vest(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let call = VestingCall::<Runtime>::vest {};
+
+        <RuntimeHelper<Runtime>>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+
+    fn schedules(
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<Vec<VestingInfo<BalanceOf<Runtime>, BlockNumberFor<Runtime>>>> {
+        handle.record_db_read::<Runtime>(
+            VestingInfo::<BalanceOf<Runtime>, BlockNumberFor<Runtime>>::max_encoded_len()
+                .saturating_mul(Runtime::MAX_VESTING_SCHEDULES as usize),
+        )?;
+
+        let who = Runtime::AddressMapping::into_account_id(who.into());
+
+        Ok(pallet_vesting::Vesting::<Runtime>::get(&who)
+            .map(|schedules| schedules.into_inner())
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::VestingSchedule;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Alice vests 1000 over 10 blocks from block 1, Bob has no schedule.
+fn ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+        .with_vesting(vec![(Alice.into(), 1, 10, 0)])
+        .build()
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["Vesting.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn vesting_schedules_are_returned() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vesting_schedules {
+                    who: Address(Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![VestingSchedule {
+                locked: U256::from(1000),
+                per_block: U256::from(100),
+                starting_block: 1,
+            }]);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vesting_schedules {
+                    who: Address(Bob.into()),
+                },
+            )
+            .execute_returns(Vec::<VestingSchedule>::new());
+    })
+}
+
+#[test]
+fn vested_balance_grows_with_blocks() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vested_balance {
+                    who: Address(Alice.into()),
+                },
+            )
+            .execute_returns(U256::zero());
+
+        System::set_block_number(5);
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vested_balance {
+                    who: Address(Alice.into()),
+                },
+            )
+            .execute_returns(U256::from(400));
+
+        System::set_block_number(20);
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vested_balance {
+                    who: Address(Alice.into()),
+                },
+            )
+            .execute_returns(U256::from(1000));
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::vested_balance {
+                    who: Address(Bob.into()),
+                },
+            )
+            .execute_returns(U256::zero());
+    })
+}
+
+#[test]
+fn vest_unlocks_vested_funds() {
+    ext().execute_with(|| {
+        assert_eq!(vesting_lock(Alice), 1000);
+
+        System::set_block_number(5);
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::vest {})
+            .execute_returns(());
+        assert_eq!(vesting_lock(Alice), 600);
+
+        // The vested balance still counts the unlocked funds
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vested_balance {
+                    who: Address(Alice.into()),
+                },
+            )
+            .execute_returns(U256::from(400));
+
+        // Finished schedules are removed
+        System::set_block_number(20);
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::vest {})
+            .execute_returns(());
+        assert_eq!(vesting_lock(Alice), 0);
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::vesting_schedules {
+                    who: Address(Alice.into()),
+                },
+            )
+            .execute_returns(Vec::<VestingSchedule>::new());
+    })
+}
+
+#[test]
+fn vest_reverts_without_schedule() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Bob, Precompile1, PCall::vest {})
+            .execute_reverts(|output| output.ends_with(b"NotVesting\") })"));
+    })
+}
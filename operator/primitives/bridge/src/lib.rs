@@ -23,7 +23,7 @@ use parity_scale_codec::DecodeAll;
 use snowbridge_inbound_queue_primitives::v2::{
     EthereumAsset, Message as SnowbridgeMessage, MessageProcessor,
 };
-use sp_core::H160;
+use sp_core::{H160, H256};
 
 // Message ID. This is not expected to change and its arbitrary bytes defined here.
 // It should match the EL_MESSAGE_ID in DataHavenSnowbridgeMessages.sol
@@ -32,32 +32,101 @@ pub const EL_MESSAGE_ID: [u8; 4] = [112, 21, 0, 56]; // 0x70150038
 // Message ID for native token transfers
 pub const NATIVE_TRANSFER_MESSAGE_ID: [u8; 4] = [112, 21, 0, 57]; // 0x70150039
 
+// Message ID for deposits escrowed on Ethereum and credited as HAVE on DataHaven
+pub const HAVE_DEPOSIT_MESSAGE_ID: [u8; 4] = [112, 21, 0, 58]; // 0x7015003a
+
 #[derive(Encode, Decode)]
 pub struct Payload<T>
 where
-    T: pallet_external_validators::Config,
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
 {
     pub message_id: [u8; 4],
     pub message: Message<T>,
 }
 
+/// Versioned EigenLayer message, the first byte after the message id being the version.
+///
+/// A V1 message is the SCALE encoding of its command, so a runtime fails to decode the whole
+/// message when it does not know the command. A V2 message puts the command behind a header
+/// giving its discriminant and length, see [`CommandEnvelope`]. Both are accepted, the
+/// contracts can move to V2 once every runtime they send to decodes it.
 #[derive(Encode, Decode)]
 pub enum Message<T>
 where
-    T: pallet_external_validators::Config,
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
 {
     V1(InboundCommand<T>),
+    V2(CommandEnvelope),
+}
+
+impl<T> Message<T>
+where
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
+{
+    /// Wrap `command` in a V2 message.
+    pub fn v2(command: &InboundCommand<T>) -> Self {
+        Message::V2(CommandEnvelope::new(command))
+    }
+
+    /// The command of the message, whatever its version.
+    pub fn into_command(self) -> Result<InboundCommand<T>, DispatchError> {
+        match self {
+            Message::V1(command) => Ok(command),
+            Message::V2(envelope) => envelope.decode_command::<T>(),
+        }
+    }
+}
+
+/// Command of a V2 message: the discriminant of an [`InboundCommand`] and the SCALE encoding
+/// of its fields, prefixed with their compact length.
+///
+/// Bytes of the body left after the fields of the command are ignored, so fields appended to
+/// a command in a later version do not prevent older runtimes from decoding it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct CommandEnvelope {
+    pub command: u8,
+    pub body: Vec<u8>,
+}
+
+impl CommandEnvelope {
+    pub fn new<T>(command: &InboundCommand<T>) -> Self
+    where
+        T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
+    {
+        let encoded = command.encode();
+        Self {
+            command: encoded[0],
+            body: encoded[1..].to_vec(),
+        }
+    }
+
+    pub fn decode_command<T>(&self) -> Result<InboundCommand<T>, DispatchError>
+    where
+        T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
+    {
+        let encoded = [&[self.command][..], &self.body[..]].concat();
+        InboundCommand::<T>::decode(&mut encoded.as_slice())
+            .map_err(|_| DispatchError::Other("unable to parse the message command"))
+    }
 }
 
 #[derive(Encode, Decode)]
 pub enum InboundCommand<T>
 where
-    T: pallet_external_validators::Config,
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
 {
     ReceiveValidators {
         validators: Vec<<T as pallet_external_validators::Config>::ValidatorId>,
         external_index: u64,
     },
+    /// Cancel the deferred slashes of `era` with the given ids, vetoed by EigenLayer
+    /// governance.
+    CancelSlash {
+        era: u32,
+        slash_ids: Vec<<T as pallet_external_validator_slashes::Config>::SlashId>,
+    },
+    /// Switch slashing on, to log only or off, during incident response.
+    SetSlashingMode(pallet_external_validator_slashes::SlashingModeOption),
 }
 
 /// EigenLayer Message Processor
@@ -65,7 +134,7 @@ pub struct EigenLayerMessageProcessor<T>(PhantomData<T>);
 
 impl<T> EigenLayerMessageProcessor<T>
 where
-    T: pallet_external_validators::Config,
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
 {
     pub fn decode_message(mut payload: &[u8]) -> Result<Payload<T>, DispatchError> {
         let decode_result = Payload::<T>::decode_all(&mut payload);
@@ -79,7 +148,7 @@ where
 
 impl<T, AccountId> MessageProcessor<AccountId> for EigenLayerMessageProcessor<T>
 where
-    T: pallet_external_validators::Config,
+    T: pallet_external_validators::Config + pallet_external_validator_slashes::Config,
 {
     fn can_process_message(_who: &AccountId, message: &SnowbridgeMessage) -> bool {
         let payload = match &message.xcm {
@@ -91,7 +160,9 @@ where
         };
         let decode_result = Self::decode_message(payload.as_slice());
         if let Ok(payload) = decode_result {
-            payload.message_id == EL_MESSAGE_ID && message.origin == T::AuthorizedOrigin::get()
+            payload.message_id == EL_MESSAGE_ID
+                && message.origin == T::AuthorizedOrigin::get()
+                && payload.message.into_command().is_ok()
         } else {
             false
         }
@@ -115,27 +186,38 @@ where
             } => return Err(DispatchError::Other("Invalid Message")),
         };
         let decode_result = Self::decode_message(payload.as_slice());
-        let inner_message = if let Ok(payload) = decode_result {
-            payload.message
+        let command = if let Ok(payload) = decode_result {
+            payload.message.into_command()?
         } else {
             return Err(DispatchError::Other("unable to parse the message payload"));
         };
 
-        match inner_message {
-            Message::V1(InboundCommand::ReceiveValidators {
+        match command {
+            InboundCommand::ReceiveValidators {
                 validators,
                 external_index,
-            }) => {
+            } => {
                 pallet_external_validators::Pallet::<T>::set_external_validators_inner(
                     validators,
                     external_index,
                 )?;
-                // Return a 32-byte identifier using the message type ID
-                let mut id = [0u8; 32];
-                id[..EL_MESSAGE_ID.len()].copy_from_slice(&EL_MESSAGE_ID);
-                Ok(id)
+            }
+            InboundCommand::CancelSlash { era, slash_ids } => {
+                pallet_external_validator_slashes::Pallet::<T>::cancel_deferred_slashes_by_id(
+                    era, slash_ids,
+                )?;
+            }
+            InboundCommand::SetSlashingMode(mode) => {
+                pallet_external_validator_slashes::Pallet::<T>::set_slashing_mode_from_ethereum(
+                    mode,
+                );
             }
         }
+
+        // Return a 32-byte identifier using the message type ID
+        let mut id = [0u8; 32];
+        id[..EL_MESSAGE_ID.len()].copy_from_slice(&EL_MESSAGE_ID);
+        Ok(id)
     }
 }
 
@@ -227,3 +309,94 @@ where
         Ok(id)
     }
 }
+
+#[derive(Encode, Decode)]
+pub struct DepositPayload {
+    pub message_id: [u8; 4],
+    pub message: DepositMessage,
+}
+
+#[derive(Encode, Decode)]
+pub enum DepositMessage {
+    V1(DepositCommand),
+}
+
+#[derive(Encode, Decode)]
+pub enum DepositCommand {
+    /// Credit `amount` of HAVE to `recipient` for the deposit identified by `deposit_id`,
+    /// emitted by the deposit contract once the deposited funds are escrowed.
+    CreditDeposit {
+        deposit_id: H256,
+        recipient: H160,
+        amount: u128,
+    },
+}
+
+/// HAVE Deposit Message Processor
+/// Mints HAVE for deposits escrowed by the `DepositContract` on Ethereum. The deposit is
+/// verified by the inbound queue, which only relays messages proven against the beacon chain.
+pub struct HaveDepositMessageProcessor<T, DepositContract>(PhantomData<(T, DepositContract)>);
+
+impl<T, DepositContract> HaveDepositMessageProcessor<T, DepositContract> {
+    pub fn decode_message(mut payload: &[u8]) -> Result<DepositPayload, DispatchError> {
+        DepositPayload::decode_all(&mut payload)
+            .map_err(|_| DispatchError::Other("unable to parse the deposit payload"))
+    }
+}
+
+impl<T, DepositContract, AccountId> MessageProcessor<AccountId>
+    for HaveDepositMessageProcessor<T, DepositContract>
+where
+    T: pallet_datahaven_native_transfer::Config,
+    T::AccountId: From<H160>,
+    DepositContract: Get<H160>,
+{
+    fn can_process_message(_who: &AccountId, message: &SnowbridgeMessage) -> bool {
+        let snowbridge_inbound_queue_primitives::v2::Payload::Raw(payload) = &message.xcm else {
+            return false;
+        };
+        let deposit_contract = DepositContract::get();
+
+        deposit_contract != H160::zero()
+            && message.origin == deposit_contract
+            && Self::decode_message(payload.as_slice())
+                .is_ok_and(|payload| payload.message_id == HAVE_DEPOSIT_MESSAGE_ID)
+    }
+
+    fn process_message(
+        _who: AccountId,
+        snow_msg: SnowbridgeMessage,
+    ) -> Result<[u8; 32], DispatchError> {
+        // Defensively re-check the Ethereum origin before minting.
+        let deposit_contract = DepositContract::get();
+        if deposit_contract == H160::zero() || snow_msg.origin != deposit_contract {
+            return Err(DispatchError::Other("unauthorized deposit origin"));
+        }
+
+        let snowbridge_inbound_queue_primitives::v2::Payload::Raw(payload) = &snow_msg.xcm else {
+            return Err(DispatchError::Other("Invalid Message"));
+        };
+
+        match Self::decode_message(payload.as_slice())?.message {
+            DepositMessage::V1(DepositCommand::CreditDeposit {
+                deposit_id,
+                recipient,
+                amount,
+            }) => {
+                let amount = amount
+                    .try_into()
+                    .map_err(|_| DispatchError::Other("Amount conversion failed"))?;
+                pallet_datahaven_native_transfer::Pallet::<T>::mint_deposit(
+                    deposit_id,
+                    &T::AccountId::from(recipient),
+                    amount,
+                )?;
+
+                // Return a 32-byte identifier using the deposit message type ID
+                let mut id = [0u8; 32];
+                id[..HAVE_DEPOSIT_MESSAGE_ID.len()].copy_from_slice(&HAVE_DEPOSIT_MESSAGE_ID);
+                Ok(id)
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! EVM tracing of the DataHaven runtimes.
+//!
+//! [`EvmTracingApi`] re-executes the transactions of a block on top of its parent state and
+//! records what the EVM did, for the `debug_trace*` and `trace_filter` RPC methods of the
+//! node. Recording relies on the tracing events of the `evm` crate, which slow down every
+//! execution, so only runtimes built with the `evm-tracing` feature record traces. Nodes
+//! serving the tracing methods run those runtimes through `--wasm-runtime-overrides`; the
+//! regular runtimes return [`TraceError::TracingDisabled`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_runtime::RuntimeDebug;
+
+#[cfg(feature = "evm-tracing")]
+pub mod tracer;
+
+/// Maximum number of opcodes recorded by [`TraceType::StructLogs`] for one transaction, the
+/// whole trace being returned at once.
+pub const MAX_STRUCT_LOGS: u32 = 100_000;
+
+/// What to record of an EVM execution.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceType {
+    /// The call tree, as the `callTracer` of geth and the Parity traces.
+    CallList,
+    /// Every executed opcode, as the default struct logger of geth.
+    StructLogs {
+        /// Do not record the stack of each step.
+        disable_stack: bool,
+        /// Record the memory of each step.
+        enable_memory: bool,
+    },
+}
+
+/// Kind of a call frame.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum CallType {
+    Call,
+    CallCode,
+    DelegateCall,
+    StaticCall,
+    Create,
+    Create2,
+    SelfDestruct,
+}
+
+/// A call, contract creation or self destruct of a transaction.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    pub call_type: CallType,
+    /// Caller, or the destructed contract for [`CallType::SelfDestruct`].
+    pub from: H160,
+    /// Called code, created contract, or beneficiary of a self destruct.
+    pub to: H160,
+    /// Transferred value, `None` for delegate and static calls.
+    pub value: Option<U256>,
+    /// Gas given to the frame, the gas limit of the transaction for the first one.
+    pub gas: u64,
+    /// Gas used by the frame, after refunds for the first one.
+    pub gas_used: u64,
+    /// Call data or init code.
+    pub input: Vec<u8>,
+    /// Returned data, the deployed code for a successful creation.
+    pub output: Vec<u8>,
+    /// Reason of the failure, `None` if the frame succeeded.
+    pub error: Option<String>,
+    /// Indices of the frame and of its ancestors among their siblings, empty for the first
+    /// frame.
+    pub trace_address: Vec<u32>,
+    /// Number of direct subcalls.
+    pub subtraces: u32,
+}
+
+/// An executed opcode.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub struct StructLog {
+    pub pc: u64,
+    pub op: u8,
+    /// Gas left before the opcode.
+    pub gas: u64,
+    pub gas_cost: u64,
+    /// Call depth, starting at 1.
+    pub depth: u32,
+    /// Stack from the bottom, unless disabled.
+    pub stack: Option<Vec<H256>>,
+    /// Memory, if enabled.
+    pub memory: Option<Vec<u8>>,
+}
+
+/// Trace of a transaction.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub enum Trace {
+    /// Call frames in execution order, each frame before its subcalls.
+    CallList(Vec<CallFrame>),
+    StructLogs {
+        /// Gas used by the transaction, after refunds.
+        gas: u64,
+        failed: bool,
+        return_value: Vec<u8>,
+        logs: Vec<StructLog>,
+    },
+}
+
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceError {
+    /// The runtime was built without the `evm-tracing` feature.
+    TracingDisabled,
+    /// The transaction is not an Ethereum transaction of the block.
+    TransactionNotFound,
+    /// The transaction executed more than [`MAX_STRUCT_LOGS`] opcodes.
+    TooManySteps,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait EvmTracingApi {
+        /// Trace the Ethereum transaction `transaction_hash` of the block of `header`, whose
+        /// extrinsics are `extrinsics`. Called on the parent block, the extrinsics before the
+        /// transaction are applied first.
+        fn trace_transaction(
+            header: &Block::Header,
+            extrinsics: Vec<Block::Extrinsic>,
+            transaction_hash: H256,
+            trace_type: TraceType,
+        ) -> Result<Trace, TraceError>;
+
+        /// Trace every Ethereum transaction of the block of `header`, in block order. Called
+        /// on the parent block.
+        fn trace_block(
+            header: &Block::Header,
+            extrinsics: Vec<Block::Extrinsic>,
+            trace_type: TraceType,
+        ) -> Result<Vec<(H256, Trace)>, TraceError>;
+    }
+}
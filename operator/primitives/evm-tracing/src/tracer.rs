@@ -0,0 +1,459 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Recording of the tracing events of the `evm` crate into a [`Trace`].
+
+use crate::{CallFrame, CallType, StructLog, Trace, TraceError, TraceType, MAX_STRUCT_LOGS};
+use alloc::{format, rc::Rc, string::String, vec::Vec};
+use core::cell::RefCell;
+use evm::{
+    tracing::{Event as EvmEvent, EventListener as EvmEventListener},
+    CreateScheme, ExitError, ExitReason,
+};
+use evm_gasometer::tracing::{
+    Event as GasometerEvent, EventListener as GasometerEventListener, Snapshot,
+};
+use evm_runtime::tracing::{Event as RuntimeEvent, EventListener as RuntimeEventListener};
+use sp_core::{H160, U256};
+use sp_runtime::Saturating;
+
+/// Trace the EVM execution of `apply`, which applies one Ethereum transaction.
+pub fn trace<R>(trace_type: TraceType, apply: impl FnOnce() -> R) -> Result<Trace, TraceError> {
+    let tracer = Rc::new(RefCell::new(Tracer::new(trace_type)));
+    let mut evm_listener = Listener(tracer.clone());
+    let mut gasometer_listener = Listener(tracer.clone());
+    let mut runtime_listener = Listener(tracer.clone());
+
+    evm::tracing::using(&mut evm_listener, || {
+        evm_gasometer::tracing::using(&mut gasometer_listener, || match trace_type {
+            // The opcode events are only listened to when they are recorded.
+            TraceType::StructLogs { .. } => {
+                evm_runtime::tracing::using(&mut runtime_listener, apply);
+            }
+            TraceType::CallList => {
+                apply();
+            }
+        })
+    });
+
+    let tracer = tracer.replace(Tracer::new(trace_type));
+    tracer.into_trace()
+}
+
+/// Forwards the events of the three `evm` crates to the shared tracer.
+struct Listener(Rc<RefCell<Tracer>>);
+
+impl EvmEventListener for Listener {
+    fn event(&mut self, event: EvmEvent<'_>) {
+        self.0.borrow_mut().evm_event(event)
+    }
+}
+
+impl GasometerEventListener for Listener {
+    fn event(&mut self, event: GasometerEvent) {
+        self.0.borrow_mut().gasometer_event(event)
+    }
+}
+
+impl RuntimeEventListener for Listener {
+    fn event(&mut self, event: RuntimeEvent<'_>) {
+        self.0.borrow_mut().runtime_event(event)
+    }
+}
+
+/// A call frame being executed.
+struct Frame {
+    /// Index of the frame in `Tracer::calls`.
+    call: usize,
+    /// Address whose storage the frame runs on, the caller of its subcalls.
+    address: H160,
+    subcalls: u32,
+    /// Gas left before the first snapshot of the frame gasometer.
+    initial_gas_left: u64,
+    /// Latest snapshot of the frame gasometer.
+    snapshot: Option<Snapshot>,
+    /// Struct log of the latest opcode of the frame, whose cost is being recorded.
+    pending_log: Option<usize>,
+}
+
+impl Frame {
+    fn gas_left(&self) -> u64 {
+        self.snapshot.as_ref().map_or(self.initial_gas_left, |snapshot| {
+            snapshot
+                .gas_limit
+                .saturating_sub(snapshot.used_gas)
+                .saturating_sub(snapshot.memory_gas)
+        })
+    }
+
+    fn gas_used(&self) -> u64 {
+        self.snapshot.as_ref().map_or(0, |snapshot| {
+            snapshot.used_gas.saturating_add(snapshot.memory_gas)
+        })
+    }
+}
+
+#[derive(Default)]
+struct TransactionResult {
+    gas_used: u64,
+    failed: bool,
+    return_value: Vec<u8>,
+}
+
+struct Tracer {
+    trace_type: TraceType,
+    /// Gas limit and intrinsic gas of the transaction.
+    gas_limit: u64,
+    intrinsic_gas: u64,
+    frames: Vec<Frame>,
+    calls: Vec<CallFrame>,
+    logs: Vec<StructLog>,
+    too_many_steps: bool,
+    /// Set when the first frame exits, the events that follow are ignored.
+    result: Option<TransactionResult>,
+}
+
+impl Tracer {
+    fn new(trace_type: TraceType) -> Self {
+        Self {
+            trace_type,
+            gas_limit: 0,
+            intrinsic_gas: 0,
+            frames: Vec::new(),
+            calls: Vec::new(),
+            logs: Vec::new(),
+            too_many_steps: false,
+            result: None,
+        }
+    }
+
+    fn into_trace(self) -> Result<Trace, TraceError> {
+        match self.trace_type {
+            TraceType::CallList => Ok(Trace::CallList(self.calls)),
+            TraceType::StructLogs { .. } if self.too_many_steps => Err(TraceError::TooManySteps),
+            TraceType::StructLogs { .. } => {
+                let result = self.result.unwrap_or_default();
+                Ok(Trace::StructLogs {
+                    gas: result.gas_used,
+                    failed: result.failed,
+                    return_value: result.return_value,
+                    logs: self.logs,
+                })
+            }
+        }
+    }
+
+    fn evm_event(&mut self, event: EvmEvent<'_>) {
+        if self.result.is_some() {
+            return;
+        }
+        match event {
+            EvmEvent::TransactCall { gas_limit, .. }
+            | EvmEvent::TransactCreate { gas_limit, .. }
+            | EvmEvent::TransactCreate2 { gas_limit, .. } => {
+                self.gas_limit = saturated_u64(gas_limit);
+            }
+            EvmEvent::Call {
+                code_address,
+                transfer,
+                input,
+                target_gas,
+                is_static,
+                context,
+            }
+            | EvmEvent::PrecompileSubcall {
+                code_address,
+                transfer,
+                input,
+                target_gas,
+                is_static,
+                context,
+            } => {
+                let call_type = if is_static {
+                    CallType::StaticCall
+                } else if context.address == code_address {
+                    CallType::Call
+                } else if transfer.is_some() {
+                    CallType::CallCode
+                } else {
+                    CallType::DelegateCall
+                };
+                let value = matches!(call_type, CallType::Call | CallType::CallCode).then(|| {
+                    transfer
+                        .as_ref()
+                        .map_or(U256::zero(), |transfer| transfer.value)
+                });
+                let from = self
+                    .frames
+                    .last()
+                    .map_or(context.caller, |parent| parent.address);
+                self.enter(
+                    call_type,
+                    from,
+                    code_address,
+                    context.address,
+                    value,
+                    target_gas,
+                    input.to_vec(),
+                );
+            }
+            EvmEvent::Create {
+                caller,
+                address,
+                scheme,
+                value,
+                init_code,
+                target_gas,
+            } => {
+                let call_type = match scheme {
+                    CreateScheme::Create2 { .. } => CallType::Create2,
+                    _ => CallType::Create,
+                };
+                self.enter(
+                    call_type,
+                    caller,
+                    address,
+                    address,
+                    Some(value),
+                    target_gas,
+                    init_code.to_vec(),
+                );
+            }
+            EvmEvent::Suicide {
+                address,
+                target,
+                balance,
+            } => {
+                // Executed by the current frame, with neither gas nor subcalls of its own.
+                if let Some(trace_address) = self.next_trace_address() {
+                    self.calls.push(CallFrame {
+                        call_type: CallType::SelfDestruct,
+                        from: address,
+                        to: target,
+                        value: Some(balance),
+                        gas: 0,
+                        gas_used: 0,
+                        input: Vec::new(),
+                        output: Vec::new(),
+                        error: None,
+                        trace_address,
+                        subtraces: 0,
+                    });
+                }
+            }
+            EvmEvent::Exit {
+                reason,
+                return_value,
+            } => self.exit(reason, return_value),
+            // Events added by later versions of the `evm` crate.
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    /// Trace address of a new subcall of the current frame, counted as such. `None` outside
+    /// of any frame.
+    fn next_trace_address(&mut self) -> Option<Vec<u32>> {
+        let parent = self.frames.last_mut()?;
+        let parent_call = &mut self.calls[parent.call];
+        parent_call.subtraces.saturating_inc();
+        let mut trace_address = parent_call.trace_address.clone();
+        trace_address.push(parent.subcalls);
+        parent.subcalls.saturating_inc();
+        // The cost of the call opcode is recorded, the gas it forwards is not part of it.
+        parent.pending_log = None;
+        Some(trace_address)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn enter(
+        &mut self,
+        call_type: CallType,
+        from: H160,
+        to: H160,
+        address: H160,
+        value: Option<U256>,
+        target_gas: Option<u64>,
+        input: Vec<u8>,
+    ) {
+        let (trace_address, gas, initial_gas_left) = match self.next_trace_address() {
+            Some(trace_address) => {
+                let gas = target_gas.unwrap_or_default();
+                (trace_address, gas, gas)
+            }
+            None => (
+                Vec::new(),
+                self.gas_limit,
+                self.gas_limit.saturating_sub(self.intrinsic_gas),
+            ),
+        };
+        self.frames.push(Frame {
+            call: self.calls.len(),
+            address,
+            subcalls: 0,
+            initial_gas_left,
+            snapshot: None,
+            pending_log: None,
+        });
+        self.calls.push(CallFrame {
+            call_type,
+            from,
+            to,
+            value,
+            gas,
+            gas_used: 0,
+            input,
+            output: Vec::new(),
+            error: None,
+            trace_address,
+            subtraces: 0,
+        });
+    }
+
+    fn exit(&mut self, reason: &ExitReason, return_value: &[u8]) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+        let call = &mut self.calls[frame.call];
+        call.output = return_value.to_vec();
+        call.error = exit_error(reason);
+        // Exceptional halts consume all the gas of the frame.
+        let halted = matches!(reason, ExitReason::Error(_) | ExitReason::Fatal(_));
+
+        if !self.frames.is_empty() {
+            call.gas_used = if halted { call.gas } else { frame.gas_used() };
+            return;
+        }
+
+        call.gas_used = if halted {
+            self.gas_limit
+        } else {
+            let used = self.intrinsic_gas.saturating_add(frame.gas_used());
+            let refunded = frame
+                .snapshot
+                .map_or(0, |snapshot| snapshot.refunded_gas.max(0) as u64);
+            // Refunds are capped to a fifth of the gas used since London.
+            used.saturating_sub(refunded.min(used / 5))
+        };
+        self.result = Some(TransactionResult {
+            gas_used: call.gas_used,
+            failed: call.error.is_some(),
+            return_value: call.output.clone(),
+        });
+    }
+
+    fn gasometer_event(&mut self, event: GasometerEvent) {
+        if self.result.is_some() {
+            return;
+        }
+        let snapshot = match event {
+            GasometerEvent::RecordTransaction { cost, .. } => {
+                self.intrinsic_gas = cost;
+                return;
+            }
+            GasometerEvent::RecordCost { snapshot, .. }
+            | GasometerEvent::RecordRefund { snapshot, .. }
+            | GasometerEvent::RecordStipend { snapshot, .. }
+            | GasometerEvent::RecordDynamicCost { snapshot, .. } => snapshot,
+            // Events added by later versions of the `evm-gasometer` crate.
+            #[allow(unreachable_patterns)]
+            _ => return,
+        };
+
+        let is_first_frame = self.frames.len() == 1;
+        let (Some(snapshot), Some(frame)) = (snapshot, self.frames.last_mut()) else {
+            return;
+        };
+        frame.snapshot = Some(snapshot);
+        // The gas given to a subcall is known once its gasometer is created.
+        if !is_first_frame {
+            self.calls[frame.call].gas = snapshot.gas_limit;
+        }
+        if let Some(log) = frame.pending_log {
+            let gas_left = frame.gas_left();
+            let log = &mut self.logs[log];
+            log.gas_cost = log.gas.saturating_sub(gas_left);
+        }
+    }
+
+    fn runtime_event(&mut self, event: RuntimeEvent<'_>) {
+        let TraceType::StructLogs {
+            disable_stack,
+            enable_memory,
+        } = self.trace_type
+        else {
+            return;
+        };
+        if self.result.is_some() {
+            return;
+        }
+        let RuntimeEvent::Step {
+            opcode,
+            position: Ok(pc),
+            stack,
+            memory,
+            ..
+        } = event
+        else {
+            return;
+        };
+        if self.logs.len() >= MAX_STRUCT_LOGS as usize {
+            self.too_many_steps = true;
+            return;
+        }
+
+        let depth = self.frames.len() as u32;
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+        frame.pending_log = Some(self.logs.len());
+        self.logs.push(StructLog {
+            pc: *pc as u64,
+            op: opcode.0,
+            gas: frame.gas_left(),
+            gas_cost: 0,
+            depth,
+            stack: (!disable_stack).then(|| stack.data().clone()),
+            memory: enable_memory.then(|| memory.data().clone()),
+        });
+    }
+}
+
+fn saturated_u64(value: U256) -> u64 {
+    if value > U256::from(u64::MAX) {
+        u64::MAX
+    } else {
+        value.low_u64()
+    }
+}
+
+/// Error of a frame, worded as geth does for the common ones.
+fn exit_error(reason: &ExitReason) -> Option<String> {
+    match reason {
+        ExitReason::Succeed(_) => None,
+        ExitReason::Revert(_) => Some("execution reverted".into()),
+        ExitReason::Error(ExitError::OutOfGas) => Some("out of gas".into()),
+        ExitReason::Error(ExitError::InvalidJump) => Some("invalid jump destination".into()),
+        ExitReason::Error(ExitError::CallTooDeep) => Some("max call depth exceeded".into()),
+        ExitReason::Error(ExitError::OutOfFund) => {
+            Some("insufficient balance for transfer".into())
+        }
+        ExitReason::Error(ExitError::Other(error)) => Some(error.as_ref().into()),
+        ExitReason::Error(error) => Some(format!("{error:?}")),
+        ExitReason::Fatal(error) => Some(format!("{error:?}")),
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the StorageHub reads needed by light clients
+//!
+//! * `user_buckets`: Page through the buckets owned by an account, across all MSPs
+//! * `bucket_metadata`: Read the owner, MSP, root, size and privacy of a bucket
+//! * `bucket_file_keys`: List the file keys of the storage requests open for a bucket
+//! * `provider_capacity`: Read the total and used capacity of a storage provider
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Metadata of a bucket, as returned by `bucket_metadata`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BucketMetadata<AccountId, ProviderId, StorageDataUnit, MerkleHash> {
+    /// Account that owns the bucket.
+    pub owner: AccountId,
+    /// MSP storing the bucket, if any.
+    pub msp_id: Option<ProviderId>,
+    /// Root of the bucket's forest.
+    pub root: MerkleHash,
+    /// Total size of the files stored in the bucket.
+    pub size: StorageDataUnit,
+    /// Whether the bucket is private.
+    pub private: bool,
+}
+
+/// Capacity of a storage provider, as returned by `provider_capacity`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProviderCapacity<StorageDataUnit> {
+    /// Capacity the provider committed to.
+    pub capacity: StorageDataUnit,
+    /// Capacity already taken by stored data.
+    pub used: StorageDataUnit,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait StorageHubApi<AccountId, BucketId, ProviderId, StorageDataUnit, MerkleHash>
+    where
+        AccountId: Codec,
+        BucketId: Codec,
+        ProviderId: Codec,
+        StorageDataUnit: Codec,
+        MerkleHash: Codec,
+    {
+        /// Up to `limit` buckets owned by `user`, in storage order, starting after `start_after`.
+        fn user_buckets(user: AccountId, start_after: Option<BucketId>, limit: u32) -> Vec<BucketId>;
+
+        /// Metadata of `bucket_id`, or `None` if the bucket does not exist.
+        fn bucket_metadata(bucket_id: BucketId) -> Option<BucketMetadata<AccountId, ProviderId, StorageDataUnit, MerkleHash>>;
+
+        /// File keys of the storage requests open for `bucket_id`. Files already stored only
+        /// live in the provider's forest and are not tracked on chain.
+        fn bucket_file_keys(bucket_id: BucketId) -> Vec<MerkleHash>;
+
+        /// Total and used capacity of `provider_id`, or `None` if it is not a provider.
+        fn provider_capacity(provider_id: ProviderId) -> Option<ProviderCapacity<StorageDataUnit>>;
+    }
+}
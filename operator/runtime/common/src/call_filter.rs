@@ -0,0 +1,115 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Call filter audit runtime API
+//!
+//! Evaluates the layers of [`RuntimeCallFilter`](crate::RuntimeCallFilter) and the proxy
+//! filters one by one for a call, to tell which one rejects it when a transaction fails
+//! with `Invalid Call`.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, DecodeLimit, Encode};
+use frame_support::traits::{Contains, InstanceFilter};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Maximum nesting level accepted when decoding the audited call.
+const CALL_DECODE_DEPTH_LIMIT: u32 = 256;
+
+/// Origin class a call is evaluated for.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub enum CallOriginKind<ProxyType> {
+    /// Signed origin, subject to the base call filter.
+    Signed,
+    /// Root origin, which bypasses the base call filter.
+    Root,
+    /// Signed origin acting through `Proxy::proxy` with the given proxy type.
+    Proxy(ProxyType),
+}
+
+/// Filter layer that rejects a call.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum CallFilterLayer {
+    /// The runtime's normal call filter.
+    Normal,
+    /// The safe-mode pallet while safe mode is entered.
+    SafeMode,
+    /// The tx-pause pallet.
+    TxPause,
+    /// The filter of the proxy type.
+    Proxy,
+}
+
+/// Result of [`CallFilterApi::is_call_allowed`].
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum CallFilterOutcome {
+    /// No filter rejects the call.
+    Allowed,
+    /// The call is rejected by the given layer. Layers are evaluated in dispatch order and
+    /// only the first rejecting one is reported.
+    Blocked(CallFilterLayer),
+    /// The encoded call is not a valid call of this runtime.
+    UndecodableCall,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait CallFilterApi<ProxyType> where ProxyType: Codec {
+        /// Evaluate the call filters for the SCALE-encoded `call` dispatched by `origin_kind`.
+        fn is_call_allowed(origin_kind: CallOriginKind<ProxyType>, call: Vec<u8>) -> CallFilterOutcome;
+    }
+}
+
+/// Implementation of [`CallFilterApi::is_call_allowed`] shared by all runtimes.
+///
+/// The filters are the ones composed into the runtime's `BaseCallFilter`, passed separately so
+/// each layer can be reported.
+pub fn is_call_allowed<Call, NormalFilter, SafeModeFilter, TxPauseFilter, ProxyType>(
+    origin_kind: CallOriginKind<ProxyType>,
+    call: Vec<u8>,
+) -> CallFilterOutcome
+where
+    Call: Decode,
+    NormalFilter: Contains<Call>,
+    SafeModeFilter: Contains<Call>,
+    TxPauseFilter: Contains<Call>,
+    ProxyType: InstanceFilter<Call>,
+{
+    let Ok(call) = Call::decode_all_with_depth_limit(CALL_DECODE_DEPTH_LIMIT, &mut &call[..])
+    else {
+        return CallFilterOutcome::UndecodableCall;
+    };
+
+    if matches!(origin_kind, CallOriginKind::Root) {
+        return CallFilterOutcome::Allowed;
+    }
+
+    let blocked_by = if !NormalFilter::contains(&call) {
+        Some(CallFilterLayer::Normal)
+    } else if !SafeModeFilter::contains(&call) {
+        Some(CallFilterLayer::SafeMode)
+    } else if !TxPauseFilter::contains(&call) {
+        Some(CallFilterLayer::TxPause)
+    } else {
+        match origin_kind {
+            CallOriginKind::Proxy(proxy_type) if !proxy_type.filter(&call) => {
+                Some(CallFilterLayer::Proxy)
+            }
+            _ => None,
+        }
+    };
+
+    blocked_by.map_or(CallFilterOutcome::Allowed, CallFilterOutcome::Blocked)
+}
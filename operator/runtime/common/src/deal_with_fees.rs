@@ -32,17 +32,18 @@ where
     R: pallet_balances::Config
         + pallet_treasury::Config
         + pallet_authorship::Config
+        + pallet_supply_ledger::Config<Currency = pallet_balances::Pallet<R>>
         + frame_system::Config,
     R::AccountId: Default,
     FeesTreasuryProportion: Get<Perbill>,
 {
     fn deal_with_fees(amount: Credit<R::AccountId, pallet_balances::Pallet<R>>) {
-        // Balances pallet automatically burns dropped Credits by decreasing
-        // total_supply accordingly
+        // The supply ledger burns the credits it receives and counts them
         let treasury_proportion = FeesTreasuryProportion::get();
         let treasury_part = treasury_proportion.deconstruct();
         let burn_part = Perbill::one().deconstruct() - treasury_part;
-        let (_, to_treasury) = amount.ration(burn_part, treasury_part);
+        let (to_burn, to_treasury) = amount.ration(burn_part, treasury_part);
+        pallet_supply_ledger::Pallet::<R>::on_unbalanced(to_burn);
         ResolveTo::<TreasuryAccountId<R>, pallet_balances::Pallet<R>>::on_unbalanced(to_treasury);
     }
 
@@ -56,10 +57,16 @@ where
     R: pallet_balances::Config
         + pallet_treasury::Config
         + pallet_authorship::Config
+        + pallet_supply_ledger::Config<Currency = pallet_balances::Pallet<R>>
         + frame_system::Config,
     R::AccountId: Default,
     FeesTreasuryProportion: Get<Perbill>,
 {
+    // Used on its own, e.g. by the XCM trader, the credit is a fee.
+    fn on_nonzero_unbalanced(amount: Credit<R::AccountId, pallet_balances::Pallet<R>>) {
+        Self::deal_with_fees(amount);
+    }
+
     fn on_unbalanceds(
         mut fees_then_tips: impl Iterator<Item = Credit<R::AccountId, pallet_balances::Pallet<R>>>,
     ) {
@@ -79,16 +86,18 @@ pub struct DealWithEthereumBaseFees<R, FeesTreasuryProportion>(
 impl<R, FeesTreasuryProportion> OnUnbalanced<Credit<R::AccountId, pallet_balances::Pallet<R>>>
     for DealWithEthereumBaseFees<R, FeesTreasuryProportion>
 where
-    R: pallet_balances::Config + pallet_treasury::Config,
+    R: pallet_balances::Config
+        + pallet_treasury::Config
+        + pallet_supply_ledger::Config<Currency = pallet_balances::Pallet<R>>,
     FeesTreasuryProportion: Get<Perbill>,
 {
     fn on_nonzero_unbalanced(amount: Credit<R::AccountId, pallet_balances::Pallet<R>>) {
-        // Balances pallet automatically burns dropped Credits by decreasing
-        // total_supply accordingly
+        // The supply ledger burns the credits it receives and counts them
         let treasury_proportion = FeesTreasuryProportion::get();
         let treasury_part = treasury_proportion.deconstruct();
         let burn_part = Perbill::one().deconstruct() - treasury_part;
-        let (_, to_treasury) = amount.ration(burn_part, treasury_part);
+        let (to_burn, to_treasury) = amount.ration(burn_part, treasury_part);
+        pallet_supply_ledger::Pallet::<R>::on_unbalanced(to_burn);
         ResolveTo::<TreasuryAccountId<R>, pallet_balances::Pallet<R>>::on_unbalanced(to_treasury);
     }
 }
@@ -21,15 +21,19 @@ pub mod constants;
 pub use constants::*;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod call_filter;
 pub mod deal_with_fees;
 pub mod impl_on_charge_evm_transaction;
 pub mod inflation;
 pub mod migrations;
 pub use migrations::*;
+pub mod outbound_schema;
 pub mod rewards_adapter;
 pub mod safe_mode;
 pub use safe_mode::*;
 pub mod slashes_adapter;
+pub mod storage_footprint;
+pub mod xcm_filter;
 
 use fp_account::EthereumSignature;
 pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
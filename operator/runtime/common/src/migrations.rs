@@ -55,7 +55,8 @@
 //! - **Execution**: Successfully executed on Testnet and Stagenet
 //! - **Removed**: 2025-01 ([PR #318](https://github.com/datahaven-xyz/datahaven/pull/318))
 
-use frame_support::pallet_prelude::*;
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, weights::RuntimeDbWeight, StorageHasher};
 
 /// Maximum encoded length permitted for a migration cursor.
 pub const MIGRATION_CURSOR_MAX_LEN: u32 = 65_536;
@@ -94,3 +95,76 @@ pub type FailedMigrationHandler<SafeMode> =
         SafeMode,
         frame_support::migrations::FreezeChainOnFailedMigration,
     >;
+
+/// Storage key of the value of a runtime parameter in `pallet_parameters::Parameters`.
+///
+/// `encoded_key` is the SCALE encoding of the parameter key: the codec index of its
+/// `dynamic_pallet_params` module followed by the codec index of the parameter.
+pub fn runtime_parameter_storage_key<ParametersPallet: PalletInfoAccess>(
+    encoded_key: &[u8],
+) -> alloc::vec::Vec<u8> {
+    let mut key =
+        frame_support::storage::storage_prefix(ParametersPallet::name().as_bytes(), b"Parameters")
+            .to_vec();
+    key.extend(Blake2_128Concat::hash(encoded_key));
+    key
+}
+
+/// Value of a `Perbill` runtime parameter whose declaration was removed, read from its raw
+/// storage so it can be carried over before [`RemoveRuntimeParameter`] deletes it.
+///
+/// `EncodedKey` is the parameter key, as taken by [`runtime_parameter_storage_key`]. Falls back
+/// to `Default` when the parameter was never set, or its stored value cannot be decoded.
+pub struct StoredPerbillParameter<ParametersPallet, EncodedKey, Default>(
+    PhantomData<(ParametersPallet, EncodedKey, Default)>,
+);
+
+impl<ParametersPallet, EncodedKey, Default> Get<sp_runtime::Perbill>
+    for StoredPerbillParameter<ParametersPallet, EncodedKey, Default>
+where
+    ParametersPallet: PalletInfoAccess,
+    EncodedKey: Get<[u8; 2]>,
+    Default: Get<sp_runtime::Perbill>,
+{
+    fn get() -> sp_runtime::Perbill {
+        let encoded_key = EncodedKey::get();
+        let key = runtime_parameter_storage_key::<ParametersPallet>(&encoded_key);
+        let Some(raw) = frame_support::storage::unhashed::get_raw(&key) else {
+            return Default::get();
+        };
+
+        // The stored value starts with the same module and parameter indices as the key.
+        raw.strip_prefix(&encoded_key[..])
+            .and_then(|mut value| sp_runtime::Perbill::decode(&mut value).ok())
+            .unwrap_or_else(|| {
+                log::warn!(
+                    target: "runtime::migrations",
+                    "Cannot decode the runtime parameter {:?}, using its default",
+                    encoded_key,
+                );
+                Default::get()
+            })
+    }
+}
+
+/// Removes the stored value of a runtime parameter whose declaration was removed, which
+/// could no longer be decoded.
+///
+/// `EncodedKey` is the parameter key, as taken by [`runtime_parameter_storage_key`].
+pub struct RemoveRuntimeParameter<ParametersPallet, EncodedKey, DbWeight>(
+    PhantomData<(ParametersPallet, EncodedKey, DbWeight)>,
+);
+
+impl<ParametersPallet, EncodedKey, DbWeight> frame_support::traits::OnRuntimeUpgrade
+    for RemoveRuntimeParameter<ParametersPallet, EncodedKey, DbWeight>
+where
+    ParametersPallet: PalletInfoAccess,
+    EncodedKey: Get<[u8; 2]>,
+    DbWeight: Get<RuntimeDbWeight>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let key = runtime_parameter_storage_key::<ParametersPallet>(&EncodedKey::get());
+        frame_support::storage::unhashed::kill(&key);
+        DbWeight::get().writes(1)
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Schema versions of the payloads sent to Ethereum by the rewards and slashes adapters.
+//!
+//! The active version is stored on-chain and set by governance. A runtime upgrade adds
+//! support for a new version first; governance then switches to it once the Ethereum
+//! contracts accept it, so neither side needs a hard cutover.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Initial schema: `submitRewards` and `slashValidatorsOperator` calls on the ServiceManager.
+pub const OUTBOUND_SCHEMA_V1: u8 = 1;
+
+/// Slashing requests carry the evidence digest of each slash and are sent through
+/// `slashValidatorsOperatorWithEvidence`. Rewards are encoded as in V1.
+pub const OUTBOUND_SCHEMA_V2: u8 = 2;
+
+/// Schema versions this runtime is able to encode.
+pub const SUPPORTED_OUTBOUND_SCHEMA_VERSIONS: &[u8] = &[OUTBOUND_SCHEMA_V1, OUTBOUND_SCHEMA_V2];
+
+/// Whether the runtime knows how to encode payloads for `version`.
+pub fn is_supported_outbound_schema(version: u8) -> bool {
+    SUPPORTED_OUTBOUND_SCHEMA_VERSIONS.contains(&version)
+}
+
+/// Schema version stored in the `OutboundSchemaVersion` runtime parameter.
+///
+/// Encoded as its version number, so governance can only set a version this runtime encodes.
+/// A stored value that no longer decodes reads as the default, [`OutboundSchema::V1`].
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+pub enum OutboundSchema {
+    /// See [`OUTBOUND_SCHEMA_V1`].
+    #[default]
+    #[codec(index = 1)]
+    V1,
+    /// See [`OUTBOUND_SCHEMA_V2`].
+    #[codec(index = 2)]
+    V2,
+}
+
+impl OutboundSchema {
+    /// Version number of the schema.
+    pub const fn version(self) -> u8 {
+        match self {
+            Self::V1 => OUTBOUND_SCHEMA_V1,
+            Self::V2 => OUTBOUND_SCHEMA_V2,
+        }
+    }
+}
+
+/// Version number of the schema returned by `Schema`, for the pallets tagging their events.
+pub struct OutboundSchemaVersionOf<Schema>(core::marker::PhantomData<Schema>);
+impl<Schema: Get<OutboundSchema>> Get<u8> for OutboundSchemaVersionOf<Schema> {
+    fn get() -> u8 {
+        Schema::get().version()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schemas_encode_as_their_version() {
+        for schema in [OutboundSchema::V1, OutboundSchema::V2] {
+            assert_eq!(schema.encode(), vec![schema.version()]);
+            assert!(is_supported_outbound_schema(schema.version()));
+        }
+    }
+
+    #[test]
+    fn unknown_versions_do_not_decode() {
+        assert_eq!(
+            OutboundSchema::decode(&mut &[1u8][..]),
+            Ok(OutboundSchema::V1)
+        );
+        assert!(OutboundSchema::decode(&mut &[0u8][..]).is_err());
+        assert!(OutboundSchema::decode(&mut &[3u8][..]).is_err());
+    }
+}
@@ -20,6 +20,7 @@
 //! via Snowbridge. The adapter is configurable through the [`RewardsSubmissionConfig`]
 //! trait, allowing runtimes to provide environment-specific values.
 
+use crate::outbound_schema::{is_supported_outbound_schema, OUTBOUND_SCHEMA_V1};
 use alloc::vec;
 use alloc::vec::Vec;
 use alloy_core::{
@@ -104,6 +105,20 @@ pub trait RewardsSubmissionConfig {
         Vec::new()
     }
 
+    /// Schema version used to encode the rewards payload.
+    ///
+    /// Defaults to [`OUTBOUND_SCHEMA_V1`].
+    fn outbound_schema_version() -> u8 {
+        OUTBOUND_SCHEMA_V1
+    }
+
+    /// Gas limit of the `submitRewards` call on Ethereum.
+    ///
+    /// Defaults to [`SUBMIT_REWARDS_GAS_LIMIT`].
+    fn submit_rewards_gas_limit() -> u64 {
+        SUBMIT_REWARDS_GAS_LIMIT
+    }
+
     /// Get the rewards duration in seconds (typically 86400 = 1 day).
     fn rewards_duration() -> u32;
 
@@ -119,7 +134,8 @@ pub trait RewardsSubmissionConfig {
     /// Handle the remainder (dust) from reward distribution.
     ///
     /// Called when there is a non-zero remainder after distributing rewards
-    /// proportionally to operators. Implementations can transfer to treasury, burn, etc.
+    /// proportionally to operators, once the rewards message of the era is sent
+    /// for the first time. Implementations can transfer to treasury, burn, etc.
     fn handle_remainder(remainder: u128);
 }
 
@@ -145,6 +161,10 @@ impl<C: RewardsSubmissionConfig> SendMessage for RewardsSubmissionAdapter<C> {
     fn deliver(ticket: Self::Ticket) -> Result<H256, SendError> {
         C::OutboundQueue::deliver(ticket)
     }
+
+    fn on_rewards_sent(rewards_utils: &EraRewardsUtils) {
+        handle_rewards_remainder::<C>(rewards_utils)
+    }
 }
 
 /// Build the complete rewards outbound message using configuration from `C`.
@@ -155,6 +175,16 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
 ) -> Option<OutboundMessage> {
     let service_manager = C::service_manager_address();
     let whave_token_address = C::whave_token_address();
+    let schema_version = C::outbound_schema_version();
+
+    if !is_supported_outbound_schema(schema_version) {
+        log::error!(
+            target: LOG_TARGET,
+            "Skipping: unsupported outbound schema version {}",
+            schema_version
+        );
+        return None;
+    }
 
     if service_manager == H160::zero() {
         log::warn!(target: LOG_TARGET, "Skipping: DatahavenServiceManagerAddress is zero");
@@ -166,7 +196,7 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
         return None;
     }
 
-    let (operator_rewards, remainder) = points_to_rewards(
+    let (operator_rewards, _) = points_to_rewards(
         &rewards_utils.individual_points,
         rewards_utils.total_points,
         rewards_utils.inflation_amount,
@@ -179,11 +209,6 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
         return None;
     }
 
-    if remainder > 0 {
-        log::debug!(target: LOG_TARGET, "Reward distribution remainder (dust): {} tokens", remainder);
-        C::handle_remainder(remainder);
-    }
-
     // Sort strategies by address (required by EigenLayer)
     let mut strategies_and_multipliers = C::strategies_and_multipliers();
     strategies_and_multipliers.sort_by_key(|(strategy, _)| *strategy);
@@ -202,7 +227,7 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
     let commands = vec![Command::CallContract {
         target: service_manager,
         calldata,
-        gas: SUBMIT_REWARDS_GAS_LIMIT,
+        gas: C::submit_rewards_gas_limit(),
         value: 0,
     }]
     .try_into()
@@ -216,6 +241,25 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
     })
 }
 
+/// Hand the remainder (dust) of the rewards of an era to `C::handle_remainder`.
+///
+/// Not part of [`build_rewards_message`], as the message of an era can be built again to
+/// resend it and the remainder must only be moved once.
+fn handle_rewards_remainder<C: RewardsSubmissionConfig>(rewards_utils: &EraRewardsUtils) {
+    let Ok((_, remainder)) = points_to_rewards(
+        &rewards_utils.individual_points,
+        rewards_utils.total_points,
+        rewards_utils.inflation_amount,
+    ) else {
+        return;
+    };
+
+    if remainder > 0 {
+        log::debug!(target: LOG_TARGET, "Reward distribution remainder (dust): {} tokens", remainder);
+        C::handle_remainder(remainder);
+    }
+}
+
 /// Calculate operator reward amounts from points and total inflation.
 /// Returns a sorted list of (operator_address, amount) tuples and the remainder (dust).
 ///
@@ -355,6 +399,11 @@ mod tests {
     /// Test era start timestamp used consistently across test cases.
     const TEST_ERA_START_TIMESTAMP: u32 = 1_700_000_000;
 
+    std::thread_local! {
+        /// Remainders handed to `HappyPathConfig::handle_remainder`.
+        static TREASURY: core::cell::Cell<u128> = const { core::cell::Cell::new(0) };
+    }
+
     struct HappyPathConfig;
 
     impl RewardsSubmissionConfig for HappyPathConfig {
@@ -380,8 +429,8 @@ mod tests {
             H256::from_low_u64_be(0x4242)
         }
 
-        fn handle_remainder(_remainder: u128) {
-            // No-op in tests
+        fn handle_remainder(remainder: u128) {
+            TREASURY.with(|treasury| treasury.set(treasury.get() + remainder));
         }
     }
 
@@ -437,6 +486,36 @@ mod tests {
         }
     }
 
+    struct UnsupportedSchemaConfig;
+
+    impl RewardsSubmissionConfig for UnsupportedSchemaConfig {
+        type OutboundQueue = TestOutboundQueue;
+
+        fn outbound_schema_version() -> u8 {
+            u8::MAX
+        }
+
+        fn rewards_duration() -> u32 {
+            HappyPathConfig::rewards_duration()
+        }
+
+        fn whave_token_address() -> H160 {
+            HappyPathConfig::whave_token_address()
+        }
+
+        fn service_manager_address() -> H160 {
+            HappyPathConfig::service_manager_address()
+        }
+
+        fn rewards_agent_origin() -> H256 {
+            HappyPathConfig::rewards_agent_origin()
+        }
+
+        fn handle_remainder(_remainder: u128) {
+            panic!("remainder must not be handled for an unsupported schema");
+        }
+    }
+
     struct InvalidMultiplierConfig;
 
     impl RewardsSubmissionConfig for InvalidMultiplierConfig {
@@ -832,6 +911,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_remainder_is_handled_on_first_send_only() {
+        let rewards_utils = EraRewardsUtils {
+            era_index: 7,
+            era_start_timestamp: TEST_ERA_START_TIMESTAMP,
+            total_points: 3u128,
+            individual_points: vec![(H160::from_low_u64_be(1), 1), (H160::from_low_u64_be(2), 2)],
+            inflation_amount: 100u128,
+        };
+
+        // Building the message does not move the remainder.
+        RewardsSubmissionAdapter::<HappyPathConfig>::build(&rewards_utils)
+            .expect("Expected message to be built");
+        assert_eq!(TREASURY.with(|treasury| treasury.get()), 0);
+
+        // The first send does.
+        RewardsSubmissionAdapter::<HappyPathConfig>::on_rewards_sent(&rewards_utils);
+        assert_eq!(TREASURY.with(|treasury| treasury.get()), 1);
+
+        // Resending builds the message again and leaves the treasury unchanged.
+        RewardsSubmissionAdapter::<HappyPathConfig>::build(&rewards_utils)
+            .expect("Expected message to be built");
+        assert_eq!(TREASURY.with(|treasury| treasury.get()), 1);
+    }
+
     #[test]
     fn test_build_rewards_message_skips_on_zero_addresses() {
         let rewards_utils = EraRewardsUtils {
@@ -846,6 +950,19 @@ mod tests {
         assert!(build_rewards_message::<ZeroTokenConfig>(&rewards_utils).is_none());
     }
 
+    #[test]
+    fn test_build_rewards_message_skips_on_unsupported_schema_version() {
+        let rewards_utils = EraRewardsUtils {
+            era_index: 7,
+            era_start_timestamp: TEST_ERA_START_TIMESTAMP,
+            total_points: 3u128,
+            individual_points: vec![(H160::from_low_u64_be(1), 3)],
+            inflation_amount: 100u128,
+        };
+
+        assert!(build_rewards_message::<UnsupportedSchemaConfig>(&rewards_utils).is_none());
+    }
+
     #[test]
     fn test_build_rewards_message_skips_when_no_operator_rewards() {
         // total_points is much larger than points * inflation, so all amounts truncate to zero.
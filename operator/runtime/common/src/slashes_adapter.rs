@@ -1,7 +1,7 @@
 use alloc::vec;
 use alloc::vec::Vec;
 use alloy_core::{
-    primitives::{Address, U256},
+    primitives::{Address, FixedBytes, U256},
     sol,
     sol_types::SolCall,
 };
@@ -11,6 +11,9 @@ use snowbridge_outbound_queue_primitives::v2::{Command, Message as OutboundMessa
 use snowbridge_outbound_queue_primitives::SendError;
 use sp_core::{H160, H256};
 
+use crate::outbound_schema::{
+    is_supported_outbound_schema, OUTBOUND_SCHEMA_V1, OUTBOUND_SCHEMA_V2,
+};
 use crate::AccountId;
 
 sol! {
@@ -24,9 +27,20 @@ sol! {
 
     // function to call in the DatahavenServiceManager to process all the slashing requests (batching)
     function slashValidatorsOperator(SlashingRequest[] calldata slashings) external;
+
+    // Slashing request binding the slash to the digest of its evidence (schema V2)
+    struct SlashingRequestWithEvidence {
+        address operator;
+        address[] strategies;
+        uint256[] wadsToSlash;
+        string description;
+        bytes32 evidence;
+    }
+
+    function slashValidatorsOperatorWithEvidence(SlashingRequestWithEvidence[] calldata slashings) external;
 }
 
-/// Gas limit for the submitRewards call on Ethereum.
+/// Gas limit for the slashValidatorsOperator call on Ethereum.
 pub const SLASH_VALIDATORS_GAS_LIMIT: u64 = 1_000_000;
 
 /// Configuration for slashes submission.
@@ -46,6 +60,20 @@ pub trait SlashesSubmissionConfig {
 
     /// Get the strategies to slash.
     fn strategies() -> Vec<Address>;
+
+    /// Schema version used to encode the slashes payload.
+    ///
+    /// Defaults to [`OUTBOUND_SCHEMA_V1`].
+    fn outbound_schema_version() -> u8 {
+        OUTBOUND_SCHEMA_V1
+    }
+
+    /// Gas limit of the slashing call on Ethereum.
+    ///
+    /// Defaults to [`SLASH_VALIDATORS_GAS_LIMIT`].
+    fn slash_validators_gas_limit() -> u64 {
+        SLASH_VALIDATORS_GAS_LIMIT
+    }
 }
 
 /// Generic slashes submission adapter.
@@ -61,13 +89,27 @@ impl<C: SlashesSubmissionConfig> pallet_external_validator_slashes::SendMessage<
     type Message = OutboundMessage;
     type Ticket = OutboundMessage;
     fn build(slashes_utils: &Vec<SlashData<AccountId>>, era: u32) -> Option<Self::Message> {
+        let schema_version = C::outbound_schema_version();
+        if !is_supported_outbound_schema(schema_version) {
+            log::error!(
+                target: "slashes_send_adapter",
+                "Unsupported outbound schema version {}",
+                schema_version
+            );
+            return None;
+        }
+
         let strategies = C::strategies();
-        let calldata = encode_slashing_request(slashes_utils, strategies);
+        let calldata = if schema_version >= OUTBOUND_SCHEMA_V2 {
+            encode_slashing_request_with_evidence(slashes_utils, strategies)
+        } else {
+            encode_slashing_request(slashes_utils, strategies)
+        };
 
         let command = Command::CallContract {
             target: C::service_manager_address(),
             calldata,
-            gas: SLASH_VALIDATORS_GAS_LIMIT,
+            gas: C::slash_validators_gas_limit(),
             value: 0,
         };
         let message = OutboundMessage {
@@ -96,7 +138,9 @@ impl<C: SlashesSubmissionConfig> pallet_external_validator_slashes::SendMessage<
     }
 }
 
-fn encode_slashing_request(
+/// ABI-encode the `slashValidatorsOperator` calldata for the DataHavenServiceManager, slashing
+/// every strategy of each operator by its WAD amount.
+pub fn encode_slashing_request(
     slashes_utils: &Vec<SlashData<AccountId>>,
     strategies: Vec<Address>,
 ) -> Vec<u8> {
@@ -109,7 +153,7 @@ fn encode_slashing_request(
         let wads_to_slash = vec![U256::from(slash_operator.wad_to_slash); strategies_len];
 
         let slashing_request = SlashingRequest {
-            operator: Address::from(slash_operator.validator.0),
+            operator: Address::from(slash_operator.operator.0),
             strategies: strategies.clone(),
             wadsToSlash: wads_to_slash,
             description: slash_operator.description.clone().into(),
@@ -123,3 +167,79 @@ fn encode_slashing_request(
 
     return calldata;
 }
+
+/// ABI-encode the `slashValidatorsOperatorWithEvidence` calldata, which carries the evidence
+/// digest of each slash along with its request.
+pub fn encode_slashing_request_with_evidence(
+    slashes_utils: &Vec<SlashData<AccountId>>,
+    strategies: Vec<Address>,
+) -> Vec<u8> {
+    let strategies_len = strategies.len();
+    let slashings = slashes_utils
+        .iter()
+        .map(|slash_operator| SlashingRequestWithEvidence {
+            operator: Address::from(slash_operator.operator.0),
+            strategies: strategies.clone(),
+            wadsToSlash: vec![U256::from(slash_operator.wad_to_slash); strategies_len],
+            description: slash_operator.description.clone().into(),
+            evidence: FixedBytes(slash_operator.evidence.0),
+        })
+        .collect();
+
+    slashValidatorsOperatorWithEvidenceCall { slashings }.abi_encode()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slash_data(index: u8, evidence: H256) -> SlashData<AccountId> {
+        SlashData {
+            validator: AccountId::from(H160::repeat_byte(index)),
+            operator: H160::repeat_byte(index.wrapping_add(0x80)),
+            wad_to_slash: 1_000 * index as u128,
+            description: "BABE equivocation".into(),
+            evidence,
+        }
+    }
+
+    #[test]
+    fn test_encode_slashing_request_with_evidence() {
+        let slashes = vec![
+            slash_data(1, H256::repeat_byte(0xaa)),
+            slash_data(2, H256::zero()),
+        ];
+        let strategies = vec![Address::repeat_byte(0x5e), Address::repeat_byte(0x5f)];
+
+        let calldata = encode_slashing_request_with_evidence(&slashes, strategies.clone());
+        let decoded = slashValidatorsOperatorWithEvidenceCall::abi_decode(&calldata, true)
+            .expect("Decoding should work");
+
+        assert_eq!(decoded.slashings.len(), 2);
+        for (request, slash) in decoded.slashings.iter().zip(&slashes) {
+            assert_eq!(request.operator, Address::from(slash.operator.0));
+            assert_eq!(request.strategies, strategies);
+            assert_eq!(request.wadsToSlash, vec![U256::from(slash.wad_to_slash); 2]);
+            assert_eq!(request.description, slash.description);
+            assert_eq!(request.evidence.0, slash.evidence.0);
+        }
+    }
+
+    #[test]
+    fn test_v1_encoding_does_not_carry_evidence() {
+        let slashes = vec![slash_data(1, H256::repeat_byte(0xaa))];
+        let strategies = vec![Address::repeat_byte(0x5e)];
+
+        let calldata = encode_slashing_request(&slashes, strategies.clone());
+
+        assert_eq!(
+            calldata[..4],
+            slashValidatorsOperatorCall::SELECTOR,
+            "V1 keeps the original entry point"
+        );
+        assert_ne!(
+            calldata,
+            encode_slashing_request_with_evidence(&slashes, strategies)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage usage telemetry for the maps expected to grow the most.
+//!
+//! The `StorageFootprintApi` runtime API reports entry counts for these maps so state
+//! growth can be tracked without dumping the full state. Counting iterates the maps,
+//! so it is meant for off-chain diagnostics only and must never be called on-chain.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::traits::PalletInfoAccess;
+use pallet_datahaven_native_transfer::{Pallet as NativeTransferPallet, TransferReceipts};
+use pallet_external_validator_slashes::{Pallet as SlashesPallet, Slashes, ValidatorSlashInEra};
+use pallet_external_validators_rewards::{
+    BlocksAuthoredInSession, ClaimedRewards, Pallet as RewardsPallet, RewardPointsForEra,
+};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Number of entries held by a single storage map.
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+pub struct StorageMapFootprint {
+    /// Name of the pallet as configured in the runtime.
+    pub pallet: Vec<u8>,
+    /// Name of the storage item.
+    pub storage: Vec<u8>,
+    /// Number of entries. For maps holding collections, the number of collection items.
+    pub entries: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait StorageFootprintApi {
+        /// Approximate entry counts of the heaviest storage maps of the runtime.
+        fn storage_footprint() -> Vec<StorageMapFootprint>;
+    }
+}
+
+fn map_footprint<P: PalletInfoAccess>(storage: &str, entries: usize) -> StorageMapFootprint {
+    StorageMapFootprint {
+        pallet: P::name().as_bytes().to_vec(),
+        storage: storage.as_bytes().to_vec(),
+        entries: entries.try_into().unwrap_or(u32::MAX),
+    }
+}
+
+/// Footprint of the external validators rewards, slashes and native transfer pallets, shared
+/// by all runtimes.
+pub fn storage_footprint<Runtime>() -> Vec<StorageMapFootprint>
+where
+    Runtime: pallet_external_validators_rewards::Config
+        + pallet_external_validator_slashes::Config
+        + pallet_datahaven_native_transfer::Config,
+{
+    vec![
+        map_footprint::<RewardsPallet<Runtime>>(
+            "RewardPointsForEra",
+            RewardPointsForEra::<Runtime>::iter_values()
+                .map(|points| points.individual.len())
+                .sum(),
+        ),
+        map_footprint::<RewardsPallet<Runtime>>(
+            "BlocksAuthoredInSession",
+            BlocksAuthoredInSession::<Runtime>::iter_keys().count(),
+        ),
+        map_footprint::<RewardsPallet<Runtime>>(
+            "ClaimedRewards",
+            ClaimedRewards::<Runtime>::iter_keys().count(),
+        ),
+        map_footprint::<SlashesPallet<Runtime>>(
+            "Slashes",
+            Slashes::<Runtime>::iter_values()
+                .map(|slashes| slashes.len())
+                .sum(),
+        ),
+        map_footprint::<SlashesPallet<Runtime>>(
+            "ValidatorSlashInEra",
+            ValidatorSlashInEra::<Runtime>::iter_keys().count(),
+        ),
+        map_footprint::<NativeTransferPallet<Runtime>>(
+            "TransferReceipts",
+            TransferReceipts::<Runtime>::iter_keys().count(),
+        ),
+    ]
+}
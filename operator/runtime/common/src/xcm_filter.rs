@@ -0,0 +1,41 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+
+//! Filters for the XCM programs accounts may execute locally
+
+use frame_support::traits::Contains;
+use xcm::latest::prelude::*;
+
+/// Accepts the programs that do not contain a `BurnAsset` instruction, including in their
+/// error handler, appendix and programs executed with another origin.
+///
+/// Burning through the executor destroys native balance without going through the supply
+/// ledger, which would break its issuance invariant.
+pub struct RejectBurnAsset;
+impl<Call> Contains<(Location, Xcm<Call>)> for RejectBurnAsset {
+    fn contains((_, message): &(Location, Xcm<Call>)) -> bool {
+        !burns_assets(message)
+    }
+}
+
+fn burns_assets<Call>(message: &Xcm<Call>) -> bool {
+    message.inner().iter().any(|instruction| match instruction {
+        BurnAsset(_) => true,
+        SetErrorHandler(nested) | SetAppendix(nested) | ExecuteWithOrigin { xcm: nested, .. } => {
+            burns_assets(nested)
+        }
+        _ => false,
+    })
+}
@@ -34,6 +34,9 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 
 const MAINNET_EVM_CHAIN_ID: u64 = 55930;
 
+/// Preset of the genesis of the mainnet network.
+pub const MAINNET_RUNTIME_PRESET: &str = "mainnet";
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
     initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, BeefyId)>,
@@ -178,11 +181,42 @@ pub fn local_config_genesis() -> Value {
     )
 }
 
+/// Return the genesis config preset of the mainnet network.
+///
+/// Only the authorities, the sudo key and the beacon relayer are funded. The authorities,
+/// the sudo key and the council members are development accounts, to be replaced before
+/// launching the network with `build-spec --preset mainnet --patch <file>`.
+pub fn mainnet_config_genesis() -> Value {
+    let initial_authorities = vec![
+        authority_keys_from_seed("Alice"),
+        authority_keys_from_seed("Bob"),
+    ];
+    let mut endowed_accounts = initial_authorities
+        .iter()
+        .map(|(account, ..)| *account)
+        .chain([alith(), beacon_relayer()])
+        .collect::<Vec<_>>();
+    endowed_accounts.sort();
+
+    testnet_genesis(
+        initial_authorities,
+        // Alith is Sudo
+        alith(),
+        endowed_accounts,
+        // Treasury Council members: Baltathar, Charleth and Dorothy
+        vec![baltathar(), charleth(), dorothy()],
+        // Technical committee members: Alith and Baltathar
+        vec![alith(), baltathar()],
+        MAINNET_EVM_CHAIN_ID,
+    )
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
     let patch = match id.as_str() {
         sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
         sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+        MAINNET_RUNTIME_PRESET => mainnet_config_genesis(),
         _ => return None,
     };
     Some(
@@ -197,6 +231,7 @@ pub fn preset_names() -> Vec<PresetId> {
     vec![
         PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
         PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+        PresetId::from(MAINNET_RUNTIME_PRESET),
     ]
 }
 
@@ -237,11 +237,43 @@ pub type CheckedExtrinsic =
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 
+parameter_types! {
+    /// Key of the `InflationTreasuryProportion` runtime parameter, superseded by
+    /// `ExternalValidatorsRewards::RewardsSplit`: the `runtime_config` module, then index 38.
+    pub const InflationTreasuryProportionKey: [u8; 2] = [0, 38];
+}
+
+/// Treasury proportion of the inflation set by governance through the removed
+/// `InflationTreasuryProportion` parameter, or the default split if it was never set.
+pub type InflationTreasuryProportion = datahaven_runtime_common::migrations::StoredPerbillParameter<
+    Parameters,
+    InflationTreasuryProportionKey,
+    configs::DefaultRewardsSplit,
+>;
+
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = (pallet_file_system::migrations::v1::MigrateV0ToV1<Runtime>,);
+type Migrations = (
+    pallet_file_system::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_external_validator_slashes::migrations::MigrateV0ToV1<Runtime>,
+    pallet_external_validator_slashes::migrations::MigrateV1ToV2<Runtime>,
+    // Carries the treasury proportion over before its runtime parameter is removed below.
+    pallet_external_validators_rewards::migrations::MigrateV1ToV2<
+        Runtime,
+        InflationTreasuryProportion,
+    >,
+    datahaven_runtime_common::migrations::RemoveRuntimeParameter<
+        Parameters,
+        InflationTreasuryProportionKey,
+        <Runtime as frame_system::Config>::DbWeight,
+    >,
+    pallet_external_validators_rewards::migrations::MigrateV2ToV3<Runtime>,
+    pallet_supply_ledger::migrations::MigrateV0ToV1<Runtime>,
+    pallet_supply_ledger::migrations::MigrateV1ToV2<Runtime>,
+    snowbridge_pallet_inbound_queue_v2::migration::MigrateV0ToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -401,6 +433,9 @@ mod runtime {
 
     #[runtime::pallet_index(104)]
     pub type TxPause = pallet_tx_pause;
+
+    #[runtime::pallet_index(110)]
+    pub type Vesting = pallet_vesting;
     // ╚═════════════════ Polkadot SDK Utility Pallets ══════════════════╝
 
     // ╔═════════════════════════ Governance Pallets ════════════════════╗
@@ -461,6 +496,11 @@ mod runtime {
     pub type MessageQueue = pallet_message_queue;
     // ╚════════════ Polkadot SDK Utility Pallets - Block 2 ═════════════╝
 
+    // ╔══════════════════════════ XCM Pallets ══════════════════════════╗
+    #[runtime::pallet_index(75)]
+    pub type PolkadotXcm = pallet_xcm;
+    // ╚══════════════════════════ XCM Pallets ══════════════════════════╝
+
     // ╔══════════════════════ StorageHub Pallets ═══════════════════════╗
     // Start with index 80
     #[runtime::pallet_index(80)]
@@ -501,6 +541,20 @@ mod runtime {
 
     #[runtime::pallet_index(106)]
     pub type ProxyGenesisCompanion = pallet_proxy_genesis_companion;
+
+    #[runtime::pallet_index(107)]
+    pub type SupplyLedger = pallet_supply_ledger;
+
+    #[runtime::pallet_index(108)]
+    pub type EthereumGateway = pallet_ethereum_gateway;
+
+    // Reads the Ethereum block and the next fee multiplier in `on_finalize`, keep it after
+    // `Ethereum` and `TransactionPayment`.
+    #[runtime::pallet_index(109)]
+    pub type EvmCongestion = pallet_evm_congestion;
+
+    #[runtime::pallet_index(111)]
+    pub type MaintenanceMode = pallet_maintenance_mode;
     // ╚═══════════════════ DataHaven-specific Pallets ══════════════════╝
 }
 
@@ -937,6 +991,193 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<Block, AccountId> for Runtime {
+        fn era_points_paged(
+            era_index: u32,
+            start_key: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, u32)> {
+            ExternalValidatorsRewards::era_points_paged(era_index, start_key, limit)
+        }
+
+        fn era_reward_points(era_index: u32) -> (u32, Vec<(AccountId, u32)>) {
+            let points = pallet_external_validators_rewards::RewardPointsForEra::<Runtime>::get(era_index);
+            (points.total, points.individual.into_iter().collect())
+        }
+
+        fn generate_rewards_merkle_proof(
+            account: AccountId,
+            era_index: u32,
+        ) -> Option<snowbridge_merkle_tree::MerkleProof> {
+            ExternalValidatorsRewards::generate_rewards_merkle_proof(account, era_index)
+        }
+
+        fn verify_rewards_merkle_proof(proof: snowbridge_merkle_tree::MerkleProof) -> bool {
+            ExternalValidatorsRewards::verify_rewards_merkle_proof(proof)
+        }
+
+        fn validator_performance(
+            era_index: u32,
+        ) -> Vec<(AccountId, pallet_external_validators_rewards::types::ValidatorPerformance)> {
+            pallet_external_validators_rewards::ValidatorPerformanceInEra::<Runtime>::iter_prefix(era_index)
+                .collect()
+        }
+
+        fn reward_address(account: AccountId) -> H160 {
+            ExternalValidatorsRewards::reward_address(&account)
+        }
+    }
+
+    impl pallet_external_validator_slashes_runtime_api::ExternalValidatorSlashesApi<Block, AccountId, u32> for Runtime {
+        fn slashes_for_era(era: u32) -> Vec<pallet_external_validator_slashes::Slash<AccountId, u32>> {
+            pallet_external_validator_slashes::Slashes::<Runtime>::get(era).into_inner()
+        }
+
+        fn unreported_queue() -> Vec<(u32, Vec<pallet_external_validator_slashes::Slash<AccountId, u32>>)> {
+            ExternalValidatorsSlashes::unsent_queue()
+        }
+
+        fn bonded_eras() -> Vec<(u32, u32, u64)> {
+            pallet_external_validator_slashes::BondedEras::<Runtime>::get()
+        }
+
+        fn slashing_mode() -> pallet_external_validator_slashes::SlashingModeOption {
+            pallet_external_validator_slashes::SlashingMode::<Runtime>::get()
+        }
+
+        fn offence_stats(
+            from_era: u32,
+            to_era: u32,
+        ) -> Vec<(
+            pallet_external_validator_slashes::OffenceKind,
+            pallet_external_validator_slashes::OffenceKindStats,
+        )> {
+            ExternalValidatorsSlashes::offence_stats(from_era, to_era)
+        }
+
+        fn offence_history(
+            era: u32,
+        ) -> Vec<pallet_external_validator_slashes::OffenceRecord<AccountId>> {
+            pallet_external_validator_slashes::OffenceHistory::<Runtime>::get(era)
+        }
+
+        fn simulate_slash(
+            validator: AccountId,
+            offence_kind: pallet_external_validator_slashes::OffenceKind,
+            fraction: Perbill,
+            era: u32,
+        ) -> Option<pallet_external_validator_slashes::SlashData<AccountId>> {
+            ExternalValidatorsSlashes::simulate_slash(validator, offence_kind, fraction, era)
+        }
+    }
+
+    impl pallet_supply_ledger_runtime_api::SupplyLedgerApi<Block> for Runtime {
+        fn supply_totals() -> pallet_supply_ledger::SupplyTotals {
+            SupplyLedger::supply_totals()
+        }
+    }
+
+    impl pallet_outbound_commitment_store_runtime_api::OutboundCommitmentStoreApi<Block, BlockNumber> for Runtime {
+        fn commitment_history() -> Vec<pallet_outbound_commitment_store::CommitmentRecord<BlockNumber>> {
+            OutboundCommitmentStore::commitment_history()
+        }
+    }
+
+    impl pallet_evm_congestion_runtime_api::EvmCongestionApi<Block> for Runtime {
+        fn congestion_summary() -> pallet_evm_congestion::CongestionSummary {
+            EvmCongestion::congestion_summary()
+        }
+    }
+
+    impl dhp_evm_tracing::EvmTracingApi<Block> for Runtime {
+        fn trace_transaction(
+            header: &<Block as BlockT>::Header,
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            transaction_hash: H256,
+            trace_type: dhp_evm_tracing::TraceType,
+        ) -> Result<dhp_evm_tracing::Trace, dhp_evm_tracing::TraceError> {
+            #[cfg(feature = "evm-tracing")]
+            {
+                Executive::initialize_block(header);
+                for extrinsic in extrinsics {
+                    let is_traced = matches!(
+                        &extrinsic.0.function,
+                        RuntimeCall::Ethereum(transact { transaction })
+                            if transaction.hash() == transaction_hash
+                    );
+                    if is_traced {
+                        return dhp_evm_tracing::tracer::trace(trace_type, || {
+                            Executive::apply_extrinsic(extrinsic)
+                        });
+                    }
+                    let _ = Executive::apply_extrinsic(extrinsic);
+                }
+                Err(dhp_evm_tracing::TraceError::TransactionNotFound)
+            }
+            #[cfg(not(feature = "evm-tracing"))]
+            {
+                let _ = (header, extrinsics, transaction_hash, trace_type);
+                Err(dhp_evm_tracing::TraceError::TracingDisabled)
+            }
+        }
+
+        fn trace_block(
+            header: &<Block as BlockT>::Header,
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            trace_type: dhp_evm_tracing::TraceType,
+        ) -> Result<Vec<(H256, dhp_evm_tracing::Trace)>, dhp_evm_tracing::TraceError> {
+            #[cfg(feature = "evm-tracing")]
+            {
+                Executive::initialize_block(header);
+                let mut traces = Vec::new();
+                for extrinsic in extrinsics {
+                    let transaction_hash = match &extrinsic.0.function {
+                        RuntimeCall::Ethereum(transact { transaction }) => Some(transaction.hash()),
+                        _ => None,
+                    };
+                    match transaction_hash {
+                        Some(transaction_hash) => {
+                            let trace = dhp_evm_tracing::tracer::trace(trace_type, || {
+                                Executive::apply_extrinsic(extrinsic)
+                            })?;
+                            traces.push((transaction_hash, trace));
+                        }
+                        None => {
+                            let _ = Executive::apply_extrinsic(extrinsic);
+                        }
+                    }
+                }
+                Ok(traces)
+            }
+            #[cfg(not(feature = "evm-tracing"))]
+            {
+                let _ = (header, extrinsics, trace_type);
+                Err(dhp_evm_tracing::TraceError::TracingDisabled)
+            }
+        }
+    }
+
+    impl datahaven_runtime_common::call_filter::CallFilterApi<Block, configs::ProxyType> for Runtime {
+        fn is_call_allowed(
+            origin_kind: datahaven_runtime_common::call_filter::CallOriginKind<configs::ProxyType>,
+            call: Vec<u8>,
+        ) -> datahaven_runtime_common::call_filter::CallFilterOutcome {
+            datahaven_runtime_common::call_filter::is_call_allowed::<
+                RuntimeCall,
+                MaintenanceMode,
+                SafeMode,
+                TxPause,
+                configs::ProxyType,
+            >(origin_kind, call)
+        }
+    }
+
+    impl datahaven_runtime_common::storage_footprint::StorageFootprintApi<Block> for Runtime {
+        fn storage_footprint() -> Vec<datahaven_runtime_common::storage_footprint::StorageMapFootprint> {
+            datahaven_runtime_common::storage_footprint::storage_footprint::<Runtime>()
+        }
+    }
+
     impl snowbridge_system_v2_runtime_api::ControlV2Api<Block> for Runtime {
         fn agent_id(location: VersionedLocation) -> Option<AgentId> {
             snowbridge_pallet_system_v2::api::agent_id::<Runtime>(location)
@@ -1197,7 +1438,7 @@ impl_runtime_apis! {
         }
 
         fn elasticity() -> Option<Permill> {
-            None
+            Some(configs::BaseFeeElasticity::get())
         }
 
         fn gas_limit_multiplier_support() {}
@@ -1421,6 +1662,50 @@ impl_runtime_apis! {
         }
     }
 
+    impl dhp_storagehub_runtime_api::StorageHubApi<Block, AccountId, BucketId<Runtime>, ProviderIdFor<Runtime>, StorageDataUnit<Runtime>, H256> for Runtime {
+        fn user_buckets(user: AccountId, start_after: Option<BucketId<Runtime>>, limit: u32) -> Vec<BucketId<Runtime>> {
+            let buckets = match start_after {
+                Some(bucket_id) => pallet_storage_providers::Buckets::<Runtime>::iter_from(
+                    pallet_storage_providers::Buckets::<Runtime>::hashed_key_for(bucket_id),
+                ),
+                None => pallet_storage_providers::Buckets::<Runtime>::iter(),
+            };
+            buckets
+                .filter(|(_, bucket)| bucket.user_id == user)
+                .map(|(bucket_id, _)| bucket_id)
+                .take(limit as usize)
+                .collect()
+        }
+
+        fn bucket_metadata(bucket_id: BucketId<Runtime>) -> Option<dhp_storagehub_runtime_api::BucketMetadata<AccountId, ProviderIdFor<Runtime>, StorageDataUnit<Runtime>, H256>> {
+            pallet_storage_providers::Buckets::<Runtime>::get(bucket_id).map(|bucket| {
+                dhp_storagehub_runtime_api::BucketMetadata {
+                    owner: bucket.user_id,
+                    msp_id: bucket.msp_id,
+                    root: bucket.root,
+                    size: bucket.size,
+                    private: bucket.private,
+                }
+            })
+        }
+
+        fn bucket_file_keys(bucket_id: BucketId<Runtime>) -> Vec<H256> {
+            pallet_file_system::StorageRequests::<Runtime>::iter()
+                .filter(|(_, request)| request.bucket_id == bucket_id)
+                .map(|(file_key, _)| file_key)
+                .collect()
+        }
+
+        fn provider_capacity(provider_id: ProviderIdFor<Runtime>) -> Option<dhp_storagehub_runtime_api::ProviderCapacity<StorageDataUnit<Runtime>>> {
+            let capacity = Providers::query_storage_provider_capacity(&provider_id).ok()?;
+            let available = Providers::query_available_storage_capacity(&provider_id).ok()?;
+            Some(dhp_storagehub_runtime_api::ProviderCapacity {
+                capacity,
+                used: capacity.saturating_sub(available),
+            })
+        }
+    }
+
     impl shp_tx_implicits_runtime_api::TxImplicitsApi<Block> for Runtime {
         fn compute_signed_extra_implicit(
             era: sp_runtime::generic::Era,
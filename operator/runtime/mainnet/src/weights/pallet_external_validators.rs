@@ -148,4 +148,19 @@ impl<T: frame_system::Config> pallet_external_validators::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+
+	fn register_operator_address() -> Weight {
+		// Reads Session::NextKeys, OperatorAddresses and OperatorValidators, writes OperatorAddresses and
+		// OperatorValidators of both operators. Recovering the signer is included in the base cost.
+		Weight::from_parts(62_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	fn remove_operator_address() -> Weight {
+		// Reads and writes OperatorAddresses, writes OperatorValidators.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
@@ -16,10 +16,15 @@
 
 //! Common test utilities for DataHaven mainnet runtime tests
 
+use codec::Encode;
 use datahaven_mainnet_runtime::{
     currency::{HAVE, SUPPLY_FACTOR},
     AccountId,
     Balance,
+    Executive,
+    ExternalValidators,
+    Header,
+    Historical,
     Runtime,
     RuntimeCall,
     RuntimeEvent,
@@ -30,21 +35,31 @@ use datahaven_mainnet_runtime::{
     // Import governance pallets for common helpers
     TechnicalCommittee,
     TreasuryCouncil,
+    UncheckedExtrinsic,
 };
 use frame_support::{
     assert_ok,
-    traits::{OnFinalize, OnInitialize},
+    traits::{Get, KeyOwnerProofSystem, OnFinalize, OnInitialize},
+    unsigned::ValidateUnsigned,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
-use sp_consensus_babe::AuthorityId as BabeId;
-use sp_consensus_beefy::ecdsa_crypto::AuthorityId as BeefyId;
+use sp_consensus_babe::{
+    digests::{CompatibleDigestItem, PreDigest, SecondaryPlainPreDigest},
+    AuthorityId as BabeId, Slot,
+};
+use sp_consensus_beefy::{
+    ecdsa_crypto::AuthorityId as BeefyId, known_payloads::MMR_ROOT_ID, Commitment,
+    DoubleVotingProof, Payload, VoteMessage,
+};
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{crypto::UncheckedFrom, H160, H256};
+use sp_core::{ecdsa, ed25519, keccak_256, sr25519, Pair, H160, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, Hash},
-    BuildStorage,
+    traits::{BlakeTwo256, Hash, Header as HeaderT},
+    transaction_validity::TransactionSource,
+    BuildStorage, Digest, DigestItem, DispatchResult,
 };
+use sp_staking::{EraIndex, SessionIndex};
 
 /// Test account constants
 pub const ALICE: [u8; 20] = [1u8; 20];
@@ -69,16 +84,21 @@ pub const PROPOSAL_BOND: Balance = 100 * HAVE * SUPPLY_FACTOR;
 #[allow(dead_code)]
 pub const VOTING_BALANCE: Balance = 10 * HAVE * SUPPLY_FACTOR;
 
+/// Seed of the test session keys of a given account, to sign with them
+pub fn session_key_seed(account: AccountId) -> [u8; 32] {
+    let account_bytes: &[u8; 20] = account.as_ref();
+    [account_bytes[0]; 32]
+}
+
 /// Generate test session keys for a given account
 pub fn generate_session_keys(account: AccountId) -> SessionKeys {
-    let account_bytes: &[u8; 20] = account.as_ref();
-    let first_byte = account_bytes[0];
+    let seed = session_key_seed(account);
 
     SessionKeys {
-        babe: BabeId::unchecked_from([first_byte; 32]),
-        grandpa: GrandpaId::unchecked_from([first_byte; 32]),
-        im_online: ImOnlineId::unchecked_from([first_byte; 32]),
-        beefy: BeefyId::unchecked_from([first_byte; 33]),
+        babe: BabeId::from(sr25519::Pair::from_seed(&seed).public()),
+        grandpa: GrandpaId::from(ed25519::Pair::from_seed(&seed).public()),
+        im_online: ImOnlineId::from(sr25519::Pair::from_seed(&seed).public()),
+        beefy: BeefyId::from(ecdsa::Pair::from_seed(&seed).public()),
     }
 }
 
@@ -89,7 +109,9 @@ pub struct ExtBuilder {
     with_default_balances: bool,
     validators: Vec<AccountId>,
     with_default_validators: bool,
+    external_validators: Vec<AccountId>,
     sudo_key: Option<AccountId>,
+    active_era: EraIndex,
 }
 
 impl ExtBuilder {
@@ -99,7 +121,9 @@ impl ExtBuilder {
             with_default_balances: true,
             validators: vec![],
             with_default_validators: true,
+            external_validators: vec![],
             sudo_key: None,
+            active_era: 0,
         }
     }
 
@@ -117,7 +141,9 @@ impl ExtBuilder {
             with_default_balances: false,
             validators: vec![],
             with_default_validators: true,
+            external_validators: vec![],
             sudo_key: None,
+            active_era: 0,
         }
     }
 
@@ -135,12 +161,26 @@ impl ExtBuilder {
         self
     }
 
+    /// Validators selected externally, which are not whitelisted and can be slashed
+    #[allow(dead_code)]
+    pub fn with_external_validators(mut self, validators: Vec<AccountId>) -> Self {
+        self.external_validators = validators;
+        self
+    }
+
     #[allow(dead_code)]
     pub fn with_sudo(mut self, sudo_key: AccountId) -> Self {
         self.sudo_key = Some(sudo_key);
         self
     }
 
+    /// Start the tests in `era`, rotating the sessions of the eras before it
+    #[allow(dead_code)]
+    pub fn with_active_era(mut self, era: EraIndex) -> Self {
+        self.active_era = era;
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         let mut balances = self.balances;
         let mut validators = self.validators;
@@ -175,9 +215,24 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .expect("Pallet balances storage can be assimilated");
 
+        // Attests the genesis issuance, once the balances are built
+        pallet_supply_ledger::GenesisConfig::<Runtime>::default()
+            .assimilate_storage(&mut t)
+            .expect("Supply ledger genesis config can be assimilated");
+
+        // Whitelist the validators, so the validator set of every era is known
+        pallet_external_validators::GenesisConfig::<Runtime> {
+            skip_external_validators: false,
+            whitelisted_validators: validators.clone(),
+            external_validators: self.external_validators.clone(),
+        }
+        .assimilate_storage(&mut t)
+        .expect("External validators genesis config can be assimilated");
+
         // Set up session keys for validators
         let session_keys: Vec<_> = validators
             .iter()
+            .chain(self.external_validators.iter())
             .map(|validator| {
                 (
                     validator.clone(),
@@ -208,6 +263,7 @@ impl ExtBuilder {
             System::set_block_number(1);
             // Initialize session
             <Session as OnInitialize<BlockNumberFor<Runtime>>>::on_initialize(1);
+            roll_to_era(self.active_era);
         });
         ext
     }
@@ -296,6 +352,30 @@ pub fn run_to_block(n: BlockNumberFor<Runtime>) {
     }
 }
 
+/// Rotate the sessions until `session` starts, advancing a block per session
+///
+/// Drives the session manager, so eras start and end as on a live chain.
+pub fn roll_to_session(session: SessionIndex) {
+    while Session::current_index() < session {
+        run_to_block(System::block_number() + 1);
+        Session::rotate_session();
+    }
+}
+
+/// Rotate the sessions until `era` is the active era
+pub fn roll_to_era(era: EraIndex) {
+    while active_era() < era {
+        roll_to_session(Session::current_index() + 1);
+    }
+}
+
+/// Index of the active era
+pub fn active_era() -> EraIndex {
+    ExternalValidators::active_era()
+        .map(|active_era| active_era.index)
+        .unwrap_or_default()
+}
+
 /// Helper function to make a proposal hash
 #[allow(dead_code)]
 pub fn make_proposal_hash(proposal: &RuntimeCall) -> H256 {
@@ -350,3 +430,134 @@ pub fn advance_referendum_time(blocks: BlockNumberFor<Runtime>) {
     let current_block = System::block_number();
     run_to_block(current_block + blocks);
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════════════════════════
+// Equivocation helper functions
+// ═══════════════════════════════════════════════════════════════════════════════════════════════════
+
+/// Forges a report of `offender` authoring two BABE blocks in the same slot of the current
+/// session, signed with its session key
+#[allow(dead_code)]
+pub fn forge_babe_equivocation(offender: AccountId) -> RuntimeCall {
+    let pair = sr25519::Pair::from_seed(&session_key_seed(offender));
+    let babe_id = BabeId::from(pair.public());
+
+    // The slot must be in the epoch of the current session
+    let epoch_duration: u64 = <Runtime as pallet_babe::Config>::EpochDuration::get();
+    let slot = Slot::from(
+        *pallet_babe::GenesisSlot::<Runtime>::get()
+            + u64::from(Session::current_index()) * epoch_duration,
+    );
+    let sealed_header = |extrinsics_root: H256| {
+        let mut header = Header::new(
+            System::block_number(),
+            extrinsics_root,
+            H256::zero(),
+            System::parent_hash(),
+            Digest::default(),
+        );
+        header
+            .digest_mut()
+            .push(DigestItem::babe_pre_digest(PreDigest::SecondaryPlain(
+                SecondaryPlainPreDigest {
+                    authority_index: 0,
+                    slot,
+                },
+            )));
+        let seal = pair.sign(header.hash().as_ref());
+        header.digest_mut().push(DigestItem::babe_seal(seal.into()));
+        header
+    };
+
+    RuntimeCall::Babe(pallet_babe::Call::report_equivocation_unsigned {
+        equivocation_proof: Box::new(sp_consensus_babe::EquivocationProof {
+            offender: babe_id.clone(),
+            slot,
+            first_header: sealed_header(H256::repeat_byte(1)),
+            second_header: sealed_header(H256::repeat_byte(2)),
+        }),
+        key_owner_proof: Historical::prove((sp_consensus_babe::KEY_TYPE, babe_id))
+            .expect("key is registered in the current session"),
+    })
+}
+
+/// Forges a report of `offender` casting two GRANDPA prevotes in the same round of the
+/// current set, signed with its session key
+#[allow(dead_code)]
+pub fn forge_grandpa_equivocation(offender: AccountId) -> RuntimeCall {
+    let pair = ed25519::Pair::from_seed(&session_key_seed(offender));
+    let grandpa_id = GrandpaId::from(pair.public());
+
+    let set_id = pallet_grandpa::CurrentSetId::<Runtime>::get();
+    let round = 1;
+    let signed_prevote = |target_hash: H256| {
+        let prevote = finality_grandpa::Prevote {
+            target_hash,
+            target_number: System::block_number(),
+        };
+        let message = finality_grandpa::Message::Prevote(prevote.clone());
+        let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+        let signature: sp_consensus_grandpa::AuthoritySignature = pair.sign(&payload).into();
+        (prevote, signature)
+    };
+
+    RuntimeCall::Grandpa(pallet_grandpa::Call::report_equivocation_unsigned {
+        equivocation_proof: Box::new(sp_consensus_grandpa::EquivocationProof::new(
+            set_id,
+            sp_consensus_grandpa::Equivocation::Prevote(finality_grandpa::Equivocation {
+                round_number: round,
+                identity: grandpa_id.clone(),
+                first: signed_prevote(H256::repeat_byte(1)),
+                second: signed_prevote(H256::repeat_byte(2)),
+            }),
+        )),
+        key_owner_proof: Historical::prove((sp_consensus_grandpa::KEY_TYPE, grandpa_id))
+            .expect("key is registered in the current session"),
+    })
+}
+
+/// Forges a report of `offender` signing two BEEFY commitments for the same block of the
+/// current set, signed with its session key
+#[allow(dead_code)]
+pub fn forge_beefy_equivocation(offender: AccountId) -> RuntimeCall {
+    let pair = ecdsa::Pair::from_seed(&session_key_seed(offender));
+    let beefy_id = BeefyId::from(pair.public());
+
+    let validator_set_id = pallet_beefy::ValidatorSetId::<Runtime>::get();
+    let vote = |mmr_root: H256| {
+        let commitment = Commitment {
+            payload: Payload::from_single_entry(MMR_ROOT_ID, mmr_root.encode()),
+            block_number: System::block_number(),
+            validator_set_id,
+        };
+        let signature: sp_consensus_beefy::ecdsa_crypto::Signature = pair
+            .sign_prehashed(&keccak_256(&commitment.encode()))
+            .into();
+        VoteMessage {
+            commitment,
+            id: beefy_id.clone(),
+            signature,
+        }
+    };
+
+    RuntimeCall::Beefy(pallet_beefy::Call::report_double_voting_unsigned {
+        equivocation_proof: Box::new(DoubleVotingProof {
+            first: vote(H256::repeat_byte(1)),
+            second: vote(H256::repeat_byte(2)),
+        }),
+        key_owner_proof: Historical::prove((sp_consensus_beefy::KEY_TYPE, beefy_id))
+            .expect("key is registered in the current session"),
+    })
+}
+
+/// Submits `call` as an unsigned extrinsic produced by the local node, as the equivocation
+/// reports of the node are
+#[allow(dead_code)]
+pub fn submit_unsigned(call: RuntimeCall) -> DispatchResult {
+    assert_ok!(<Runtime as ValidateUnsigned>::validate_unsigned(
+        TransactionSource::Local,
+        &call,
+    ));
+    Executive::apply_extrinsic(UncheckedExtrinsic::new_bare(call))
+        .expect("unsigned extrinsic is valid")
+}
@@ -0,0 +1,100 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Equivocations forged with the session keys of a validator are reported through the unsigned
+//! extrinsics of the consensus pallets, and slashed by the slashes pallet.
+
+mod common;
+use common::*;
+
+use datahaven_mainnet_runtime::{AccountId, Runtime};
+use frame_support::{assert_ok, unsigned::ValidateUnsigned};
+use pallet_external_validator_slashes::{OffenceKind, Slashes};
+use sp_runtime::transaction_validity::TransactionSource;
+
+/// Offence kinds of the slashes deferred against `validator`
+fn slashed_offence_kinds(validator: AccountId) -> Vec<OffenceKind> {
+    Slashes::<Runtime>::iter_values()
+        .flat_map(|slashes| slashes.into_inner())
+        .filter(|slash| slash.validator == validator)
+        .map(|slash| slash.offence_kind)
+        .collect()
+}
+
+fn ext_with_external_validator() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_external_validators(vec![eve()])
+        .build()
+}
+
+#[test]
+fn babe_equivocation_is_slashed() {
+    ext_with_external_validator().execute_with(|| {
+        assert_ok!(submit_unsigned(forge_babe_equivocation(eve())));
+
+        assert_eq!(
+            slashed_offence_kinds(eve()),
+            vec![OffenceKind::BabeEquivocation]
+        );
+    });
+}
+
+#[test]
+fn grandpa_equivocation_is_slashed() {
+    ext_with_external_validator().execute_with(|| {
+        assert_ok!(submit_unsigned(forge_grandpa_equivocation(eve())));
+
+        assert_eq!(
+            slashed_offence_kinds(eve()),
+            vec![OffenceKind::GrandpaEquivocation]
+        );
+    });
+}
+
+#[test]
+fn beefy_equivocation_is_slashed() {
+    ext_with_external_validator().execute_with(|| {
+        assert_ok!(submit_unsigned(forge_beefy_equivocation(eve())));
+
+        assert_eq!(
+            slashed_offence_kinds(eve()),
+            vec![OffenceKind::BeefyEquivocation]
+        );
+    });
+}
+
+#[test]
+fn known_equivocation_is_not_reported_again() {
+    ext_with_external_validator().execute_with(|| {
+        assert_ok!(submit_unsigned(forge_grandpa_equivocation(eve())));
+
+        assert!(<Runtime as ValidateUnsigned>::validate_unsigned(
+            TransactionSource::Local,
+            &forge_grandpa_equivocation(eve()),
+        )
+        .is_err());
+        assert_eq!(slashed_offence_kinds(eve()).len(), 1);
+    });
+}
+
+#[test]
+fn whitelisted_validator_equivocation_is_not_slashed() {
+    ext_with_external_validator().execute_with(|| {
+        assert_ok!(submit_unsigned(forge_grandpa_equivocation(charlie())));
+
+        assert!(slashed_offence_kinds(charlie()).is_empty());
+    });
+}
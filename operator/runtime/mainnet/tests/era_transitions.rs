@@ -0,0 +1,115 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Era transitions driven through the session manager
+
+mod common;
+use common::*;
+
+use datahaven_mainnet_runtime::{
+    AccountId, Authorship, ExternalValidators, Runtime, RuntimeEvent, Session, System,
+};
+use frame_support::traits::{Get, OnFinalize, OnInitialize};
+use pallet_external_validators_rewards::{BlocksAuthoredInSession, RewardPointsForEra};
+use sp_staking::EraIndex;
+
+fn sessions_per_era() -> u32 {
+    <Runtime as pallet_external_validators::Config>::SessionsPerEra::get()
+}
+
+#[test]
+fn eras_start_every_sessions_per_era() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(active_era(), 0);
+
+        roll_to_era(2);
+
+        assert_eq!(active_era(), 2);
+        assert_eq!(Session::current_index(), 2 * sessions_per_era());
+        assert_eq!(
+            ExternalValidators::eras_start_session_index(2),
+            Some(2 * sessions_per_era())
+        );
+        assert!(has_event(RuntimeEvent::ExternalValidators(
+            pallet_external_validators::Event::NewEra { era: 2 }
+        )));
+    });
+}
+
+#[test]
+fn with_active_era_starts_in_the_given_era() {
+    ExtBuilder::default()
+        .with_active_era(3)
+        .build()
+        .execute_with(|| {
+            assert_eq!(active_era(), 3);
+            assert_eq!(Session::current_index(), 3 * sessions_per_era());
+        });
+}
+
+#[test]
+fn configured_validators_validate_every_era() {
+    ExtBuilder::default()
+        .with_validators(vec![account_id(ALICE), account_id(BOB)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                Session::validators(),
+                vec![account_id(ALICE), account_id(BOB)]
+            );
+
+            roll_to_era(1);
+
+            assert_eq!(
+                Session::validators(),
+                vec![account_id(ALICE), account_id(BOB)]
+            );
+        });
+}
+
+/// Reward points of `validator` in `era`
+fn reward_points(era: EraIndex, validator: &AccountId) -> u32 {
+    RewardPointsForEra::<Runtime>::get(era)
+        .individual
+        .get(validator)
+        .copied()
+        .unwrap_or_default()
+}
+
+#[test]
+fn last_session_of_an_era_is_awarded_before_the_era_ends() {
+    ExtBuilder::default()
+        .with_external_validators(vec![eve()])
+        .build()
+        .execute_with(|| {
+            roll_to_session(sessions_per_era() - 1);
+            for _ in 0..3 {
+                let block_number = System::block_number() + 1;
+                System::set_block_number(block_number);
+                set_block_author(eve());
+                Authorship::on_initialize(block_number);
+                Authorship::on_finalize(block_number);
+            }
+            let points_before_last_session = reward_points(0, &eve());
+
+            roll_to_era(1);
+
+            // The last session ended, and was awarded to its era, before the era ended
+            assert!(reward_points(0, &eve()) > points_before_last_session);
+            assert_eq!(BlocksAuthoredInSession::<Runtime>::iter().count(), 0);
+            assert_eq!(reward_points(1, &eve()), 0);
+        });
+}
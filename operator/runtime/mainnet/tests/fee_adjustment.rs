@@ -19,18 +19,19 @@
 
 use datahaven_mainnet_runtime::{
     configs::{
-        MinimumMultiplier, RuntimeBlockWeights, SlowAdjustingFeeUpdate, TargetBlockFullness,
-        TransactionPaymentAsGasPrice,
+        BaseFeeElasticity, MinimumMultiplier, RuntimeBlockWeights, SlowAdjustingFeeUpdate,
+        TargetBlockFullness, TransactionPaymentAsGasPrice,
     },
     currency::WEIGHT_FEE,
-    Runtime, System,
+    Runtime, System, WeightToFee,
 };
 use datahaven_runtime_common::constants::gas::WEIGHT_PER_GAS;
 use fp_evm::FeeCalculator;
 use frame_support::pallet_prelude::DispatchClass;
 use frame_support::traits::OnFinalize;
+use frame_support::weights::{Weight, WeightToFee as _};
 use sp_core::U256;
-use sp_runtime::{traits::Convert, BuildStorage, FixedPointNumber, FixedU128, Perbill};
+use sp_runtime::{traits::Convert, BuildStorage, FixedPointNumber, FixedU128, Perbill, Permill};
 
 /// Helper function to run tests with a specific system weight
 fn run_with_system_weight<F>(w: frame_support::weights::Weight, mut assertions: F)
@@ -68,6 +69,63 @@ fn multiplier_can_grow_from_zero() {
     })
 }
 
+#[test]
+fn multiplier_follows_block_fullness() {
+    let normal_max_weight = RuntimeBlockWeights::get()
+        .get(DispatchClass::Normal)
+        .max_total
+        .unwrap();
+    let start_multiplier = FixedU128::from_u32(2);
+    let next_multiplier = |block_weight: Weight| {
+        let mut next = FixedU128::from_u32(0);
+        run_with_system_weight(block_weight, || {
+            pallet_transaction_payment::NextFeeMultiplier::<Runtime>::set(start_multiplier);
+            pallet_transaction_payment::Pallet::<Runtime>::on_finalize(System::block_number());
+            next = pallet_transaction_payment::NextFeeMultiplier::<Runtime>::get();
+        });
+        next
+    };
+
+    // full blocks raise the fees
+    let after_full_block = next_multiplier(normal_max_weight);
+    assert!(after_full_block > start_multiplier);
+
+    // empty blocks lower them
+    let after_empty_block = next_multiplier(Weight::zero());
+    assert!(after_empty_block < start_multiplier);
+
+    // blocks filled up to the target leave them untouched
+    let target = TargetBlockFullness::get() * normal_max_weight;
+    assert_eq!(next_multiplier(target), start_multiplier);
+}
+
+#[test]
+fn base_fee_elasticity_matches_a_full_block() {
+    let normal_max_weight = RuntimeBlockWeights::get()
+        .get(DispatchClass::Normal)
+        .max_total
+        .unwrap();
+    run_with_system_weight(normal_max_weight, || {
+        let start_multiplier = FixedU128::from_u32(2);
+        pallet_transaction_payment::NextFeeMultiplier::<Runtime>::set(start_multiplier);
+        pallet_transaction_payment::Pallet::<Runtime>::on_finalize(System::block_number());
+        let next_multiplier = pallet_transaction_payment::NextFeeMultiplier::<Runtime>::get();
+
+        let increase = Permill::from_rational(
+            (next_multiplier - start_multiplier).into_inner(),
+            start_multiplier.into_inner(),
+        );
+        let elasticity = BaseFeeElasticity::get();
+        assert!(!elasticity.is_zero());
+        assert!(
+            increase.deconstruct().abs_diff(elasticity.deconstruct()) <= 1,
+            "{:?} != {:?}",
+            increase,
+            elasticity
+        );
+    });
+}
+
 #[test]
 fn fee_calculation() {
     let base_extrinsic = RuntimeBlockWeights::get()
@@ -78,10 +136,13 @@ fn fee_calculation() {
     let extrinsic_weight = 5_000u64;
     let tip = 42u128;
 
-    // For IdentityFee, the fee is just the weight itself
-    // Formula: base_extrinsic + (multiplier * call_weight) + extrinsic_len + tip
-    let expected_fee = base_extrinsic.ref_time() as u128
-        + (multiplier.saturating_mul_int(extrinsic_weight as u128))
+    // Weights are priced by the `WeightToFee` polynomial, only the call weight is scaled by the
+    // multiplier and the length fee is the length itself
+    // Formula: base_fee + (multiplier * call_fee) + extrinsic_len + tip
+    let weight_to_fee =
+        |ref_time: u64| WeightToFee::weight_to_fee(&Weight::from_parts(ref_time, 0));
+    let expected_fee = weight_to_fee(base_extrinsic.ref_time())
+        + (multiplier.saturating_mul_int(weight_to_fee(extrinsic_weight)))
         + extrinsic_len as u128
         + tip;
 
@@ -0,0 +1,154 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Maintenance mode tests for the DataHaven mainnet runtime
+
+mod common;
+use common::*;
+
+use datahaven_mainnet_runtime::{
+    configs::{MaxConsecutiveDeliveryFailures, MessageDeliveryHandler},
+    governance::custom_origins,
+    MaintenanceMode, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Contains, Get},
+};
+use snowbridge_pallet_outbound_queue_v2::OnMessageDelivered;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Dispatchable;
+
+fn is_allowed(call: &RuntimeCall) -> bool {
+    <Runtime as frame_system::Config>::BaseCallFilter::contains(call)
+}
+
+fn enter_call() -> RuntimeCall {
+    RuntimeCall::MaintenanceMode(pallet_maintenance_mode::Call::enter_maintenance_mode {})
+}
+
+fn resume_call() -> RuntimeCall {
+    RuntimeCall::MaintenanceMode(pallet_maintenance_mode::Call::resume_normal_operation {})
+}
+
+fn bridge_call() -> RuntimeCall {
+    RuntimeCall::DataHavenNativeTransfer(
+        pallet_datahaven_native_transfer::Call::transfer_to_ethereum {
+            recipient: H160::repeat_byte(0x42),
+            amount: 1_000,
+            fee: 1,
+        },
+    )
+}
+
+fn ethereum_call() -> RuntimeCall {
+    let transaction = pallet_ethereum::Transaction::Legacy(ethereum::LegacyTransaction {
+        nonce: U256::zero(),
+        gas_price: U256::one(),
+        gas_limit: U256::from(21_000),
+        action: ethereum::TransactionAction::Call(H160::repeat_byte(0x42)),
+        value: U256::one(),
+        input: vec![],
+        signature: ethereum::TransactionSignature::new(
+            27,
+            H256::from_low_u64_be(1),
+            H256::from_low_u64_be(1),
+        )
+        .expect("valid signature; qed"),
+    });
+    RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction })
+}
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark {
+        remark: b"still running".to_vec(),
+    })
+}
+
+#[test]
+fn fast_general_admin_enters_and_resumes_maintenance() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            enter_call().dispatch(RuntimeOrigin::signed(account_id(ALICE))),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(enter_call().dispatch(custom_origins::Origin::FastGeneralAdmin.into()));
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+        System::assert_last_event(RuntimeEvent::MaintenanceMode(
+            pallet_maintenance_mode::Event::EnteredMaintenanceMode,
+        ));
+
+        assert_ok!(resume_call().dispatch(RuntimeOrigin::root()));
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+        System::assert_last_event(RuntimeEvent::MaintenanceMode(
+            pallet_maintenance_mode::Event::NormalOperationResumed,
+        ));
+    });
+}
+
+#[test]
+fn maintenance_suspends_bridge_and_evm_calls_only() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(is_allowed(&bridge_call()));
+
+        assert_ok!(enter_call().dispatch(RuntimeOrigin::root()));
+
+        assert!(!is_allowed(&bridge_call()));
+        assert!(!is_allowed(&ethereum_call()));
+        assert!(is_allowed(&remark_call()));
+        assert!(is_allowed(&resume_call()));
+        // The filter of the normal operation still applies
+        assert!(!is_allowed(&RuntimeCall::Balances(
+            pallet_balances::Call::burn {
+                value: 1,
+                keep_alive: true,
+            }
+        )));
+
+        assert_ok!(resume_call().dispatch(RuntimeOrigin::root()));
+        assert!(is_allowed(&bridge_call()));
+    });
+}
+
+#[test]
+fn repeated_delivery_failures_enter_maintenance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let max_failures: u32 = MaxConsecutiveDeliveryFailures::get();
+
+        // A successful delivery resets the count
+        for _ in 1..max_failures {
+            MessageDeliveryHandler::on_message_delivered(H256::zero(), false);
+        }
+        MessageDeliveryHandler::on_message_delivered(H256::zero(), true);
+        for _ in 1..max_failures {
+            MessageDeliveryHandler::on_message_delivered(H256::zero(), false);
+        }
+        assert!(!MaintenanceMode::is_in_maintenance_mode());
+
+        MessageDeliveryHandler::on_message_delivered(H256::zero(), false);
+        assert!(MaintenanceMode::is_in_maintenance_mode());
+        System::assert_last_event(RuntimeEvent::MaintenanceMode(
+            pallet_maintenance_mode::Event::EnteredMaintenanceModeOnDeliveryFailures {
+                failures: max_failures,
+            },
+        ));
+        assert!(!is_allowed(&bridge_call()));
+
+        assert_ok!(resume_call().dispatch(RuntimeOrigin::root()));
+        assert!(is_allowed(&bridge_call()));
+    });
+}
@@ -20,10 +20,15 @@ mod common;
 use codec::Encode;
 use common::*;
 use datahaven_mainnet_runtime::{
-    configs::EthereumSovereignAccount, currency::HAVE, AccountId, Balance, Balances,
-    DataHavenNativeTransfer, Runtime, RuntimeEvent, RuntimeOrigin, SnowbridgeSystemV2, System,
+    configs::{EthereumSovereignAccount, GatewayRotationGracePeriod},
+    currency::HAVE,
+    AccountId, Balance, Balances, DataHavenNativeTransfer, EthereumGateway, Runtime, RuntimeEvent,
+    RuntimeOrigin, SnowbridgeSystemV2, SupplyLedger, System,
+};
+use dhp_bridge::{
+    DepositCommand, DepositMessage, DepositPayload, HaveDepositMessageProcessor,
+    NativeTokenTransferMessageProcessor, HAVE_DEPOSIT_MESSAGE_ID,
 };
-use dhp_bridge::NativeTokenTransferMessageProcessor;
 use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
 use pallet_datahaven_native_transfer::Event as NativeTransferEvent;
 use snowbridge_core::TokenIdOf;
@@ -45,8 +50,52 @@ const ETH_BOB: H160 = H160([0x22; 20]);
 
 // Get the gateway address from runtime configuration
 fn gateway_address() -> H160 {
-    use datahaven_mainnet_runtime::configs::runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress;
-    EthereumGatewayAddress::get()
+    EthereumGateway::active_gateway()
+}
+
+const DEPOSIT_CONTRACT: H160 = H160([0xde; 20]);
+
+type DepositContractAddress =
+    datahaven_mainnet_runtime::configs::runtime_params::dynamic_params::runtime_config::HaveDepositContractAddress;
+
+fn set_deposit_contract(address: H160) {
+    use datahaven_mainnet_runtime::{
+        configs::runtime_params::dynamic_params::runtime_config, RuntimeParameters,
+    };
+    assert_ok!(pallet_parameters::Pallet::<Runtime>::set_parameter(
+        root_origin(),
+        RuntimeParameters::RuntimeConfig(runtime_config::Parameters::HaveDepositContractAddress(
+            runtime_config::HaveDepositContractAddress,
+            Some(address),
+        ))
+    ));
+}
+
+fn create_deposit_message(
+    origin: H160,
+    deposit_id: H256,
+    recipient: H160,
+    amount: Balance,
+) -> SnowbridgeMessage {
+    let payload = DepositPayload {
+        message_id: HAVE_DEPOSIT_MESSAGE_ID,
+        message: DepositMessage::V1(DepositCommand::CreditDeposit {
+            deposit_id,
+            recipient,
+            amount,
+        }),
+    };
+    SnowbridgeMessage {
+        gateway: gateway_address(),
+        nonce: 1,
+        origin,
+        assets: vec![],
+        xcm: Payload::Raw(payload.encode()),
+        claimer: None,
+        value: 0,
+        execution_fee: 100,
+        relayer_fee: 0,
+    }
 }
 
 fn register_native_token() -> H256 {
@@ -151,6 +200,56 @@ fn transfer_to_ethereum_works() {
     });
 }
 
+#[test]
+fn large_transfer_is_delayed_until_cancelled() {
+    use datahaven_mainnet_runtime::{
+        configs::runtime_params::dynamic_params::runtime_config, RuntimeParameters,
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let _token_id = register_native_token();
+        let alice = account_id(ALICE);
+        let alice_initial = Balances::balance(&alice);
+        let sovereign_initial = Balances::balance(&EthereumSovereignAccount::get());
+
+        assert_ok!(pallet_parameters::Pallet::<Runtime>::set_parameter(
+            root_origin(),
+            RuntimeParameters::RuntimeConfig(runtime_config::Parameters::LargeTransferThreshold(
+                runtime_config::LargeTransferThreshold,
+                Some(TRANSFER_AMOUNT),
+            ))
+        ));
+
+        assert_ok!(DataHavenNativeTransfer::transfer_to_ethereum(
+            RuntimeOrigin::signed(alice.clone()),
+            ETH_ALICE,
+            TRANSFER_AMOUNT,
+            FEE_AMOUNT
+        ));
+
+        // Nothing is sent until the delay is over
+        assert_eq!(
+            Balances::balance(&EthereumSovereignAccount::get()),
+            sovereign_initial
+        );
+        assert!(pallet_datahaven_native_transfer::PendingLargeTransfers::<
+            Runtime,
+        >::contains_key(0));
+
+        assert_ok!(DataHavenNativeTransfer::cancel_large_transfer(
+            root_origin(),
+            0
+        ));
+        assert_eq!(Balances::balance(&alice), alice_initial);
+        assert!(System::events().iter().any(|e| matches!(
+            &e.event,
+            RuntimeEvent::DataHavenNativeTransfer(NativeTransferEvent::LargeTransferCancelled {
+                id: 0
+            })
+        )));
+    });
+}
+
 #[test]
 fn transfer_fails_when_paused() {
     ExtBuilder::default().build().execute_with(|| {
@@ -228,6 +327,18 @@ fn treasury_collects_fees_from_multiple_transfers() {
             fee2
         ));
 
+        // Fees are held until the delivery of the messages is proven
+        assert_eq!(
+            Balances::balance(&treasury_account),
+            initial_treasury_balance
+        );
+        let message_ids: Vec<H256> =
+            pallet_datahaven_native_transfer::PendingTransferFees::<Runtime>::iter_keys().collect();
+        assert_eq!(message_ids.len(), 2);
+        for message_id in message_ids {
+            DataHavenNativeTransfer::on_message_delivered(message_id, true);
+        }
+
         let expected_treasury_balance = initial_treasury_balance + fee1 + fee2;
         assert_eq!(
             Balances::balance(&treasury_account),
@@ -430,6 +541,118 @@ fn end_to_end_transfer_flow() {
     });
 }
 
+#[test]
+fn supply_ledger_tracks_locked_and_returned_tokens() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let alice = account_id(ALICE);
+
+        assert_ok!(DataHavenNativeTransfer::transfer_to_ethereum(
+            RuntimeOrigin::signed(alice.clone()),
+            ETH_ALICE,
+            TRANSFER_AMOUNT,
+            FEE_AMOUNT
+        ));
+        assert_eq!(SupplyLedger::supply_totals().locked, TRANSFER_AMOUNT);
+
+        let message = create_message(token_id, TRANSFER_AMOUNT / 4, ETH_BOB, 1);
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        let totals = SupplyLedger::supply_totals();
+        assert_eq!(totals.returned, TRANSFER_AMOUNT / 4);
+        assert_eq!(totals.bridged_out(), TRANSFER_AMOUNT * 3 / 4);
+    });
+}
+
+#[test]
+fn previous_gateway_is_accepted_during_rotation_grace_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let old_gateway = gateway_address();
+        let new_gateway = H160::repeat_byte(0x99);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 3);
+
+        assert_ok!(EthereumGateway::rotate_gateway(root_origin(), new_gateway));
+        assert_eq!(gateway_address(), new_gateway);
+
+        // Messages emitted by the old gateway before the rotation are still relayed
+        let mut message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 1);
+        message.gateway = old_gateway;
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            )
+        );
+
+        let message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 2);
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            )
+        );
+
+        // Once the grace period is over, only the new gateway is accepted
+        System::set_block_number(System::block_number() + GatewayRotationGracePeriod::get() + 1);
+        let mut message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 3);
+        message.gateway = old_gateway;
+        assert_noop!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            ),
+            snowbridge_pallet_inbound_queue_v2::Error::<Runtime>::InvalidGateway
+        );
+    });
+}
+
+#[test]
+fn rotated_gateway_nonces_start_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let old_gateway = gateway_address();
+        let new_gateway = H160::repeat_byte(0x99);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 3);
+
+        let message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 1);
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            )
+        );
+
+        assert_ok!(EthereumGateway::rotate_gateway(root_origin(), new_gateway));
+        assert_eq!(
+            datahaven_mainnet_runtime::configs::runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress::get(),
+            new_gateway
+        );
+
+        // The new gateway starts numbering its messages from scratch
+        let message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 1);
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            )
+        );
+
+        // The nonces of the old gateway are still tracked
+        let mut message = create_message(token_id, TRANSFER_AMOUNT, ETH_BOB, 1);
+        message.gateway = old_gateway;
+        assert_noop!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            ),
+            snowbridge_pallet_inbound_queue_v2::Error::<Runtime>::InvalidNonce
+        );
+    });
+}
+
 #[test]
 fn message_routing_works_correctly() {
     ExtBuilder::default().build().execute_with(|| {
@@ -456,3 +679,124 @@ fn message_routing_works_correctly() {
         );
     });
 }
+
+// === Deposit Tests ===
+
+#[test]
+fn deposit_processor_is_disabled_by_default() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = account_id(ALICE);
+        let message = create_deposit_message(
+            H160::zero(),
+            H256::repeat_byte(1),
+            ETH_ALICE,
+            TRANSFER_AMOUNT,
+        );
+
+        assert_eq!(DepositContractAddress::get(), H160::zero());
+        assert!(!HaveDepositMessageProcessor::<
+            Runtime,
+            DepositContractAddress,
+        >::can_process_message(&alice, &message));
+    });
+}
+
+#[test]
+fn deposit_message_mints_to_recipient() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_deposit_contract(DEPOSIT_CONTRACT);
+        let alice = account_id(ALICE);
+        let deposit_id = H256::repeat_byte(1);
+        let issuance_before = Balances::total_issuance();
+        let message =
+            create_deposit_message(DEPOSIT_CONTRACT, deposit_id, ETH_ALICE, TRANSFER_AMOUNT);
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        let recipient: AccountId = ETH_ALICE.into();
+        assert_eq!(Balances::balance(&recipient), TRANSFER_AMOUNT);
+        assert_eq!(
+            Balances::total_issuance(),
+            issuance_before + TRANSFER_AMOUNT
+        );
+        assert!(System::events().iter().any(|e| matches!(
+            &e.event,
+            RuntimeEvent::DataHavenNativeTransfer(NativeTransferEvent::DepositMinted {
+                deposit_id: id,
+                ..
+            }) if *id == deposit_id
+        )));
+    });
+}
+
+#[test]
+fn deposit_message_keeps_the_supply_ledger_balanced() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_deposit_contract(DEPOSIT_CONTRACT);
+        assert_ok!(SupplyLedger::check_issuance());
+
+        let message = create_deposit_message(
+            DEPOSIT_CONTRACT,
+            H256::repeat_byte(1),
+            ETH_ALICE,
+            TRANSFER_AMOUNT,
+        );
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                account_id(ALICE),
+                message
+            )
+        );
+
+        assert_eq!(SupplyLedger::supply_totals().bridge_minted, TRANSFER_AMOUNT);
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn deposit_message_cannot_be_replayed() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_deposit_contract(DEPOSIT_CONTRACT);
+        let alice = account_id(ALICE);
+        let message = create_deposit_message(
+            DEPOSIT_CONTRACT,
+            H256::repeat_byte(1),
+            ETH_ALICE,
+            TRANSFER_AMOUNT,
+        );
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(
+                alice.clone(),
+                message.clone()
+            )
+        );
+        assert_noop!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message),
+            pallet_datahaven_native_transfer::Error::<Runtime>::DepositAlreadyProcessed
+        );
+    });
+}
+
+#[test]
+fn deposit_message_from_other_origin_is_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_deposit_contract(DEPOSIT_CONTRACT);
+        let alice = account_id(ALICE);
+        let message =
+            create_deposit_message(ETH_BOB, H256::repeat_byte(1), ETH_ALICE, TRANSFER_AMOUNT);
+
+        assert!(!HaveDepositMessageProcessor::<
+            Runtime,
+            DepositContractAddress,
+        >::can_process_message(&alice, &message));
+        assert_noop!(
+            HaveDepositMessageProcessor::<Runtime, DepositContractAddress>::process_message(
+                alice, message
+            ),
+            DispatchError::Other("unauthorized deposit origin")
+        );
+    });
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM tests for the DataHaven Mainnet runtime
+
+mod common;
+use common::*;
+
+use datahaven_mainnet_runtime::{
+    currency::HAVE, Balance, Balances, PolkadotXcm, Runtime, RuntimeCall, RuntimeOrigin,
+};
+use frame_support::weights::Weight;
+use xcm::prelude::*;
+
+/// Program moving `amount` of the native balance of the origin to `to`.
+fn transfer_to(to: [u8; 20], amount: Balance) -> Xcm<RuntimeCall> {
+    Xcm(vec![
+        WithdrawAsset((Here, amount).into()),
+        DepositAsset {
+            assets: AllCounted(1).into(),
+            beneficiary: AccountKey20 {
+                network: None,
+                key: to,
+            }
+            .into(),
+        },
+    ])
+}
+
+#[test]
+fn accounts_cannot_execute_programs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_balance = Balances::free_balance(account_id(ALICE));
+
+        let result = PolkadotXcm::execute(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedXcm::from(transfer_to(BOB, 100 * HAVE))),
+            Weight::from_parts(4_000_000_000, 128 * 1024),
+        );
+
+        assert_eq!(
+            result.map_err(|e| e.error),
+            Err(pallet_xcm::Error::<Runtime>::Filtered.into())
+        );
+        assert_eq!(Balances::free_balance(account_id(ALICE)), alice_balance);
+    });
+}
+
+#[test]
+fn sending_fails_without_a_transport() {
+    ExtBuilder::default().build().execute_with(|| {
+        let result = PolkadotXcm::send(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedLocation::from(Location::parent())),
+            Box::new(VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]))),
+        );
+
+        assert_eq!(
+            result,
+            Err(pallet_xcm::Error::<Runtime>::Unreachable.into())
+        );
+    });
+}
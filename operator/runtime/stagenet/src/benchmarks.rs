@@ -43,6 +43,7 @@ frame_benchmarking::define_benchmarks!(
     [pallet_message_queue, MessageQueue]
     [pallet_safe_mode, SafeMode]
     [pallet_tx_pause, TxPause]
+    [pallet_vesting, Vesting]
 
     // StorageHub pallets
     [pallet_nfts, Nfts]
@@ -66,6 +67,8 @@ frame_benchmarking::define_benchmarks!(
     [pallet_external_validators_rewards, ExternalValidatorsRewards]
     [pallet_external_validator_slashes, ExternalValidatorsSlashes]
     [pallet_datahaven_native_transfer, DataHavenNativeTransfer]
+    [pallet_ethereum_gateway, EthereumGateway]
+    [pallet_maintenance_mode, MaintenanceMode]
 
     // Snowbridge pallets
     [snowbridge_pallet_ethereum_client, EthereumBeaconClient]
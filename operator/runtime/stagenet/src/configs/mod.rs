@@ -17,18 +17,20 @@
 pub mod governance;
 pub mod runtime_params;
 mod storagehub;
+pub mod xcm_config;
 
 use super::{
     currency::*,
     precompiles::{DataHavenPrecompiles, PrecompileName},
     AccountId, Babe, Balance, Balances, BeefyMmrLeaf, Block, BlockNumber, EthereumBeaconClient,
     EthereumOutboundQueueV2, EvmChainId, ExistentialDeposit, ExternalValidators,
-    ExternalValidatorsRewards, ExternalValidatorsSlashes, Hash, Historical, ImOnline, MessageQueue,
-    MultiBlockMigrations, Nonce, Offences, OriginCaller, OutboundCommitmentStore, PalletInfo,
-    Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
-    RuntimeHoldReason, RuntimeOrigin, RuntimeTask, SafeMode, Scheduler, Session, SessionKeys,
-    Signature, System, Timestamp, Treasury, TxPause, BLOCK_HASH_COUNT, EXTRINSIC_BASE_WEIGHT,
-    MAXIMUM_BLOCK_WEIGHT, NORMAL_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    ExternalValidatorsRewards, ExternalValidatorsSlashes, Hash, Historical, ImOnline,
+    MaintenanceMode, MessageQueue, MultiBlockMigrations, Nonce, Offences, OriginCaller,
+    OutboundCommitmentStore, PalletInfo, Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, SafeMode, Scheduler,
+    Session, SessionKeys, Signature, SupplyLedger, System, Timestamp, Treasury, TxPause,
+    WeightToFee, BLOCK_HASH_COUNT, EXTRINSIC_BASE_WEIGHT, MAXIMUM_BLOCK_WEIGHT,
+    NORMAL_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
 };
 use alloc::vec::Vec;
 use alloy_core::primitives::Address;
@@ -88,6 +90,7 @@ use datahaven_runtime_common::{
         FailedMigrationHandler, MigrationCursorMaxLen, MigrationIdentifierMaxLen,
         MigrationStatusHandler,
     },
+    outbound_schema::OutboundSchemaVersionOf,
     safe_mode::{
         ReleaseDelayNone, RuntimeCallFilter, SafeModeDuration, SafeModeEnterDeposit,
         SafeModeExtendDeposit, TxPauseWhitelistedCalls,
@@ -102,10 +105,11 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit, HoldConsideration, Inspect},
         tokens::{PayFromAccount, UnityAssetBalanceConversion},
-        ConstU128, ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse, EqualPrivilegeOnly,
-        FindAuthor, KeyOwnerProofSystem, LinearStoragePrice, OnUnbalanced, VariantCountOf,
+        ConstU128, ConstU32, ConstU64, ConstU8, Contains, EitherOf, EitherOfDiverse,
+        EqualPrivilegeOnly, FindAuthor, KeyOwnerProofSystem, LinearStoragePrice, MapSuccess,
+        OnUnbalanced, VariantCountOf, WithdrawReasons,
     },
-    weights::{constants::RocksDbWeight, IdentityFee, RuntimeDbWeight, Weight},
+    weights::{constants::RocksDbWeight, ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight},
     PalletId,
 };
 use frame_system::{limits::BlockLength, EnsureRoot, EnsureRootWithSuccess};
@@ -131,7 +135,7 @@ use snowbridge_outbound_queue_primitives::{
     v2::ConstantGasMeter,
     SendError, SendMessageFeeProvider,
 };
-use snowbridge_pallet_outbound_queue_v2::OnNewCommitment;
+use snowbridge_pallet_outbound_queue_v2::{OnMessageDelivered, OnNewCommitment};
 use snowbridge_pallet_system::BalanceOf;
 use sp_consensus_beefy::{
     ecdsa_crypto::AuthorityId as BeefyId,
@@ -140,8 +144,10 @@ use sp_consensus_beefy::{
 use sp_core::{crypto::KeyTypeId, Get, H160, H256, U256};
 use sp_runtime::FixedU128;
 use sp_runtime::{
-    traits::{Convert, ConvertInto, IdentityLookup, Keccak256, OpaqueKeys, UniqueSaturatedInto},
-    FixedPointNumber, Perbill, Perquintill,
+    traits::{
+        Convert, ConvertInto, IdentityLookup, Keccak256, OpaqueKeys, Replace, UniqueSaturatedInto,
+    },
+    FixedPointNumber, Perbill, Permill, Perquintill,
 };
 use sp_staking::EraIndex;
 use sp_version::RuntimeVersion;
@@ -220,11 +226,32 @@ impl Contains<RuntimeCall> for NormalCallFilter {
             // Note: It is also assumed that EVM calls are only allowed through `Origin::Root` so
             // this can be seen as an additional security
             RuntimeCall::EVM(_) => false,
+            // Burns through the balances pallet bypass the supply ledger, which would then no
+            // longer match the total issuance.
+            RuntimeCall::Balances(pallet_balances::Call::burn { .. }) => false,
             _ => true,
         }
     }
 }
 
+/// Call filter during maintenance: on top of the normal filter, suspends the calls that cross
+/// the bridge or run the EVM.
+pub struct MaintenanceCallFilter;
+impl Contains<RuntimeCall> for MaintenanceCallFilter {
+    fn contains(c: &RuntimeCall) -> bool {
+        match c {
+            RuntimeCall::Ethereum(_) => false,
+            RuntimeCall::EVM(_) => false,
+            RuntimeCall::PolkadotXcm(_) => false,
+            RuntimeCall::EthereumInboundQueueV2(_) => false,
+            RuntimeCall::DataHavenNativeTransfer(
+                pallet_datahaven_native_transfer::Call::transfer_to_ethereum { .. },
+            ) => false,
+            _ => NormalCallFilter::contains(c),
+        }
+    }
+}
+
 /// Calls that can bypass the safe-mode pallet.
 /// These calls are essential for emergency governance, system maintenance, and basic operation.
 pub struct SafeModeWhitelistedCalls;
@@ -239,6 +266,8 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
             RuntimeCall::SafeMode(_) => true,
             // Transaction pause management
             RuntimeCall::TxPause(_) => true,
+            // Maintenance mode management
+            RuntimeCall::MaintenanceMode(_) => true,
             // Emergency admin access (testnet/dev only)
             RuntimeCall::Sudo(_) => true,
             // Governance infrastructure - critical for emergency responses
@@ -255,7 +284,7 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
 }
 
 pub type StagenetRuntimeCallFilter =
-    RuntimeCallFilter<RuntimeCall, NormalCallFilter, SafeMode, TxPause>;
+    RuntimeCallFilter<RuntimeCall, MaintenanceMode, SafeMode, TxPause>;
 
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
 /// [`SoloChainDefaultConfig`](`struct@frame_system::config_preludes::SolochainDefaultConfig`),
@@ -348,7 +377,7 @@ impl pallet_balances::Config for Runtime {
     type Balance = Balance;
     /// The ubiquitous event type.
     type RuntimeEvent = RuntimeEvent;
-    type DustRemoval = ();
+    type DustRemoval = SupplyLedger;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = stagenet_weights::pallet_balances::WeightInfo<Runtime>;
@@ -479,6 +508,27 @@ pub type FastAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
     MaximumMultiplier,
 >;
 
+/// Relative increase of the fee multiplier, and therefore of the EVM base fee, after a full block.
+///
+/// `eth_feeHistory` uses it to predict the base fee of the next block, so it has to follow
+/// `FastAdjustingFeeUpdate`: with a full block, `diff = 1 - target` and the multiplier grows by
+/// `(v * diff) + ((v * diff)^2 / 2)`.
+pub struct BaseFeeElasticity;
+impl Get<Permill> for BaseFeeElasticity {
+    fn get() -> Permill {
+        let diff = Multiplier::saturating_from_rational(
+            (Perquintill::one() - TargetBlockFullness::get()).deconstruct(),
+            Perquintill::one().deconstruct(),
+        );
+        let first_term = AdjustmentVariable::get().saturating_mul(diff);
+        let second_term = first_term
+            .saturating_mul(first_term)
+            .saturating_mul(Multiplier::saturating_from_rational(1, 2));
+        let increase = first_term.saturating_add(second_term);
+        Permill::from_rational(increase.into_inner(), Multiplier::DIV)
+    }
+}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnChargeTransaction = FungibleAdapter<
@@ -490,7 +540,7 @@ impl pallet_transaction_payment::Config for Runtime {
     >;
     type OperationalFeeMultiplier = ConstU8<5>;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type WeightToFee = IdentityFee<Balance>;
+    type WeightToFee = WeightToFee;
     #[cfg(feature = "runtime-benchmarks")]
     type WeightToFee = benchmark_helpers::BenchmarkWeightToFee;
     #[cfg(not(feature = "runtime-benchmarks"))]
@@ -530,11 +580,19 @@ parameter_types! {
     pub LeafVersion: MmrLeafVersion = MmrLeafVersion::new(0, 0);
 }
 
+/// Extra data of the BEEFY MMR leaves, the `leaf_extra` of `MmrLeaf`.
+///
+/// It is the latest commitment of the outbound queue, the Merkle root of the messages sent to
+/// Ethereum, SCALE-encoded as its 32 bytes. A leaf committing to a block without outbound
+/// messages carries the commitment of the last block that had some, and blocks before the
+/// first commitment carry zero. Light clients on Ethereum verify the messages against it once
+/// the leaf is proven under a BEEFY finalized MMR root.
 #[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
 pub struct LeafExtraData {
     extra: H256,
 }
 
+/// Provides the latest outbound queue commitment as the extra data of the BEEFY MMR leaves.
 pub struct LeafExtraDataProvider;
 impl BeefyDataProvider<LeafExtraData> for LeafExtraDataProvider {
     fn extra_data() -> LeafExtraData {
@@ -725,6 +783,10 @@ impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
                             | RuntimeCall::ConvictionVoting(..)
                             | RuntimeCall::TreasuryCouncil(..)
                             | RuntimeCall::TechnicalCommittee(..)
+                            | RuntimeCall::Vesting(
+                                pallet_vesting::Call::vest {}
+                                    | pallet_vesting::Call::vest_other { .. }
+                            )
                     )
                 }
             },
@@ -741,7 +803,13 @@ impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
             }
             ProxyType::Staking => {
                 // Todo: Add additional staking calls when available
-                matches!(c, RuntimeCall::Utility(..))
+                matches!(
+                    c,
+                    RuntimeCall::Utility(..)
+                        | RuntimeCall::ExternalValidatorsSlashes(
+                            pallet_external_validator_slashes::Call::acknowledge_slash { .. }
+                        )
+                )
             }
             ProxyType::CancelProxy => {
                 matches!(
@@ -857,6 +925,58 @@ impl pallet_proxy_genesis_companion::Config for Runtime {
     type ProxyType = ProxyType;
 }
 
+impl pallet_supply_ledger::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+}
+
+parameter_types! {
+    /// Messages of the replaced gateway are still relayed for a week after a rotation.
+    pub const GatewayRotationGracePeriod: BlockNumber = 7 * DAYS;
+}
+
+/// Keeps the active gateway in the `EthereumGatewayAddress` parameter, which rotations update.
+pub struct EthereumGatewayParameter;
+impl pallet_ethereum_gateway::GatewayStore for EthereumGatewayParameter {
+    fn get() -> H160 {
+        runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress::get()
+    }
+
+    fn set(gateway: H160) -> sp_runtime::DispatchResult {
+        pallet_parameters::Pallet::<Runtime>::set_parameter(
+            RuntimeOrigin::root(),
+            RuntimeParameters::RuntimeConfig(
+                runtime_params::dynamic_params::runtime_config::Parameters::EthereumGatewayAddress(
+                    runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress,
+                    Some(gateway),
+                ),
+            ),
+        )
+    }
+}
+
+impl pallet_ethereum_gateway::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type GatewayOrigin = EnsureRoot<AccountId>;
+    type ActiveGateway = EthereumGatewayParameter;
+    type RotationGracePeriod = GatewayRotationGracePeriod;
+    type WeightInfo = stagenet_weights::pallet_ethereum_gateway::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    /// Failed deliveries in a row after which the bridge is put in maintenance.
+    pub const MaxConsecutiveDeliveryFailures: u32 = 5;
+}
+
+impl pallet_maintenance_mode::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type NormalCallFilter = NormalCallFilter;
+    type MaintenanceCallFilter = MaintenanceCallFilter;
+    type MaintenanceOrigin = governance::referenda::FastGeneralAdminOrRoot;
+    type MaxConsecutiveDeliveryFailures = MaxConsecutiveDeliveryFailures;
+    type WeightInfo = stagenet_weights::pallet_maintenance_mode::WeightInfo<Runtime>;
+}
+
 impl pallet_parameters::Config for Runtime {
     type AdminOrigin = EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
@@ -955,6 +1075,25 @@ impl pallet_treasury::Config for Runtime {
     type BlockNumberProvider = System;
 }
 
+parameter_types! {
+    pub const MinVestedTransfer: Balance = HAVE;
+    /// Unvested funds can still pay fees, but cannot be transferred or reserved.
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+    // Upstream weights until pallet_vesting is benchmarked on the DataHaven reference hardware
+    type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    type BlockNumberProvider = System;
+    const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
 //╔═══════════════════════════════════════════════════════════════════════════════════════════════════════════════╗
 //║                                        FRONTIER (EVM) PALLETS                                                 ║
 //╚═══════════════════════════════════════════════════════════════════════════════════════════════════════════════╝
@@ -970,6 +1109,38 @@ impl pallet_ethereum::Config for Runtime {
     type ExtraDataLength = ConstU32<30>;
 }
 
+/// EVM gas used by the current block, read from the Ethereum block stored by
+/// `pallet_ethereum::on_finalize`.
+pub struct EvmBlockGasUsed;
+impl Get<u64> for EvmBlockGasUsed {
+    fn get() -> u64 {
+        pallet_ethereum::CurrentBlock::<Runtime>::get()
+            .map(|block| block.header.gas_used.low_u64())
+            .unwrap_or_default()
+    }
+}
+
+pub struct NextFeeMultiplier;
+impl Get<Multiplier> for NextFeeMultiplier {
+    fn get() -> Multiplier {
+        TransactionPayment::next_fee_multiplier()
+    }
+}
+
+parameter_types! {
+    /// Ten minutes of 6 second blocks.
+    pub const CongestionAverageWindow: u32 = 100;
+}
+
+impl pallet_evm_congestion::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type GasUsed = EvmBlockGasUsed;
+    type BlockGasLimit = BlockGasLimit;
+    type TargetBlockFullness = TargetBlockFullness;
+    type FeeMultiplier = NextFeeMultiplier;
+    type AverageWindow = CongestionAverageWindow;
+}
+
 // Ported from Moonbeam, please check for reference: https://github.com/moonbeam-foundation/moonbeam/pull/1765
 pub struct TransactionPaymentAsGasPrice;
 impl FeeCalculator for TransactionPaymentAsGasPrice {
@@ -978,9 +1149,10 @@ impl FeeCalculator for TransactionPaymentAsGasPrice {
         //       scaled by the multiplier, which means its multiplier will be overstated when
         //       applied to an ethereum transaction
         // note: transaction-payment uses both a congestion modifier (next_fee_multiplier, which is
-        //       updated once per block in on_finalize) and a 'WeightToFee' implementation. Our
-        //       runtime implements this as a 'ConstantModifier', so we can get away with a simple
-        //       multiplication here.
+        //       updated once per block in on_finalize) and a 'WeightToFee' polynomial. The gas
+        //       price keeps its own constant price per unit of weight, 'WEIGHT_FEE', and only
+        //       follows the congestion modifier, so we can get away with a simple multiplication
+        //       here.
         let min_gas_price: u128 = TransactionPayment::<Runtime>::next_fee_multiplier()
             .saturating_mul_int((WEIGHT_FEE).saturating_mul(WEIGHT_PER_GAS as u128));
         (
@@ -1262,14 +1434,37 @@ impl snowbridge_inbound_queue_primitives::v2::MessageProcessor<AccountId> for No
     }
 }
 
+/// Verifier of the `dev-nobridge` runtime, accepting every message without a beacon client
+/// so that the messages from Ethereum can be submitted on a local chain.
+#[cfg(feature = "dev-nobridge")]
+pub struct NoBridgeVerifier;
+
+#[cfg(feature = "dev-nobridge")]
+impl snowbridge_verification_primitives::Verifier for NoBridgeVerifier {
+    fn verify(
+        _event: &snowbridge_verification_primitives::Log,
+        _proof: &snowbridge_verification_primitives::Proof,
+    ) -> Result<(), snowbridge_verification_primitives::VerificationError> {
+        Ok(())
+    }
+}
+
 impl snowbridge_pallet_inbound_queue_v2::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    #[cfg(not(feature = "dev-nobridge"))]
     type Verifier = EthereumBeaconClient;
-    type GatewayAddress = runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress;
+    #[cfg(feature = "dev-nobridge")]
+    type Verifier = NoBridgeVerifier;
+    type GatewayAddress = pallet_ethereum_gateway::ActiveGatewayAddress<Runtime>;
+    type PreviousGateways = pallet_ethereum_gateway::PreviousGatewayAddress<Runtime>;
     #[cfg(not(feature = "runtime-benchmarks"))]
     type MessageProcessor = (
         dhp_bridge::EigenLayerMessageProcessor<Runtime>,
         dhp_bridge::NativeTokenTransferMessageProcessor<Runtime>,
+        dhp_bridge::HaveDepositMessageProcessor<
+            Runtime,
+            runtime_params::dynamic_params::runtime_config::HaveDepositContractAddress,
+        >,
     );
     #[cfg(feature = "runtime-benchmarks")]
     type MessageProcessor = NoOpMessageProcessor;
@@ -1291,7 +1486,22 @@ parameter_types! {
 pub struct CommitmentHandler;
 impl OnNewCommitment for CommitmentHandler {
     fn on_new_commitment(commitment: H256) {
-        OutboundCommitmentStore::store_commitment(commitment);
+        OutboundCommitmentStore::store_commitment(
+            pallet_outbound_commitment_store::CommitmentSource::OutboundQueueV2,
+            commitment,
+        );
+    }
+}
+
+/// Settles the fee held for a native token transfer once its delivery is proven, records the
+/// delivery of the era rewards messages, and reports the delivery to the maintenance mode,
+/// which is entered after repeated failures.
+pub struct MessageDeliveryHandler;
+impl OnMessageDelivered for MessageDeliveryHandler {
+    fn on_message_delivered(id: H256, success: bool) {
+        DataHavenNativeTransfer::on_message_delivered(id, success);
+        ExternalValidatorsRewards::on_message_delivered(id, success);
+        MaintenanceMode::note_message_delivery(success);
     }
 }
 
@@ -1304,10 +1514,18 @@ impl snowbridge_pallet_outbound_queue_v2::Config for Runtime {
     type MaxMessagePayloadSize = ConstU32<2048>;
     type MaxMessagesPerBlock = ConstU32<32>;
     type OnNewCommitment = CommitmentHandler;
-    type WeightToFee = IdentityFee<Balance>;
+    type OnMessageDelivered = MessageDeliveryHandler;
+    type WeightToFee = ConstantMultiplier<
+        Balance,
+        runtime_params::dynamic_params::runtime_config::OutboundQueueFeePerWeight,
+    >;
     type WeightInfo = stagenet_weights::snowbridge_pallet_outbound_queue_v2::WeightInfo<Runtime>;
+    #[cfg(not(feature = "dev-nobridge"))]
     type Verifier = EthereumBeaconClient;
-    type GatewayAddress = runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress;
+    #[cfg(feature = "dev-nobridge")]
+    type Verifier = NoBridgeVerifier;
+    type GatewayAddress = pallet_ethereum_gateway::ActiveGatewayAddress<Runtime>;
+    type PreviousGateways = pallet_ethereum_gateway::PreviousGatewayAddress<Runtime>;
     type RewardKind = ();
     type DefaultRewardKind = DefaultRewardKind;
     type RewardPayment = DummyRewardPayment;
@@ -1416,8 +1634,15 @@ impl snowbridge_pallet_system_v2::BenchmarkHelper<RuntimeOrigin> for () {
     }
 }
 
+parameter_types! {
+    /// Outbound commitments kept for the relayers: a little over a day of history when one
+    /// block in 60 commits messages.
+    pub const CommitmentHistorySize: u32 = 256;
+}
+
 impl pallet_outbound_commitment_store::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type HistorySize = CommitmentHistorySize;
 }
 
 parameter_types! {
@@ -1476,7 +1701,7 @@ pub type ExternalRewardsEraInflationProvider =
 /// Wrapper struct for the inflation handler using common runtime implementation.
 ///
 /// Handles minting of inflation tokens by:
-/// 1. Splitting total inflation between rewards and treasury based on InflationTreasuryProportion
+/// 1. Splitting total inflation between rewards and treasury based on the rewards split
 /// 2. Minting rewards portion to the rewards account
 /// 3. Minting treasury portion to the treasury account
 pub struct ExternalRewardsInflationHandler;
@@ -1493,7 +1718,7 @@ impl pallet_external_validators_rewards::types::HandleInflation<AccountId>
     > {
         datahaven_runtime_common::inflation::ExternalRewardsInflationHandler::<
             Balances,
-            runtime_params::dynamic_params::runtime_config::InflationTreasuryProportion,
+            pallet_external_validators_rewards::TreasuryProportion<Runtime>,
             TreasuryAccount,
         >::mint_inflation(who, amount)
     }
@@ -1521,6 +1746,14 @@ impl datahaven_runtime_common::rewards_adapter::RewardsSubmissionConfig for Stag
         runtime_params::dynamic_params::runtime_config::AgentOrigin::get()
     }
 
+    fn outbound_schema_version() -> u8 {
+        runtime_params::dynamic_params::runtime_config::OutboundSchemaVersion::get().version()
+    }
+
+    fn submit_rewards_gas_limit() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SubmitRewardsGasLimit::get()
+    }
+
     fn strategies_and_multipliers() -> Vec<(H160, u128)> {
         runtime_params::dynamic_params::runtime_config::RewardsStrategiesAndMultipliers::get()
             .into_iter()
@@ -1530,7 +1763,7 @@ impl datahaven_runtime_common::rewards_adapter::RewardsSubmissionConfig for Stag
 
     fn handle_remainder(remainder: u128) {
         use frame_support::traits::{fungible::Mutate, tokens::Preservation};
-        let source = ExternalValidatorRewardsAccount::get();
+        let source = ExternalValidatorsRewards::rewards_account();
         let dest = TreasuryAccount::get();
         if let Err(e) = Balances::transfer(&source, &dest, remainder, Preservation::Preserve) {
             log::error!(
@@ -1573,8 +1806,27 @@ parameter_types! {
 
     /// Maximum inflation percentage (caps at 100% even if blocks exceed expectations)
     pub const MaxInflationPercent: u32 = 100;
+
+    /// Era rewards are paid out on Ethereum by EigenLayer
+    pub const RewardsPayout: pallet_external_validators_rewards::types::RewardsPayout =
+        pallet_external_validators_rewards::types::RewardsPayout::Ethereum;
+
+    /// Validators rewarded per era, well above `MaxExternalValidators` so that only a set
+    /// rotating many times within an era gets capped
+    pub const MaxRewardedValidatorsPerEra: u32 = 1_000;
+
+    /// Steps of the end of an era processed per block, so that up to 200 rewarded validators
+    /// are handled when the era ends and larger sets over the next blocks
+    pub const EraEndStepsPerBlock: u32 = 200;
+
+    /// Proportion of the inflation minted to the treasury until governance sets the split
+    pub const DefaultRewardsSplit: Perbill = Perbill::from_percent(20);
 }
 
+/// Notified of the slashes, rewards and native transfer messages accepted by the outbound
+/// queue. Pallets reacting to the messages sent to Ethereum are added to this tuple.
+pub type OutboundMessageObservers = ();
+
 impl pallet_external_validators_rewards::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type EraIndexProvider = ExternalValidators;
@@ -1589,17 +1841,31 @@ impl pallet_external_validators_rewards::Config for Runtime {
         runtime_params::dynamic_params::runtime_config::OperatorRewardsBlockAuthoringWeight;
     type LivenessWeight =
         runtime_params::dynamic_params::runtime_config::OperatorRewardsLivenessWeight;
+    type LivenessCheck = pallet_external_validator_slashes::ImOnlineLiveness<Runtime>;
     type FairShareCap = runtime_params::dynamic_params::runtime_config::OperatorRewardsFairShareCap;
     type ExpectedBlocksPerEra = ExpectedBlocksPerEra;
     type MinInflationPercent = MinInflationPercent;
     type MaxInflationPercent = MaxInflationPercent;
+    type RewardsPayout = RewardsPayout;
+    type MaxRewardedValidatorsPerEra = MaxRewardedValidatorsPerEra;
+    type EraEndStepsPerBlock = EraEndStepsPerBlock;
+    type OperatorAddresses = ExternalValidators;
     type Hashing = Keccak256;
     type Currency = Balances;
     type RewardsEthereumSovereignAccount = ExternalValidatorRewardsAccount;
     type SendMessage = RewardsSendAdapter;
+    type OutboundSchemaVersion = OutboundSchemaVersionOf<
+        runtime_params::dynamic_params::runtime_config::OutboundSchemaVersion,
+    >;
+    type OnOutboundMessageSent = OutboundMessageObservers;
+    type HoldOutboundMessages = pallet_maintenance_mode::InMaintenance<Runtime>;
     type HandleInflation = ExternalRewardsInflationHandler;
+    type DefaultRewardsSplit = DefaultRewardsSplit;
+    type RewardsLedger = SupplyLedger;
     type GovernanceOrigin =
         EitherOfDiverse<EnsureRoot<AccountId>, governance::custom_origins::GeneralAdmin>;
+    // Allows deterministic end-to-end tests of the rewards path
+    type InflationOverrideOrigin = EnsureRoot<AccountId>;
     type WeightInfo = stagenet_weights::pallet_external_validators_rewards::WeightInfo<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
@@ -1626,29 +1892,47 @@ impl Get<Option<TokenId>> for DataHavenTokenId {
     }
 }
 
-/// Mock implementation for benchmarks
-#[cfg(feature = "runtime-benchmarks")]
+/// Mock implementation for benchmarks and the `dev-nobridge` runtime
+#[cfg(any(feature = "runtime-benchmarks", feature = "dev-nobridge"))]
 pub struct MockNativeTokenId;
-#[cfg(feature = "runtime-benchmarks")]
+#[cfg(any(feature = "runtime-benchmarks", feature = "dev-nobridge"))]
 impl Get<Option<TokenId>> for MockNativeTokenId {
     fn get() -> Option<TokenId> {
-        // For benchmarks, always return a valid token ID
+        // Always return a valid token ID
         // This represents a pre-registered native token
         Some(TokenId::from([1u8; 32]))
     }
 }
 
+parameter_types! {
+    /// Fees of native token transfers whose delivery is not proven after this delay are
+    /// refunded.
+    pub const NativeTransferFeeRefundTimeout: BlockNumber = 7 * DAYS;
+    /// Receipts of native token transfers are kept for this long after the transfer.
+    pub const NativeTransferReceiptRetention: BlockNumber = 30 * DAYS;
+}
+
 impl pallet_datahaven_native_transfer::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type EthereumSovereignAccount = EthereumSovereignAccount;
     type OutboundQueue = EthereumOutboundQueueV2;
-    #[cfg(feature = "runtime-benchmarks")]
+    #[cfg(any(feature = "runtime-benchmarks", feature = "dev-nobridge"))]
     type NativeTokenId = MockNativeTokenId;
-    #[cfg(not(feature = "runtime-benchmarks"))]
+    #[cfg(not(any(feature = "runtime-benchmarks", feature = "dev-nobridge")))]
     type NativeTokenId = DataHavenTokenId;
     type FeeRecipient = TreasuryAccount;
+    type FeeRefundTimeout = NativeTransferFeeRefundTimeout;
+    type TransferReceiptRetention = NativeTransferReceiptRetention;
     type PauseOrigin = EnsureRoot<AccountId>;
+    type Ledger = SupplyLedger;
+    type OnOutboundMessageSent = OutboundMessageObservers;
+    type EraIndexProvider = ExternalValidators;
+    type LargeTransferThreshold =
+        runtime_params::dynamic_params::runtime_config::LargeTransferThreshold;
+    type LargeTransferDelay = runtime_params::dynamic_params::runtime_config::LargeTransferDelay;
+    type SecurityOrigin = EnsureRoot<AccountId>;
     type WeightInfo = stagenet_weights::pallet_datahaven_native_transfer::WeightInfo<Runtime>;
 }
 
@@ -1656,6 +1940,18 @@ impl pallet_datahaven_native_transfer::Config for Runtime {
 //║                                          SAFE MODE & TX PAUSE PALLETS                                           ║
 //╚══════════════════════════════════════════════════════════════════════════════════════════════════════════════════╝
 
+/// Root or the fast general admin track can force safe mode, for `SafeModeDuration` blocks.
+pub type SafeModeForceOrigin = EitherOf<
+    EnsureRootWithSuccess<AccountId, SafeModeDuration>,
+    MapSuccess<
+        EitherOf<
+            governance::custom_origins::GeneralAdmin,
+            governance::custom_origins::FastGeneralAdmin,
+        >,
+        Replace<SafeModeDuration>,
+    >,
+>;
+
 impl pallet_safe_mode::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -1665,9 +1961,9 @@ impl pallet_safe_mode::Config for Runtime {
     type ExtendDuration = SafeModeDuration;
     type EnterDepositAmount = SafeModeEnterDeposit;
     type ExtendDepositAmount = SafeModeExtendDeposit;
-    type ForceEnterOrigin = EnsureRootWithSuccess<AccountId, SafeModeDuration>;
-    type ForceExtendOrigin = EnsureRootWithSuccess<AccountId, SafeModeDuration>;
-    type ForceExitOrigin = EnsureRoot<AccountId>;
+    type ForceEnterOrigin = SafeModeForceOrigin;
+    type ForceExtendOrigin = SafeModeForceOrigin;
+    type ForceExitOrigin = governance::referenda::FastGeneralAdminOrRoot;
     type ForceDepositOrigin = EnsureRoot<AccountId>;
     type ReleaseDelay = ReleaseDelayNone;
     type Notify = ();
@@ -1677,8 +1973,8 @@ impl pallet_safe_mode::Config for Runtime {
 impl pallet_tx_pause::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type PauseOrigin = EnsureRoot<AccountId>;
-    type UnpauseOrigin = EnsureRoot<AccountId>;
+    type PauseOrigin = governance::referenda::FastGeneralAdminOrRoot;
+    type UnpauseOrigin = governance::referenda::FastGeneralAdminOrRoot;
     type WhitelistedCalls = TxPauseWhitelistedCalls<Runtime>;
     type MaxNameLen = ConstU32<256>;
     type WeightInfo = stagenet_weights::pallet_tx_pause::WeightInfo<Runtime>;
@@ -1699,6 +1995,14 @@ impl datahaven_runtime_common::slashes_adapter::SlashesSubmissionConfig for Stag
         // TODO: Can we use the same as reward and just rename the config to `AgentOrigin` ?
     }
 
+    fn outbound_schema_version() -> u8 {
+        runtime_params::dynamic_params::runtime_config::OutboundSchemaVersion::get().version()
+    }
+
+    fn slash_validators_gas_limit() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SlashValidatorsGasLimit::get()
+    }
+
     fn strategies() -> Vec<Address> {
         // We only slash strategy that we reward
         let mut strategies: Vec<Address> =
@@ -1723,19 +2027,43 @@ impl pallet_external_validator_slashes::Config for Runtime {
     type ValidatorIdOf = ConvertInto;
     type SlashDeferDuration = SlashDeferDuration;
     type BondingDuration = BondingDuration;
+    type OffenceHistoryDepth = OffenceHistoryDepth;
     type SlashId = u32;
     type EraIndexProvider = ExternalValidators;
     type InvulnerablesProvider = ExternalValidators;
     type ExternalIndexProvider = ExternalValidators;
     type MaxSlashWad = runtime_params::dynamic_params::runtime_config::MaxSlashWad;
-    type QueuedSlashesProcessedPerBlock = ConstU32<10>;
+    type QueuedSlashesProcessedPerBlock =
+        runtime_params::dynamic_params::runtime_config::QueuedSlashesProcessedPerBlock;
+    type MaxSlashesPerEra = ConstU32<1000>;
+    type MaxQueuedSlashes = ConstU32<200>;
+    type SlashAggregationMode =
+        runtime_params::dynamic_params::runtime_config::SlashAggregationMode;
+    type SlashPolicy = pallet_external_validator_slashes::EscalatingSlashPolicy<
+        runtime_params::dynamic_params::runtime_config::RepeatOffenceSlashIncrease,
+    >;
+    type OperatorAddresses = ExternalValidators;
+    type MaxSlashMessageRetries = ConstU32<10>;
+    type SlashMessageRetryInterval = ConstU32<10>;
+    type MaxInboxEntries = ConstU32<16>;
     type WeightInfo = stagenet_weights::pallet_external_validator_slashes::WeightInfo<Runtime>;
     type SendMessage = SlashesSendAdapter;
+    type OutboundSchemaVersion = OutboundSchemaVersionOf<
+        runtime_params::dynamic_params::runtime_config::OutboundSchemaVersion,
+    >;
+    type OnOutboundMessageSent = OutboundMessageObservers;
+    type HoldOutboundMessages = pallet_maintenance_mode::InMaintenance<Runtime>;
     type GovernanceOrigin = EnsureRootWithSuccess<AccountId, RootLocation>;
+    type ForceInjectSlashOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCommitteeInstance, 2, 3>,
+    >;
 }
 
 parameter_types! {
     pub const SlashDeferDuration: EraIndex = polkadot_runtime_common::prod_or_fast!(0, 0);
+    /// The slashes relayed to EigenLayer are kept for about a year of eras.
+    pub const OffenceHistoryDepth: EraIndex = polkadot_runtime_common::prod_or_fast!(365, 10);
 }
 
 #[cfg(test)]
@@ -34,6 +34,13 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 
 const STAGENET_EVM_CHAIN_ID: u64 = 55932;
 
+/// Preset of the genesis of the stagenet network.
+pub const STAGENET_RUNTIME_PRESET: &str = "stagenet";
+
+/// Preset of the single-node development chain of the `dev-nobridge` runtime.
+#[cfg(feature = "dev-nobridge")]
+pub const DEV_NOBRIDGE_RUNTIME_PRESET: &str = "dev-nobridge";
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
     initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, BeefyId)>,
@@ -42,6 +49,7 @@ fn testnet_genesis(
     treasury_council_members: Vec<AccountId>,
     technical_committee_members: Vec<AccountId>,
     evm_chain_id: u64,
+    slashing_mode: SlashingModeOption,
 ) -> Value {
     // This is the simplest bytecode to revert without returning any data.
     // We will pre-deploy it under all of our precompiles to ensure they can be called from
@@ -125,7 +133,7 @@ fn testnet_genesis(
             members: treasury_council_members,
         },
         external_validators_slashes: pallet_external_validator_slashes::GenesisConfig {
-            slashing_mode: SlashingModeOption::LogOnly,
+            slashing_mode,
             ..Default::default()
         },
         ..Default::default()
@@ -159,6 +167,7 @@ pub fn development_config_genesis() -> Value {
         // Technical committee members: Alith and Baltathar
         vec![alith(), baltathar()],
         STAGENET_EVM_CHAIN_ID,
+        SlashingModeOption::LogOnly,
     )
 }
 
@@ -184,6 +193,68 @@ pub fn local_config_genesis() -> Value {
         // Technical committee members: Alith and Baltathar
         vec![alith(), baltathar()],
         STAGENET_EVM_CHAIN_ID,
+        SlashingModeOption::LogOnly,
+    )
+}
+
+/// Return the genesis config preset of the development chain without a bridge.
+///
+/// Same accounts and authority as the development preset, but the slashes are applied and
+/// sent instead of only being logged, so the whole flow can be followed locally.
+#[cfg(feature = "dev-nobridge")]
+pub fn nobridge_development_config_genesis() -> Value {
+    let mut endowed_accounts = pre_funded_accounts();
+    endowed_accounts.sort();
+
+    testnet_genesis(
+        // Alith is the only authority (using Alice's session keys)
+        vec![(
+            alith(),
+            get_from_seed::<BabeId>("Alice"),
+            get_from_seed::<GrandpaId>("Alice"),
+            get_from_seed::<ImOnlineId>("Alice"),
+            get_from_seed::<BeefyId>("Alice"),
+        )],
+        // Alith is Sudo
+        alith(),
+        endowed_accounts,
+        // Treasury Council members: Baltathar, Charleth and Dorothy
+        vec![baltathar(), charleth(), dorothy()],
+        // Technical committee members: Alith and Baltathar
+        vec![alith(), baltathar()],
+        STAGENET_EVM_CHAIN_ID,
+        SlashingModeOption::Enabled,
+    )
+}
+
+/// Return the genesis config preset of the stagenet network.
+///
+/// Only the authorities, the sudo key and the beacon relayer are funded, and the slashes are
+/// applied. The authorities, the sudo key and the council members are development accounts, to
+/// be replaced before launching the network with `build-spec --preset stagenet --patch <file>`.
+pub fn stagenet_config_genesis() -> Value {
+    let initial_authorities = vec![
+        authority_keys_from_seed("Alice"),
+        authority_keys_from_seed("Bob"),
+    ];
+    let mut endowed_accounts = initial_authorities
+        .iter()
+        .map(|(account, ..)| *account)
+        .chain([alith(), beacon_relayer()])
+        .collect::<Vec<_>>();
+    endowed_accounts.sort();
+
+    testnet_genesis(
+        initial_authorities,
+        // Alith is Sudo
+        alith(),
+        endowed_accounts,
+        // Treasury Council members: Baltathar, Charleth and Dorothy
+        vec![baltathar(), charleth(), dorothy()],
+        // Technical committee members: Alith and Baltathar
+        vec![alith(), baltathar()],
+        STAGENET_EVM_CHAIN_ID,
+        SlashingModeOption::Enabled,
     )
 }
 
@@ -192,6 +263,9 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
     let patch = match id.as_str() {
         sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
         sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+        STAGENET_RUNTIME_PRESET => stagenet_config_genesis(),
+        #[cfg(feature = "dev-nobridge")]
+        DEV_NOBRIDGE_RUNTIME_PRESET => nobridge_development_config_genesis(),
         _ => return None,
     };
     Some(
@@ -206,6 +280,9 @@ pub fn preset_names() -> Vec<PresetId> {
     vec![
         PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
         PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+        PresetId::from(STAGENET_RUNTIME_PRESET),
+        #[cfg(feature = "dev-nobridge")]
+        PresetId::from(DEV_NOBRIDGE_RUNTIME_PRESET),
     ]
 }
 
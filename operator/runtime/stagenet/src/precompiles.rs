@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::configs::runtime_params::dynamic_params::runtime_config;
 use crate::configs::MaxAdditionalFields;
 use crate::governance::councils::{TechnicalCommitteeInstance, TreasuryCouncilInstance};
 use crate::governance::custom_origins::Origin;
+use frame_support::traits::{Contains, Get};
 use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
 use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
@@ -25,19 +27,39 @@ use pallet_evm_precompile_call_permit::CallPermitPrecompile;
 use pallet_evm_precompile_collective::CollectivePrecompile;
 use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
 use pallet_evm_precompile_datahaven_native_transfer::DataHavenNativeTransferPrecompile;
+use pallet_evm_precompile_era_info::EraInfoPrecompile;
+use pallet_evm_precompile_external_validator_slashes::ExternalValidatorSlashesPrecompile;
+use pallet_evm_precompile_external_validators::ExternalValidatorsPrecompile;
+use pallet_evm_precompile_external_validators_rewards::ExternalValidatorsRewardsPrecompile;
 use pallet_evm_precompile_file_system::FileSystemPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_payment_streams::PaymentStreamsPrecompile;
 use pallet_evm_precompile_preimage::PreimagePrecompile;
 use pallet_evm_precompile_proxy::{OnlyIsProxyAndProxy, ProxyPrecompile};
 use pallet_evm_precompile_referenda::ReferendaPrecompile;
 use pallet_evm_precompile_registry::PrecompileRegistry;
+use pallet_evm_precompile_session_keys::SessionKeysPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_supply_ledger::SupplyLedgerPrecompile;
+use pallet_evm_precompile_vesting::VestingPrecompile;
 use precompile_utils::precompile_set::*;
+use sp_core::H160;
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
 
+/// Governance contract allowed to act on slashes through the slashes precompile.
+/// Nobody is allowed while the `SlashesPrecompileGovernor` parameter is the zero address.
+pub struct SlashesPrecompileGovernor;
+
+impl Contains<H160> for SlashesPrecompileGovernor {
+    fn contains(caller: &H160) -> bool {
+        let governor = runtime_config::SlashesPrecompileGovernor::get();
+        !governor.is_zero() && *caller == governor
+    }
+}
+
 pub struct NativeErc20Metadata;
 
 impl Erc20Metadata for NativeErc20Metadata {
@@ -147,6 +169,46 @@ type DataHavenPrecompilesAt<R> = (
         DataHavenNativeTransferPrecompile<R>,
         (CallableByContract, CallableByPrecompile),
     >,
+    PrecompileAt<
+        AddressU64<2074>,
+        ExternalValidatorsRewardsPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2075>,
+        EraInfoPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2076>,
+        ExternalValidatorSlashesPrecompile<R, SlashesPrecompileGovernor>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2077>,
+        SupplyLedgerPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2078>,
+        VestingPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2079>,
+        PaymentStreamsPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2080>,
+        SessionKeysPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2081>,
+        ExternalValidatorsPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
     PrecompileAt<AddressU64<1028>, FileSystemPrecompile<R>>,
 );
 
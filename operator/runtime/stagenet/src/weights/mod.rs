@@ -18,9 +18,11 @@
 
 // DataHaven pallets
 pub mod pallet_datahaven_native_transfer;
+pub mod pallet_ethereum_gateway;
 pub mod pallet_external_validator_slashes;
 pub mod pallet_external_validators;
 pub mod pallet_external_validators_rewards;
+pub mod pallet_maintenance_mode;
 
 // Snowbridge pallets
 pub mod snowbridge_pallet_ethereum_client;
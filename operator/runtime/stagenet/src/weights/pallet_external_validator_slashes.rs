@@ -114,8 +114,56 @@ impl<T: frame_system::Config> pallet_external_validator_slashes::WeightInfo for
 			.saturating_add(Weight::from_parts(0, 38).saturating_mul(s.into()))
 	}
 	fn retry_unsent_slash_era() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Same as the success path for one queued batch.
 		Self::process_slashes_queue(10)
 	}
+	fn process_failed_slash_messages(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the due message, after reading every failed message to find it, and moving
+		// it to the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn redrive_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Sending the message, after taking it out of the dead-letter queue.
+		Self::process_slashes_queue(s)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn discard_dead_letter_slash_message(s: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Taking the message out of the dead-letter queue, with the proof size per slash of
+		// `process_slashes_queue`.
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(s.into()))
+	}
+	/// Storage: `ExternalValidatorsSlashes::SlashFractionBoundsOf` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::SlashFractionBoundsOf` (`max_values`: None, `max_size`: Some(284), added: 2759, mode: `MaxEncodedLen`)
+	fn set_slash_fraction_bounds() -> Weight {
+		Weight::from_parts(4_080_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::Slashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::Slashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reduce_deferred_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38528`
+		//  Estimated: `41993`
+		Weight::from_parts(1_089_309_061, 41993)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `ExternalValidatorsSlashes::SlashingMode` (r:0 w:1)
 	/// Proof: `ExternalValidatorsSlashes::SlashingMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn set_slashing_mode() -> Weight {
@@ -136,4 +184,15 @@ impl<T: frame_system::Config> pallet_external_validator_slashes::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `ExternalValidatorsSlashes::ValidatorInbox` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::ValidatorInbox` (`max_values`: None, `max_size`: Some(4381), added: 6856, mode: `MaxEncodedLen`)
+	fn acknowledge_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4420`
+		//  Estimated: `7846`
+		// Minimum execution time: 15_962_000 picoseconds.
+		Weight::from_parts(16_540_000, 7846)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -63,16 +63,22 @@ impl<T: frame_system::Config> pallet_external_validators_rewards::WeightInfo for
 	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
 	/// Storage: `ExternalValidatorsRewards::RewardPointsForEra` (r:1 w:0)
 	/// Proof: `ExternalValidatorsRewards::RewardPointsForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsRewards::RewardLeavesForEra` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardLeavesForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-	fn on_era_end() -> Weight {
+	/// The range of component `n` is `[1, 1000]`.
+	fn on_era_end(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `25697`
-		//  Estimated: `29162`
-		// Minimum execution time: 1_855_466_000 picoseconds.
-		Weight::from_parts(1_894_953_000, 29162)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+		//  Measured:  `1697 + n * (76 ±0)`
+		//  Estimated: `5162 + n * (76 ±0)`
+		// Minimum execution time: 182_191_000 picoseconds.
+		Weight::from_parts(181_540_000, 5162)
+			// Standard Error: 1_877
+			.saturating_add(Weight::from_parts(415_966, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 76).saturating_mul(n.into()))
 	}
 
 	fn process_unsent_reward_eras_empty() -> Weight {
@@ -89,7 +95,7 @@ impl<T: frame_system::Config> pallet_external_validators_rewards::WeightInfo for
 	fn process_unsent_reward_eras_success() -> Weight {
 		Weight::from_parts(1_894_953_000, 29162)
 			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 
 	fn process_unsent_reward_eras_failed() -> Weight {
@@ -99,4 +105,112 @@ impl<T: frame_system::Config> pallet_external_validators_rewards::WeightInfo for
 	fn retry_unsent_reward_era() -> Weight {
 		Self::process_unsent_reward_eras_success()
 	}
+
+	fn force_resend_rewards_message() -> Weight {
+		// Success path plus the reads of the era rewards pot and of its delivery
+		Self::process_unsent_reward_eras_success()
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::NextEraInflationOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::NextEraInflationOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_next_era_inflation() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::PerformanceWeightsOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::PerformanceWeightsOverride` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_performance_weights() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::InflationParamsOverride` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::InflationParamsOverride` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_inflation_params() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::RewardsSplit` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardsSplit` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_rewards_split() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `set_transfer_limits` of the native transfer pallet, which also puts a
+		// single storage value. Nothing is read, so nothing is added to the proof.
+		Weight::from_parts(7_688_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::RewardsAccountOverride` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardsAccountOverride` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn set_rewards_account() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `transfer_all` of the balances pallet, which also moves a whole
+		// balance, plus that of `set_transfer_limits` to put the new account. The proof size adds
+		// up the reads listed above.
+		Weight::from_parts(72_523_000, 5697)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::EraRewardAddresses` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::RewardPointsForEra` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardPointsForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsRewards::RewardLeavesForEra` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardLeavesForEra` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsRewards::RewardDestination` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardDestination` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_reward_address() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// The points and leaves of the active era are measured as in `on_era_end`, 76 bytes per
+		// validator, for the 1_000 of `MaxRewardedValidatorsPerEra`. The execution time adds the
+		// time per validator of `on_era_end` for each of them, an upper bound of decoding and
+		// encoding their points and leaves. Hashing the leaf is included in the base cost.
+		Weight::from_parts(440_966_000, 79059)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorsRewards::EraRewardsPots` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardsPots` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::ClaimedRewards` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::ClaimedRewards` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::EraRewardAddresses` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::EraRewardAddresses` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::RewardsAccountOverride` (r:1 w:0)
+	/// Proof: `ExternalValidatorsRewards::RewardsAccountOverride` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn claim_rewards() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate, re-run the benchmark of this pallet on the
+		// reference hardware.
+		// Execution time of `transfer_keep_alive` of the balances pallet, which also moves funds
+		// from an account kept alive, plus 20 µs to hash a full depth proof. The proof size adds
+		// up the reads listed above.
+		Weight::from_parts(78_930_000, 13319)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
@@ -0,0 +1,69 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Block authorship is noted by the rewards pallet, which feeds the performance points.
+
+mod common;
+use common::*;
+
+use datahaven_stagenet_runtime::{Authorship, ExternalValidators, Runtime, System};
+use frame_support::traits::{OnFinalize, OnInitialize};
+use pallet_external_validators::traits::EraIndexProvider;
+use pallet_external_validators_rewards::{BlocksAuthoredInSession, BlocksProducedInEra};
+
+/// Authors the next block with the validator at `validator_index`.
+fn author_block(validator_index: u32) {
+    let block_number = System::block_number() + 1;
+    System::set_block_number(block_number);
+    set_block_author_by_index(validator_index);
+    Authorship::on_initialize(block_number);
+    Authorship::on_finalize(block_number);
+}
+
+#[test]
+fn block_author_is_noted_by_rewards_pallet() {
+    ExtBuilder::default().build().execute_with(|| {
+        let era_index = <ExternalValidators as EraIndexProvider>::active_era().index;
+
+        author_block(0);
+        author_block(0);
+        author_block(1);
+
+        assert_eq!(
+            BlocksAuthoredInSession::<Runtime>::get(get_validator_by_index(0)),
+            2
+        );
+        assert_eq!(
+            BlocksAuthoredInSession::<Runtime>::get(get_validator_by_index(1)),
+            1
+        );
+        assert_eq!(BlocksProducedInEra::<Runtime>::get(era_index), 3);
+    });
+}
+
+#[test]
+fn block_without_author_is_not_noted() {
+    ExtBuilder::default().build().execute_with(|| {
+        let era_index = <ExternalValidators as EraIndexProvider>::active_era().index;
+        let block_number = System::block_number() + 1;
+        System::set_block_number(block_number);
+
+        Authorship::on_initialize(block_number);
+        Authorship::on_finalize(block_number);
+
+        assert_eq!(BlocksProducedInEra::<Runtime>::get(era_index), 0);
+    });
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The BEEFY MMR leaves carry the latest outbound queue commitment as their extra data.
+
+mod common;
+use common::*;
+
+use codec::Encode;
+use datahaven_stagenet_runtime::{configs::LeafExtraDataProvider, OutboundCommitmentStore};
+use pallet_outbound_commitment_store::CommitmentSource;
+use sp_consensus_beefy::mmr::BeefyDataProvider;
+use sp_core::H256;
+
+#[test]
+fn leaf_extra_data_is_zero_before_the_first_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            LeafExtraDataProvider::extra_data().encode(),
+            H256::zero().encode()
+        );
+    });
+}
+
+#[test]
+fn leaf_extra_data_is_the_latest_outbound_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        let commitment = H256::repeat_byte(0xc0);
+        OutboundCommitmentStore::store_commitment(CommitmentSource::OutboundQueueV2, commitment);
+
+        assert_eq!(
+            LeafExtraDataProvider::extra_data().encode(),
+            commitment.encode()
+        );
+    });
+}
@@ -27,6 +27,9 @@ use common::*;
 use datahaven_stagenet_runtime::{
     currency::HAVE, Balances, Runtime, System, UncheckedExtrinsic, VERSION,
 };
+use frame_support::assert_ok;
+use pallet_external_validator_slashes::SlashingModeOption;
+use pallet_external_validator_slashes_runtime_api::runtime_decl_for_external_validator_slashes_api::ExternalValidatorSlashesApiV1;
 use sp_core::H160;
 use sp_runtime::transaction_validity::{
     InvalidTransaction, TransactionSource, TransactionValidityError,
@@ -77,3 +80,66 @@ fn validate_transaction_fails_on_filtered_call() {
         );
     });
 }
+
+#[test]
+fn slashes_api_reports_slashing_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(Runtime::slashing_mode(), SlashingModeOption::Enabled);
+        assert!(Runtime::slashes_for_era(0).is_empty());
+        assert!(Runtime::unreported_queue().is_empty());
+
+        assert_ok!(
+            pallet_external_validator_slashes::Pallet::<Runtime>::set_slashing_mode(
+                root_origin(),
+                SlashingModeOption::LogOnly,
+            )
+        );
+        assert_eq!(Runtime::slashing_mode(), SlashingModeOption::LogOnly);
+    });
+}
+
+#[test]
+fn outbound_fee_parameters_are_governed() {
+    use datahaven_stagenet_runtime::{
+        configs::{runtime_params::dynamic_params::runtime_config, SlashesSendAdapter},
+        EthereumOutboundQueueV2, RuntimeParameters,
+    };
+    use frame_support::traits::Get;
+    use pallet_external_validator_slashes::SendMessage;
+    use snowbridge_outbound_queue_primitives::{v2::Command, SendMessageFeeProvider};
+
+    ExtBuilder::default().build().execute_with(|| {
+        let set_parameter = |parameter: runtime_config::Parameters| {
+            assert_ok!(pallet_parameters::Pallet::<Runtime>::set_parameter(
+                root_origin(),
+                RuntimeParameters::RuntimeConfig(parameter),
+            ));
+        };
+
+        let local_fee = EthereumOutboundQueueV2::local_fee();
+        set_parameter(runtime_config::Parameters::OutboundQueueFeePerWeight(
+            runtime_config::OutboundQueueFeePerWeight,
+            Some(3),
+        ));
+        assert_eq!(EthereumOutboundQueueV2::local_fee(), 3 * local_fee);
+
+        set_parameter(runtime_config::Parameters::SlashValidatorsGasLimit(
+            runtime_config::SlashValidatorsGasLimit,
+            Some(3_000_000),
+        ));
+        let message = SlashesSendAdapter::build(&vec![], 1).expect("message is built; qed");
+        assert!(matches!(
+            message.commands[0],
+            Command::CallContract { gas: 3_000_000, .. }
+        ));
+
+        set_parameter(runtime_config::Parameters::QueuedSlashesProcessedPerBlock(
+            runtime_config::QueuedSlashesProcessedPerBlock,
+            Some(50),
+        ));
+        assert_eq!(
+            <Runtime as pallet_external_validator_slashes::Config>::QueuedSlashesProcessedPerBlock::get(),
+            50
+        );
+    });
+}
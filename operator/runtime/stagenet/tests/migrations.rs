@@ -17,13 +17,23 @@
 #[path = "common.rs"]
 mod common;
 
+use codec::Encode;
 use common::*;
+use datahaven_runtime_common::migrations::{runtime_parameter_storage_key, RemoveRuntimeParameter};
 use datahaven_stagenet_runtime::{
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SafeMode, System,
+    configs::runtime_params::{dynamic_params::runtime_config, RuntimeParametersKey},
+    configs::DefaultRewardsSplit,
+    ExternalValidatorsRewards, InflationTreasuryProportion, InflationTreasuryProportionKey,
+    Parameters, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SafeMode, System,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    storage::unhashed,
+    traits::{Get, OnRuntimeUpgrade, StorageVersion},
+    weights::constants::RocksDbWeight,
 };
-use frame_support::{assert_noop, assert_ok};
 use pallet_migrations::{Call as MigrationsCall, HistoricCleanupSelector};
-use sp_runtime::{traits::Dispatchable, DispatchError};
+use sp_runtime::{traits::Dispatchable, DispatchError, Perbill};
 
 #[test]
 fn migrations_force_calls_are_root_only() {
@@ -144,3 +154,57 @@ fn safe_mode_allows_governance_during_migration_failure() {
         );
     });
 }
+
+#[test]
+fn removed_runtime_parameter_is_cleared() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Keys are derived as `pallet_parameters` stores them
+        let key = RuntimeParametersKey::RuntimeConfig(
+            runtime_config::ParametersKey::InflationAnnualAmount(
+                runtime_config::InflationAnnualAmount,
+            ),
+        );
+        assert_eq!(
+            runtime_parameter_storage_key::<Parameters>(&key.encode()),
+            pallet_parameters::Parameters::<Runtime>::hashed_key_for(key)
+        );
+
+        let removed =
+            runtime_parameter_storage_key::<Parameters>(&InflationTreasuryProportionKey::get());
+        unhashed::put_raw(&removed, &[0, 38, 0, 0, 0, 0]);
+
+        RemoveRuntimeParameter::<Parameters, InflationTreasuryProportionKey, RocksDbWeight>::on_runtime_upgrade();
+
+        assert!(!unhashed::exists(&removed));
+    });
+}
+
+#[test]
+fn treasury_proportion_is_carried_over_to_rewards_split() {
+    ExtBuilder::default().build().execute_with(|| {
+        let removed =
+            runtime_parameter_storage_key::<Parameters>(&InflationTreasuryProportionKey::get());
+
+        // Never set by governance
+        assert_eq!(InflationTreasuryProportion::get(), DefaultRewardsSplit::get());
+
+        // Stored as the parameter key followed by its value
+        let mut stored = InflationTreasuryProportionKey::get().to_vec();
+        stored.extend(Perbill::from_percent(35).encode());
+        unhashed::put_raw(&removed, &stored);
+        assert_eq!(InflationTreasuryProportion::get(), Perbill::from_percent(35));
+
+        StorageVersion::new(1).put::<ExternalValidatorsRewards>();
+        pallet_external_validators_rewards::migrations::MigrateV1ToV2::<
+            Runtime,
+            InflationTreasuryProportion,
+        >::on_runtime_upgrade();
+        RemoveRuntimeParameter::<Parameters, InflationTreasuryProportionKey, RocksDbWeight>::on_runtime_upgrade();
+
+        assert_eq!(
+            pallet_external_validators_rewards::RewardsSplit::<Runtime>::get(),
+            Perbill::from_percent(35)
+        );
+        assert!(!unhashed::exists(&removed));
+    });
+}
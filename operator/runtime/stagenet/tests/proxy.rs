@@ -475,6 +475,52 @@ fn test_proxy_call_with_staking_type() {
         });
 }
 
+#[test]
+fn test_staking_proxy_can_acknowledge_slashes() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (account_id(ALICE), 10_000 * HAVE),
+            (account_id(BOB), 1_000 * HAVE),
+        ])
+        .build()
+        .execute_with(|| {
+            let alice = account_id(ALICE);
+            let bob = account_id(BOB);
+
+            pallet_external_validator_slashes::ValidatorInbox::<Runtime>::insert(
+                &alice,
+                frame_support::BoundedVec::truncate_from(vec![
+                    pallet_external_validator_slashes::InboxEntry {
+                        slash_id: 0,
+                        era: 1,
+                        percentage: sp_runtime::Perbill::from_percent(10),
+                        offence_kind:
+                            pallet_external_validator_slashes::OffenceKind::LivenessOffence,
+                    },
+                ]),
+            );
+            assert_ok!(Proxy::add_proxy(
+                RuntimeOrigin::signed(alice.clone()),
+                bob.clone(),
+                ProxyType::Staking,
+                0
+            ));
+
+            assert_ok!(Proxy::proxy(
+                RuntimeOrigin::signed(bob),
+                alice.clone(),
+                None,
+                Box::new(RuntimeCall::ExternalValidatorsSlashes(
+                    pallet_external_validator_slashes::Call::acknowledge_slash { slash_id: 0 }
+                ))
+            ));
+
+            assert!(
+                !pallet_external_validator_slashes::ValidatorInbox::<Runtime>::contains_key(&alice)
+            );
+        });
+}
+
 #[test]
 fn test_proxy_call_with_identity_judgement_type() {
     ExtBuilder::default()
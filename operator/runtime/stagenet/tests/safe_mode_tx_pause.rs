@@ -445,3 +445,113 @@ mod combined_behaviour {
             });
     }
 }
+
+mod governance_and_filtered_calls {
+    use super::*;
+    use datahaven_stagenet_runtime::{configs::SafeModeDuration, governance::custom_origins};
+    use frame_support::traits::Contains;
+    use sp_core::{H160, H256, U256};
+
+    fn fast_general_admin() -> RuntimeOrigin {
+        RuntimeOrigin::from(custom_origins::Origin::FastGeneralAdmin)
+    }
+
+    fn is_allowed(call: &RuntimeCall) -> bool {
+        <Runtime as frame_system::Config>::BaseCallFilter::contains(call)
+    }
+
+    fn ethereum_call() -> RuntimeCall {
+        let transaction = pallet_ethereum::Transaction::Legacy(ethereum::LegacyTransaction {
+            nonce: U256::zero(),
+            gas_price: U256::one(),
+            gas_limit: U256::from(21_000),
+            action: ethereum::TransactionAction::Call(H160::repeat_byte(0x42)),
+            value: U256::one(),
+            input: vec![],
+            signature: ethereum::TransactionSignature::new(
+                27,
+                H256::from_low_u64_be(1),
+                H256::from_low_u64_be(1),
+            )
+            .expect("valid signature; qed"),
+        });
+        RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction })
+    }
+
+    fn bridge_call() -> RuntimeCall {
+        RuntimeCall::DataHavenNativeTransfer(
+            pallet_datahaven_native_transfer::Call::transfer_to_ethereum {
+                recipient: H160::repeat_byte(0x42),
+                amount: 1_000,
+                fee: 1,
+            },
+        )
+    }
+
+    #[test]
+    fn fast_general_admin_controls_safe_mode() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(
+                RuntimeCall::SafeMode(pallet_safe_mode::Call::force_enter {})
+                    .dispatch(fast_general_admin())
+            );
+            assert_eq!(
+                EnteredUntil::<Runtime>::get(),
+                Some(System::block_number() + SafeModeDuration::get())
+            );
+
+            assert_ok!(
+                RuntimeCall::SafeMode(pallet_safe_mode::Call::force_extend {})
+                    .dispatch(fast_general_admin())
+            );
+            assert_eq!(
+                EnteredUntil::<Runtime>::get(),
+                Some(System::block_number() + 2 * SafeModeDuration::get())
+            );
+
+            assert_ok!(RuntimeCall::SafeMode(pallet_safe_mode::Call::force_exit {})
+                .dispatch(fast_general_admin()));
+            assert!(EnteredUntil::<Runtime>::get().is_none());
+        });
+    }
+
+    #[test]
+    fn fast_general_admin_controls_tx_pause() {
+        ExtBuilder::default().build().execute_with(|| {
+            let call = bridge_call();
+            let call_name = call_name(&call);
+
+            assert_ok!(RuntimeCall::TxPause(pallet_tx_pause::Call::pause {
+                full_name: call_name.clone(),
+            })
+            .dispatch(fast_general_admin()));
+            assert!(!is_allowed(&call));
+
+            assert_ok!(
+                RuntimeCall::TxPause(pallet_tx_pause::Call::unpause { ident: call_name })
+                    .dispatch(fast_general_admin())
+            );
+            assert!(is_allowed(&call));
+        });
+    }
+
+    #[test]
+    fn safe_mode_blocks_bridge_and_evm_calls() {
+        ExtBuilder::default().build().execute_with(|| {
+            let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+            assert!(is_allowed(&bridge_call()));
+            assert!(is_allowed(&ethereum_call()));
+
+            assert_ok!(
+                RuntimeCall::SafeMode(pallet_safe_mode::Call::force_enter {})
+                    .dispatch(RuntimeOrigin::root())
+            );
+
+            assert!(!is_allowed(&bridge_call()));
+            assert!(!is_allowed(&ethereum_call()));
+            // System calls stay available to keep the chain operable
+            assert!(is_allowed(&remark));
+            assert_ok!(remark.dispatch(RuntimeOrigin::signed(account_id(ALICE))));
+        });
+    }
+}
@@ -18,9 +18,12 @@
 //!
 //! Tests for processing Snowbridge messages through DataHaven
 
+use codec::{Decode, Encode};
 use datahaven_stagenet_runtime::{AccountId, Runtime};
-use dhp_bridge::InboundCommand;
-use dhp_bridge::Message;
+use dhp_bridge::EL_MESSAGE_ID;
+use dhp_bridge::{CommandEnvelope, EigenLayerMessageProcessor, InboundCommand, Message, Payload};
+use pallet_external_validator_slashes::SlashingModeOption;
+use proptest::prelude::*;
 
 use std::fs;
 
@@ -144,18 +147,22 @@ fn test_eigenlayer_message_processor_with_binary_file() {
     }
 
     // Try to decode the payload data directly
-    let decoded_result =
-        dhp_bridge::EigenLayerMessageProcessor::<Runtime>::decode_message(payload_data);
+    let decoded_result = EigenLayerMessageProcessor::<Runtime>::decode_message(payload_data);
 
     match decoded_result {
         Ok(payload) => {
             println!("✅ Successfully decoded payload data");
 
-            match payload.message {
-                Message::V1(InboundCommand::ReceiveValidators {
+            assert!(matches!(payload.message, Message::V1(_)));
+            match payload
+                .message
+                .into_command()
+                .expect("Binary file should hold a known command")
+            {
+                InboundCommand::ReceiveValidators {
                     validators,
                     external_index,
-                }) => {
+                } => {
                     println!(
                         "📊 Decoded {} validators with external_index: {}",
                         validators.len(),
@@ -179,6 +186,9 @@ fn test_eigenlayer_message_processor_with_binary_file() {
                         "✅ Binary file test passed - decoded validators match expected values"
                     );
                 }
+                InboundCommand::CancelSlash { .. } | InboundCommand::SetSlashingMode(_) => {
+                    panic!("Binary file should decode to a ReceiveValidators command");
+                }
             }
         }
         Err(e) => {
@@ -187,3 +197,100 @@ fn test_eigenlayer_message_processor_with_binary_file() {
         }
     }
 }
+
+/// SCALE encoding of an arbitrary `InboundCommand`.
+fn encoded_command() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        (
+            prop::collection::vec(any::<[u8; 20]>(), 0..70),
+            any::<u64>()
+        )
+            .prop_map(|(validators, external_index)| {
+                InboundCommand::<Runtime>::ReceiveValidators {
+                    validators: validators.into_iter().map(AccountId::from).collect(),
+                    external_index,
+                }
+                .encode()
+            }),
+        (any::<u32>(), prop::collection::vec(any::<u32>(), 0..10)).prop_map(|(era, slash_ids)| {
+            InboundCommand::<Runtime>::CancelSlash { era, slash_ids }.encode()
+        }),
+        prop_oneof![
+            Just(SlashingModeOption::Enabled),
+            Just(SlashingModeOption::LogOnly),
+            Just(SlashingModeOption::Disabled),
+        ]
+        .prop_map(|mode| InboundCommand::<Runtime>::SetSlashingMode(mode).encode()),
+    ]
+}
+
+fn command(encoded: &[u8]) -> InboundCommand<Runtime> {
+    InboundCommand::decode(&mut &encoded[..]).expect("encoded by the strategy")
+}
+
+fn encode_payload(message: Message<Runtime>) -> Vec<u8> {
+    Payload::<Runtime> {
+        message_id: EL_MESSAGE_ID,
+        message,
+    }
+    .encode()
+}
+
+/// Command of an EigenLayer payload, re-encoded.
+fn decode_command(payload: &[u8]) -> Result<Vec<u8>, sp_runtime::DispatchError> {
+    EigenLayerMessageProcessor::<Runtime>::decode_message(payload)?
+        .message
+        .into_command()
+        .map(|command| command.encode())
+}
+
+proptest! {
+    #[test]
+    fn v1_and_v2_messages_carry_the_same_command(encoded in encoded_command()) {
+        let v1 = encode_payload(Message::V1(command(&encoded)));
+        let v2 = encode_payload(Message::v2(&command(&encoded)));
+
+        // Version and command discriminant, followed by the compact length of the fields.
+        prop_assert_eq!(v1[4], 0);
+        prop_assert_eq!(v2[4], 1);
+        prop_assert_eq!(v2[5], encoded[0]);
+
+        prop_assert_eq!(decode_command(&v1), Ok(encoded.clone()));
+        prop_assert_eq!(decode_command(&v2), Ok(encoded));
+    }
+
+    #[test]
+    fn v2_ignores_bytes_appended_to_the_command(
+        encoded in encoded_command(),
+        appended in prop::collection::vec(any::<u8>(), 1..64),
+    ) {
+        let mut envelope = CommandEnvelope::new(&command(&encoded));
+        envelope.body.extend(appended);
+
+        let payload = encode_payload(Message::V2(envelope));
+
+        prop_assert_eq!(decode_command(&payload), Ok(encoded));
+    }
+
+    #[test]
+    fn v2_rejects_unknown_commands(
+        command in 3u8..,
+        body in prop::collection::vec(any::<u8>(), 0..64),
+    ) {
+        let payload = encode_payload(Message::V2(CommandEnvelope { command, body }));
+
+        // The envelope decodes, only its command is unknown.
+        prop_assert!(EigenLayerMessageProcessor::<Runtime>::decode_message(&payload).is_ok());
+        prop_assert!(decode_command(&payload).is_err());
+    }
+
+    #[test]
+    fn arbitrary_payloads_do_not_panic(
+        version in 0u8..4,
+        bytes in prop::collection::vec(any::<u8>(), 0..256),
+    ) {
+        let payload = [&EL_MESSAGE_ID[..], &[version], &bytes].concat();
+
+        let _ = decode_command(&payload);
+    }
+}
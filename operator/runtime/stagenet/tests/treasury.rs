@@ -27,7 +27,7 @@ use datahaven_stagenet_runtime::{
     },
     currency::*,
     AccountId, Balances, ExistentialDeposit, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
-    System, Treasury, TreasuryCouncil,
+    SupplyLedger, System, Treasury, TreasuryCouncil,
 };
 use fp_evm::FeeCalculator;
 use frame_support::{
@@ -303,6 +303,7 @@ fn deal_with_fees_handles_tip() {
             // Step 6: Verify that 80% of the fee was burned (removed from the total supply).
             let total_supply_after = Balances::total_issuance();
             assert_eq!(total_supply_before - total_supply_after, burnt_fee_part,);
+            assert_eq!(SupplyLedger::supply_totals().burned, burnt_fee_part);
 
             // Step 7: Validate that the block author (collator) received 100% of the tip.
             let block_author_balance_after = Balances::free_balance(&block_author);
@@ -376,6 +377,102 @@ fn fees_burned_when_block_author_not_set() {
         });
 }
 
+#[test]
+fn burned_fees_keep_the_supply_ledger_balanced() {
+    use datahaven_runtime_common::deal_with_fees::{
+        DealWithEthereumBaseFees, DealWithSubstrateFeesAndTip,
+    };
+    use frame_support::traits::{
+        fungible::Balanced,
+        tokens::{Fortitude, Precision, Preservation},
+        OnUnbalanced,
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(SupplyLedger::check_issuance());
+
+        let withdraw_fee = || {
+            <Balances as Balanced<AccountId>>::withdraw(
+                &account_id(ALICE),
+                1_000,
+                Precision::Exact,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+            .unwrap()
+        };
+        DealWithSubstrateFeesAndTip::<Runtime, FeesTreasuryProportion>::on_unbalanceds(
+            vec![withdraw_fee()].into_iter(),
+        );
+        DealWithEthereumBaseFees::<Runtime, FeesTreasuryProportion>::on_unbalanced(withdraw_fee());
+
+        let burnt_fee_part: Balance = 1_000 - FeesTreasuryProportion::get().mul_floor(1_000);
+        assert_eq!(SupplyLedger::supply_totals().burned, 2 * burnt_fee_part);
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn fee_split_is_reported_by_events() {
+    use datahaven_runtime_common::deal_with_fees::{
+        DealWithEthereumBaseFees, DealWithSubstrateFeesAndTip,
+    };
+    use frame_support::traits::{
+        fungible::Balanced,
+        tokens::{Fortitude, Precision, Preservation},
+        OnUnbalanced,
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let withdraw_fee = || {
+            <Balances as Balanced<AccountId>>::withdraw(
+                &account_id(ALICE),
+                1_000,
+                Precision::Exact,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+            .unwrap()
+        };
+        let treasury_fee_part: Balance = FeesTreasuryProportion::get().mul_floor(1_000);
+        let split_events = [
+            RuntimeEvent::SupplyLedger(pallet_supply_ledger::Event::Burned {
+                amount: 1_000 - treasury_fee_part,
+            }),
+            RuntimeEvent::Balances(pallet_balances::Event::Deposit {
+                who: Treasury::account_id(),
+                amount: treasury_fee_part,
+            }),
+        ];
+
+        // Substrate transaction fees
+        DealWithSubstrateFeesAndTip::<Runtime, FeesTreasuryProportion>::on_unbalanceds(
+            vec![withdraw_fee()].into_iter(),
+        );
+        for event in split_events.clone() {
+            System::assert_has_event(event);
+        }
+
+        // EVM base fees
+        System::reset_events();
+        DealWithEthereumBaseFees::<Runtime, FeesTreasuryProportion>::on_unbalanced(withdraw_fee());
+        for event in split_events {
+            System::assert_has_event(event);
+        }
+    });
+}
+
+#[test]
+fn balances_burn_is_filtered() {
+    use frame_support::traits::Contains;
+
+    let burn = RuntimeCall::Balances(pallet_balances::Call::burn {
+        value: 1,
+        keep_alive: true,
+    });
+    assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&burn));
+}
+
 #[cfg(test)]
 mod treasury_tests {
     use super::*;
@@ -24,7 +24,8 @@ use crate::Runtime;
 
 use crate::configs::storagehub::{ChallengeTicksTolerance, ReplicationTargetType, SpMinDeposit};
 use crate::currency::{GIGAWEI, HAVE, SUPPLY_FACTOR};
-use datahaven_runtime_common::{Balance, BlockNumber};
+use datahaven_runtime_common::{outbound_schema::OutboundSchema, Balance, BlockNumber};
+use pallet_external_validator_slashes::SlashAggregation;
 
 #[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
 pub mod dynamic_params {
@@ -36,9 +37,11 @@ pub mod dynamic_params {
 
         #[codec(index = 0)]
         #[allow(non_upper_case_globals)]
-        /// Set the initial address of the Snowbridge Gateway contract on Ethereum.
+        /// Address of the active Snowbridge Gateway contract on Ethereum.
         /// The fact that this is a parameter means that we can set it initially to the zero address,
         /// and then change it later via governance, to the actual address of the deployed contract.
+        /// Once messages are in flight, replace it through `EthereumGateway::rotate_gateway`, which
+        /// updates this parameter and keeps accepting the previous gateway for a grace period.
         pub static EthereumGatewayAddress: H160 = H160::repeat_byte(0x0);
 
         #[codec(index = 2)]
@@ -358,12 +361,8 @@ pub mod dynamic_params {
         /// Per-era inflation ≈ 3,422 HAVE (testnet)
         pub static InflationAnnualAmount: Balance = 5_000_000 * HAVE * SUPPLY_FACTOR;
 
-        #[codec(index = 38)]
-        #[allow(non_upper_case_globals)]
-        /// Proportion of inflation rewards allocated to the treasury.
-        /// Default: 20% of minted rewards go to treasury, 80% to validator rewards
-        /// The treasury portion is minted separately and sent to the treasury account.
-        pub static InflationTreasuryProportion: Perbill = Perbill::from_percent(20);
+        // Index 38 was `InflationTreasuryProportion`, superseded by
+        // `ExternalValidatorsRewards::RewardsSplit`.
 
         #[codec(index = 39)]
         #[allow(non_upper_case_globals)]
@@ -428,7 +427,84 @@ pub mod dynamic_params {
         /// 5e16 = 5% in WAD format (1e18 = 100%).
         pub static MaxSlashWad: u128 = 50_000_000_000_000_000u128;
 
+        #[codec(index = 48)]
+        #[allow(non_upper_case_globals)]
+        /// EVM contract allowed to cancel deferred slashes and change the slashing mode
+        /// through the slashes precompile. The zero address disables these actions.
+        pub static SlashesPrecompileGovernor: H160 = H160::repeat_byte(0x0);
+
+        #[codec(index = 50)]
+        #[allow(non_upper_case_globals)]
+        /// How the slashes of a validator sent in the same message are merged: by keeping
+        /// the largest one, or by summing them up to `MaxSlashWad`.
+        pub static SlashAggregationMode: SlashAggregation = SlashAggregation::Max;
+
+        #[codec(index = 53)]
+        #[allow(non_upper_case_globals)]
+        /// Share of the reported slash fraction added for each offence the validator committed
+        /// earlier in the bonding period. Zero slashes repeat offenders like first ones.
+        pub static RepeatOffenceSlashIncrease: Perbill = Perbill::zero();
+
+        #[codec(index = 57)]
+        #[allow(non_upper_case_globals)]
+        /// Number of queued slashes sent per block, and so per slashes message. Capped by
+        /// `MaxQueuedSlashes`.
+        pub static QueuedSlashesProcessedPerBlock: u32 = 10;
+
         // ╚══════════════════════ EigenLayer Slashing ═══════════════════════╝
+
+        // ╔══════════════════════ Outbound Messages ═══════════════════════╗
+
+        #[codec(index = 47)]
+        #[allow(non_upper_case_globals)]
+        /// Schema version used to encode rewards and slashes payloads sent to Ethereum.
+        /// Bump only once DataHavenSnowbridgeMessages.sol accepts the new version.
+        pub static OutboundSchemaVersion: OutboundSchema = OutboundSchema::V1;
+
+        #[codec(index = 54)]
+        #[allow(non_upper_case_globals)]
+        /// Local fee, in wei, charged per picosecond of weight spent processing and committing
+        /// an outbound message.
+        pub static OutboundQueueFeePerWeight: Balance = 1;
+
+        #[codec(index = 55)]
+        #[allow(non_upper_case_globals)]
+        /// Gas limit of the rewards submission call on Ethereum.
+        pub static SubmitRewardsGasLimit: u64 =
+            datahaven_runtime_common::rewards_adapter::SUBMIT_REWARDS_GAS_LIMIT;
+
+        #[codec(index = 56)]
+        #[allow(non_upper_case_globals)]
+        /// Gas limit of the slashing call on Ethereum.
+        pub static SlashValidatorsGasLimit: u64 =
+            datahaven_runtime_common::slashes_adapter::SLASH_VALIDATORS_GAS_LIMIT;
+
+        // ╚══════════════════════ Outbound Messages ═══════════════════════╝
+
+        // ╔══════════════════════ HAVE Deposits ═══════════════════════╗
+
+        #[codec(index = 49)]
+        #[allow(non_upper_case_globals)]
+        /// Ethereum contract escrowing the deposits credited as HAVE on DataHaven.
+        /// The zero address disables deposits.
+        pub static HaveDepositContractAddress: H160 = H160::repeat_byte(0x0);
+
+        // ╚══════════════════════ HAVE Deposits ═══════════════════════╝
+
+        // ╔══════════════════════ Native Transfers ═══════════════════════╗
+
+        #[codec(index = 51)]
+        #[allow(non_upper_case_globals)]
+        /// Transfers to Ethereum of at least this amount are delayed by
+        /// `LargeTransferDelay` blocks, during which they can be cancelled. Zero disables it.
+        pub static LargeTransferThreshold: Balance = 1_000_000 * HAVE;
+
+        #[codec(index = 52)]
+        #[allow(non_upper_case_globals)]
+        /// Number of blocks a large transfer to Ethereum waits before it is executed.
+        pub static LargeTransferDelay: BlockNumber = 14_400; // 1 day with a 6 second block time
+
+        // ╚══════════════════════ Native Transfers ═══════════════════════╝
     }
 }
 
@@ -0,0 +1,154 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM configuration for DataHaven Testnet Runtime
+//!
+//! DataHaven is not connected to another consensus system through XCM yet, no router is
+//! configured and programs can only be executed locally, moving the native balance.
+//! Accounts can execute programs on Testnet to exercise the executor before a transport is
+//! configured.
+
+use super::*;
+use crate::{AllPalletsWithSystem, PolkadotXcm, RuntimeOrigin};
+use datahaven_runtime_common::xcm_filter::RejectBurnAsset;
+use frame_support::traits::{Disabled, Everything, Nothing};
+use pallet_xcm::XcmPassthrough;
+use xcm_builder::{
+    AccountKey20Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
+    AllowTopLevelPaidExecutionFrom, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin,
+    FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, HashedDescription, IsConcrete,
+    SignedAccountKey20AsNative, SignedToAccountKey20, SovereignSignedViaLocation, TakeWeightCredit,
+    TrailingSetTopicAsId, UsingComponents,
+};
+use xcm_executor::XcmExecutor;
+
+parameter_types! {
+    /// DataHaven is not part of a relay chain, its accounts are not bound to a network.
+    pub const ThisNetwork: Option<NetworkId> = None;
+    pub HereLocation: Location = Location::here();
+    /// Weight of a single instruction, until the executor is benchmarked.
+    pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    pub const MaxInstructions: u32 = 100;
+    pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+/// Converts a location into the local account it controls.
+pub type LocationToAccountId = (
+    // Accounts of this chain.
+    AccountKey20Aliases<ThisNetwork, AccountId>,
+    // Any other location gets an account derived from its description.
+    HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+);
+
+/// The native balance is the only asset handled by the executor.
+pub type AssetTransactor =
+    FungibleAdapter<Balances, IsConcrete<HereLocation>, LocationToAccountId, AccountId, ()>;
+
+/// Converts the origin of a `Transact` instruction into a dispatch origin.
+pub type XcmOriginToTransactDispatchOrigin = (
+    SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+    SignedAccountKey20AsNative<ThisNetwork, RuntimeOrigin>,
+    XcmPassthrough<RuntimeOrigin>,
+);
+
+pub type Barrier = TrailingSetTopicAsId<(
+    TakeWeightCredit,
+    AllowTopLevelPaidExecutionFrom<Everything>,
+    AllowKnownQueryResponses<PolkadotXcm>,
+    AllowSubscriptionsFrom<Everything>,
+)>;
+
+pub type XcmWeigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+
+/// No transport is configured yet, sending a program fails as unroutable.
+pub type XcmRouter = ();
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = XcmRouter;
+    type AssetTransactor = AssetTransactor;
+    type OriginConverter = XcmOriginToTransactDispatchOrigin;
+    type IsReserve = ();
+    type IsTeleporter = ();
+    type UniversalLocation = UniversalLocation;
+    type Barrier = Barrier;
+    type Weigher = XcmWeigher;
+    // Fees are charged like extrinsic fees and split between the treasury and the supply ledger.
+    type Trader = UsingComponents<
+        WeightToFee,
+        HereLocation,
+        AccountId,
+        Balances,
+        DealWithSubstrateFeesAndTip<
+            Runtime,
+            runtime_params::dynamic_params::runtime_config::FeesTreasuryProportion,
+        >,
+    >;
+    type ResponseHandler = PolkadotXcm;
+    type AssetTrap = PolkadotXcm;
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type AssetClaims = PolkadotXcm;
+    type SubscriptionService = PolkadotXcm;
+    type PalletInstancesInfo = AllPalletsWithSystem;
+    type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+    type TransactionalProcessor = FrameTransactionalProcessor;
+    type HrmpNewChannelOpenRequestHandler = ();
+    type HrmpChannelAcceptedHandler = ();
+    type HrmpChannelClosingHandler = ();
+    type XcmRecorder = PolkadotXcm;
+}
+
+/// Converts a local signed origin into its location.
+pub type LocalOriginToLocation = SignedToAccountKey20<RuntimeOrigin, AccountId, ThisNetwork>;
+
+/// Programs local accounts may execute on Testnet, any that does not burn assets.
+pub type XcmExecuteFilter = RejectBurnAsset;
+
+impl pallet_xcm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmRouter = XcmRouter;
+    type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+    type XcmExecuteFilter = XcmExecuteFilter;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+    type XcmTeleportFilter = Nothing;
+    type XcmReserveTransferFilter = Nothing;
+    type Weigher = XcmWeigher;
+    type UniversalLocation = UniversalLocation;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+    type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+    type Currency = Balances;
+    type CurrencyMatcher = IsConcrete<HereLocation>;
+    type TrustedLockers = ();
+    type SovereignAccountOf = LocationToAccountId;
+    type MaxLockers = ConstU32<8>;
+    // Benchmarked once a transport is configured, every call but `execute` fails until then.
+    type WeightInfo = pallet_xcm::TestWeightInfo;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxRemoteLockConsumers = ConstU32<0>;
+    type RemoteLockConsumerIdentifier = ();
+    type AuthorizedAliasConsideration = Disabled;
+}
@@ -0,0 +1,54 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Genesis config presets exposed through the `GenesisBuilder` runtime API
+
+use datahaven_testnet_runtime::{
+    genesis_config_presets::{get_preset, preset_names, TESTNET_RUNTIME_PRESET},
+    RuntimeGenesisConfig,
+};
+use sp_genesis_builder::{PresetId, DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET};
+use sp_runtime::BuildStorage;
+
+#[test]
+fn named_presets_are_listed() {
+    let names = preset_names();
+    for preset in [
+        DEV_RUNTIME_PRESET,
+        LOCAL_TESTNET_RUNTIME_PRESET,
+        TESTNET_RUNTIME_PRESET,
+    ] {
+        assert!(
+            names.contains(&PresetId::from(preset)),
+            "{preset} is not listed"
+        );
+    }
+}
+
+#[test]
+fn listed_presets_build_the_genesis_state() {
+    for preset in preset_names() {
+        let json = get_preset(&preset).expect("listed presets exist");
+        let config: RuntimeGenesisConfig =
+            serde_json::from_slice(&json).expect("presets are full genesis configs");
+        assert!(config.build_storage().is_ok(), "{preset:?} does not build");
+    }
+}
+
+#[test]
+fn unknown_presets_are_not_found() {
+    assert!(get_preset(&PresetId::from("unknown")).is_none());
+}
@@ -0,0 +1,83 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Validators missing their ImOnline heartbeats lose the liveness weight of their rewards.
+
+mod common;
+use common::*;
+
+use datahaven_testnet_runtime::{AccountId, Authorship, Runtime, Session, System};
+use frame_support::traits::{Contains, OnFinalize, OnInitialize};
+use pallet_external_validator_slashes::{ImOnlineLiveness, UnresponsiveInSession};
+
+/// Authors the next block with the validator at `validator_index`.
+fn author_block(validator_index: u32) {
+    let block_number = System::block_number() + 1;
+    System::set_block_number(block_number);
+    set_block_author_by_index(validator_index);
+    Authorship::on_initialize(block_number);
+    Authorship::on_finalize(block_number);
+}
+
+/// Records a heartbeat of `validator` for the current session.
+fn send_heartbeat(validator: AccountId) {
+    let auth_index = Session::validators()
+        .iter()
+        .position(|v| *v == validator)
+        .expect("validator is in the session") as u32;
+    pallet_im_online::ReceivedHeartbeats::<Runtime>::insert(
+        Session::current_index(),
+        auth_index,
+        true,
+    );
+}
+
+#[test]
+fn validator_missing_heartbeats_is_reported_unresponsive() {
+    ExtBuilder::default().build().execute_with(|| {
+        let session = Session::current_index();
+
+        // Every validator counts as online until ImOnline reports them
+        assert!(ImOnlineLiveness::<Runtime>::contains(&charlie()));
+        assert!(ImOnlineLiveness::<Runtime>::contains(&dave()));
+
+        // Charlie proves liveness by authoring a block, Dave neither authors nor sends heartbeats
+        author_block(0);
+        roll_to_session(session + 1);
+
+        assert_eq!(UnresponsiveInSession::<Runtime>::get(charlie()), None);
+        assert_eq!(UnresponsiveInSession::<Runtime>::get(dave()), Some(session));
+
+        // The unresponsiveness only applies to the session it was reported in
+        assert!(ImOnlineLiveness::<Runtime>::contains(&dave()));
+    });
+}
+
+#[test]
+fn heartbeat_proves_liveness_without_authored_blocks() {
+    ExtBuilder::default().build().execute_with(|| {
+        let session = Session::current_index();
+
+        send_heartbeat(dave());
+        roll_to_session(session + 1);
+
+        assert_eq!(
+            UnresponsiveInSession::<Runtime>::get(charlie()),
+            Some(session)
+        );
+        assert_eq!(UnresponsiveInSession::<Runtime>::get(dave()), None);
+    });
+}
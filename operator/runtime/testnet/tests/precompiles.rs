@@ -0,0 +1,496 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompiles executed end to end, through `pallet_ethereum::transact`.
+//!
+//! The precompile crates are unit-tested against mock runtimes. These tests send Ethereum
+//! transactions from mapped `AccountId20` accounts to the precompile set of the runtime and
+//! check the exit reason, the dispatched calls and the gas charged to the sender.
+
+mod common;
+use common::*;
+
+use codec::{Decode, Encode};
+use datahaven_testnet_runtime::{
+    configs::TransactionPaymentAsGasPrice,
+    currency::HAVE,
+    precompiles::{DataHavenPrecompiles, NativeErc20Metadata},
+    Balances, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys, System, Vesting,
+};
+use fp_evm::{ExitReason, FeeCalculator};
+use frame_support::assert_ok;
+use pallet_evm_precompile_balances_erc20::Erc20BalancesPrecompileCall;
+use pallet_evm_precompile_batch::BatchPrecompileCall;
+use pallet_evm_precompile_payment_streams::{PaymentStream, PaymentStreamsPrecompileCall};
+use pallet_evm_precompile_preimage::PreimagePrecompileCall;
+use pallet_evm_precompile_proxy::ProxyPrecompileCall;
+use pallet_evm_precompile_session_keys::SessionKeysPrecompileCall;
+use pallet_external_validator_slashes::SlashingModeOption;
+use pallet_payment_streams::types::FixedRatePaymentStream;
+use precompile_utils::{
+    prelude::{Address, BoundedBytes},
+    testing::PrecompileTesterExt,
+};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash};
+
+type Erc20PCall = Erc20BalancesPrecompileCall<Runtime, NativeErc20Metadata, ()>;
+type BatchPCall = BatchPrecompileCall<Runtime>;
+type PaymentStreamsPCall = PaymentStreamsPrecompileCall<Runtime>;
+type PreimagePCall = PreimagePrecompileCall<Runtime>;
+type ProxyPCall = ProxyPrecompileCall<Runtime>;
+type SessionKeysPCall = SessionKeysPrecompileCall<Runtime>;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+/// Gas of a plain transfer, the lower bound of any transaction.
+const INTRINSIC_GAS: u64 = 21_000;
+
+/// Outcome of an Ethereum transaction.
+struct Executed {
+    exit_reason: ExitReason,
+    /// Gas charged to the sender, derived from its balance change.
+    gas_used: u64,
+}
+
+impl Executed {
+    fn assert_succeeded(&self) {
+        assert!(
+            matches!(self.exit_reason, ExitReason::Succeed(_)),
+            "unexpected exit reason {:?}",
+            self.exit_reason
+        );
+        // The unused gas is refunded.
+        assert!(self.gas_used > INTRINSIC_GAS && self.gas_used < GAS_LIMIT);
+    }
+
+    fn assert_reverted(&self) {
+        assert!(
+            matches!(self.exit_reason, ExitReason::Revert(_)),
+            "unexpected exit reason {:?}",
+            self.exit_reason
+        );
+        assert!(self.gas_used > INTRINSIC_GAS && self.gas_used < GAS_LIMIT);
+    }
+}
+
+fn precompile(index: u64) -> H160 {
+    H160::from_low_u64_be(index)
+}
+
+/// ABI call data for functions taking static arguments only.
+fn call_data(signature: &str, args: &[H256]) -> Vec<u8> {
+    let mut data = sp_io::hashing::keccak_256(signature.as_bytes())[..4].to_vec();
+    for arg in args {
+        data.extend_from_slice(arg.as_bytes());
+    }
+    data
+}
+
+fn address_arg(address: H160) -> H256 {
+    address.into()
+}
+
+fn uint_arg(value: u64) -> H256 {
+    H256::from_low_u64_be(value)
+}
+
+/// Send a legacy Ethereum transaction from `from` to `to`, paying the minimum gas price.
+///
+/// The transaction is dispatched with the origin set by `pallet_ethereum` for signed
+/// transactions, so its signature is not checked.
+fn ethereum_transact(from: [u8; 20], to: H160, value: U256, input: Vec<u8>) -> Executed {
+    let source = H160::from(from);
+    let (gas_price, _) = TransactionPaymentAsGasPrice::min_gas_price();
+    assert!(!gas_price.is_zero());
+    let (account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&source);
+    let balance_before = Balances::free_balance(account_id(from));
+
+    let transaction = pallet_ethereum::Transaction::Legacy(ethereum::LegacyTransaction {
+        nonce: account.nonce,
+        gas_price,
+        gas_limit: GAS_LIMIT.into(),
+        action: ethereum::TransactionAction::Call(to),
+        value,
+        input,
+        signature: ethereum::TransactionSignature::new(
+            27,
+            H256::from_low_u64_be(1),
+            H256::from_low_u64_be(1),
+        )
+        .expect("valid signature; qed"),
+    });
+    assert_ok!(
+        RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction }).dispatch(
+            RuntimeOrigin::from(pallet_ethereum::RawOrigin::EthereumTransaction(source))
+        )
+    );
+
+    let exit_reason = System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed {
+                from,
+                to: executed_to,
+                exit_reason,
+                ..
+            }) => {
+                assert_eq!((from, executed_to), (source, to));
+                Some(exit_reason)
+            }
+            _ => None,
+        })
+        .expect("the transaction was executed");
+
+    let transferred = match exit_reason {
+        ExitReason::Succeed(_) => value.as_u128(),
+        _ => 0,
+    };
+    let fee = balance_before - Balances::free_balance(account_id(from)) - transferred;
+    assert_eq!(fee % gas_price.as_u128(), 0);
+
+    Executed {
+        exit_reason,
+        gas_used: (fee / gas_price.as_u128()) as u64,
+    }
+}
+
+#[test]
+fn ethereum_precompiles_execute_through_transact() {
+    ExtBuilder::default().build().execute_with(|| {
+        let calls = [
+            // ECRecover, an invalid signature recovers no address
+            (1, vec![0u8; 128]),
+            // Sha256, Ripemd160, Identity
+            (2, b"DataHaven".to_vec()),
+            (3, b"DataHaven".to_vec()),
+            (4, b"DataHaven".to_vec()),
+            // Modexp with empty operands
+            (5, vec![0u8; 96]),
+            // Bn128Add and Bn128Mul of the point at infinity, empty Bn128Pairing
+            (6, vec![0u8; 128]),
+            (7, vec![0u8; 96]),
+            (8, vec![]),
+            // Blake2F with zero rounds
+            (9, vec![0u8; 213]),
+            // Sha3FIPS256
+            (1024, b"DataHaven".to_vec()),
+        ];
+
+        for (index, input) in calls {
+            ethereum_transact(ALICE, precompile(index), U256::zero(), input).assert_succeeded();
+        }
+    });
+}
+
+#[test]
+fn failing_precompile_calls_are_charged() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Removed precompiles and unknown selectors revert, refunding the unused gas.
+        for index in [1025, 1027] {
+            ethereum_transact(ALICE, precompile(index), U256::zero(), vec![]).assert_reverted();
+        }
+        ethereum_transact(
+            ALICE,
+            precompile(1028),
+            U256::zero(),
+            call_data("unknownFunction()", &[]),
+        )
+        .assert_reverted();
+
+        // An error consumes all the gas.
+        let executed = ethereum_transact(ALICE, precompile(1026), U256::zero(), vec![0u8; 128]);
+        assert!(matches!(executed.exit_reason, ExitReason::Error(_)));
+        assert_eq!(executed.gas_used, GAS_LIMIT);
+    });
+}
+
+#[test]
+fn view_precompiles_execute_through_transact() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = address_arg(H160::from(ALICE));
+        let calls = [
+            (2050, call_data("totalSupply()", &[])),
+            (2050, call_data("balanceOf(address)", &[alice])),
+            (2058, call_data("nonces(address)", &[alice])),
+            (
+                2059,
+                call_data(
+                    "isProxy(address,address,uint8,uint32)",
+                    &[
+                        alice,
+                        address_arg(H160::from(BOB)),
+                        uint_arg(0),
+                        uint_arg(0),
+                    ],
+                ),
+            ),
+            (2064, call_data("members()", &[])),
+            (2065, call_data("referendumCount()", &[])),
+            (2066, call_data("classLocksFor(address)", &[alice])),
+            (2068, call_data("isMember(address)", &[alice])),
+            (
+                2069,
+                call_data("isPrecompile(address)", &[address_arg(precompile(2050))]),
+            ),
+            (2072, call_data("registrars()", &[])),
+            (2073, call_data("isPaused()", &[])),
+            (2074, call_data("eraTotalPoints(uint32)", &[uint_arg(0)])),
+            (2074, call_data("rewardAddress(address)", &[alice])),
+            (2075, call_data("activeEra()", &[])),
+            (2076, call_data("slashingMode()", &[])),
+            (2077, call_data("supplyTotals()", &[])),
+            (2078, call_data("vestingSchedules(address)", &[alice])),
+            (2078, call_data("vestedBalance(address)", &[alice])),
+            (2079, call_data("paymentStreamsOf(address)", &[alice])),
+            (2080, call_data("nextKeys(address)", &[alice])),
+            (2081, call_data("activeValidators()", &[])),
+            (2081, call_data("externalIndex()", &[])),
+        ];
+
+        for (index, input) in calls {
+            ethereum_transact(ALICE, precompile(index), U256::zero(), input).assert_succeeded();
+        }
+    });
+}
+
+#[test]
+fn vest_unlocks_the_vested_funds_of_the_sender() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Vesting::vested_transfer(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            account_id(CHARLIE),
+            pallet_vesting::VestingInfo::new(10 * HAVE, HAVE, 1),
+        ));
+
+        System::set_block_number(5);
+        ethereum_transact(
+            CHARLIE,
+            precompile(2078),
+            U256::zero(),
+            call_data("vest()", &[]),
+        )
+        .assert_succeeded();
+
+        assert!(has_event(RuntimeEvent::Vesting(
+            pallet_vesting::Event::VestingUpdated {
+                account: account_id(CHARLIE),
+                unvested: 6 * HAVE,
+            }
+        )));
+    });
+}
+
+#[test]
+fn erc20_transfer_moves_the_native_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob_balance = Balances::free_balance(account_id(BOB));
+
+        ethereum_transact(
+            ALICE,
+            precompile(2050),
+            U256::zero(),
+            Erc20PCall::transfer {
+                to: Address(H160::from(BOB)),
+                value: U256::from(1_000u32),
+            }
+            .into(),
+        )
+        .assert_succeeded();
+
+        assert_eq!(Balances::free_balance(account_id(BOB)), bob_balance + 1_000);
+        assert!(has_event(RuntimeEvent::Balances(
+            pallet_balances::Event::Transfer {
+                from: account_id(ALICE),
+                to: account_id(BOB),
+                amount: 1_000,
+            }
+        )));
+    });
+}
+
+#[test]
+fn batch_all_dispatches_every_subcall() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob_balance = Balances::free_balance(account_id(BOB));
+        let charlie_balance = Balances::free_balance(account_id(CHARLIE));
+
+        ethereum_transact(
+            ALICE,
+            precompile(2056),
+            U256::zero(),
+            BatchPCall::batch_all {
+                to: vec![Address(H160::from(BOB)), Address(H160::from(CHARLIE))].into(),
+                value: vec![U256::from(100u32), U256::from(200u32)].into(),
+                call_data: vec![].into(),
+                gas_limit: vec![].into(),
+            }
+            .into(),
+        )
+        .assert_succeeded();
+
+        assert_eq!(Balances::free_balance(account_id(BOB)), bob_balance + 100);
+        assert_eq!(
+            Balances::free_balance(account_id(CHARLIE)),
+            charlie_balance + 200
+        );
+    });
+}
+
+#[test]
+fn proxy_added_for_the_mapped_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        ethereum_transact(
+            ALICE,
+            precompile(2059),
+            U256::zero(),
+            ProxyPCall::add_proxy {
+                delegate: Address(H160::from(BOB)),
+                proxy_type: 0,
+                delay: 0,
+            }
+            .into(),
+        )
+        .assert_succeeded();
+
+        let (proxies, _) = pallet_proxy::Proxies::<Runtime>::get(account_id(ALICE));
+        assert_eq!(proxies.len(), 1);
+        assert_eq!(proxies[0].delegate, account_id(BOB));
+    });
+}
+
+#[test]
+fn preimage_noted_through_transact() {
+    ExtBuilder::default().build().execute_with(|| {
+        let preimage = b"DataHaven preimage".to_vec();
+
+        ethereum_transact(
+            ALICE,
+            precompile(2067),
+            U256::zero(),
+            PreimagePCall::note_preimage {
+                encoded_proposal: BoundedBytes::from(preimage.clone()),
+            }
+            .into(),
+        )
+        .assert_succeeded();
+
+        assert!(has_event(RuntimeEvent::Preimage(
+            pallet_preimage::Event::Noted {
+                hash: BlakeTwo256::hash(&preimage),
+            }
+        )));
+    });
+}
+
+#[test]
+fn slashes_governance_calls_revert_for_other_callers() {
+    ExtBuilder::default().build().execute_with(|| {
+        ethereum_transact(
+            ALICE,
+            precompile(2076),
+            U256::zero(),
+            call_data("setSlashingMode(uint8)", &[uint_arg(1)]),
+        )
+        .assert_reverted();
+
+        assert_eq!(
+            pallet_external_validator_slashes::SlashingMode::<Runtime>::get(),
+            SlashingModeOption::Enabled
+        );
+    });
+}
+
+#[test]
+fn payment_streams_of_lists_the_streams_of_the_user() {
+    ExtBuilder::default().build().execute_with(|| {
+        let provider = H256::repeat_byte(1);
+        pallet_payment_streams::FixedRatePaymentStreams::<Runtime>::insert(
+            provider,
+            account_id(ALICE),
+            FixedRatePaymentStream {
+                rate: 10 * HAVE,
+                last_charged_tick: 0,
+                user_deposit: 100 * HAVE,
+                out_of_funds_tick: None,
+            },
+        );
+
+        let precompiles = DataHavenPrecompiles::<Runtime>::new();
+        precompiles
+            .prepare_test(
+                ALICE,
+                precompile(2079),
+                PaymentStreamsPCall::payment_streams_of {
+                    user: Address(H160::from(ALICE)),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![PaymentStream {
+                provider,
+                fixed_rate: true,
+                rate: (10 * HAVE).into(),
+                deposit: (100 * HAVE).into(),
+                ..Default::default()
+            }]);
+        precompiles
+            .prepare_test(
+                ALICE,
+                precompile(2079),
+                PaymentStreamsPCall::payment_streams_of {
+                    user: Address(H160::from(BOB)),
+                },
+            )
+            .execute_returns(Vec::<PaymentStream>::new());
+    });
+}
+
+#[test]
+fn set_keys_sets_the_session_keys_of_the_sender() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Babe, grandpa and im-online public keys of 32 bytes, then a beefy key of 33 bytes.
+        let keys = SessionKeys::decode(&mut &[7u8; 129][..]).unwrap();
+
+        ethereum_transact(
+            CHARLIE,
+            precompile(2080),
+            U256::zero(),
+            SessionKeysPCall::set_keys {
+                keys: keys.encode().into(),
+                proof: Vec::<u8>::new().into(),
+            }
+            .into(),
+        )
+        .assert_succeeded();
+        assert_eq!(
+            pallet_session::NextKeys::<Runtime>::get(account_id(CHARLIE)),
+            Some(keys)
+        );
+
+        ethereum_transact(
+            CHARLIE,
+            precompile(2080),
+            U256::zero(),
+            call_data("purgeKeys()", &[]),
+        )
+        .assert_succeeded();
+        assert_eq!(
+            pallet_session::NextKeys::<Runtime>::get(account_id(CHARLIE)),
+            None
+        );
+    });
+}
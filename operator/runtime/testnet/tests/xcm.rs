@@ -0,0 +1,151 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM tests for the DataHaven Testnet runtime
+
+mod common;
+use common::*;
+
+use datahaven_testnet_runtime::{
+    configs::TreasuryAccount, currency::HAVE, Balance, Balances, PolkadotXcm, Runtime, RuntimeCall,
+    RuntimeOrigin, SupplyLedger, WeightToFee,
+};
+use frame_support::{
+    assert_ok,
+    weights::{Weight, WeightToFee as _},
+};
+use sp_core::Get;
+use xcm::prelude::*;
+
+/// Program moving `amount` of the native balance of the origin to `to`.
+fn transfer_to(to: [u8; 20], amount: Balance) -> Xcm<RuntimeCall> {
+    Xcm(vec![
+        WithdrawAsset((Here, amount).into()),
+        DepositAsset {
+            assets: AllCounted(1).into(),
+            beneficiary: AccountKey20 {
+                network: None,
+                key: to,
+            }
+            .into(),
+        },
+    ])
+}
+
+#[test]
+fn executed_program_moves_the_native_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_balance = Balances::free_balance(account_id(ALICE));
+        let bob_balance = Balances::free_balance(account_id(BOB));
+
+        assert_ok!(PolkadotXcm::execute(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedXcm::from(transfer_to(BOB, 100 * HAVE))),
+            Weight::from_parts(4_000_000_000, 128 * 1024),
+        ));
+
+        assert_eq!(
+            Balances::free_balance(account_id(ALICE)),
+            alice_balance - 100 * HAVE
+        );
+        assert_eq!(
+            Balances::free_balance(account_id(BOB)),
+            bob_balance + 100 * HAVE
+        );
+    });
+}
+
+#[test]
+fn execution_fees_go_to_the_fee_handler() {
+    ExtBuilder::default().build().execute_with(|| {
+        let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+        let bob_balance = Balances::free_balance(account_id(BOB));
+        let burned = SupplyLedger::supply_totals().burned;
+
+        let program = Xcm(vec![
+            WithdrawAsset((Here, 100 * HAVE).into()),
+            BuyExecution {
+                fees: (Here, 10 * HAVE).into(),
+                weight_limit: Unlimited,
+            },
+            DepositAsset {
+                assets: AllCounted(1).into(),
+                beneficiary: AccountKey20 {
+                    network: None,
+                    key: BOB,
+                }
+                .into(),
+            },
+        ]);
+        assert_ok!(PolkadotXcm::execute(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedXcm::from(program)),
+            Weight::from_parts(4_000_000_000, 128 * 1024),
+        ));
+
+        // Three instructions, weighed at the unit weight cost each.
+        let fee = WeightToFee::weight_to_fee(&Weight::from_parts(3_000_000_000, 3 * 64 * 1024));
+        assert!(fee > 0);
+        assert_eq!(
+            Balances::free_balance(account_id(BOB)),
+            bob_balance + 100 * HAVE - fee
+        );
+        let to_treasury = Balances::free_balance(TreasuryAccount::get()) - treasury_balance;
+        let to_ledger = SupplyLedger::supply_totals().burned - burned;
+        assert_eq!(to_treasury + to_ledger, fee);
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn programs_burning_assets_are_filtered() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_balance = Balances::free_balance(account_id(ALICE));
+        let program = Xcm(vec![
+            WithdrawAsset((Here, 100 * HAVE).into()),
+            SetAppendix(Xcm(vec![BurnAsset((Here, 100 * HAVE).into())])),
+        ]);
+
+        let result = PolkadotXcm::execute(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedXcm::from(program)),
+            Weight::from_parts(4_000_000_000, 128 * 1024),
+        );
+
+        assert_eq!(
+            result.map_err(|e| e.error),
+            Err(pallet_xcm::Error::<Runtime>::Filtered.into())
+        );
+        assert_eq!(Balances::free_balance(account_id(ALICE)), alice_balance);
+        assert_ok!(SupplyLedger::check_issuance());
+    });
+}
+
+#[test]
+fn sending_fails_without_a_transport() {
+    ExtBuilder::default().build().execute_with(|| {
+        let result = PolkadotXcm::send(
+            RuntimeOrigin::signed(account_id(ALICE)),
+            Box::new(VersionedLocation::from(Location::parent())),
+            Box::new(VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]))),
+        );
+
+        assert_eq!(
+            result,
+            Err(pallet_xcm::Error::<Runtime>::Unreachable.into())
+        );
+    });
+}
@@ -0,0 +1,81 @@
+#!/bin/bash
+# DataHaven try-runtime Script
+# Builds a runtime with the `try-runtime` feature and dry-runs it against the live state of a
+# chain with the standalone try-runtime CLI (https://github.com/paritytech/try-runtime-cli):
+# - `on-runtime-upgrade` runs the migrations of the runtime, with their pre/post upgrade checks,
+#   followed by the `try_state` hooks of all the pallets.
+# - `execute-block` executes the latest block of the chain with the runtime and runs the
+#   `try_state` hooks.
+
+set -e
+
+# Configuration
+RUNTIME=${1:-testnet}
+URI=$2
+COMMAND=${3:-on-runtime-upgrade}
+FEATURES="try-runtime"
+BLOCK_TIME_MS=6000
+
+# Color codes for output
+RED='\033[0;31m'
+GREEN='\033[0;32m'
+YELLOW='\033[1;33m'
+NC='\033[0m' # No Color
+
+# Display usage if help is requested or the URI is missing
+if [[ "$1" == "-h" ]] || [[ "$1" == "--help" ]] || [ -z "$URI" ]; then
+    echo "Usage: $0 <runtime> <uri> [command]"
+    echo ""
+    echo "Arguments:"
+    echo "  runtime  - Runtime to check (testnet, stagenet, mainnet). Default: testnet"
+    echo "  uri      - WebSocket RPC endpoint of a node of the chain, ideally an archive node"
+    echo "  command  - on-runtime-upgrade or execute-block. Default: on-runtime-upgrade"
+    echo ""
+    echo "Examples:"
+    echo "  $0 testnet ws://127.0.0.1:9944                  # Dry-run the testnet migrations"
+    echo "  $0 mainnet wss://<mainnet-rpc> execute-block    # Execute the latest mainnet block"
+    exit 0
+fi
+
+case "$COMMAND" in
+    on-runtime-upgrade)
+        COMMAND_ARGS=(on-runtime-upgrade --checks all --blocktime "$BLOCK_TIME_MS")
+        ;;
+    execute-block)
+        COMMAND_ARGS=(execute-block --try-state all)
+        ;;
+    *)
+        echo -e "${RED}Error: Unknown command $COMMAND${NC}"
+        exit 1
+        ;;
+esac
+
+echo -e "${GREEN}DataHaven try-runtime Script${NC}"
+echo "Runtime: $RUNTIME"
+echo "URI: $URI"
+echo "Command: $COMMAND"
+echo ""
+
+# Ensure try-runtime is installed
+if ! command -v try-runtime &> /dev/null; then
+    echo -e "${YELLOW}Installing try-runtime...${NC}"
+    cargo install --git https://github.com/paritytech/try-runtime-cli --locked
+fi
+
+# Build the runtime WASM
+echo -e "${YELLOW}Building runtime $RUNTIME (production profile) with features: $FEATURES${NC}"
+cargo build --profile production --features "$FEATURES" -p datahaven-$RUNTIME-runtime
+
+WASM_PATH="target/production/wbuild/datahaven-$RUNTIME-runtime/datahaven_${RUNTIME}_runtime.compact.compressed.wasm"
+
+if [ ! -f "$WASM_PATH" ]; then
+    echo -e "${RED}Error: WASM runtime not found at $WASM_PATH${NC}"
+    exit 1
+fi
+
+RUST_LOG=${RUST_LOG:-"runtime=info,try-runtime::cli=info"} try-runtime \
+    --runtime "$WASM_PATH" \
+    "${COMMAND_ARGS[@]}" \
+    live --uri "$URI"
+
+echo -e "${GREEN}try-runtime $COMMAND passed for $RUNTIME${NC}"
@@ -0,0 +1,423 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! End-to-end harness for multi-node DataHaven networks.
+//!
+//! Spawns `datahaven-node` processes on free local ports, connects every node to the first
+//! one and queries them over JSON-RPC. Storage and events are decoded with the types of the
+//! stagenet runtime, so the node must be built from the same tree with the `dev-nobridge`
+//! feature, which also enables the fast runtime (one minute sessions and eras):
+//!
+//! ```sh
+//! cargo build --release --features dev-nobridge
+//! cargo test -p datahaven-e2e -- --ignored --test-threads 1
+//! ```
+//!
+//! The node binary is read from `DATAHAVEN_NODE`, `target/release/datahaven-node` by default.
+//! The logs of the nodes are written to a `datahaven-e2e-<pid>` directory of the system
+//! temporary directory.
+
+use codec::{Decode, Encode};
+use datahaven_stagenet_runtime as runtime;
+use fp_account::EthereumSignature;
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use serde::Deserialize;
+use sp_core::{
+    ecdsa,
+    hashing::{keccak_256, twox_128},
+    Bytes, Pair, H256,
+};
+use sp_runtime::{generic::Era, traits::Header as _, MultiSignature};
+use std::{
+    error::Error,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::{Duration, Instant},
+};
+use tokio::process::{Child, Command};
+
+pub use runtime::{RuntimeCall, RuntimeEvent};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Environment variable holding the path of the node binary.
+pub const NODE_BINARY_VAR: &str = "DATAHAVEN_NODE";
+
+/// Node binary used when `DATAHAVEN_NODE` is not set, relative to this crate.
+const DEFAULT_NODE_BINARY: &str = "../../target/release/datahaven-node";
+
+/// Private key of Alith, the sudo account of the stagenet chain specs.
+const ALITH_KEY: &str = "0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133";
+
+/// Time given to a node to open its RPC server.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval between two polls of a node.
+const POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// Versions of the runtime the node runs.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+    spec_version: u32,
+    transaction_version: u32,
+}
+
+/// Key pair of Alith.
+pub fn alith() -> ecdsa::Pair {
+    ecdsa::Pair::from_string(ALITH_KEY, None).expect("Alith key is valid; qed")
+}
+
+/// A node to spawn.
+pub struct NodeConfig {
+    name: &'static str,
+    args: Vec<String>,
+}
+
+impl NodeConfig {
+    /// Validator authoring with its own dev keys, `alice` runs with `--alice`
+    pub fn validator(name: &'static str) -> Self {
+        Self::validator_with_keys(name, name)
+    }
+
+    /// Validator authoring with the dev keys of `keys`
+    ///
+    /// Two validators using the same keys equivocate, as they author and vote on their own
+    /// forks.
+    pub fn validator_with_keys(name: &'static str, keys: &str) -> Self {
+        Self {
+            name,
+            args: vec![
+                format!("--{keys}"),
+                // Validators need a persistent node key, unless forced to generate one.
+                "--unsafe-force-node-key-generation".into(),
+            ],
+        }
+    }
+
+    /// Full node following the chain
+    pub fn full(name: &'static str) -> Self {
+        Self { name, args: vec![] }
+    }
+}
+
+/// Network of nodes running the same chain.
+pub struct Network {
+    binary: String,
+    chain: String,
+    log_dir: PathBuf,
+    nodes: Vec<Node>,
+}
+
+impl Network {
+    /// Spawn `nodes` running `chain`, the nodes after the first one use it as bootnode
+    pub async fn spawn(chain: &str, nodes: Vec<NodeConfig>) -> Result<Self> {
+        let log_dir = std::env::temp_dir().join(format!("datahaven-e2e-{}", std::process::id()));
+        std::fs::create_dir_all(&log_dir)?;
+
+        let mut network = Self {
+            binary: std::env::var(NODE_BINARY_VAR).unwrap_or_else(|_| DEFAULT_NODE_BINARY.into()),
+            chain: chain.into(),
+            log_dir,
+            nodes: Vec::with_capacity(nodes.len()),
+        };
+        for config in nodes {
+            network.add(config).await?;
+        }
+
+        Ok(network)
+    }
+
+    /// Spawn another node joining the network
+    pub async fn add(&mut self, config: NodeConfig) -> Result<&Node> {
+        let bootnode = match self.nodes.first() {
+            Some(first) => Some(first.multiaddr().await?),
+            None => None,
+        };
+        let node = Node::spawn(&self.binary, &self.chain, config, bootnode, &self.log_dir).await?;
+        self.nodes.push(node);
+        Ok(self.nodes.last().expect("Node was just pushed; qed"))
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The node spawned as `name`
+    pub fn node(&self, name: &str) -> &Node {
+        self.nodes
+            .iter()
+            .find(|node| node.name == name)
+            .unwrap_or_else(|| panic!("No node named {name}"))
+    }
+}
+
+/// A running node, killed when dropped.
+pub struct Node {
+    pub name: &'static str,
+    p2p_port: u16,
+    rpc: HttpClient,
+    _process: Child,
+}
+
+impl Node {
+    async fn spawn(
+        binary: &str,
+        chain: &str,
+        config: NodeConfig,
+        bootnode: Option<String>,
+        log_dir: &Path,
+    ) -> Result<Self> {
+        let p2p_port = free_port()?;
+        let rpc_port = free_port()?;
+        let log: PathBuf = log_dir.join(format!("{}.log", config.name));
+
+        let mut command = Command::new(binary);
+        command
+            .args(["--chain", chain, "--name", config.name, "--tmp"])
+            .args(["--port", &p2p_port.to_string()])
+            .args([
+                "--rpc-port",
+                &rpc_port.to_string(),
+                "--rpc-methods",
+                "unsafe",
+            ])
+            .args(["--no-mdns", "--no-prometheus", "--no-telemetry"])
+            .arg("--no-hardware-benchmarks")
+            .args(&config.args);
+        if let Some(bootnode) = bootnode {
+            command.args(["--bootnodes", &bootnode]);
+        }
+        let process = command
+            .stdout(Stdio::null())
+            .stderr(std::fs::File::create(&log)?)
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("Failed to spawn {binary}: {e}"))?;
+
+        let node = Self {
+            name: config.name,
+            p2p_port,
+            rpc: HttpClientBuilder::default().build(format!("http://127.0.0.1:{rpc_port}"))?,
+            _process: process,
+        };
+        node.wait_ready(&log).await?;
+        Ok(node)
+    }
+
+    async fn wait_ready(&self, log: &Path) -> Result<()> {
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            let health: std::result::Result<serde::de::IgnoredAny, _> =
+                self.rpc.request("system_health", rpc_params![]).await;
+            if health.is_ok() {
+                return Ok(());
+            }
+            if Instant::now() > deadline {
+                return Err(format!("{} did not start, see {}", self.name, log.display()).into());
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Address other nodes use to connect to this node.
+    async fn multiaddr(&self) -> Result<String> {
+        let peer_id: String = self
+            .rpc
+            .request("system_localPeerId", rpc_params![])
+            .await?;
+        Ok(format!(
+            "/ip4/127.0.0.1/tcp/{}/p2p/{peer_id}",
+            self.p2p_port
+        ))
+    }
+
+    /// Number and hash of the latest finalized block
+    pub async fn finalized_block(&self) -> Result<(u32, H256)> {
+        let hash: H256 = self
+            .rpc
+            .request("chain_getFinalizedHead", rpc_params![])
+            .await?;
+        let header: Option<runtime::Header> = self
+            .rpc
+            .request("chain_getHeader", rpc_params![hash])
+            .await?;
+        let header = header.ok_or("Finalized header not found")?;
+        Ok((*header.number(), hash))
+    }
+
+    pub async fn block_hash(&self, number: u32) -> Result<H256> {
+        let hash: Option<H256> = self
+            .rpc
+            .request("chain_getBlockHash", rpc_params![number])
+            .await?;
+        hash.ok_or_else(|| format!("{}: block #{number} not found", self.name).into())
+    }
+
+    async fn storage<T: Decode>(&self, pallet: &str, item: &str, at: H256) -> Result<Option<T>> {
+        let key = [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat();
+        let data: Option<Bytes> = self
+            .rpc
+            .request("state_getStorage", rpc_params![Bytes(key), at])
+            .await?;
+        data.map(|data| T::decode(&mut &data[..]))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Events emitted in the block `at`
+    pub async fn events(&self, at: H256) -> Result<Vec<RuntimeEvent>> {
+        let records: Vec<frame_system::EventRecord<RuntimeEvent, H256>> = self
+            .storage("System", "Events", at)
+            .await?
+            .unwrap_or_default();
+        Ok(records.into_iter().map(|record| record.event).collect())
+    }
+
+    /// Index of the active era at the block `at`
+    pub async fn active_era(&self, at: H256) -> Result<u32> {
+        let active_era: Option<pallet_external_validators::traits::ActiveEraInfo> =
+            self.storage("ExternalValidators", "ActiveEra", at).await?;
+        Ok(active_era.map(|era| era.index).unwrap_or_default())
+    }
+
+    /// Wait until block `number` is finalized
+    pub async fn wait_for_finalized(&self, number: u32, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let (finalized, _) = self.finalized_block().await?;
+            if finalized >= number {
+                return Ok(());
+            }
+            if Instant::now() > deadline {
+                return Err(format!(
+                    "{}: block #{number} not finalized, finalized #{finalized}",
+                    self.name
+                )
+                .into());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Wait for a finalized block, from block `from`, emitting an event matching `predicate`
+    ///
+    /// Returns the number of the block.
+    pub async fn wait_for_event(
+        &self,
+        from: u32,
+        timeout: Duration,
+        predicate: impl Fn(&RuntimeEvent) -> bool,
+    ) -> Result<u32> {
+        let deadline = Instant::now() + timeout;
+        let mut next = from;
+        loop {
+            let (finalized, _) = self.finalized_block().await?;
+            while next <= finalized {
+                let events = self.events(self.block_hash(next).await?).await?;
+                if events.iter().any(&predicate) {
+                    return Ok(next);
+                }
+                next += 1;
+            }
+            if Instant::now() > deadline {
+                return Err(format!(
+                    "{}: no matching event finalized from #{from} to #{finalized}",
+                    self.name
+                )
+                .into());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Dispatch `call` with the root origin, through a sudo transaction of `signer`
+    pub async fn sudo(&self, signer: &ecdsa::Pair, call: RuntimeCall) -> Result<H256> {
+        let call = RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+            call: Box::new(call),
+        });
+        self.submit(signer, call).await
+    }
+
+    /// Sign `call` with `signer` and submit it to the transaction pool
+    ///
+    /// The transaction is immortal and uses the next nonce of `signer` known to the node.
+    pub async fn submit(&self, signer: &ecdsa::Pair, call: RuntimeCall) -> Result<H256> {
+        let account = runtime::AccountId::from(signer.public());
+        let nonce: runtime::Nonce = self
+            .rpc
+            .request("system_accountNextIndex", rpc_params![account])
+            .await?;
+        let genesis_hash = self.block_hash(0).await?;
+        let version: RuntimeVersion = self
+            .rpc
+            .request("state_getRuntimeVersion", rpc_params![])
+            .await?;
+
+        let extra: runtime::SignedExtra = (
+            frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+            frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+            frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+            frame_system::CheckGenesis::<runtime::Runtime>::new(),
+            frame_system::CheckEra::<runtime::Runtime>::from(Era::Immortal),
+            frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+            frame_system::CheckWeight::<runtime::Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+            frame_system::WeightReclaim::<runtime::Runtime>::new(),
+        );
+        let raw_payload = runtime::SignedPayload::from_raw(
+            call.clone(),
+            extra.clone(),
+            (
+                (),
+                version.spec_version,
+                version.transaction_version,
+                genesis_hash,
+                genesis_hash,
+                (),
+                (),
+                (),
+                None,
+                (),
+            ),
+        );
+        // Ethereum signatures are checked against the Keccak-256 hash of the payload.
+        let signature =
+            raw_payload.using_encoded(|payload| signer.sign_prehashed(&keccak_256(payload)));
+        let signature = EthereumSignature::from(MultiSignature::Ecdsa(signature));
+
+        let extrinsic = runtime::UncheckedExtrinsic::new_signed(call, account, signature, extra);
+        let hash = self
+            .rpc
+            .request(
+                "author_submitExtrinsic",
+                rpc_params![Bytes(extrinsic.encode())],
+            )
+            .await?;
+        Ok(hash)
+    }
+}
+
+/// A local port free at the time of the call.
+fn free_port() -> Result<u16> {
+    Ok(TcpListener::bind("127.0.0.1:0")?.local_addr()?.port())
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Multi-node networks running the `local` stagenet chain, see the crate docs to run them.
+
+use datahaven_e2e::{alith, Network, NodeConfig, RuntimeCall, RuntimeEvent};
+use pallet_external_validator_slashes::SlashingModeOption;
+use std::time::Duration;
+
+/// Eras last one minute with the fast runtime, give each awaited event a few of them.
+const TIMEOUT: Duration = Duration::from_secs(6 * 60);
+
+/// Kind of the offences reported for GRANDPA equivocations.
+const GRANDPA_EQUIVOCATION: [u8; 16] = *b"grandpa:equivoca";
+
+#[tokio::test]
+#[ignore = "spawns datahaven-node processes"]
+async fn nodes_finalize_blocks_across_era_rotations() {
+    let network = Network::spawn(
+        "local",
+        vec![
+            NodeConfig::validator("alice"),
+            NodeConfig::validator("bob"),
+            NodeConfig::full("charlie"),
+            NodeConfig::full("dave"),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let alice = network.node("alice");
+    let new_era = alice
+        .wait_for_event(1, TIMEOUT, |event| {
+            matches!(
+                event,
+                RuntimeEvent::ExternalValidators(pallet_external_validators::Event::NewEra {
+                    era: 2
+                })
+            )
+        })
+        .await
+        .unwrap();
+    let hash = alice.block_hash(new_era).await.unwrap();
+    assert_eq!(alice.active_era(hash).await.unwrap(), 2);
+
+    for node in network.nodes() {
+        node.wait_for_finalized(new_era, TIMEOUT).await.unwrap();
+        assert_eq!(
+            node.block_hash(new_era).await.unwrap(),
+            hash,
+            "{} finalized another chain",
+            node.name
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore = "spawns datahaven-node processes"]
+async fn grandpa_equivocation_slash_is_committed() {
+    let mut network = Network::spawn(
+        "local",
+        vec![
+            NodeConfig::validator("alice"),
+            NodeConfig::validator("bob"),
+            NodeConfig::full("charlie"),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // The local chain only logs slashes.
+    let charlie = network.node("charlie");
+    charlie
+        .sudo(
+            &alith(),
+            RuntimeCall::ExternalValidatorsSlashes(
+                pallet_external_validator_slashes::Call::set_slashing_mode {
+                    mode: SlashingModeOption::Enabled,
+                },
+            ),
+        )
+        .await
+        .unwrap();
+    let enabled = charlie
+        .wait_for_event(1, TIMEOUT, |event| {
+            matches!(
+                event,
+                RuntimeEvent::Sudo(pallet_sudo::Event::Sudid {
+                    sudo_result: Ok(())
+                })
+            )
+        })
+        .await
+        .unwrap();
+
+    // A second node voting with the keys of Alice makes her equivocate in GRANDPA.
+    network
+        .add(NodeConfig::validator_with_keys("alice-twin", "alice"))
+        .await
+        .unwrap();
+
+    let charlie = network.node("charlie");
+    let reported = charlie
+        .wait_for_event(enabled, TIMEOUT, |event| {
+            matches!(
+                event,
+                RuntimeEvent::Offences(pallet_offences::Event::Offence { kind, .. })
+                    if *kind == GRANDPA_EQUIVOCATION
+            )
+        })
+        .await
+        .unwrap();
+    let sent = charlie
+        .wait_for_event(reported, TIMEOUT, |event| {
+            matches!(
+                event,
+                RuntimeEvent::ExternalValidatorsSlashes(
+                    pallet_external_validator_slashes::Event::SlashesMessageSent { .. }
+                )
+            )
+        })
+        .await
+        .unwrap();
+    charlie
+        .wait_for_event(sent, TIMEOUT, |event| {
+            matches!(
+                event,
+                RuntimeEvent::EthereumOutboundQueueV2(
+                    snowbridge_pallet_outbound_queue_v2::Event::MessagesCommitted { .. }
+                )
+            )
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! JSON-RPC client of the DataHaven node.
+
+use crate::Result;
+use codec::{Decode, Encode};
+use datahaven_stagenet_runtime as runtime;
+use fp_account::EthereumSignature;
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use serde::Deserialize;
+use snowbridge_outbound_queue_primitives::v2::OutboundMessage;
+use sp_core::{
+    ecdsa,
+    hashing::{keccak_256, twox_128},
+    Bytes, Pair, H256,
+};
+use sp_runtime::{generic::Era, traits::Header as _, MultiSignature};
+
+/// Name of the outbound queue pallet in the DataHaven runtimes.
+const OUTBOUND_QUEUE_PALLET: &[u8] = b"EthereumOutboundQueueV2";
+
+/// Versions of the runtime the node runs, which may be newer than the one of this crate.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+    spec_version: u32,
+    transaction_version: u32,
+}
+
+pub(crate) struct DataHavenClient {
+    rpc: HttpClient,
+}
+
+impl DataHavenClient {
+    pub(crate) fn new(url: &str) -> Result<Self> {
+        Ok(Self {
+            rpc: HttpClientBuilder::default().build(url)?,
+        })
+    }
+
+    /// Number and hash of the latest finalized block.
+    pub(crate) async fn finalized_block(&self) -> Result<(u32, H256)> {
+        let hash: H256 = self
+            .rpc
+            .request("chain_getFinalizedHead", rpc_params![])
+            .await?;
+        let header: Option<runtime::Header> = self
+            .rpc
+            .request("chain_getHeader", rpc_params![hash])
+            .await?;
+        let header = header.ok_or("Finalized header not found")?;
+        Ok((*header.number(), hash))
+    }
+
+    pub(crate) async fn block_hash(&self, number: u32) -> Result<H256> {
+        let hash: Option<H256> = self
+            .rpc
+            .request("chain_getBlockHash", rpc_params![number])
+            .await?;
+        hash.ok_or_else(|| format!("Block #{number} not found").into())
+    }
+
+    async fn storage<T: Decode>(&self, key: Vec<u8>, at: H256) -> Result<Option<T>> {
+        let data: Option<Bytes> = self
+            .rpc
+            .request("state_getStorage", rpc_params![Bytes(key), at])
+            .await?;
+        data.map(|data| T::decode(&mut &data[..]))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Messages committed by the outbound queue in the block `at`.
+    pub(crate) async fn outbound_messages(&self, at: H256) -> Result<Vec<OutboundMessage>> {
+        let key = [twox_128(OUTBOUND_QUEUE_PALLET), twox_128(b"Messages")].concat();
+        Ok(self.storage(key, at).await?.unwrap_or_default())
+    }
+
+    /// Sign `call` with `signer` and submit it to the transaction pool.
+    ///
+    /// The transaction is immortal and uses the next nonce of `signer` known to the node.
+    pub(crate) async fn submit(
+        &self,
+        signer: &ecdsa::Pair,
+        call: runtime::RuntimeCall,
+    ) -> Result<H256> {
+        let account = runtime::AccountId::from(signer.public());
+        let nonce: runtime::Nonce = self
+            .rpc
+            .request("system_accountNextIndex", rpc_params![account])
+            .await?;
+        let genesis_hash = self.block_hash(0).await?;
+        let version: RuntimeVersion = self
+            .rpc
+            .request("state_getRuntimeVersion", rpc_params![])
+            .await?;
+
+        let extra: runtime::SignedExtra = (
+            frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+            frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+            frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+            frame_system::CheckGenesis::<runtime::Runtime>::new(),
+            frame_system::CheckEra::<runtime::Runtime>::from(Era::Immortal),
+            frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+            frame_system::CheckWeight::<runtime::Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+            frame_system::WeightReclaim::<runtime::Runtime>::new(),
+        );
+        let raw_payload = runtime::SignedPayload::from_raw(
+            call.clone(),
+            extra.clone(),
+            (
+                (),
+                version.spec_version,
+                version.transaction_version,
+                genesis_hash,
+                genesis_hash,
+                (),
+                (),
+                (),
+                None,
+                (),
+            ),
+        );
+        // Ethereum signatures are checked against the Keccak-256 hash of the payload.
+        let signature =
+            raw_payload.using_encoded(|payload| signer.sign_prehashed(&keccak_256(payload)));
+        let signature = EthereumSignature::from(MultiSignature::Ecdsa(signature));
+
+        let extrinsic = runtime::UncheckedExtrinsic::new_signed(call, account, signature, extra);
+        let hash = self
+            .rpc
+            .request(
+                "author_submitExtrinsic",
+                rpc_params![Bytes(extrinsic.encode())],
+            )
+            .await?;
+        Ok(hash)
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! JSON-RPC client of the Ethereum dev chain.
+
+use crate::Result;
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use snowbridge_inbound_queue_primitives::Log;
+use sp_core::{Bytes, H160, H256, U256};
+use std::time::Duration;
+
+/// Time between two polls of a transaction receipt.
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Number of receipt polls before a transaction is considered lost.
+const RECEIPT_POLLS: u32 = 60;
+
+#[derive(Deserialize)]
+struct RpcLog {
+    address: H160,
+    topics: Vec<H256>,
+    data: Bytes,
+}
+
+#[derive(Deserialize)]
+struct Receipt {
+    status: U256,
+}
+
+pub(crate) struct EthereumClient {
+    rpc: HttpClient,
+}
+
+impl EthereumClient {
+    pub(crate) fn new(url: &str) -> Result<Self> {
+        Ok(Self {
+            rpc: HttpClientBuilder::default().build(url)?,
+        })
+    }
+
+    pub(crate) async fn block_number(&self) -> Result<u64> {
+        let number: U256 = self.rpc.request("eth_blockNumber", rpc_params![]).await?;
+        Ok(number.low_u64())
+    }
+
+    /// Logs of `address` with the first topic `topic`, in the blocks `from..=to`.
+    pub(crate) async fn logs(
+        &self,
+        address: H160,
+        topic: H256,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<Log>> {
+        let filter = json!({
+            "address": address,
+            "topics": [topic],
+            "fromBlock": format!("{from:#x}"),
+            "toBlock": format!("{to:#x}"),
+        });
+        let logs: Vec<RpcLog> = self.rpc.request("eth_getLogs", rpc_params![filter]).await?;
+        Ok(logs
+            .into_iter()
+            .map(|log| Log {
+                address: log.address,
+                topics: log.topics,
+                data: log.data.0,
+            })
+            .collect())
+    }
+
+    /// Result of calling `to` with `data` on the latest block.
+    pub(crate) async fn call(&self, to: H160, data: Vec<u8>) -> Result<Vec<u8>> {
+        let call = json!({ "to": to, "data": Bytes(data) });
+        let result: Bytes = self
+            .rpc
+            .request("eth_call", rpc_params![call, "latest"])
+            .await?;
+        Ok(result.0)
+    }
+
+    /// Send a transaction from `from`, which must be unlocked or impersonated, and wait for
+    /// its successful execution.
+    pub(crate) async fn transact(
+        &self,
+        from: H160,
+        to: H160,
+        data: Vec<u8>,
+        value: U256,
+    ) -> Result<H256> {
+        let transaction = json!({ "from": from, "to": to, "data": Bytes(data), "value": value });
+        let hash: H256 = self
+            .rpc
+            .request("eth_sendTransaction", rpc_params![transaction])
+            .await?;
+
+        for _ in 0..RECEIPT_POLLS {
+            let receipt: Option<Receipt> = self
+                .rpc
+                .request("eth_getTransactionReceipt", rpc_params![hash])
+                .await?;
+            match receipt {
+                Some(receipt) if receipt.status.is_zero() => {
+                    return Err(format!("Transaction {hash:?} reverted").into())
+                }
+                Some(_) => return Ok(hash),
+                None => tokio::time::sleep(RECEIPT_POLL_INTERVAL).await,
+            }
+        }
+        Err(format!("Transaction {hash:?} not included").into())
+    }
+
+    /// Let the transactions from `address` be sent without its key, funding it for their fees.
+    ///
+    /// Only supported by dev chains such as anvil.
+    pub(crate) async fn impersonate(&self, address: H160) -> Result<()> {
+        let balance: U256 = self
+            .rpc
+            .request("eth_getBalance", rpc_params![address, "latest"])
+            .await?;
+        let fees = U256::exp10(18);
+        let _: Value = self
+            .rpc
+            .request(
+                "anvil_setBalance",
+                rpc_params![address, balance.saturating_add(fees)],
+            )
+            .await?;
+        let _: Value = self
+            .rpc
+            .request("anvil_impersonateAccount", rpc_params![address])
+            .await?;
+        Ok(())
+    }
+}
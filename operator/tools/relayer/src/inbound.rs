@@ -0,0 +1,200 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Messages from Ethereum to DataHaven.
+//!
+//! The `OutboundMessageAccepted` logs of the Gateway are submitted as they are to the inbound
+//! queue, with an empty proof that only the `dev-nobridge` runtime accepts.
+
+use crate::{datahaven::DataHavenClient, ethereum::EthereumClient, Result, LOG_TARGET};
+use alloy_core::{
+    primitives::{Address, Bytes},
+    sol_types::SolEvent,
+};
+use codec::Encode;
+use datahaven_stagenet_runtime::{AccountId, Runtime, RuntimeCall};
+use dhp_bridge::{EigenLayerMessageProcessor, InboundCommand, Message, Payload, EL_MESSAGE_ID};
+use snowbridge_beacon_primitives::{
+    types::deneb, BeaconHeader, ExecutionProof, VersionedExecutionPayloadHeader,
+};
+use snowbridge_inbound_queue_primitives::{
+    v2::{IGatewayV2, Message as GatewayMessage, Payload as GatewayPayload},
+    EventProof, Log, Proof,
+};
+use sp_core::{ecdsa, H160, H256};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Number of Ethereum blocks per `eth_getLogs` request.
+const LOGS_RANGE: u64 = 1_000;
+
+/// Submit the messages of `gateway` from the Ethereum block `from_block` on.
+pub(crate) async fn run(
+    datahaven: &DataHavenClient,
+    signer: &ecdsa::Pair,
+    ethereum: &EthereumClient,
+    gateway: H160,
+    from_block: Option<u64>,
+    poll_interval: Duration,
+) -> Result<()> {
+    let mut next = match from_block {
+        Some(block) => block,
+        None => ethereum.block_number().await?,
+    };
+    info!(target: LOG_TARGET, "Relaying the Gateway messages from Ethereum block #{next}");
+
+    loop {
+        if let Err(err) = relay_new_logs(datahaven, signer, ethereum, gateway, &mut next).await {
+            warn!(target: LOG_TARGET, "Failed to relay the messages from #{next}: {err}");
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+async fn relay_new_logs(
+    datahaven: &DataHavenClient,
+    signer: &ecdsa::Pair,
+    ethereum: &EthereumClient,
+    gateway: H160,
+    next: &mut u64,
+) -> Result<()> {
+    let topic = H256(IGatewayV2::OutboundMessageAccepted::SIGNATURE_HASH.0);
+    let head = ethereum.block_number().await?;
+
+    while *next <= head {
+        let to = head.min(*next + LOGS_RANGE - 1);
+        for log in ethereum.logs(gateway, topic, *next, to).await? {
+            let description = describe(&log);
+            // A message already submitted is rejected by the nonce check of the queue.
+            match submit(datahaven, signer, log).await {
+                Ok(hash) => info!(target: LOG_TARGET, "Submitted {description} in {hash:?}"),
+                Err(err) => warn!(target: LOG_TARGET, "Failed to submit {description}: {err}"),
+            }
+        }
+        *next = to + 1;
+    }
+    Ok(())
+}
+
+/// Submit a Gateway log to the inbound queue.
+pub(crate) async fn submit(
+    datahaven: &DataHavenClient,
+    signer: &ecdsa::Pair,
+    log: Log,
+) -> Result<H256> {
+    let event = EventProof {
+        event_log: log,
+        proof: Proof {
+            receipt_proof: (Vec::new(), Vec::new()),
+            execution_proof: ExecutionProof {
+                header: BeaconHeader::default(),
+                ancestry_proof: None,
+                execution_header: VersionedExecutionPayloadHeader::Deneb(
+                    deneb::ExecutionPayloadHeader::default(),
+                ),
+                execution_branch: Vec::new(),
+            },
+        },
+    };
+    let call =
+        RuntimeCall::EthereumInboundQueueV2(snowbridge_pallet_inbound_queue_v2::Call::submit {
+            event: Box::new(event),
+        });
+    datahaven.submit(signer, call).await
+}
+
+/// Gateway log of a validator set message, as sent by the service manager `origin`.
+pub(crate) fn validators_log(
+    gateway: H160,
+    origin: H160,
+    nonce: u64,
+    validators: Vec<H160>,
+    external_index: u64,
+) -> Log {
+    let payload = Payload::<Runtime> {
+        message_id: EL_MESSAGE_ID,
+        message: Message::V1(InboundCommand::ReceiveValidators {
+            validators: validators.into_iter().map(AccountId::from).collect(),
+            external_index,
+        }),
+    };
+    let event = IGatewayV2::OutboundMessageAccepted {
+        nonce,
+        payload: IGatewayV2::Payload {
+            origin: Address::from(origin.0),
+            assets: Vec::new(),
+            xcm: IGatewayV2::Xcm {
+                kind: 0,
+                data: payload.encode().into(),
+            },
+            claimer: Bytes::new(),
+            value: 0,
+            executionFee: 0,
+            relayerFee: 0,
+        },
+    };
+
+    let log_data = event.encode_log_data();
+    Log {
+        address: gateway,
+        topics: log_data
+            .topics()
+            .iter()
+            .map(|topic| H256(topic.0))
+            .collect(),
+        data: log_data.data.to_vec(),
+    }
+}
+
+/// Short description of the message of a Gateway log, for the logs of the relayer.
+fn describe(log: &Log) -> String {
+    let Ok(message) = GatewayMessage::try_from(log) else {
+        return "undecodable message".into();
+    };
+
+    let command = match &message.xcm {
+        GatewayPayload::Raw(payload) => {
+            EigenLayerMessageProcessor::<Runtime>::decode_message(payload)
+                .ok()
+                .filter(|payload| payload.message_id == EL_MESSAGE_ID)
+                .and_then(|payload| payload.message.into_command().ok())
+        }
+        GatewayPayload::CreateAsset { .. } => None,
+    };
+    let nonce = message.nonce;
+    match command {
+        Some(InboundCommand::ReceiveValidators {
+            validators,
+            external_index,
+        }) => format!(
+            "message {nonce}: set of {} validators with index {external_index}",
+            validators.len()
+        ),
+        Some(InboundCommand::CancelSlash { era, slash_ids }) => {
+            format!("message {nonce}: cancellation of the slashes {slash_ids:?} of era {era}")
+        }
+        Some(InboundCommand::SetSlashingMode(mode)) => {
+            format!("message {nonce}: slashing mode {mode:?}")
+        }
+        None if !message.assets.is_empty() => {
+            format!(
+                "message {nonce}: transfer of {} assets",
+                message.assets.len()
+            )
+        }
+        None => format!("message {nonce}"),
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Reference relayer for the DataHaven dev and stage environments.
+//!
+//! Relays the bridge messages between a DataHaven node built with the `dev-nobridge` feature
+//! and an Ethereum dev chain (anvil), without the Snowbridge relayers, beacon client or BEEFY
+//! light client:
+//! - `inbound`: submits the `OutboundMessageAccepted` logs of the Gateway, such as the
+//!   validator sets sent by the service manager, to the inbound queue. The `dev-nobridge`
+//!   runtime accepts them without a proof.
+//! - `outbound`: executes the commands committed by the outbound queue of DataHaven (slashes,
+//!   rewards and native token transfers) on the Ethereum dev chain, impersonating the Gateway
+//!   agent or the Gateway itself as the Gateway would dispatch them. No delivery receipt is
+//!   sent back.
+//! - `send-validators`: submits a validator set message built from the command line, for
+//!   environments without an Ethereum chain.
+//!
+//! The messages are encoded and decoded with the codecs of the runtime and `dhp-bridge`.
+//!
+//! Run with `cargo run -p datahaven-relayer -- <command> --help`.
+
+mod datahaven;
+mod ethereum;
+mod inbound;
+mod outbound;
+
+use clap::{Args, Parser, Subcommand};
+use sp_core::{ecdsa, Pair, H160};
+use std::{error::Error, time::Duration};
+
+pub(crate) type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub(crate) const LOG_TARGET: &str = "relayer";
+
+/// Private key of Alith, the development account funded in the dev chain specs.
+const DEV_RELAYER_KEY: &str = "0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133";
+
+#[derive(Debug, Parser)]
+#[command(about = "Relay bridge messages between DataHaven and Ethereum dev chains")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Submit the messages sent through the Gateway to the DataHaven inbound queue.
+    Inbound(InboundCmd),
+    /// Execute the messages committed by the DataHaven outbound queue on Ethereum.
+    Outbound(OutboundCmd),
+    /// Submit a validator set message to the DataHaven inbound queue.
+    SendValidators(SendValidatorsCmd),
+}
+
+#[derive(Debug, Args)]
+struct DataHavenArgs {
+    /// HTTP RPC endpoint of the DataHaven node.
+    #[arg(long, default_value = "http://127.0.0.1:9944")]
+    datahaven_rpc: String,
+
+    /// Secret URI or hex private key of the account submitting the inbound messages.
+    #[arg(long, env = "RELAYER_KEY", default_value = DEV_RELAYER_KEY)]
+    relayer_key: String,
+}
+
+#[derive(Debug, Args)]
+struct InboundCmd {
+    #[command(flatten)]
+    datahaven: DataHavenArgs,
+
+    /// HTTP RPC endpoint of the Ethereum chain.
+    #[arg(long, default_value = "http://127.0.0.1:8545")]
+    ethereum_rpc: String,
+
+    /// Address of the Gateway proxy on Ethereum.
+    #[arg(long)]
+    gateway: H160,
+
+    /// Ethereum block to start from, the latest one if not set.
+    #[arg(long)]
+    from_block: Option<u64>,
+
+    /// Seconds between two polls of the chains.
+    #[arg(long, default_value_t = 6)]
+    poll_interval: u64,
+}
+
+#[derive(Debug, Args)]
+struct OutboundCmd {
+    /// HTTP RPC endpoint of the DataHaven node.
+    #[arg(long, default_value = "http://127.0.0.1:9944")]
+    datahaven_rpc: String,
+
+    /// HTTP RPC endpoint of the Ethereum dev chain, which must allow impersonating accounts.
+    #[arg(long, default_value = "http://127.0.0.1:8545")]
+    ethereum_rpc: String,
+
+    /// Address of the Gateway proxy on Ethereum.
+    #[arg(long)]
+    gateway: H160,
+
+    /// DataHaven block to start from, the latest finalized one if not set.
+    #[arg(long)]
+    from_block: Option<u32>,
+
+    /// Log the messages without executing them on Ethereum.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Seconds between two polls of the chains.
+    #[arg(long, default_value_t = 6)]
+    poll_interval: u64,
+}
+
+#[derive(Debug, Args)]
+struct SendValidatorsCmd {
+    #[command(flatten)]
+    datahaven: DataHavenArgs,
+
+    /// Gateway address the message is attributed to, the one set in the runtime.
+    #[arg(long)]
+    gateway: H160,
+
+    /// Sender of the message on Ethereum, the service manager address set in the runtime.
+    #[arg(long)]
+    origin: H160,
+
+    /// Gateway nonce of the message, not used by a previous message.
+    #[arg(long)]
+    nonce: u64,
+
+    /// Index of the validator set on Ethereum.
+    #[arg(long)]
+    external_index: u64,
+
+    /// Validator addresses, in order.
+    #[arg(long, value_delimiter = ',', required = true)]
+    validators: Vec<H160>,
+}
+
+fn relayer_key(key: &str) -> Result<ecdsa::Pair> {
+    ecdsa::Pair::from_string(key, None)
+        .map_err(|err| format!("Invalid relayer key: {err:?}").into())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| format!("{LOG_TARGET}=info").into()),
+        )
+        .init();
+
+    match Cli::parse().command {
+        Command::Inbound(cmd) => {
+            let datahaven = datahaven::DataHavenClient::new(&cmd.datahaven.datahaven_rpc)?;
+            let signer = relayer_key(&cmd.datahaven.relayer_key)?;
+            let ethereum = ethereum::EthereumClient::new(&cmd.ethereum_rpc)?;
+            inbound::run(
+                &datahaven,
+                &signer,
+                &ethereum,
+                cmd.gateway,
+                cmd.from_block,
+                Duration::from_secs(cmd.poll_interval),
+            )
+            .await
+        }
+        Command::Outbound(cmd) => {
+            let datahaven = datahaven::DataHavenClient::new(&cmd.datahaven_rpc)?;
+            let ethereum = ethereum::EthereumClient::new(&cmd.ethereum_rpc)?;
+            outbound::run(
+                &datahaven,
+                &ethereum,
+                cmd.gateway,
+                cmd.from_block,
+                cmd.dry_run,
+                Duration::from_secs(cmd.poll_interval),
+            )
+            .await
+        }
+        Command::SendValidators(cmd) => {
+            let datahaven = datahaven::DataHavenClient::new(&cmd.datahaven.datahaven_rpc)?;
+            let signer = relayer_key(&cmd.datahaven.relayer_key)?;
+            let log = inbound::validators_log(
+                cmd.gateway,
+                cmd.origin,
+                cmd.nonce,
+                cmd.validators,
+                cmd.external_index,
+            );
+            let hash = inbound::submit(&datahaven, &signer, log).await?;
+            println!("{hash:?}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Messages from DataHaven to Ethereum.
+//!
+//! The Gateway only executes a message with a BEEFY proof of its commitment. On a dev chain,
+//! the relayer executes the commands of the committed messages itself, from the accounts the
+//! Gateway would use: the agent of the message origin for contract calls and the Gateway for
+//! token mints.
+
+use crate::{datahaven::DataHavenClient, ethereum::EthereumClient, Result, LOG_TARGET};
+use alloy_core::{
+    primitives::{Address, B256, U256 as AbiU256},
+    sol,
+    sol_types::{SolCall, SolValue},
+};
+use datahaven_runtime_common::{
+    rewards_adapter::submitRewardsCall,
+    slashes_adapter::{slashValidatorsOperatorCall, slashValidatorsOperatorWithEvidenceCall},
+};
+use snowbridge_outbound_queue_primitives::v2::{
+    abi::{CallContractParams, MintForeignTokenParams},
+    OutboundCommandWrapper, OutboundMessage,
+};
+use sp_core::{H160, H256, U256};
+use std::time::Duration;
+use tracing::{info, warn};
+
+sol! {
+    interface IGateway {
+        function agentOf(bytes32 agentID) external view returns (address);
+        function tokenAddressOf(bytes32 tokenID) external view returns (address);
+    }
+
+    interface IToken {
+        function mint(address account, uint256 amount) external;
+    }
+}
+
+/// Kind of the `MintForeignToken` command, see `Command::index`.
+const MINT_FOREIGN_TOKEN: u8 = 4;
+
+/// Kind of the `CallContract` command, see `Command::index`.
+const CALL_CONTRACT: u8 = 5;
+
+/// Command of an outbound message, as dispatched by the Gateway.
+enum Dispatch {
+    /// Call of `target` from the agent of the message origin.
+    CallContract {
+        target: H160,
+        data: Vec<u8>,
+        value: U256,
+    },
+    /// Mint of a token registered by DataHaven, the native token, from the Gateway.
+    MintForeignToken {
+        token_id: H256,
+        recipient: H160,
+        amount: u128,
+    },
+}
+
+impl Dispatch {
+    fn decode(command: &OutboundCommandWrapper) -> Result<Self> {
+        match command.kind {
+            CALL_CONTRACT => {
+                let params = <CallContractParams as SolValue>::abi_decode(&command.payload, true)?;
+                Ok(Dispatch::CallContract {
+                    target: H160::from_slice(params.target.as_slice()),
+                    data: params.data.to_vec(),
+                    value: U256::from_big_endian(&params.value.to_be_bytes::<32>()),
+                })
+            }
+            MINT_FOREIGN_TOKEN => {
+                let params =
+                    <MintForeignTokenParams as SolValue>::abi_decode(&command.payload, true)?;
+                Ok(Dispatch::MintForeignToken {
+                    token_id: H256(params.foreignTokenID.0),
+                    recipient: H160::from_slice(params.recipient.as_slice()),
+                    amount: params.amount,
+                })
+            }
+            kind => Err(format!("Unsupported command kind {kind}").into()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Dispatch::CallContract { target, data, .. } => {
+                let selector = data.get(..4).unwrap_or_default();
+                let call = if selector == slashValidatorsOperatorCall::SELECTOR {
+                    slashValidatorsOperatorCall::abi_decode(data, true)
+                        .map(|call| format!("slashes of {} operators", call.slashings.len()))
+                        .ok()
+                } else if selector == slashValidatorsOperatorWithEvidenceCall::SELECTOR {
+                    slashValidatorsOperatorWithEvidenceCall::abi_decode(data, true)
+                        .map(|call| format!("slashes of {} operators", call.slashings.len()))
+                        .ok()
+                } else if selector == submitRewardsCall::SELECTOR {
+                    submitRewardsCall::abi_decode(data, true)
+                        .map(|call| {
+                            format!(
+                                "rewards of {} operators",
+                                call.submission.operatorRewards.len()
+                            )
+                        })
+                        .ok()
+                } else {
+                    None
+                };
+                let call = call.unwrap_or_else(|| format!("call 0x{}", hex::encode(selector)));
+                format!("{call} to {target:?}")
+            }
+            Dispatch::MintForeignToken {
+                recipient, amount, ..
+            } => format!("native token transfer of {amount} to {recipient:?}"),
+        }
+    }
+}
+
+/// Execute the messages committed from the DataHaven block `from_block` on.
+pub(crate) async fn run(
+    datahaven: &DataHavenClient,
+    ethereum: &EthereumClient,
+    gateway: H160,
+    from_block: Option<u32>,
+    dry_run: bool,
+    poll_interval: Duration,
+) -> Result<()> {
+    let mut next = match from_block {
+        Some(block) => block,
+        None => datahaven.finalized_block().await?.0,
+    };
+    info!(target: LOG_TARGET, "Relaying the outbound messages from DataHaven block #{next}");
+
+    loop {
+        if let Err(err) =
+            relay_finalized_blocks(datahaven, ethereum, gateway, dry_run, &mut next).await
+        {
+            warn!(target: LOG_TARGET, "Failed to relay the messages of #{next}: {err}");
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+async fn relay_finalized_blocks(
+    datahaven: &DataHavenClient,
+    ethereum: &EthereumClient,
+    gateway: H160,
+    dry_run: bool,
+    next: &mut u32,
+) -> Result<()> {
+    let (finalized, _) = datahaven.finalized_block().await?;
+
+    while *next <= finalized {
+        let hash = datahaven.block_hash(*next).await?;
+        for message in datahaven.outbound_messages(hash).await? {
+            relay_message(ethereum, gateway, &message, dry_run).await;
+        }
+        *next += 1;
+    }
+    Ok(())
+}
+
+/// Execute the commands of `message`, logging the ones that fail.
+async fn relay_message(
+    ethereum: &EthereumClient,
+    gateway: H160,
+    message: &OutboundMessage,
+    dry_run: bool,
+) {
+    let nonce = message.nonce;
+    for command in message.commands.iter() {
+        let dispatch = match Dispatch::decode(command) {
+            Ok(dispatch) => dispatch,
+            Err(err) => {
+                warn!(target: LOG_TARGET, "Skipping a command of message {nonce}: {err}");
+                continue;
+            }
+        };
+        let description = dispatch.describe();
+        if dry_run {
+            info!(target: LOG_TARGET, "Message {nonce}: {description}");
+            continue;
+        }
+        match execute(ethereum, gateway, message.origin, dispatch).await {
+            Ok(hash) => info!(target: LOG_TARGET, "Message {nonce}: {description} in {hash:?}"),
+            Err(err) => warn!(target: LOG_TARGET, "Message {nonce}: {description} failed: {err}"),
+        }
+    }
+}
+
+async fn execute(
+    ethereum: &EthereumClient,
+    gateway: H160,
+    origin: H256,
+    dispatch: Dispatch,
+) -> Result<H256> {
+    match dispatch {
+        Dispatch::CallContract {
+            target,
+            data,
+            value,
+        } => {
+            let agent = gateway_address_query(
+                ethereum,
+                gateway,
+                IGateway::agentOfCall {
+                    agentID: B256::from(origin.0),
+                }
+                .abi_encode(),
+            )
+            .await?;
+            ethereum.impersonate(agent).await?;
+            ethereum.transact(agent, target, data, value).await
+        }
+        Dispatch::MintForeignToken {
+            token_id,
+            recipient,
+            amount,
+        } => {
+            let token = gateway_address_query(
+                ethereum,
+                gateway,
+                IGateway::tokenAddressOfCall {
+                    tokenID: B256::from(token_id.0),
+                }
+                .abi_encode(),
+            )
+            .await?;
+            let mint = IToken::mintCall {
+                account: Address::from(recipient.0),
+                amount: AbiU256::from(amount),
+            };
+            ethereum.impersonate(gateway).await?;
+            ethereum
+                .transact(gateway, token, mint.abi_encode(), U256::zero())
+                .await
+        }
+    }
+}
+
+/// Address returned by a view function of the Gateway.
+async fn gateway_address_query(
+    ethereum: &EthereumClient,
+    gateway: H160,
+    calldata: Vec<u8>,
+) -> Result<H160> {
+    let output = ethereum.call(gateway, calldata).await?;
+    let address = <Address as SolValue>::abi_decode(&output, true)?;
+    if address.is_zero() {
+        return Err("Unknown to the Gateway".into());
+    }
+    Ok(H160::from_slice(address.as_slice()))
+}
@@ -0,0 +1,196 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inbound command vectors, for the messages sent from Ethereum to DataHaven.
+
+use crate::{test_address, to_hex, VALIDATOR_PREFIX};
+use codec::Encode;
+use datahaven_mainnet_runtime::Runtime;
+use datahaven_runtime_common::AccountId;
+use dhp_bridge::{
+    DepositCommand, DepositMessage, DepositPayload, InboundCommand, Message, Payload,
+    EL_MESSAGE_ID, HAVE_DEPOSIT_MESSAGE_ID,
+};
+use pallet_external_validator_slashes::SlashingModeOption;
+use serde::Serialize;
+use sp_core::H256;
+
+/// Prefix of the native token recipient addresses.
+const RECIPIENT_PREFIX: u8 = 0xbe;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundVectors {
+    receive_validators: Vec<ReceiveValidatorsVector>,
+    cancel_slashes: Vec<CancelSlashVector>,
+    slashing_modes: Vec<SlashingModeVector>,
+    native_transfer_claimers: Vec<ClaimerVector>,
+    have_deposits: Vec<HaveDepositVector>,
+}
+
+/// Payload of a new validator set message, as decoded by `EigenLayerMessageProcessor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReceiveValidatorsVector {
+    validators: Vec<String>,
+    external_index: u64,
+    payload: String,
+}
+
+/// Payload of a deferred slashes cancellation, as decoded by `EigenLayerMessageProcessor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelSlashVector {
+    era: u32,
+    slash_ids: Vec<u32>,
+    payload: String,
+}
+
+/// Payload of a slashing mode change, as decoded by `EigenLayerMessageProcessor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SlashingModeVector {
+    mode: String,
+    payload: String,
+}
+
+/// Claimer of a native token transfer back to DataHaven, the recipient of the tokens.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimerVector {
+    recipient: String,
+    claimer: String,
+}
+
+/// Payload of a deposit credited as HAVE, as decoded by `HaveDepositMessageProcessor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HaveDepositVector {
+    deposit_id: String,
+    recipient: String,
+    /// Decimal string, as the amount may not fit in a JSON number.
+    amount: String,
+    payload: String,
+}
+
+fn have_deposit(deposit_id: u64, recipient_index: u8, amount: u128) -> HaveDepositVector {
+    let deposit_id = H256::from_low_u64_be(deposit_id);
+    let recipient = test_address(RECIPIENT_PREFIX, recipient_index);
+
+    let payload = DepositPayload {
+        message_id: HAVE_DEPOSIT_MESSAGE_ID,
+        message: DepositMessage::V1(DepositCommand::CreditDeposit {
+            deposit_id,
+            recipient,
+            amount,
+        }),
+    };
+
+    HaveDepositVector {
+        deposit_id: to_hex(deposit_id),
+        recipient: to_hex(recipient),
+        amount: amount.to_string(),
+        payload: to_hex(payload.encode()),
+    }
+}
+
+fn receive_validators(validators_count: u8, external_index: u64) -> ReceiveValidatorsVector {
+    let validators = (1..=validators_count)
+        .map(|index| AccountId::from(test_address(VALIDATOR_PREFIX, index)))
+        .collect::<Vec<_>>();
+
+    let payload = Payload::<Runtime> {
+        message_id: EL_MESSAGE_ID,
+        message: Message::V1(InboundCommand::ReceiveValidators {
+            validators: validators.clone(),
+            external_index,
+        }),
+    };
+
+    ReceiveValidatorsVector {
+        validators: validators
+            .iter()
+            .map(|validator| to_hex(validator.0))
+            .collect(),
+        external_index,
+        payload: to_hex(payload.encode()),
+    }
+}
+
+fn cancel_slash(era: u32, slash_ids: Vec<u32>) -> CancelSlashVector {
+    let payload = Payload::<Runtime> {
+        message_id: EL_MESSAGE_ID,
+        message: Message::V1(InboundCommand::CancelSlash {
+            era,
+            slash_ids: slash_ids.clone(),
+        }),
+    };
+
+    CancelSlashVector {
+        era,
+        slash_ids,
+        payload: to_hex(payload.encode()),
+    }
+}
+
+fn slashing_mode(name: &str, mode: SlashingModeOption) -> SlashingModeVector {
+    let payload = Payload::<Runtime> {
+        message_id: EL_MESSAGE_ID,
+        message: Message::V1(InboundCommand::SetSlashingMode(mode)),
+    };
+
+    SlashingModeVector {
+        mode: name.into(),
+        payload: to_hex(payload.encode()),
+    }
+}
+
+pub fn generate() -> InboundVectors {
+    InboundVectors {
+        // The compact length prefix changes size at 64 validators.
+        receive_validators: vec![
+            receive_validators(0, 0),
+            receive_validators(1, 1),
+            receive_validators(3, 0),
+            receive_validators(32, 7),
+            receive_validators(64, u64::MAX),
+        ],
+        cancel_slashes: vec![
+            cancel_slash(1, vec![0]),
+            cancel_slash(42, vec![3, 7, 1_000]),
+            cancel_slash(u32::MAX, vec![u32::MAX]),
+        ],
+        slashing_modes: vec![
+            slashing_mode("Enabled", SlashingModeOption::Enabled),
+            slashing_mode("LogOnly", SlashingModeOption::LogOnly),
+            slashing_mode("Disabled", SlashingModeOption::Disabled),
+        ],
+        native_transfer_claimers: (1..=2)
+            .map(|index| {
+                let recipient = test_address(RECIPIENT_PREFIX, index);
+                ClaimerVector {
+                    recipient: to_hex(recipient),
+                    claimer: to_hex(recipient.encode()),
+                }
+            })
+            .collect(),
+        have_deposits: vec![
+            have_deposit(1, 1, 1),
+            have_deposit(2, 2, 1_000_000_000_000_000_000),
+            have_deposit(u64::MAX, 1, u128::MAX),
+        ],
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test vectors for the DataHaven Ethereum contracts.
+//!
+//! Encodes a fixed set of inputs with the codecs and hashers used by the runtime and writes
+//! them as JSON, so the contract unit tests can check they produce and consume the same
+//! bytes as the chain:
+//! - slash messages: WAD amounts, offence descriptions and `slashValidatorsOperator` calldata,
+//! - era rewards: merkle roots and the proof of every validator,
+//! - inbound commands: SCALE-encoded payloads of the messages sent to DataHaven.
+//!
+//! Run with `cargo run -p datahaven-test-vectors -- --output <file>`.
+
+mod inbound;
+mod rewards;
+mod slashes;
+
+use clap::Parser;
+use serde::Serialize;
+use std::{error::Error, fs, path::PathBuf};
+
+#[derive(Debug, Parser)]
+#[command(about = "Generate test vectors for the DataHaven Ethereum contracts")]
+struct Cli {
+    /// File to write the test vectors to. Printed to stdout if not set.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TestVectors {
+    slashes: slashes::SlashVectors,
+    rewards: Vec<rewards::RewardsTreeVector>,
+    inbound: inbound::InboundVectors,
+}
+
+/// Prefix of the validator addresses.
+pub(crate) const VALIDATOR_PREFIX: u8 = 0xa0;
+
+/// `0x`-prefixed hex encoding, as expected by Foundry's JSON cheatcodes.
+pub(crate) fn to_hex(bytes: impl AsRef<[u8]>) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Deterministic address of the vectors, the `index`-th one of the kind given by `prefix`.
+pub(crate) fn test_address(prefix: u8, index: u8) -> sp_core::H160 {
+    let mut address = sp_core::H160::repeat_byte(index);
+    address.0[0] = prefix;
+    address
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+
+    // Runtime parameters are read from storage, an empty one yields their default values.
+    let vectors = sp_io::TestExternalities::default().execute_with(|| TestVectors {
+        slashes: slashes::generate(),
+        rewards: rewards::generate(),
+        inbound: inbound::generate(),
+    });
+
+    let json = serde_json::to_string_pretty(&vectors)?;
+    match cli.output {
+        Some(path) => fs::write(path, json + "\n")?,
+        None => println!("{json}"),
+    }
+
+    Ok(())
+}
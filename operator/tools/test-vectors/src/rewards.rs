@@ -0,0 +1,111 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Era rewards merkle tree vectors.
+
+use crate::{test_address, to_hex, VALIDATOR_PREFIX};
+use datahaven_runtime_common::AccountId;
+use pallet_external_validators_rewards::EraRewardPoints;
+use serde::Serialize;
+use snowbridge_merkle_tree::{merkle_proof, merkle_root};
+use sp_core::H160;
+use sp_runtime::traits::Keccak256;
+
+/// Prefix of the reward addresses set by the validators.
+const REWARD_ADDRESS_PREFIX: u8 = 0xde;
+
+/// Rewards tree of an era, as built by the rewards pallet.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardsTreeVector {
+    total_points: u32,
+    number_of_leaves: u64,
+    root: String,
+    leaves: Vec<RewardsLeafVector>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RewardsLeafVector {
+    validator: String,
+    reward_address: String,
+    points: u32,
+    /// Keccak-256 of the SCALE-encoded `(validator, reward_address, points)` tuple.
+    leaf: String,
+    leaf_index: u64,
+    proof: Vec<String>,
+}
+
+/// Reward address recorded for `validator` at the end of the era, of the same index.
+fn reward_address(validator: &AccountId) -> H160 {
+    test_address(REWARD_ADDRESS_PREFIX, validator.0[1])
+}
+
+fn rewards_tree(points: &[(u8, u32)]) -> RewardsTreeVector {
+    let mut era_rewards = EraRewardPoints::<AccountId>::default();
+    for (validator, validator_points) in points {
+        era_rewards.individual.insert(
+            AccountId::from(test_address(VALIDATOR_PREFIX, *validator)),
+            *validator_points,
+        );
+        era_rewards.total += validator_points;
+    }
+
+    // Leaves are ordered by account, like the `individual` map they come from.
+    let leaves = era_rewards
+        .generate_rewards_leaves::<Keccak256>(|validator| Some(reward_address(validator)));
+    let root = merkle_root::<Keccak256, _>(leaves.iter().cloned());
+
+    RewardsTreeVector {
+        total_points: era_rewards.total,
+        number_of_leaves: leaves.len() as u64,
+        root: to_hex(root),
+        leaves: era_rewards
+            .individual
+            .iter()
+            .enumerate()
+            .map(|(leaf_index, (validator, validator_points))| {
+                let proof = merkle_proof::<Keccak256, _>(leaves.iter().cloned(), leaf_index as u64);
+                debug_assert_eq!(proof.root, root);
+
+                RewardsLeafVector {
+                    validator: to_hex(validator.0),
+                    reward_address: to_hex(reward_address(validator)),
+                    points: *validator_points,
+                    leaf: to_hex(proof.leaf),
+                    leaf_index: proof.leaf_index,
+                    proof: proof.proof.iter().map(to_hex).collect(),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Trees with one leaf, and with even and odd numbers of leaves, the last leaf of an odd
+/// level being promoted as is to the next one.
+pub fn generate() -> Vec<RewardsTreeVector> {
+    vec![
+        rewards_tree(&[(1, 1_000)]),
+        rewards_tree(&[(1, 20), (2, 40)]),
+        rewards_tree(&[(3, 600), (1, 20), (2, 40)]),
+        rewards_tree(&[(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)]),
+        rewards_tree(
+            &(1..=32)
+                .map(|validator| (validator, 20 * validator as u32))
+                .collect::<Vec<_>>(),
+        ),
+    ]
+}
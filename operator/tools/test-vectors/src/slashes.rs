@@ -0,0 +1,192 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Slash message vectors.
+
+use crate::{test_address, to_hex, VALIDATOR_PREFIX};
+use alloy_core::primitives::Address;
+use datahaven_mainnet_runtime::configs::runtime_params::dynamic_params::runtime_config::MaxSlashWad;
+use datahaven_runtime_common::{
+    slashes_adapter::{encode_slashing_request, encode_slashing_request_with_evidence},
+    AccountId,
+};
+use frame_support::{traits::Get, BoundedVec};
+use pallet_external_validator_slashes::{perbill_to_wad, OffenceKind, SlashData};
+use serde::Serialize;
+use sp_core::H256;
+use sp_runtime::Perbill;
+
+/// Prefix of the strategy addresses.
+const STRATEGY_PREFIX: u8 = 0x5e;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlashVectors {
+    /// WAD amount of a 100% slash.
+    max_slash_wad: String,
+    wads: Vec<WadVector>,
+    descriptions: Vec<DescriptionVector>,
+    messages: Vec<SlashMessageVector>,
+}
+
+/// Conversion of a slashed proportion into the WAD amount sent to EigenLayer.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WadVector {
+    /// Slashed proportion in parts per billion.
+    perbill: u32,
+    /// Decimal string, as the amount may not fit in a JSON number.
+    wad_to_slash: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DescriptionVector {
+    offence_kind: String,
+    description: String,
+}
+
+/// A slashes message for one era.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SlashMessageVector {
+    era: u32,
+    /// Id of the outbound message, derived from the era.
+    message_id: String,
+    strategies: Vec<String>,
+    operators: Vec<String>,
+    wads_to_slash: Vec<String>,
+    descriptions: Vec<String>,
+    evidences: Vec<String>,
+    /// ABI-encoded `slashValidatorsOperator` call.
+    calldata: String,
+    /// ABI-encoded `slashValidatorsOperatorWithEvidence` call, sent from schema V2.
+    calldata_with_evidence: String,
+}
+
+const PERBILLS: [u32; 7] = [
+    0,
+    1,
+    999_999,
+    10_000_000,
+    333_333_333,
+    500_000_000,
+    1_000_000_000,
+];
+
+fn offence_kinds() -> Vec<(&'static str, OffenceKind)> {
+    vec![
+        ("LivenessOffence", OffenceKind::LivenessOffence),
+        ("BabeEquivocation", OffenceKind::BabeEquivocation),
+        ("GrandpaEquivocation", OffenceKind::GrandpaEquivocation),
+        ("BeefyEquivocation", OffenceKind::BeefyEquivocation),
+        (
+            "Custom",
+            OffenceKind::Custom(BoundedVec::truncate_from(
+                b"Manual slash: double signing".to_vec(),
+            )),
+        ),
+    ]
+}
+
+fn slash_message(
+    era: u32,
+    strategies_count: u8,
+    slashes: &[(u8, u32, OffenceKind)],
+) -> SlashMessageVector {
+    let max_wad = MaxSlashWad::get();
+    let strategies = (1..=strategies_count)
+        .map(|index| test_address(STRATEGY_PREFIX, index))
+        .collect::<Vec<_>>();
+    let slashes = slashes
+        .iter()
+        .map(|(validator, perbill, offence_kind)| SlashData {
+            validator: AccountId::from(test_address(VALIDATOR_PREFIX, *validator)),
+            // Validators without a registered operator are slashed at their own address.
+            operator: test_address(VALIDATOR_PREFIX, *validator),
+            wad_to_slash: perbill_to_wad(Perbill::from_parts(*perbill), max_wad),
+            description: offence_kind.to_description(),
+            evidence: H256::repeat_byte(*validator),
+        })
+        .collect::<Vec<_>>();
+
+    let strategy_addresses = strategies
+        .iter()
+        .map(|strategy| Address::from(strategy.0))
+        .collect::<Vec<_>>();
+    let calldata = encode_slashing_request(&slashes, strategy_addresses.clone());
+    let calldata_with_evidence =
+        encode_slashing_request_with_evidence(&slashes, strategy_addresses);
+
+    SlashMessageVector {
+        era,
+        message_id: to_hex(H256::from_low_u64_be(era as u64)),
+        strategies: strategies.iter().map(to_hex).collect(),
+        operators: slashes
+            .iter()
+            .map(|slash| to_hex(slash.validator.0))
+            .collect(),
+        wads_to_slash: slashes
+            .iter()
+            .map(|slash| slash.wad_to_slash.to_string())
+            .collect(),
+        descriptions: slashes
+            .iter()
+            .map(|slash| slash.description.clone())
+            .collect(),
+        evidences: slashes.iter().map(|slash| to_hex(slash.evidence)).collect(),
+        calldata: to_hex(calldata),
+        calldata_with_evidence: to_hex(calldata_with_evidence),
+    }
+}
+
+pub fn generate() -> SlashVectors {
+    let max_wad = MaxSlashWad::get();
+
+    SlashVectors {
+        max_slash_wad: max_wad.to_string(),
+        wads: PERBILLS
+            .iter()
+            .map(|perbill| WadVector {
+                perbill: *perbill,
+                wad_to_slash: perbill_to_wad(Perbill::from_parts(*perbill), max_wad).to_string(),
+            })
+            .collect(),
+        descriptions: offence_kinds()
+            .into_iter()
+            .map(|(name, offence_kind)| DescriptionVector {
+                offence_kind: name.into(),
+                description: offence_kind.to_description(),
+            })
+            .collect(),
+        messages: vec![
+            slash_message(0, 1, &[]),
+            slash_message(1, 1, &[(1, 100_000_000, OffenceKind::BabeEquivocation)]),
+            slash_message(
+                42,
+                3,
+                &offence_kinds()
+                    .into_iter()
+                    .zip(PERBILLS)
+                    .enumerate()
+                    .map(|(index, ((_, offence_kind), perbill))| {
+                        (index as u8 + 1, perbill, offence_kind)
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        ],
+    }
+}
@@ -2319,6 +2319,31 @@ export const dataHavenServiceManagerAbi = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  {
+    type: 'function',
+    inputs: [
+      {
+        name: 'slashings',
+        internalType:
+          'struct IDataHavenServiceManager.SlashingRequestWithEvidence[]',
+        type: 'tuple[]',
+        components: [
+          { name: 'operator', internalType: 'address', type: 'address' },
+          {
+            name: 'strategies',
+            internalType: 'contract IStrategy[]',
+            type: 'address[]',
+          },
+          { name: 'wadsToSlash', internalType: 'uint256[]', type: 'uint256[]' },
+          { name: 'description', internalType: 'string', type: 'string' },
+          { name: 'evidence', internalType: 'bytes32', type: 'bytes32' },
+        ],
+      },
+    ],
+    name: 'slashValidatorsOperatorWithEvidence',
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
   {
     type: 'function',
     inputs: [],
@@ -2503,6 +2528,25 @@ export const dataHavenServiceManagerAbi = [
     ],
     name: 'OperatorRegistered',
   },
+  {
+    type: 'event',
+    anonymous: false,
+    inputs: [
+      {
+        name: 'operator',
+        internalType: 'address',
+        type: 'address',
+        indexed: true,
+      },
+      {
+        name: 'evidence',
+        internalType: 'bytes32',
+        type: 'bytes32',
+        indexed: false,
+      },
+    ],
+    name: 'OperatorSlashedWithEvidence',
+  },
   {
     type: 'event',
     anonymous: false,
@@ -11252,6 +11296,15 @@ export const writeDataHavenServiceManagerSlashValidatorsOperator =
     functionName: 'slashValidatorsOperator',
   })
 
+/**
+ * Wraps __{@link writeContract}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `functionName` set to `"slashValidatorsOperatorWithEvidence"`
+ */
+export const writeDataHavenServiceManagerSlashValidatorsOperatorWithEvidence =
+  /*#__PURE__*/ createWriteContract({
+    abi: dataHavenServiceManagerAbi,
+    functionName: 'slashValidatorsOperatorWithEvidence',
+  })
+
 /**
  * Wraps __{@link writeContract}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `functionName` set to `"submitRewards"`
  */
@@ -11438,6 +11491,15 @@ export const simulateDataHavenServiceManagerSlashValidatorsOperator =
     functionName: 'slashValidatorsOperator',
   })
 
+/**
+ * Wraps __{@link simulateContract}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `functionName` set to `"slashValidatorsOperatorWithEvidence"`
+ */
+export const simulateDataHavenServiceManagerSlashValidatorsOperatorWithEvidence =
+  /*#__PURE__*/ createSimulateContract({
+    abi: dataHavenServiceManagerAbi,
+    functionName: 'slashValidatorsOperatorWithEvidence',
+  })
+
 /**
  * Wraps __{@link simulateContract}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `functionName` set to `"submitRewards"`
  */
@@ -11516,6 +11578,15 @@ export const watchDataHavenServiceManagerOperatorRegisteredEvent =
     eventName: 'OperatorRegistered',
   })
 
+/**
+ * Wraps __{@link watchContractEvent}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `eventName` set to `"OperatorSlashedWithEvidence"`
+ */
+export const watchDataHavenServiceManagerOperatorSlashedWithEvidenceEvent =
+  /*#__PURE__*/ createWatchContractEvent({
+    abi: dataHavenServiceManagerAbi,
+    eventName: 'OperatorSlashedWithEvidence',
+  })
+
 /**
  * Wraps __{@link watchContractEvent}__ with `abi` set to __{@link dataHavenServiceManagerAbi}__ and `eventName` set to `"OwnershipTransferred"`
  */
@@ -142,7 +142,8 @@ describe("Should slash an operator", () => {
       offence_kind: {
         type: "Custom",
         value: Binary.fromText("Manual slash: E2E test")
-      }
+      },
+      evidence_hash: FixedSizeBinary.fromHex(`0x${"ee".repeat(32)}`)
     });
     const sudoTx = dhApi.tx.Sudo.sudo({
       call: sudoSlashCall.decodedCall